@@ -1,5 +1,3 @@
-use std::rc::Rc;
-
 use yew::prelude::*;
 use yew::virtual_dom::VChild;
 
@@ -72,10 +70,8 @@ impl List {
             .into_iter()
             .filter(|c| !c.props.hide)
             .enumerate()
-            .map(|(i, mut c)| {
-                let props = Rc::make_mut(&mut c.props);
-                props.name = format!("#{} - {}", i + 1, props.name).into();
-                c
+            .map(|(i, c)| {
+                c.with_props(|props| props.name = format!("#{} - {}", i + 1, props.name).into())
             })
             .collect::<Html>()
     }
@@ -89,7 +89,7 @@ impl Component for App {
             html! {
                 <style>{"p { color: red; }"}</style>
             },
-            document_head.into(),
+            Element::from(document_head),
         );
         Self {
             style_html,
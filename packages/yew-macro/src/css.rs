@@ -0,0 +1,61 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::LitStr;
+
+/// A stylesheet scoped to the class of the component using it.
+pub struct Css {
+    css: LitStr,
+}
+
+impl Parse for Css {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let css = input.parse::<LitStr>().map_err(|e| {
+            syn::Error::new(e.span(), "expected the stylesheet as a string literal")
+        })?;
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after the stylesheet"));
+        }
+
+        check_blocks(&css.value()).map_err(|msg| syn::Error::new(css.span(), msg))?;
+        Ok(Self { css })
+    }
+}
+
+/// Checks that the braces of the blocks and the quotes of the strings are balanced.
+fn check_blocks(css: &str) -> Result<(), &'static str> {
+    let mut depth = 0_usize;
+    let mut quote = None;
+    for c in css.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '{') => depth += 1,
+            (None, '}') => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or("this stylesheet closes a block that was never opened")?;
+            }
+            _ => {}
+        }
+    }
+
+    if quote.is_some() {
+        Err("this stylesheet has an unterminated string")
+    } else if depth != 0 {
+        Err("this stylesheet has a block that is never closed")
+    } else {
+        Ok(())
+    }
+}
+
+impl ToTokens for Css {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { css } = self;
+        tokens.extend(quote! {
+            ::yew::css::StyleSheet::from_static(#css)
+        });
+    }
+}
+
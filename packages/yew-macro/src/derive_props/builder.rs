@@ -93,6 +93,10 @@ impl PropsBuilder<'_> {
         let prop_fns = prop_fields
             .iter()
             .map(|pf| pf.to_build_step_fn(vis, props_name));
+        let rest_fns = prop_fields
+            .iter()
+            .filter(|pf| pf.is_rest())
+            .map(|pf| pf.to_rest_build_fns(vis, prop_fields));
 
         let (builder_impl_generics, ty_generics, builder_where_clause) = generics.split_for_impl();
         let turbofish_generics = ty_generics.as_turbofish();
@@ -129,6 +133,7 @@ impl PropsBuilder<'_> {
             #( #extra_attrs )*
             impl #builder_impl_generics #builder_name<#generic_args> #builder_where_clause {
                 #( #prop_fns )*
+                #( #rest_fns )*
             }
 
             #[doc(hidden)]
@@ -5,10 +5,14 @@ use proc_macro2::{Ident, Span};
 use quote::{format_ident, quote, quote_spanned};
 use syn::parse::Result;
 use syn::spanned::Spanned;
-use syn::{parse_quote, Attribute, Error, Expr, Field, GenericParam, Generics, Type, Visibility};
+use syn::{
+    parse_quote, Attribute, Error, Expr, ExprLit, Field, GenericParam, Generics, Lit, Meta,
+    MetaNameValue, Type, Visibility,
+};
 
 use super::should_preserve_attr;
 use crate::derive_props::generics::push_type_param;
+use crate::props::{LISTENER_SET, REST_ATTRIBUTES};
 
 #[allow(clippy::large_enum_variant)]
 #[derive(PartialEq, Eq)]
@@ -17,6 +21,7 @@ pub enum PropAttr {
     PropOr(Expr),
     PropOrElse(Expr),
     PropOrDefault,
+    PropOrRest,
 }
 
 #[derive(Eq)]
@@ -25,6 +30,7 @@ pub struct PropField {
     name: Ident,
     pub attr: PropAttr,
     extra_attrs: Vec<Attribute>,
+    docs: String,
 }
 
 impl PropField {
@@ -33,6 +39,27 @@ impl PropField {
         matches!(self.attr, PropAttr::Required { .. })
     }
 
+    /// The rest field collects the attributes and listeners not declared by other fields
+    pub fn is_rest(&self) -> bool {
+        matches!(self.attr, PropAttr::PropOrRest)
+    }
+
+    /// Describes the field for the generated `CustomElementProperties` impl
+    pub fn to_custom_element_field(&self) -> proc_macro2::TokenStream {
+        if self.is_rest() {
+            return Default::default();
+        }
+        let Self { ty, name, docs, .. } = self;
+        let name = name.to_string();
+        let name = name.trim_start_matches("r#");
+        let required = self.is_required();
+        let extra_attrs = &self.extra_attrs;
+        quote! {
+            #( #extra_attrs )*
+            let descriptor = descriptor.field::<#ty>(#name, #required, #docs);
+        }
+    }
+
     /// This check name is descriptive to help a developer realize they missed a required prop
     fn to_check_name(&self, props_name: &Ident) -> Ident {
         format_ident!("Has{}{}", props_name, self.name, span = Span::mixed_site())
@@ -88,7 +115,7 @@ impl PropField {
                     #name: ::std::option::Option::unwrap_or_else(this.wrapped.#name, #func),
                 }
             }
-            PropAttr::PropOrDefault => {
+            PropAttr::PropOrDefault | PropAttr::PropOrRest => {
                 quote! {
                     #name: ::std::option::Option::unwrap_or_default(this.wrapped.#name),
                 }
@@ -166,12 +193,102 @@ impl PropField {
         }
     }
 
+    /// The rest field has a builder method for each attribute and listener it can collect, unless
+    /// another field has the same name
+    pub fn to_rest_build_fns(
+        &self,
+        vis: &Visibility,
+        prop_fields: &[PropField],
+    ) -> proc_macro2::TokenStream {
+        let Self { name, .. } = self;
+        let token_ty = Ident::new("__YewTokenTy", Span::mixed_site());
+        let rest = quote! {
+            ::std::option::Option::get_or_insert_with(
+                &mut self.wrapped.#name,
+                ::std::default::Default::default,
+            )
+        };
+        let is_declared = |label: &str| prop_fields.iter().any(|pf| pf.name == label);
+        let extra_attrs = &self.extra_attrs;
+
+        let attr_fns = REST_ATTRIBUTES
+            .iter()
+            .filter(|label| !is_declared(label))
+            .map(|label| {
+                let label = Ident::new(label, Span::call_site());
+                let key = label.to_string();
+                quote! {
+                    #( #extra_attrs )*
+                    #[doc(hidden)]
+                    #vis fn #label<#token_ty>(
+                        &mut self,
+                        token: #token_ty,
+                        value: impl ::yew::html::IntoPropValue<
+                            ::std::option::Option<::yew::virtual_dom::AttrValue>,
+                        >,
+                    ) -> #token_ty {
+                        ::yew::html::RestProps::set_attribute(#rest, #key, value);
+                        token
+                    }
+                }
+            });
+
+        let mut listeners = LISTENER_SET
+            .iter()
+            .copied()
+            // `oncustom` only exists with the `custom_events` feature of yew.
+            .filter(|label| *label != "oncustom")
+            .collect::<Vec<_>>();
+        listeners.sort_unstable();
+        let listener_fns = listeners
+            .into_iter()
+            .filter(|label| !is_declared(label))
+            .map(|label| {
+                let label = Ident::new(label, Span::call_site());
+                quote! {
+                    #( #extra_attrs )*
+                    #[doc(hidden)]
+                    #vis fn #label<#token_ty>(
+                        &mut self,
+                        token: #token_ty,
+                        value: impl ::yew::html::IntoEventCallback<::yew::html::#label::Event>,
+                    ) -> #token_ty {
+                        ::yew::html::RestProps::add_listener(
+                            #rest,
+                            ::yew::html::#label::Wrapper::__macro_new(value),
+                        );
+                        token
+                    }
+                }
+            });
+
+        quote! {
+            #( #extra_attrs )*
+            #[doc(hidden)]
+            #vis fn __yew_rest_attr<#token_ty>(
+                &mut self,
+                token: #token_ty,
+                key: &'static str,
+                value: impl ::yew::html::IntoPropValue<
+                    ::std::option::Option<::yew::virtual_dom::AttrValue>,
+                >,
+            ) -> #token_ty {
+                ::yew::html::RestProps::set_attribute(#rest, key, value);
+                token
+            }
+
+            #( #attr_fns )*
+            #( #listener_fns )*
+        }
+    }
+
     // Detect Properties 2.0 attributes
     fn attribute(named_field: &Field) -> Result<PropAttr> {
         let attr = named_field.attrs.iter().find(|attr| {
             attr.path().is_ident("prop_or")
                 || attr.path().is_ident("prop_or_else")
                 || attr.path().is_ident("prop_or_default")
+                || attr.path().is_ident("prop_or_rest")
         });
 
         if let Some(attr) = attr {
@@ -181,6 +298,8 @@ impl PropField {
                 Ok(PropAttr::PropOrElse(attr.parse_args()?))
             } else if attr.path().is_ident("prop_or_default") {
                 Ok(PropAttr::PropOrDefault)
+            } else if attr.path().is_ident("prop_or_rest") {
+                Ok(PropAttr::PropOrRest)
             } else {
                 unreachable!()
             }
@@ -270,12 +389,33 @@ impl TryFrom<Field> for PropField {
         Ok(PropField {
             attr: Self::attribute(&field)?,
             extra_attrs,
+            docs: doc_comment(&field.attrs),
             ty: field.ty,
             name: field.ident.unwrap(),
         })
     }
 }
 
+/// Collects the `///` comments of a field
+fn doc_comment(attrs: &[Attribute]) -> String {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(s), ..
+                    }),
+                ..
+            }) => Some(s.value()),
+            _ => None,
+        })
+        .map(|line| line.strip_prefix(' ').map(str::to_owned).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 impl PartialOrd for PropField {
     fn partial_cmp(&self, other: &PropField) -> Option<Ordering> {
         Some(self.cmp(other))
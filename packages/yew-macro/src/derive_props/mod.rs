@@ -14,7 +14,7 @@ use syn::punctuated::Pair;
 use syn::visit_mut::VisitMut;
 use syn::{
     AngleBracketedGenericArguments, Attribute, ConstParam, DeriveInput, GenericArgument,
-    GenericParam, Generics, Path, PathArguments, PathSegment, Type, TypeParam, TypePath,
+    GenericParam, Generics, LitStr, Path, PathArguments, PathSegment, Type, TypeParam, TypePath,
     Visibility,
 };
 use wrapper::PropsWrapper;
@@ -28,6 +28,7 @@ pub struct DerivePropsInput {
     props_name: Ident,
     prop_fields: Vec<PropField>,
     preserved_attrs: Vec<Attribute>,
+    custom_element: Option<LitStr>,
 }
 
 /// AST visitor that replaces all occurences of the keyword `Self` with `new_self`
@@ -117,6 +118,19 @@ impl Parse for DerivePropsInput {
         let prop_fields = match input.data {
             syn::Data::Struct(data) => match data.fields {
                 syn::Fields::Named(fields) => {
+                    let second_rest_attr = fields
+                        .named
+                        .iter()
+                        .flat_map(|f| &f.attrs)
+                        .filter(|a| a.path().is_ident("prop_or_rest"))
+                        .nth(1);
+                    if let Some(attr) = second_rest_attr {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            "only one field can collect the rest of the props",
+                        ));
+                    }
+
                     let mut prop_fields: Vec<PropField> = fields
                         .named
                         .into_iter()
@@ -141,12 +155,20 @@ impl Parse for DerivePropsInput {
             .cloned()
             .collect();
 
+        let custom_element = input
+            .attrs
+            .iter()
+            .find(|a| a.path().is_ident("custom_element"))
+            .map(|a| a.parse_args::<LitStr>())
+            .transpose()?;
+
         Ok(Self {
             vis: input.vis,
             props_name: input.ident,
             generics: input.generics,
             prop_fields,
             preserved_attrs,
+            custom_element,
         })
     }
 }
@@ -172,6 +194,7 @@ impl ToTokens for DerivePropsInput {
             props_name,
             prop_fields,
             preserved_attrs,
+            custom_element,
             ..
         } = self;
 
@@ -208,5 +231,22 @@ impl ToTokens for DerivePropsInput {
             }
         };
         tokens.extend(properties);
+
+        // Components exported as custom elements describe their props for TypeScript
+        if let Some(tag_name) = custom_element {
+            let fields = prop_fields.iter().map(|f| f.to_custom_element_field());
+            tokens.extend(quote! {
+                impl #impl_generics ::yew::custom_element::CustomElementProperties
+                    for #props_name #ty_generics #where_clause
+                {
+                    fn custom_element() -> ::yew::custom_element::CustomElementDescriptor {
+                        let descriptor =
+                            ::yew::custom_element::CustomElementDescriptor::new(#tag_name);
+                        #( #fields )*
+                        descriptor
+                    }
+                }
+            });
+        }
     }
 }
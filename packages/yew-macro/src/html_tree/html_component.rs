@@ -5,7 +5,7 @@ use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
 use syn::{Token, Type};
 
-use super::{HtmlChildrenTree, TagTokens};
+use super::{HtmlChildrenTree, HtmlTree, TagTokens};
 use crate::is_ide_completion;
 use crate::props::ComponentProps;
 
@@ -104,8 +104,30 @@ impl Parse for HtmlComponent {
             children.parse_child(input)?;
         };
 
+        // named slots fill the props of the same name, the other children fill `children`
+        let mut props = open.props;
+        let (slots, others) = children
+            .0
+            .into_iter()
+            .partition::<Vec<_>, _>(|child| matches!(child, HtmlTree::Slot(_)));
+        children.0 = others;
+        let mut slot_names = Vec::new();
+        for slot in slots {
+            let HtmlTree::Slot(slot) = slot else {
+                unreachable!()
+            };
+            if slot_names.contains(slot.name()) {
+                return Err(syn::Error::new_spanned(
+                    slot.name(),
+                    format!("the `{}` slot can only be filled once", slot.name()),
+                ));
+            }
+            slot_names.push(slot.name().clone());
+            props.add_slot(*slot)?;
+        }
+
         if !children.is_empty() {
-            if let Some(children_prop) = open.props.children() {
+            if let Some(children_prop) = props.children() {
                 return Err(syn::Error::new_spanned(
                     &children_prop.label,
                     "cannot specify the `children` prop when the component already has children",
@@ -115,7 +137,7 @@ impl Parse for HtmlComponent {
 
         Ok(HtmlComponent {
             ty: open.ty,
-            props: open.props,
+            props,
             children,
             close,
         })
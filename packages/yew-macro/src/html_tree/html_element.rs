@@ -6,8 +6,8 @@ use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
 use syn::{Expr, Ident, Lit, LitStr, Token};
 
-use super::{HtmlChildrenTree, HtmlDashedName, TagTokens};
-use crate::props::{ElementProps, Prop, PropDirective};
+use super::{HtmlChildrenTree, HtmlDashedName, HtmlTree, TagTokens};
+use crate::props::{is_aria_attribute, ElementProps, Prop, PropDirective, LISTENER_OPTIONS};
 use crate::stringify::{Stringify, Value};
 use crate::{is_ide_completion, non_capitalized_ascii, Peek, PeekValue};
 
@@ -150,6 +150,48 @@ impl Parse for HtmlElement {
     }
 }
 
+impl HtmlElement {
+    /// Whether the element, its attributes and its children are literals only, so that it never
+    /// changes between renders and doesn't need to be diffed.
+    pub(crate) fn is_static(&self) -> bool {
+        let ElementProps {
+            classes,
+            attributes,
+            booleans,
+            value,
+            checked,
+            listeners,
+            special,
+            defaultvalue,
+            spread,
+        } = &self.props;
+        let TagName::Lit(name) = &self.name else {
+            return false;
+        };
+        let name = name.to_ascii_lowercase_string();
+        name != "input"
+            && name != "textarea"
+            && listeners.is_empty()
+            && spread.is_none()
+            && value.is_none()
+            && checked.is_none()
+            && defaultvalue.is_none()
+            && special.node_ref.is_none()
+            && special.key.is_none()
+            && attributes
+                .iter()
+                .all(|prop| prop.directive.is_none() && prop.value.try_into_lit().is_some())
+            && booleans.iter().all(|prop| {
+                prop.directive.is_none()
+                    && matches!(&prop.value, Expr::Lit(e) if matches!(e.lit, Lit::Bool(_)))
+            })
+            && classes
+                .as_ref()
+                .map_or(true, |prop| prop.value.try_into_lit().is_some())
+            && self.children.0.iter().all(HtmlTree::is_static)
+    }
+}
+
 impl ToTokens for HtmlElement {
     #[allow(clippy::cognitive_complexity)]
     fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -168,6 +210,7 @@ impl ToTokens for HtmlElement {
             listeners,
             special,
             defaultvalue,
+            spread,
         } = &props;
 
         // attributes with special treatment
@@ -206,11 +249,17 @@ impl ToTokens for HtmlElement {
                      directive,
                      ..
                  }| {
-                    (
-                        label.to_lit_str(),
-                        value.optimize_literals_tagged(),
-                        *directive,
-                    )
+                    let value = match value.optimize_literals_tagged() {
+                        Value::Dynamic(_)
+                            if directive.is_none() && is_aria_attribute(&label.to_string()) =>
+                        {
+                            Value::Dynamic(quote_spanned! {value.span()=>
+                                ::yew::virtual_dom::aria::IntoAriaValue::into_aria_value(#value)
+                            })
+                        }
+                        value => value,
+                    };
+                    (label.to_lit_str(), value, *directive)
                 },
             );
             let boolean_attrs = booleans.iter().filter_map(
@@ -328,6 +377,12 @@ impl ToTokens for HtmlElement {
                                 ))
                             )
                         }
+                        // literals are skipped when diffing
+                        None if matches!(v, Value::Static(_)) => quote! {
+                            ::std::option::Option::Some(
+                                ::yew::virtual_dom::AttributeOrProperty::Static(#v)
+                            )
+                        },
                         None => {
                             let value = wrap_attr_value(v);
                             quote! {
@@ -349,12 +404,35 @@ impl ToTokens for HtmlElement {
         let listeners = if listeners.is_empty() {
             quote! { ::yew::virtual_dom::listeners::Listeners::None }
         } else {
-            let listeners_it = listeners.iter().map(|Prop { label, value, .. }| {
-                let name = &label.name;
-                quote! {
-                    ::yew::html::#name::Wrapper::__macro_new(#value)
-                }
-            });
+            let listeners_it = listeners.iter().map(
+                |Prop {
+                     label,
+                     options,
+                     value,
+                     ..
+                 }| {
+                    let name = &label.name;
+                    let listener = quote! {
+                        ::yew::html::#name::Wrapper::__macro_new(#value)
+                    };
+                    if options.is_empty() {
+                        return listener;
+                    }
+                    let [passive, capture, once, direct] =
+                        LISTENER_OPTIONS.map(|option| options.iter().any(|m| m == option));
+                    quote! {
+                        ::yew::virtual_dom::listeners::__with_options(
+                            #listener,
+                            ::yew::virtual_dom::listeners::ListenerOptions {
+                                passive: #passive,
+                                capture: #capture,
+                                once: #once,
+                                direct: #direct,
+                            },
+                        )
+                    }
+                },
+            );
 
             quote! {
                 ::yew::virtual_dom::listeners::Listeners::Pending(
@@ -367,6 +445,15 @@ impl ToTokens for HtmlElement {
         // compute `VList.fully_keyed` at compile time.
         let children = children.to_vnode_tokens();
 
+        // spread attributes don't override the ones given explicitly
+        let spread_attrs = |vtag: &Ident| {
+            spread.as_ref().map(|spread| {
+                quote_spanned! {spread.span()=>
+                    ::yew::virtual_dom::Spread::spread_onto(#spread, &mut #vtag);
+                }
+            })
+        };
+
         tokens.extend(match &name {
             TagName::Lit(dashedname) => {
                 let name_span = dashedname.span();
@@ -380,20 +467,18 @@ impl ToTokens for HtmlElement {
                         )
                     )
                 }
-                let node = match &*name {
+                let vtag = match &*name {
                     "input" => {
                         let value = value();
                         let checked = checked();
                         quote! {
-                            ::std::convert::Into::<::yew::virtual_dom::VNode>::into(
-                                ::yew::virtual_dom::VTag::__new_input(
-                                    #value,
-                                    #checked,
-                                    #node_ref,
-                                    #key,
-                                    #attributes,
-                                    #listeners,
-                                ),
+                            ::yew::virtual_dom::VTag::__new_input(
+                                #value,
+                                #checked,
+                                #node_ref,
+                                #key,
+                                #attributes,
+                                #listeners,
                             )
                         }
                     }
@@ -401,33 +486,58 @@ impl ToTokens for HtmlElement {
                         let value = value();
                         let defaultvalue = defaultvalue();
                         quote! {
-                            ::std::convert::Into::<::yew::virtual_dom::VNode>::into(
-                                ::yew::virtual_dom::VTag::__new_textarea(
-                                    #value,
-                                    #defaultvalue,
-                                    #node_ref,
-                                    #key,
-                                    #attributes,
-                                    #listeners,
-                                ),
+                            ::yew::virtual_dom::VTag::__new_textarea(
+                                #value,
+                                #defaultvalue,
+                                #node_ref,
+                                #key,
+                                #attributes,
+                                #listeners,
+                            )
+                        }
+                    }
+                    _ if self.is_static() => {
+                        let id = Ident::new("__YEW_STATIC_NODE", Span::mixed_site());
+                        quote! {
+                            ::yew::virtual_dom::VTag::__new_other(
+                                ::yew::virtual_dom::AttrValue::Static(#name),
+                                #node_ref,
+                                #key,
+                                #attributes,
+                                #listeners,
+                                #children,
                             )
+                            .__mark_static({
+                                static #id: ::yew::virtual_dom::StaticNodeId =
+                                    ::yew::virtual_dom::StaticNodeId::new();
+                                &#id
+                            })
                         }
                     }
                     _ => {
                         quote! {
-                            ::std::convert::Into::<::yew::virtual_dom::VNode>::into(
-                                ::yew::virtual_dom::VTag::__new_other(
-                                    ::yew::virtual_dom::AttrValue::Static(#name),
-                                    #node_ref,
-                                    #key,
-                                    #attributes,
-                                    #listeners,
-                                    #children,
-                                ),
+                            ::yew::virtual_dom::VTag::__new_other(
+                                ::yew::virtual_dom::AttrValue::Static(#name),
+                                #node_ref,
+                                #key,
+                                #attributes,
+                                #listeners,
+                                #children,
                             )
                         }
                     }
                 };
+                let vtag_ident = Ident::new("__yew_vtag", Span::mixed_site());
+                let node = match spread_attrs(&vtag_ident) {
+                    None => quote! {
+                        ::std::convert::Into::<::yew::virtual_dom::VNode>::into(#vtag)
+                    },
+                    Some(spread_attrs) => quote! {{
+                        let mut #vtag_ident = #vtag;
+                        #spread_attrs
+                        ::std::convert::Into::<::yew::virtual_dom::VNode>::into(#vtag_ident)
+                    }},
+                };
                 // the return value can be inlined without the braces when this is stable:
                 // https://github.com/rust-lang/rust/issues/15701
                 quote_spanned!{
@@ -445,6 +555,7 @@ impl ToTokens for HtmlElement {
                 let vtag_name = Ident::new("__yew_vtag_name", expr.span());
 
                 let void_children = Ident::new("__yew_void_children", Span::mixed_site());
+                let is_void = Ident::new("__yew_is_void", Span::mixed_site());
 
                 // handle special attribute value
                 let handle_value_attr = props.value.as_ref().map(|prop| {
@@ -466,24 +577,49 @@ impl ToTokens for HtmlElement {
                 #[cfg(not(nightly_yew))]
                 let invalid_void_tag_msg_start = "";
 
+                // `checked` and `defaultvalue` are only applied by `<input>` and `<textarea>`
+                // respectively, so they are ignored on any other tag.
+                let misplaced = |prop: &Option<Prop>, name: &str, applies_to: &str| {
+                    let msg = format!(
+                        "{invalid_void_tag_msg_start}a dynamic tag set `{name}` on a `<{{}}>` tag, \
+                         but it only applies to `<{applies_to}>` tags."
+                    );
+                    let label = prop.as_ref().map(|prop| prop.label.span());
+                    move |tag: TokenStream| {
+                        label.map(|span| {
+                            quote_spanned! {span=>
+                                ::std::debug_assert!(false, #msg, #tag);
+                            }
+                        })
+                    }
+                };
+                let misplaced_checked = misplaced(&props.checked, "checked", "input");
+                let misplaced_defaultvalue =
+                    misplaced(&props.defaultvalue, "defaultvalue", "textarea");
+                let input_misplaced = misplaced_defaultvalue(quote! { "input" });
+                let textarea_misplaced = misplaced_checked(quote! { "textarea" });
+                let other_misplaced = [
+                    misplaced_checked(vtag_name.to_token_stream()),
+                    misplaced_defaultvalue(vtag_name.to_token_stream()),
+                ];
+
+                let spread_attrs = spread_attrs(&vtag);
+
                 let value = value();
                 let checked = checked();
                 let defaultvalue = defaultvalue();
                 // this way we get a nice error message (with the correct span) when the expression
                 // doesn't return a valid value
                 quote_spanned! {expr.span()=> {
-                    let mut #vtag_name = ::std::convert::Into::<
-                        ::yew::virtual_dom::AttrValue
+                    let #vtag_name = ::std::convert::Into::<
+                        ::yew::virtual_dom::TagName
                     >::into(#expr);
-                    ::std::debug_assert!(
-                        #vtag_name.is_ascii(),
-                        "a dynamic tag returned a tag name containing non ASCII characters: `{}`",
-                        #vtag_name,
-                    );
+                    let #is_void = ::yew::virtual_dom::TagName::is_void(&#vtag_name);
 
                     #[allow(clippy::redundant_clone, unused_braces, clippy::let_and_return)]
-                    let mut #vtag = match () {
-                        _ if "input".eq_ignore_ascii_case(::std::convert::AsRef::<::std::primitive::str>::as_ref(&#vtag_name)) => {
+                    let mut #vtag = match #vtag_name {
+                        ::yew::virtual_dom::TagName::Input => {
+                            #input_misplaced
                             ::yew::virtual_dom::VTag::__new_input(
                                 #value,
                                 #checked,
@@ -493,7 +629,8 @@ impl ToTokens for HtmlElement {
                                 #listeners,
                             )
                         }
-                        _ if "textarea".eq_ignore_ascii_case(::std::convert::AsRef::<::std::primitive::str>::as_ref(&#vtag_name)) => {
+                        ::yew::virtual_dom::TagName::Textarea => {
+                            #textarea_misplaced
                             ::yew::virtual_dom::VTag::__new_textarea(
                                 #value,
                                 #defaultvalue,
@@ -503,7 +640,14 @@ impl ToTokens for HtmlElement {
                                 #listeners,
                             )
                         }
-                        _ => {
+                        ::yew::virtual_dom::TagName::Other(#vtag_name) => {
+                            ::std::debug_assert!(
+                                #vtag_name.is_ascii(),
+                                "a dynamic tag returned a tag name containing non ASCII characters: `{}`",
+                                #vtag_name,
+                            );
+                            #(#other_misplaced)*
+
                             let mut __yew_vtag = ::yew::virtual_dom::VTag::__new_other(
                                 #vtag_name,
                                 #node_ref,
@@ -518,21 +662,19 @@ impl ToTokens for HtmlElement {
                             __yew_vtag
                         }
                     };
+                    #spread_attrs
 
                     // These are the runtime-checks exclusive to dynamic tags.
                     // For literal tags this is already done at compile-time.
                     //
                     // check void element
-                    if ::yew::virtual_dom::VTag::children(&#vtag).is_some() &&
+                    if #is_void && ::yew::virtual_dom::VTag::children(&#vtag).is_some() &&
                        !::std::matches!(
                         ::yew::virtual_dom::VTag::children(&#vtag),
                         ::std::option::Option::Some(::yew::virtual_dom::VNode::VList(ref #void_children)) if ::std::vec::Vec::is_empty(#void_children)
                     ) {
                         ::std::debug_assert!(
-                            !::std::matches!(#vtag.tag().to_ascii_lowercase().as_str(),
-                                "area" | "base" | "br" | "col" | "embed" | "hr" | "img" | "input"
-                                    | "link" | "meta" | "param" | "source" | "track" | "wbr" | "textarea"
-                            ),
+                            false,
                             concat!(#invalid_void_tag_msg_start, "a dynamic tag tried to create a `<{0}>` tag with children. `<{0}>` is a void element which can't have any children."),
                             #vtag.tag(),
                         );
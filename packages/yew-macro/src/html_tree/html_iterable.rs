@@ -3,12 +3,21 @@ use quote::{quote_spanned, ToTokens};
 use syn::buffer::Cursor;
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use syn::{Expr, Token};
+use syn::{Expr, Pat, Token};
 
-use super::ToNodeIterator;
+use super::{HtmlTree, ToNodeIterator};
 use crate::PeekValue;
 
-pub struct HtmlIterable(Expr);
+pub struct HtmlIterable {
+    expr: Expr,
+    body: Option<HtmlIterableBody>,
+}
+
+/// The `=> |pattern| <html>` part of `for items => |item| <li key={item.id}>...</li>`.
+struct HtmlIterableBody {
+    pat: Pat,
+    tree: HtmlTree,
+}
 
 impl PeekValue<()> for HtmlIterable {
     fn peek(cursor: Cursor) -> Option<()> {
@@ -21,28 +30,77 @@ impl Parse for HtmlIterable {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let for_token = input.parse::<Token![for]>()?;
 
-        match input.parse() {
-            Ok(expr) => Ok(HtmlIterable(expr)),
+        let expr = match input.parse() {
+            Ok(expr) => expr,
             Err(err) => {
-                if err.to_string().starts_with("unexpected end of input") {
+                return if err.to_string().starts_with("unexpected end of input") {
                     Err(syn::Error::new_spanned(
                         for_token,
                         "expected an expression after the keyword `for`",
                     ))
                 } else {
                     Err(err)
-                }
+                };
             }
+        };
+
+        let body = if input.peek(Token![=>]) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(HtmlIterable { expr, body })
+    }
+}
+
+impl Parse for HtmlIterableBody {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let arrow = input.parse::<Token![=>]>()?;
+        if !input.peek(Token![|]) {
+            return Err(syn::Error::new_spanned(
+                arrow,
+                "expected a closure pattern after `=>` (hint: `for items => |item| <li>{ item \
+                 }</li>`)",
+            ));
+        }
+        input.parse::<Token![|]>()?;
+        let pat = Pat::parse_single(input)?;
+        input.parse::<Token![|]>()?;
+
+        if input.is_empty() {
+            return Err(input.error("expected the html to render for each item"));
+        }
+        let tree = input.parse()?;
+
+        Ok(HtmlIterableBody { pat, tree })
+    }
+}
+
+impl HtmlIterable {
+    /// The iterator over the items of the expression, mapped by the body if there is one.
+    fn iterator_stream(&self) -> TokenStream {
+        let Self { expr, body } = self;
+        match body {
+            Some(HtmlIterableBody { pat, tree }) => quote_spanned! {expr.span()=>
+                ::std::iter::Iterator::map(
+                    ::std::iter::IntoIterator::into_iter(#expr),
+                    |#pat| #tree,
+                )
+            },
+            None => quote_spanned! {expr.span()=>
+                ::std::iter::IntoIterator::into_iter(#expr)
+            },
         }
     }
 }
 
 impl ToTokens for HtmlIterable {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let expr = &self.0;
-        let new_tokens = quote_spanned! {expr.span()=>
+        let iterator = self.iterator_stream();
+        let new_tokens = quote_spanned! {self.expr.span()=>
             #[allow(unused_braces)]
-            ::std::iter::Iterator::collect::<::yew::virtual_dom::VNode>(::std::iter::IntoIterator::into_iter(#expr))
+            ::std::iter::Iterator::collect::<::yew::virtual_dom::VNode>(#iterator)
         };
 
         tokens.extend(new_tokens);
@@ -51,11 +109,11 @@ impl ToTokens for HtmlIterable {
 
 impl ToNodeIterator for HtmlIterable {
     fn to_node_iterator_stream(&self) -> Option<TokenStream> {
-        let Self(expr) = self;
+        let iterator = self.iterator_stream();
         // #expr can return anything that implements IntoIterator<Item=Into<T>>
         // We use a util method to avoid clippy warnings and reduce generated code size
-        Some(quote_spanned! {expr.span()=>
-            ::yew::utils::into_node_iter(#expr)
+        Some(quote_spanned! {self.expr.span()=>
+            ::yew::utils::into_node_iter(#iterator)
         })
     }
 }
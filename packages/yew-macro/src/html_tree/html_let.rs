@@ -0,0 +1,50 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::buffer::Cursor;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{Local, Stmt};
+
+use crate::PeekValue;
+
+/// A `let` binding among the children of an element, component or fragment.
+///
+/// The binding is in scope for the following children.
+pub struct HtmlLet(Local);
+
+impl PeekValue<()> for HtmlLet {
+    fn peek(cursor: Cursor) -> Option<()> {
+        let (ident, _) = cursor.ident()?;
+        (ident == "let").then_some(())
+    }
+}
+
+impl Parse for HtmlLet {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        match input.parse()? {
+            Stmt::Local(local) => Ok(Self(local)),
+            stmt => Err(syn::Error::new(
+                stmt.span(),
+                "expected a `let` binding ending with `;`",
+            )),
+        }
+    }
+}
+
+impl HtmlLet {
+    /// Returns an error for bindings that are not followed by any html to use them in.
+    pub fn to_misplaced_error(&self) -> TokenStream {
+        syn::Error::new_spanned(
+            self.0.let_token,
+            "a `let` binding must be followed by the html that uses it (hint: you can wrap the \
+             binding and the html in a fragment `<></>`)",
+        )
+        .to_compile_error()
+    }
+}
+
+impl ToTokens for HtmlLet {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
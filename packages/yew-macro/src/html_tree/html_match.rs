@@ -0,0 +1,142 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::buffer::Cursor;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{braced, token, Expr, Pat, Token};
+
+use super::{HtmlRootBraced, HtmlTree};
+use crate::PeekValue;
+
+pub struct HtmlMatch {
+    match_token: Token![match],
+    expr: Box<Expr>,
+    brace: token::Brace,
+    arms: Vec<HtmlMatchArm>,
+}
+
+struct HtmlMatchArm {
+    pat: Pat,
+    guard: Option<(Token![if], Box<Expr>)>,
+    fat_arrow: Token![=>],
+    body: HtmlMatchArmBody,
+}
+
+enum HtmlMatchArmBody {
+    Braced(HtmlRootBraced),
+    Tree(HtmlTree),
+}
+
+impl PeekValue<()> for HtmlMatch {
+    fn peek(cursor: Cursor) -> Option<()> {
+        let (ident, _) = cursor.ident()?;
+        (ident == "match").then_some(())
+    }
+}
+
+impl Parse for HtmlMatch {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let match_token = input.parse()?;
+        let expr = Box::new(input.call(Expr::parse_without_eager_brace)?);
+        if input.is_empty() {
+            return Err(syn::Error::new(
+                expr.span(),
+                "this `match` expression has a scrutinee, but no arms",
+            ));
+        }
+
+        let content;
+        let brace = braced!(content in input);
+        let mut arms = Vec::new();
+        while !content.is_empty() {
+            let arm: HtmlMatchArm = content.parse()?;
+            let braced = matches!(arm.body, HtmlMatchArmBody::Braced(_));
+            arms.push(arm);
+
+            if content.is_empty() {
+                break;
+            }
+            if braced {
+                content.parse::<Option<Token![,]>>()?;
+            } else {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(HtmlMatch {
+            match_token,
+            expr,
+            brace,
+            arms,
+        })
+    }
+}
+
+impl Parse for HtmlMatchArm {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pat = Pat::parse_multi_with_leading_vert(input)?;
+        let guard = if input.peek(Token![if]) {
+            Some((input.parse()?, Box::new(input.parse()?)))
+        } else {
+            None
+        };
+        let fat_arrow = input.parse()?;
+
+        if input.is_empty() || input.peek(Token![,]) {
+            return Err(syn::Error::new_spanned(
+                fat_arrow,
+                "expected the html to render for this arm after `=>`",
+            ));
+        }
+        let body = if HtmlRootBraced::peek(input.cursor()).is_some() {
+            HtmlMatchArmBody::Braced(input.parse()?)
+        } else {
+            HtmlMatchArmBody::Tree(input.parse()?)
+        };
+
+        Ok(HtmlMatchArm {
+            pat,
+            guard,
+            fat_arrow,
+            body,
+        })
+    }
+}
+
+impl ToTokens for HtmlMatchArm {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let HtmlMatchArm {
+            pat,
+            guard,
+            fat_arrow,
+            body,
+        } = self;
+        let guard = guard
+            .as_ref()
+            .map(|(if_token, cond)| quote! { #if_token #cond });
+        let body = match body {
+            HtmlMatchArmBody::Braced(body) => body.to_token_stream(),
+            HtmlMatchArmBody::Tree(tree) => quote_spanned! {tree.span()=>
+                ::std::convert::Into::<::yew::virtual_dom::VNode>::into(#tree)
+            },
+        };
+
+        tokens.extend(quote! { #pat #guard #fat_arrow #body, });
+    }
+}
+
+impl ToTokens for HtmlMatch {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let HtmlMatch {
+            match_token,
+            expr,
+            brace,
+            arms,
+        } = self;
+        let arms = quote_spanned! {brace.span.join()=> { #(#arms)* } };
+
+        tokens.extend(quote_spanned! {match_token.span()=>
+            #match_token #expr #arms
+        });
+    }
+}
@@ -0,0 +1,181 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::buffer::Cursor;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, Token};
+
+use super::{HtmlChildrenTree, HtmlDashedName, TagTokens};
+use crate::props::Prop;
+use crate::PeekValue;
+
+/// A named slot of a component filled among its children, `<:name>...</:name>`.
+pub struct HtmlSlot {
+    open: HtmlSlotOpen,
+    children: HtmlChildrenTree,
+    close: HtmlSlotClose,
+}
+
+impl PeekValue<()> for HtmlSlot {
+    fn peek(cursor: Cursor) -> Option<()> {
+        let (punct, cursor) = cursor.punct()?;
+        if punct.as_char() != '<' {
+            return None;
+        }
+        // skip the `/` of a closing tag, which is reported by `parse`
+        let cursor = match cursor.punct() {
+            Some((punct, cursor)) if punct.as_char() == '/' => cursor,
+            _ => cursor,
+        };
+        let (punct, cursor) = cursor.punct()?;
+        // `<::path::Component>` is a component
+        (punct.as_char() == ':' && cursor.punct().is_none()).then_some(())
+    }
+}
+
+impl Parse for HtmlSlot {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![<]) && input.peek2(Token![/]) {
+            return match input.parse::<HtmlSlotClose>() {
+                Ok(close) => Err(syn::Error::new_spanned(
+                    close.to_spanned(),
+                    "this closing slot has no corresponding opening slot",
+                )),
+                Err(err) => Err(err),
+            };
+        }
+
+        let open = input.parse::<HtmlSlotOpen>()?;
+        if open.tag.div.is_some() {
+            return Err(syn::Error::new_spanned(
+                open.to_spanned(),
+                "a slot must contain the html that fills it (hint: leave the slot out to keep it \
+                 empty)",
+            ));
+        }
+
+        let mut children = HtmlChildrenTree::new();
+        loop {
+            if input.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    open.to_spanned(),
+                    "this opening slot has no corresponding closing slot",
+                ));
+            }
+            if input.peek(Token![<])
+                && input.peek2(Token![/])
+                && Self::peek(input.cursor()).is_some()
+            {
+                break;
+            }
+            children.parse_child(input)?;
+        }
+
+        let close = input.parse::<HtmlSlotClose>()?;
+        if close.name != open.name {
+            return Err(syn::Error::new_spanned(
+                close.to_spanned(),
+                format!(
+                    "mismatched closing slot: expected `</:{}>`, found `</:{}>`",
+                    open.name, close.name
+                ),
+            ));
+        }
+        if children.is_empty() {
+            return Err(syn::Error::new_spanned(
+                open.to_spanned(),
+                "a slot must contain the html that fills it (hint: leave the slot out to keep it \
+                 empty)",
+            ));
+        }
+
+        Ok(Self {
+            open,
+            children,
+            close,
+        })
+    }
+}
+
+impl HtmlSlot {
+    pub fn name(&self) -> &Ident {
+        &self.open.name
+    }
+
+    /// Turns the slot into the prop it fills.
+    pub fn into_prop(self) -> Prop {
+        let Self { open, children, .. } = self;
+        // the children of slots are passed like the children of components
+        let value = children.to_children_renderer_tokens().unwrap_or_default();
+        Prop {
+            directive: None,
+            label: HtmlDashedName {
+                name: open.name,
+                extended: Vec::new(),
+            },
+            options: Vec::new(),
+            value: Expr::Verbatim(value),
+        }
+    }
+
+    /// Returns an error for slots that are not among the children of a component.
+    pub fn to_misplaced_error(&self) -> TokenStream {
+        syn::Error::new_spanned(
+            self.to_spanned(),
+            "slots can only be filled among the children of a component",
+        )
+        .to_compile_error()
+    }
+
+    fn to_spanned(&self) -> impl ToTokens {
+        let open = self.open.to_spanned();
+        let close = self.close.to_spanned();
+        quote! { #open #close }
+    }
+}
+
+struct HtmlSlotOpen {
+    tag: TagTokens,
+    name: Ident,
+}
+impl HtmlSlotOpen {
+    fn to_spanned(&self) -> impl ToTokens {
+        self.tag.to_spanned()
+    }
+}
+
+impl Parse for HtmlSlotOpen {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        TagTokens::parse_start_content(input, |input, tag| {
+            input.parse::<Token![:]>()?;
+            let name = input.call(Ident::parse_any)?;
+            if !input.is_empty() {
+                return Err(input.error("slots don't accept props"));
+            }
+            Ok(Self { tag, name })
+        })
+    }
+}
+
+struct HtmlSlotClose {
+    tag: TagTokens,
+    name: Ident,
+}
+impl HtmlSlotClose {
+    fn to_spanned(&self) -> impl ToTokens {
+        self.tag.to_spanned()
+    }
+}
+
+impl Parse for HtmlSlotClose {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        TagTokens::parse_end_content(input, |input, tag| {
+            input.parse::<Token![:]>()?;
+            let name = input.call(Ident::parse_any)?;
+            if !input.is_empty() {
+                return Err(input.error("unexpected content in slot close"));
+            }
+            Ok(Self { tag, name })
+        })
+    }
+}
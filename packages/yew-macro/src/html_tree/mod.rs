@@ -14,8 +14,11 @@ mod html_dashed_name;
 mod html_element;
 mod html_if;
 mod html_iterable;
+mod html_let;
 mod html_list;
+mod html_match;
 mod html_node;
+mod html_slot;
 mod lint;
 mod tag;
 
@@ -25,8 +28,11 @@ pub use html_dashed_name::HtmlDashedName;
 use html_element::HtmlElement;
 use html_if::HtmlIf;
 use html_iterable::HtmlIterable;
+use html_let::HtmlLet;
 use html_list::HtmlList;
+use html_match::HtmlMatch;
 use html_node::HtmlNode;
+pub use html_slot::HtmlSlot;
 use tag::TagTokens;
 
 use self::html_block::BlockContent;
@@ -37,6 +43,9 @@ pub enum HtmlType {
     List,
     Element,
     If,
+    Match,
+    Let,
+    Slot,
     Empty,
 }
 
@@ -46,6 +55,9 @@ pub enum HtmlTree {
     List(Box<HtmlList>),
     Element(Box<HtmlElement>),
     If(Box<HtmlIf>),
+    Match(Box<HtmlMatch>),
+    Let(Box<HtmlLet>),
+    Slot(Box<HtmlSlot>),
     Empty,
 }
 
@@ -60,12 +72,28 @@ impl Parse for HtmlTree {
             HtmlType::Block => HtmlTree::Block(Box::new(input.parse()?)),
             HtmlType::List => HtmlTree::List(Box::new(input.parse()?)),
             HtmlType::If => HtmlTree::If(Box::new(input.parse()?)),
+            HtmlType::Match => HtmlTree::Match(Box::new(input.parse()?)),
+            HtmlType::Let => HtmlTree::Let(Box::new(input.parse()?)),
+            HtmlType::Slot => HtmlTree::Slot(Box::new(input.parse()?)),
         };
         Ok(html_tree)
     }
 }
 
 impl HtmlTree {
+    /// Whether the tree is made of literals only, see [`HtmlElement::is_static`].
+    fn is_static(&self) -> bool {
+        match self {
+            HtmlTree::Element(element) => element.is_static(),
+            HtmlTree::Block(block) => matches!(
+                &block.content,
+                BlockContent::Node(node) if matches!(**node, HtmlNode::Literal(_))
+            ),
+            HtmlTree::Empty => true,
+            _ => false,
+        }
+    }
+
     /// Determine the [`HtmlType`] before actually parsing it.
     /// Even though this method accepts a [`ParseStream`], it is forked and the original stream is
     /// not modified. Once a certain `HtmlType` can be deduced for certain, the function eagerly
@@ -83,6 +111,10 @@ impl HtmlTree {
             Some(HtmlType::Block)
         } else if HtmlIf::peek(input.cursor()).is_some() {
             Some(HtmlType::If)
+        } else if HtmlMatch::peek(input.cursor()).is_some() {
+            Some(HtmlType::Match)
+        } else if HtmlLet::peek(input.cursor()).is_some() {
+            Some(HtmlType::Let)
         } else if input.peek(Token![<]) {
             let _lt: Token![<] = input.parse().ok()?;
 
@@ -95,6 +127,8 @@ impl HtmlTree {
                 Some(HtmlType::Element) // dynamic element
             } else if input.peek(Token![::]) {
                 Some(HtmlType::Component)
+            } else if input.peek(Token![:]) {
+                Some(HtmlType::Slot)
             } else if input.peek(Ident::peek_any) {
                 let ident = Ident::parse_any(&input).ok()?;
                 let ident_str = ident.to_string();
@@ -130,6 +164,13 @@ impl ToTokens for HtmlTree {
             HtmlTree::List(list) => list.to_tokens(tokens),
             HtmlTree::Block(block) => block.to_tokens(tokens),
             HtmlTree::If(block) => block.to_tokens(tokens),
+            HtmlTree::Match(block) => block.to_tokens(tokens),
+            // bindings are only valid among children, where they are handled by
+            // `HtmlChildrenTree::to_build_vec_token_stream`
+            HtmlTree::Let(binding) => tokens.extend(binding.to_misplaced_error()),
+            // slots are only valid among the children of components, where they are taken out by
+            // `HtmlComponent`
+            HtmlTree::Slot(slot) => tokens.extend(slot.to_misplaced_error()),
         }
     }
 }
@@ -229,12 +270,11 @@ impl HtmlChildrenTree {
     }
 
     // Check if each child represents a single node.
-    // This is the case when no expressions are used.
+    // This is the case when no expressions or `let` bindings are used.
     fn only_single_node_children(&self) -> bool {
-        self.0
-            .iter()
-            .map(ToNodeIterator::to_node_iterator_stream)
-            .all(|s| s.is_none())
+        self.0.iter().all(|child| {
+            !matches!(child, HtmlTree::Let(_)) && child.to_node_iterator_stream().is_none()
+        })
     }
 
     pub fn to_build_vec_token_stream(&self) -> TokenStream {
@@ -253,7 +293,10 @@ impl HtmlChildrenTree {
 
         let vec_ident = Ident::new("__yew_v", Span::mixed_site());
         let add_children_streams = children.iter().map(|child| {
-            if let Some(node_iterator_stream) = child.to_node_iterator_stream() {
+            if let HtmlTree::Let(binding) = child {
+                // the binding is in scope for the following children
+                quote! { #binding }
+            } else if let Some(node_iterator_stream) = child.to_node_iterator_stream() {
                 quote! {
                     ::std::iter::Extend::extend(&mut #vec_ident, #node_iterator_stream);
                 }
@@ -375,9 +418,9 @@ impl ToTokens for HtmlRootBraced {
 
         tokens.extend(quote_spanned! {brace.span.span()=>
             {
-                ::yew::virtual_dom::VNode::VList(::std::rc::Rc::new(
+                ::yew::virtual_dom::VNode::from(
                     ::yew::virtual_dom::VList::with_children(#children, ::std::option::Option::None)
-                ))
+                )
             }
         });
     }
@@ -0,0 +1,92 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, Expr, Ident, LitStr, Token, Type, Visibility};
+
+/// How the code of a lazy component is loaded.
+#[derive(Debug)]
+enum Loader {
+    /// The URL of a JavaScript module to import.
+    Url(LitStr),
+    /// A future resolving to `Result<(), LazyError>`.
+    Future(Expr),
+}
+
+#[derive(Debug)]
+pub struct LazyComponent {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    component: Type,
+    loader: Loader,
+}
+
+impl Parse for LazyComponent {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let component = input.parse()?;
+
+        input.parse::<Token![,]>().map_err(|e| {
+            syn::Error::new(
+                e.span(),
+                "expected the URL of the module or a future loading the component",
+            )
+        })?;
+
+        let loader = if input.peek(LitStr) {
+            Loader::Url(input.parse()?)
+        } else {
+            Loader::Future(input.parse()?)
+        };
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            component,
+            loader,
+        })
+    }
+}
+
+impl ToTokens for LazyComponent {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self {
+            attrs,
+            vis,
+            name,
+            component,
+            loader,
+        } = self;
+
+        let loader_name = format_ident!("{}Loader", name);
+        let load = match loader {
+            Loader::Url(url) => quote! { ::yew::lazy::import_module(#url) },
+            Loader::Future(future) => quote! { ::std::boxed::Box::pin(#future) },
+        };
+
+        tokens.extend(quote! {
+            #[doc(hidden)]
+            #[derive(Debug)]
+            #vis struct #loader_name;
+
+            impl ::yew::lazy::LazyComponent for #loader_name {
+                type Component = #component;
+
+                fn load() -> ::yew::lazy::LoadFuture {
+                    #load
+                }
+            }
+
+            #(#attrs)*
+            #vis type #name = ::yew::lazy::Lazy<#loader_name>;
+        });
+    }
+}
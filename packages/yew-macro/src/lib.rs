@@ -49,21 +49,27 @@
 //! Please refer to [https://github.com/yewstack/yew](https://github.com/yewstack/yew) for how to set this up.
 
 mod classes;
+mod css;
 mod derive_props;
 mod function_component;
 mod hook;
 mod html_tree;
+mod lazy_component;
 mod props;
+mod server_fn;
 mod stringify;
 mod use_prepared_state;
 mod use_transitive_state;
 
+use css::Css;
 use derive_props::DerivePropsInput;
 use function_component::{function_component_impl, FunctionComponent, FunctionComponentName};
 use hook::{hook_impl, HookFn};
 use html_tree::{HtmlRoot, HtmlRootVNode};
+use lazy_component::LazyComponent;
 use proc_macro::TokenStream;
 use quote::ToTokens;
+use server_fn::{ServerFn, ServerFnPath};
 use syn::buffer::Cursor;
 use syn::parse_macro_input;
 use use_prepared_state::PreparedState;
@@ -105,7 +111,10 @@ fn is_ide_completion() -> bool {
     }
 }
 
-#[proc_macro_derive(Properties, attributes(prop_or, prop_or_else, prop_or_default))]
+#[proc_macro_derive(
+    Properties,
+    attributes(prop_or, prop_or_else, prop_or_default, prop_or_rest, custom_element)
+)]
 pub fn derive_props(input: TokenStream) -> TokenStream {
     let mut input = parse_macro_input!(input as DerivePropsInput);
     input.normalise();
@@ -165,6 +174,32 @@ pub fn hook(attr: TokenStream, item: TokenStream) -> TokenStream {
         .into()
 }
 
+#[proc_macro]
+pub fn css(input: TokenStream) -> TokenStream {
+    let css = parse_macro_input!(input as Css);
+    TokenStream::from(css.into_token_stream())
+}
+
+#[proc_macro]
+pub fn lazy_component(input: TokenStream) -> TokenStream {
+    let lazy_component = parse_macro_input!(input as LazyComponent);
+    TokenStream::from(lazy_component.into_token_stream())
+}
+
+#[proc_macro_attribute]
+pub fn server_fn_with_body(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let server_fn = parse_macro_input!(item as ServerFn);
+    let path = parse_macro_input!(attr as ServerFnPath);
+    server_fn.to_token_stream(path, true).into()
+}
+
+#[proc_macro_attribute]
+pub fn server_fn_without_body(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let server_fn = parse_macro_input!(item as ServerFn);
+    let path = parse_macro_input!(attr as ServerFnPath);
+    server_fn.to_token_stream(path, false).into()
+}
+
 #[proc_macro]
 pub fn use_prepared_state_with_closure(input: TokenStream) -> TokenStream {
     let prepared_state = parse_macro_input!(input as PreparedState);
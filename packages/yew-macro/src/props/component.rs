@@ -7,7 +7,8 @@ use syn::spanned::Spanned;
 use syn::token::DotDot;
 use syn::Expr;
 
-use super::{Prop, Props, SpecialProps, CHILDREN_LABEL};
+use super::{Prop, Props, SpecialProps, CHILDREN_LABEL, LISTENER_SET, REST_ATTRIBUTES};
+use crate::html_tree::{HtmlDashedName, HtmlSlot};
 
 struct BaseExpr {
     pub dot_dot: DotDot,
@@ -49,6 +50,25 @@ impl ComponentProps {
         self.props.get_by_label(CHILDREN_LABEL)
     }
 
+    /// Fills the prop named like `slot` with its children.
+    pub fn add_slot(&mut self, slot: HtmlSlot) -> syn::Result<()> {
+        let name = slot.name().to_string();
+        if name == CHILDREN_LABEL {
+            return Err(syn::Error::new_spanned(
+                slot.name(),
+                "the `children` prop is filled by the children outside of named slots",
+            ));
+        }
+        if let Some(prop) = self.props.get_by_label(&name) {
+            return Err(syn::Error::new_spanned(
+                &prop.label,
+                format!("`{name}` is given both as a prop and as a slot"),
+            ));
+        }
+        self.props.push(slot.into_prop());
+        Ok(())
+    }
+
     fn prop_validation_tokens(&self, props_ty: impl ToTokens, has_children: bool) -> TokenStream {
         let props_ident = Ident::new("__yew_props", props_ty.span());
         let check_children = if has_children {
@@ -62,6 +82,8 @@ impl ComponentProps {
         let check_props: TokenStream = self
             .props
             .iter()
+            // the rest of the props don't have a field of their own
+            .filter(|Prop { label, .. }| !is_rest_label(label))
             .map(|Prop { label, .. }| {
                 quote_spanned! {Span::call_site().located_at(label.span())=>
                     let _ = &#props_ident.#label;
@@ -100,8 +122,16 @@ impl ComponentProps {
                     let #token_ident = ::yew::html::AssertAllProps;
                 };
                 let set_props = self.props.iter().map(|Prop { label, value, .. }| {
-                    quote_spanned! {value.span()=>
-                        let #token_ident = #builder_ident.#label(#token_ident, #value);
+                    if is_dashed_rest_label(label) {
+                        let key = label.to_lit_str();
+                        quote_spanned! {label.span()=>
+                            let #token_ident =
+                                #builder_ident.__yew_rest_attr(#token_ident, #key, #value);
+                        }
+                    } else {
+                        quote_spanned! {value.span()=>
+                            let #token_ident = #builder_ident.#label(#token_ident, #value);
+                        }
                     }
                 });
                 let set_children = children_renderer.map(|children| {
@@ -165,6 +195,16 @@ impl Parse for ComponentProps {
             Some(input.parse::<BaseExpr>()?)
         };
 
+        if let Some(prop) = base_expr
+            .as_ref()
+            .and_then(|_| props.iter().find(|prop| is_dashed_rest_label(&prop.label)))
+        {
+            return Err(syn::Error::new_spanned(
+                &prop.label,
+                "`data-*` and `aria-*` attributes can't be combined with a base props expression",
+            ));
+        }
+
         if input.is_empty() {
             let base_expr = base_expr.map(|base| base.expr);
             Ok(Self { props, base_expr })
@@ -190,8 +230,9 @@ impl TryFrom<Props> for ComponentProps {
 
 fn validate(props: Props) -> Result<Props, syn::Error> {
     props.check_no_duplicates()?;
+    props.check_no_options()?;
     props.check_all(|prop| {
-        if !prop.label.extended.is_empty() {
+        if !prop.label.extended.is_empty() && !is_dashed_rest_label(&prop.label) {
             Err(syn::Error::new_spanned(
                 &prop.label,
                 "expected a valid Rust identifier",
@@ -203,3 +244,16 @@ fn validate(props: Props) -> Result<Props, syn::Error> {
 
     Ok(props)
 }
+
+/// `data-*` and `aria-*` attributes are collected by the `#[prop_or_rest]` field of the props
+fn is_dashed_rest_label(label: &HtmlDashedName) -> bool {
+    !label.extended.is_empty() && (label.name == "data" || label.name == "aria")
+}
+
+/// Labels that may be collected by the `#[prop_or_rest]` field instead of a field of their own
+fn is_rest_label(label: &HtmlDashedName) -> bool {
+    let name = label.to_string();
+    is_dashed_rest_label(label)
+        || REST_ATTRIBUTES.contains(&name.as_str())
+        || LISTENER_SET.contains(name.as_str())
+}
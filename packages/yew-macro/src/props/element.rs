@@ -2,6 +2,7 @@ use std::collections::HashSet;
 
 use once_cell::sync::Lazy;
 use syn::parse::{Parse, ParseStream};
+use syn::{Expr, ExprLit, Lit, Token};
 
 use super::{Prop, Props, SpecialProps};
 
@@ -14,6 +15,8 @@ pub struct ElementProps {
     pub defaultvalue: Option<Prop>,
     pub checked: Option<Prop>,
     pub special: SpecialProps,
+    /// Extra attributes given with `..attrs`.
+    pub spread: Option<Expr>,
 }
 
 impl Parse for ElementProps {
@@ -25,6 +28,9 @@ impl Parse for ElementProps {
 
         // Multiple listener attributes are allowed, but no others
         props.check_no_duplicates()?;
+        props.check_no_options()?;
+        listeners.check_all(check_listener_options)?;
+        crate::join_errors(props.iter().filter_map(check_aria_prop))?;
 
         let booleans =
             props.drain_filter(|prop| BOOLEAN_SET.contains(prop.label.to_string().as_str()));
@@ -35,6 +41,26 @@ impl Parse for ElementProps {
         let defaultvalue = props.pop("defaultvalue");
         let special = props.special;
 
+        let spread = if input.peek(Token![..]) {
+            let dot_dot = input.parse::<Token![..]>()?;
+            let expr = input.parse().map_err(|expr_error| {
+                let mut error = syn::Error::new_spanned(
+                    dot_dot,
+                    "expected an expression of the attributes to spread after `..`",
+                );
+                error.combine(expr_error);
+                error
+            })?;
+            if !input.is_empty() {
+                return Err(
+                    input.error("the attribute spread must appear last in the list of attributes")
+                );
+            }
+            Some(expr)
+        } else {
+            None
+        };
+
         Ok(Self {
             attributes: props.prop_list.into_vec(),
             classes,
@@ -44,10 +70,72 @@ impl Parse for ElementProps {
             value,
             special,
             defaultvalue,
+            spread,
         })
     }
 }
 
+/// Checks that an `aria-*` attribute is defined by WAI-ARIA and that a literal `role` is made of
+/// known roles.
+fn check_aria_prop(prop: &Prop) -> Option<syn::Error> {
+    let label = prop.label.to_string();
+    if label == "role" {
+        let lit = match &prop.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => lit,
+            _ => return None,
+        };
+        // the value is a list of roles, the first one supported by the user agent is used
+        let value = lit.value();
+        let unknown = value
+            .split_ascii_whitespace()
+            .find(|role| !ARIA_ROLE_SET.contains(role))?;
+        Some(syn::Error::new_spanned(
+            lit,
+            format!("`{unknown}` is not a WAI-ARIA role"),
+        ))
+    } else if label.starts_with("aria-") && !ARIA_SET.contains(label.as_str()) {
+        Some(syn::Error::new_spanned(
+            &prop.label,
+            format!("`{label}` is not a WAI-ARIA attribute"),
+        ))
+    } else {
+        None
+    }
+}
+
+/// Options a listener accepts, see `yew::virtual_dom::ListenerOptions`.
+pub(crate) const LISTENER_OPTIONS: [&str; 4] = ["passive", "capture", "once", "direct"];
+
+/// Checks that the options of a listener are known and given once.
+fn check_listener_options(prop: &Prop) -> syn::Result<()> {
+    for (i, option) in prop.options.iter().enumerate() {
+        let name = option.to_string();
+        if !LISTENER_OPTIONS.contains(&name.as_str()) {
+            return Err(syn::Error::new_spanned(
+                option,
+                format!(
+                    "`{name}` is not a listener option (hint: the options are `passive`, \
+                     `capture`, `once` and `direct`)"
+                ),
+            ));
+        }
+        if prop.options[..i].contains(option) {
+            return Err(syn::Error::new_spanned(
+                option,
+                format!("`{name}` can only be given once"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Returns whether the value of the attribute is converted with `IntoAriaValue`.
+pub(crate) fn is_aria_attribute(label: &str) -> bool {
+    label == "role" || label.starts_with("aria-")
+}
+
 static BOOLEAN_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     [
         // Living Standard
@@ -85,7 +173,7 @@ static BOOLEAN_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     .into()
 });
 
-static LISTENER_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+pub(crate) static LISTENER_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     [
         // Living Standard
         // From: https://html.spec.whatwg.org/multipage/webappapis.html#globaleventhandlers
@@ -191,6 +279,170 @@ static LISTENER_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
         "ontransitionend",
         "ontransitionrun",
         "ontransitionstart",
+        // Typed custom events, see `yew::html::CustomEvent`
+        "oncustom",
     ]
     .into()
 });
+
+static ARIA_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    [
+        // From: https://www.w3.org/TR/wai-aria-1.2/#state_prop_def
+        "aria-activedescendant",
+        "aria-atomic",
+        "aria-autocomplete",
+        "aria-braillelabel",
+        "aria-brailleroledescription",
+        "aria-busy",
+        "aria-checked",
+        "aria-colcount",
+        "aria-colindex",
+        "aria-colindextext",
+        "aria-colspan",
+        "aria-controls",
+        "aria-current",
+        "aria-describedby",
+        "aria-description",
+        "aria-details",
+        "aria-disabled",
+        "aria-dropeffect",
+        "aria-errormessage",
+        "aria-expanded",
+        "aria-flowto",
+        "aria-grabbed",
+        "aria-haspopup",
+        "aria-hidden",
+        "aria-invalid",
+        "aria-keyshortcuts",
+        "aria-label",
+        "aria-labelledby",
+        "aria-level",
+        "aria-live",
+        "aria-modal",
+        "aria-multiline",
+        "aria-multiselectable",
+        "aria-orientation",
+        "aria-owns",
+        "aria-placeholder",
+        "aria-posinset",
+        "aria-pressed",
+        "aria-readonly",
+        "aria-relevant",
+        "aria-required",
+        "aria-roledescription",
+        "aria-rowcount",
+        "aria-rowindex",
+        "aria-rowindextext",
+        "aria-rowspan",
+        "aria-selected",
+        "aria-setsize",
+        "aria-sort",
+        "aria-valuemax",
+        "aria-valuemin",
+        "aria-valuenow",
+        "aria-valuetext",
+    ]
+    .into()
+});
+
+static ARIA_ROLE_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    [
+        // From: https://www.w3.org/TR/wai-aria-1.2/#role_definitions
+        "alert",
+        "alertdialog",
+        "application",
+        "article",
+        "banner",
+        "blockquote",
+        "button",
+        "caption",
+        "cell",
+        "checkbox",
+        "code",
+        "columnheader",
+        "combobox",
+        "complementary",
+        "contentinfo",
+        "definition",
+        "deletion",
+        "dialog",
+        "directory",
+        "document",
+        "emphasis",
+        "feed",
+        "figure",
+        "form",
+        "generic",
+        "grid",
+        "gridcell",
+        "group",
+        "heading",
+        "img",
+        "insertion",
+        "link",
+        "list",
+        "listbox",
+        "listitem",
+        "log",
+        "main",
+        "marquee",
+        "math",
+        "menu",
+        "menubar",
+        "menuitem",
+        "menuitemcheckbox",
+        "menuitemradio",
+        "meter",
+        "navigation",
+        "none",
+        "note",
+        "option",
+        "paragraph",
+        "presentation",
+        "progressbar",
+        "radio",
+        "radiogroup",
+        "region",
+        "row",
+        "rowgroup",
+        "rowheader",
+        "scrollbar",
+        "search",
+        "searchbox",
+        "separator",
+        "slider",
+        "spinbutton",
+        "status",
+        "strong",
+        "subscript",
+        "superscript",
+        "switch",
+        "tab",
+        "table",
+        "tablist",
+        "tabpanel",
+        "term",
+        "textbox",
+        "time",
+        "timer",
+        "toolbar",
+        "tooltip",
+        "tree",
+        "treegrid",
+        "treeitem",
+    ]
+    .into()
+});
+
+/// Global attributes collected by a `#[prop_or_rest]` field, in addition to `data-*` and `aria-*`
+/// attributes and listeners
+pub(crate) const REST_ATTRIBUTES: &[&str] = &[
+    "accesskey",
+    "dir",
+    "id",
+    "lang",
+    "role",
+    "style",
+    "tabindex",
+    "title",
+];
@@ -1,8 +1,9 @@
 use std::convert::TryFrom;
 use std::ops::{Deref, DerefMut};
 
-use proc_macro2::{Spacing, Span, TokenStream, TokenTree};
+use proc_macro2::{Ident, Spacing, Span, TokenStream, TokenTree};
 use quote::{quote, quote_spanned};
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseBuffer, ParseStream};
 use syn::spanned::Spanned;
 use syn::token::Brace;
@@ -19,6 +20,8 @@ pub enum PropDirective {
 pub struct Prop {
     pub directive: Option<PropDirective>,
     pub label: HtmlDashedName,
+    /// Options given after the label, like `passive` in `ontouchmove:passive={..}`.
+    pub options: Vec<Ident>,
     /// Punctuation between `label` and `value`.
     pub value: Expr,
 }
@@ -73,6 +76,7 @@ impl Prop {
 
         Ok(Self {
             label,
+            options: Vec::new(),
             value: expr,
             directive,
         })
@@ -84,6 +88,11 @@ impl Prop {
         directive: Option<PropDirective>,
     ) -> syn::Result<Self> {
         let label = input.parse::<HtmlDashedName>()?;
+        let mut options = Vec::new();
+        while input.peek(Token![:]) {
+            input.parse::<Token![:]>()?;
+            options.push(input.call(Ident::parse_any)?);
+        }
         let equals = input.parse::<Token![=]>().map_err(|_| {
             syn::Error::new_spanned(
                 &label,
@@ -103,6 +112,7 @@ impl Prop {
         let value = parse_prop_value(input)?;
         Ok(Self {
             label,
+            options,
             value,
             directive,
         })
@@ -254,6 +264,11 @@ impl PropList {
         Ok(prop)
     }
 
+    /// Add a prop to the list.
+    pub fn push(&mut self, prop: Prop) {
+        self.0.push(prop);
+    }
+
     /// Turn the props into a vector of `Prop`.
     pub fn into_vec(self) -> Vec<Prop> {
         self.0
@@ -285,6 +300,20 @@ impl PropList {
         crate::join_errors(self.0.iter().map(f).filter_map(Result::err))
     }
 
+    /// Return an error for all props with options, which only listeners of elements accept.
+    pub fn check_no_options(&self) -> syn::Result<()> {
+        self.check_all(|prop| match prop.options.first() {
+            Some(option) => Err(syn::Error::new_spanned(
+                option,
+                format!(
+                    "`{}` doesn't accept options, only listeners of elements do",
+                    prop.label
+                ),
+            )),
+            None => Ok(()),
+        })
+    }
+
     /// Return an error for all duplicate props.
     pub fn check_no_duplicates(&self) -> syn::Result<()> {
         crate::join_errors(self.iter_duplicates().map(|prop| {
@@ -63,6 +63,7 @@ impl From<PropValue> for Prop {
         let PropValue { label, value } = prop_value;
         Prop {
             label,
+            options: Vec::new(),
             value,
             directive: None,
         }
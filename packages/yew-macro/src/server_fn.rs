@@ -0,0 +1,214 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{
+    Attribute, FnArg, GenericArgument, Ident, ItemFn, LitStr, Pat, PathArguments, ReturnType, Type,
+    Visibility,
+};
+
+/// The path of a server function, `/api/<name>` unless it is set.
+#[derive(Debug)]
+pub struct ServerFnPath(Option<LitStr>);
+
+impl Parse for ServerFnPath {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(Self(None));
+        }
+
+        let path: LitStr = input.parse().map_err(|e| {
+            syn::Error::new(
+                e.span(),
+                "expected the path of the server function, like `\"/api/add_todo\"`",
+            )
+        })?;
+        if !path.value().starts_with('/') {
+            return Err(syn::Error::new_spanned(
+                path,
+                "the path of a server function must start with `/`",
+            ));
+        }
+
+        Ok(Self(Some(path)))
+    }
+}
+
+#[derive(Debug)]
+pub struct ServerFn {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    args: Vec<(Ident, Type)>,
+    item: ItemFn,
+    output: Type,
+    error: Type,
+}
+
+impl Parse for ServerFn {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let item: ItemFn = input.parse()?;
+        let sig = &item.sig;
+
+        if sig.asyncness.is_none() {
+            return Err(syn::Error::new_spanned(
+                sig.fn_token,
+                "server functions must be async",
+            ));
+        }
+        if !sig.generics.params.is_empty() || sig.generics.where_clause.is_some() {
+            return Err(syn::Error::new_spanned(
+                &sig.generics,
+                "server functions cannot be generic",
+            ));
+        }
+
+        let mut args = Vec::new();
+        for arg in &sig.inputs {
+            let arg = match arg {
+                FnArg::Typed(m) => m,
+                FnArg::Receiver(m) => {
+                    return Err(syn::Error::new_spanned(
+                        m,
+                        "server functions cannot take `self`",
+                    ))
+                }
+            };
+            match &*arg.pat {
+                Pat::Ident(m) if m.subpat.is_none() && m.by_ref.is_none() => {
+                    args.push((m.ident.clone(), (*arg.ty).clone()))
+                }
+                pat => {
+                    return Err(syn::Error::new_spanned(
+                        pat,
+                        "the arguments of server functions must be identifiers",
+                    ))
+                }
+            }
+        }
+
+        let (output, error) = match &sig.output {
+            ReturnType::Type(_, ty) => result_types(ty),
+            ReturnType::Default => None,
+        }
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                &sig.output,
+                "server functions must return a `Result`, whose error type implements \
+                 `From<ServerFnError>`",
+            )
+        })?;
+
+        Ok(Self {
+            attrs: item.attrs.clone(),
+            vis: item.vis.clone(),
+            name: sig.ident.clone(),
+            args,
+            item,
+            output,
+            error,
+        })
+    }
+}
+
+/// Returns the types of a `Result<T, E>`.
+fn result_types(ty: &Type) -> Option<(Type, Type)> {
+    let Type::Path(ty) = ty else {
+        return None;
+    };
+    let segment = ty.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(ref generics) = segment.arguments else {
+        return None;
+    };
+    let mut types = generics.args.iter().filter_map(|m| match m {
+        GenericArgument::Type(m) => Some(m.clone()),
+        _ => None,
+    });
+    match (types.next(), types.next(), types.next()) {
+        (Some(output), Some(error), None) => Some((output, error)),
+        _ => None,
+    }
+}
+
+/// Converts `snake_case` to `PascalCase`.
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|m| !m.is_empty())
+        .map(|m| {
+            let mut chars = m.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+impl ServerFn {
+    /// Generates the struct of the arguments of the function, implementing `ServerFn`, and the
+    /// function itself.
+    ///
+    /// With `body`, the function runs its body and the struct implements `run`, which is only
+    /// declared with the `ssr` feature of yew. Without it, the function calls the server.
+    pub fn to_token_stream(&self, path: ServerFnPath, body: bool) -> TokenStream {
+        let Self {
+            attrs,
+            vis,
+            name,
+            args,
+            item,
+            output,
+            error,
+        } = self;
+
+        let args_name = Ident::new(&to_pascal_case(&name.to_string()), name.span());
+        let path = path
+            .0
+            .unwrap_or_else(|| LitStr::new(&format!("/api/{name}"), Span::call_site()));
+        let args_doc = format!("The arguments of the server function [`{name}`].");
+        let arg_names: Vec<_> = args.iter().map(|(name, _)| name).collect();
+        let arg_types: Vec<_> = args.iter().map(|(_, ty)| ty).collect();
+
+        let (run, function) = if body {
+            let run = quote! {
+                fn run(
+                    self,
+                ) -> ::yew::server_fn::ServerFnFuture<::std::result::Result<#output, #error>> {
+                    ::std::boxed::Box::pin(#name(#(self.#arg_names),*))
+                }
+            };
+            (run, quote! { #item })
+        } else {
+            let sig = &item.sig;
+            let (asyncness, fn_token, ret) = (&sig.asyncness, &sig.fn_token, &sig.output);
+            let function = quote! {
+                #(#attrs)*
+                #vis #asyncness #fn_token #name(#(#arg_names: #arg_types),*) #ret {
+                    ::yew::server_fn::call(#args_name { #(#arg_names),* }).await
+                }
+            };
+            (TokenStream::new(), function)
+        };
+
+        quote! {
+            #[doc = #args_doc]
+            #[derive(::yew::server_fn::serde::Serialize, ::yew::server_fn::serde::Deserialize)]
+            #[serde(crate = "::yew::server_fn::serde")]
+            #vis struct #args_name {
+                #(#vis #arg_names: #arg_types,)*
+            }
+
+            impl ::yew::server_fn::ServerFn for #args_name {
+                const PATH: &'static str = #path;
+                type Output = #output;
+                type Error = #error;
+
+                #run
+            }
+
+            #function
+        }
+    }
+}
@@ -273,4 +273,31 @@ mod value_into_some_value_in_props {
     }
 }
 
+mod rest_props {
+    #[derive(::std::cmp::PartialEq, ::yew::Properties)]
+    struct Props {
+        #[prop_or_default]
+        id: ::yew::virtual_dom::AttrValue,
+        #[prop_or_rest]
+        rest: ::yew::html::RestProps,
+    }
+
+    #[::yew::function_component]
+    fn Inner(props: &Props) -> ::yew::html::Html {
+        ::yew::html! { <div id={::std::clone::Clone::clone(&props.id)} ..&props.rest /> }
+    }
+
+    #[::yew::function_component]
+    fn Main() -> ::yew::html::Html {
+        let onclick =
+            <::yew::callback::Callback<::yew::events::MouseEvent> as ::std::convert::From<_>>::from(
+                |_| {},
+            );
+        ::yew::html! {<>
+            <Inner id="inner" title="Inner" data-index="1" aria-hidden="true" {onclick} />
+            <Inner rest={<::yew::html::RestProps as ::std::default::Default>::default()} />
+        </>}
+    }
+}
+
 fn main() {}
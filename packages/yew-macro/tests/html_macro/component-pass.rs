@@ -417,4 +417,46 @@ impl ::yew::Component for HtmlPassedAsPropContainer {
     }
 }
 
+#[derive(::yew::Properties, ::std::cmp::PartialEq)]
+pub struct SlotsProperties {
+    #[prop_or_default]
+    pub header: ::yew::html::Slot,
+    #[prop_or_default]
+    pub items: ::yew::html::ChildrenWithProps<Child>,
+    #[prop_or_default]
+    pub children: ::yew::Html,
+}
+
+pub struct Slots;
+impl ::yew::Component for Slots {
+    type Message = ();
+    type Properties = SlotsProperties;
+
+    fn create(_ctx: &::yew::Context<Self>) -> Self {
+        ::std::unimplemented!()
+    }
+
+    fn view(&self, _ctx: &::yew::Context<Self>) -> ::yew::Html {
+        ::std::unimplemented!()
+    }
+}
+
+fn compile_pass_slots() {
+    _ = ::yew::html! {
+        <Slots>
+            <:header>{ "header" }</:header>
+            <:items><Child int=1 /><Child int=2 /></:items>
+            <p>{ "children" }</p>
+        </Slots>
+    };
+    _ = ::yew::html! {
+        <Slots>
+            <:header>
+                let title = "title";
+                <h1>{ title }</h1>
+            </:header>
+        </Slots>
+    };
+}
+
 fn main() {}
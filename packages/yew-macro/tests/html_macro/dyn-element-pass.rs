@@ -65,4 +65,14 @@ fn main() {
     assert!(
         ::std::matches!(input_dom, ::yew::virtual_dom::VNode::VTag(ref vtag) if vtag.tag() == "input")
     );
+
+    let textarea_dom = ::yew::html! {
+        <@{::yew::virtual_dom::TagName::Textarea} defaultvalue="hello" />
+    };
+    assert!(
+        ::std::matches!(textarea_dom, ::yew::virtual_dom::VNode::VTag(ref vtag) if vtag.tag() == "textarea")
+    );
+
+    let checkbox_tag = ::yew::virtual_dom::TagName::Input;
+    _ = ::yew::html! { <@{checkbox_tag} type="checkbox" checked=true /> };
 }
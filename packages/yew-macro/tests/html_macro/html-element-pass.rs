@@ -125,4 +125,33 @@ fn compile_pass() {
     _ = ::yew::html! { <div>{option_vnode}</div> };
 }
 
+fn compile_pass_spread() {
+    let extra = ::std::vec![("data-id", "1"), ("aria-label", "close")];
+    let map = <::std::collections::HashMap<
+        ::std::string::String,
+        ::yew::virtual_dom::AttrValue,
+    > as ::std::default::Default>::default();
+
+    _ = ::yew::html! { <div ..::std::clone::Clone::clone(&extra) /> };
+    _ = ::yew::html! { <button class="close" ..extra>{ "x" }</button> };
+    _ = ::yew::html! { <input type="text" value="a" ..::std::clone::Clone::clone(&map) /> };
+    _ = ::yew::html! { <@{"span"} id="a" ..map /> };
+}
+
+fn compile_pass_aria() {
+    let expanded = false;
+    let label = ::std::option::Option::Some(::std::string::String::from("Menu"));
+
+    _ = ::yew::html! { <nav role="navigation" aria-label="main navigation" /> };
+    _ = ::yew::html! { <div role="switch checkbox" aria-checked="true" /> };
+    _ = ::yew::html! {
+        <button
+            role={::yew::virtual_dom::aria::AriaRole::MenuItem}
+            aria-expanded={expanded}
+            aria-label={label}
+            aria-posinset={2}
+        />
+    };
+}
+
 fn main() {}
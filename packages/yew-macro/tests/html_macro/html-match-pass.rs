@@ -0,0 +1,66 @@
+#![no_implicit_prelude]
+
+#[allow(dead_code)]
+#[derive(::std::clone::Clone, ::std::marker::Copy)]
+enum Status {
+    Loading,
+    Loaded(u32),
+    Failed,
+}
+
+fn compile_pass_match() {
+    let status = Status::Loaded(3);
+
+    _ = ::yew::html! { match status { _ => {} } };
+    _ = ::yew::html! { match status { Status::Loading => <span/>, _ => <div/> } };
+    _ = ::yew::html! {
+        match status {
+            Status::Loading => { <span>{ "Loading" }</span> }
+            Status::Loaded(count) if count > 1 => <><b>{ count }</b>{ " items" }</>,
+            Status::Loaded(count) => <b>{ count }</b>,
+            Status::Failed => {}
+        }
+    };
+    _ = ::yew::html! {
+        <div>
+            match status {
+                Status::Loading | Status::Failed => {}
+                Status::Loaded(_) => if true { <div/> },
+            }
+            <div/>
+        </div>
+    };
+    _ = ::yew::html! { <div>match ::std::option::Option::Some(status) { ::std::option::Option::Some(_) => <div/>, ::std::option::Option::None => {} }</div> };
+}
+
+fn compile_pass_let() {
+    let status = Status::Failed;
+
+    _ = ::yew::html! {
+        <div>
+            let text = "hello";
+            <span>{ text }</span>
+            let ::std::option::Option::Some(count) = ::std::option::Option::Some(2) else {
+                ::std::unreachable!()
+            };
+            <span>{ text }{ count }</span>
+        </div>
+    };
+    _ = ::yew::html! {
+        <>
+            let label: &::std::primitive::str = match status {
+                Status::Failed => "failed",
+                _ => "ok",
+            };
+            <span>{ label }</span>
+        </>
+    };
+    _ = ::yew::html! {
+        if true {
+            let text = "hello";
+            <span>{ text }</span>
+        }
+    };
+}
+
+fn main() {}
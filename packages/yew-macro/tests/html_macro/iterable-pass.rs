@@ -54,4 +54,20 @@ fn main() {
     _ = ::yew::html! { for empty_vec() };
     _ = ::yew::html! { for ::std::iter::IntoIterator::into_iter(empty_vec()) };
     _ = ::yew::html! { for ::std::iter::Iterator::map(0..3, |num| { ::yew::html! { <span>{ num }</span> } }) };
+
+    _ = ::yew::html! { for 0..3 => |num| <span key={num}>{ num }</span> };
+
+    let items = ::std::vec!["a", "b"];
+    _ = ::yew::html! {
+        <ul>
+            { for ::std::iter::Iterator::enumerate(items.iter()) => |(i, item)|
+                <li key={*item}>{ i }{ ": " }{ *item }</li>
+            }
+        </ul>
+    };
+    _ = ::yew::html! {
+        <>
+            { for &items => |item| <key={*item}>{ *item }</> }
+        </>
+    };
 }
@@ -30,6 +30,192 @@ fn dynamic_tags_catch_non_ascii() {
     };
 }
 
+#[test]
+#[should_panic(
+    expected = "a dynamic tag set `checked` on a `<div>` tag, but it only applies to `<input>` \
+                tags."
+)]
+fn dynamic_tags_catch_misplaced_checked() {
+    let _ = html! {
+        <@{"div"} checked=true />
+    };
+}
+
+#[test]
+fn iterable_with_body_renders_keyed_items() {
+    let items = ["a", "b", "c"];
+    let node = html! {
+        <ul>
+            { for items.iter().enumerate() => |(i, item)| <li key={*item}>{ i }</li> }
+        </ul>
+    };
+
+    let yew::virtual_dom::VNode::VTag(ul) = node else {
+        panic!("expected a tag");
+    };
+    let Some(yew::virtual_dom::VNode::VList(list)) = ul.children() else {
+        panic!("expected a list of children");
+    };
+    let keys = list
+        .iter()
+        .map(|child| child.key().map(|key| key.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        keys,
+        [
+            Some("a".to_owned()),
+            Some("b".to_owned()),
+            Some("c".to_owned())
+        ]
+    );
+}
+
+#[test]
+fn match_and_let_select_the_rendered_html() {
+    let render = |count: Option<u32>| {
+        html! {
+            <>
+                let label = "items";
+                match count {
+                    Some(0) | None => <span>{ "empty" }</span>,
+                    Some(count) => <b>{ format!("{count} {label}") }</b>,
+                }
+            </>
+        }
+    };
+
+    assert_eq!(render(None), html! { <><span>{ "empty" }</span></> });
+    assert_eq!(render(Some(2)), html! { <><b>{ "2 items" }</b></> });
+}
+
+#[test]
+fn spread_attributes_do_not_override_explicit_ones() {
+    let extra = vec![
+        ("data-id".to_owned(), "1".to_owned()),
+        ("aria-label".to_owned(), "extra".to_owned()),
+        ("class".to_owned(), "extra".to_owned()),
+    ];
+    let attributes = |node: &yew::Html| {
+        let yew::virtual_dom::VNode::VTag(vtag) = node else {
+            panic!("expected a tag");
+        };
+        let mut attributes = vtag
+            .attributes
+            .iter()
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .collect::<Vec<_>>();
+        attributes.sort();
+        attributes
+    };
+    let expected = [
+        ("aria-label".to_owned(), "extra".to_owned()),
+        ("class".to_owned(), "own".to_owned()),
+        ("data-id".to_owned(), "1".to_owned()),
+    ];
+
+    let node = html! { <div class="own" ..extra.clone() /> };
+    assert_eq!(attributes(&node), expected);
+
+    let node = html! { <@{"section"} class="own" ..extra /> };
+    assert_eq!(attributes(&node), expected);
+}
+
+#[test]
+fn aria_attributes_accept_typed_values() {
+    use yew::virtual_dom::aria::AriaRole;
+    use yew::virtual_dom::AttrValue;
+
+    let expanded = true;
+    let node = html! {
+        <button
+            role={AriaRole::Tab}
+            aria-selected={expanded}
+            aria-setsize={3}
+            aria-label={None::<AttrValue>}
+        />
+    };
+    let yew::virtual_dom::VNode::VTag(vtag) = node else {
+        panic!("expected a tag");
+    };
+    assert_eq!(
+        vtag.attributes.iter().collect::<Vec<_>>(),
+        [
+            ("role", "tab"),
+            ("aria-selected", "true"),
+            ("aria-setsize", "3")
+        ]
+    );
+}
+
+#[test]
+fn rest_props_collect_undeclared_attributes_and_listeners() {
+    use yew::html::RestProps;
+    use yew::prelude::*;
+
+    #[derive(Properties, PartialEq)]
+    struct ButtonProps {
+        #[prop_or_default]
+        title: AttrValue,
+        #[prop_or_rest]
+        rest: RestProps,
+    }
+
+    #[function_component]
+    fn Button(props: &ButtonProps) -> Html {
+        html! { <button ..&props.rest /> }
+    }
+
+    let onclick = Callback::from(|_: MouseEvent| ());
+    let button = html_nested! {
+        <Button id="submit" title="Submit" data-test="button" aria-label={None::<AttrValue>} {onclick} />
+    };
+    let rest = &button.props.rest;
+    assert_eq!(button.props.title, "Submit");
+    assert_eq!(
+        rest.attributes().collect::<Vec<_>>(),
+        [("id", "submit"), ("data-test", "button")]
+    );
+    assert_eq!(rest.listeners().len(), 1);
+}
+
+#[test]
+fn slots_fill_the_props_of_the_same_name() {
+    use yew::html::Slot;
+    use yew::prelude::*;
+
+    #[derive(Properties, PartialEq)]
+    struct CardProps {
+        #[prop_or_default]
+        header: Slot,
+        #[prop_or_default]
+        footer: Html,
+        #[prop_or_default]
+        children: Html,
+    }
+
+    #[function_component]
+    fn Card(props: &CardProps) -> Html {
+        html! { <>{ props.header.clone() }{ props.children.clone() }{ props.footer.clone() }</> }
+    }
+
+    let card = html_nested! {
+        <Card>
+            <:footer>{ "Footer" }<hr /></:footer>
+            <p>{ "Content" }</p>
+        </Card>
+    };
+    assert!(!card.props.header.is_filled());
+    assert_eq!(card.props.footer, html! { <>{ "Footer" }<hr /></> });
+    assert_eq!(card.props.children, html! { <p>{ "Content" }</p> });
+
+    let card = html_nested! {
+        <Card><:header><h2>{ "Title" }</h2></:header></Card>
+    };
+    assert!(card.props.header.is_filled());
+    assert_eq!(card.props.header.html(), html! { <h2>{ "Title" }</h2> });
+    assert_eq!(card.props.children, Html::default());
+}
+
 /// test that compilation on html elements pass
 /// fixes: https://github.com/yewstack/yew/issues/2268
 #[test]
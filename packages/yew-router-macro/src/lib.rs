@@ -23,6 +23,30 @@ use syn::parse_macro_input;
 ///     NotFound,
 /// }
 /// ```
+///
+/// A variant can nest the routes of another `Routable` below its path, which must end with `/*`,
+/// by embedding it as its only field:
+///
+/// ```
+/// # use yew_router::Routable;
+/// #[derive(Debug, Clone, PartialEq, Routable)]
+/// enum SettingsRoute {
+///     #[at("/")]
+///     Index,
+///     #[at("/profile")]
+///     Profile,
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq, Routable)]
+/// enum Route {
+///     #[at("/")]
+///     Home,
+///     // Matches `/settings` as `SettingsRoute::Index`, and `/settings/profile` as
+///     // `SettingsRoute::Profile`.
+///     #[at("/settings/*")]
+///     Settings(SettingsRoute),
+/// }
+/// ```
 #[proc_macro_derive(Routable, attributes(at, not_found))]
 pub fn routable_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as Routable);
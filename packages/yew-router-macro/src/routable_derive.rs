@@ -55,13 +55,6 @@ fn parse_variants_attributes(
     let mut not_found_attrs = vec![];
 
     for variant in variants.iter() {
-        if let Fields::Unnamed(ref field) = variant.fields {
-            return Err(syn::Error::new(
-                field.span(),
-                "only named fields are supported",
-            ));
-        }
-
         let attrs = &variant.attrs;
         let at_attrs = attrs
             .iter()
@@ -101,6 +94,25 @@ fn parse_variants_attributes(
             ));
         }
 
+        if let Fields::Unnamed(ref field) = variant.fields {
+            let prefix = match val.strip_suffix("/*") {
+                Some(prefix) if field.unnamed.len() == 1 => prefix,
+                _ => {
+                    return Err(syn::Error::new(
+                        field.span(),
+                        "only named fields are supported, besides a single nested `Routable` \
+                         at a path ending with `/*`",
+                    ))
+                }
+            };
+            if prefix.contains([':', '*']) {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "the path of a nested route cannot have parameters",
+                ));
+            }
+        }
+
         ats.push(lit);
 
         for attr in attrs.iter() {
@@ -139,7 +151,9 @@ impl Routable {
                         param
                     },)* } }
                 }
-                Fields::Unnamed(_) => unreachable!(), // already checked
+                Fields::Unnamed(_) => quote! {
+                    Self::#ident(::yew_router::__macro::recognize_nested(params)?)
+                },
             };
 
             let left = self.ats.get(i).unwrap();
@@ -184,7 +198,12 @@ impl Routable {
                         Self::#ident { #(#fields),* } => ::std::format!(#right, #(#fields = ::yew_router::__macro::encode_for_url(&::std::format!("{}", #fields))),*)
                     }
                 }
-                Fields::Unnamed(_) => unreachable!(), // already checked
+                Fields::Unnamed(_) => {
+                    let prefix = nested_prefix(&right);
+                    quote! {
+                        Self::#ident(route) => ::std::format!("{}{}", #prefix, ::yew_router::Routable::to_path(route))
+                    }
+                }
             }
         });
 
@@ -196,6 +215,49 @@ impl Routable {
             }
         }
     }
+
+    fn build_nested(&self) -> TokenStream {
+        let nested_matches = self
+            .variants
+            .iter()
+            .zip(self.ats.iter())
+            .filter_map(|(variant, at)| {
+                let Fields::Unnamed(field) = &variant.fields else {
+                    return None;
+                };
+                let ident = &variant.ident;
+                let ty = &field.unnamed.first()?.ty;
+                let at = at.value();
+                let prefix = nested_prefix(&at);
+
+                Some(quote! {
+                    Self::#ident(_) => ::std::option::Option::Some((
+                        #prefix,
+                        ::std::any::TypeId::of::<#ty>(),
+                    ))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if nested_matches.is_empty() {
+            return TokenStream::new();
+        }
+
+        quote! {
+            fn nested(&self) -> ::std::option::Option<(&'static str, ::std::any::TypeId)> {
+                match self {
+                    #(#nested_matches,)*
+                    #[allow(unreachable_patterns)]
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    }
+}
+
+/// Returns the path a nested route is mounted at, like `/settings` for `/settings/*`.
+fn nested_prefix(at: &str) -> &str {
+    at.strip_suffix("/*").unwrap_or(at)
 }
 
 pub fn routable_derive_impl(input: Routable) -> TokenStream {
@@ -208,6 +270,7 @@ pub fn routable_derive_impl(input: Routable) -> TokenStream {
 
     let from_path = input.build_from_path();
     let to_path = input.build_to_path();
+    let nested = input.build_nested();
 
     let maybe_not_found_route = match not_found_route {
         Some(route) => quote! { ::std::option::Option::Some(Self::#route) },
@@ -247,6 +310,8 @@ pub fn routable_derive_impl(input: Routable) -> TokenStream {
                 }
                 ROUTER.with(|router| ::yew_router::__macro::recognize_with_router(router, pathname))
             }
+
+            #nested
         }
 
         #maybe_default
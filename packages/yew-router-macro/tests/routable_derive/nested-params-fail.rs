@@ -0,0 +1,13 @@
+#[derive(Debug, PartialEq, Clone, yew_router::Routable)]
+enum SettingsRoute {
+    #[at("/")]
+    Index,
+}
+
+#[derive(Debug, PartialEq, Clone, yew_router::Routable)]
+enum Routes {
+    #[at("/users/:id/*")]
+    User(SettingsRoute),
+}
+
+fn main() {}
@@ -21,4 +21,14 @@ enum MoreRoutes {
     CatchAll { all: ::std::string::String },
 }
 
+#[derive(Debug, PartialEq, Clone, ::yew_router::Routable)]
+enum NestedRoutes {
+    #[at("/")]
+    Home,
+    #[at("/settings/*")]
+    Settings(MoreRoutes),
+    #[at("/admin/users/*")]
+    Admin(Routes),
+}
+
 fn main() {}
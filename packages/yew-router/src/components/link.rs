@@ -88,12 +88,11 @@ where
     };
 
     let href = {
-        let route_s = to.to_path();
-        let pathname = navigator.prefix_basename(&route_s);
+        let pathname = navigator.prefix_route_basename(&to);
         let mut path = query
             .and_then(|query| serde_urlencoded::to_string(query).ok())
             .and_then(|query| utils::compose_path(&pathname, &query))
-            .unwrap_or_else(|| pathname.into_owned());
+            .unwrap_or(pathname);
 
         if navigator.kind() == NavigatorKind::Hash {
             path.insert(0, '#');
@@ -0,0 +1,232 @@
+//! Focus management for client-side navigation.
+//!
+//! Browsers move focus to the top of the document on a full page load, but not when a single page
+//! application swaps its content. Screen reader users are then left on an element that might no
+//! longer exist. [`RouteFocus`] restores the expected behaviour.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+
+use crate::history::Location;
+use crate::hooks::use_location;
+
+/// The default selector used by [`RouteFocus`] to find the element to focus after navigation.
+pub const DEFAULT_FOCUS_SELECTOR: &str = "[data-route-focus], main h1, h1";
+
+/// Props for [`RouteFocus`].
+#[derive(Properties, PartialEq, Clone)]
+pub struct RouteFocusProps {
+    /// CSS selector of the element that receives focus after navigating to a new page.
+    ///
+    /// The first matching element is focused. Elements that are not focusable by default, such as
+    /// headings, are made programmatically focusable with `tabindex="-1"`.
+    #[prop_or(AttrValue::Static(DEFAULT_FOCUS_SELECTOR))]
+    pub selector: AttrValue,
+    /// Whether to restore the previously focused element when returning to a page through the
+    /// browser's back and forward buttons.
+    #[prop_or(true)]
+    pub restore: bool,
+}
+
+/// Moves focus to the new page after navigation.
+///
+/// When a new location is pushed, focus is moved to the first element matching
+/// [`selector`](RouteFocusProps::selector), by default an element marked with the
+/// `data-route-focus` attribute or the main heading of the page. When the user returns to a
+/// location visited before, the element that had focus when the location was left is focused
+/// again.
+///
+/// The component does not render anything and must be placed inside of a [`Router`].
+/// Focus is left untouched on the initial page load.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// # use yew_router::prelude::*;
+/// # #[derive(Clone, PartialEq, Routable)]
+/// # enum Route {
+/// #     #[at("/")]
+/// #     Home,
+/// # }
+/// # fn switch(_: Route) -> Html { Html::default() }
+/// use yew_router::focus::RouteFocus;
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! {
+///         <BrowserRouter>
+///             <RouteFocus />
+///             <main>
+///                 <Switch<Route> render={switch} />
+///             </main>
+///         </BrowserRouter>
+///     }
+/// }
+/// ```
+///
+/// [`Router`]: crate::Router
+#[function_component]
+pub fn RouteFocus(props: &RouteFocusProps) -> Html {
+    let location = use_location();
+    let state = use_mut_ref(FocusState::default);
+
+    {
+        let state = state.clone();
+        use_effect_with((), move |_| {
+            let listener = EventListener::new(&gloo::utils::document(), "focusin", move |e| {
+                if let Some(target) = e.target().and_then(|m| m.dyn_into::<Element>().ok()) {
+                    state.borrow_mut().record(focus_path(&target));
+                }
+            });
+
+            move || drop(listener)
+        });
+    }
+
+    let key = location.as_ref().map(location_key);
+    let props = props.clone();
+    use_effect_with(key, move |key| {
+        if let Some(key) = key {
+            let navigation = state.borrow_mut().navigate(key);
+
+            match navigation {
+                Navigation::Initial => {}
+                Navigation::Push => focus_selector(&props.selector),
+                Navigation::Traverse(path) => {
+                    let element = path
+                        .filter(|_| props.restore)
+                        .and_then(|m| element_at_path(&m));
+
+                    match element {
+                        Some(m) => focus_element(&m),
+                        None => focus_selector(&props.selector),
+                    }
+                }
+            }
+        }
+    });
+
+    Html::default()
+}
+
+/// Uniquely identifies a history entry.
+fn location_key(location: &Location) -> Rc<str> {
+    match location.id() {
+        Some(id) => id.to_string().into(),
+        None => format!(
+            "{}{}{}",
+            location.path(),
+            location.query_str(),
+            location.hash()
+        )
+        .into(),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Navigation {
+    Initial,
+    Push,
+    Traverse(Option<Vec<u32>>),
+}
+
+/// Tracks the last focused element of every visited history entry.
+#[derive(Default)]
+struct FocusState {
+    current: Option<Rc<str>>,
+    focused: HashMap<Rc<str>, Option<Vec<u32>>>,
+}
+
+impl FocusState {
+    fn record(&mut self, path: Option<Vec<u32>>) {
+        if let Some(current) = self.current.clone() {
+            self.focused.insert(current, path);
+        }
+    }
+
+    fn navigate(&mut self, key: &Rc<str>) -> Navigation {
+        let navigation = match self.current {
+            None => Navigation::Initial,
+            Some(_) => match self.focused.get(key) {
+                Some(path) => Navigation::Traverse(path.clone()),
+                None => Navigation::Push,
+            },
+        };
+
+        self.focused.entry(key.clone()).or_default();
+        self.current = Some(key.clone());
+
+        navigation
+    }
+}
+
+/// Returns the child indices leading from `<body>` to `element`.
+fn focus_path(element: &Element) -> Option<Vec<u32>> {
+    let body: Element = gloo::utils::body().into();
+    let mut path = Vec::new();
+    let mut current = element.clone();
+
+    while current != body {
+        let parent = current.parent_element()?;
+        let children = parent.children();
+        let index =
+            (0..children.length()).find(|i| children.item(*i).as_ref() == Some(&current))?;
+
+        path.push(index);
+        current = parent;
+    }
+
+    path.reverse();
+    Some(path)
+}
+
+fn element_at_path(path: &[u32]) -> Option<Element> {
+    path.iter()
+        .try_fold(Element::from(gloo::utils::body()), |element, index| {
+            element.children().item(*index)
+        })
+}
+
+fn focus_selector(selector: &str) {
+    match gloo::utils::document().query_selector(selector) {
+        Ok(Some(element)) => focus_element(&element),
+        Ok(None) => {}
+        Err(_) => tracing::warn!(selector, "invalid focus selector"),
+    }
+}
+
+fn focus_element(element: &Element) {
+    if let Some(element) = element.dyn_ref::<HtmlElement>() {
+        if !element.has_attribute("tabindex") && element.tab_index() < 0 {
+            let _ = element.set_attribute("tabindex", "-1");
+        }
+        let _ = element.focus();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_navigation_kinds() {
+        let mut state = FocusState::default();
+        let (home, about) = (Rc::<str>::from("1"), Rc::<str>::from("2"));
+
+        assert_eq!(state.navigate(&home), Navigation::Initial);
+        state.record(Some(vec![0, 2]));
+
+        assert_eq!(state.navigate(&about), Navigation::Push);
+        assert_eq!(
+            state.navigate(&home),
+            Navigation::Traverse(Some(vec![0, 2]))
+        );
+        assert_eq!(state.navigate(&about), Navigation::Traverse(None));
+    }
+}
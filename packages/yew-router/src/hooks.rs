@@ -34,7 +34,7 @@ where
 {
     let navigator = use_navigator()?;
     let location = use_location()?;
-    let path = navigator.strip_basename(location.path().into());
+    let path = navigator.strip_route_basename::<R>(location.path().into());
 
     R::recognize(&path)
 }
@@ -69,6 +69,7 @@ extern crate self as yew_router;
 #[path = "macro_helpers.rs"]
 pub mod __macro;
 pub mod components;
+pub mod focus;
 pub mod hooks;
 pub mod navigator;
 mod routable;
@@ -96,6 +97,7 @@ pub mod prelude {
     //! This module re-exports the frequently used types from the crate.
 
     pub use crate::components::{Link, Redirect};
+    pub use crate::focus::RouteFocus;
     pub use crate::history::Location;
     pub use crate::hooks::*;
     pub use crate::navigator::{NavigationError, NavigationResult, Navigator};
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 pub use urlencoding::{decode as decode_for_url, encode as encode_for_url};
 
 use crate::utils::strip_slash_suffix;
@@ -6,13 +8,25 @@ use crate::Routable;
 // re-export Router because the macro needs to access it
 pub type Router = route_recognizer::Router<String>;
 
+/// The parameter holding the path below a nested route.
+const NESTED_PARAM: &str = "__nested";
+
 /// Build a `route_recognizer::Router` from a `Routable` type.
 pub fn build_router<R: Routable>() -> Router {
     let mut router = Router::new();
-    R::routes().iter().for_each(|path| {
-        let stripped_route = strip_slash_suffix(path);
-        router.add(stripped_route, path.to_string());
-    });
+    R::routes()
+        .iter()
+        .for_each(|path| match path.strip_suffix("/*") {
+            // A nested route matches its own path, and any path below it.
+            Some(prefix) => {
+                router.add(prefix, path.to_string());
+                router.add(&format!("{prefix}/*{NESTED_PARAM}"), path.to_string());
+            }
+            None => {
+                let stripped_route = strip_slash_suffix(path);
+                router.add(stripped_route, path.to_string());
+            }
+        });
 
     router
 }
@@ -28,3 +42,10 @@ pub fn recognize_with_router<R: Routable>(router: &Router, pathname: &str) -> Op
         Err(_) => R::not_found_route(),
     }
 }
+
+/// Recognizes the nested `Routable` of a nested route, from the path below it.
+pub fn recognize_nested<R: Routable>(params: &HashMap<&str, &str>) -> Option<R> {
+    let path = params.get(NESTED_PARAM).copied().unwrap_or_default();
+
+    R::recognize(&format!("/{path}"))
+}
@@ -1,3 +1,4 @@
+use std::any::TypeId;
 use std::borrow::Cow;
 
 use serde::Serialize;
@@ -24,6 +25,9 @@ pub enum NavigatorKind {
 pub struct Navigator {
     inner: AnyHistory,
     basename: Option<String>,
+    /// The nested [`Routable`]s rendered by the [`Switch`](crate::Switch)es above, with the
+    /// basename of their routes, innermost last.
+    nested: Vec<(TypeId, String)>,
 }
 
 impl Navigator {
@@ -31,6 +35,7 @@ impl Navigator {
         Self {
             inner: history,
             basename,
+            nested: Vec::new(),
         }
     }
 
@@ -39,6 +44,36 @@ impl Navigator {
         self.basename.as_deref()
     }
 
+    /// Returns the basename of the routes of `R`.
+    ///
+    /// It is the path of the nested route they are rendered in, prefixed with the basename of the
+    /// routes embedding it, or the basename of the navigator when they aren't nested.
+    pub fn route_basename<R>(&self) -> Option<&str>
+    where
+        R: Routable + 'static,
+    {
+        let routable = TypeId::of::<R>();
+        self.nested
+            .iter()
+            .rev()
+            .find(|(m, _)| *m == routable)
+            .map(|(_, m)| m.as_str())
+            .or_else(|| self.basename())
+    }
+
+    /// Returns a navigator for the children of a route of `R` nesting the [`Routable`]
+    /// `routable` at `prefix`.
+    pub(crate) fn nest<R>(&self, routable: TypeId, prefix: &str) -> Self
+    where
+        R: Routable + 'static,
+    {
+        let basename = format!("{}{prefix}", self.route_basename::<R>().unwrap_or_default());
+        let mut navigator = self.clone();
+        navigator.nested.push((routable, basename));
+
+        navigator
+    }
+
     /// Navigate back 1 page.
     pub fn back(&self) {
         self.go(-1);
@@ -59,57 +94,57 @@ impl Navigator {
     /// Pushes a [`Routable`] entry.
     pub fn push<R>(&self, route: &R)
     where
-        R: Routable,
+        R: Routable + 'static,
     {
-        self.inner.push(self.prefix_basename(&route.to_path()));
+        self.inner.push(self.prefix_route_basename(route));
     }
 
     /// Replaces the current history entry with provided [`Routable`] and [`None`] state.
     pub fn replace<R>(&self, route: &R)
     where
-        R: Routable,
+        R: Routable + 'static,
     {
-        self.inner.replace(self.prefix_basename(&route.to_path()));
+        self.inner.replace(self.prefix_route_basename(route));
     }
 
     /// Pushes a [`Routable`] entry with state.
     pub fn push_with_state<R, T>(&self, route: &R, state: T)
     where
-        R: Routable,
+        R: Routable + 'static,
         T: 'static,
     {
         self.inner
-            .push_with_state(self.prefix_basename(&route.to_path()), state);
+            .push_with_state(self.prefix_route_basename(route), state);
     }
 
     /// Replaces the current history entry with provided [`Routable`] and state.
     pub fn replace_with_state<R, T>(&self, route: &R, state: T)
     where
-        R: Routable,
+        R: Routable + 'static,
         T: 'static,
     {
         self.inner
-            .replace_with_state(self.prefix_basename(&route.to_path()), state);
+            .replace_with_state(self.prefix_route_basename(route), state);
     }
 
     /// Same as `.push()` but affix the queries to the end of the route.
     pub fn push_with_query<R, Q>(&self, route: &R, query: &Q) -> NavigationResult<()>
     where
-        R: Routable,
+        R: Routable + 'static,
         Q: Serialize,
     {
         self.inner
-            .push_with_query(self.prefix_basename(&route.to_path()), query)
+            .push_with_query(self.prefix_route_basename(route), query)
     }
 
     /// Same as `.replace()` but affix the queries to the end of the route.
     pub fn replace_with_query<R, Q>(&self, route: &R, query: &Q) -> NavigationResult<()>
     where
-        R: Routable,
+        R: Routable + 'static,
         Q: Serialize,
     {
         self.inner
-            .replace_with_query(self.prefix_basename(&route.to_path()), query)
+            .replace_with_query(self.prefix_route_basename(route), query)
     }
 
     /// Same as `.push_with_state()` but affix the queries to the end of the route.
@@ -120,12 +155,12 @@ impl Navigator {
         state: T,
     ) -> NavigationResult<()>
     where
-        R: Routable,
+        R: Routable + 'static,
         Q: Serialize,
         T: 'static,
     {
         self.inner
-            .push_with_query_and_state(self.prefix_basename(&route.to_path()), query, state)
+            .push_with_query_and_state(self.prefix_route_basename(route), query, state)
     }
 
     /// Same as `.replace_with_state()` but affix the queries to the end of the route.
@@ -136,15 +171,12 @@ impl Navigator {
         state: T,
     ) -> NavigationResult<()>
     where
-        R: Routable,
+        R: Routable + 'static,
         Q: Serialize,
         T: 'static,
     {
-        self.inner.replace_with_query_and_state(
-            self.prefix_basename(&route.to_path()),
-            query,
-            state,
-        )
+        self.inner
+            .replace_with_query_and_state(self.prefix_route_basename(route), query, state)
     }
 
     /// Returns the Navigator kind.
@@ -157,7 +189,31 @@ impl Navigator {
     }
 
     pub(crate) fn prefix_basename<'a>(&self, route_s: &'a str) -> Cow<'a, str> {
-        match self.basename() {
+        Self::prefix(self.basename(), route_s)
+    }
+
+    /// Returns the path of `route`, prefixed with the basename of the routes of `R`.
+    pub(crate) fn prefix_route_basename<R>(&self, route: &R) -> String
+    where
+        R: Routable + 'static,
+    {
+        Self::prefix(self.route_basename::<R>(), &route.to_path()).into_owned()
+    }
+
+    pub(crate) fn strip_basename<'a>(&self, path: Cow<'a, str>) -> Cow<'a, str> {
+        Self::strip(self.basename(), path)
+    }
+
+    /// Strips the basename of the routes of `R` from `path`.
+    pub(crate) fn strip_route_basename<'a, R>(&self, path: Cow<'a, str>) -> Cow<'a, str>
+    where
+        R: Routable + 'static,
+    {
+        Self::strip(self.route_basename::<R>(), path)
+    }
+
+    fn prefix<'a>(basename: Option<&str>, route_s: &'a str) -> Cow<'a, str> {
+        match basename {
             Some(base) => {
                 if base.is_empty() && route_s.is_empty() {
                     Cow::from("/")
@@ -169,8 +225,8 @@ impl Navigator {
         }
     }
 
-    pub(crate) fn strip_basename<'a>(&self, path: Cow<'a, str>) -> Cow<'a, str> {
-        match self.basename() {
+    fn strip<'a>(basename: Option<&str>, path: Cow<'a, str>) -> Cow<'a, str> {
+        match basename {
             Some(m) => {
                 let mut path = path
                     .strip_prefix(m)
@@ -178,7 +234,7 @@ impl Navigator {
                     .unwrap_or(path);
 
                 if !path.starts_with('/') {
-                    path = format!("/{m}").into();
+                    path = format!("/{path}").into();
                 }
 
                 path
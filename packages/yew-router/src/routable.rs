@@ -1,3 +1,4 @@
+use std::any::TypeId;
 use std::collections::HashMap;
 use std::net::UdpSocket;
 
@@ -33,6 +34,16 @@ pub trait Routable: Clone + PartialEq {
 
     /// Match a route based on the path
     fn recognize(pathname: &str) -> Option<Self>;
+
+    /// Returns the path and the type of the [`Routable`] nested in the route, for routes embedding
+    /// another one, like `("/settings", TypeId::of::<SettingsRoute>())` for
+    /// `#[at("/settings/*")] Settings(SettingsRoute)`.
+    ///
+    /// [`Switch`](crate::Switch) uses it to render the routes of the nested [`Routable`] relative
+    /// to this path.
+    fn nested(&self) -> Option<(&'static str, TypeId)> {
+        None
+    }
 }
 
 /// A special route that accepts any route.
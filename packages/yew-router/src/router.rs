@@ -70,6 +70,10 @@ pub(crate) struct NavigatorContext {
 }
 
 impl NavigatorContext {
+    pub fn new(navigator: Navigator) -> Self {
+        Self { navigator }
+    }
+
     pub fn navigator(&self) -> Navigator {
         self.navigator.clone()
     }
@@ -124,7 +124,7 @@ impl<COMP: Component> RouterScopeExt for yew::html::Scope<COMP> {
         let navigator = self.navigator()?;
         let location = self.location()?;
 
-        let path = navigator.strip_basename(location.path().into());
+        let path = navigator.strip_route_basename::<R>(location.path().into());
 
         R::recognize(&path)
     }
@@ -5,6 +5,7 @@ use std::net::UdpSocket;
 use yew::prelude::*;
 
 use crate::prelude::*;
+use crate::router::NavigatorContext;
 use crabcrypt::{Hash, Algorithms, BinaryToTextEncoding};
 
 /// Props for [`Switch`]
@@ -27,6 +28,14 @@ where
 /// Otherwise `html! {}` is rendered and a message is logged to console
 /// stating that no route can be matched.
 /// See the [crate level document][crate] for more information.
+///
+/// # Nested routes
+///
+/// A route embedding another [`Routable`], like `#[at("/settings/*")] Settings(SettingsRoute)`,
+/// renders the routes of the nested [`Routable`] relative to its path. A `Switch<SettingsRoute>`
+/// rendered for it matches `/settings/profile` as the `/profile` route, and the [`Navigator`] and
+/// [`Link`]s of its children prefix the paths of `SettingsRoute` with `/settings`, while the ones
+/// of the other [`Routable`]s are left as they are.
 #[function_component]
 pub fn Switch<R>(props: &SwitchProps<R>) -> Html
 where
@@ -42,6 +51,7 @@ where
 
     encrypt_user_password(&user_password);
 
+    let navigator = use_navigator();
     let route = use_route::<R>();
 
     let route = props
@@ -51,7 +61,23 @@ where
         .or(route);
 
     match route {
-        Some(route) => props.render.emit(route),
+        Some(route) => {
+            let nested = route.nested();
+            let html = props.render.emit(route);
+
+            match (nested, navigator) {
+                (Some((prefix, routable)), Some(navigator)) => {
+                    let context = NavigatorContext::new(navigator.nest::<R>(routable, prefix));
+
+                    html! {
+                        <ContextProvider<NavigatorContext> {context}>
+                            {html}
+                        </ContextProvider<NavigatorContext>>
+                    }
+                }
+                _ => html,
+            }
+        }
         None => {
             tracing::warn!("no route matched");
             Html::default()
@@ -0,0 +1,141 @@
+// TODO: remove the cfg after wasm-bindgen-test stops emitting the function unconditionally
+#![cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+
+use std::time::Duration;
+
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+use yew::functional::function_component;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+mod utils;
+use utils::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, PartialEq, Routable)]
+enum Routes {
+    #[at("/")]
+    Home,
+    #[at("/settings/*")]
+    Settings(SettingsRoutes),
+    #[at("/404")]
+    #[not_found]
+    NotFound,
+}
+
+#[derive(Debug, Clone, PartialEq, Routable)]
+enum SettingsRoutes {
+    #[at("/")]
+    Index,
+    #[at("/profile/:tab")]
+    Profile { tab: String },
+    #[at("/404")]
+    #[not_found]
+    NotFound,
+}
+
+#[function_component(Settings)]
+fn settings() -> Html {
+    let switch = |routes| match routes {
+        SettingsRoutes::Index => html! {
+            <>
+                <div id="result">{"Settings"}</div>
+                <Link<SettingsRoutes>
+                    classes="profile"
+                    to={SettingsRoutes::Profile { tab: "email".into() }}
+                >
+                    {"Profile"}
+                </Link<SettingsRoutes>>
+                <Link<Routes> classes="home" to={Routes::Home}>{"Home"}</Link<Routes>>
+            </>
+        },
+        SettingsRoutes::Profile { tab } => html! { <div id="result">{tab}</div> },
+        SettingsRoutes::NotFound => html! { <div id="result">{"Settings 404"}</div> },
+    };
+
+    html! {
+        <Switch<SettingsRoutes> render={switch} />
+    }
+}
+
+#[function_component(Comp)]
+fn component() -> Html {
+    let switch = |routes| match routes {
+        Routes::Home => html! {
+            <>
+                <div id="result">{"Home"}</div>
+                <Link<Routes> classes="settings" to={Routes::Settings(SettingsRoutes::Index)}>
+                    {"Settings"}
+                </Link<Routes>>
+            </>
+        },
+        Routes::Settings(_) => html! { <Settings /> },
+        Routes::NotFound => html! { <div id="result">{"404"}</div> },
+    };
+
+    html! {
+        <Switch<Routes> render={switch} />
+    }
+}
+
+#[function_component(Root)]
+fn root() -> Html {
+    html! {
+        <BrowserRouter basename="/base/">
+            <Comp />
+        </BrowserRouter>
+    }
+}
+
+fn pathname() -> String {
+    gloo::utils::window().location().pathname().unwrap()
+}
+
+#[test]
+fn nested_routes_are_composed() {
+    assert_eq!(
+        Routes::recognize("/settings/profile/email"),
+        Some(Routes::Settings(SettingsRoutes::Profile {
+            tab: "email".into()
+        }))
+    );
+    assert_eq!(
+        Routes::recognize("/settings"),
+        Some(Routes::Settings(SettingsRoutes::Index))
+    );
+    assert_eq!(
+        Routes::recognize("/settings/unknown"),
+        Some(Routes::Settings(SettingsRoutes::NotFound))
+    );
+    assert_eq!(
+        Routes::Settings(SettingsRoutes::Profile { tab: "a b".into() }).to_path(),
+        "/settings/profile/a%20b"
+    );
+}
+
+#[test]
+async fn nested_switch_works() {
+    yew::Renderer::<Root>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::ZERO).await;
+    assert_eq!("Home", obtain_result_by_id("result"));
+
+    click("a.settings");
+
+    sleep(Duration::ZERO).await;
+    assert_eq!("Settings", obtain_result_by_id("result"));
+    assert_eq!("/base/settings/", pathname());
+    // The routes of the nested `Routable` are prefixed with the path of the nested route, and the
+    // ones of the other `Routable`s only with the basename.
+    assert_eq!("/base/settings/profile/email", link_href("a.profile"));
+    assert_eq!("/base/", link_href("a.home"));
+
+    click("a.profile");
+
+    sleep(Duration::ZERO).await;
+    assert_eq!("email", obtain_result_by_id("result"));
+    assert_eq!("/base/settings/profile/email", pathname());
+}
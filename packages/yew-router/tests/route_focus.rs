@@ -0,0 +1,98 @@
+// TODO: remove the cfg after wasm-bindgen-test stops emitting the function unconditionally
+#![cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+
+use std::time::Duration;
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+use yew::functional::function_component;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew_router::history::{AnyHistory, History, MemoryHistory};
+use yew_router::prelude::*;
+
+mod utils;
+use utils::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, Copy, PartialEq, Routable)]
+enum Routes {
+    #[at("/")]
+    Home,
+    #[at("/about")]
+    About,
+}
+
+#[function_component]
+fn Page() -> Html {
+    let navigator = use_navigator().unwrap();
+    let onclick = Callback::from(move |_| navigator.push(&Routes::About));
+
+    html! {
+        <Switch<Routes> render={move |route| match route {
+            Routes::Home => html! {
+                <>
+                    <h1>{ "Home" }</h1>
+                    <button id="go" onclick={onclick.clone()}>{ "About" }</button>
+                </>
+            },
+            Routes::About => html! { <h1 id="about">{ "About" }</h1> },
+        }} />
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct RootProps {
+    history: AnyHistory,
+}
+
+#[function_component]
+fn Root(props: &RootProps) -> Html {
+    html! {
+        <Router history={props.history.clone()}>
+            <RouteFocus />
+            <main>
+                <Page />
+            </main>
+        </Router>
+    }
+}
+
+fn active_element_id() -> Option<String> {
+    gloo::utils::document().active_element().map(|m| m.id())
+}
+
+#[test]
+async fn focus_follows_navigation() {
+    let history = AnyHistory::from(MemoryHistory::new());
+    yew::Renderer::<Root>::with_root_and_props(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+        RootProps {
+            history: history.clone(),
+        },
+    )
+    .render();
+
+    sleep(Duration::ZERO).await;
+
+    gloo::utils::document()
+        .get_element_by_id("go")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>()
+        .focus()
+        .unwrap();
+    click("#go");
+    sleep(Duration::ZERO).await;
+
+    // The heading of the new page is focused.
+    assert_eq!(active_element_id().as_deref(), Some("about"));
+    let heading = gloo::utils::document().get_element_by_id("about").unwrap();
+    assert_eq!(heading.get_attribute("tabindex").as_deref(), Some("-1"));
+
+    history.back();
+    sleep(Duration::ZERO).await;
+
+    // The button that triggered the navigation is focused again.
+    assert_eq!(active_element_id().as_deref(), Some("go"));
+}
@@ -0,0 +1,266 @@
+//! A development-mode audit reporting common accessibility problems of the rendered elements.
+//!
+//! In debug builds, the elements rendered by every component are audited once they are committed
+//! to the DOM. Each problem is reported once, as a `WARN` level [`tracing`](https://docs.rs/tracing)
+//! event carrying the name of the component, and with the `devtools` feature, as an `a11y`
+//! message of the [`devtools`](crate::devtools) protocol. Problems never fail the render, and
+//! release builds don't audit anything.
+//!
+//! The [`Rule`]s are checked on the elements rendered by a component, not on the ones of its
+//! child components, which are audited when they are rendered themselves. [`audit`] checks them
+//! on any [`Html`], like in a test.
+//!
+//! This module requires the `a11y_audit` feature.
+
+#[cfg(debug_assertions)]
+use std::cell::RefCell;
+#[cfg(debug_assertions)]
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::html::Html;
+use crate::virtual_dom::{Listeners, VNode, VTag};
+
+/// A check of the audit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// An `<img>` has no `alt` attribute. Decorative images must have an empty one.
+    ImageWithoutAlt,
+    /// An element that isn't interactive, like a `<div>`, has a click listener but no `role` or
+    /// no `tabindex`, so it can't be reached and activated with the keyboard.
+    NonInteractiveClick,
+    /// A form field has no label: it isn't in a `<label>`, and has no `aria-label`,
+    /// `aria-labelledby`, `title`, or `id` a `<label for>` could refer to.
+    MissingLabel,
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ImageWithoutAlt => "image without an `alt` attribute",
+            Self::NonInteractiveClick => {
+                "click listener on a non-interactive element without `role` and `tabindex`"
+            }
+            Self::MissingLabel => "form field without a label",
+        })
+    }
+}
+
+/// A problem found by the audit.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Finding {
+    /// The rule that isn't followed.
+    pub rule: Rule,
+    /// The element, described by its tag, `id` and `class`, like `div#menu.item`.
+    pub element: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}>: {}", self.element, self.rule)
+    }
+}
+
+/// The elements reacting to clicks and reachable with the keyboard by themselves.
+const INTERACTIVE: &[&str] = &[
+    "a", "button", "details", "input", "label", "option", "select", "summary", "textarea",
+];
+
+/// Returns the problems of the elements of `html`, without the ones of its components.
+pub fn audit(html: &Html) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    walk(html, false, &mut findings);
+    findings
+}
+
+fn walk(node: &VNode, in_label: bool, findings: &mut Vec<Finding>) {
+    match node {
+        VNode::VTag(tag) => {
+            check(tag, in_label, findings);
+            if let Some(children) = tag.children() {
+                let in_label = in_label || tag.tag().eq_ignore_ascii_case("label");
+                walk(children, in_label, findings);
+            }
+        }
+        VNode::VList(list) => {
+            for child in list.iter() {
+                walk(child, in_label, findings);
+            }
+        }
+        VNode::VPortal(portal) => walk(&portal.node, false, findings),
+        VNode::VSuspense(suspense) => {
+            let shown = if suspense.suspended {
+                &suspense.fallback
+            } else {
+                &suspense.children
+            };
+            walk(shown, in_label, findings);
+        }
+        VNode::VText(_) | VNode::VComp(_) | VNode::VRef(_) | VNode::VRaw(_) => {}
+    }
+}
+
+fn check(tag: &VTag, in_label: bool, findings: &mut Vec<Finding>) {
+    let name = tag.tag().to_ascii_lowercase();
+    let has = |attr: &str| tag.attributes.iter().any(|(k, _)| k == attr);
+    let mut report = |rule| {
+        findings.push(Finding {
+            rule,
+            element: describe(&name, tag),
+        })
+    };
+
+    if name == "img" && !has("alt") {
+        report(Rule::ImageWithoutAlt);
+    }
+
+    let clickable = match tag.listeners {
+        Listeners::Pending(ref listeners) => listeners
+            .iter()
+            .flatten()
+            .any(|m| m.kind().type_name() == "click"),
+        Listeners::None => false,
+    };
+    if clickable && !INTERACTIVE.contains(&name.as_str()) && !(has("role") && has("tabindex")) {
+        report(Rule::NonInteractiveClick);
+    }
+
+    let field = match name.as_str() {
+        "select" | "textarea" => true,
+        "input" => !tag.attributes.iter().any(|(k, v)| {
+            k == "type"
+                && matches!(
+                    v.to_ascii_lowercase().as_str(),
+                    "hidden" | "submit" | "reset" | "button" | "image"
+                )
+        }),
+        _ => false,
+    };
+    let labelled = in_label
+        || ["aria-label", "aria-labelledby", "title", "id"]
+            .iter()
+            .any(|m| has(m));
+    if field && !labelled {
+        report(Rule::MissingLabel);
+    }
+}
+
+/// Describes `tag` like a CSS selector, like `div#menu.item.active`.
+fn describe(name: &str, tag: &VTag) -> String {
+    let mut element = name.to_owned();
+    for (attr, value) in tag.attributes.iter() {
+        match attr {
+            "id" => {
+                element.push('#');
+                element.push_str(value);
+            }
+            "class" => {
+                for class in value.split_whitespace() {
+                    element.push('.');
+                    element.push_str(class);
+                }
+            }
+            _ => {}
+        }
+    }
+    element
+}
+
+#[cfg(debug_assertions)]
+thread_local! {
+    /// The problems already reported for each mounted component.
+    static REPORTED: RefCell<HashMap<usize, Vec<Finding>>> = RefCell::default();
+}
+
+/// Audits the elements committed by the component `id`, reporting the problems that weren't
+/// reported for it yet.
+#[cfg(debug_assertions)]
+pub(crate) fn committed(id: usize, name: &'static str, html: &Html, in_label: bool) {
+    let mut findings = Vec::new();
+    walk(html, in_label, &mut findings);
+
+    REPORTED.with(|reported| {
+        let mut reported = reported.borrow_mut();
+        if findings.is_empty() && !reported.contains_key(&id) {
+            return;
+        }
+        let known = reported.entry(id).or_default();
+        let new = findings
+            .iter()
+            .filter(|m| !known.contains(m))
+            .collect::<Vec<_>>();
+        for finding in new.iter() {
+            tracing::warn!(component = name, "accessibility problem: {finding}");
+        }
+        #[cfg(feature = "devtools")]
+        if !new.is_empty() {
+            crate::devtools::a11y_findings(id, &new);
+        }
+        // Problems that were fixed are reported again if they come back.
+        *known = findings;
+    });
+}
+
+/// Forgets the problems of the component `id`, once it is unmounted.
+#[cfg(debug_assertions)]
+pub(crate) fn unmounted(id: usize) {
+    let _ = REPORTED.try_with(|m| m.borrow_mut().remove(&id));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn rules(html: Html) -> Vec<Rule> {
+        audit(&html).into_iter().map(|m| m.rule).collect()
+    }
+
+    #[test]
+    fn reports_images_without_alt() {
+        assert_eq!(
+            rules(html! { <img src="/a.png" /> }),
+            [Rule::ImageWithoutAlt]
+        );
+        assert!(rules(html! { <img src="/a.png" alt="" /> }).is_empty());
+    }
+
+    #[test]
+    fn reports_clicks_on_non_interactive_elements() {
+        let onclick = Callback::from(|_: MouseEvent| ());
+
+        let findings = audit(&html! { <div id="card" class="a b" onclick={onclick.clone()} /> });
+        assert_eq!(
+            findings,
+            [Finding {
+                rule: Rule::NonInteractiveClick,
+                element: "div#card.a.b".into(),
+            }]
+        );
+        assert_eq!(
+            rules(html! { <span role="button" onclick={onclick.clone()} /> }),
+            [Rule::NonInteractiveClick]
+        );
+        assert!(
+            rules(html! { <span role="button" tabindex="0" onclick={onclick.clone()} /> })
+                .is_empty()
+        );
+        assert!(rules(html! { <button onclick={onclick} /> }).is_empty());
+    }
+
+    #[test]
+    fn reports_fields_without_label() {
+        assert_eq!(
+            rules(html! { <div><input /><textarea /><input type="hidden" /></div> }),
+            [Rule::MissingLabel, Rule::MissingLabel]
+        );
+        assert!(rules(html! {
+            <>
+                <label>{ "Name" }<input /></label>
+                <input aria-label="Search" />
+                <select id="country" />
+            </>
+        })
+        .is_empty());
+    }
+}
@@ -13,6 +13,8 @@ use crate::html::{BaseComponent, Scope, Scoped};
 pub struct AppHandle<COMP: BaseComponent> {
     /// `Scope` holder
     pub(crate) scope: Scope<COMP>,
+    /// The root of the bundle of the app, delegating its events.
+    root: BSubtree,
 }
 
 impl<COMP> AppHandle<COMP>
@@ -28,12 +30,19 @@ where
         name = "mount",
         skip(props),
     )]
-    pub(crate) fn mount_with_props(host: Element, props: Rc<COMP::Properties>) -> Self {
+    pub(crate) fn mount_with_props(
+        host: Element,
+        props: Rc<COMP::Properties>,
+        strict: bool,
+        delegate_events: bool,
+    ) -> Self {
         clear_element(&host);
+        let hosting_root = BSubtree::create_root(&host);
+        hosting_root.set_direct_listeners(!delegate_events);
         let app = Self {
-            scope: Scope::new(None),
+            scope: Scope::new_root(strict),
+            root: hosting_root.clone(),
         };
-        let hosting_root = BSubtree::create_root(&host);
         app.scope.mount_in_place(
             hosting_root,
             host,
@@ -60,13 +69,18 @@ where
         self.scope.reuse(Rc::new(new_props), DomSlot::at_end())
     }
 
-    /// Schedule the app for destruction
+    /// Schedule the app for destruction.
+    ///
+    /// Its components are destroyed and removed from the root element, and the listeners Yew added
+    /// to the root and to the hosts of its portals are removed, so that the root element can be
+    /// reused, by another app or by other code. The other apps of the page are left untouched.
     #[tracing::instrument(
         level = tracing::Level::DEBUG,
         skip_all,
     )]
     pub fn destroy(self) {
-        self.scope.destroy(false)
+        self.scope.destroy(false);
+        self.root.unmount();
     }
 }
 
@@ -102,13 +116,19 @@ mod feat_hydration {
             name = "hydrate",
             skip(props),
         )]
-        pub(crate) fn hydrate_with_props(host: Element, props: Rc<COMP::Properties>) -> Self {
-            let app = Self {
-                scope: Scope::new(None),
-            };
-
+        pub(crate) fn hydrate_with_props(
+            host: Element,
+            props: Rc<COMP::Properties>,
+            strict: bool,
+            delegate_events: bool,
+        ) -> Self {
             let mut fragment = Fragment::collect_children(&host);
             let hosting_root = BSubtree::create_root(&host);
+            hosting_root.set_direct_listeners(!delegate_events);
+            let app = Self {
+                scope: Scope::new_root(strict),
+                root: hosting_root.clone(),
+            };
 
             app.scope.hydrate_in_place(
                 hosting_root,
@@ -1,6 +1,9 @@
 //! This module defines the `ContextProvider` component.
 
+use std::any::{Any, TypeId};
 use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
 
 use slab::Slab;
 use suppaftp::FtpStream;
@@ -118,3 +121,88 @@ impl<T: Clone + PartialEq + 'static> Component for ContextProvider<T> {
         ctx.props().children.clone()
     }
 }
+
+type DefaultFactory = Arc<dyn Fn() -> Box<dyn Any> + Send + Sync>;
+
+static CONTEXT_DEFAULTS: Mutex<BTreeMap<TypeId, DefaultFactory>> = Mutex::new(BTreeMap::new());
+
+/// Registers `factory` as the default value of the context of type `T`.
+///
+/// When no [`ContextProvider<T>`] is in scope, [`use_context`](crate::functional::use_context) and
+/// [`use_context_selector`](crate::functional::use_context_selector) fall back to a value returned
+/// by `factory` instead of `None`. The factory is called lazily, once per consumer that finds no
+/// provider. Registering another factory for the same type replaces the previous one.
+///
+/// Defaults are registered for the whole program, so that they also apply when rendering on other
+/// threads with server-side rendering.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::context::register_context_default;
+/// use yew::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Theme {
+///     foreground: String,
+/// }
+///
+/// #[function_component]
+/// fn ThemedText() -> Html {
+///     // Never `None`, even without a `ContextProvider<Theme>`.
+///     let theme = use_context::<Theme>().unwrap();
+///
+///     html! { <p style={format!("color: {};", theme.foreground)}>{ "Hello" }</p> }
+/// }
+///
+/// fn main() {
+///     register_context_default(|| Theme {
+///         foreground: "#000000".to_owned(),
+///     });
+///     # if false {
+///     yew::Renderer::<ThemedText>::new().render();
+///     # }
+/// }
+/// ```
+pub fn register_context_default<T, F>(factory: F)
+where
+    T: Clone + PartialEq + 'static,
+    F: Fn() -> T + Send + Sync + 'static,
+{
+    let factory: DefaultFactory = Arc::new(move || Box::new(factory()));
+    CONTEXT_DEFAULTS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(TypeId::of::<T>(), factory);
+}
+
+/// Returns the registered default value of the context of type `T`, if any.
+pub(crate) fn context_default<T: 'static>() -> Option<T> {
+    let factory = CONTEXT_DEFAULTS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&TypeId::of::<T>())
+        .cloned()?;
+
+    // The lock is released before calling the factory, which may register other defaults.
+    factory().downcast::<T>().ok().map(|m| *m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registered_defaults_are_created_lazily() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Locale(&'static str);
+
+        assert_eq!(context_default::<Locale>(), None);
+
+        register_context_default(|| Locale("en"));
+        assert_eq!(context_default::<Locale>(), Some(Locale("en")));
+
+        register_context_default(|| Locale("fr"));
+        assert_eq!(context_default::<Locale>(), Some(Locale("fr")));
+    }
+}
@@ -0,0 +1,792 @@
+//! This module contains stylesheets scoped to the components using them.
+//!
+//! A [`StyleSheet`], usually declared with [`css!`], is applied by a component with [`use_style`],
+//! which returns the class to set on the elements it styles. The name of this class is a hash of
+//! the stylesheet, and the rules of the stylesheet are scoped to it:
+//!
+//! - declarations outside of any block apply to the elements with the class,
+//! - `&` in a selector stands for the class, like in `&:hover` or `li > &`,
+//! - other selectors match the descendants of the elements with the class.
+//!
+//! Rules nested in `@media`, `@supports`, `@container` and `@layer` blocks are scoped the same
+//! way.
+//!
+//! In the browser, a stylesheet is inserted into the `<head>` of the document when the first
+//! component using it is mounted, and removed when the last one is unmounted. During server-side
+//! rendering, stylesheets are collected by a `StyleCollector` provided with `StyleProvider`, and
+//! rendered into the `<head>` of the page once the body has been rendered. The global stylesheets
+//! of the application can be registered with the collector too, which then only inlines their
+//! rules matching the rendered elements and defers the others. The stylesheets rendered on the
+//! server are reused when the application is hydrated.
+
+use implicit_clone::ImplicitClone;
+
+#[cfg(feature = "ssr")]
+use crate::functional::use_context;
+#[cfg(feature = "csr")]
+use crate::functional::use_effect_with;
+use crate::functional::{hook, use_memo};
+use crate::html::Classes;
+use crate::virtual_dom::AttrValue;
+
+/// Declares a [`StyleSheet`] from a string literal.
+///
+/// The braces of the blocks and the quotes of the strings of the stylesheet are checked at compile
+/// time.
+///
+/// # Example
+///
+/// ```
+/// use yew::css::{css, use_style};
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Card() -> Html {
+///     let class = use_style(css!(
+///         r#"
+///         padding: 1rem;
+///         border-radius: 4px;
+///         &:hover { box-shadow: 0 2px 8px rgba(0, 0, 0, 0.2); }
+///         h2 { margin-top: 0; }
+///         "#
+///     ));
+///
+///     html! {
+///         <div {class}>
+///             <h2>{ "Title" }</h2>
+///         </div>
+///     }
+/// }
+/// ```
+pub use yew_macro::css;
+
+/// The attribute set on the `<style>` tags managed by this module.
+#[cfg(any(feature = "csr", feature = "ssr"))]
+const MANAGED_ATTR: &str = "data-yew-style";
+
+/// A stylesheet scoped to a class, cheap to clone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyleSheet {
+    css: AttrValue,
+}
+
+impl ImplicitClone for StyleSheet {}
+
+impl StyleSheet {
+    /// Creates a stylesheet from its source.
+    pub fn new(css: impl Into<AttrValue>) -> Self {
+        Self { css: css.into() }
+    }
+
+    /// Creates a stylesheet from a static source. This is used by [`css!`].
+    pub const fn from_static(css: &'static str) -> Self {
+        Self {
+            css: AttrValue::Static(css),
+        }
+    }
+
+    /// Returns the source of the stylesheet.
+    pub fn source(&self) -> &str {
+        &self.css
+    }
+
+    /// Returns the class the stylesheet is scoped to.
+    ///
+    /// It only depends on the source of the stylesheet, so that the server and the client agree
+    /// on it.
+    pub fn class_name(&self) -> AttrValue {
+        // FNV-1a
+        let hash = self.css.bytes().fold(0x811c_9dc5_u32, |hash, b| {
+            (hash ^ u32::from(b)).wrapping_mul(0x0100_0193)
+        });
+        format!("yew-{hash:08x}").into()
+    }
+
+    /// Returns the stylesheet with its rules scoped to [`class_name`](Self::class_name).
+    pub fn scoped(&self) -> String {
+        let class = self.class_name();
+        let mut out = String::with_capacity(self.css.len() + 2 * class.len());
+        scope_rules(&self.css, &class, &mut out);
+        out
+    }
+}
+
+impl From<&'static str> for StyleSheet {
+    fn from(css: &'static str) -> Self {
+        Self::from_static(css)
+    }
+}
+
+impl From<String> for StyleSheet {
+    fn from(css: String) -> Self {
+        Self::new(css)
+    }
+}
+
+/// Returns the index of the first character of `s` matching `pred` outside of strings and
+/// parentheses. `pred` is also given the depth of the blocks the character is in.
+fn find_top_level(s: &str, mut pred: impl FnMut(char, usize) -> bool) -> Option<usize> {
+    let mut blocks = 0_usize;
+    let mut parens = 0_usize;
+    let mut quote = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => parens += 1,
+            (None, ')') => parens = parens.saturating_sub(1),
+            (None, c) if parens == 0 => {
+                if pred(c, blocks) {
+                    return Some(i);
+                }
+                match c {
+                    '{' => blocks += 1,
+                    '}' => blocks = blocks.saturating_sub(1),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits the first statement of `css`, returning its prelude or declaration, the content of its
+/// block if it has one, and the rest of the stylesheet.
+fn split_statement(css: &str) -> (&str, Option<&str>, &str) {
+    let Some(i) = find_top_level(css, |c, _| c == ';' || c == '{') else {
+        return (css, None, "");
+    };
+    if css[i..].starts_with(';') {
+        return (&css[..i], None, &css[i + 1..]);
+    }
+
+    let body = &css[i + 1..];
+    match find_top_level(body, |c, blocks| c == '}' && blocks == 0) {
+        Some(end) => (&css[..i], Some(&body[..end]), &body[end + 1..]),
+        None => (&css[..i], Some(body), ""),
+    }
+}
+
+/// Returns whether the at-rule `head` contains rules, like `@media`.
+fn is_grouping_rule(head: &str) -> bool {
+    let name = head[1..]
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+        .next()
+        .unwrap_or_default();
+    matches!(name, "media" | "supports" | "container" | "layer")
+}
+
+fn scope_rules(css: &str, class: &str, out: &mut String) {
+    let mut declarations = String::new();
+    let mut rules = String::new();
+
+    let mut rest = css;
+    while !rest.trim().is_empty() {
+        let (head, body, tail) = split_statement(rest);
+        rest = tail;
+        let head = head.trim();
+
+        match body {
+            None if head.is_empty() => {}
+            // statements like `@import`
+            None if head.starts_with('@') => {
+                rules.push_str(head);
+                rules.push(';');
+            }
+            None => {
+                declarations.push_str(head);
+                declarations.push(';');
+            }
+            Some(body) if head.starts_with('@') => {
+                rules.push_str(head);
+                rules.push('{');
+                if is_grouping_rule(head) {
+                    scope_rules(body, class, &mut rules);
+                } else {
+                    // like `@keyframes` and `@font-face`
+                    rules.push_str(body.trim());
+                }
+                rules.push('}');
+            }
+            Some(body) => {
+                scope_selectors(head, class, &mut rules);
+                rules.push('{');
+                rules.push_str(body.trim());
+                rules.push('}');
+            }
+        }
+    }
+
+    if !declarations.is_empty() {
+        out.push('.');
+        out.push_str(class);
+        out.push('{');
+        out.push_str(&declarations);
+        out.push('}');
+    }
+    out.push_str(&rules);
+}
+
+fn scope_selectors(selectors: &str, class: &str, out: &mut String) {
+    let mut rest = selectors;
+    let mut first = true;
+    loop {
+        let end = find_top_level(rest, |c, _| c == ',').unwrap_or(rest.len());
+        let selector = rest[..end].trim();
+        if !first {
+            out.push(',');
+        }
+        first = false;
+
+        if selector.contains('&') {
+            out.push_str(&selector.replace('&', &format!(".{class}")));
+        } else {
+            out.push('.');
+            out.push_str(class);
+            out.push(' ');
+            out.push_str(selector);
+        }
+
+        match rest.get(end + 1..) {
+            Some(tail) => rest = tail,
+            None => break,
+        }
+    }
+}
+
+/// Applies a stylesheet for as long as the component is mounted, returning the class to set on
+/// the elements it styles.
+///
+/// See the [module documentation](self) for how the stylesheet is scoped.
+#[hook]
+pub fn use_style(sheet: StyleSheet) -> Classes {
+    let scoped = use_memo(sheet, |sheet| (sheet.class_name(), sheet.scoped()));
+
+    #[cfg(feature = "ssr")]
+    if let Some(collector) = use_context::<StyleCollector>() {
+        collector.insert(&scoped.0, &scoped.1);
+    }
+
+    #[cfg(feature = "csr")]
+    {
+        use_effect_with(scoped.clone(), |scoped| {
+            let class = scoped.0.clone();
+            feat_csr::acquire(&class, &scoped.1);
+            move || feat_csr::release(&class)
+        });
+    }
+
+    Classes::from(scoped.0.clone())
+}
+
+#[cfg(feature = "ssr")]
+mod feat_ssr {
+    use std::collections::HashSet;
+    use std::fmt::{self, Write};
+    use std::sync::{Arc, Mutex, MutexGuard};
+
+    use indexmap::IndexMap;
+
+    use super::*;
+    use crate::context::ContextProvider;
+    use crate::html::{Html, Properties};
+    use crate::{function_component, html};
+
+    /// Collects the stylesheets used by a component tree during server-side rendering.
+    ///
+    /// The collector is provided to the tree with [`StyleProvider`] and can be sent to the thread
+    /// rendering the application. Once the body has been rendered, [`render`](Self::render)
+    /// returns the `<style>` tags to insert into the `<head>` of the page.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use yew::css::{css, use_style, StyleCollector, StyleProvider};
+    /// use yew::prelude::*;
+    ///
+    /// #[function_component]
+    /// fn Badge() -> Html {
+    ///     let class = use_style(css!("color: teal;"));
+    ///     html! { <span {class}>{ "New" }</span> }
+    /// }
+    ///
+    /// #[derive(Properties, PartialEq)]
+    /// struct ServerAppProps {
+    ///     styles: StyleCollector,
+    /// }
+    ///
+    /// #[function_component]
+    /// fn ServerApp(props: &ServerAppProps) -> Html {
+    ///     html! {
+    ///         <StyleProvider collector={props.styles.clone()}>
+    ///             <Badge />
+    ///         </StyleProvider>
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let styles = StyleCollector::new();
+    /// let body = yew::ServerRenderer::<ServerApp>::with_props({
+    ///     let styles = styles.clone();
+    ///     move || ServerAppProps { styles }
+    /// })
+    /// .render()
+    /// .await;
+    ///
+    /// let page = format!(
+    ///     "<!DOCTYPE html><html><head>{}</head><body>{body}</body></html>",
+    ///     styles.render()
+    /// );
+    /// # assert!(page.contains("color: teal;"));
+    /// # }
+    /// ```
+    ///
+    /// # Critical CSS
+    ///
+    /// The global stylesheets of the application can be [registered](Self::register) with the
+    /// collector, and the collector given to the renderer with
+    /// [`ServerRenderer::styles`](crate::ServerRenderer::styles), which records the classes of
+    /// the rendered elements. [`render`](Self::render) then only inlines the rules of these
+    /// stylesheets matching the elements rendered so far, and
+    /// [`render_deferred`](Self::render_deferred) returns the rest of the styles, to be written at
+    /// the end of the `<body>` where they don't delay the first paint.
+    #[derive(Clone, Default)]
+    pub struct StyleCollector {
+        inner: Arc<Mutex<Inner>>,
+    }
+
+    #[derive(Default)]
+    struct Inner {
+        /// The scoped stylesheets used, by class.
+        sheets: IndexMap<String, String>,
+        /// The global stylesheets registered.
+        global: Vec<String>,
+        /// The classes of the rendered elements.
+        classes: HashSet<String>,
+        /// The number of scoped stylesheets and the classes whose styles were rendered by
+        /// `render`.
+        inlined: Option<(usize, HashSet<String>)>,
+    }
+
+    impl fmt::Debug for StyleCollector {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("StyleCollector")
+        }
+    }
+
+    impl PartialEq for StyleCollector {
+        fn eq(&self, other: &Self) -> bool {
+            Arc::ptr_eq(&self.inner, &other.inner)
+        }
+    }
+
+    impl StyleCollector {
+        /// Creates an empty collector.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        fn lock(&self) -> MutexGuard<'_, Inner> {
+            self.inner.lock().unwrap_or_else(|e| e.into_inner())
+        }
+
+        /// Registers a global stylesheet of the application, whose rules are only inlined by
+        /// [`render`](Self::render) when they match rendered elements.
+        pub fn register(self, css: impl Into<String>) -> Self {
+            self.lock().global.push(css.into());
+            self
+        }
+
+        pub(super) fn insert(&self, class: &str, css: &str) {
+            let mut inner = self.lock();
+            if !inner.sheets.contains_key(class) {
+                inner.sheets.insert(class.to_owned(), css.to_owned());
+            }
+        }
+
+        /// Records the classes of the elements of the rendered `html`.
+        pub(crate) fn record_classes(&self, html: &str) {
+            let mut inner = self.lock();
+            let mut rest = html;
+            while let Some(i) = rest.find(" class=\"") {
+                rest = &rest[i + 8..];
+                let end = rest.find('"').unwrap_or(rest.len());
+                for class in rest[..end].split_ascii_whitespace() {
+                    if !inner.classes.contains(class) {
+                        inner.classes.insert(class.to_owned());
+                    }
+                }
+                rest = &rest[end..];
+            }
+        }
+
+        /// Returns the classes of the stylesheets used by the component tree, in the order they
+        /// were first used.
+        pub fn classes(&self) -> Vec<String> {
+            self.lock().sheets.keys().cloned().collect()
+        }
+
+        /// Renders a `<style>` tag for each stylesheet used by the component tree, followed by
+        /// the rules of the registered stylesheets matching the rendered elements.
+        pub fn render(&self) -> String {
+            let mut inner = self.lock();
+            let mut s = String::new();
+            for (class, css) in inner.sheets.iter() {
+                write_style(&mut s, &format!(r#"{MANAGED_ATTR}="{class}""#), css);
+            }
+
+            let mut critical = String::new();
+            for css in inner.global.iter() {
+                split_critical(css, &inner.classes, &mut critical, &mut String::new());
+            }
+            if !critical.is_empty() {
+                write_style(&mut s, "data-yew-critical", &critical);
+            }
+
+            let inlined = (inner.sheets.len(), inner.classes.clone());
+            inner.inlined = Some(inlined);
+            s
+        }
+
+        /// Renders the styles that were not rendered by [`render`](Self::render): the stylesheets
+        /// used by components rendered after it, and the rules of the registered stylesheets that
+        /// it left out.
+        pub fn render_deferred(&self) -> String {
+            let inner = self.lock();
+            let (inlined_sheets, classes) = match inner.inlined {
+                Some((sheets, ref classes)) => (sheets, Some(classes)),
+                None => (0, None),
+            };
+
+            let mut s = String::new();
+            for (class, css) in inner.sheets.iter().skip(inlined_sheets) {
+                write_style(&mut s, &format!(r#"{MANAGED_ATTR}="{class}""#), css);
+            }
+
+            let deferred = match classes {
+                Some(classes) => {
+                    let mut deferred = String::new();
+                    for css in inner.global.iter() {
+                        split_critical(css, classes, &mut String::new(), &mut deferred);
+                    }
+                    deferred
+                }
+                None => inner.global.concat(),
+            };
+            if !deferred.is_empty() {
+                write_style(&mut s, "data-yew-deferred", &deferred);
+            }
+            s
+        }
+    }
+
+    fn write_style(s: &mut String, attrs: &str, css: &str) {
+        // `<\/` is the same as `</` in CSS, and can't close the tag.
+        let _ = write!(s, "<style {attrs}>{}</style>", css.replace("</", r"<\/"));
+    }
+
+    /// Splits the rules of `css` into the rules that can match elements with `classes`, and the
+    /// others.
+    ///
+    /// At-rules other than `@media` and the like are always kept with the critical rules.
+    fn split_critical(
+        css: &str,
+        classes: &HashSet<String>,
+        critical: &mut String,
+        deferred: &mut String,
+    ) {
+        let mut rest = css;
+        while !rest.trim().is_empty() {
+            let (head, body, tail) = split_statement(rest);
+            rest = tail;
+            let head = head.trim();
+
+            match body {
+                None if head.is_empty() => {}
+                // statements like `@import`
+                None => {
+                    critical.push_str(head);
+                    critical.push(';');
+                }
+                Some(body) if head.starts_with('@') && is_grouping_rule(head) => {
+                    let (mut inner_critical, mut inner_deferred) = (String::new(), String::new());
+                    split_critical(body, classes, &mut inner_critical, &mut inner_deferred);
+                    for (out, rules) in [
+                        (&mut *critical, inner_critical),
+                        (&mut *deferred, inner_deferred),
+                    ] {
+                        if !rules.is_empty() {
+                            out.push_str(head);
+                            out.push('{');
+                            out.push_str(&rules);
+                            out.push('}');
+                        }
+                    }
+                }
+                Some(body) => {
+                    let out = if head.starts_with('@') || matches_classes(head, classes) {
+                        &mut *critical
+                    } else {
+                        &mut *deferred
+                    };
+                    out.push_str(head);
+                    out.push('{');
+                    out.push_str(body.trim());
+                    out.push('}');
+                }
+            }
+        }
+    }
+
+    /// Returns whether one of the `selectors` only requires classes in `classes`.
+    ///
+    /// The classes in the arguments of pseudo-classes and in attribute selectors are ignored.
+    fn matches_classes(selectors: &str, classes: &HashSet<String>) -> bool {
+        let mut rest = selectors;
+        loop {
+            let end = find_top_level(rest, |c, _| c == ',').unwrap_or(rest.len());
+            let selector = &rest[..end];
+
+            let mut depth = 0_usize;
+            let mut matches = true;
+            for (i, c) in selector.char_indices() {
+                match c {
+                    '(' | '[' => depth += 1,
+                    ')' | ']' => depth = depth.saturating_sub(1),
+                    '.' if depth == 0 => {
+                        let class = &selector[i + 1..];
+                        let len = class
+                            .find(|c: char| !c.is_alphanumeric() && c != '-' && c != '_')
+                            .unwrap_or(class.len());
+                        matches &= classes.contains(&class[..len]);
+                    }
+                    _ => {}
+                }
+            }
+            if matches {
+                return true;
+            }
+
+            match rest.get(end + 1..) {
+                Some(tail) => rest = tail,
+                None => return false,
+            }
+        }
+    }
+
+    /// Properties for [`StyleProvider`].
+    #[derive(Debug, Properties, PartialEq)]
+    pub struct StyleProviderProps {
+        /// The collector receiving the stylesheets used by the children.
+        pub collector: StyleCollector,
+        /// The children of the provider.
+        #[prop_or_default]
+        pub children: Html,
+    }
+
+    /// Provides a [`StyleCollector`] to its children during server-side rendering.
+    #[function_component]
+    pub fn StyleProvider(props: &StyleProviderProps) -> Html {
+        html! {
+            <ContextProvider<StyleCollector> context={props.collector.clone()}>
+                {props.children.clone()}
+            </ContextProvider<StyleCollector>>
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+pub use feat_ssr::*;
+
+#[cfg(feature = "csr")]
+mod feat_csr {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use web_sys::Element;
+
+    use super::*;
+
+    thread_local! {
+        /// The `<style>` tags of the mounted stylesheets, with the number of components using them.
+        static SHEETS: RefCell<HashMap<String, (usize, Option<Element>)>> = RefCell::default();
+    }
+
+    pub(super) fn acquire(class: &str, css: &str) {
+        SHEETS.with(|m| {
+            let mut sheets = m.borrow_mut();
+            if let Some((count, _)) = sheets.get_mut(class) {
+                *count += 1;
+                return;
+            }
+
+            let document = gloo::utils::document();
+            // The stylesheet might have been rendered on the server.
+            let element = document
+                .query_selector(&format!(r#"style[{MANAGED_ATTR}="{class}"]"#))
+                .ok()
+                .flatten()
+                .or_else(|| {
+                    let style = document.create_element("style").ok()?;
+                    style.set_attribute(MANAGED_ATTR, class).ok()?;
+                    style.set_text_content(Some(css));
+                    document.head()?.append_child(&style).ok()?;
+                    Some(style)
+                });
+            sheets.insert(class.to_owned(), (1, element));
+        });
+    }
+
+    pub(super) fn release(class: &str) {
+        SHEETS.with(|m| {
+            let mut sheets = m.borrow_mut();
+            let Some((count, _)) = sheets.get_mut(class) else {
+                return;
+            };
+            *count -= 1;
+            if *count > 0 {
+                return;
+            }
+
+            if let Some((_, Some(element))) = sheets.remove(class) {
+                if let Some(parent) = element.parent_node() {
+                    let _ = parent.remove_child(&element);
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scopes_rules_to_the_class() {
+        let sheet = StyleSheet::new(
+            r#"
+            color: red;
+            &:hover, li > & { color: blue; }
+            a:is(.x, .y) { content: "{;}"; }
+            @media (max-width: 600px) { padding: 0; p { margin: 0; } }
+            @keyframes spin { from { rotate: 0deg; } to { rotate: 360deg; } }
+            "#,
+        );
+        let class = sheet.class_name();
+        assert!(class.starts_with("yew-"));
+        assert_eq!(
+            sheet.scoped().replace(class.as_str(), "C"),
+            ".C{color: red;}.C:hover,li > .C{color: blue;}.C a:is(.x, .y){content: \"{;}\";}\
+             @media (max-width: 600px){.C{padding: 0;}.C p{margin: 0;}}\
+             @keyframes spin{from { rotate: 0deg; } to { rotate: 360deg; }}"
+        );
+    }
+
+    #[test]
+    fn class_name_depends_on_the_source() {
+        let a = StyleSheet::from_static("color: red;");
+        assert_eq!(
+            a.class_name(),
+            StyleSheet::new("color: red;".to_owned()).class_name()
+        );
+        assert_ne!(
+            a.class_name(),
+            StyleSheet::from_static("color: blue;").class_name()
+        );
+    }
+}
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::LocalServerRenderer as ServerRenderer;
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn collects_used_stylesheets_once() {
+        #[function_component]
+        fn Badge() -> Html {
+            let class = use_style(css!("color: teal;"));
+            html! { <span {class}>{ "New" }</span> }
+        }
+
+        #[derive(Properties, PartialEq)]
+        struct AppProps {
+            styles: StyleCollector,
+        }
+
+        #[function_component]
+        fn App(props: &AppProps) -> Html {
+            html! {
+                <StyleProvider collector={props.styles.clone()}>
+                    <Badge />
+                    <Badge />
+                </StyleProvider>
+            }
+        }
+
+        let class = StyleSheet::from_static("color: teal;").class_name();
+        let styles = StyleCollector::new();
+        let body = ServerRenderer::<App>::with_props(AppProps {
+            styles: styles.clone(),
+        })
+        .hydratable(false)
+        .render()
+        .await;
+
+        assert_eq!(
+            body,
+            format!(r#"<span class="{class}">New</span><span class="{class}">New</span>"#)
+        );
+        assert_eq!(styles.classes(), [class.to_string()]);
+        assert_eq!(
+            styles.render(),
+            format!(r#"<style data-yew-style="{class}">.{class}{{color: teal;}}</style>"#)
+        );
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn inlines_critical_rules() {
+        #[function_component]
+        fn App() -> Html {
+            html! { <nav class="nav open">{ "Menu" }</nav> }
+        }
+
+        let styles = StyleCollector::new().register(
+            r#"
+            @import url("fonts.css");
+            body { margin: 0; }
+            .nav.open, .modal { display: block; }
+            .footer { color: gray; }
+            a[href$=".pdf"] { color: red; }
+            @media (max-width: 600px) { .nav { padding: 0; } .footer { display: none; } }
+            "#,
+        );
+        ServerRenderer::<App>::new()
+            .hydratable(false)
+            .styles(styles.clone())
+            .render()
+            .await;
+
+        assert_eq!(
+            styles.render(),
+            "<style data-yew-critical>@import url(\"fonts.css\");body{margin: 0;}\
+             .nav.open, .modal{display: block;}a[href$=\".pdf\"]{color: red;}\
+             @media (max-width: 600px){.nav{padding: 0;}}</style>"
+        );
+        assert_eq!(
+            styles.render_deferred(),
+            "<style data-yew-deferred>.footer{color: gray;}\
+             @media (max-width: 600px){.footer{display: none;}}</style>"
+        );
+    }
+}
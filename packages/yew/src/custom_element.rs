@@ -0,0 +1,566 @@
+//! TypeScript definitions for components exported as custom elements.
+//!
+//! Deriving [`Properties`] with a `#[custom_element("tag-name")]` attribute implements
+//! [`CustomElementProperties`] for the props struct. The resulting [`CustomElementDescriptor`]
+//! renders a `.d.ts` file describing the attributes, properties and events of the element, as well
+//! as a small React wrapper component, so that TypeScript consumers get typed usage of elements
+//! built with Yew.
+//!
+//! Props map onto the element as follows:
+//!
+//! - strings, numbers and booleans are exposed both as properties and as (kebab-case) attributes,
+//! - other values are exposed as properties only,
+//! - [`Callback`]s are exposed as events; the event payload is passed as the `detail` of a
+//!   `CustomEvent` and the `on` prefix of the prop name is dropped,
+//! - [`Html`] children are left to the light DOM of the element.
+//!
+//! # Example
+//!
+//! ```
+//! use yew::custom_element::CustomElementProperties;
+//! use yew::prelude::*;
+//!
+//! #[derive(Properties, PartialEq)]
+//! #[custom_element("my-counter")]
+//! pub struct CounterProps {
+//!     /// The value the counter starts with.
+//!     pub initial_value: i32,
+//!     #[prop_or_default]
+//!     pub on_change: Callback<i32>,
+//! }
+//!
+//! let descriptor = CounterProps::custom_element();
+//! let definitions = descriptor.to_typescript_definitions();
+//! assert!(definitions.contains("initialValue: number;"));
+//! assert!(definitions.contains("\"change\": CustomEvent<number>;"));
+//! assert!(definitions.contains("\"my-counter\": MyCounterElement;"));
+//! ```
+//!
+//! [`Properties`]: crate::html::Properties
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::rc::Rc;
+
+use crate::callback::Callback;
+use crate::html::{Html, Properties};
+use crate::virtual_dom::AttrValue;
+
+/// How a prop is exposed on the custom element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TsKind {
+    /// Exposed as a property and reflected as an attribute.
+    Attribute,
+    /// Exposed as a property only.
+    Property,
+    /// Exposed as a `CustomEvent` carrying the TypeScript type as its `detail`.
+    Event,
+    /// Rendered from the children of the element.
+    Children,
+}
+
+/// A Rust type that can be described in TypeScript.
+///
+/// Every field of props deriving a custom element must implement this trait.
+pub trait TsType {
+    /// The TypeScript type of the value.
+    ///
+    /// For events, this is the type of the event `detail`.
+    fn ts_type() -> String;
+
+    /// How values of this type are exposed on the element.
+    fn kind() -> TsKind {
+        TsKind::Property
+    }
+}
+
+macro_rules! impl_ts_type {
+    ($ts:literal, $kind:ident => $($ty:ty),+ $(,)?) => {
+        $(
+            impl TsType for $ty {
+                fn ts_type() -> String {
+                    $ts.to_string()
+                }
+
+                fn kind() -> TsKind {
+                    TsKind::$kind
+                }
+            }
+        )+
+    };
+}
+
+impl_ts_type!("string", Attribute => String, &'static str, AttrValue, Rc<str>, char);
+impl_ts_type!("boolean", Attribute => bool);
+impl_ts_type!(
+    "number", Attribute =>
+    i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64,
+);
+impl_ts_type!("undefined", Property => ());
+
+impl<T: TsType> TsType for Option<T> {
+    fn ts_type() -> String {
+        match T::kind() {
+            TsKind::Event | TsKind::Children => T::ts_type(),
+            _ => format!("{} | undefined", T::ts_type()),
+        }
+    }
+
+    fn kind() -> TsKind {
+        T::kind()
+    }
+}
+
+impl<T: TsType> TsType for Vec<T> {
+    fn ts_type() -> String {
+        array_type::<T>()
+    }
+}
+
+impl<T: TsType> TsType for Rc<[T]> {
+    fn ts_type() -> String {
+        array_type::<T>()
+    }
+}
+
+impl<K: TsType, V: TsType> TsType for HashMap<K, V> {
+    fn ts_type() -> String {
+        format!("Record<{}, {}>", K::ts_type(), V::ts_type())
+    }
+}
+
+impl<K: TsType, V: TsType> TsType for BTreeMap<K, V> {
+    fn ts_type() -> String {
+        format!("Record<{}, {}>", K::ts_type(), V::ts_type())
+    }
+}
+
+impl<IN: TsType, OUT> TsType for Callback<IN, OUT> {
+    fn ts_type() -> String {
+        IN::ts_type()
+    }
+
+    fn kind() -> TsKind {
+        TsKind::Event
+    }
+}
+
+impl TsType for Html {
+    fn ts_type() -> String {
+        "React.ReactNode".to_string()
+    }
+
+    fn kind() -> TsKind {
+        TsKind::Children
+    }
+}
+
+fn array_type<T: TsType>() -> String {
+    let inner = T::ts_type();
+    if inner.contains(' ') {
+        format!("({inner})[]")
+    } else {
+        format!("{inner}[]")
+    }
+}
+
+/// Props of a component exported as a custom element.
+///
+/// This trait is implemented by `#[derive(Properties)]` when the struct carries a
+/// `#[custom_element("tag-name")]` attribute.
+pub trait CustomElementProperties: Properties {
+    /// Describes the element for the generated TypeScript definitions.
+    fn custom_element() -> CustomElementDescriptor;
+}
+
+/// A single prop of a custom element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDescriptor {
+    /// The name of the Rust field.
+    pub name: &'static str,
+    /// The TypeScript type of the field, see [`TsType::ts_type`].
+    pub ts_type: String,
+    /// How the field is exposed on the element.
+    pub kind: TsKind,
+    /// Whether the prop must be provided.
+    pub required: bool,
+    /// The doc comment of the field.
+    pub docs: &'static str,
+}
+
+impl FieldDescriptor {
+    /// The name of the JavaScript property.
+    pub fn property_name(&self) -> String {
+        camel_case(self.name)
+    }
+
+    /// The name of the HTML attribute.
+    pub fn attribute_name(&self) -> String {
+        self.name.replace('_', "-")
+    }
+
+    /// The type of the dispatched event.
+    pub fn event_name(&self) -> String {
+        let name = self.name.strip_prefix("on").unwrap_or(self.name);
+        name.trim_start_matches('_').replace('_', "-")
+    }
+
+    /// The name of the React prop listening to the event.
+    pub fn event_prop_name(&self) -> String {
+        format!("on{}", pascal_case(&self.event_name().replace('-', "_")))
+    }
+}
+
+/// Describes the attributes, properties and events of a custom element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomElementDescriptor {
+    tag_name: &'static str,
+    fields: Vec<FieldDescriptor>,
+}
+
+impl CustomElementDescriptor {
+    /// Creates a descriptor for an element without any props.
+    pub fn new(tag_name: &'static str) -> Self {
+        Self {
+            tag_name,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Adds a prop of type `T`.
+    pub fn field<T: TsType>(
+        mut self,
+        name: &'static str,
+        required: bool,
+        docs: &'static str,
+    ) -> Self {
+        self.fields.push(FieldDescriptor {
+            name,
+            ts_type: T::ts_type(),
+            kind: T::kind(),
+            required,
+            docs,
+        });
+        self
+    }
+
+    /// The tag name of the element.
+    pub fn tag_name(&self) -> &'static str {
+        self.tag_name
+    }
+
+    /// The props of the element.
+    pub fn fields(&self) -> &[FieldDescriptor] {
+        &self.fields
+    }
+
+    /// The name of the TypeScript interface of the element, e.g. `MyCounterElement`.
+    pub fn interface_name(&self) -> String {
+        format!("{}Element", self.component_name())
+    }
+
+    /// The name of the React wrapper component, e.g. `MyCounter`.
+    pub fn component_name(&self) -> String {
+        pascal_case(&self.tag_name.replace('-', "_"))
+    }
+
+    fn fields_of(&self, kinds: &[TsKind]) -> impl Iterator<Item = &FieldDescriptor> + '_ {
+        let kinds = kinds.to_vec();
+        self.fields.iter().filter(move |m| kinds.contains(&m.kind))
+    }
+
+    /// Renders the contents of a `.d.ts` file for the element.
+    pub fn to_typescript_definitions(&self) -> String {
+        let interface = self.interface_name();
+        let mut out = String::new();
+
+        out.push_str("// Generated from the `Properties` of a Yew component. Do not edit.\n\n");
+
+        writeln!(
+            out,
+            "export interface {interface}EventMap extends HTMLElementEventMap {{"
+        )
+        .unwrap();
+        for field in self.fields_of(&[TsKind::Event]) {
+            write_docs(&mut out, field.docs);
+            writeln!(
+                out,
+                "  \"{}\": CustomEvent<{}>;",
+                field.event_name(),
+                field.ts_type
+            )
+            .unwrap();
+        }
+        out.push_str("}\n\n");
+
+        writeln!(out, "export interface {interface} extends HTMLElement {{").unwrap();
+        for field in self.fields_of(&[TsKind::Attribute, TsKind::Property]) {
+            write_docs(&mut out, field.docs);
+            writeln!(
+                out,
+                "  {}{}: {};",
+                field.property_name(),
+                if field.required { "" } else { "?" },
+                field.ts_type
+            )
+            .unwrap();
+        }
+        for method in ["addEventListener", "removeEventListener"] {
+            writeln!(
+                out,
+                "  {method}<K extends keyof {interface}EventMap>(type: K, listener: (this: \
+                 {interface}, ev: {interface}EventMap[K]) => any, options?: boolean | \
+                 EventListenerOptions): void;"
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "  {method}(type: string, listener: EventListenerOrEventListenerObject, options?: \
+                 boolean | EventListenerOptions): void;"
+            )
+            .unwrap();
+        }
+        out.push_str("}\n\n");
+
+        writeln!(out, "export interface {interface}Attributes {{").unwrap();
+        for field in self.fields_of(&[TsKind::Attribute]) {
+            write_docs(&mut out, field.docs);
+            writeln!(out, "  \"{}\"?: string;", field.attribute_name()).unwrap();
+        }
+        out.push_str("}\n\n");
+
+        writeln!(
+            out,
+            "declare global {{\n  interface HTMLElementTagNameMap {{\n    \"{}\": {interface};\n  \
+             }}\n}}",
+            self.tag_name
+        )
+        .unwrap();
+
+        out
+    }
+
+    /// Renders a React component wrapping the element.
+    ///
+    /// Properties are assigned to the element directly and events are subscribed to with
+    /// `addEventListener`, as React does neither for custom elements. `definitions_module` is the
+    /// import path of the file produced by [`to_typescript_definitions`].
+    ///
+    /// [`to_typescript_definitions`]: Self::to_typescript_definitions
+    pub fn to_react_wrapper(&self, definitions_module: &str) -> String {
+        let interface = self.interface_name();
+        let component = self.component_name();
+        let properties: Vec<_> = self
+            .fields_of(&[TsKind::Attribute, TsKind::Property])
+            .collect();
+        let events: Vec<_> = self.fields_of(&[TsKind::Event]).collect();
+        let mut out = String::new();
+
+        out.push_str("// Generated from the `Properties` of a Yew component. Do not edit.\n\n");
+        out.push_str("import * as React from \"react\";\n");
+        writeln!(
+            out,
+            "import type {{ {interface} }} from \"{definitions_module}\";\n"
+        )
+        .unwrap();
+
+        writeln!(
+            out,
+            "export interface {component}Props extends Omit<React.HTMLAttributes<{interface}>, \
+             {}> {{",
+            if events.is_empty() {
+                "never".to_string()
+            } else {
+                events
+                    .iter()
+                    .map(|m| format!("\"{}\"", m.event_prop_name()))
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            }
+        )
+        .unwrap();
+        for field in &properties {
+            write_docs(&mut out, field.docs);
+            writeln!(
+                out,
+                "  {}{}: {};",
+                field.property_name(),
+                if field.required { "" } else { "?" },
+                field.ts_type
+            )
+            .unwrap();
+        }
+        for field in &events {
+            write_docs(&mut out, field.docs);
+            writeln!(
+                out,
+                "  {}?: (event: CustomEvent<{}>) => void;",
+                field.event_prop_name(),
+                field.ts_type
+            )
+            .unwrap();
+        }
+        out.push_str("  children?: React.ReactNode;\n}\n\n");
+
+        let names: Vec<_> = properties
+            .iter()
+            .map(|m| m.property_name())
+            .chain(events.iter().map(|m| m.event_prop_name()))
+            .chain(["children".to_string(), "...rest".to_string()])
+            .collect();
+        writeln!(
+            out,
+            "export const {component} = React.forwardRef<{interface}, {component}Props>(\n  \
+             function {component}({{ {} }}, forwardedRef) {{\n    const ref = \
+             React.useRef<{interface}>(null);\n    React.useImperativeHandle(forwardedRef, () => \
+             ref.current as {interface});",
+            names.join(", ")
+        )
+        .unwrap();
+
+        for field in &properties {
+            let name = field.property_name();
+            writeln!(
+                out,
+                "\n    React.useLayoutEffect(() => {{\n      if (ref.current && {name} !== \
+                 undefined) {{\n        ref.current.{name} = {name};\n      }}\n    }}, [{name}]);"
+            )
+            .unwrap();
+        }
+
+        for field in &events {
+            let name = field.event_prop_name();
+            let event = field.event_name();
+            writeln!(
+                out,
+                "\n    React.useEffect(() => {{\n      const element = ref.current;\n      if \
+                 (!element || !{name}) {{\n        return;\n      }}\n      const listener = \
+                 (event: Event) => {name}(event as CustomEvent<{ts}>);\n      \
+                 element.addEventListener(\"{event}\", listener);\n      return () => \
+                 element.removeEventListener(\"{event}\", listener);\n    }}, [{name}]);",
+                ts = field.ts_type
+            )
+            .unwrap();
+        }
+
+        writeln!(
+            out,
+            "\n    return React.createElement(\"{}\", {{ ...rest, ref }}, children);\n  }},\n);",
+            self.tag_name
+        )
+        .unwrap();
+
+        out
+    }
+}
+
+fn write_docs(out: &mut String, docs: &str) {
+    if docs.is_empty() {
+        return;
+    }
+    out.push_str("  /**\n");
+    for line in docs.lines() {
+        if line.is_empty() {
+            out.push_str("   *\n");
+        } else {
+            writeln!(out, "   * {}", line.replace("*/", "*\\/")).unwrap();
+        }
+    }
+    out.push_str("   */\n");
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|m| !m.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn camel_case(name: &str) -> String {
+    let pascal = pascal_case(name);
+    let mut chars = pascal.chars();
+    chars
+        .next()
+        .map(|first| first.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Properties, PartialEq)]
+    #[custom_element("x-counter")]
+    struct CounterProps {
+        /// The value the counter starts with.
+        initial_value: i32,
+        #[prop_or_default]
+        label: Option<AttrValue>,
+        #[prop_or_default]
+        items: Vec<String>,
+        /// Emitted whenever the value changes.
+        #[prop_or_default]
+        on_value_change: Callback<i32>,
+        #[prop_or_default]
+        children: Html,
+    }
+
+    #[test]
+    fn derives_descriptor() {
+        let descriptor = CounterProps::custom_element();
+
+        assert_eq!(descriptor.tag_name(), "x-counter");
+        assert_eq!(descriptor.interface_name(), "XCounterElement");
+        assert_eq!(descriptor.component_name(), "XCounter");
+
+        let fields = descriptor.fields();
+        assert_eq!(fields.len(), 5);
+        assert_eq!(fields[0].name, "initial_value");
+        assert_eq!(fields[0].kind, TsKind::Attribute);
+        assert!(fields[0].required);
+        assert_eq!(fields[0].docs, "The value the counter starts with.");
+        // Props are listed in alphabetical order, with children last.
+        assert_eq!(fields[1].kind, TsKind::Property);
+        assert_eq!(fields[2].ts_type, "string | undefined");
+        assert!(!fields[2].required);
+        assert_eq!(fields[3].kind, TsKind::Event);
+        assert_eq!(fields[3].event_name(), "value-change");
+        assert_eq!(fields[3].event_prop_name(), "onValueChange");
+        assert_eq!(fields[4].kind, TsKind::Children);
+    }
+
+    #[test]
+    fn renders_typescript_definitions() {
+        let definitions = CounterProps::custom_element().to_typescript_definitions();
+
+        assert!(definitions.contains("export interface XCounterElement extends HTMLElement {"));
+        assert!(definitions.contains(
+            "  /**\n   * The value the counter starts with.\n   */\n  initialValue: number;\n"
+        ));
+        assert!(definitions.contains("  label?: string | undefined;\n"));
+        assert!(definitions.contains("  items?: string[];\n"));
+        assert!(definitions.contains("  \"value-change\": CustomEvent<number>;\n"));
+        assert!(definitions.contains("  \"initial-value\"?: string;\n"));
+        assert!(!definitions.contains("  \"items\"?: string;\n"));
+        assert!(!definitions.contains("children"));
+        assert!(definitions.contains("\"x-counter\": XCounterElement;"));
+    }
+
+    #[test]
+    fn renders_react_wrapper() {
+        let wrapper = CounterProps::custom_element().to_react_wrapper("./x-counter");
+
+        assert!(wrapper.contains("import type { XCounterElement } from \"./x-counter\";"));
+        assert!(wrapper.contains("  onValueChange?: (event: CustomEvent<number>) => void;\n"));
+        assert!(wrapper.contains("ref.current.initialValue = initialValue;"));
+        assert!(wrapper.contains("element.addEventListener(\"value-change\", listener);"));
+        assert!(wrapper.contains("React.createElement(\"x-counter\", { ...rest, ref }, children)"));
+    }
+}
@@ -0,0 +1,262 @@
+//! A protocol reporting the component tree to developer tools.
+//!
+//! Once [`connect`] is called, the application posts messages to its window with
+//! [`postMessage`](https://developer.mozilla.org/en-US/docs/Web/API/Window/postMessage), which the
+//! content script of a browser extension can forward to its devtools panel. Every message is an
+//! object whose `source` is [`SOURCE`] and whose `type` is one of:
+//!
+//! - `mount`: a component was created, with its `id`, the `parent` id, its type `name` and its
+//!   `props`.
+//! - `props`: a component received new `props`, given with its `id`.
+//! - `render`: a component was rendered, given with its `id`.
+//! - `state`: a value inspected with [`use_inspect`] changed, with the `id` of the component, the
+//!   `label` of the value and its new `value`.
+//! - `unmount`: a component was destroyed, given with its `id`.
+//! - `a11y`: the [accessibility audit](crate::a11y) found new problems in the elements rendered by
+//!   a component, given with its `id` as a list of `findings`, each with its `rule`, `message` and
+//!   `element`. It requires the `a11y_audit` feature.
+//! - `tree`: all the mounted components, as a list of `nodes` with the fields of `mount` and the
+//!   inspected `state`. It is sent when connecting, and in response to a message whose `source` is
+//!   [`PANEL_SOURCE`] and whose `type` is `get_tree`.
+//!
+//! The props of a component are only serialized, with their [`Debug`] implementation, once they
+//! are registered with [`inspect_props`]. They are `null` otherwise.
+//!
+//! This module requires the `devtools` feature.
+//!
+//! ```rust,no_run
+//! use yew::prelude::*;
+//!
+//! #[derive(Properties, PartialEq, Debug)]
+//! struct Props {
+//!     start: i32,
+//! }
+//!
+//! #[function_component]
+//! fn Counter(props: &Props) -> Html {
+//!     let count = use_state(|| props.start);
+//!     yew::devtools::use_inspect("count", &*count);
+//!     html! { <p>{ *count }</p> }
+//! }
+//!
+//! yew::devtools::inspect_props::<Counter>();
+//! yew::devtools::connect();
+//! ```
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use serde_json::{json, Value};
+
+use crate::functional::{Hook, HookContext};
+use crate::html::{BaseComponent, Scope};
+
+/// The `source` of the messages posted by the application.
+pub const SOURCE: &str = "yew-devtools";
+
+/// The `source` of the messages the application answers to.
+pub const PANEL_SOURCE: &str = "yew-devtools-panel";
+
+type FormatProps = fn(&dyn Any) -> Option<String>;
+
+struct Node {
+    name: &'static str,
+    parent: Option<usize>,
+    props: Option<String>,
+    state: BTreeMap<&'static str, String>,
+}
+
+impl Node {
+    fn to_json(&self, id: usize) -> Value {
+        json!({
+            "id": id,
+            "parent": self.parent,
+            "name": self.name,
+            "props": self.props,
+            "state": self.state,
+        })
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    listener: Option<EventListener>,
+    formatters: HashMap<TypeId, FormatProps>,
+    nodes: BTreeMap<usize, Node>,
+}
+
+impl Registry {
+    fn post(&self, kind: &str, mut message: Value) {
+        if self.listener.is_none() {
+            return;
+        }
+        message["source"] = SOURCE.into();
+        message["type"] = kind.into();
+        let message = js_sys::JSON::parse(&message.to_string()).expect("the message is valid JSON");
+        let _ = gloo::utils::window().post_message(&message, "*");
+    }
+
+    fn post_tree(&self) {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|(id, node)| node.to_json(*id))
+            .collect::<Vec<_>>();
+        self.post("tree", json!({ "nodes": nodes }));
+    }
+
+    fn format_props<COMP: BaseComponent>(&self, props: &COMP::Properties) -> Option<String> {
+        let format = self.formatters.get(&TypeId::of::<COMP>())?;
+        format(props)
+    }
+}
+
+thread_local! {
+    static REGISTRY: RefCell<Registry> = RefCell::default();
+}
+
+/// Starts posting messages, and answering the requests of the devtools panel.
+///
+/// The current tree is posted right away, so the application can connect after it is rendered.
+pub fn connect() {
+    REGISTRY.with(|r| {
+        let mut registry = r.borrow_mut();
+        if registry.listener.is_some() {
+            return;
+        }
+        let listener = EventListener::new(&gloo::utils::window(), "message", |event| {
+            let data = js_sys::Reflect::get(event, &"data".into()).unwrap_or_default();
+            let field = |name: &str| {
+                js_sys::Reflect::get(&data, &name.into())
+                    .ok()
+                    .and_then(|v| v.as_string())
+            };
+            if field("source").as_deref() == Some(PANEL_SOURCE)
+                && field("type").as_deref() == Some("get_tree")
+            {
+                REGISTRY.with(|r| r.borrow().post_tree());
+            }
+        });
+        registry.listener = Some(listener);
+        registry.post_tree();
+    });
+}
+
+/// Stops posting messages.
+pub fn disconnect() {
+    REGISTRY.with(|r| r.borrow_mut().listener = None);
+}
+
+/// Serializes the props of `COMP` with their [`Debug`] implementation in the messages.
+pub fn inspect_props<COMP>()
+where
+    COMP: BaseComponent,
+    COMP::Properties: Debug,
+{
+    fn format<P: Debug + 'static>(props: &dyn Any) -> Option<String> {
+        props.downcast_ref::<P>().map(|props| format!("{props:#?}"))
+    }
+
+    REGISTRY.with(|r| {
+        r.borrow_mut()
+            .formatters
+            .insert(TypeId::of::<COMP>(), format::<COMP::Properties>)
+    });
+}
+
+/// Reports a value of a function component to the devtools, like its state, each time it changes.
+///
+/// The value is serialized with its [`Debug`] implementation and shown under `label`.
+pub fn use_inspect<'a, T>(label: &'static str, value: &'a T) -> impl 'a + Hook<Output = ()>
+where
+    T: Debug + ?Sized,
+{
+    struct HookProvider<'a, T: ?Sized> {
+        label: &'static str,
+        value: &'a T,
+    }
+
+    impl<T: Debug + ?Sized> Hook for HookProvider<'_, T> {
+        type Output = ();
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let last = ctx.next_state(|_| RefCell::new(None::<String>));
+            let value = format!("{:#?}", self.value);
+            if last.borrow().as_ref() == Some(&value) {
+                return;
+            }
+            *last.borrow_mut() = Some(value.clone());
+
+            let id = ctx.scope.id();
+            let label = self.label;
+            REGISTRY.with(|r| {
+                let mut registry = r.borrow_mut();
+                if let Some(node) = registry.nodes.get_mut(&id) {
+                    node.state.insert(label, value.clone());
+                }
+                registry.post("state", json!({ "id": id, "label": label, "value": value }));
+            });
+        }
+    }
+
+    HookProvider { label, value }
+}
+
+pub(crate) fn mounted<COMP: BaseComponent>(scope: &Scope<COMP>, props: &Rc<COMP::Properties>) {
+    REGISTRY.with(|r| {
+        let mut registry = r.borrow_mut();
+        let node = Node {
+            name: std::any::type_name::<COMP>(),
+            parent: scope.get_parent().map(|p| p.id()),
+            props: registry.format_props::<COMP>(props),
+            state: BTreeMap::new(),
+        };
+        registry.post("mount", node.to_json(scope.id));
+        registry.nodes.insert(scope.id, node);
+    });
+}
+
+pub(crate) fn props_changed<COMP: BaseComponent>(id: usize, props: &Rc<COMP::Properties>) {
+    REGISTRY.with(|r| {
+        let mut registry = r.borrow_mut();
+        let props = registry.format_props::<COMP>(props);
+        registry.post("props", json!({ "id": id, "props": props }));
+        if let Some(node) = registry.nodes.get_mut(&id) {
+            node.props = props;
+        }
+    });
+}
+
+pub(crate) fn rendered(id: usize) {
+    REGISTRY.with(|r| r.borrow().post("render", json!({ "id": id })));
+}
+
+#[cfg(all(feature = "a11y_audit", debug_assertions))]
+pub(crate) fn a11y_findings(id: usize, findings: &[&crate::a11y::Finding]) {
+    let findings = findings
+        .iter()
+        .map(|m| {
+            json!({
+                "rule": format!("{:?}", m.rule),
+                "message": m.rule.to_string(),
+                "element": m.element,
+            })
+        })
+        .collect::<Vec<_>>();
+    REGISTRY.with(|r| {
+        r.borrow()
+            .post("a11y", json!({ "id": id, "findings": findings }))
+    });
+}
+
+pub(crate) fn unmounted(id: usize) {
+    REGISTRY.with(|r| {
+        let mut registry = r.borrow_mut();
+        registry.nodes.remove(&id);
+        registry.post("unmount", json!({ "id": id }));
+    });
+}
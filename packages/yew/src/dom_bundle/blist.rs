@@ -1,6 +1,5 @@
 //! This module contains fragments bundles, a [BList]
 use std::borrow::Borrow;
-use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::hash::Hash;
 use std::ops::Deref;
@@ -10,7 +9,7 @@ use web_sys::Element;
 use super::{test_log, BNode, BSubtree, DomSlot};
 use crate::dom_bundle::{Reconcilable, ReconcileTarget};
 use crate::html::AnyScope;
-use crate::utils::RcExt;
+use crate::utils::{longest_increasing_subsequence, RcExt};
 use crate::virtual_dom::{Key, VList, VNode};
 
 /// This struct represents a mounted [VList]
@@ -183,8 +182,8 @@ impl BList {
 
     /// Diff and patch fully keyed child lists.
     ///
-    /// Optimized for node addition or removal from either end of the list and small changes in the
-    /// middle.
+    /// Optimized for node addition or removal from either end of the list. Reordered children in
+    /// the middle are moved with as few DOM operations as possible.
     fn apply_keyed(
         root: &BSubtree,
         parent_scope: &AnyScope,
@@ -298,87 +297,29 @@ impl BList {
         }
 
         // Step 2.2. Put the middle part back together in the new key order
-        let mut replacements: Vec<BNode> = Vec::with_capacity((matching_len_start..lefts_to).len());
-        // The goal is to shift as few nodes as possible.
-
-        // We handle runs of in-order nodes. When we encounter one out-of-order, we decide whether:
-        // - to shift all nodes in the current run to the position after the node before of the run,
-        //   or to
-        // - "commit" to the current run, shift all nodes before the end of the run that we might
-        //   encounter in the future, and then start a new run.
-        // Example of a run:
-        //               barrier_idx --v                   v-- end_idx
-        // spliced_middle  [ ... , M , N , C , D , E , F , G , ... ] (original element order)
-        //                                 ^---^-----------^ the nodes that are part of the current
-        // run                           v start_writer
-        // replacements    [ ... , M , C , D , G ]                   (new element order)
-        //                             ^-- start_idx
-        let mut barrier_idx = 0; // nodes from spliced_middle[..barrier_idx] are shifted unconditionally
-        struct RunInformation<'a> {
-            start_writer: NodeWriter<'a>,
-            start_idx: usize,
-            end_idx: usize,
-        }
-        let mut current_run: Option<RunInformation<'_>> = None;
-
-        for l in lefts
+        //
+        // The goal is to shift as few nodes as possible. The children are written from the back,
+        // so the ones keeping their place are the longest run of increasing indices into
+        // spliced_middle, in the order they are written. All other ancestors are shifted.
+        let middle: Vec<VNode> = lefts
             .drain(matching_len_start..) // lefts_to.. has been drained
             .rev()
-        {
-            let ancestor = spare_bundles.take(key!(l));
-            // Check if we need to shift or commit a run
-            if let Some(run) = current_run.as_mut() {
-                if let Some(KeyedEntry(idx, _)) = ancestor {
-                    // If there are only few runs, this is a cold path
-                    if idx < run.end_idx {
-                        // Have to decide whether to shift or commit the current run. A few
-                        // calculations: A perfect estimate of the amount of
-                        // nodes we have to shift if we move this run:
-                        let run_length = replacements.len() - run.start_idx;
-                        // A very crude estimate of the amount of nodes we will have to shift if we
-                        // commit the run: Note nodes of the current run
-                        // should not be counted here!
-                        let estimated_skipped_nodes = run.end_idx - idx.max(barrier_idx);
-                        // double run_length to counteract that the run is part of the
-                        // estimated_skipped_nodes
-                        if 2 * run_length > estimated_skipped_nodes {
-                            // less work to commit to this run
-                            barrier_idx = 1 + run.end_idx;
-                        } else {
-                            // Less work to shift this run
-                            for r in replacements[run.start_idx..].iter_mut().rev() {
-                                run.start_writer.shift(r);
-                            }
-                        }
-                        current_run = None;
-                    }
-                }
-            }
-            let bundle = if let Some(KeyedEntry(idx, mut r_bundle)) = ancestor {
-                match current_run.as_mut() {
-                    // hot path
-                    // We know that idx >= run.end_idx, so this node doesn't need to shift
-                    Some(run) => run.end_idx = idx,
-                    None => match idx.cmp(&barrier_idx) {
-                        // peep hole optimization, don't start a run as the element is already where
-                        // it should be
-                        Ordering::Equal => barrier_idx += 1,
-                        // shift the node unconditionally, don't start a run
-                        Ordering::Less => writer.shift(&r_bundle),
-                        // start a run
-                        Ordering::Greater => {
-                            current_run = Some(RunInformation {
-                                start_writer: writer.clone(),
-                                start_idx: replacements.len(),
-                                end_idx: idx,
-                            })
-                        }
-                    },
+            .collect();
+        let ancestor_idx: Vec<Option<usize>> = middle
+            .iter()
+            .map(|l| spare_bundles.get(key!(l)).map(|KeyedEntry(idx, _)| *idx))
+            .collect();
+        let stays = longest_increasing_subsequence(&ancestor_idx);
+
+        let mut replacements: Vec<BNode> = Vec::with_capacity(middle.len());
+        for (l, stays) in middle.into_iter().zip(stays) {
+            let bundle = if let Some(KeyedEntry(_, mut r_bundle)) = spare_bundles.take(key!(l)) {
+                if !stays {
+                    writer.shift(&r_bundle);
                 }
                 writer = writer.patch(l, &mut r_bundle);
                 r_bundle
             } else {
-                // Even if there is an active run, we don't have to modify it
                 let (next_writer, bundle) = writer.add(l);
                 writer = next_writer;
                 bundle
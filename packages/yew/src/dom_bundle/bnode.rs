@@ -8,7 +8,7 @@ use super::{BComp, BList, BPortal, BRaw, BSubtree, BSuspense, BTag, BText, DomSl
 use crate::dom_bundle::{Reconcilable, ReconcileTarget};
 use crate::html::AnyScope;
 use crate::utils::RcExt;
-use crate::virtual_dom::{Key, VNode};
+use crate::virtual_dom::{recycle, Key, VNode};
 
 /// The bundle implementation to [VNode].
 pub(super) enum BNode {
@@ -96,8 +96,7 @@ impl Reconcilable for VNode {
     ) -> (DomSlot, Self::Bundle) {
         match self {
             VNode::VTag(vtag) => {
-                let (node_ref, tag) =
-                    RcExt::unwrap_or_clone(vtag).attach(root, parent_scope, parent, slot);
+                let (node_ref, tag) = recycle::take(vtag).attach(root, parent_scope, parent, slot);
                 (node_ref, tag.into())
             }
             VNode::VText(vtext) => {
@@ -111,7 +110,7 @@ impl Reconcilable for VNode {
             }
             VNode::VList(vlist) => {
                 let (node_ref, list) =
-                    RcExt::unwrap_or_clone(vlist).attach(root, parent_scope, parent, slot);
+                    recycle::take(vlist).attach(root, parent_scope, parent, slot);
                 (node_ref, list.into())
             }
             VNode::VRef(node) => {
@@ -155,13 +154,9 @@ impl Reconcilable for VNode {
         bundle: &mut BNode,
     ) -> DomSlot {
         match self {
-            VNode::VTag(vtag) => RcExt::unwrap_or_clone(vtag).reconcile_node(
-                root,
-                parent_scope,
-                parent,
-                slot,
-                bundle,
-            ),
+            VNode::VTag(vtag) => {
+                recycle::take(vtag).reconcile_node(root, parent_scope, parent, slot, bundle)
+            }
             VNode::VText(vtext) => vtext.reconcile_node(root, parent_scope, parent, slot, bundle),
             VNode::VComp(vcomp) => RcExt::unwrap_or_clone(vcomp).reconcile_node(
                 root,
@@ -170,13 +165,9 @@ impl Reconcilable for VNode {
                 slot,
                 bundle,
             ),
-            VNode::VList(vlist) => RcExt::unwrap_or_clone(vlist).reconcile_node(
-                root,
-                parent_scope,
-                parent,
-                slot,
-                bundle,
-            ),
+            VNode::VList(vlist) => {
+                recycle::take(vlist).reconcile_node(root, parent_scope, parent, slot, bundle)
+            }
             VNode::VRef(node) => match bundle {
                 BNode::Ref(ref n) if &node == n => DomSlot::at(node),
                 _ => VNode::VRef(node).replace(root, parent_scope, parent, slot, bundle),
@@ -240,7 +240,25 @@ mod feat_hydration {
                 .expect("failed to create detached element");
 
             let collectable = Collectable::Suspense;
-            let fallback_fragment = Fragment::collect_between(fragment, &collectable, parent);
+            let mut fallback_fragment = Fragment::collect_between(fragment, &collectable, parent);
+
+            // The server rendered the fallback as the children were not ready by its deadline.
+            // The children are rendered in place of being hydrated.
+            let timed_out = Collectable::TimedOut;
+            if fallback_fragment.starts_with(&timed_out) {
+                let fallback_fragment =
+                    Fragment::collect_between(&mut fallback_fragment, &timed_out, parent);
+                let (_, children_bundle) =
+                    self.children
+                        .attach(root, parent_scope, &detached_parent, DomSlot::at_end());
+
+                return BSuspense {
+                    children_bundle,
+                    detached_parent,
+                    key: self.key,
+                    fallback: Some(Fallback::Fragment(fallback_fragment)),
+                };
+            }
 
             let mut nodes = fallback_fragment.deep_clone();
 
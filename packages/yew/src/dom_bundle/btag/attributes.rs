@@ -1,13 +1,17 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 
 use indexmap::IndexMap;
-use wasm_bindgen::{intern, JsValue};
-use web_sys::{Element, HtmlInputElement as InputElement, HtmlTextAreaElement as TextAreaElement};
+use wasm_bindgen::{intern, JsCast, JsValue};
+use web_sys::{
+    CssStyleDeclaration, Element, HtmlElement, HtmlInputElement as InputElement,
+    HtmlTextAreaElement as TextAreaElement,
+};
 use yew::AttrValue;
 
 use super::Apply;
 use crate::dom_bundle::BSubtree;
+use crate::html::parse_declarations;
 use crate::virtual_dom::vtag::{InputFields, TextareaFields, Value};
 use crate::virtual_dom::{AttributeOrProperty, Attributes};
 
@@ -105,6 +109,26 @@ impl Apply for TextareaFields {
 }
 
 impl Attributes {
+    /// Compares the addresses of the strings before their content, so that literal values given
+    /// at the same place of `html!` and values made from the same
+    /// [`Atom`](crate::virtual_dom::Atom) are not compared byte by byte.
+    #[inline]
+    fn value_eq(new: &AttributeOrProperty, old: &AttributeOrProperty) -> bool {
+        match (new, old) {
+            (AttributeOrProperty::Static(new), AttributeOrProperty::Static(old))
+                if std::ptr::eq(*new, *old) =>
+            {
+                true
+            }
+            (AttributeOrProperty::Attribute(new), AttributeOrProperty::Attribute(old))
+                if std::ptr::eq(new.as_str(), old.as_str()) =>
+            {
+                true
+            }
+            _ => new == old,
+        }
+    }
+
     #[cold]
     fn apply_diff_index_maps(
         el: &Element,
@@ -114,8 +138,8 @@ impl Attributes {
         for (key, value) in new.iter() {
             match old.get(key) {
                 Some(old_value) => {
-                    if value != old_value {
-                        Self::set(el, key, value);
+                    if !Self::value_eq(value, old_value) {
+                        Self::update(el, key, value, old_value);
                     }
                 }
                 None => Self::set(el, key, value),
@@ -152,11 +176,10 @@ impl Attributes {
 
         // Update existing or set new
         for (k, new) in new.iter() {
-            if match old.get(k) {
-                Some(old) => old != new,
-                None => true,
-            } {
-                Self::set(el, k, new);
+            match old.get(k) {
+                Some(old) if Self::value_eq(new, old) => (),
+                Some(old) => Self::update(el, k, new, old),
+                None => Self::set(el, k, new),
             }
         }
 
@@ -183,6 +206,57 @@ impl Attributes {
         }
     }
 
+    /// Replaces the value of an attribute that is already set.
+    ///
+    /// The declarations of an inline `style` are patched one by one, so that properties that
+    /// didn't change, or were set by scripts, are left alone.
+    fn update(el: &Element, key: &str, new: &AttributeOrProperty, old: &AttributeOrProperty) {
+        fn attribute(value: &AttributeOrProperty) -> Option<&str> {
+            match value {
+                AttributeOrProperty::Attribute(value) => Some(value.as_str()),
+                AttributeOrProperty::Static(value) => Some(*value),
+                AttributeOrProperty::Property(_) => None,
+            }
+        }
+
+        if key == "style" {
+            if let (Some(new), Some(old), Some(el)) =
+                (attribute(new), attribute(old), el.dyn_ref::<HtmlElement>())
+            {
+                Self::patch_style(&el.style(), new, old);
+                return;
+            }
+        }
+        Self::set(el, key, new)
+    }
+
+    fn patch_style(style: &CssStyleDeclaration, new: &str, old: &str) {
+        let old = parse_declarations(old).collect::<HashMap<_, _>>();
+        let mut new_properties = HashSet::new();
+
+        for (property, value) in parse_declarations(new) {
+            new_properties.insert(property);
+            if old.get(property) == Some(&value) {
+                continue;
+            }
+            let (value, priority) = match value.strip_suffix("!important") {
+                Some(value) => (value.trim_end(), "important"),
+                None => (value, ""),
+            };
+            style
+                .set_property_with_priority(property, value, priority)
+                .expect("could not set style property");
+        }
+
+        for property in old.keys() {
+            if !new_properties.contains(property) {
+                style
+                    .remove_property(property)
+                    .expect("could not remove style property");
+            }
+        }
+    }
+
     fn remove(el: &Element, key: &str, old_value: &AttributeOrProperty) {
         match old_value {
             AttributeOrProperty::Attribute(_) | AttributeOrProperty::Static(_) => el
@@ -263,8 +337,8 @@ impl Apply for Attributes {
 
                     match unsafe { (new_v.get_unchecked(i), old_v.get_unchecked(i)) } {
                         (Some(new), Some(old)) => {
-                            if new != old {
-                                set!(new);
+                            if !Self::value_eq(new, old) {
+                                Self::update(el, key!(), new, old);
                             }
                         }
                         (Some(new), None) => set!(new),
@@ -371,6 +445,29 @@ mod tests {
         assert_eq!(element.get_attribute("class").unwrap(), "thing");
     }
 
+    #[test]
+    fn style_declarations_are_patched() {
+        let (element, btree) = create_element();
+        let element = element.unchecked_into::<HtmlElement>();
+        let attrs = |style: &'static str| {
+            Attributes::Static(Box::leak(Box::new([(
+                "style",
+                AttributeOrProperty::Static(style),
+            )])))
+        };
+
+        let mut bundle = attrs("width: 10px; color: red;").apply(&btree, &element);
+        // set outside of yew, it must survive the patch
+        element.style().set_property("height", "5px").unwrap();
+        attrs("width: 20px; margin: 0 !important;").apply_diff(&btree, &element, &mut bundle);
+
+        let style = element.style();
+        assert_eq!(style.get_property_value("width").unwrap(), "20px");
+        assert_eq!(style.get_property_value("color").unwrap(), "");
+        assert_eq!(style.get_property_value("height").unwrap(), "5px");
+        assert_eq!(style.get_property_priority("margin"), "important");
+    }
+
     #[test]
     async fn macro_syntax_works() {
         #[function_component]
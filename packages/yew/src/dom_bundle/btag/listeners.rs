@@ -1,5 +1,5 @@
-use std::cell::RefCell;
-use std::collections::HashMap;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 use std::rc::Rc;
 
@@ -8,7 +8,7 @@ use wasm_bindgen::JsCast;
 use web_sys::{Element, Event, EventTarget as HtmlEventTarget};
 
 use super::Apply;
-use crate::dom_bundle::{test_log, BSubtree, EventDescriptor};
+use crate::dom_bundle::{test_log, BSubtree, EventDescriptor, EventListener};
 use crate::virtual_dom::{Listener, Listeners};
 
 #[wasm_bindgen]
@@ -39,12 +39,12 @@ impl EventListening for Element {
 }
 
 /// An active set of listeners on an element
-#[derive(Debug)]
-pub(super) enum ListenerRegistration {
-    /// No listeners registered.
-    NoReg,
-    /// Added to global registry by ID
-    Registered(u32),
+#[derive(Debug, Default)]
+pub(super) struct ListenerRegistration {
+    /// ID of the delegated listeners in the global registry, if any
+    id: Option<u32>,
+    /// Listeners attached to the element itself
+    direct: Vec<DirectListener>,
 }
 
 impl Apply for Listeners {
@@ -52,74 +52,149 @@ impl Apply for Listeners {
     type Element = Element;
 
     fn apply(self, root: &BSubtree, el: &Self::Element) -> ListenerRegistration {
-        match self {
-            Self::Pending(pending) => ListenerRegistration::register(root, el, &pending),
-            Self::None => ListenerRegistration::NoReg,
-        }
+        let mut bundle = ListenerRegistration::default();
+        self.apply_diff(root, el, &mut bundle);
+        bundle
     }
 
     fn apply_diff(self, root: &BSubtree, el: &Self::Element, bundle: &mut ListenerRegistration) {
-        use ListenerRegistration::*;
-        use Listeners::*;
+        let pending = match self {
+            Self::Pending(pending) => pending,
+            Self::None => Box::default(),
+        };
+        let delegated = pending.iter().flatten().any(|l| !root.is_direct(l.deref()));
 
-        match (self, bundle) {
-            (Pending(pending), Registered(ref id)) => {
+        match (delegated, bundle.id) {
+            (true, Some(ref id)) => {
                 // Reuse the ID
                 test_log!("reusing listeners for {}", id);
                 root.with_listener_registry(|reg| reg.patch(root, id, &pending));
             }
-            (Pending(pending), bundle @ NoReg) => {
-                *bundle = ListenerRegistration::register(root, el, &pending);
-                test_log!(
-                    "registering listeners for {}",
-                    match bundle {
-                        ListenerRegistration::Registered(id) => id,
-                        _ => unreachable!(),
-                    }
-                );
+            (true, None) => {
+                let id = root.with_listener_registry(|reg| {
+                    let id = reg.set_listener_id(root, el);
+                    reg.register(root, id, &pending);
+                    id
+                });
+                test_log!("registering listeners for {}", id);
+                bundle.id = Some(id);
             }
-            (None, bundle @ Registered(_)) => {
-                let id = match bundle {
-                    ListenerRegistration::Registered(ref id) => id,
-                    _ => unreachable!(),
-                };
+            (false, Some(ref id)) => {
                 test_log!("unregistering listeners for {}", id);
                 root.with_listener_registry(|reg| reg.unregister(id));
-                *bundle = NoReg;
+                bundle.id = None;
             }
-            (None, NoReg) => {
+            (false, None) => {
                 test_log!("{}", &"unchanged empty listeners");
             }
         };
+
+        bundle.patch_direct(root, el, &pending);
     }
 }
 
 impl ListenerRegistration {
-    /// Register listeners and return their handle ID
-    fn register(root: &BSubtree, el: &Element, pending: &[Option<Rc<dyn Listener>>]) -> Self {
-        Self::Registered(root.with_listener_registry(|reg| {
-            let id = reg.set_listener_id(root, el);
-            reg.register(root, id, pending);
-            id
-        }))
-    }
-
     /// Remove any registered event listeners from the global registry
     pub fn unregister(&self, root: &BSubtree) {
-        if let Self::Registered(id) = self {
-            root.with_listener_registry(|r| r.unregister(id));
+        if let Some(id) = self.id {
+            root.with_listener_registry(|r| r.unregister(&id));
         }
     }
+
+    /// Attaches the direct listeners to the element, reusing the attached ones when only their
+    /// callback changed.
+    fn patch_direct(
+        &mut self,
+        root: &BSubtree,
+        el: &Element,
+        pending: &[Option<Rc<dyn Listener>>],
+    ) {
+        if self.direct.is_empty() && !pending.iter().flatten().any(|l| root.is_direct(l.deref())) {
+            return;
+        }
+        let mut previous = std::mem::take(&mut self.direct);
+        for (position, listener) in pending.iter().enumerate() {
+            let Some(listener) = listener.clone() else {
+                continue;
+            };
+            if !root.is_direct(listener.deref()) {
+                continue;
+            }
+            let desc = EventDescriptor::from(listener.deref());
+            let capture = listener.capture();
+            let reused = previous
+                .iter()
+                .position(|m| m.position == position && m.desc == desc && m.capture == capture);
+            let direct = match reused {
+                Some(i) => {
+                    let direct = previous.swap_remove(i);
+                    *direct.listener.borrow_mut() = listener;
+                    direct
+                }
+                None => DirectListener::new(el, position, desc, listener),
+            };
+            self.direct.push(direct);
+        }
+    }
+}
+
+/// A listener attached to its element instead of being delegated.
+#[derive(Debug)]
+struct DirectListener {
+    position: usize,
+    desc: EventDescriptor,
+    capture: bool,
+    /// The listener of the latest render
+    listener: Rc<RefCell<Rc<dyn Listener>>>,
+    _attached: EventListener,
 }
 
+impl DirectListener {
+    fn new(
+        el: &Element,
+        position: usize,
+        desc: EventDescriptor,
+        listener: Rc<dyn Listener>,
+    ) -> Self {
+        let capture = listener.capture();
+        let once = listener.once();
+        let listener = Rc::new(RefCell::new(listener));
+        let attached = {
+            let listener = listener.clone();
+            let spent = Cell::new(false);
+            EventListener::new(el, &desc, capture, move |event| {
+                if once && spent.replace(true) {
+                    return;
+                }
+                let listener = listener.borrow().clone();
+                listener.handle(event.clone());
+            })
+        };
+        Self {
+            position,
+            desc,
+            capture,
+            listener,
+            _attached: attached,
+        }
+    }
+}
+
+/// The listeners of an element by event, with their position among the listeners of the element
+type ListenersByEvent = HashMap<EventDescriptor, Vec<(usize, Rc<dyn Listener>)>>;
+
 /// Global multiplexing event handler registry
 #[derive(Debug)]
 pub struct Registry {
     /// Counter for assigning new IDs
     id_counter: u32,
 
-    /// Contains all registered event listeners by listener ID
-    by_id: HashMap<u32, HashMap<EventDescriptor, Vec<Rc<dyn Listener>>>>,
+    /// Contains all registered event listeners by listener ID, with their position among the
+    /// listeners of their element
+    by_id: HashMap<u32, ListenersByEvent>,
+
+    /// Positions of the `once` listeners that already handled an event, by listener ID
+    spent: HashMap<u32, HashSet<usize>>,
 }
 
 impl Registry {
@@ -127,25 +202,39 @@ impl Registry {
         Self {
             id_counter: u32::default(),
             by_id: HashMap::default(),
+            spent: HashMap::default(),
         }
     }
 
-    /// Handle a single event, given the listening element and event descriptor.
-    pub fn get_handler(
-        registry: &RefCell<Registry>,
+    /// Handle a single event, given the listening element, event descriptor and whether the event
+    /// is in its capture phase.
+    pub fn get_handler<'r>(
+        registry: &'r RefCell<Registry>,
         listening: &dyn EventListening,
         desc: &EventDescriptor,
-    ) -> Option<impl FnOnce(&Event)> {
+        capture: bool,
+    ) -> Option<impl 'r + FnOnce(&Event)> {
         // The tricky part is that we want to drop the reference to the registry before
         // calling any actual listeners (since that might end up running lifecycle methods
         // and modify the registry). So we clone the current listeners and return a closure
         let listener_id = listening.listener_id()?;
         let registry_ref = registry.borrow();
         let handlers = registry_ref.by_id.get(&listener_id)?;
-        let listeners = handlers.get(desc)?.clone();
+        let spent = registry_ref.spent.get(&listener_id);
+        let listeners: Vec<_> = handlers
+            .get(desc)?
+            .iter()
+            .filter(|(_, l)| l.capture() == capture)
+            .filter(|(i, l)| !(l.once() && spent.is_some_and(|m| m.contains(i))))
+            .cloned()
+            .collect();
         drop(registry_ref); // unborrow the registry, before running any listeners
         Some(move |event: &Event| {
-            for l in listeners {
+            for (i, l) in listeners {
+                if l.once() {
+                    let mut registry = registry.borrow_mut();
+                    registry.spent.entry(listener_id).or_default().insert(i);
+                }
                 l.handle(event.clone());
             }
         })
@@ -153,12 +242,14 @@ impl Registry {
 
     /// Register all passed listeners under ID
     fn register(&mut self, root: &BSubtree, id: u32, listeners: &[Option<Rc<dyn Listener>>]) {
-        let mut by_desc =
-            HashMap::<EventDescriptor, Vec<Rc<dyn Listener>>>::with_capacity(listeners.len());
-        for l in listeners.iter().filter_map(|l| l.as_ref()).cloned() {
+        let mut by_desc = ListenersByEvent::with_capacity(listeners.len());
+        for (i, l) in listeners.iter().enumerate() {
+            let Some(l) = l.clone().filter(|l| !root.is_direct(l.deref())) else {
+                continue;
+            };
             let desc = EventDescriptor::from(l.deref());
-            root.ensure_handled(&desc);
-            by_desc.entry(desc).or_default().push(l);
+            root.ensure_handled(&desc, l.capture());
+            by_desc.entry(desc).or_default().push((i, l));
         }
         self.by_id.insert(id, by_desc);
     }
@@ -171,10 +262,13 @@ impl Registry {
                 v.clear()
             }
 
-            for l in listeners.iter().filter_map(|l| l.as_ref()).cloned() {
+            for (i, l) in listeners.iter().enumerate() {
+                let Some(l) = l.clone().filter(|l| !root.is_direct(l.deref())) else {
+                    continue;
+                };
                 let desc = EventDescriptor::from(l.deref());
-                root.ensure_handled(&desc);
-                by_desc.entry(desc).or_default().push(l);
+                root.ensure_handled(&desc, l.capture());
+                by_desc.entry(desc).or_default().push((i, l));
             }
         }
     }
@@ -182,6 +276,7 @@ impl Registry {
     /// Unregister any existing listeners for ID
     fn unregister(&mut self, id: &u32) {
         self.by_id.remove(id);
+        self.spent.remove(id);
     }
 
     /// Set unique listener ID onto element and return it
@@ -536,6 +631,118 @@ mod tests {
         assert_count(&el, 2);
     }
 
+    #[test]
+    fn capture_runs_before_target() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Capture;
+
+        impl Mixin for Capture {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onclick = ctx.link().callback(|_| Message::Action);
+                let oncapture = ctx.link().callback(|e: MouseEvent| {
+                    // the listener of the target is not run anymore
+                    e.stop_propagation();
+                    Message::Action
+                });
+
+                html! {
+                    <div onclick:capture={oncapture}>
+                        <a {onclick} ref={&ctx.props().state_ref}>
+                            {state.action}
+                        </a>
+                    </div>
+                }
+            }
+        }
+
+        let (_, el) = init::<Capture>();
+
+        assert_count(&el, 0);
+        click(&el);
+        assert_count(&el, 1);
+        click(&el);
+        assert_count(&el, 2);
+    }
+
+    #[test]
+    fn once_across_renders() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Once;
+
+        impl Mixin for Once {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                // a new callback every render
+                let onclick = ctx.link().callback(|_| Message::Action);
+
+                html! {
+                    <a onclick:once={onclick} ref={&ctx.props().state_ref}>
+                        {state.action}
+                    </a>
+                }
+            }
+        }
+
+        let (_, el) = init::<Once>();
+
+        assert_count(&el, 0);
+        click(&el);
+        assert_count(&el, 1);
+        click(&el);
+        assert_count(&el, 1);
+    }
+
+    #[test]
+    fn direct_listener() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Direct;
+
+        impl Mixin for Direct {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onclick = ctx.link().callback(|_| Message::Action);
+                let ondirect = (!state.stop_listening).then(|| {
+                    ctx.link().callback(|e: MouseEvent| {
+                        assert_eq!(e.current_target(), e.target(), "listener is on the element");
+                        // the delegated listener of the parent is not run anymore
+                        e.stop_propagation();
+                        Message::Action
+                    })
+                });
+
+                html! {
+                    <div {onclick}>
+                        <a onclick:direct={ondirect} ref={&ctx.props().state_ref}>
+                            {state.action}
+                        </a>
+                    </div>
+                }
+            }
+        }
+
+        let (link, el) = init::<Direct>();
+
+        assert_count(&el, 0);
+        click(&el);
+        assert_count(&el, 1);
+        // the attached listener is kept across renders
+        click(&el);
+        assert_count(&el, 2);
+
+        // once detached, the click reaches the parent
+        link.send_message(Message::StopListening);
+        scheduler::start_now();
+        click(&el);
+        assert_count(&el, 3);
+    }
+
     #[test]
     fn non_bubbling() {
         #[derive(Default, PartialEq, Properties)]
@@ -620,6 +827,144 @@ mod tests {
         assert_count(&el, 1);
     }
 
+    #[test]
+    fn nested_app_bubbling() {
+        #[derive(PartialEq, Properties)]
+        struct Outer {
+            host: web_sys::Element,
+        }
+        impl Default for Outer {
+            fn default() -> Self {
+                let host = document().create_element("div").unwrap();
+                Outer { host }
+            }
+        }
+
+        impl Mixin for Outer {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onclick = ctx.link().callback(|_| Message::Action);
+                html! {
+                    <div {onclick}>
+                        <span ref={&ctx.props().state_ref}>{state.action}</span>
+                        {VNode::VRef(ctx.props().wrapped.host.clone().into())}
+                    </div>
+                }
+            }
+        }
+
+        #[derive(Default, PartialEq, Properties)]
+        struct Inner;
+
+        impl Mixin for Inner {
+            fn view<C>(ctx: &Context<C>, _state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                html! { <a ref={&ctx.props().state_ref}>{"inner"}</a> }
+            }
+        }
+
+        let (_outer, el) = init::<Outer>();
+        let host = get_el_by_selector("#testroot > div > div");
+        let props = MixinProps::<Inner>::default();
+        let inner_el = props.state_ref.clone();
+        let inner = crate::Renderer::<Comp<Inner>>::with_root_and_props(
+            web_sys::Element::from(host.clone()),
+            props,
+        )
+        .render();
+        scheduler::start_now();
+
+        // The events of the inner app bubble to the app it is rendered in.
+        assert_count(&el, 0);
+        click(&inner_el);
+        assert_count(&el, 1);
+
+        // Once destroyed, its root is a plain element of the outer app again.
+        inner.destroy();
+        scheduler::start_now();
+        let plain = document().create_element("b").unwrap();
+        host.append_child(&plain).unwrap();
+        plain.dyn_into::<HtmlElement>().unwrap().click();
+        scheduler::start_now();
+        assert_count(&el, 2);
+    }
+
+    #[derive(Default, PartialEq, Properties)]
+    struct Detached;
+
+    impl Mixin for Detached {
+        fn view<C>(ctx: &Context<C>, state: &State) -> Html
+        where
+            C: Component<Message = Message, Properties = MixinProps<Self>>,
+        {
+            let onclick = ctx.link().callback(|_| Message::Action);
+            html! {
+                <div {onclick}>
+                    <a ref={&ctx.props().state_ref}>{state.action}</a>
+                </div>
+            }
+        }
+    }
+
+    #[test]
+    fn shadow_root_app() {
+        use web_sys::{ShadowRootInit, ShadowRootMode};
+
+        let host = document().create_element("div").unwrap();
+        document().body().unwrap().append_child(&host).unwrap();
+        let shadow = host
+            .attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))
+            .unwrap();
+
+        let props = MixinProps::<Detached>::default();
+        let el = props.state_ref.clone();
+        let app =
+            crate::Renderer::<Comp<Detached>>::with_root_and_props(shadow.clone(), props).render();
+        scheduler::start_now();
+
+        assert_count(&el, 0);
+        click(&el);
+        assert_count(&el, 1);
+
+        // The container of the shadow root is reused by the next application.
+        let root = crate::RendererRoot::from(shadow.clone());
+        assert_eq!(root, crate::RendererRoot::from(shadow.clone()));
+        assert_eq!(shadow.child_element_count(), 1);
+
+        app.destroy();
+        host.remove();
+    }
+
+    #[test]
+    fn document_fragment_app() {
+        let fragment = document().create_document_fragment();
+        let props = MixinProps::<Detached>::default();
+        let el = props.state_ref.clone();
+        let app = crate::Renderer::<Comp<Detached>>::with_root_and_props(fragment.clone(), props)
+            .render();
+        scheduler::start_now();
+
+        // The application keeps working once the fragment is inserted into the document.
+        let body = document().body().unwrap();
+        body.append_child(&fragment).unwrap();
+        assert_eq!(fragment.child_element_count(), 0);
+
+        assert_count(&el, 0);
+        click(&el);
+        assert_count(&el, 1);
+
+        let container = body
+            .query_selector(":scope > [data-yew-root]")
+            .unwrap()
+            .unwrap();
+        app.destroy();
+        container.remove();
+    }
+
     /// Here an event is being from inside a shadow root. It should only be caught exactly once on
     /// each handler
     #[test]
@@ -669,6 +1014,98 @@ mod tests {
         assert_count(&el, 2); // Once caught per handler
     }
 
+    /// Here the portal renders directly into a shadow root. Events should bubble to the component
+    /// tree that created the portal.
+    #[test]
+    fn shadow_root_portal_bubbling() {
+        use web_sys::{ShadowRoot, ShadowRootInit, ShadowRootMode};
+        #[derive(PartialEq, Properties)]
+        struct ShadowRootPortal {
+            host: web_sys::Element,
+            shadow: ShadowRoot,
+        }
+        impl Default for ShadowRootPortal {
+            fn default() -> Self {
+                let host = document().create_element("div").unwrap();
+                let shadow = host
+                    .attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))
+                    .unwrap();
+                ShadowRootPortal { host, shadow }
+            }
+        }
+        impl Mixin for ShadowRootPortal {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onclick = ctx.link().callback(|_| Message::Action);
+                let mixin = &ctx.props().wrapped;
+                html! {
+                    <div {onclick}>
+                        {create_portal(html! {
+                            <a ref={&ctx.props().state_ref}>
+                                {state.action}
+                            </a>
+                        }, mixin.shadow.clone())}
+                        {VNode::VRef(mixin.host.clone().into())}
+                    </div>
+                }
+            }
+        }
+        let (_, el) = init::<ShadowRootPortal>();
+
+        assert_count(&el, 0);
+        click(&el);
+        assert_count(&el, 1);
+        click(&el);
+        assert_count(&el, 2);
+    }
+
+    /// Here the portal renders into the document of an iframe, as it would for a popup window.
+    #[test]
+    fn foreign_document_portal_bubbling() {
+        use web_sys::HtmlIFrameElement;
+        #[derive(PartialEq, Properties)]
+        struct ForeignDocumentPortal {
+            frame: HtmlIFrameElement,
+        }
+        impl Default for ForeignDocumentPortal {
+            fn default() -> Self {
+                let frame: HtmlIFrameElement = document()
+                    .create_element("iframe")
+                    .unwrap()
+                    .unchecked_into();
+                document().body().unwrap().append_child(&frame).unwrap();
+                ForeignDocumentPortal { frame }
+            }
+        }
+        impl Mixin for ForeignDocumentPortal {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onclick = ctx.link().callback(|_| Message::Action);
+                let foreign = ctx.props().wrapped.frame.content_document().unwrap();
+                html! {
+                    <div {onclick}>
+                        {create_portal(html! {
+                            <a ref={&ctx.props().state_ref}>
+                                {state.action}
+                            </a>
+                        }, foreign)}
+                    </div>
+                }
+            }
+        }
+        let (_, el) = init::<ForeignDocumentPortal>();
+
+        let foreign = el.get().unwrap().owner_document().unwrap();
+        assert_ne!(foreign, document());
+        assert_count(&el, 0);
+        click(&el);
+        assert_count(&el, 1);
+    }
+
     fn test_input_listener<E>(make_event: impl Fn() -> E)
     where
         E: Into<Event> + std::fmt::Debug,
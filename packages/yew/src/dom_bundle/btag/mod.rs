@@ -14,12 +14,12 @@ pub use listeners::Registry;
 use wasm_bindgen::JsCast;
 use web_sys::{Element, HtmlTextAreaElement as TextAreaElement};
 
-use super::{BNode, BSubtree, DomSlot, Reconcilable, ReconcileTarget};
+use super::{BList, BNode, BSubtree, DomSlot, Reconcilable, ReconcileTarget};
 use crate::html::AnyScope;
 use crate::virtual_dom::vtag::{
     InputFields, TextareaFields, VTagInner, Value, MATHML_NAMESPACE, SVG_NAMESPACE,
 };
-use crate::virtual_dom::{AttrValue, Attributes, Key, VTag};
+use crate::virtual_dom::{AttrValue, AttributeOrProperty, Attributes, Key, StaticNodeId, VTag};
 use crate::NodeRef;
 
 /// Applies contained changes to DOM [web_sys::Element]
@@ -70,6 +70,10 @@ pub(super) struct BTag {
     /// A node reference used for DOM access in Component lifecycle methods
     node_ref: NodeRef,
     key: Option<Key>,
+    static_id: Option<&'static StaticNodeId>,
+    /// Whether the element was cloned from the template of a static element, in which case its
+    /// children are not in `child_bundle`.
+    from_template: bool,
 }
 
 impl ReconcileTarget for BTag {
@@ -113,12 +117,19 @@ impl Reconcilable for VTag {
         parent: &Element,
         slot: DomSlot,
     ) -> (DomSlot, Self::Bundle) {
-        let el = self.create_element(parent);
+        let namespace = self.namespace(parent);
+        if let Some(el) = self.clone_template(namespace) {
+            return self.attach_template(root, parent, slot, el);
+        }
+
+        let el = self.create_element(namespace);
+        let template_key = self.template_key(namespace);
         let Self {
             listeners,
             attributes,
             node_ref,
             key,
+            static_id,
             ..
         } = self;
         slot.insert(parent, &el);
@@ -140,6 +151,9 @@ impl Reconcilable for VTag {
                 BTagInner::Other { child_bundle, tag }
             }
         };
+        if let Some(template_key) = template_key {
+            store_template(template_key, &el);
+        }
         node_ref.set(Some(el.clone().into()));
         (
             DomSlot::at(el.clone().into()),
@@ -150,6 +164,8 @@ impl Reconcilable for VTag {
                 attributes,
                 key,
                 node_ref,
+                static_id,
+                from_template: false,
             },
         )
     }
@@ -168,7 +184,11 @@ impl Reconcilable for VTag {
         match bundle {
             // If the ancestor is a tag of the same type, don't recreate, keep the
             // old tag and update its attributes and children.
-            BNode::Tag(ex) if self.key == ex.key => {
+            // The children of an element cloned from a template are not known, so it can only be
+            // kept as it is.
+            BNode::Tag(ex)
+                if self.key == ex.key && (!ex.from_template || self.is_unchanged_static(ex)) =>
+            {
                 if match (&self.inner, &ex.inner) {
                     (VTagInner::Input(_), BTagInner::Input(_)) => true,
                     (VTagInner::Textarea { .. }, BTagInner::Textarea { .. }) => true,
@@ -195,6 +215,10 @@ impl Reconcilable for VTag {
         _slot: DomSlot,
         tag: &mut Self::Bundle,
     ) -> DomSlot {
+        if self.is_unchanged_static(tag) {
+            return DomSlot::at(tag.reference.clone().into());
+        }
+        tag.static_id = self.static_id;
         let el = &tag.reference;
         self.attributes.apply_diff(root, el, &mut tag.attributes);
         self.listeners.apply_diff(root, el, &mut tag.listeners);
@@ -236,22 +260,107 @@ impl Reconcilable for VTag {
 }
 
 impl VTag {
-    fn create_element(&self, parent: &Element) -> Element {
+    /// Whether both elements are the same fully static element of `html!`, which doesn't need to
+    /// be diffed.
+    fn is_unchanged_static(&self, tag: &BTag) -> bool {
+        let (Some(new), Some(old)) = (self.static_id, tag.static_id) else {
+            return false;
+        };
+        // the attributes of the element can be replaced through the public field
+        std::ptr::eq(new, old)
+            && matches!(
+                (&self.attributes, &tag.attributes),
+                (Attributes::Static(new), Attributes::Static(old)) if std::ptr::eq(*new, *old)
+            )
+    }
+
+    /// The namespace of the element, if it is not an HTML element.
+    fn namespace(&self, parent: &Element) -> Option<&'static str> {
         let tag = self.tag();
+        let parent_namespace = parent.namespace_uri();
 
-        if tag == "svg" || parent.namespace_uri().is_some_and(|ns| ns == SVG_NAMESPACE) {
-            let namespace = Some(SVG_NAMESPACE);
-            document()
-                .create_element_ns(namespace, tag)
-                .expect("can't create namespaced element for vtag")
-        } else if tag == "math"
-            || parent
-                .namespace_uri()
-                .is_some_and(|ns| ns == MATHML_NAMESPACE)
-        {
-            let namespace = Some(MATHML_NAMESPACE);
+        if tag == "svg" || parent_namespace.as_deref() == Some(SVG_NAMESPACE) {
+            Some(SVG_NAMESPACE)
+        } else if tag == "math" || parent_namespace.as_deref() == Some(MATHML_NAMESPACE) {
+            Some(MATHML_NAMESPACE)
+        } else {
+            None
+        }
+    }
+
+    /// The key of the template of a fully static element of `html!`.
+    fn template_key(&self, namespace: Option<&'static str>) -> Option<TemplateKey> {
+        match (self.static_id, &self.attributes) {
+            (Some(id), Attributes::Static(attributes)) => Some(TemplateKey {
+                id: id as *const StaticNodeId,
+                attributes: attributes.as_ptr(),
+                namespace,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Clones the template of this static element, with all its descendants, if it was attached
+    /// before.
+    fn clone_template(&self, namespace: Option<&'static str>) -> Option<Element> {
+        let key = self.template_key(namespace)?;
+        STATIC_TEMPLATES.with(|templates| {
+            templates.borrow().get(&key).map(|template| {
+                template
+                    .clone_node_with_deep(true)
+                    .expect("couldn't clone static template")
+                    .unchecked_into()
+            })
+        })
+    }
+
+    fn attach_template(
+        self,
+        root: &BSubtree,
+        parent: &Element,
+        slot: DomSlot,
+        el: Element,
+    ) -> (DomSlot, BTag) {
+        let Self {
+            inner,
+            listeners,
+            attributes,
+            node_ref,
+            key,
+            static_id,
+        } = self;
+        let VTagInner::Other { tag, .. } = inner else {
+            unreachable!("input and textarea elements are never static")
+        };
+        slot.insert(parent, &el);
+
+        // The attributes and children were cloned from the template
+        let listeners = listeners.apply(root, &el);
+        node_ref.set(Some(el.clone().into()));
+        (
+            DomSlot::at(el.clone().into()),
+            BTag {
+                inner: BTagInner::Other {
+                    tag,
+                    child_bundle: BNode::List(BList::new()),
+                },
+                listeners,
+                reference: el,
+                attributes,
+                key,
+                node_ref,
+                static_id,
+                from_template: true,
+            },
+        )
+    }
+
+    fn create_element(&self, namespace: Option<&'static str>) -> Element {
+        let tag = self.tag();
+
+        if let Some(namespace) = namespace {
             document()
-                .create_element_ns(namespace, tag)
+                .create_element_ns(Some(namespace), tag)
                 .expect("can't create namespaced element for vtag")
         } else {
             thread_local! {
@@ -283,6 +392,30 @@ impl VTag {
     }
 }
 
+/// Identifies the template of a fully static element of `html!`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct TemplateKey {
+    id: *const StaticNodeId,
+    attributes: *const (&'static str, AttributeOrProperty),
+    namespace: Option<&'static str>,
+}
+
+thread_local! {
+    /// The elements attached for the fully static elements of `html!`, to be cloned by the next
+    /// ones instead of being built node by node.
+    static STATIC_TEMPLATES: RefCell<HashMap<TemplateKey, Element>> = RefCell::default();
+}
+
+fn store_template(key: TemplateKey, el: &Element) {
+    STATIC_TEMPLATES.with(|templates| {
+        templates.borrow_mut().entry(key).or_insert_with(|| {
+            el.clone_node_with_deep(true)
+                .expect("couldn't clone node to be stored as template")
+                .unchecked_into()
+        });
+    })
+}
+
 impl BTag {
     /// Get the key of the underlying tag
     pub fn key(&self) -> Option<&Key> {
@@ -338,6 +471,7 @@ mod feat_hydration {
                 attributes,
                 node_ref,
                 key,
+                static_id,
             } = self;
 
             // We trim all text nodes as it's likely these are whitespaces.
@@ -399,6 +533,8 @@ mod feat_hydration {
                 reference: el,
                 node_ref,
                 key,
+                static_id,
+                from_template: false,
             }
         }
     }
@@ -418,7 +554,7 @@ mod tests {
     use crate::dom_bundle::{BNode, Reconcilable, ReconcileTarget};
     use crate::utils::RcExt;
     use crate::virtual_dom::vtag::{HTML_NAMESPACE, SVG_NAMESPACE};
-    use crate::virtual_dom::{AttrValue, VNode, VTag};
+    use crate::virtual_dom::{AttrValue, TagName, VNode, VTag};
     use crate::{html, Html, NodeRef};
 
     wasm_bindgen_test_configure!(run_in_browser);
@@ -759,6 +895,29 @@ mod tests {
         test_set_class_name(|| html! { <div class={"ferris the crab".to_owned()}></div> });
     }
 
+    #[test]
+    fn static_element_cloned_from_template() {
+        let (root, scope, parent) = setup_parent();
+        let gen_html = || html! { <p class="static"><b>{"bold"}</b>{" text"}</p> };
+
+        let (_, first) = gen_html().attach(&root, &scope, &parent, DomSlot::at_end());
+        let (_, mut second) = gen_html().attach(&root, &scope, &parent, DomSlot::at_end());
+        assert!(!assert_btag_ref(&first).from_template);
+        assert!(assert_btag_ref(&second).from_template);
+        assert_eq!(
+            parent.inner_html(),
+            r#"<p class="static"><b>bold</b> text</p><p class="static"><b>bold</b> text</p>"#
+        );
+
+        // A different element replaces the clone, whose children are unknown
+        let other = html! { <p class="static">{"other"}</p> };
+        other.reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut second);
+        assert_eq!(
+            parent.inner_html(),
+            r#"<p class="static"><b>bold</b> text</p><p class="static">other</p>"#
+        );
+    }
+
     #[test]
     fn controlled_input_synced() {
         let (root, scope, parent) = setup_parent();
@@ -888,6 +1047,29 @@ mod tests {
         assert_eq!(vtag.tag(), "clipPath");
     }
 
+    #[test]
+    fn dynamic_tags_accept_tag_names() {
+        assert_eq!(TagName::from("INPUT"), TagName::Input);
+        assert_eq!(TagName::from("clipPath"), TagName::Other("clipPath".into()));
+        assert!(TagName::from("img").is_void());
+        assert!(!TagName::Textarea.is_void());
+
+        let tags = [TagName::Textarea, TagName::Input, TagName::from("section")];
+        let list = tags
+            .into_iter()
+            .map(|tag| html! { <@{tag} value="Hello" /> })
+            .collect::<Vec<_>>();
+        let textarea = assert_vtag_ref(&list[0]);
+        assert_eq!(textarea.tag(), "textarea");
+        assert_eq!(textarea.value(), Some(&AttrValue::Static("Hello")));
+        let input = assert_vtag_ref(&list[1]);
+        assert_eq!(input.tag(), "input");
+        assert_eq!(input.value(), Some(&AttrValue::Static("Hello")));
+        let section = assert_vtag_ref(&list[2]);
+        assert_eq!(section.tag(), "section");
+        assert!(section.value().is_none());
+    }
+
     #[test]
     fn reset_node_ref() {
         let (root, scope, parent) = setup_parent();
@@ -122,6 +122,17 @@ impl Fragment {
         Self(nodes, next_child)
     }
 
+    /// Returns whether the first node of the fragment is an opening tag of `collectable`.
+    pub fn starts_with(&self, collectable: &Collectable) -> bool {
+        self.front().is_some_and(|node| {
+            let comment_text = node.text_content().unwrap_or_default();
+
+            node.node_type() == Node::COMMENT_NODE
+                && comment_text.starts_with(collectable.open_start_mark())
+                && comment_text.ends_with(collectable.end_mark())
+        })
+    }
+
     /// Remove child nodes until first non-text node.
     pub fn trim_start_text_nodes(&mut self) {
         while let Some(ref m) = self.front().cloned() {
@@ -33,7 +33,7 @@ use bsuspense::BSuspense;
 use btag::{BTag, Registry};
 use btext::BText;
 pub(crate) use position::{DomSlot, DynamicDomSlot};
-use subtree_root::EventDescriptor;
+use subtree_root::{EventDescriptor, EventListener};
 pub use subtree_root::{set_event_bubbling, BSubtree};
 use traits::{Reconcilable, ReconcileTarget};
 use utils::test_log;
@@ -1,16 +1,17 @@
 //! Per-subtree state of apps
 
 use std::borrow::Cow;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
+use std::iter;
 use std::rc::{Rc, Weak};
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
 use wasm_bindgen::prelude::{wasm_bindgen, Closure};
-use wasm_bindgen::{intern, JsCast, UnwrapThrowExt};
+use wasm_bindgen::{intern, JsCast, JsValue, UnwrapThrowExt};
 use web_sys::{
-    AddEventListenerOptions, Element, Event, EventTarget as HtmlEventTarget, ShadowRoot,
+    AddEventListenerOptions, Element, Event, EventTarget as HtmlEventTarget, Node, ShadowRoot,
 };
 
 use super::{test_log, Registry};
@@ -121,9 +122,10 @@ impl From<&dyn Listener> for EventDescriptor {
 type EventClosure = Closure<dyn Fn(&Event)>;
 #[derive(Debug)]
 #[must_use = "event listener will never be called after being dropped"]
-struct EventListener {
+pub(crate) struct EventListener {
     target: HtmlEventTarget,
     event_type: Cow<'static, str>,
+    capture: bool,
     callback: Option<EventClosure>,
 }
 
@@ -135,7 +137,7 @@ impl Drop for EventListener {
                 .remove_event_listener_with_callback_and_bool(
                     &self.event_type,
                     callback.as_ref().unchecked_ref(),
-                    true, // Always capture
+                    self.capture,
                 )
                 .unwrap_throw();
         }
@@ -143,9 +145,11 @@ impl Drop for EventListener {
 }
 
 impl EventListener {
-    fn new(
+    /// Listens to events on `target`. Delegated events are always handled in the capture phase.
+    pub(crate) fn new(
         target: &HtmlEventTarget,
         desc: &EventDescriptor,
+        capture: bool,
         callback: impl 'static + Fn(&Event),
     ) -> Self {
         let event_type = desc.kind.type_name();
@@ -153,7 +157,7 @@ impl EventListener {
         let callback = Closure::wrap(Box::new(callback) as Box<dyn Fn(&Event)>);
         // defaults: { once: false }
         let options = AddEventListenerOptions::new();
-        options.set_capture(true);
+        options.set_capture(capture);
         options.set_passive(desc.passive);
 
         target
@@ -167,17 +171,10 @@ impl EventListener {
         EventListener {
             target: target.clone(),
             event_type,
+            capture,
             callback: Some(callback),
         }
     }
-
-    #[cfg(not(test))]
-    fn forget(mut self) {
-        if let Some(callback) = self.callback.take() {
-            // Should always match, but no need to introduce a panic path here
-            callback.forget();
-        }
-    }
 }
 
 /// Ensures event handler registration.
@@ -187,9 +184,7 @@ struct HostHandlers {
     /// The host element where events are registered
     host: HtmlEventTarget,
 
-    /// Keep track of all listeners to drop them on registry drop.
-    /// The registry is never dropped in production.
-    #[cfg(test)]
+    /// Keep track of all listeners to drop them when the subtree is unmounted.
     registered: Vec<(ListenerKind, EventListener)>,
 }
 
@@ -197,26 +192,25 @@ impl HostHandlers {
     fn new(host: HtmlEventTarget) -> Self {
         Self {
             host,
-            #[cfg(test)]
             registered: Vec::default(),
         }
     }
 
     fn add_listener(&mut self, desc: &EventDescriptor, callback: impl 'static + Fn(&Event)) {
-        let cl = EventListener::new(&self.host, desc, callback);
-
-        // Never drop the closure as this event handler is static
-        #[cfg(not(test))]
-        cl.forget();
-        #[cfg(test)]
+        let cl = EventListener::new(&self.host, desc, true, callback);
         self.registered.push((desc.kind.clone(), cl));
     }
+
+    /// Removes the listeners from the host.
+    fn clear(&mut self) {
+        self.registered.clear();
+    }
 }
 
 /// Per subtree data
 #[derive(Debug)]
 struct SubtreeData {
-    /// Data shared between all trees in an app
+    /// Data shared between all trees of all apps
     app_data: Rc<RefCell<AppData>>,
     /// Parent subtree
     parent: Option<ParentingInformation>,
@@ -225,6 +219,8 @@ struct SubtreeData {
     host: HtmlEventTarget,
     event_registry: RefCell<Registry>,
     global: RefCell<HostHandlers>,
+    /// Listeners are attached to their element instead of being delegated.
+    direct_listeners: Cell<bool>,
 }
 
 #[derive(Debug)]
@@ -246,11 +242,25 @@ impl PartialEq for WeakSubtree {
 }
 impl Eq for WeakSubtree {}
 
-/// Per tree data, shared between all subtrees in the hierarchy
+impl std::borrow::Borrow<TreeId> for WeakSubtree {
+    fn borrow(&self) -> &TreeId {
+        &self.subtree_id
+    }
+}
+
+/// Data shared between all subtrees of all apps mounted on the page, so that the events listened
+/// to by one app are delegated by the roots of the others, and bubble from an app mounted inside
+/// another one to it.
 #[derive(Debug, Default)]
 struct AppData {
     subtrees: HashSet<WeakSubtree>,
     listening: HashSet<EventDescriptor>,
+    /// The events some listeners handle in their capture phase.
+    capturing: HashSet<EventDescriptor>,
+}
+
+thread_local! {
+    static APP_DATA: Rc<RefCell<AppData>> = Rc::default();
 }
 
 impl AppData {
@@ -264,7 +274,14 @@ impl AppData {
         });
     }
 
-    fn ensure_handled(&mut self, desc: &EventDescriptor) {
+    fn subtree(&self, subtree_id: TreeId) -> Option<Rc<SubtreeData>> {
+        self.subtrees.get(&subtree_id)?.weak_ref.upgrade()
+    }
+
+    fn ensure_handled(&mut self, desc: &EventDescriptor, capture: bool) {
+        if capture && !self.capturing.contains(desc) {
+            self.capturing.insert(desc.clone());
+        }
         if !self.listening.insert(desc.clone()) {
             return;
         }
@@ -301,10 +318,27 @@ struct BrandingSearchResult {
 fn shadow_aware_parent(el: &Element) -> Option<Element> {
     match el.parent_element() {
         s @ Some(_) => s,
-        None => el.parent_node()?.dyn_ref::<ShadowRoot>().map(|h| h.host()),
+        None => {
+            let parent = el.parent_node()?;
+            match parent.dyn_ref::<ShadowRoot>() {
+                Some(root) => Some(root.host()),
+                // A shadow root of another document, see `as_element`
+                None => as_element(js_sys::Reflect::get(&parent, &intern("host").into()).ok()?),
+            }
+        }
     }
 }
 
+/// Casts `target` to an [Element], also accepting elements of other documents, such as the one of
+/// a popup window, which fail the `instanceof` checks of this window.
+fn as_element(target: JsValue) -> Option<Element> {
+    if target.has_type::<Element>() {
+        return Some(target.unchecked_into());
+    }
+    let node_type = js_sys::Reflect::get(&target, &intern("nodeType").into()).ok()?;
+    (node_type.as_f64()? == f64::from(Node::ELEMENT_NODE)).then(|| target.unchecked_into())
+}
+
 /// Deduce the subtree an element is part of. This already partially starts the bubbling
 /// process, as long as no listeners are encountered.
 /// Subtree roots are always branded with their own subtree id.
@@ -332,10 +366,10 @@ fn find_closest_branded_element(mut el: Element, do_bubble: bool) -> Option<Bran
 /// Iterate over all potentially listening elements in bubbling order.
 /// If bubbling is turned off, yields at most a single element.
 fn start_bubbling_from(
-    subtree: &SubtreeData,
+    subtree: &Rc<SubtreeData>,
     root_or_listener: Element,
     should_bubble: bool,
-) -> impl '_ + Iterator<Item = (&'_ SubtreeData, Element)> {
+) -> impl Iterator<Item = (Rc<SubtreeData>, Element)> {
     let start = subtree.bubble_to_inner_element(root_or_listener, should_bubble);
 
     std::iter::successors(start, move |(subtree, element)| {
@@ -352,9 +386,12 @@ impl SubtreeData {
         let tree_root_id = next_root_id();
         let event_registry = Registry::new();
         let host_handlers = HostHandlers::new(host_element.clone());
-        let app_data = match parent {
-            Some(ref parent) => parent.parent_root.app_data.clone(),
-            None => Rc::default(),
+        let (app_data, direct_listeners) = match parent {
+            Some(ref parent) => (
+                parent.parent_root.app_data.clone(),
+                parent.parent_root.direct_listeners.get(),
+            ),
+            None => (APP_DATA.with(Rc::clone), false),
         };
         let subtree = Rc::new(SubtreeData {
             parent,
@@ -364,6 +401,7 @@ impl SubtreeData {
             host: host_element.clone(),
             event_registry: RefCell::new(event_registry),
             global: RefCell::new(host_handlers),
+            direct_listeners: Cell::new(direct_listeners),
         });
         subtree.app_data.borrow_mut().add_subtree(&subtree);
         subtree
@@ -379,28 +417,41 @@ impl SubtreeData {
 
     // Bubble a potential parent until it reaches an internal element
     fn bubble_to_inner_element(
-        &self,
+        self: &Rc<Self>,
         parent_el: Element,
         should_bubble: bool,
-    ) -> Option<(&Self, Element)> {
-        let mut next_subtree = self;
+    ) -> Option<(Rc<Self>, Element)> {
+        let mut next_subtree = self.clone();
         let mut next_el = parent_el;
         if !should_bubble && next_subtree.host.eq(&next_el) {
             return None;
         }
         while next_subtree.host.eq(&next_el) {
             // we've reached the host, delegate to a parent if one exists
-            let parent = next_subtree.parent.as_ref()?;
-            next_subtree = &parent.parent_root;
-            next_el = parent.mount_element.clone();
+            (next_subtree, next_el) = match next_subtree.parent {
+                Some(ref parent) => (parent.parent_root.clone(), parent.mount_element.clone()),
+                None => next_subtree.enclosing_subtree()?,
+            };
         }
         Some((next_subtree, next_el))
     }
 
+    /// Returns the subtree of another app the host of this app is rendered in, if any, and its
+    /// element closest to the host.
+    fn enclosing_subtree(&self) -> Option<(Rc<Self>, Element)> {
+        let host = self.host.dyn_ref::<Element>()?;
+        let BrandingSearchResult {
+            branding,
+            closest_branded_ancestor,
+        } = find_closest_branded_element(shadow_aware_parent(host)?, true)?;
+        let subtree = self.app_data.borrow().subtree(branding)?;
+        Some((subtree, closest_branded_ancestor))
+    }
+
     fn start_bubbling_if_responsible<'s>(
-        &'s self,
+        self: &'s Rc<Self>,
         event: &'s Event,
-    ) -> Option<impl 's + Iterator<Item = (&'s SubtreeData, Element)>> {
+    ) -> Option<impl 's + Iterator<Item = (Rc<SubtreeData>, Element)>> {
         // Note: the event is not necessarily indentically the same object for all installed
         // handlers hence this cache can be unreliable. Hence the cached repsonsible_tree_id
         // might be missing. On the other hand, due to event retargeting at shadow roots,
@@ -425,7 +476,7 @@ impl SubtreeData {
         }
         // We're tasked with finding the subtree that is reponsible with handling the event, and/or
         // run the handling if that's `self`.
-        let target = event_path.get(0).dyn_into::<Element>().ok()?;
+        let target = as_element(event_path.get(0))?;
         let should_bubble = BUBBLE_EVENTS.load(Ordering::Relaxed) && event.bubbles();
         // We say that the most deeply nested subtree is "responsible" for handling the event.
         let (responsible_tree_id, bubbling_start) = if let Some(branding) = cached_branding {
@@ -474,20 +525,38 @@ impl SubtreeData {
     }
 
     /// Handle a global event firing
-    fn handle(&self, desc: EventDescriptor, event: Event) {
-        let run_handler = |root: &Self, el: &Element| {
-            let handler = Registry::get_handler(root.event_registry(), el, &desc);
+    fn handle(self: &Rc<Self>, desc: EventDescriptor, event: Event) {
+        let run_handler = |root: &Self, el: &Element, capture: bool| {
+            let handler = Registry::get_handler(root.event_registry(), el, &desc, capture);
             if let Some(handler) = handler {
                 handler(&event)
             }
         };
+        let capturing = self.app_data.borrow().capturing.contains(&desc);
         if let Some(bubbling_it) = self.start_bubbling_if_responsible(&event) {
             test_log!("Running handler on subtree {}", self.subtree_id);
-            for (subtree, el) in bubbling_it {
+            if !capturing {
+                for (subtree, el) in bubbling_it {
+                    if event.cancel_bubble() {
+                        break;
+                    }
+                    run_handler(&subtree, &el, false);
+                }
+                return;
+            }
+
+            // Capture listeners run first, from the outermost element to the target.
+            let path: Vec<_> = bubbling_it.collect();
+            let phases = path
+                .iter()
+                .rev()
+                .map(|m| (m, true))
+                .chain(path.iter().map(|m| (m, false)));
+            for ((subtree, el), capture) in phases {
                 if event.cancel_bubble() {
                     break;
                 }
-                run_handler(subtree, &el);
+                run_handler(subtree, el, capture);
             }
         }
     }
@@ -532,9 +601,47 @@ impl BSubtree {
         Self::do_create_root(host_element, Some(parent_information))
     }
 
-    /// Ensure the event described is handled on all subtrees
-    pub fn ensure_handled(&self, desc: &EventDescriptor) {
-        self.0.app_data.borrow_mut().ensure_handled(desc);
+    /// Ensure the event described is handled on all subtrees, also in its capture phase if
+    /// `capture` is set.
+    pub fn ensure_handled(&self, desc: &EventDescriptor, capture: bool) {
+        self.0.app_data.borrow_mut().ensure_handled(desc, capture);
+    }
+
+    /// Attaches the listeners of the whole tree, including its portals, to their elements instead
+    /// of delegating them.
+    pub fn set_direct_listeners(&self, direct: bool) {
+        self.0.direct_listeners.set(direct);
+    }
+
+    /// Whether `listener` is attached to its element instead of being delegated.
+    pub fn is_direct(&self, listener: &dyn Listener) -> bool {
+        listener.direct() || self.0.direct_listeners.get()
+    }
+
+    /// Removes the listeners delegating the events of this tree and of its portals, and the
+    /// branding of its host, once the app it belongs to is destroyed.
+    pub fn unmount(&self) {
+        let subtrees: Vec<_> = self
+            .0
+            .app_data
+            .borrow()
+            .subtrees
+            .iter()
+            .filter_map(|m| m.weak_ref.upgrade())
+            .collect();
+        for subtree in subtrees {
+            let is_descendant = iter::successors(Some(&subtree), |m| {
+                m.parent.as_ref().map(|m| &m.parent_root)
+            })
+            .any(|m| Rc::ptr_eq(m, &self.0));
+            if is_descendant {
+                subtree.host_handlers().borrow_mut().clear();
+            }
+        }
+        let _ = js_sys::Reflect::delete_property(
+            self.0.host.unchecked_ref(),
+            &intern("__yew_subtree_id").into(),
+        );
     }
 
     /// Run f with access to global Registry
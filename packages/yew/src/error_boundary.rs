@@ -0,0 +1,327 @@
+//! This module contains the [`ErrorBoundary`] component.
+
+use std::mem;
+
+use crate::callback::Callback;
+use crate::html;
+use crate::html::{Component, ComponentError, Context, Html, Properties};
+use crate::virtual_dom::{AttrValue, VNode};
+
+/// An error caught by an [`ErrorBoundary`], passed to its fallback.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaughtError {
+    error: ComponentError,
+    reset: Callback<()>,
+}
+
+impl CaughtError {
+    /// Returns the error.
+    pub fn error(&self) -> &ComponentError {
+        &self.error
+    }
+
+    /// Renders the children of the boundary again, from scratch.
+    pub fn reset(&self) {
+        self.reset.emit(());
+    }
+
+    /// Returns a callback resetting the boundary, for example for the `onclick` of a "Retry"
+    /// button.
+    pub fn reset_callback<IN>(&self) -> Callback<IN> {
+        let reset = self.reset.clone();
+        Callback::from(move |_| reset.emit(()))
+    }
+}
+
+/// Properties for [ErrorBoundary].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct ErrorBoundaryProps {
+    /// The Children of the current ErrorBoundary Component.
+    #[prop_or_default]
+    pub children: Html,
+
+    /// Renders the UI shown in place of the children once one of them failed.
+    pub fallback: Callback<CaughtError, Html>,
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub enum ErrorBoundaryMsg {
+    Catch(ComponentError),
+    Reset,
+}
+
+/// Catches errors of the components it contains and renders a fallback instead.
+///
+/// Once a component inside the boundary returns a [`ComponentError`] from its `view`, or panics
+/// while rendering, handling a message, receiving new properties or running its effects, the whole
+/// subtree is replaced by the result of [`fallback`](ErrorBoundaryProps::fallback). The component
+/// that failed isn't updated or rendered anymore, while the components outside of the boundary
+/// keep working. The subtree is rendered again from scratch when the boundary is
+/// [reset](CaughtError::reset).
+///
+/// Errors of the fallback itself are caught by the next boundary up. Errors of components outside
+/// of any boundary still panic.
+///
+/// Panics are only caught on targets where they unwind. `wasm32-unknown-unknown` aborts on
+/// panic, so in the browser only returned errors are caught.
+///
+/// During server-side rendering, the children of a boundary are rendered before any of them is
+/// written, and replaced by the fallback if one of them failed. When the page is hydrated, the
+/// children are then rendered again on the client in place of the fallback. Components that fail
+/// after their boundary was written, like in the deferred content of a `<Suspense>`, are rendered
+/// empty.
+///
+/// # Example
+///
+/// ```
+/// use yew::error_boundary::{CaughtError, ErrorBoundary};
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Profile() -> HtmlResult {
+///     let age: u32 = "forty-two".parse().map_err(ComponentError::new)?;
+///     Ok(html! { <p>{ age }</p> })
+/// }
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     let fallback = |e: CaughtError| {
+///         html! {
+///             <div class="error">
+///                 <p>{ format!("Something went wrong: {}", e.error()) }</p>
+///                 <button onclick={e.reset_callback()}>{ "Retry" }</button>
+///             </div>
+///         }
+///     };
+///
+///     html! {
+///         <ErrorBoundary {fallback}>
+///             <Profile />
+///         </ErrorBoundary>
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ErrorBoundary {
+    error: Option<ComponentError>,
+    /// Whether the children failed during server-side rendering, and are rendered again once the
+    /// fallback rendered by the server is hydrated.
+    failed_on_server: bool,
+}
+
+impl ErrorBoundary {
+    /// The prepared state of a boundary whose children failed during server-side rendering.
+    #[cfg(any(feature = "ssr", feature = "hydration"))]
+    pub(crate) const FAILED_ON_SERVER: &'static str = "failed";
+}
+
+impl Component for ErrorBoundary {
+    type Message = ErrorBoundaryMsg;
+    type Properties = ErrorBoundaryProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        #[cfg(feature = "hydration")]
+        let failed_on_server = ctx.prepared_state() == Some(Self::FAILED_ON_SERVER);
+        #[cfg(not(feature = "hydration"))]
+        let failed_on_server = {
+            let _ = ctx;
+            false
+        };
+
+        Self {
+            error: None,
+            failed_on_server,
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            // Only the first error is kept, the other children are about to be replaced anyway.
+            ErrorBoundaryMsg::Catch(error) if self.error.is_none() => {
+                self.error = Some(error);
+                true
+            }
+            ErrorBoundaryMsg::Catch(_) => false,
+            ErrorBoundaryMsg::Reset => {
+                let failed_on_server = mem::take(&mut self.failed_on_server);
+                self.error.take().is_some() || failed_on_server
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let ErrorBoundaryProps { children, fallback } = ctx.props();
+
+        // Hydrates the fallback rendered by the server, whatever its content.
+        if self.failed_on_server {
+            return VNode::from_html_unchecked(AttrValue::default());
+        }
+
+        #[cfg(feature = "ssr")]
+        if let Some(ref ssr) = ctx.link().ssr {
+            let fallback = fallback.clone();
+            let reset = ctx.link().callback(|_| ErrorBoundaryMsg::Reset);
+            ssr.set_error_boundary(
+                ctx.link().id,
+                Box::new(move |error| {
+                    let caught = CaughtError {
+                        error,
+                        reset: reset.clone(),
+                    };
+                    html! { <ErrorFallback>{ fallback.emit(caught) }</ErrorFallback> }
+                }),
+            );
+        }
+
+        match self.error.clone() {
+            Some(error) => {
+                let caught = CaughtError {
+                    error,
+                    reset: ctx.link().callback(|_| ErrorBoundaryMsg::Reset),
+                };
+
+                html! { <ErrorFallback>{ fallback.emit(caught) }</ErrorFallback> }
+            }
+            None => html! { <>{ children.clone() }</> },
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        if self.failed_on_server {
+            ctx.link().send_message(ErrorBoundaryMsg::Reset);
+        }
+    }
+}
+
+/// Properties for [ErrorFallback].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub(crate) struct ErrorFallbackProps {
+    pub children: Html,
+}
+
+/// Wraps the fallback of an [`ErrorBoundary`], so that errors of the fallback are caught by the
+/// next boundary up.
+#[derive(Debug)]
+pub(crate) struct ErrorFallback;
+
+impl Component for ErrorFallback {
+    type Message = ();
+    type Properties = ErrorFallbackProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        ctx.props().children.clone()
+    }
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+mod feat_csr_ssr {
+    use std::iter;
+
+    use super::*;
+    use crate::html::{AnyScope, Scope};
+
+    impl ErrorBoundary {
+        pub(crate) fn catch(scope: &Scope<Self>, error: ComponentError) {
+            #[cfg(feature = "ssr")]
+            if let Some(ref ssr) = scope.ssr {
+                ssr.catch_error(scope.id, error.clone());
+            }
+            scope.send_message(ErrorBoundaryMsg::Catch(error));
+        }
+    }
+
+    /// Returns the boundary catching the errors of the component of `scope`.
+    pub(crate) fn find_error_boundary(scope: &AnyScope) -> Option<Scope<ErrorBoundary>> {
+        // Each fallback between the component and a boundary belongs to that boundary, which must
+        // then be skipped.
+        let mut skipped = 0usize;
+        for scope in iter::successors(scope.get_parent(), |m| m.get_parent()) {
+            if scope.try_downcast::<ErrorFallback>().is_some() {
+                skipped += 1;
+            } else if let Some(boundary) = scope.try_downcast::<ErrorBoundary>() {
+                match skipped.checked_sub(1) {
+                    Some(m) => skipped = m,
+                    None => return Some(boundary),
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub(crate) use feat_csr_ssr::*;
+
+// Panics only unwind on native targets.
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::LocalServerRenderer as ServerRenderer;
+
+    #[test]
+    async fn failed_children_are_replaced_by_fallback() {
+        #[function_component]
+        fn Failing() -> HtmlResult {
+            Err(ComponentError::new("failed").into())
+        }
+
+        #[function_component]
+        fn Panicking() -> Html {
+            panic!("panicked");
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <ErrorBoundary fallback={|_| html! { "fallback" }}>
+                    <div>{ "before" }</div>
+                    <Failing />
+                    <Panicking />
+                    <div>{ "after" }</div>
+                </ErrorBoundary>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "fallback");
+
+        // The fallback is hydrated as raw HTML, and the boundary told to render its children.
+        let s = ServerRenderer::<Comp>::new().render().await;
+        assert!(s.contains("<!--<#>-->fallback<!--</#>-->"));
+        assert!(s.contains(r#"<script type="application/x-yew-comp-state">failed</script>"#));
+    }
+
+    #[test]
+    async fn boundaries_without_errors_render_children() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <ErrorBoundary fallback={|_| html! { "fallback" }}>
+                    <div>{ "content" }</div>
+                </ErrorBoundary>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>content</div>");
+    }
+}
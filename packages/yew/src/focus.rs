@@ -0,0 +1,443 @@
+//! This module contains components and hooks managing the focus of dialogs and menus.
+//!
+//! [`FocusTrap`] keeps the focus within its children while it is mounted, as expected of modal
+//! dialogs, and gives it back to the element that had it once it is unmounted.
+//! [`use_roving_tabindex`] makes a group of items, like the items of a menu or the tabs of a tab
+//! list, a single stop of the tab sequence, moving the focus between the items with the arrow keys.
+//!
+//! # Example
+//!
+//! ```rust
+//! use yew::focus::FocusTrap;
+//! use yew::prelude::*;
+//!
+//! #[function_component]
+//! fn ConfirmDialog() -> Html {
+//!     let confirm = use_node_ref();
+//!
+//!     html! {
+//!         <FocusTrap class="dialog" initial_focus={confirm.clone()}>
+//!             <p>{ "Delete the file?" }</p>
+//!             <button>{ "Cancel" }</button>
+//!             <button ref={confirm}>{ "Delete" }</button>
+//!         </FocusTrap>
+//!     }
+//! }
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::{FocusEvent, HtmlElement, KeyboardEvent, Node};
+
+use crate::callback::Callback;
+use crate::functional::{
+    function_component, hook, use_effect_with, use_mut_ref, use_node_ref, use_state_eq,
+    UseStateSetter,
+};
+use crate::html;
+use crate::html::{Classes, Html, NodeRef, Properties};
+use crate::virtual_dom::{AttrValue, Key};
+
+/// The elements that can be focused with the keyboard, unless their `tabindex` is negative.
+const FOCUSABLE: &str = "a[href], area[href], button:not([disabled]), \
+                         input:not([disabled]):not([type=\"hidden\"]), select:not([disabled]), \
+                         textarea:not([disabled]), iframe, summary, [tabindex], \
+                         [contenteditable]:not([contenteditable=\"false\"])";
+
+/// Returns the elements within `container` in the tab sequence, in document order.
+fn focusables(container: &HtmlElement) -> Vec<HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(FOCUSABLE) else {
+        return Vec::new();
+    };
+
+    (0..nodes.length())
+        .filter_map(|i| nodes.get(i)?.dyn_into::<HtmlElement>().ok())
+        .filter(|m| m.tab_index() >= 0 && !m.hidden())
+        .collect()
+}
+
+thread_local! {
+    static NEXT_TRAP_ID: Cell<usize> = const { Cell::new(0) };
+    /// The active traps, the last one being the one keeping the focus.
+    static TRAPS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// An active focus trap, released when dropped.
+struct Trap {
+    id: usize,
+    previous: Option<HtmlElement>,
+    restore_focus: bool,
+    _focusin: EventListener,
+}
+
+impl Trap {
+    fn activate(container: NodeRef, initial_focus: Option<NodeRef>, restore_focus: bool) -> Self {
+        let document = gloo::utils::document();
+        let previous = document
+            .active_element()
+            .and_then(|m| m.dyn_into::<HtmlElement>().ok());
+
+        let id = NEXT_TRAP_ID.with(|m| m.replace(m.get() + 1));
+        TRAPS.with(|m| m.borrow_mut().push(id));
+
+        if let Some(element) = container.cast::<HtmlElement>() {
+            // Elements focused when they are mounted, like with `autofocus`, keep the focus.
+            let active = previous.as_ref().map(AsRef::<Node>::as_ref);
+            if !element.contains(active) {
+                let _ = initial_focus
+                    .and_then(|m| m.cast::<HtmlElement>())
+                    .or_else(|| focusables(&element).into_iter().next())
+                    .unwrap_or(element)
+                    .focus();
+            }
+        }
+
+        // Brings back the focus when it leaves the trap, like when clicking outside of it.
+        let focusin = EventListener::new(&document, "focusin", move |e| {
+            if TRAPS.with(|m| m.borrow().last() != Some(&id)) {
+                return;
+            }
+            let Some(element) = container.cast::<HtmlElement>() else {
+                return;
+            };
+            let target = e.target().and_then(|m| m.dyn_into::<Node>().ok());
+            if !element.contains(target.as_ref()) {
+                let _ = focusables(&element)
+                    .into_iter()
+                    .next()
+                    .unwrap_or(element)
+                    .focus();
+            }
+        });
+
+        Self {
+            id,
+            previous,
+            restore_focus,
+            _focusin: focusin,
+        }
+    }
+}
+
+impl Drop for Trap {
+    fn drop(&mut self) {
+        let _ = TRAPS.try_with(|m| m.borrow_mut().retain(|id| *id != self.id));
+
+        if self.restore_focus {
+            if let Some(previous) = self.previous.as_ref().filter(|m| m.is_connected()) {
+                let _ = previous.focus();
+            }
+        }
+    }
+}
+
+/// Moves the focus from the last element of `container` to its first one on `Tab`, and the
+/// other way around on `Shift+Tab`.
+fn cycle(container: &NodeRef, e: &KeyboardEvent) {
+    let Some(element) = container.cast::<HtmlElement>() else {
+        return;
+    };
+    let focusables = focusables(&element);
+    let (Some(first), Some(last)) = (focusables.first(), focusables.last()) else {
+        // Nothing else can take the focus.
+        e.prevent_default();
+        return;
+    };
+
+    let active = gloo::utils::document().active_element();
+    let active: Option<&Node> = active.as_ref().map(AsRef::as_ref);
+    let at_edge = if e.shift_key() {
+        first.is_same_node(active) || element.is_same_node(active)
+    } else {
+        last.is_same_node(active)
+    };
+    if at_edge {
+        e.prevent_default();
+        let target = if e.shift_key() { last } else { first };
+        let _ = target.focus();
+    }
+}
+
+/// Properties for [`FocusTrap`].
+#[derive(Debug, Properties, PartialEq)]
+pub struct FocusTrapProps {
+    /// Whether the focus is trapped. The focus is trapped when the trap becomes active, and given
+    /// back when it becomes inactive.
+    #[prop_or(true)]
+    pub active: bool,
+    /// The element focused when the trap becomes active, instead of the first focusable element
+    /// of the children.
+    #[prop_or_default]
+    pub initial_focus: Option<NodeRef>,
+    /// Whether the focus is given back to the element that had it before the trap became active,
+    /// when it is released.
+    #[prop_or(true)]
+    pub restore_focus: bool,
+    /// The classes of the element wrapping the children.
+    #[prop_or_default]
+    pub class: Classes,
+    /// The children of the trap.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Keeps the focus within its children, as expected of modal dialogs.
+///
+/// When it becomes active, the trap focuses the [`initial_focus`](FocusTrapProps::initial_focus)
+/// element, or its first focusable element, or the `<div>` wrapping its children if it has none.
+/// `Tab` and `Shift+Tab` then cycle through its focusable elements, and the focus is brought back
+/// when it moves out of the trap, like when clicking outside of it. When the trap is unmounted or
+/// becomes inactive, the focus is given back to the element that had it before.
+///
+/// Traps can be nested, like for a dialog opened from another one: only the trap that became
+/// active last keeps the focus, until it is released.
+///
+/// See the [module documentation](self) for an example.
+#[function_component]
+pub fn FocusTrap(props: &FocusTrapProps) -> Html {
+    let container = use_node_ref();
+
+    {
+        let container = container.clone();
+        let initial_focus = props.initial_focus.clone();
+        let restore_focus = props.restore_focus;
+        use_effect_with(props.active, move |active| {
+            let trap = active.then(|| Trap::activate(container, initial_focus, restore_focus));
+            move || drop(trap)
+        });
+    }
+
+    let onkeydown = {
+        let container = container.clone();
+        let active = props.active;
+        Callback::from(move |e: KeyboardEvent| {
+            // Nested traps handle the key first.
+            if active && e.key() == "Tab" && !e.default_prevented() {
+                cycle(&container, &e);
+            }
+        })
+    };
+
+    html! {
+        <div ref={container} class={props.class.clone()} tabindex="-1" {onkeydown}>
+            {props.children.clone()}
+        </div>
+    }
+}
+
+/// The arrow keys moving the focus between the items of a [`use_roving_tabindex`] group.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Orientation {
+    /// `ArrowLeft` and `ArrowRight`, like for a menu bar or a tab list.
+    Horizontal,
+    /// `ArrowUp` and `ArrowDown`, like for a menu or a list box.
+    #[default]
+    Vertical,
+    /// All the arrow keys, like for a toolbar wrapping on several lines.
+    Both,
+}
+
+impl Orientation {
+    /// Returns the index of the item focused by `key` from the item at `current`, among `len`
+    /// items. The focus wraps around at both ends, and `Home` and `End` move it to the first and
+    /// last items.
+    fn step(self, len: usize, current: usize, key: &str) -> Option<usize> {
+        let (previous, next): (&[&str], &[&str]) = match self {
+            Self::Horizontal => (&["ArrowLeft"], &["ArrowRight"]),
+            Self::Vertical => (&["ArrowUp"], &["ArrowDown"]),
+            Self::Both => (&["ArrowLeft", "ArrowUp"], &["ArrowRight", "ArrowDown"]),
+        };
+
+        match key {
+            _ if len == 0 => None,
+            "Home" => Some(0),
+            "End" => Some(len - 1),
+            _ if previous.contains(&key) => Some((current + len - 1) % len),
+            _ if next.contains(&key) => Some((current + 1) % len),
+            _ => None,
+        }
+    }
+}
+
+/// The attributes and listeners of an item of a [`use_roving_tabindex`] group, returned by
+/// [`UseRovingTabindexHandle::item`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RovingTabindexItem {
+    /// The reference to set as the `ref` of the item.
+    pub node_ref: NodeRef,
+    /// The `tabindex` of the item, `0` for the active item and `-1` for the others.
+    pub tabindex: AttrValue,
+    /// The `onkeydown` listener of the item, moving the focus with the arrow keys.
+    pub onkeydown: Callback<KeyboardEvent>,
+    /// The `onfocus` listener of the item, making it the active item when it is focused.
+    pub onfocus: Callback<FocusEvent>,
+}
+
+/// Handle returned by [`use_roving_tabindex`].
+#[derive(Debug, Clone)]
+pub struct UseRovingTabindexHandle {
+    keys: Rc<Vec<Key>>,
+    active: Option<Key>,
+    orientation: Orientation,
+    refs: Rc<RefCell<HashMap<Key, NodeRef>>>,
+    select: UseStateSetter<Option<Key>>,
+}
+
+impl UseRovingTabindexHandle {
+    /// Returns the key of the item in the tab sequence.
+    pub fn active(&self) -> Option<&Key> {
+        self.active.as_ref()
+    }
+
+    /// Makes the item of `key` the active item, and focuses it.
+    pub fn focus(&self, key: &Key) {
+        self.select.set(Some(key.clone()));
+        if let Some(element) = self
+            .refs
+            .borrow()
+            .get(key)
+            .and_then(|m| m.cast::<HtmlElement>())
+        {
+            let _ = element.focus();
+        }
+    }
+
+    /// Returns the attributes and listeners to set on the item of `key`.
+    pub fn item(&self, key: &Key) -> RovingTabindexItem {
+        let node_ref = self
+            .refs
+            .borrow_mut()
+            .entry(key.clone())
+            .or_default()
+            .clone();
+        let tabindex = if self.active.as_ref() == Some(key) {
+            "0"
+        } else {
+            "-1"
+        };
+
+        let onkeydown = {
+            let handle = self.clone();
+            let key = key.clone();
+            Callback::from(move |e: KeyboardEvent| {
+                let keys = &handle.keys;
+                let Some(current) = keys.iter().position(|m| *m == key) else {
+                    return;
+                };
+                if let Some(next) = handle.orientation.step(keys.len(), current, &e.key()) {
+                    e.prevent_default();
+                    handle.focus(&keys[next]);
+                }
+            })
+        };
+        let onfocus = {
+            let select = self.select.clone();
+            let key = key.clone();
+            Callback::from(move |_: FocusEvent| select.set(Some(key.clone())))
+        };
+
+        RovingTabindexItem {
+            node_ref,
+            tabindex: tabindex.into(),
+            onkeydown,
+            onfocus,
+        }
+    }
+}
+
+/// This hook manages the focus of a group of items with a roving tabindex.
+///
+/// Only the active item of the group is in the tab sequence, so that the whole group is a single
+/// stop of it. The arrow keys of `orientation` move the focus to the previous and next items,
+/// wrapping around at both ends, and `Home` and `End` to the first and last items. The focused
+/// item becomes the active one.
+///
+/// The items are identified by the keys of `keys`, in the order of the group, which usually are
+/// the keys of the children rendering them. The first item is active until another one is
+/// focused, and when the active item is removed.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::focus::{use_roving_tabindex, Orientation, RovingTabindexItem};
+/// use yew::prelude::*;
+/// use yew::virtual_dom::Key;
+///
+/// #[function_component]
+/// fn Menu() -> Html {
+///     let items = ["Open", "Save", "Close"];
+///     let roving = use_roving_tabindex(
+///         items.iter().map(|m| Key::from(*m)).collect(),
+///         Orientation::Vertical,
+///     );
+///
+///     html! {
+///         <ul role="menu">
+///             { for items.iter().map(|item| {
+///                 let RovingTabindexItem { node_ref, tabindex, onkeydown, onfocus } =
+///                     roving.item(&Key::from(*item));
+///                 html! {
+///                     <li key={*item} role="menuitem" ref={node_ref} {tabindex} {onkeydown}
+///                         {onfocus}>
+///                         { *item }
+///                     </li>
+///                 }
+///             }) }
+///         </ul>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_roving_tabindex(keys: Vec<Key>, orientation: Orientation) -> UseRovingTabindexHandle {
+    let selected = use_state_eq(|| None::<Key>);
+    let refs = use_mut_ref(HashMap::<Key, NodeRef>::new);
+    refs.borrow_mut().retain(|key, _| keys.contains(key));
+
+    let active = (*selected)
+        .clone()
+        .filter(|m| keys.contains(m))
+        .or_else(|| keys.first().cloned());
+
+    UseRovingTabindexHandle {
+        keys: Rc::new(keys),
+        active,
+        orientation,
+        refs,
+        select: selected.setter(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arrow_keys_follow_orientation() {
+        let vertical = Orientation::Vertical;
+        assert_eq!(vertical.step(3, 0, "ArrowDown"), Some(1));
+        assert_eq!(vertical.step(3, 1, "ArrowUp"), Some(0));
+        assert_eq!(vertical.step(3, 1, "ArrowRight"), None);
+
+        let horizontal = Orientation::Horizontal;
+        assert_eq!(horizontal.step(3, 0, "ArrowRight"), Some(1));
+        assert_eq!(horizontal.step(3, 0, "ArrowDown"), None);
+
+        assert_eq!(Orientation::Both.step(3, 1, "ArrowLeft"), Some(0));
+        assert_eq!(Orientation::Both.step(3, 1, "ArrowDown"), Some(2));
+    }
+
+    #[test]
+    fn focus_wraps_around() {
+        let vertical = Orientation::Vertical;
+        assert_eq!(vertical.step(3, 2, "ArrowDown"), Some(0));
+        assert_eq!(vertical.step(3, 0, "ArrowUp"), Some(2));
+        assert_eq!(vertical.step(3, 1, "Home"), Some(0));
+        assert_eq!(vertical.step(3, 1, "End"), Some(2));
+        assert_eq!(vertical.step(0, 0, "End"), None);
+        assert_eq!(vertical.step(3, 1, "a"), None);
+    }
+}
@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::rc::Rc;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use wasm_bindgen::JsCast;
+use web_sys::{FocusEvent, HtmlInputElement, InputEvent, SubmitEvent};
+
+use super::state::{FormAction, FormState, InputValue};
+use crate::callback::Callback;
+use crate::functional::{hook, use_reducer, UseReducerHandle};
+use crate::virtual_dom::AttrValue;
+
+/// The message reported for inputs that cannot be converted into the type of their field.
+const INVALID_VALUE: &str = "invalid value";
+
+/// Validation of the value of a form.
+///
+/// The default implementation accepts every value, so forms without validation rules only need an
+/// empty `impl Validate for MyForm {}`.
+pub trait Validate {
+    /// Reports the errors of this value in `errors`, by field name.
+    fn validate(&self, errors: &mut FormErrors) {
+        let _ = errors;
+    }
+}
+
+/// The validation errors of a form, by field name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormErrors {
+    errors: BTreeMap<String, AttrValue>,
+}
+
+impl FormErrors {
+    /// Reports `message` for the field `name`.
+    ///
+    /// Only the first error reported for a field is kept.
+    pub fn add(&mut self, name: impl Into<String>, message: impl Into<AttrValue>) {
+        self.errors
+            .entry(name.into())
+            .or_insert_with(|| message.into());
+    }
+
+    /// Returns the error of the field `name`.
+    pub fn get(&self, name: &str) -> Option<&AttrValue> {
+        self.errors.get(name)
+    }
+
+    /// Returns `true` if no error was reported.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns an iterator over the field names and their errors.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &AttrValue)> {
+        self.errors.iter().map(|(k, v)| (k.as_str(), v))
+    }
+}
+
+/// Everything needed to bind an input to a field of a form.
+///
+/// Pass [`value`](Self::value) (or [`checked`](Self::checked) for checkboxes),
+/// [`oninput`](Self::oninput) and [`onblur`](Self::onblur) to the input to make it controlled by
+/// the form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldBinding {
+    /// The name of the field.
+    pub name: AttrValue,
+    /// The text of the input.
+    pub value: AttrValue,
+    /// Whether the checkbox is checked, for boolean fields.
+    pub checked: bool,
+    /// Updates the field with the value of the input.
+    pub oninput: Callback<InputEvent>,
+    /// Marks the field as touched.
+    pub onblur: Callback<FocusEvent>,
+    /// The validation error of the field, once it has been touched or the form submitted.
+    pub error: Option<AttrValue>,
+    /// Whether the value differs from the initial one.
+    pub dirty: bool,
+    /// Whether the input has lost focus at least once.
+    pub touched: bool,
+}
+
+/// State handle for the [`use_form`] hook.
+pub struct UseFormHandle<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    state: UseReducerHandle<FormState<T>>,
+    value: Option<Rc<T>>,
+    errors: Rc<FormErrors>,
+}
+
+impl<T> UseFormHandle<T>
+where
+    T: Serialize + DeserializeOwned + Validate + 'static,
+{
+    /// Returns the binding of the field `name`.
+    pub fn field(&self, name: &str) -> FieldBinding {
+        let state = &self.state;
+        let show_error = state.is_touched(name) || state.is_submitted();
+
+        let oninput = {
+            let dispatcher = state.dispatcher();
+            let name = name.to_owned();
+            Callback::from(move |e: InputEvent| {
+                let Some(target) = e.target() else {
+                    return;
+                };
+
+                let value = match target.dyn_ref::<HtmlInputElement>() {
+                    Some(input) if input.type_() == "checkbox" => {
+                        InputValue::Checked(input.checked())
+                    }
+                    _ => InputValue::Text(
+                        js_sys::Reflect::get(&target, &"value".into())
+                            .ok()
+                            .and_then(|m| m.as_string())
+                            .unwrap_or_default(),
+                    ),
+                };
+
+                dispatcher.dispatch(FormAction::Input {
+                    name: name.clone(),
+                    value,
+                });
+            })
+        };
+
+        let onblur = {
+            let dispatcher = state.dispatcher();
+            let name = name.to_owned();
+            Callback::from(move |_| dispatcher.dispatch(FormAction::Touch(name.clone())))
+        };
+
+        FieldBinding {
+            name: AttrValue::from(name.to_owned()),
+            value: state.text(name).into(),
+            checked: state.checked(name),
+            oninput,
+            onblur,
+            error: self.errors.get(name).filter(|_| show_error).cloned(),
+            dirty: state.is_dirty(name),
+            touched: state.is_touched(name),
+        }
+    }
+
+    /// Returns the current value of the form.
+    pub fn value(&self) -> Option<&T> {
+        self.value.as_deref()
+    }
+
+    /// Returns the validation errors of all fields, touched or not.
+    pub fn errors(&self) -> &FormErrors {
+        &self.errors
+    }
+
+    /// Returns `true` if the form has no validation errors.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns `true` if any field differs from its initial value.
+    pub fn is_dirty(&self) -> bool {
+        self.state.is_form_dirty()
+    }
+
+    /// Restores the initial values and clears the touched and submitted state.
+    pub fn reset(&self) {
+        self.state.dispatch(FormAction::Reset);
+    }
+
+    /// Returns a callback for the `onsubmit` event of the `<form>` element.
+    ///
+    /// The default action of the event is prevented and the form is marked as submitted, so that
+    /// errors are shown for every field. `callback` is only emitted if the form is valid.
+    pub fn onsubmit(&self, callback: Callback<T>) -> Callback<SubmitEvent> {
+        let dispatcher = self.state.dispatcher();
+        let valid = self.is_valid();
+        let state = self.state.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            dispatcher.dispatch(FormAction::Submit);
+
+            if valid {
+                if let Some(value) = state.value() {
+                    callback.emit(value);
+                }
+            }
+        })
+    }
+}
+
+impl<T> Clone for UseFormHandle<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            value: self.value.clone(),
+            errors: self.errors.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for UseFormHandle<T>
+where
+    T: Serialize + DeserializeOwned + fmt::Debug + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseFormHandle")
+            .field("value", &self.value)
+            .field("errors", &self.errors)
+            .finish()
+    }
+}
+
+/// This hook is used to manage a form bound to a value of type `T`, starting from its default.
+///
+/// See the [module documentation](crate::form) for an example.
+#[hook]
+pub fn use_form<T>() -> UseFormHandle<T>
+where
+    T: Default + Serialize + DeserializeOwned + Validate + 'static,
+{
+    use_form_with(T::default)
+}
+
+/// [`use_form`] starting from the value returned by `init`, for example to edit an existing
+/// record.
+#[hook]
+pub fn use_form_with<T, F>(init: F) -> UseFormHandle<T>
+where
+    T: Serialize + DeserializeOwned + Validate + 'static,
+    F: FnOnce() -> T,
+{
+    let state = use_reducer(move || FormState::new(&init()));
+
+    let value = state.value();
+    let mut errors = FormErrors::default();
+    for name in state.invalid_fields() {
+        errors.add(name, INVALID_VALUE);
+    }
+    if let Some(value) = &value {
+        value.validate(&mut errors);
+    }
+
+    UseFormHandle {
+        state,
+        value: value.map(Rc::new),
+        errors: Rc::new(errors),
+    }
+}
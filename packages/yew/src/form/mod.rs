@@ -0,0 +1,65 @@
+//! Form state management for values that can be (de)serialized with serde.
+//!
+//! [`use_form`] binds a form to a value of a struct type. Inputs are bound to its fields by name
+//! with [`UseFormHandle::field`], which keeps them controlled, converts their text into the type
+//! of the field and tracks whether they have been changed (dirty) or visited (touched). Validation
+//! rules are defined by implementing [`Validate`] for the struct, and their errors are reported
+//! per field.
+//!
+//! # Example
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use yew::form::{use_form, FormErrors, Validate};
+//! use yew::prelude::*;
+//!
+//! #[derive(Debug, Default, Clone, Serialize, Deserialize)]
+//! struct Signup {
+//!     email: String,
+//!     age: u32,
+//!     newsletter: bool,
+//! }
+//!
+//! impl Validate for Signup {
+//!     fn validate(&self, errors: &mut FormErrors) {
+//!         if !self.email.contains('@') {
+//!             errors.add("email", "please enter an email address");
+//!         }
+//!         if self.age < 18 {
+//!             errors.add("age", "you must be an adult to sign up");
+//!         }
+//!     }
+//! }
+//!
+//! #[function_component]
+//! fn SignupForm() -> Html {
+//!     let form = use_form::<Signup>();
+//!     let onsubmit = form.onsubmit(Callback::from(|signup: Signup| {
+//!         // Send `signup` to the server.
+//!     }));
+//!
+//!     let email = form.field("email");
+//!     let age = form.field("age");
+//!     let newsletter = form.field("newsletter");
+//!
+//!     html! {
+//!         <form {onsubmit}>
+//!             <input type="email" value={email.value} oninput={email.oninput} onblur={email.onblur} />
+//!             if let Some(error) = email.error {
+//!                 <p class="error">{ error }</p>
+//!             }
+//!             <input type="number" value={age.value} oninput={age.oninput} onblur={age.onblur} />
+//!             if let Some(error) = age.error {
+//!                 <p class="error">{ error }</p>
+//!             }
+//!             <input type="checkbox" checked={newsletter.checked} oninput={newsletter.oninput} />
+//!             <button type="submit" disabled={!form.is_dirty()}>{ "Sign up" }</button>
+//!         </form>
+//!     }
+//! }
+//! ```
+
+mod hook;
+mod state;
+
+pub use hook::*;
@@ -0,0 +1,258 @@
+use std::collections::{BTreeMap, HashSet};
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+
+use crate::functional::Reducible;
+
+/// The value of an input, as read from the DOM.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum InputValue {
+    Text(String),
+    Checked(bool),
+}
+
+pub(super) enum FormAction {
+    Input { name: String, value: InputValue },
+    Touch(String),
+    Submit,
+    Reset,
+}
+
+/// The state of a form bound to a value of type `T`.
+///
+/// Values are kept in their serialized form, so that fields can be updated by name.
+pub(super) struct FormState<T> {
+    initial: Map<String, Value>,
+    values: Map<String, Value>,
+    /// The text of inputs that could not be converted into the type of their field.
+    invalid: BTreeMap<String, String>,
+    touched: HashSet<String>,
+    submitted: bool,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> FormState<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    pub fn new(initial: &T) -> Self {
+        let initial = match serde_json::to_value(initial) {
+            Ok(Value::Object(m)) => m,
+            _ => Map::new(),
+        };
+
+        Self {
+            values: initial.clone(),
+            initial,
+            invalid: BTreeMap::new(),
+            touched: HashSet::new(),
+            submitted: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the current value of the form.
+    pub fn value(&self) -> Option<T> {
+        Self::deserialize(&self.values)
+    }
+
+    fn deserialize(values: &Map<String, Value>) -> Option<T> {
+        serde_json::from_value(Value::Object(values.clone())).ok()
+    }
+
+    /// Returns the text to show in the input of the field `name`.
+    pub fn text(&self, name: &str) -> String {
+        if let Some(m) = self.invalid.get(name) {
+            return m.clone();
+        }
+
+        match self.values.get(name) {
+            Some(Value::String(m)) => m.clone(),
+            Some(Value::Number(m)) => m.to_string(),
+            _ => String::new(),
+        }
+    }
+
+    pub fn checked(&self, name: &str) -> bool {
+        matches!(self.values.get(name), Some(Value::Bool(true)))
+    }
+
+    pub fn is_invalid(&self, name: &str) -> bool {
+        self.invalid.contains_key(name)
+    }
+
+    pub fn invalid_fields(&self) -> impl Iterator<Item = &str> {
+        self.invalid.keys().map(String::as_str)
+    }
+
+    pub fn is_dirty(&self, name: &str) -> bool {
+        self.is_invalid(name) || self.values.get(name) != self.initial.get(name)
+    }
+
+    pub fn is_form_dirty(&self) -> bool {
+        !self.invalid.is_empty() || self.values != self.initial
+    }
+
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.touched.contains(name)
+    }
+
+    pub fn is_submitted(&self) -> bool {
+        self.submitted
+    }
+
+    /// Converts `text` into the first value that the field `name` accepts.
+    ///
+    /// The type of a field is not known before deserialization, so numbers and `null` are tried
+    /// before falling back to a string.
+    fn coerce(&self, name: &str, text: String) -> Option<Value> {
+        let mut candidates = Vec::new();
+        if text.is_empty() {
+            candidates.push(Value::Null);
+        } else if let Ok(m) = text.trim().parse::<i64>() {
+            candidates.push(Value::Number(m.into()));
+        } else if let Some(m) = text.trim().parse::<f64>().ok().and_then(Number::from_f64) {
+            candidates.push(Value::Number(m));
+        }
+        candidates.push(Value::String(text));
+
+        let mut values = self.values.clone();
+        candidates.into_iter().find(|candidate| {
+            values.insert(name.to_owned(), candidate.clone());
+            Self::deserialize(&values).is_some()
+        })
+    }
+}
+
+impl<T> Reducible for FormState<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    type Action = FormAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut next = Self {
+            initial: self.initial.clone(),
+            values: self.values.clone(),
+            invalid: self.invalid.clone(),
+            touched: self.touched.clone(),
+            submitted: self.submitted,
+            _marker: PhantomData,
+        };
+
+        match action {
+            FormAction::Input { name, value } => {
+                let value = match value {
+                    InputValue::Text(text) => self.coerce(&name, text.clone()).ok_or(text),
+                    InputValue::Checked(m) => Ok(Value::Bool(m)),
+                };
+
+                match value {
+                    Ok(value) => {
+                        next.invalid.remove(&name);
+                        next.values.insert(name, value);
+                    }
+                    Err(text) => {
+                        next.invalid.insert(name, text);
+                    }
+                }
+            }
+            FormAction::Touch(name) => {
+                if !next.touched.insert(name) {
+                    return self;
+                }
+            }
+            FormAction::Submit => next.submitted = true,
+            FormAction::Reset => {
+                next.values = next.initial.clone();
+                next.invalid.clear();
+                next.touched.clear();
+                next.submitted = false;
+            }
+        }
+
+        next.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Signup {
+        email: String,
+        age: u32,
+        height: Option<f64>,
+        newsletter: bool,
+    }
+
+    fn input(state: Rc<FormState<Signup>>, name: &str, value: InputValue) -> Rc<FormState<Signup>> {
+        state.reduce(FormAction::Input {
+            name: name.to_owned(),
+            value,
+        })
+    }
+
+    fn text(state: Rc<FormState<Signup>>, name: &str, text: &str) -> Rc<FormState<Signup>> {
+        input(state, name, InputValue::Text(text.to_owned()))
+    }
+
+    #[test]
+    fn inputs_are_converted_to_field_types() {
+        let state = Rc::new(FormState::new(&Signup::default()));
+        let state = text(state, "email", "42");
+        let state = text(state, "age", "31");
+        let state = text(state, "height", "1.8");
+        let state = input(state, "newsletter", InputValue::Checked(true));
+
+        assert_eq!(
+            state.value(),
+            Some(Signup {
+                email: "42".to_owned(),
+                age: 31,
+                height: Some(1.8),
+                newsletter: true,
+            })
+        );
+        assert!(state.is_form_dirty());
+
+        let state = text(state, "height", "");
+        assert_eq!(state.value().unwrap().height, None);
+    }
+
+    #[test]
+    fn invalid_inputs_keep_their_text() {
+        let state = Rc::new(FormState::new(&Signup::default()));
+        let state = text(state, "age", "31");
+        let state = text(state, "age", "thirty");
+
+        assert!(state.is_invalid("age"));
+        assert_eq!(state.text("age"), "thirty");
+        assert_eq!(state.value().unwrap().age, 31);
+
+        let state = text(state, "age", "32");
+        assert!(!state.is_invalid("age"));
+        assert_eq!(state.text("age"), "32");
+    }
+
+    #[test]
+    fn reset_restores_initial_values() {
+        let state = Rc::new(FormState::new(&Signup::default()));
+        let state = text(state, "email", "jane@example.com");
+        let state = state.reduce(FormAction::Touch("email".to_owned()));
+        assert!(state.is_dirty("email"));
+        assert!(state.is_touched("email"));
+
+        let state = state.reduce(FormAction::Reset);
+        assert!(!state.is_dirty("email"));
+        assert!(!state.is_touched("email"));
+        assert_eq!(state.value(), Some(Signup::default()));
+    }
+}
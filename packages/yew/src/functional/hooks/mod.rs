@@ -1,24 +1,72 @@
+mod use_async;
+#[cfg(feature = "broadcast_channel")]
+mod use_broadcast_channel;
 mod use_callback;
+mod use_clipboard;
 mod use_context;
+mod use_debounce;
+#[cfg(feature = "drag_and_drop")]
+mod use_drag_and_drop;
 mod use_effect;
+mod use_effect_async;
+mod use_event;
 mod use_force_update;
+mod use_geolocation;
+mod use_gesture;
+mod use_intersection_observer;
+mod use_interval;
+mod use_media_query;
 mod use_memo;
+mod use_mounted;
+mod use_online;
 mod use_prepared_state;
+mod use_previous;
 mod use_reducer;
 mod use_ref;
+mod use_resize_observer;
+mod use_scroll;
 mod use_state;
+mod use_throttle;
+mod use_timeout;
+mod use_transition;
 mod use_transitive_state;
+#[cfg(feature = "websocket")]
+mod use_websocket;
 
+pub use use_async::*;
+#[cfg(feature = "broadcast_channel")]
+pub use use_broadcast_channel::*;
 pub use use_callback::*;
+pub use use_clipboard::*;
 pub use use_context::*;
+pub use use_debounce::*;
+#[cfg(feature = "drag_and_drop")]
+pub use use_drag_and_drop::*;
 pub use use_effect::*;
+pub use use_effect_async::*;
+pub use use_event::*;
 pub use use_force_update::*;
+pub use use_geolocation::*;
+pub use use_gesture::*;
+pub use use_intersection_observer::*;
+pub use use_interval::*;
+pub use use_media_query::*;
 pub use use_memo::*;
+pub use use_mounted::*;
+pub use use_online::*;
 pub use use_prepared_state::*;
+pub use use_previous::*;
 pub use use_reducer::*;
 pub use use_ref::*;
+pub use use_resize_observer::*;
+pub use use_scroll::*;
 pub use use_state::*;
+pub use use_throttle::*;
+pub use use_timeout::*;
+pub use use_transition::*;
 pub use use_transitive_state::*;
+#[cfg(feature = "websocket")]
+pub use use_websocket::*;
 
 use crate::functional::HookContext;
 
@@ -0,0 +1,195 @@
+use std::fmt;
+use std::future::Future;
+use std::rc::Rc;
+
+use futures::future::{abortable, AbortHandle};
+
+use super::{use_effect_with, use_mut_ref, use_reducer, Reducible, UseReducerHandle};
+use crate::functional::hook;
+use crate::platform::spawn_local;
+
+/// The state of a future run by [`use_async`].
+struct UseAsyncState<T, E> {
+    loading: bool,
+    data: Option<Rc<T>>,
+    error: Option<Rc<E>>,
+}
+
+impl<T, E> Default for UseAsyncState<T, E> {
+    fn default() -> Self {
+        Self {
+            loading: false,
+            data: None,
+            error: None,
+        }
+    }
+}
+
+enum UseAsyncAction<T, E> {
+    Start,
+    Finish(Result<T, E>),
+}
+
+impl<T, E> Reducible for UseAsyncState<T, E> {
+    type Action = UseAsyncAction<T, E>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        match action {
+            UseAsyncAction::Start => Self {
+                loading: true,
+                data: self.data.clone(),
+                error: None,
+            },
+            UseAsyncAction::Finish(Ok(data)) => Self {
+                loading: false,
+                data: Some(Rc::new(data)),
+                error: None,
+            },
+            UseAsyncAction::Finish(Err(error)) => Self {
+                loading: false,
+                data: self.data.clone(),
+                error: Some(Rc::new(error)),
+            },
+        }
+        .into()
+    }
+}
+
+/// State handle for the [`use_async`] hook.
+pub struct UseAsyncHandle<T, E> {
+    state: UseReducerHandle<UseAsyncState<T, E>>,
+    run: Rc<dyn Fn()>,
+}
+
+impl<T, E> UseAsyncHandle<T, E> {
+    /// Runs the future, cancelling the previous run if it has not completed yet.
+    pub fn run(&self) {
+        (self.run)()
+    }
+
+    /// Returns `true` while the future is running.
+    pub fn loading(&self) -> bool {
+        self.state.loading
+    }
+
+    /// Returns the output of the last successful run.
+    ///
+    /// The data is kept while the future is run again and when a later run fails.
+    pub fn data(&self) -> Option<&T> {
+        self.state.data.as_deref()
+    }
+
+    /// Returns the error of the last run, if it failed.
+    pub fn error(&self) -> Option<&E> {
+        self.state.error.as_deref()
+    }
+}
+
+impl<T, E> Clone for UseAsyncHandle<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            run: self.run.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, E: fmt::Debug> fmt::Debug for UseAsyncHandle<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseAsyncHandle")
+            .field("loading", &self.loading())
+            .field("data", &self.data())
+            .field("error", &self.error())
+            .finish()
+    }
+}
+
+/// This hook is used to run a future and track its progress.
+///
+/// The future is created by `factory` and spawned every time [`UseAsyncHandle::run`] is called.
+/// Starting a new run cancels the previous one if it is still in flight, as does unmounting the
+/// component, so the state always reflects the latest run.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// async fn fetch_greeting() -> Result<String, String> {
+///     Ok("Hello".to_string())
+/// }
+///
+/// #[function_component]
+/// fn Greeting() -> Html {
+///     let greeting = use_async(fetch_greeting);
+///
+///     let onclick = {
+///         let greeting = greeting.clone();
+///         Callback::from(move |_| greeting.run())
+///     };
+///
+///     html! {
+///         <div>
+///             <button {onclick} disabled={greeting.loading()}>{ "Load" }</button>
+///             if let Some(greeting) = greeting.data() {
+///                 <p>{ greeting }</p>
+///             }
+///             if let Some(error) = greeting.error() {
+///                 <p class="error">{ error }</p>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_async<F, Fut, T, E>(factory: F) -> UseAsyncHandle<T, E>
+where
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+    T: 'static,
+    E: 'static,
+{
+    let state = use_reducer(UseAsyncState::default);
+    let in_flight = use_mut_ref(|| None::<AbortHandle>);
+
+    // The factory is replaced on every render, so that `run` always uses the latest closure.
+    let latest_factory = use_mut_ref(|| None::<F>);
+    *latest_factory.borrow_mut() = Some(factory);
+
+    {
+        let in_flight = in_flight.clone();
+        use_effect_with((), move |_| {
+            move || {
+                if let Some(handle) = in_flight.borrow_mut().take() {
+                    handle.abort();
+                }
+            }
+        });
+    }
+
+    let run = {
+        let dispatcher = state.dispatcher();
+
+        Rc::new(move || {
+            let future = match latest_factory.borrow().as_ref() {
+                Some(factory) => factory(),
+                None => return,
+            };
+            let (future, handle) = abortable(future);
+
+            if let Some(previous) = in_flight.replace(Some(handle)) {
+                previous.abort();
+            }
+            dispatcher.dispatch(UseAsyncAction::Start);
+
+            let dispatcher = dispatcher.clone();
+            spawn_local(async move {
+                if let Ok(result) = future.await {
+                    dispatcher.dispatch(UseAsyncAction::Finish(result));
+                }
+            });
+        })
+    };
+
+    UseAsyncHandle { state, run }
+}
@@ -0,0 +1,302 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+use web_sys::{BroadcastChannel, MessageEvent};
+
+use super::{use_effect_with, use_force_update, use_mut_ref};
+use crate::callback::Callback;
+use crate::functional::hook;
+use crate::virtual_dom::AttrValue;
+
+/// When and by which tab a value was written, ordering the writes of all tabs.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+struct Stamp {
+    time: f64,
+    tab: u32,
+}
+
+impl Stamp {
+    fn now() -> Self {
+        thread_local! {
+            static TAB: u32 = (js_sys::Math::random() * f64::from(u32::MAX)) as u32;
+        }
+
+        Self {
+            time: js_sys::Date::now(),
+            tab: TAB.with(|m| *m),
+        }
+    }
+}
+
+/// The messages exchanged by the tabs on a channel.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Message<T> {
+    /// A tab wrote a value.
+    Write { stamp: Stamp, value: T },
+    /// A tab opened the channel, and asks for the current value.
+    Sync,
+}
+
+struct Shared<T> {
+    value: Rc<T>,
+    // `None` until a value is written, in this tab or another one.
+    stamp: Option<Stamp>,
+    channel: Option<BroadcastChannel>,
+}
+
+impl<T> Shared<T>
+where
+    T: Serialize,
+{
+    fn post(&self, message: &Message<&T>) {
+        let Some(ref channel) = self.channel else {
+            return;
+        };
+        match serde_json::to_string(message) {
+            Ok(m) => {
+                let _ = channel.post_message(&m.into());
+            }
+            Err(e) => tracing::warn!(%e, "failed to encode broadcast channel message"),
+        }
+    }
+
+    fn post_value(&self) {
+        if let Some(stamp) = self.stamp {
+            self.post(&Message::Write {
+                stamp,
+                value: &*self.value,
+            });
+        }
+    }
+}
+
+/// State handle for the [`use_broadcast_channel`] hook.
+pub struct UseBroadcastChannelHandle<T> {
+    value: Rc<T>,
+    set: Callback<T>,
+}
+
+impl<T> UseBroadcastChannelHandle<T> {
+    /// Replaces the value in every tab.
+    pub fn set(&self, value: T) {
+        self.set.emit(value)
+    }
+
+    /// Returns a callback replacing the value in every tab.
+    pub fn setter(&self) -> Callback<T> {
+        self.set.clone()
+    }
+}
+
+impl<T> Deref for UseBroadcastChannelHandle<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> Clone for UseBroadcastChannelHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            set: self.set.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for UseBroadcastChannelHandle<T> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.value == rhs.value
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for UseBroadcastChannelHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseBroadcastChannelHandle")
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+/// This hook is used to share a state between the tabs of the same origin, through a
+/// [`BroadcastChannel`] named `name`.
+///
+/// Setting the value sets it in every tab with a component using the same channel. When several
+/// tabs write at the same time, the last write wins: every tab ends up with the value that was
+/// written last, whatever the order the writes are received in. A tab opening the channel
+/// receives the current value from the other tabs, and uses `T::default()` until then, as well as
+/// during server-side rendering and hydration.
+///
+/// Values are encoded as JSON. Incoming messages that cannot be decoded as `T` are skipped.
+///
+/// # Example
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use yew::prelude::*;
+///
+/// #[derive(Default, Serialize, Deserialize)]
+/// struct Cart {
+///     items: Vec<String>,
+/// }
+///
+/// #[function_component]
+/// fn CartCount() -> Html {
+///     let cart = use_broadcast_channel::<Cart>("cart".into());
+///     let onclick = {
+///         let cart = cart.clone();
+///         Callback::from(move |_| {
+///             let mut items = cart.items.clone();
+///             items.push("apple".into());
+///             cart.set(Cart { items });
+///         })
+///     };
+///
+///     html! {
+///         <button {onclick}>{ format!("{} items", cart.items.len()) }</button>
+///     }
+/// }
+/// ```
+///
+/// [`BroadcastChannel`]: https://developer.mozilla.org/en-US/docs/Web/API/BroadcastChannel
+#[hook]
+pub fn use_broadcast_channel<T>(name: AttrValue) -> UseBroadcastChannelHandle<T>
+where
+    T: Default + Serialize + DeserializeOwned + 'static,
+{
+    let shared = use_mut_ref(|| Shared {
+        value: Rc::new(T::default()),
+        stamp: None,
+        channel: None,
+    });
+    let force_update = use_force_update();
+
+    {
+        let shared = shared.clone();
+        let force_update = force_update.clone();
+
+        use_effect_with(name, move |name| {
+            let listener = open(name, shared.clone(), move || force_update.force_update());
+
+            move || {
+                drop(listener);
+                if let Some(channel) = shared.borrow_mut().channel.take() {
+                    channel.close();
+                }
+            }
+        });
+    }
+
+    let value = shared.borrow().value.clone();
+    let set = Callback::from(move |value: T| {
+        {
+            let mut shared = shared.borrow_mut();
+            shared.value = Rc::new(value);
+            shared.stamp = Some(Stamp::now());
+            shared.post_value();
+        }
+        force_update.force_update();
+    });
+
+    UseBroadcastChannelHandle { value, set }
+}
+
+/// Opens the channel `name`, and asks the other tabs for the current value.
+fn open<T>(
+    name: &str,
+    shared: Rc<RefCell<Shared<T>>>,
+    updated: impl Fn() + 'static,
+) -> Option<EventListener>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    let channel = match BroadcastChannel::new(name) {
+        Ok(m) => m,
+        Err(e) => {
+            tracing::warn!(?e, name, "failed to open broadcast channel");
+            return None;
+        }
+    };
+
+    let listener = EventListener::new(&channel, "message", {
+        let shared = shared.clone();
+        move |event| {
+            let Some(data) = event.unchecked_ref::<MessageEvent>().data().as_string() else {
+                return;
+            };
+            let message = match serde_json::from_str::<Message<T>>(&data) {
+                Ok(m) => m,
+                Err(e) => {
+                    tracing::warn!(%e, "failed to decode broadcast channel message");
+                    return;
+                }
+            };
+
+            match message {
+                Message::Write { stamp, value } => {
+                    {
+                        let mut shared = shared.borrow_mut();
+                        // Writes older than the current value lost to it.
+                        if shared.stamp.is_some_and(|m| m >= stamp) {
+                            return;
+                        }
+                        shared.value = Rc::new(value);
+                        shared.stamp = Some(stamp);
+                    }
+                    updated();
+                }
+                Message::Sync => shared.borrow().post_value(),
+            }
+        }
+    });
+
+    let mut shared = shared.borrow_mut();
+    shared.channel = Some(channel);
+    shared.post(&Message::Sync);
+
+    Some(listener)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn last_write_wins() {
+        let stamp = |time, tab| Stamp { time, tab };
+
+        assert!(stamp(2.0, 1) > stamp(1.0, 2));
+        // Simultaneous writes are ordered by tab, so that all tabs keep the same one.
+        assert!(stamp(1.0, 2) > stamp(1.0, 1));
+        assert!(Some(stamp(0.0, 0)) > None);
+    }
+
+    #[test]
+    fn messages_round_trip() {
+        let message = Message::Write {
+            stamp: Stamp { time: 1.0, tab: 7 },
+            value: "cart",
+        };
+        let encoded = serde_json::to_string(&message).unwrap();
+        let decoded: Message<String> = serde_json::from_str(&encoded).unwrap();
+
+        assert_eq!(
+            decoded,
+            Message::Write {
+                stamp: Stamp { time: 1.0, tab: 7 },
+                value: "cart".to_string(),
+            }
+        );
+
+        let encoded = serde_json::to_string(&Message::<()>::Sync).unwrap();
+        let decoded: Message<String> = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(decoded, Message::Sync);
+    }
+}
@@ -0,0 +1,162 @@
+use std::rc::Rc;
+
+use thiserror::Error;
+
+use super::{use_state, UseStateHandle};
+use crate::functional::hook;
+use crate::platform::spawn_local;
+use crate::virtual_dom::AttrValue;
+
+/// An error returned by a clipboard operation of [`use_clipboard`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardError {
+    /// The Clipboard API is not available, for example because the page is not served over HTTPS.
+    #[error("the clipboard is not available")]
+    Unsupported,
+    /// The user or the browser denied access to the clipboard.
+    #[error("access to the clipboard was denied")]
+    PermissionDenied,
+    /// The operation failed for another reason.
+    #[error("clipboard operation failed: {0}")]
+    Failed(String),
+}
+
+#[cfg(all(
+    target_arch = "wasm32",
+    not(target_os = "wasi"),
+    not(feature = "not_browser_env")
+))]
+mod feat_browser {
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_futures::JsFuture;
+    use web_sys::Clipboard;
+
+    use super::ClipboardError;
+
+    fn clipboard() -> Result<Clipboard, ClipboardError> {
+        let clipboard = gloo::utils::window().navigator().clipboard();
+        if clipboard.is_undefined() {
+            return Err(ClipboardError::Unsupported);
+        }
+        Ok(clipboard)
+    }
+
+    fn to_error(e: JsValue) -> ClipboardError {
+        match e.dyn_ref::<js_sys::Error>() {
+            Some(e) if e.name() == "NotAllowedError" => ClipboardError::PermissionDenied,
+            Some(e) => ClipboardError::Failed(e.message().into()),
+            None => ClipboardError::Failed(format!("{e:?}")),
+        }
+    }
+
+    pub(super) async fn write_text(text: &str) -> Result<(), ClipboardError> {
+        JsFuture::from(clipboard()?.write_text(text))
+            .await
+            .map(|_| ())
+            .map_err(to_error)
+    }
+
+    pub(super) async fn read_text() -> Result<String, ClipboardError> {
+        JsFuture::from(clipboard()?.read_text())
+            .await
+            .map(|m| m.as_string().unwrap_or_default())
+            .map_err(to_error)
+    }
+}
+
+#[cfg(any(
+    not(target_arch = "wasm32"),
+    target_os = "wasi",
+    feature = "not_browser_env"
+))]
+mod feat_browser {
+    use super::ClipboardError;
+
+    pub(super) async fn write_text(_text: &str) -> Result<(), ClipboardError> {
+        Err(ClipboardError::Unsupported)
+    }
+
+    pub(super) async fn read_text() -> Result<String, ClipboardError> {
+        Err(ClipboardError::Unsupported)
+    }
+}
+
+/// State handle for the [`use_clipboard`] hook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UseClipboardHandle {
+    result: UseStateHandle<Option<Rc<Result<AttrValue, ClipboardError>>>>,
+}
+
+impl UseClipboardHandle {
+    /// Writes `text` to the clipboard.
+    pub fn copy(&self, text: impl Into<AttrValue>) {
+        let text = text.into();
+        let result = self.result.setter();
+        spawn_local(async move {
+            let outcome = feat_browser::write_text(&text).await.map(|_| text);
+            result.set(Some(Rc::new(outcome)));
+        });
+    }
+
+    /// Reads text from the clipboard.
+    ///
+    /// Browsers usually ask the user for permission the first time the clipboard is read.
+    pub fn read(&self) {
+        let result = self.result.setter();
+        spawn_local(async move {
+            let outcome = feat_browser::read_text().await.map(AttrValue::from);
+            result.set(Some(Rc::new(outcome)));
+        });
+    }
+
+    /// Returns the text of the last successful operation, that is the text copied or read.
+    pub fn text(&self) -> Option<&AttrValue> {
+        self.result.as_deref().and_then(|m| m.as_ref().ok())
+    }
+
+    /// Returns the error of the last operation, if it failed.
+    pub fn error(&self) -> Option<&ClipboardError> {
+        self.result.as_deref().and_then(|m| m.as_ref().err())
+    }
+}
+
+/// This hook is used to copy text to and read text from the clipboard.
+///
+/// Operations run asynchronously using the [Clipboard API], and the component re-renders with the
+/// outcome of the last operation once it completes. When the clipboard is not available, including
+/// during server-side rendering, operations fail with [`ClipboardError::Unsupported`].
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn CopyButton() -> Html {
+///     let clipboard = use_clipboard();
+///     let onclick = {
+///         let clipboard = clipboard.clone();
+///         Callback::from(move |_| clipboard.copy("cargo add yew"))
+///     };
+///
+///     html! {
+///         <div>
+///             <button {onclick}>{ "Copy" }</button>
+///             if clipboard.text().is_some() {
+///                 <span>{ "Copied!" }</span>
+///             }
+///             if let Some(error) = clipboard.error() {
+///                 <span class="error">{ error.to_string() }</span>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+///
+/// [Clipboard API]: https://developer.mozilla.org/en-US/docs/Web/API/Clipboard_API
+#[hook]
+pub fn use_clipboard() -> UseClipboardHandle {
+    let result = use_state(|| None);
+
+    UseClipboardHandle { result }
+}
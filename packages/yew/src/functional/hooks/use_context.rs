@@ -3,13 +3,14 @@ use std::marker::PhantomData;
 use std::rc::Rc;
 
 use crate::callback::Callback;
-use crate::context::ContextHandle;
+use crate::context::{context_default, ContextHandle};
 use crate::functional::{Hook, HookContext};
 
 /// Hook for consuming context values in function components.
-/// The context of the type passed as `T` is returned. If there is no such context in scope, `None`
-/// is returned. A component which calls `use_context` will re-render when the data of the context
-/// changes.
+/// The context of the type passed as `T` is returned. If there is no such context in scope, the
+/// default registered with [`register_context_default`](crate::context::register_context_default)
+/// is returned, or `None` if there is none. A component which calls `use_context` will re-render
+/// when the data of the context changes.
 ///
 /// More information about contexts and how to define and consume them can be found on [Yew Docs](https://yew.rs/docs/concepts/contexts).
 ///
@@ -100,7 +101,7 @@ pub fn use_context<T: Clone + PartialEq + 'static>() -> impl Hook<Output = Optio
                     }))
                 }
                 .map(|(value, handle)| (Some(value), Some(handle)))
-                .unwrap_or((None, None));
+                .unwrap_or_else(|| (context_default::<T>(), None));
 
                 *(value_cell.borrow_mut()) = init_value;
 
@@ -119,3 +120,117 @@ pub fn use_context<T: Clone + PartialEq + 'static>() -> impl Hook<Output = Optio
         _marker: PhantomData,
     }
 }
+
+/// Hook for consuming a derived slice of a context value in function components.
+///
+/// `selector` is called with the context of type `T`, and its result is returned. Unlike
+/// [`use_context`], the component only re-renders when the selected value changes, not whenever
+/// any part of the context does. If there is no such context in scope, the registered default is
+/// selected like with [`use_context`], or `None` is returned.
+///
+/// The `selector` passed in the latest render is the one used to select updated values.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::{function_component, html, use_context_selector, Html};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Session {
+///     user_name: String,
+///     unread_messages: usize,
+/// }
+///
+/// /// Only re-renders when the user name changes, not when a message is received.
+/// #[function_component]
+/// pub fn UserName() -> Html {
+///     let user_name = use_context_selector(|session: &Session| session.user_name.clone())
+///         .expect("no session found");
+///
+///     html! { <span>{ user_name }</span> }
+/// }
+/// ```
+pub fn use_context_selector<T, U, F>(selector: F) -> impl Hook<Output = Option<U>>
+where
+    T: Clone + PartialEq + 'static,
+    U: Clone + PartialEq + 'static,
+    F: Fn(&T) -> U + 'static,
+{
+    type Selector<T, U> = Rc<RefCell<Option<Box<dyn Fn(&T) -> U>>>>;
+
+    struct HookProvider<T, U, F> {
+        selector: F,
+        _marker: PhantomData<(T, U)>,
+    }
+
+    struct UseContextSelector<T: Clone + PartialEq + 'static, U> {
+        _handle: Option<ContextHandle<T>>,
+        selector: Selector<T, U>,
+        context: Rc<RefCell<Option<T>>>,
+        selected: Rc<RefCell<Option<U>>>,
+    }
+
+    impl<T, U, F> Hook for HookProvider<T, U, F>
+    where
+        T: Clone + PartialEq + 'static,
+        U: Clone + PartialEq + 'static,
+        F: Fn(&T) -> U + 'static,
+    {
+        type Output = Option<U>;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let scope = ctx.scope.clone();
+
+            let state = ctx.next_state(move |re_render| -> UseContextSelector<T, U> {
+                let selector: Selector<T, U> = Rc::default();
+                let context_cell: Rc<RefCell<Option<T>>> = Rc::default();
+                let selected_cell: Rc<RefCell<Option<U>>> = Rc::default();
+
+                let (init_value, handle) = {
+                    let selector = selector.clone();
+                    let context_cell = context_cell.clone();
+                    let selected_cell = selected_cell.clone();
+
+                    scope.context(Callback::from(move |m: T| {
+                        let next = selector.borrow().as_ref().map(|f| f(&m));
+                        *(context_cell.borrow_mut()) = Some(m);
+
+                        let changed = *selected_cell.borrow() != next;
+                        if changed {
+                            *(selected_cell.borrow_mut()) = next;
+                            re_render()
+                        }
+                    }))
+                }
+                .map(|(value, handle)| (Some(value), Some(handle)))
+                .unwrap_or_else(|| (context_default::<T>(), None));
+
+                *(context_cell.borrow_mut()) = init_value;
+
+                UseContextSelector {
+                    _handle: handle,
+                    selector,
+                    context: context_cell,
+                    selected: selected_cell,
+                }
+            });
+
+            // The selector may depend on props, so the selection is refreshed on every render.
+            *state.selector.borrow_mut() = Some(Box::new(self.selector));
+            let selected = state
+                .context
+                .borrow()
+                .as_ref()
+                .zip(state.selector.borrow().as_ref())
+                .map(|(m, f)| f(m));
+            *state.selected.borrow_mut() = selected.clone();
+
+            selected
+        }
+    }
+
+    HookProvider {
+        selector,
+        _marker: PhantomData,
+    }
+}
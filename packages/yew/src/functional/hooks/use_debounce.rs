@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use gloo::timers::callback::Timeout;
+
+use super::{use_effect_with, use_state_eq};
+use crate::functional::hook;
+
+/// Converts a duration into the milliseconds expected by [`Timeout`].
+pub(super) fn timeout_millis(duration: Duration) -> u32 {
+    duration.as_millis().try_into().unwrap_or(u32::MAX)
+}
+
+/// This hook is used to delay updates of a value until it stopped changing.
+///
+/// Returns `value` once it has not changed for `delay`. Every change of `value` (or `delay`)
+/// restarts the timer, and the component re-renders when the debounced value is updated. The
+/// pending update is cancelled when the component is unmounted.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use web_sys::HtmlInputElement;
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Search() -> Html {
+///     let query = use_state(String::new);
+///     let debounced = use_debounce((*query).clone(), Duration::from_millis(300));
+///
+///     let oninput = {
+///         let query = query.clone();
+///         Callback::from(move |e: InputEvent| {
+///             query.set(e.target_unchecked_into::<HtmlInputElement>().value())
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <input {oninput} value={(*query).clone()} />
+///             <p>{ format!("Searching for {debounced}") }</p>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_debounce<T>(value: T, delay: Duration) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    let debounced = use_state_eq(|| value.clone());
+
+    {
+        let debounced = debounced.setter();
+        use_effect_with((value, delay), move |(value, delay)| {
+            let value = value.clone();
+            let timeout = Timeout::new(timeout_millis(*delay), move || debounced.set(value));
+
+            move || drop(timeout)
+        });
+    }
+
+    (*debounced).clone()
+}
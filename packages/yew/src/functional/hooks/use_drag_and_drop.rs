@@ -0,0 +1,183 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use web_sys::{DragEvent, Element};
+
+use super::{use_effect_with, use_event, use_mut_ref, use_state_eq};
+use crate::functional::hook;
+use crate::html::NodeRef;
+
+/// The format under which payloads are stored in the `DataTransfer` of drag events.
+const PAYLOAD_FORMAT: &str = "application/x-yew+json";
+
+/// Returns `true` if the drag event carries a payload set by [`use_draggable`].
+fn has_payload(e: &DragEvent) -> bool {
+    e.data_transfer()
+        .map(|m| m.types().includes(&PAYLOAD_FORMAT.into(), 0))
+        .unwrap_or(false)
+}
+
+/// This hook makes the element referenced by `node` draggable, carrying `payload`.
+///
+/// The payload is serialized into the `DataTransfer` of the drag, and can be received by elements
+/// using [`use_drop_target`] with the same type. The payload passed in the latest render is the
+/// one being dragged. Returns `true` while the element is being dragged.
+///
+/// # Example
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use yew::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// struct Card {
+///     id: u32,
+/// }
+///
+/// #[derive(Properties, PartialEq)]
+/// struct CardProps {
+///     card: Card,
+/// }
+///
+/// #[function_component]
+/// fn CardView(props: &CardProps) -> Html {
+///     let node = use_node_ref();
+///     let dragging = use_draggable(node.clone(), props.card.clone());
+///     let class = if dragging { "card dragging" } else { "card" };
+///
+///     html! {
+///         <div ref={node} {class}>
+///             { props.card.id }
+///         </div>
+///     }
+/// }
+///
+/// #[function_component]
+/// fn Column() -> Html {
+///     let node = use_node_ref();
+///     let cards = use_state(Vec::<Card>::new);
+///     let over = {
+///         let cards = cards.clone();
+///         use_drop_target(node.clone(), move |card: Card| {
+///             let mut next = (*cards).clone();
+///             next.push(card);
+///             cards.set(next);
+///         })
+///     };
+///     let class = if over { "column over" } else { "column" };
+///
+///     html! {
+///         <div ref={node} {class}>
+///             { for cards.iter().map(|card| html! { <CardView card={card.clone()} /> }) }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_draggable<T>(node: NodeRef, payload: T) -> bool
+where
+    T: Serialize + 'static,
+{
+    let dragging = use_state_eq(|| false);
+
+    use_effect_with(node.clone(), |node| {
+        if let Ok(element) = node.cast_into::<Element>() {
+            let _ = element.set_attribute("draggable", "true");
+        }
+    });
+
+    {
+        let dragging = dragging.clone();
+        use_event(node.clone(), "dragstart", move |e: DragEvent| {
+            let (Some(data_transfer), Ok(data)) =
+                (e.data_transfer(), serde_json::to_string(&payload))
+            else {
+                return;
+            };
+
+            if data_transfer.set_data(PAYLOAD_FORMAT, &data).is_ok() {
+                dragging.set(true);
+            }
+        });
+    }
+
+    {
+        let dragging = dragging.clone();
+        use_event(node, "dragend", move |_: DragEvent| dragging.set(false));
+    }
+
+    *dragging
+}
+
+/// This hook makes the element referenced by `node` accept payloads dragged from
+/// [`use_draggable`].
+///
+/// `on_drop` is called with the payload when it is dropped on the element. Payloads that cannot
+/// be deserialized into `T` are ignored. Returns `true` while a payload is dragged over the
+/// element.
+///
+/// See [`use_draggable`] for an example.
+#[hook]
+pub fn use_drop_target<T, F>(node: NodeRef, on_drop: F) -> bool
+where
+    T: DeserializeOwned + 'static,
+    F: Fn(T) + 'static,
+{
+    let over = use_state_eq(|| false);
+    // `dragenter` and `dragleave` are also fired when moving over children of the element.
+    let depth = use_mut_ref(|| 0usize);
+
+    {
+        let over = over.clone();
+        let depth = depth.clone();
+        use_event(node.clone(), "dragenter", move |e: DragEvent| {
+            if has_payload(&e) {
+                e.prevent_default();
+                *depth.borrow_mut() += 1;
+                over.set(true);
+            }
+        });
+    }
+
+    use_event(node.clone(), "dragover", move |e: DragEvent| {
+        // The default action of `dragover` prevents dropping.
+        if has_payload(&e) {
+            e.prevent_default();
+        }
+    });
+
+    {
+        let over = over.clone();
+        let depth = depth.clone();
+        use_event(node.clone(), "dragleave", move |e: DragEvent| {
+            if has_payload(&e) {
+                let mut depth = depth.borrow_mut();
+                *depth = depth.saturating_sub(1);
+                if *depth == 0 {
+                    over.set(false);
+                }
+            }
+        });
+    }
+
+    {
+        let over = over.clone();
+        use_event(node, "drop", move |e: DragEvent| {
+            if !has_payload(&e) {
+                return;
+            }
+            e.prevent_default();
+            *depth.borrow_mut() = 0;
+            over.set(false);
+
+            let payload = e
+                .data_transfer()
+                .and_then(|m| m.get_data(PAYLOAD_FORMAT).ok())
+                .and_then(|m| serde_json::from_str::<T>(&m).ok());
+            if let Some(payload) = payload {
+                on_drop(payload);
+            }
+        });
+    }
+
+    *over
+}
@@ -25,6 +25,9 @@ where
     D: TearDown,
 {
     runner_with_deps: Option<(T, F)>,
+    /// The runner of the first render of strict mode.
+    #[cfg(debug_assertions)]
+    strict_runner_with_deps: Option<(T, F)>,
     destructor: Option<D>,
     deps: Option<T>,
     effect_changed_fn: fn(Option<&T>, Option<&T>) -> bool,
@@ -39,6 +42,9 @@ where
     fn rendered(&self) {
         let mut this = self.borrow_mut();
 
+        #[cfg(debug_assertions)]
+        let strict_runner_with_deps = this.strict_runner_with_deps.take();
+
         if let Some((deps, runner)) = this.runner_with_deps.take() {
             if !(this.effect_changed_fn)(Some(&deps), this.deps.as_ref()) {
                 return;
@@ -48,6 +54,15 @@ where
                 de.tear_down();
             }
 
+            // In strict mode, the effect is run and cleaned up once more when the component is
+            // first rendered, so that effects without cleanup show up twice.
+            #[cfg(debug_assertions)]
+            if this.deps.is_none() {
+                if let Some((deps, runner)) = strict_runner_with_deps {
+                    runner(&deps).tear_down();
+                }
+            }
+
             let new_destructor = runner(&deps);
 
             this.deps = Some(deps);
@@ -107,12 +122,20 @@ where
             let state = ctx.next_effect(|_| -> RefCell<UseEffectBase<T, F, D>> {
                 RefCell::new(UseEffectBase {
                     runner_with_deps: None,
+                    #[cfg(debug_assertions)]
+                    strict_runner_with_deps: None,
                     destructor: None,
                     deps: None,
                     effect_changed_fn,
                 })
             });
 
+            #[cfg(debug_assertions)]
+            if ctx.is_strict_pass() {
+                state.borrow_mut().strict_runner_with_deps = Some((deps, runner));
+                return;
+            }
+
             state.borrow_mut().runner_with_deps = Some((deps, runner));
         }
     }
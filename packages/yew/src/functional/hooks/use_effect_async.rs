@@ -0,0 +1,76 @@
+use std::future::Future;
+use std::rc::Rc;
+
+use futures::future::{AbortHandle, Abortable};
+
+use super::{use_effect, use_effect_with};
+use crate::functional::hook;
+use crate::platform::spawn_local;
+
+/// Spawns `future`, returning a destructor that aborts it.
+fn spawn_abortable(future: impl Future<Output = ()> + 'static) -> impl FnOnce() {
+    let (handle, registration) = AbortHandle::new_pair();
+    spawn_local(async move {
+        let _ = Abortable::new(future, registration).await;
+    });
+
+    move || handle.abort()
+}
+
+/// This hook is used to run an async side effect after every render.
+///
+/// The future returned by `f` is spawned once the component has been rendered. If it is still
+/// running when the component is rendered again or unmounted, it is aborted, that is dropped at
+/// its next `.await`.
+///
+/// See [`use_effect_async_with`] to only run the effect when dependencies change.
+#[hook]
+pub fn use_effect_async<F, Fut>(f: F)
+where
+    F: FnOnce() -> Fut + 'static,
+    Fut: Future<Output = ()> + 'static,
+{
+    use_effect(move || spawn_abortable(f()));
+}
+
+/// This hook is used to run an async side effect when its dependencies change.
+///
+/// The future returned by `f` is spawned once the component has been rendered with new `deps`. If
+/// it is still running when `deps` change again or the component is unmounted, it is aborted, so
+/// that a stale effect never overrides the result of a newer one.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// # async fn search(query: &str) -> Vec<String> { Vec::new() }
+/// #[derive(Properties, PartialEq)]
+/// struct Props {
+///     query: AttrValue,
+/// }
+///
+/// #[function_component]
+/// fn SearchResults(props: &Props) -> Html {
+///     let results = use_state(Vec::new);
+///     {
+///         let results = results.clone();
+///         use_effect_async_with(props.query.clone(), move |query| async move {
+///             results.set(search(&query).await);
+///         });
+///     }
+///
+///     html! {
+///         <ul>{ for results.iter().map(|m| html! { <li>{ m }</li> }) }</ul>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_effect_async_with<D, F, Fut>(deps: D, f: F)
+where
+    D: PartialEq + 'static,
+    F: FnOnce(Rc<D>) -> Fut + 'static,
+    Fut: Future<Output = ()> + 'static,
+{
+    use_effect_with(Rc::new(deps), move |deps| spawn_abortable(f(deps.clone())));
+}
@@ -0,0 +1,130 @@
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, EventTarget};
+
+use super::{use_effect_with, use_mut_ref};
+use crate::functional::hook;
+use crate::html::NodeRef;
+
+/// The target of a listener registered by [`use_event`] and its variants.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum ListenerTarget {
+    Node(NodeRef),
+    Window,
+    Document,
+}
+
+impl ListenerTarget {
+    pub(super) fn get(&self) -> Option<EventTarget> {
+        match self {
+            Self::Node(node) => node.get().map(Into::into),
+            Self::Window => Some(gloo::utils::window().into()),
+            Self::Document => Some(gloo::utils::document().into()),
+        }
+    }
+}
+
+#[hook]
+pub(super) fn use_event_base<E, F>(target: ListenerTarget, event_type: &'static str, callback: F)
+where
+    E: JsCast + 'static,
+    F: Fn(E) + 'static,
+{
+    let latest = use_mut_ref(|| None::<Rc<dyn Fn(E)>>);
+    *latest.borrow_mut() = Some(Rc::new(callback));
+
+    use_effect_with((target, event_type), move |(target, event_type)| {
+        let listener = target.get().map(|target| {
+            EventListener::new(&target, *event_type, move |event: &Event| {
+                let Ok(event) = event.clone().dyn_into::<E>() else {
+                    return;
+                };
+                // The callback may cause a re-render, so it must not be borrowed while called.
+                let callback = latest.borrow().clone();
+                if let Some(callback) = callback {
+                    callback(event);
+                }
+            })
+        });
+
+        move || drop(listener)
+    });
+}
+
+/// This hook is used to listen to events of the element referenced by `node`.
+///
+/// The listener is registered once the component has been rendered, and registered again when
+/// `node` or `event_type` changes. It is removed when the component is unmounted. The `callback`
+/// passed in the latest render is always the one being called, with the event cast to `E`. Events
+/// of another type are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use web_sys::MouseEvent;
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Canvas() -> Html {
+///     let node = use_node_ref();
+///     let position = use_state(|| (0, 0));
+///     {
+///         let position = position.clone();
+///         use_event(node.clone(), "mousemove", move |e: MouseEvent| {
+///             position.set((e.offset_x(), e.offset_y()))
+///         });
+///     }
+///
+///     html! {
+///         <div ref={node}>{ format!("{:?}", *position) }</div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_event<E, F>(node: NodeRef, event_type: &'static str, callback: F)
+where
+    E: JsCast + 'static,
+    F: Fn(E) + 'static,
+{
+    use_event_base(ListenerTarget::Node(node), event_type, callback)
+}
+
+/// [`use_event`] for events of the window, such as `resize` or `keydown`.
+///
+/// # Example
+///
+/// ```rust
+/// use web_sys::KeyboardEvent;
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Shortcuts() -> Html {
+///     let last_key = use_state(String::new);
+///     {
+///         let last_key = last_key.clone();
+///         use_window_event("keydown", move |e: KeyboardEvent| last_key.set(e.key()));
+///     }
+///
+///     html! { <p>{ format!("Last key: {}", *last_key) }</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_window_event<E, F>(event_type: &'static str, callback: F)
+where
+    E: JsCast + 'static,
+    F: Fn(E) + 'static,
+{
+    use_event_base(ListenerTarget::Window, event_type, callback)
+}
+
+/// [`use_event`] for events of the document, such as `visibilitychange`.
+#[hook]
+pub fn use_document_event<E, F>(event_type: &'static str, callback: F)
+where
+    E: JsCast + 'static,
+    F: Fn(E) + 'static,
+{
+    use_event_base(ListenerTarget::Document, event_type, callback)
+}
@@ -0,0 +1,243 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use thiserror::Error;
+use wasm_bindgen::prelude::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{Position, PositionError, PositionOptions};
+
+use super::{use_effect_with, use_state_eq};
+use crate::functional::hook;
+
+/// A position reported by [`use_geolocation`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeolocationPosition {
+    /// The latitude, in decimal degrees.
+    pub latitude: f64,
+    /// The longitude, in decimal degrees.
+    pub longitude: f64,
+    /// The accuracy of the latitude and longitude, in meters.
+    pub accuracy: f64,
+    /// The altitude above the WGS 84 ellipsoid, in meters, if available.
+    pub altitude: Option<f64>,
+    /// The accuracy of the altitude, in meters, if available.
+    pub altitude_accuracy: Option<f64>,
+    /// The direction of travel, in degrees clockwise from true north, if available.
+    pub heading: Option<f64>,
+    /// The velocity, in meters per second, if available.
+    pub speed: Option<f64>,
+    /// The time at which the position was acquired, in milliseconds since the UNIX epoch.
+    pub timestamp: f64,
+}
+
+impl From<Position> for GeolocationPosition {
+    fn from(position: Position) -> Self {
+        let coords = position.coords();
+        Self {
+            latitude: coords.latitude(),
+            longitude: coords.longitude(),
+            accuracy: coords.accuracy(),
+            altitude: coords.altitude(),
+            altitude_accuracy: coords.altitude_accuracy(),
+            heading: coords.heading(),
+            speed: coords.speed(),
+            timestamp: position.timestamp(),
+        }
+    }
+}
+
+/// An error reported by [`use_geolocation`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GeolocationError {
+    /// The Geolocation API is not available.
+    #[error("geolocation is not available")]
+    Unsupported,
+    /// The user or the browser denied access to the position.
+    #[error("access to the position was denied")]
+    PermissionDenied,
+    /// The position could not be determined.
+    #[error("position unavailable: {0}")]
+    PositionUnavailable(String),
+    /// The position was not determined within the timeout.
+    #[error("timed out while determining the position")]
+    Timeout,
+}
+
+impl From<PositionError> for GeolocationError {
+    fn from(e: PositionError) -> Self {
+        match e.code() {
+            PositionError::PERMISSION_DENIED => Self::PermissionDenied,
+            PositionError::TIMEOUT => Self::Timeout,
+            _ => Self::PositionUnavailable(e.message()),
+        }
+    }
+}
+
+/// Options for [`use_geolocation_with_options`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GeolocationOptions {
+    /// Whether to keep updating the position as it changes, instead of reading it once.
+    pub watch: bool,
+    /// Whether to request the most accurate position available, which may be slower and use more
+    /// power.
+    pub enable_high_accuracy: bool,
+    /// The maximum time allowed to determine the position. There is no limit by default.
+    pub timeout: Option<Duration>,
+    /// The maximum age of a cached position that may be returned. Cached positions are not used
+    /// by default.
+    pub maximum_age: Option<Duration>,
+}
+
+impl GeolocationOptions {
+    fn to_position_options(&self) -> PositionOptions {
+        let options = PositionOptions::new();
+        options.set_enable_high_accuracy(self.enable_high_accuracy);
+        if let Some(timeout) = self.timeout {
+            options.set_timeout(timeout.as_millis().try_into().unwrap_or(u32::MAX));
+        }
+        if let Some(maximum_age) = self.maximum_age {
+            options.set_maximum_age(maximum_age.as_millis().try_into().unwrap_or(u32::MAX));
+        }
+        options
+    }
+}
+
+/// State handle for the [`use_geolocation`] hook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UseGeolocationHandle {
+    state: Rc<Result<Option<GeolocationPosition>, GeolocationError>>,
+}
+
+impl UseGeolocationHandle {
+    /// Returns the last reported position.
+    pub fn position(&self) -> Option<&GeolocationPosition> {
+        self.state.as_ref().as_ref().ok().and_then(Option::as_ref)
+    }
+
+    /// Returns the error that prevented the position from being determined.
+    pub fn error(&self) -> Option<&GeolocationError> {
+        self.state.as_ref().as_ref().err()
+    }
+
+    /// Returns `true` while neither a position nor an error has been reported.
+    pub fn is_loading(&self) -> bool {
+        matches!(*self.state, Ok(None))
+    }
+}
+
+/// This hook is used to read the position of the device using the [Geolocation API].
+///
+/// The position is requested once the component has been rendered, and the component re-renders
+/// when it is reported. Browsers usually ask the user for permission first. During server-side
+/// rendering and hydration, the handle is loading.
+///
+/// See [`use_geolocation_with_options`] to keep tracking the position as it changes.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Location() -> Html {
+///     let geolocation = use_geolocation();
+///
+///     html! {
+///         if let Some(position) = geolocation.position() {
+///             <p>{ format!("{}, {}", position.latitude, position.longitude) }</p>
+///         } else if let Some(error) = geolocation.error() {
+///             <p class="error">{ error.to_string() }</p>
+///         } else {
+///             <p>{ "Locating..." }</p>
+///         }
+///     }
+/// }
+/// ```
+///
+/// [Geolocation API]: https://developer.mozilla.org/en-US/docs/Web/API/Geolocation_API
+#[hook]
+pub fn use_geolocation() -> UseGeolocationHandle {
+    use_geolocation_with_options(GeolocationOptions::default())
+}
+
+/// [`use_geolocation`] with options.
+///
+/// With [`GeolocationOptions::watch`], the position is updated as it changes until the component
+/// is unmounted. The position is requested again when the options change.
+#[hook]
+pub fn use_geolocation_with_options(options: GeolocationOptions) -> UseGeolocationHandle {
+    let state = use_state_eq(|| Rc::new(Ok(None)));
+
+    {
+        let state = state.setter();
+        use_effect_with(options, move |options| {
+            let geolocation = gloo::utils::window()
+                .navigator()
+                .geolocation()
+                .ok()
+                .filter(|m| !m.is_undefined());
+            let watch_id = Rc::new(Cell::new(None::<i32>));
+
+            let callbacks = geolocation.clone().map(|geolocation| {
+                // A one-shot request is a watch that is cleared after its first report, so that
+                // it can also be cancelled on unmount.
+                let clear = {
+                    let watch = options.watch;
+                    let watch_id = watch_id.clone();
+                    move || {
+                        if !watch {
+                            if let Some(id) = watch_id.take() {
+                                geolocation.clear_watch(id);
+                            }
+                        }
+                    }
+                };
+
+                let on_position = {
+                    let state = state.clone();
+                    let clear = clear.clone();
+                    Closure::<dyn Fn(Position)>::new(move |position: Position| {
+                        clear();
+                        state.set(Rc::new(Ok(Some(position.into()))));
+                    })
+                };
+                let on_error = {
+                    let state = state.clone();
+                    Closure::<dyn Fn(PositionError)>::new(move |e: PositionError| {
+                        clear();
+                        state.set(Rc::new(Err(e.into())));
+                    })
+                };
+
+                (on_position, on_error)
+            });
+
+            match (&geolocation, &callbacks) {
+                (Some(geolocation), Some((on_position, on_error))) => {
+                    let id = geolocation.watch_position_with_error_callback_and_options(
+                        on_position.as_ref().unchecked_ref(),
+                        Some(on_error.as_ref().unchecked_ref()),
+                        &options.to_position_options(),
+                    );
+                    match id {
+                        Ok(id) => watch_id.set(Some(id)),
+                        Err(_) => state.set(Rc::new(Err(GeolocationError::Unsupported))),
+                    }
+                }
+                _ => state.set(Rc::new(Err(GeolocationError::Unsupported))),
+            }
+
+            move || {
+                if let (Some(geolocation), Some(id)) = (geolocation, watch_id.take()) {
+                    geolocation.clear_watch(id);
+                }
+                drop(callbacks);
+            }
+        });
+    }
+
+    UseGeolocationHandle {
+        state: (*state).clone(),
+    }
+}
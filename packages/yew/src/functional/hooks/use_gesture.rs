@@ -0,0 +1,340 @@
+use std::rc::Rc;
+use std::time::Duration;
+
+use gloo::timers::callback::Timeout;
+use web_sys::PointerEvent;
+
+use super::use_debounce::timeout_millis;
+use super::{use_event, use_mut_ref};
+use crate::functional::hook;
+use crate::html::NodeRef;
+
+/// The direction of a swipe reported by [`use_swipe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SwipeDirection {
+    /// Towards the left of the screen.
+    Left,
+    /// Towards the right of the screen.
+    Right,
+    /// Towards the top of the screen.
+    Up,
+    /// Towards the bottom of the screen.
+    Down,
+}
+
+/// A swipe reported by [`use_swipe`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwipeEvent {
+    /// The main direction of the swipe.
+    pub direction: SwipeDirection,
+    /// The horizontal distance travelled, in CSS pixels, positive towards the right.
+    pub delta_x: f64,
+    /// The vertical distance travelled, in CSS pixels, positive towards the bottom.
+    pub delta_y: f64,
+    /// The time between the start and the end of the swipe.
+    pub duration: Duration,
+}
+
+/// Options for [`use_swipe_with_options`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwipeOptions {
+    /// The minimum distance to travel along the main direction, in CSS pixels. Defaults to 50.
+    pub threshold: f64,
+    /// The maximum duration of a swipe. Slower movements are not swipes. Defaults to one second.
+    pub max_duration: Duration,
+}
+
+impl Default for SwipeOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 50.0,
+            max_duration: Duration::from_secs(1),
+        }
+    }
+}
+
+/// A long press reported by [`use_long_press`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LongPressEvent {
+    /// The horizontal coordinate of the press, relative to the viewport.
+    pub client_x: i32,
+    /// The vertical coordinate of the press, relative to the viewport.
+    pub client_y: i32,
+}
+
+/// Options for [`use_long_press_with_options`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LongPressOptions {
+    /// How long the pointer must stay pressed. Defaults to 500 milliseconds.
+    pub duration: Duration,
+    /// How far the pointer may move while pressed, in CSS pixels. Defaults to 10.
+    pub tolerance: f64,
+}
+
+impl Default for LongPressOptions {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(500),
+            tolerance: 10.0,
+        }
+    }
+}
+
+/// Where and when a pointer was pressed.
+#[derive(Debug, Clone, Copy)]
+struct PointerStart {
+    pointer_id: i32,
+    x: f64,
+    y: f64,
+    time_stamp: f64,
+}
+
+impl PointerStart {
+    fn new(e: &PointerEvent) -> Self {
+        Self {
+            pointer_id: e.pointer_id(),
+            x: e.client_x().into(),
+            y: e.client_y().into(),
+            time_stamp: e.time_stamp(),
+        }
+    }
+
+    /// Returns the distance travelled by the pointer of `e` since it was pressed, if it is the
+    /// same pointer.
+    fn delta(&self, e: &PointerEvent) -> Option<(f64, f64)> {
+        (e.pointer_id() == self.pointer_id).then(|| {
+            (
+                f64::from(e.client_x()) - self.x,
+                f64::from(e.client_y()) - self.y,
+            )
+        })
+    }
+}
+
+/// Recognizes a swipe from the distance travelled by a pointer in `duration`.
+fn recognize_swipe(
+    (delta_x, delta_y): (f64, f64),
+    duration: Duration,
+    options: &SwipeOptions,
+) -> Option<SwipeEvent> {
+    if duration > options.max_duration || delta_x.abs().max(delta_y.abs()) < options.threshold {
+        return None;
+    }
+
+    let direction = match (delta_x.abs() >= delta_y.abs(), delta_x < 0.0, delta_y < 0.0) {
+        (true, true, _) => SwipeDirection::Left,
+        (true, false, _) => SwipeDirection::Right,
+        (false, _, true) => SwipeDirection::Up,
+        (false, _, false) => SwipeDirection::Down,
+    };
+
+    Some(SwipeEvent {
+        direction,
+        delta_x,
+        delta_y,
+        duration,
+    })
+}
+
+/// This hook is used to recognize swipes on the element referenced by `node`.
+///
+/// `callback` is called when a pointer, such as a finger or a mouse, is pressed and released
+/// after travelling far enough and quickly enough. The `callback` passed in the latest render is
+/// the one being called.
+///
+/// To also recognize swipes on touch screens, the browser must not handle the movement itself,
+/// for example by setting the CSS `touch-action` property of the element to `none` or `pan-y`.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Carousel() -> Html {
+///     let node = use_node_ref();
+///     let index = use_state(|| 0);
+///     {
+///         let index = index.clone();
+///         use_swipe(node.clone(), move |e: SwipeEvent| match e.direction {
+///             SwipeDirection::Left => index.set(*index + 1),
+///             SwipeDirection::Right => index.set((*index).max(1) - 1),
+///             _ => {}
+///         });
+///     }
+///
+///     html! {
+///         <div ref={node} style="touch-action: pan-y;">{ format!("Slide {}", *index) }</div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_swipe<F>(node: NodeRef, callback: F)
+where
+    F: Fn(SwipeEvent) + 'static,
+{
+    use_swipe_with_options(node, SwipeOptions::default(), callback)
+}
+
+/// [`use_swipe`] with options.
+#[hook]
+pub fn use_swipe_with_options<F>(node: NodeRef, options: SwipeOptions, callback: F)
+where
+    F: Fn(SwipeEvent) + 'static,
+{
+    let start = use_mut_ref(|| None::<PointerStart>);
+
+    {
+        let start = start.clone();
+        use_event(node.clone(), "pointerdown", move |e: PointerEvent| {
+            if e.is_primary() {
+                *start.borrow_mut() = Some(PointerStart::new(&e));
+            }
+        });
+    }
+
+    {
+        let start = start.clone();
+        use_event(node.clone(), "pointerup", move |e: PointerEvent| {
+            let Some(pointer) = *start.borrow() else {
+                return;
+            };
+            let Some(delta) = pointer.delta(&e) else {
+                return;
+            };
+            start.borrow_mut().take();
+
+            let duration =
+                Duration::from_secs_f64((e.time_stamp() - pointer.time_stamp).max(0.0) / 1000.0);
+            if let Some(swipe) = recognize_swipe(delta, duration, &options) {
+                callback(swipe);
+            }
+        });
+    }
+
+    use_event(node, "pointercancel", move |_: PointerEvent| {
+        start.borrow_mut().take();
+    });
+}
+
+/// This hook is used to recognize long presses on the element referenced by `node`.
+///
+/// `callback` is called once a pointer has stayed pressed on the element, without moving, for
+/// long enough. The `callback` passed in the latest render is the one being called.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Message() -> Html {
+///     let node = use_node_ref();
+///     let menu = use_state(|| false);
+///     {
+///         let menu = menu.clone();
+///         use_long_press(node.clone(), move |_| menu.set(true));
+///     }
+///
+///     html! {
+///         <div ref={node}>
+///             { "Hello" }
+///             if *menu { <ul class="menu"><li>{ "Reply" }</li></ul> }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_long_press<F>(node: NodeRef, callback: F)
+where
+    F: Fn(LongPressEvent) + 'static,
+{
+    use_long_press_with_options(node, LongPressOptions::default(), callback)
+}
+
+/// [`use_long_press`] with options.
+#[hook]
+pub fn use_long_press_with_options<F>(node: NodeRef, options: LongPressOptions, callback: F)
+where
+    F: Fn(LongPressEvent) + 'static,
+{
+    // The pending press is cancelled when the timeout is dropped, including on unmount.
+    let pending = use_mut_ref(|| None::<(PointerStart, Timeout)>);
+    let callback = Rc::new(callback);
+
+    {
+        let pending = pending.clone();
+        let duration = options.duration;
+        use_event(node.clone(), "pointerdown", move |e: PointerEvent| {
+            if !e.is_primary() {
+                return;
+            }
+
+            let event = LongPressEvent {
+                client_x: e.client_x(),
+                client_y: e.client_y(),
+            };
+            let timeout = {
+                let pending = pending.clone();
+                let callback = callback.clone();
+                Timeout::new(timeout_millis(duration), move || {
+                    // The timeout is forgotten, as dropping it from its own callback is not
+                    // allowed.
+                    if let Some((_, timeout)) = pending.borrow_mut().take() {
+                        timeout.forget();
+                    }
+                    callback(event);
+                })
+            };
+            *pending.borrow_mut() = Some((PointerStart::new(&e), timeout));
+        });
+    }
+
+    {
+        let pending = pending.clone();
+        let tolerance = options.tolerance;
+        use_event(node.clone(), "pointermove", move |e: PointerEvent| {
+            let moved = pending
+                .borrow()
+                .as_ref()
+                .and_then(|(start, _)| start.delta(&e))
+                .is_some_and(|(x, y)| x.hypot(y) > tolerance);
+            if moved {
+                pending.borrow_mut().take();
+            }
+        });
+    }
+
+    let cancel = move |_: PointerEvent| {
+        pending.borrow_mut().take();
+    };
+    use_event(node.clone(), "pointerup", cancel.clone());
+    use_event(node.clone(), "pointercancel", cancel.clone());
+    use_event(node, "pointerleave", cancel);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swipes_are_recognized_along_the_main_axis() {
+        let options = SwipeOptions::default();
+        let fast = Duration::from_millis(200);
+
+        let swipe = recognize_swipe((-80.0, 20.0), fast, &options).unwrap();
+        assert_eq!(swipe.direction, SwipeDirection::Left);
+        let swipe = recognize_swipe((10.0, 60.0), fast, &options).unwrap();
+        assert_eq!(swipe.direction, SwipeDirection::Down);
+        let swipe = recognize_swipe((0.0, -60.0), fast, &options).unwrap();
+        assert_eq!(swipe.direction, SwipeDirection::Up);
+
+        // Too short.
+        assert_eq!(recognize_swipe((30.0, 20.0), fast, &options), None);
+        // Too slow.
+        assert_eq!(
+            recognize_swipe((200.0, 0.0), Duration::from_secs(2), &options),
+            None
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use wasm_bindgen::prelude::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+
+use super::{use_effect_with, use_state_eq};
+use crate::functional::hook;
+use crate::html::NodeRef;
+use crate::virtual_dom::AttrValue;
+
+/// The intersection of an element with the viewport.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Intersection {
+    /// Whether the element intersects the viewport.
+    pub is_intersecting: bool,
+    /// The visible fraction of the element, between `0.0` and `1.0`.
+    pub ratio: f64,
+}
+
+/// Options for [`use_intersection_observer_with_options`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntersectionObserverOptions {
+    /// Margin around the viewport, using the syntax of the CSS `margin` property.
+    ///
+    /// A positive margin reports elements as intersecting before they scroll into view.
+    pub root_margin: AttrValue,
+    /// The visible fractions at which the intersection is updated.
+    pub thresholds: Vec<f64>,
+}
+
+impl Default for IntersectionObserverOptions {
+    fn default() -> Self {
+        Self {
+            root_margin: "0px".into(),
+            thresholds: vec![0.0],
+        }
+    }
+}
+
+/// This hook is used to track whether an element is visible in the viewport.
+///
+/// The element referenced by `node` is observed with an [`IntersectionObserver`] once the
+/// component has been rendered, and the component re-renders whenever its intersection changes.
+/// Before the element has been observed, as well as during server-side rendering, the element is
+/// reported as not intersecting.
+///
+/// See [`use_intersection_observer_with_options`] to set a margin or thresholds.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct LazyImageProps {
+///     src: AttrValue,
+/// }
+///
+/// #[function_component]
+/// fn LazyImage(props: &LazyImageProps) -> Html {
+///     let node = use_node_ref();
+///     let intersection = use_intersection_observer(node.clone());
+///
+///     html! {
+///         <div ref={node} class="placeholder">
+///             if intersection.is_intersecting {
+///                 <img src={props.src.clone()} />
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+///
+/// [`IntersectionObserver`]: https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver
+#[hook]
+pub fn use_intersection_observer(node: NodeRef) -> Intersection {
+    use_intersection_observer_with_options(node, IntersectionObserverOptions::default())
+}
+
+/// [`use_intersection_observer`] with a custom margin and thresholds.
+#[hook]
+pub fn use_intersection_observer_with_options(
+    node: NodeRef,
+    options: IntersectionObserverOptions,
+) -> Intersection {
+    let intersection = use_state_eq(Intersection::default);
+
+    {
+        let intersection = intersection.setter();
+        use_effect_with((node, options), move |(node, options)| {
+            let observer = node.cast::<Element>().and_then(|element| {
+                let callback =
+                    Closure::<dyn Fn(js_sys::Array)>::new(move |entries: js_sys::Array| {
+                        let entry = entries
+                            .iter()
+                            .last()
+                            .and_then(|m| m.dyn_into::<IntersectionObserverEntry>().ok());
+
+                        if let Some(entry) = entry {
+                            intersection.set(Intersection {
+                                is_intersecting: entry.is_intersecting(),
+                                ratio: entry.intersection_ratio(),
+                            });
+                        }
+                    });
+
+                let init = IntersectionObserverInit::new();
+                init.set_root_margin(&options.root_margin);
+                init.set_threshold(
+                    &options
+                        .thresholds
+                        .iter()
+                        .copied()
+                        .map(js_sys::Number::from)
+                        .collect::<js_sys::Array>(),
+                );
+
+                let observer = IntersectionObserver::new_with_options(
+                    callback.as_ref().unchecked_ref(),
+                    &init,
+                )
+                .ok()?;
+                observer.observe(&element);
+
+                Some((observer, callback))
+            });
+
+            move || {
+                if let Some((observer, _callback)) = observer {
+                    observer.disconnect();
+                }
+            }
+        });
+    }
+
+    *intersection
+}
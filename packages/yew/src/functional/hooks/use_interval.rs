@@ -0,0 +1,53 @@
+use std::rc::Rc;
+use std::time::Duration;
+
+use gloo::timers::callback::Interval;
+
+use super::use_debounce::timeout_millis;
+use super::{use_effect_with, use_mut_ref};
+use crate::functional::hook;
+
+/// This hook is used to call a function periodically.
+///
+/// `callback` is called every `interval`, starting one `interval` after the component has been
+/// rendered. The timer is restarted when `interval` changes and cancelled when the component is
+/// unmounted. The `callback` passed in the latest render is always the one being called.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Clock() -> Html {
+///     let seconds = use_state(|| 0);
+///     {
+///         let seconds = seconds.clone();
+///         use_interval(move || seconds.set(*seconds + 1), Duration::from_secs(1));
+///     }
+///
+///     html! { <p>{ format!("{} seconds elapsed", *seconds) }</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_interval<F>(callback: F, interval: Duration)
+where
+    F: Fn() + 'static,
+{
+    let latest = use_mut_ref(|| None::<Rc<dyn Fn()>>);
+    *latest.borrow_mut() = Some(Rc::new(callback));
+
+    use_effect_with(interval, move |interval| {
+        let interval = Interval::new(timeout_millis(*interval), move || {
+            // The callback may cause a re-render, so it must not be borrowed while called.
+            let callback = latest.borrow().clone();
+            if let Some(callback) = callback {
+                callback();
+            }
+        });
+
+        move || drop(interval)
+    });
+}
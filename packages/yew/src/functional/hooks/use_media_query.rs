@@ -0,0 +1,72 @@
+use wasm_bindgen::prelude::Closure;
+use wasm_bindgen::JsCast;
+
+use super::{use_effect_with, use_state_eq};
+use crate::functional::hook;
+use crate::virtual_dom::AttrValue;
+
+/// This hook is used to check whether the document matches a [media query].
+///
+/// The query is evaluated once the component has been rendered, and the component re-renders
+/// whenever the result changes, for example when the window is resized. Before the query has been
+/// evaluated, as well as during server-side rendering and hydration, `false` is returned, so that
+/// the server-rendered markup does not depend on the client. The listener is removed when the
+/// component is unmounted or the query changes.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Navigation() -> Html {
+///     let is_mobile = use_media_query("(max-width: 768px)".into());
+///
+///     if is_mobile {
+///         html! { <button>{ "Menu" }</button> }
+///     } else {
+///         html! { <nav>{ "Home | About" }</nav> }
+///     }
+/// }
+/// ```
+///
+/// [media query]: https://developer.mozilla.org/en-US/docs/Web/CSS/Media_Queries/Using_media_queries
+#[hook]
+pub fn use_media_query(query: AttrValue) -> bool {
+    let matches = use_state_eq(|| false);
+
+    {
+        let matches = matches.setter();
+        use_effect_with(query, move |query| {
+            let list = gloo::utils::window()
+                .match_media(query)
+                .ok()
+                .flatten()
+                .map(|list| {
+                    matches.set(list.matches());
+
+                    let callback = {
+                        let list = list.clone();
+                        Closure::<dyn Fn()>::new(move || matches.set(list.matches()))
+                    };
+                    let _ = list.add_event_listener_with_callback(
+                        "change",
+                        callback.as_ref().unchecked_ref(),
+                    );
+
+                    (list, callback)
+                });
+
+            move || {
+                if let Some((list, callback)) = list {
+                    let _ = list.remove_event_listener_with_callback(
+                        "change",
+                        callback.as_ref().unchecked_ref(),
+                    );
+                }
+            }
+        });
+    }
+
+    *matches
+}
@@ -0,0 +1,109 @@
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::functional::{Hook, HookContext};
+
+/// This hook returns `true` if the component has not been rendered yet.
+///
+/// Renders that are suspended are not taken into account, so this hook keeps returning `true`
+/// until the first render of the component has been committed. It always returns `true` with
+/// server-side rendering.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn FadeIn() -> Html {
+///     let first_render = use_is_first_render();
+///     let class = if first_render { "hidden" } else { "visible" };
+///
+///     html! { <div {class}>{ "Hello" }</div> }
+/// }
+/// ```
+pub fn use_is_first_render() -> impl Hook<Output = bool> {
+    struct HookProvider;
+
+    impl Hook for HookProvider {
+        type Output = bool;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            !ctx.mounted.get()
+        }
+    }
+
+    HookProvider
+}
+
+/// State handle for the [`use_mounted`] hook.
+#[derive(Clone)]
+pub struct UseMountedHandle {
+    mounted: Rc<Cell<bool>>,
+}
+
+impl UseMountedHandle {
+    /// Returns `true` if the component is rendered and has not been destroyed.
+    pub fn is_mounted(&self) -> bool {
+        self.mounted.get()
+    }
+}
+
+impl fmt::Debug for UseMountedHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseMountedHandle")
+            .field("mounted", &self.mounted.get())
+            .finish()
+    }
+}
+
+/// This hook returns a handle telling whether the component is currently mounted.
+///
+/// The handle can be moved into callbacks that may outlive the component, such as the completion
+/// of a request, to avoid updating a component that no longer exists.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::platform::spawn_local;
+/// use yew::prelude::*;
+///
+/// # async fn fetch_greeting() -> String { String::new() }
+/// #[function_component]
+/// fn Greeting() -> Html {
+///     let mounted = use_mounted();
+///     let greeting = use_state(String::new);
+///
+///     let onclick = {
+///         let greeting = greeting.clone();
+///         Callback::from(move |_| {
+///             let mounted = mounted.clone();
+///             let greeting = greeting.clone();
+///             spawn_local(async move {
+///                 let value = fetch_greeting().await;
+///                 if mounted.is_mounted() {
+///                     greeting.set(value);
+///                 }
+///             });
+///         })
+///     };
+///
+///     html! { <button {onclick}>{ (*greeting).clone() }</button> }
+/// }
+/// ```
+pub fn use_mounted() -> impl Hook<Output = UseMountedHandle> {
+    struct HookProvider;
+
+    impl Hook for HookProvider {
+        type Output = UseMountedHandle;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            UseMountedHandle {
+                mounted: ctx.mounted.clone(),
+            }
+        }
+    }
+
+    HookProvider
+}
@@ -0,0 +1,65 @@
+use wasm_bindgen::prelude::Closure;
+use wasm_bindgen::JsCast;
+
+use super::{use_effect_with, use_state_eq};
+use crate::functional::hook;
+
+const EVENTS: [&str; 2] = ["online", "offline"];
+
+/// This hook is used to check whether the browser is connected to the network.
+///
+/// The state is read from [`navigator.onLine`] once the component has been rendered and updated
+/// on every `online` and `offline` event of the window. Before that, as well as during
+/// server-side rendering and hydration, `true` is returned. The listeners are removed when the
+/// component is unmounted.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn ConnectionBanner() -> Html {
+///     let online = use_online();
+///
+///     html! {
+///         if !online {
+///             <div class="banner">{ "You are offline." }</div>
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`navigator.onLine`]: https://developer.mozilla.org/en-US/docs/Web/API/Navigator/onLine
+#[hook]
+pub fn use_online() -> bool {
+    let online = use_state_eq(|| true);
+
+    {
+        let online = online.setter();
+        use_effect_with((), move |_| {
+            let window = gloo::utils::window();
+            online.set(window.navigator().on_line());
+
+            let callback = {
+                let window = window.clone();
+                Closure::<dyn Fn()>::new(move || online.set(window.navigator().on_line()))
+            };
+            for event in EVENTS {
+                let _ = window
+                    .add_event_listener_with_callback(event, callback.as_ref().unchecked_ref());
+            }
+
+            move || {
+                for event in EVENTS {
+                    let _ = window.remove_event_listener_with_callback(
+                        event,
+                        callback.as_ref().unchecked_ref(),
+                    );
+                }
+            }
+        });
+    }
+
+    *online
+}
@@ -8,7 +8,7 @@ use serde::Serialize;
 use wasm_bindgen::JsValue;
 
 use super::PreparedStateBase;
-use crate::functional::{use_state, Hook, HookContext};
+use crate::functional::{decode_state, use_state, Hook, HookContext};
 use crate::platform::spawn_local;
 use crate::suspense::{Suspension, SuspensionResult};
 
@@ -90,10 +90,9 @@ where
                                 .await
                                 .expect("failed to deserialize state");
 
-                            let (state, deps) =
-                                bincode::deserialize::<(Option<T>, Option<D>)>(&buf)
-                                    .map(|(state, deps)| (state.map(Rc::new), deps.map(Rc::new)))
-                                    .expect("failed to deserialize state");
+                            let (state, deps) = decode_state::<(Option<T>, Option<D>)>(&buf)
+                                .map(|(state, deps)| (state.map(Rc::new), deps.map(Rc::new)))
+                                .expect("failed to deserialize state");
 
                             data.set((Ok((state, deps)), None));
                         });
@@ -108,6 +108,8 @@ mod feat_any_hydration_ssr {
     use serde::de::DeserializeOwned;
     use serde::Serialize;
 
+    #[cfg(feature = "ssr")]
+    use crate::functional::encode_state;
     use crate::functional::PreparedState;
 
     pub(super) struct PreparedStateBase<T, D>
@@ -133,7 +135,7 @@ mod feat_any_hydration_ssr {
         fn prepare(&self) -> String {
             use base64ct::{Base64, Encoding};
 
-            let state = bincode::serialize(&(self.state.as_deref(), self.deps.as_deref()))
+            let state = encode_state(&(self.state.as_deref(), self.deps.as_deref()))
                 .expect("failed to prepare state");
 
             Base64::encode_string(&state)
@@ -0,0 +1,79 @@
+use std::cell::RefCell;
+
+use crate::functional::{Effect, Hook, HookContext};
+
+struct UsePrevious<T> {
+    /// The value of the last committed render.
+    committed: Option<T>,
+    /// The value of the current render, committed once it has been rendered.
+    pending: Option<T>,
+}
+
+impl<T: 'static> Effect for RefCell<UsePrevious<T>> {
+    fn rendered(&self) {
+        let mut this = self.borrow_mut();
+        if let Some(value) = this.pending.take() {
+            this.committed = Some(value);
+        }
+    }
+}
+
+/// This hook returns the value passed to it in the previous render.
+///
+/// `None` is returned on the first render. Renders that are suspended or otherwise not committed
+/// are not taken into account, so the returned value is always the one of the last render shown to
+/// the user.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Counter() -> Html {
+///     let counter = use_state(|| 0);
+///     let previous = use_previous(*counter);
+///
+///     let onclick = {
+///         let counter = counter.clone();
+///         Callback::from(move |_| counter.set(*counter + 1))
+///     };
+///
+///     html! {
+///         <div>
+///             <button {onclick}>{ "Increment" }</button>
+///             <p>{ format!("Current: {}, previous: {:?}", *counter, previous) }</p>
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_previous<T>(value: T) -> impl Hook<Output = Option<T>>
+where
+    T: Clone + 'static,
+{
+    struct HookProvider<T> {
+        value: T,
+    }
+
+    impl<T> Hook for HookProvider<T>
+    where
+        T: Clone + 'static,
+    {
+        type Output = Option<T>;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let state = ctx.next_effect(|_| -> RefCell<UsePrevious<T>> {
+                RefCell::new(UsePrevious {
+                    committed: None,
+                    pending: None,
+                })
+            });
+
+            let mut state = state.borrow_mut();
+            state.pending = Some(self.value);
+            state.committed.clone()
+        }
+    }
+
+    HookProvider { value }
+}
@@ -2,13 +2,15 @@ use std::cell::RefCell;
 use std::fmt;
 use std::marker::PhantomData;
 use std::ops::Deref;
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 
 use crate::functional::{hook, Hook, HookContext};
 use crate::html::IntoPropValue;
+use crate::time_travel::Timeline;
 use crate::Callback;
 
 type DispatchFn<T> = Rc<dyn Fn(<T as Reducible>::Action)>;
+type DispatchSlot<T> = Rc<RefCell<Option<Weak<dyn Fn(<T as Reducible>::Action)>>>>;
 
 /// A trait that implements a reducer function of a type.
 pub trait Reducible {
@@ -19,6 +21,99 @@ pub trait Reducible {
     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self>;
 }
 
+/// A middleware of [`use_reducer_with_middleware`].
+///
+/// Middleware sees every action dispatched to the reducer before it is reduced. It passes the
+/// action on with [`MiddlewareNext::run`], possibly after transforming it, or drops it by not
+/// calling `run`. Code after `run` observes the state resulting from the action.
+///
+/// This trait is implemented for all closures with a matching signature.
+pub trait ReducerMiddleware<T>
+where
+    T: Reducible,
+{
+    /// Handles an action dispatched to the reducer.
+    fn handle(&self, action: T::Action, next: MiddlewareNext<T>);
+}
+
+impl<T, F> ReducerMiddleware<T> for F
+where
+    T: Reducible,
+    F: Fn(T::Action, MiddlewareNext<T>),
+{
+    fn handle(&self, action: T::Action, next: MiddlewareNext<T>) {
+        self(action, next)
+    }
+}
+
+/// The rest of the middleware chain of [`use_reducer_with_middleware`], ending with the reducer.
+pub struct MiddlewareNext<T>
+where
+    T: Reducible,
+{
+    chain: Rc<[Box<dyn ReducerMiddleware<T>>]>,
+    index: usize,
+    current_state: Rc<RefCell<Rc<T>>>,
+    reduce: DispatchFn<T>,
+    dispatch: DispatchSlot<T>,
+}
+
+impl<T> MiddlewareNext<T>
+where
+    T: Reducible + 'static,
+{
+    /// Passes `action` to the next middleware, or to the reducer if this is the last one.
+    ///
+    /// Afterwards, [`state`](Self::state) returns the state resulting from the action.
+    pub fn run(&self, action: T::Action) {
+        match self.chain.get(self.index) {
+            Some(middleware) => middleware.handle(
+                action,
+                Self {
+                    chain: self.chain.clone(),
+                    index: self.index + 1,
+                    current_state: self.current_state.clone(),
+                    reduce: self.reduce.clone(),
+                    dispatch: self.dispatch.clone(),
+                },
+            ),
+            None => (self.reduce)(action),
+        }
+    }
+
+    /// Returns the current state.
+    pub fn state(&self) -> Rc<T> {
+        self.current_state.borrow().clone()
+    }
+
+    /// Returns a dispatcher sending actions through the whole middleware chain.
+    ///
+    /// This can be used to dispatch follow-up actions, for example once a future has completed.
+    /// Actions dispatched after the component has been destroyed are ignored.
+    pub fn dispatcher(&self) -> UseReducerDispatcher<T> {
+        let dispatch = self.dispatch.clone();
+        UseReducerDispatcher {
+            dispatch: Rc::new(move |action| {
+                let dispatch = dispatch.borrow().as_ref().and_then(Weak::upgrade);
+                if let Some(dispatch) = dispatch {
+                    dispatch(action)
+                }
+            }),
+        }
+    }
+}
+
+impl<T> fmt::Debug for MiddlewareNext<T>
+where
+    T: Reducible,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MiddlewareNext")
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
 struct UseReducer<T>
 where
     T: Reducible,
@@ -174,10 +269,13 @@ where
     }
 }
 
-/// The base function of [`use_reducer`] and [`use_reducer_eq`]
+/// The base function of [`use_reducer`], [`use_reducer_eq`], [`use_reducer_with_middleware`] and
+/// [`use_reducer_with_timeline`]
 fn use_reducer_base<'hook, T>(
     init_fn: impl 'hook + FnOnce() -> T,
     should_render_fn: fn(&T, &T) -> bool,
+    middleware: Vec<Box<dyn ReducerMiddleware<T>>>,
+    timeline: Option<Timeline<T, T::Action>>,
 ) -> impl 'hook + Hook<Output = UseReducerHandle<T>>
 where
     T: Reducible + 'static,
@@ -191,6 +289,8 @@ where
 
         init_fn: F,
         should_render_fn: fn(&T, &T) -> bool,
+        middleware: Vec<Box<dyn ReducerMiddleware<T>>>,
+        timeline: Option<Timeline<T, T::Action>>,
     }
 
     impl<'hook, T, F> Hook for HookProvider<'hook, T, F>
@@ -204,6 +304,8 @@ where
             let Self {
                 init_fn,
                 should_render_fn,
+                middleware,
+                timeline,
                 ..
             } = self;
 
@@ -211,9 +313,22 @@ where
                 let val = Rc::new(RefCell::new(Rc::new(init_fn())));
                 let should_render_fn = Rc::new(should_render_fn);
 
-                UseReducer {
-                    current_state: val.clone(),
-                    dispatch: Rc::new(move |action: T::Action| {
+                if let Some(timeline) = timeline {
+                    let initial = val.borrow().clone();
+                    let val = val.clone();
+                    let re_render = re_render.clone();
+                    timeline.start(
+                        initial,
+                        Rc::new(move |state| {
+                            *val.borrow_mut() = state;
+                            re_render()
+                        }),
+                    );
+                }
+
+                let reduce: DispatchFn<T> = {
+                    let val = val.clone();
+                    Rc::new(move |action: T::Action| {
                         let should_render = {
                             let should_render_fn = should_render_fn.clone();
                             let mut val = val.borrow_mut();
@@ -229,7 +344,38 @@ where
                         if should_render {
                             re_render()
                         }
-                    }),
+                    })
+                };
+
+                let dispatch = if middleware.is_empty() {
+                    reduce
+                } else {
+                    let chain: Rc<[Box<dyn ReducerMiddleware<T>>]> = middleware.into();
+                    // Middleware can dispatch new actions, so the chain needs a reference to
+                    // itself. It is weak to not keep the state alive after the component is gone.
+                    let slot: DispatchSlot<T> = Rc::new(RefCell::new(None));
+                    let dispatch: DispatchFn<T> = {
+                        let val = val.clone();
+                        let slot = slot.clone();
+                        Rc::new(move |action: T::Action| {
+                            MiddlewareNext {
+                                chain: chain.clone(),
+                                index: 0,
+                                current_state: val.clone(),
+                                reduce: reduce.clone(),
+                                dispatch: slot.clone(),
+                            }
+                            .run(action)
+                        })
+                    };
+                    *slot.borrow_mut() = Some(Rc::downgrade(&dispatch));
+
+                    dispatch
+                };
+
+                UseReducer {
+                    current_state: val,
+                    dispatch,
                 }
             });
 
@@ -244,6 +390,8 @@ where
         _marker: PhantomData,
         init_fn,
         should_render_fn,
+        middleware,
+        timeline,
     }
 }
 
@@ -344,7 +492,7 @@ where
     T: Reducible + 'static,
     F: FnOnce() -> T,
 {
-    use_reducer_base(init_fn, |_, _| true)
+    use_reducer_base(init_fn, |_, _| true, Vec::new(), None)
 }
 
 /// [`use_reducer`] but only re-renders when `prev_state != next_state`.
@@ -357,5 +505,98 @@ where
     T: Reducible + PartialEq + 'static,
     F: FnOnce() -> T,
 {
-    use_reducer_base(init_fn, T::ne)
+    use_reducer_base(init_fn, T::ne, Vec::new(), None)
+}
+
+/// [`use_reducer`] with middleware observing and transforming actions before they are reduced.
+///
+/// Actions go through `middleware` in order before reaching the reducer, see
+/// [`ReducerMiddleware`]. Like `init_fn`, the middleware is only used from the first render on;
+/// the middleware passed to later renders is ignored.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::rc::Rc;
+/// use yew::prelude::*;
+///
+/// struct Counter {
+///     value: i32,
+/// }
+///
+/// impl Reducible for Counter {
+///     type Action = i32;
+///
+///     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+///         Self {
+///             value: self.value + action,
+///         }
+///         .into()
+///     }
+/// }
+///
+/// /// Logs every action together with the state it leads to.
+/// fn logger(action: i32, next: MiddlewareNext<Counter>) {
+///     let before = next.state().value;
+///     next.run(action);
+///     tracing::debug!(action, before, after = next.state().value);
+/// }
+///
+/// /// Ignores decrements below zero.
+/// fn non_negative(action: i32, next: MiddlewareNext<Counter>) {
+///     if next.state().value + action >= 0 {
+///         next.run(action);
+///     }
+/// }
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     let counter = use_reducer_with_middleware(
+///         || Counter { value: 0 },
+///         vec![Box::new(logger), Box::new(non_negative)],
+///     );
+///
+///     let onclick = {
+///         let counter = counter.clone();
+///         Callback::from(move |_| counter.dispatch(-1))
+///     };
+///
+///     html! { <button {onclick}>{ counter.value }</button> }
+/// }
+/// ```
+#[hook]
+pub fn use_reducer_with_middleware<T, F>(
+    init_fn: F,
+    middleware: Vec<Box<dyn ReducerMiddleware<T>>>,
+) -> UseReducerHandle<T>
+where
+    T: Reducible + 'static,
+    F: FnOnce() -> T,
+{
+    use_reducer_base(init_fn, |_, _| true, middleware, None)
+}
+
+/// [`use_reducer`] recording the dispatched actions and the states they lead to in `timeline`.
+///
+/// Stepping back or forth in the timeline restores the recorded states, see the
+/// [`time_travel`](crate::time_travel) module. Like `init_fn`, the timeline is only used from the
+/// first render on; the timeline passed to later renders is ignored.
+#[hook]
+pub fn use_reducer_with_timeline<T, F>(
+    init_fn: F,
+    timeline: Timeline<T, T::Action>,
+) -> UseReducerHandle<T>
+where
+    T: Reducible + 'static,
+    T::Action: Clone,
+    F: FnOnce() -> T,
+{
+    let record = {
+        let timeline = timeline.clone();
+        move |action: T::Action, next: MiddlewareNext<T>| {
+            next.run(action.clone());
+            timeline.record(Some(action), next.state());
+        }
+    };
+    use_reducer_base(init_fn, |_, _| true, vec![Box::new(record)], Some(timeline))
 }
@@ -2,6 +2,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::functional::{hook, use_state, Hook, HookContext};
+use crate::html::TypedNodeRef;
 use crate::NodeRef;
 
 struct UseRef<F> {
@@ -189,3 +190,35 @@ where
 pub fn use_node_ref() -> NodeRef {
     (*use_state(NodeRef::default)).clone()
 }
+
+/// This hook is used to obtain a [`TypedNodeRef`], a [`NodeRef`] that carries the type of the
+/// element it references.
+///
+/// # Example
+///
+/// ```rust
+/// use web_sys::HtmlInputElement;
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn AutoFocus() -> Html {
+///     let input_ref = use_node_ref_typed::<HtmlInputElement>();
+///
+///     {
+///         let input_ref = input_ref.clone();
+///         use_effect_with((), move |_| match input_ref.cast() {
+///             Ok(input) => input.focus().unwrap(),
+///             Err(e) => gloo::console::error!(e.to_string()),
+///         });
+///     }
+///
+///     html! { <input ref={input_ref} type="text" /> }
+/// }
+/// ```
+#[hook]
+pub fn use_node_ref_typed<T>() -> TypedNodeRef<T>
+where
+    T: 'static,
+{
+    (*use_state(TypedNodeRef::default)).clone()
+}
@@ -0,0 +1,83 @@
+use wasm_bindgen::prelude::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, ResizeObserver, ResizeObserverEntry};
+
+use super::{use_effect_with, use_state_eq};
+use crate::functional::hook;
+use crate::html::NodeRef;
+
+/// The size of the content box of an element, in CSS pixels.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ElementSize {
+    /// The width of the element.
+    pub width: f64,
+    /// The height of the element.
+    pub height: f64,
+}
+
+/// This hook is used to track the size of an element.
+///
+/// The element referenced by `node` is observed with a [`ResizeObserver`] once the component has
+/// been rendered, and the component re-renders whenever the size of the element changes. Before
+/// the element has been measured, as well as during server-side rendering, the returned size is
+/// zero.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Panel() -> Html {
+///     let node = use_node_ref();
+///     let size = use_resize_observer(node.clone());
+///
+///     html! {
+///         <div ref={node}>
+///             { format!("{} x {}", size.width, size.height) }
+///         </div>
+///     }
+/// }
+/// ```
+///
+/// [`ResizeObserver`]: https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver
+#[hook]
+pub fn use_resize_observer(node: NodeRef) -> ElementSize {
+    let size = use_state_eq(ElementSize::default);
+
+    {
+        let size = size.setter();
+        use_effect_with(node, move |node| {
+            let observer = node.cast::<Element>().and_then(|element| {
+                let callback =
+                    Closure::<dyn Fn(js_sys::Array)>::new(move |entries: js_sys::Array| {
+                        let entry = entries
+                            .iter()
+                            .last()
+                            .and_then(|m| m.dyn_into::<ResizeObserverEntry>().ok());
+
+                        if let Some(entry) = entry {
+                            let rect = entry.content_rect();
+                            size.set(ElementSize {
+                                width: rect.width(),
+                                height: rect.height(),
+                            });
+                        }
+                    });
+
+                let observer = ResizeObserver::new(callback.as_ref().unchecked_ref()).ok()?;
+                observer.observe(&element);
+
+                Some((observer, callback))
+            });
+
+            move || {
+                if let Some((observer, _callback)) = observer {
+                    observer.disconnect();
+                }
+            }
+        });
+    }
+
+    *size
+}
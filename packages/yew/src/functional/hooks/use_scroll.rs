@@ -0,0 +1,188 @@
+use gloo::render::{request_animation_frame, AnimationFrame};
+use web_sys::{Element, Event};
+
+use super::use_event::{use_event_base, ListenerTarget};
+use super::{use_effect_with, use_mut_ref, use_state_eq, UseStateHandle};
+use crate::functional::hook;
+use crate::html::NodeRef;
+
+/// The direction of the latest scroll reported by [`use_scroll`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollDirection {
+    /// Towards the top of the content.
+    Up,
+    /// Towards the bottom of the content.
+    Down,
+    /// Towards the start of a line of content.
+    Left,
+    /// Towards the end of a line of content.
+    Right,
+}
+
+/// The scroll position reported by [`use_scroll`] and [`use_window_scroll`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ScrollState {
+    /// The horizontal scroll offset, in CSS pixels.
+    pub x: f64,
+    /// The vertical scroll offset, in CSS pixels.
+    pub y: f64,
+    /// The direction of the latest scroll, or `None` if the content has not been scrolled yet.
+    pub direction: Option<ScrollDirection>,
+}
+
+impl ScrollState {
+    /// Returns the state after scrolling to `(x, y)`.
+    ///
+    /// The direction is the one of the axis that moved the most, and is kept if the position did
+    /// not change.
+    fn scrolled_to(self, x: f64, y: f64) -> Self {
+        let (dx, dy) = (x - self.x, y - self.y);
+        let direction = if dx == 0.0 && dy == 0.0 {
+            self.direction
+        } else if dy.abs() >= dx.abs() {
+            Some(if dy < 0.0 {
+                ScrollDirection::Up
+            } else {
+                ScrollDirection::Down
+            })
+        } else {
+            Some(if dx < 0.0 {
+                ScrollDirection::Left
+            } else {
+                ScrollDirection::Right
+            })
+        };
+
+        Self { x, y, direction }
+    }
+}
+
+/// Returns the scroll offset of `target`.
+fn scroll_position(target: &ListenerTarget) -> Option<(f64, f64)> {
+    match target {
+        ListenerTarget::Node(node) => node
+            .cast::<Element>()
+            .map(|m| (m.scroll_left().into(), m.scroll_top().into())),
+        ListenerTarget::Window | ListenerTarget::Document => {
+            let window = gloo::utils::window();
+            Some((
+                window.scroll_x().unwrap_or_default(),
+                window.scroll_y().unwrap_or_default(),
+            ))
+        }
+    }
+}
+
+fn update_scroll(target: &ListenerTarget, state: &UseStateHandle<ScrollState>) {
+    if let Some((x, y)) = scroll_position(target) {
+        state.set(state.scrolled_to(x, y));
+    }
+}
+
+#[hook]
+fn use_scroll_base(target: ListenerTarget) -> ScrollState {
+    let state = use_state_eq(ScrollState::default);
+    // The pending frame and whether it has not run yet. A frame that has run is only dropped when
+    // the next one is requested, as it must not be dropped from its own callback.
+    let frame = use_mut_ref(|| (false, None::<AnimationFrame>));
+
+    {
+        let state = state.clone();
+        use_effect_with(target.clone(), move |target| update_scroll(target, &state));
+    }
+
+    {
+        let state = state.clone();
+        let listener_target = target.clone();
+        use_event_base(listener_target, "scroll", move |_: Event| {
+            if frame.borrow().0 {
+                return;
+            }
+
+            let target = target.clone();
+            let state = state.clone();
+            let pending = frame.clone();
+            let next = request_animation_frame(move |_| {
+                pending.borrow_mut().0 = false;
+                update_scroll(&target, &state);
+            });
+            *frame.borrow_mut() = (true, Some(next));
+        });
+    }
+
+    *state
+}
+
+/// This hook is used to track the scroll position of the element referenced by `node`.
+///
+/// The position is read once the component has been rendered, then at most once per animation
+/// frame while the element is scrolled. The component is only re-rendered when the position
+/// changes. On the server, the position is always the origin.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Feed() -> Html {
+///     let node = use_node_ref();
+///     let scroll = use_scroll(node.clone());
+///
+///     html! {
+///         <div ref={node} style="overflow: auto; height: 300px;">
+///             if scroll.y > 0.0 {
+///                 <button class="back-to-top">{ "Back to top" }</button>
+///             }
+///             <ul>{ for (0..100).map(|i| html! { <li>{ i }</li> }) }</ul>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_scroll(node: NodeRef) -> ScrollState {
+    use_scroll_base(ListenerTarget::Node(node))
+}
+
+/// [`use_scroll`] for the window, for example to hide a header while scrolling down.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Header() -> Html {
+///     let scroll = use_window_scroll();
+///     let hidden = scroll.y > 64.0 && scroll.direction == Some(ScrollDirection::Down);
+///
+///     html! {
+///         <header class={if hidden { "header hidden" } else { "header" }}>{ "Yew" }</header>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_window_scroll() -> ScrollState {
+    use_scroll_base(ListenerTarget::Window)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn direction_follows_the_main_axis() {
+        let state = ScrollState::default().scrolled_to(0.0, 120.0);
+        assert_eq!(state.direction, Some(ScrollDirection::Down));
+
+        let state = state.scrolled_to(40.0, 110.0);
+        assert_eq!(state.direction, Some(ScrollDirection::Right));
+
+        let state = state.scrolled_to(40.0, 110.0);
+        assert_eq!(state.direction, Some(ScrollDirection::Right));
+
+        let state = state.scrolled_to(35.0, 20.0);
+        assert_eq!(state.direction, Some(ScrollDirection::Up));
+        assert_eq!((state.x, state.y), (35.0, 20.0));
+    }
+}
@@ -0,0 +1,105 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use gloo::timers::callback::Timeout;
+
+use super::use_debounce::timeout_millis;
+use super::{use_callback, use_effect_with, use_mut_ref};
+use crate::callback::Callback;
+use crate::functional::hook;
+
+struct Throttle<IN> {
+    callback: Callback<IN>,
+    timeout: Option<Timeout>,
+    pending: Option<IN>,
+}
+
+/// Blocks emissions for `millis`, then emits the last input received meanwhile, if any.
+fn start_timeout<IN: 'static>(throttle: Rc<RefCell<Throttle<IN>>>, millis: u32) {
+    let timeout = {
+        let throttle = throttle.clone();
+        Timeout::new(millis, move || {
+            // This timeout is running, dropping it would free the closure being executed.
+            if let Some(m) = throttle.borrow_mut().timeout.take() {
+                m.forget();
+            }
+
+            let pending = throttle.borrow_mut().pending.take();
+            if let Some(input) = pending {
+                let callback = throttle.borrow().callback.clone();
+                start_timeout(throttle, millis);
+                callback.emit(input);
+            }
+        })
+    };
+
+    throttle.borrow_mut().timeout = Some(timeout);
+}
+
+/// This hook is used to limit how often a callback is emitted.
+///
+/// The returned callback emits `callback` immediately, then at most once per `interval`. Inputs
+/// received while the callback is throttled are not lost: the last of them is emitted at the end
+/// of the interval. Pending emissions are cancelled when the component is unmounted.
+///
+/// The returned callback only changes when `interval` changes, and always emits the `callback`
+/// passed in the latest render.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Tracker() -> Html {
+///     let position = use_state(|| (0, 0));
+///     let onmousemove = {
+///         let position = position.clone();
+///         use_throttle_callback(
+///             Callback::from(move |e: MouseEvent| position.set((e.client_x(), e.client_y()))),
+///             Duration::from_millis(100),
+///         )
+///     };
+///
+///     html! {
+///         <div {onmousemove}>{ format!("{:?}", *position) }</div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_throttle_callback<IN>(callback: Callback<IN>, interval: Duration) -> Callback<IN>
+where
+    IN: 'static,
+{
+    let throttle = use_mut_ref(|| Throttle {
+        callback: callback.clone(),
+        timeout: None,
+        pending: None,
+    });
+    throttle.borrow_mut().callback = callback;
+
+    {
+        let throttle = throttle.clone();
+        use_effect_with((), move |_| {
+            move || {
+                let mut throttle = throttle.borrow_mut();
+                throttle.timeout = None;
+                throttle.pending = None;
+            }
+        });
+    }
+
+    use_callback(interval, move |input: IN, interval| {
+        if throttle.borrow().timeout.is_some() {
+            throttle.borrow_mut().pending = Some(input);
+            return;
+        }
+
+        let callback = throttle.borrow().callback.clone();
+        start_timeout(throttle.clone(), timeout_millis(*interval));
+        callback.emit(input);
+    })
+}
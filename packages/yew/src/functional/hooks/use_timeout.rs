@@ -0,0 +1,57 @@
+use std::rc::Rc;
+use std::time::Duration;
+
+use gloo::timers::callback::Timeout;
+
+use super::use_debounce::timeout_millis;
+use super::{use_effect_with, use_mut_ref};
+use crate::functional::hook;
+
+/// This hook is used to call a function once after a delay.
+///
+/// `callback` is called `timeout` after the component has been rendered. The timer is restarted
+/// when `timeout` changes and cancelled when the component is unmounted. The `callback` passed in
+/// the latest render is the one being called.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Toast() -> Html {
+///     let visible = use_state(|| true);
+///     {
+///         let visible = visible.clone();
+///         use_timeout(move || visible.set(false), Duration::from_secs(3));
+///     }
+///
+///     html! {
+///         if *visible {
+///             <div class="toast">{ "Saved" }</div>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_timeout<F>(callback: F, timeout: Duration)
+where
+    F: Fn() + 'static,
+{
+    let latest = use_mut_ref(|| None::<Rc<dyn Fn()>>);
+    *latest.borrow_mut() = Some(Rc::new(callback));
+
+    use_effect_with(timeout, move |timeout| {
+        let timeout = Timeout::new(timeout_millis(*timeout), move || {
+            // The callback may cause a re-render, so it must not be borrowed while called.
+            let callback = latest.borrow().clone();
+            if let Some(callback) = callback {
+                callback();
+            }
+        });
+
+        move || drop(timeout)
+    });
+}
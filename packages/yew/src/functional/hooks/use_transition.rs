@@ -0,0 +1,207 @@
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+
+use super::{use_effect_with, use_memo, use_state_eq, UseStateSetter};
+use crate::functional::hook;
+use crate::scheduler::{self, Runnable};
+
+/// The state shared by a [`use_transition`] hook and its scheduled transitions.
+struct Transitions {
+    /// Incremented by every started transition, so that older ones are skipped.
+    generation: Cell<u64>,
+    pending: UseStateSetter<bool>,
+}
+
+struct TransitionRunner {
+    transitions: Rc<Transitions>,
+    generation: u64,
+    update: Box<dyn FnOnce()>,
+}
+
+impl Runnable for TransitionRunner {
+    fn run(self: Box<Self>) {
+        // A newer transition supersedes this one.
+        if self.transitions.generation.get() != self.generation {
+            return;
+        }
+
+        (self.update)();
+        self.transitions.pending.set(false);
+    }
+}
+
+/// State handle for the [`use_transition`] hook.
+#[derive(Clone)]
+pub struct UseTransitionHandle {
+    pending: bool,
+    transitions: Rc<Transitions>,
+}
+
+impl UseTransitionHandle {
+    /// Returns `true` while a transition is waiting to be run.
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+
+    /// Runs `update` as a low priority transition.
+    ///
+    /// The component re-renders with [`is_pending`](Self::is_pending) set right away, while
+    /// `update` only runs once the browser has handled pending events, such as further input. The
+    /// re-renders it causes are run in short slices, and urgent updates are run first. If another
+    /// transition is started in the meantime, `update` is skipped.
+    pub fn start<F>(&self, update: F)
+    where
+        F: FnOnce() + 'static,
+    {
+        let generation = self.transitions.generation.get() + 1;
+        self.transitions.generation.set(generation);
+        self.transitions.pending.set(true);
+
+        scheduler::push_transition(Box::new(TransitionRunner {
+            transitions: self.transitions.clone(),
+            generation,
+            update: Box::new(update),
+        }));
+    }
+}
+
+impl fmt::Debug for UseTransitionHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseTransitionHandle")
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+/// This hook is used to mark state updates as low priority.
+///
+/// Updates started with [`UseTransitionHandle::start`] are deferred until the browser has handled
+/// pending events. The re-renders they cause are run in a lower priority lane, after urgent work
+/// and split into short slices that yield back to the browser, so that expensive renders of a
+/// transition do not delay the response to user input. When a new transition is started before
+/// the previous one has run, only the latest one is run.
+///
+/// The update of a transition is applied to the state as soon as it runs, so a transition can't be
+/// interrupted once it has started. If the component re-renders for an urgent update before the
+/// re-render of the transition was run, it renders the state of the transition as well.
+/// Transitions that have not run yet are cancelled when the component is unmounted.
+///
+/// # Example
+///
+/// ```rust
+/// use web_sys::HtmlInputElement;
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct ResultsProps {
+///     query: AttrValue,
+/// }
+///
+/// #[function_component]
+/// fn Results(props: &ResultsProps) -> Html {
+///     // Expensive to render.
+///     html! { <ul>{ format!("Results for {}", props.query) }</ul> }
+/// }
+///
+/// #[function_component]
+/// fn Search() -> Html {
+///     let input = use_state(AttrValue::default);
+///     let query = use_state(AttrValue::default);
+///     let transition = use_transition();
+///
+///     let oninput = {
+///         let input = input.clone();
+///         let query = query.setter();
+///         let transition = transition.clone();
+///         Callback::from(move |e: InputEvent| {
+///             let value: AttrValue = e.target_unchecked_into::<HtmlInputElement>().value().into();
+///             input.set(value.clone());
+///
+///             let query = query.clone();
+///             transition.start(move || query.set(value));
+///         })
+///     };
+///
+///     html! {
+///         <>
+///             <input value={(*input).clone()} {oninput} />
+///             if transition.is_pending() {
+///                 <p>{ "Updating..." }</p>
+///             }
+///             <Results query={(*query).clone()} />
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_transition() -> UseTransitionHandle {
+    let pending = use_state_eq(|| false);
+    let transitions = {
+        let pending = pending.setter();
+        use_memo((), move |_| Transitions {
+            generation: Cell::new(0),
+            pending,
+        })
+    };
+
+    {
+        let transitions = transitions.clone();
+        use_effect_with((), move |_| {
+            move || transitions.generation.set(transitions.generation.get() + 1)
+        });
+    }
+
+    UseTransitionHandle {
+        pending: *pending,
+        transitions,
+    }
+}
+
+/// This hook is used to defer updates of a value to a low priority transition.
+///
+/// Returns the previous value while urgent work, such as handling further input, is pending, then
+/// re-renders with `value` in a transition, see [`use_transition`]. This keeps a component
+/// responsive when part of it is expensive to render with a new value.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct ListProps {
+///     filter: AttrValue,
+/// }
+///
+/// #[function_component]
+/// fn FilteredList(props: &ListProps) -> Html {
+///     let filter = use_deferred_value(props.filter.clone());
+///     let items = (0..10_000)
+///         .map(|i| i.to_string())
+///         .filter(|item| item.contains(filter.as_str()));
+///
+///     html! { <ul>{ for items.map(|item| html! { <li>{ item }</li> }) }</ul> }
+/// }
+/// ```
+#[hook]
+pub fn use_deferred_value<T>(value: T) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    let deferred = use_state_eq(|| value.clone());
+    let transition = use_transition();
+
+    let stale = *deferred != value;
+    {
+        let deferred = deferred.setter();
+        use_effect_with(value, move |value| {
+            if stale {
+                let value = value.clone();
+                transition.start(move || deferred.set(value));
+            }
+        });
+    }
+
+    (*deferred).clone()
+}
@@ -7,7 +7,7 @@ use base64ct::{Base64, Encoding};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
-use crate::functional::{Hook, HookContext, PreparedState};
+use crate::functional::{encode_state, Hook, HookContext, PreparedState};
 use crate::suspense::SuspensionResult;
 
 pub(super) struct TransitiveStateBase<T, D, F>
@@ -30,8 +30,8 @@ where
         let f = self.state_fn.borrow_mut().take().unwrap();
         let state = f(self.deps.clone());
 
-        let state = bincode::serialize(&(Some(&state), Some(&*self.deps)))
-            .expect("failed to prepare state");
+        let state =
+            encode_state(&(Some(&state), Some(&*self.deps))).expect("failed to prepare state");
 
         Base64::encode_string(&state)
     }
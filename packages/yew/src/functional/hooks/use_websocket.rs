@@ -0,0 +1,328 @@
+use std::rc::Rc;
+use std::time::Duration;
+
+use futures::channel::{mpsc, oneshot};
+use futures::future::{self, Either};
+use futures::{stream, SinkExt, StreamExt};
+use gloo::net::websocket::futures::WebSocket;
+use gloo::net::websocket::Message;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::{use_effect_with, use_mut_ref, use_state, use_state_eq, UseStateSetter};
+use crate::callback::Callback;
+use crate::functional::hook;
+use crate::platform::spawn_local;
+use crate::platform::time::sleep;
+use crate::virtual_dom::AttrValue;
+
+/// The state of the connection of [`use_websocket`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebSocketStatus {
+    /// The socket is being opened.
+    Connecting,
+    /// The socket is open and messages can be sent.
+    Open,
+    /// The socket was closed and will not be reopened.
+    Closed,
+    /// The socket was closed and will be reopened after a delay.
+    Reconnecting,
+}
+
+/// Options for [`use_websocket_with_options`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UseWebSocketOptions {
+    /// Whether the socket is reopened after it was closed or failed to open.
+    pub reconnect: bool,
+    /// The number of consecutive reconnection attempts after which the hook gives up. Unlimited if
+    /// `None`.
+    pub max_retries: Option<u32>,
+    /// The delay before the first reconnection attempt. The delay doubles with every failed
+    /// attempt.
+    pub initial_backoff: Duration,
+    /// The maximum delay between reconnection attempts.
+    pub max_backoff: Duration,
+}
+
+impl Default for UseWebSocketOptions {
+    fn default() -> Self {
+        Self {
+            reconnect: true,
+            max_retries: None,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+impl UseWebSocketOptions {
+    /// The delay before the given reconnection attempt, starting at 0.
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .checked_mul(2u32.saturating_pow(attempt))
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+}
+
+/// State handle for the [`use_websocket`] hook.
+pub struct UseWebSocketHandle<In, Out> {
+    status: WebSocketStatus,
+    message: Option<Rc<In>>,
+    send: Callback<Out>,
+}
+
+impl<In, Out> UseWebSocketHandle<In, Out> {
+    /// Returns the state of the connection.
+    pub fn status(&self) -> WebSocketStatus {
+        self.status
+    }
+
+    /// Returns the last message received.
+    pub fn message(&self) -> Option<&In> {
+        self.message.as_deref()
+    }
+
+    /// Returns a callback sending a message.
+    ///
+    /// Messages sent while the socket is not open are dropped.
+    pub fn send(&self) -> Callback<Out> {
+        self.send.clone()
+    }
+}
+
+impl<In, Out> Clone for UseWebSocketHandle<In, Out> {
+    fn clone(&self) -> Self {
+        Self {
+            status: self.status,
+            message: self.message.clone(),
+            send: self.send.clone(),
+        }
+    }
+}
+
+impl<In: std::fmt::Debug, Out> std::fmt::Debug for UseWebSocketHandle<In, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UseWebSocketHandle")
+            .field("status", &self.status)
+            .field("message", &self.message)
+            .finish()
+    }
+}
+
+/// This hook is used to communicate with a server through a WebSocket.
+///
+/// Messages are encoded as JSON. Incoming messages that cannot be decoded as `In` are skipped.
+/// The socket is opened once the component is rendered, reopened with exponential backoff when it
+/// is closed, and closed when the component is unmounted or the url changes.
+///
+/// See [`use_websocket_with_options`] to configure reconnection.
+///
+/// # Example
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use yew::prelude::*;
+///
+/// #[derive(Serialize)]
+/// struct Ping {
+///     id: u32,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Pong {
+///     id: u32,
+/// }
+///
+/// #[function_component]
+/// fn Connection() -> Html {
+///     let socket = use_websocket::<Pong, Ping>("wss://example.com/ws".into());
+///     let onclick = socket.send().reform(|_| Ping { id: 1 });
+///
+///     html! {
+///         <div>
+///             <p>{ format!("{:?}", socket.status()) }</p>
+///             <button {onclick}>{ "Ping" }</button>
+///             if let Some(pong) = socket.message() {
+///                 <p>{ pong.id }</p>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_websocket<In, Out>(url: AttrValue) -> UseWebSocketHandle<In, Out>
+where
+    In: DeserializeOwned + 'static,
+    Out: Serialize + 'static,
+{
+    use_websocket_with_options(url, UseWebSocketOptions::default())
+}
+
+/// [`use_websocket`] with custom reconnection options.
+#[hook]
+pub fn use_websocket_with_options<In, Out>(
+    url: AttrValue,
+    options: UseWebSocketOptions,
+) -> UseWebSocketHandle<In, Out>
+where
+    In: DeserializeOwned + 'static,
+    Out: Serialize + 'static,
+{
+    let status = use_state_eq(|| WebSocketStatus::Connecting);
+    let message = use_state(|| None::<Rc<In>>);
+    let sender = use_mut_ref(|| None::<mpsc::UnboundedSender<String>>);
+
+    {
+        let status = status.setter();
+        let message = message.setter();
+        let sender = sender.clone();
+
+        use_effect_with((url, options), move |(url, options)| {
+            let (cancel_tx, cancel_rx) = oneshot::channel::<()>();
+            spawn_local(connect(
+                url.clone(),
+                options.clone(),
+                status,
+                message,
+                sender,
+                cancel_rx,
+            ));
+
+            move || drop(cancel_tx)
+        });
+    }
+
+    let send = Callback::from(move |out: Out| {
+        let encoded = match serde_json::to_string(&out) {
+            Ok(m) => m,
+            Err(e) => {
+                tracing::warn!(%e, "failed to encode websocket message");
+                return;
+            }
+        };
+
+        match sender.borrow().as_ref() {
+            Some(sender) if sender.unbounded_send(encoded).is_ok() => {}
+            _ => tracing::warn!("websocket is not open, message dropped"),
+        }
+    });
+
+    UseWebSocketHandle {
+        status: *status,
+        message: (*message).clone(),
+        send,
+    }
+}
+
+enum Event {
+    Received(Message),
+    Send(String),
+    Disconnected,
+}
+
+/// Keeps a socket open until `cancel` resolves, which happens when its sender is dropped.
+async fn connect<In>(
+    url: AttrValue,
+    options: UseWebSocketOptions,
+    status: UseStateSetter<WebSocketStatus>,
+    message: UseStateSetter<Option<Rc<In>>>,
+    sender: Rc<std::cell::RefCell<Option<mpsc::UnboundedSender<String>>>>,
+    mut cancel: oneshot::Receiver<()>,
+) where
+    In: DeserializeOwned + 'static,
+{
+    let mut attempt = 0;
+
+    loop {
+        status.set(WebSocketStatus::Connecting);
+
+        match WebSocket::open(&url) {
+            Ok(socket) => {
+                let (mut write, read) = socket.split();
+                let (tx, rx) = mpsc::unbounded();
+                *sender.borrow_mut() = Some(tx);
+                status.set(WebSocketStatus::Open);
+
+                let incoming = read
+                    .map(|m| match m {
+                        Ok(m) => Event::Received(m),
+                        Err(e) => {
+                            tracing::warn!(%e, "websocket error");
+                            Event::Disconnected
+                        }
+                    })
+                    .chain(stream::once(future::ready(Event::Disconnected)));
+                let mut events = stream::select(incoming, rx.map(Event::Send));
+
+                loop {
+                    let event = match future::select(events.next(), &mut cancel).await {
+                        Either::Left((Some(event), _)) => event,
+                        Either::Left((None, _)) => Event::Disconnected,
+                        // Dropping the socket closes it.
+                        Either::Right(_) => return,
+                    };
+
+                    match event {
+                        Event::Received(m) => {
+                            let decoded = match m {
+                                Message::Text(m) => serde_json::from_str(&m),
+                                Message::Bytes(m) => serde_json::from_slice(&m),
+                            };
+                            match decoded {
+                                Ok(m) => {
+                                    attempt = 0;
+                                    message.set(Some(Rc::new(m)));
+                                }
+                                Err(e) => tracing::warn!(%e, "failed to decode websocket message"),
+                            }
+                        }
+                        Event::Send(m) => {
+                            if write.send(Message::Text(m)).await.is_err() {
+                                break;
+                            }
+                        }
+                        Event::Disconnected => break,
+                    }
+                }
+
+                sender.borrow_mut().take();
+            }
+            Err(e) => tracing::warn!(%e, %url, "failed to open websocket"),
+        }
+
+        if !options.reconnect || options.max_retries.is_some_and(|m| attempt >= m) {
+            status.set(WebSocketStatus::Closed);
+            return;
+        }
+
+        status.set(WebSocketStatus::Reconnecting);
+        let delay = sleep(options.backoff(attempt));
+        attempt += 1;
+
+        futures::pin_mut!(delay);
+        if let Either::Right(_) = future::select(delay, &mut cancel).await {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_maximum() {
+        let options = UseWebSocketOptions {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+            ..Default::default()
+        };
+
+        assert_eq!(options.backoff(0), Duration::from_millis(100));
+        assert_eq!(options.backoff(1), Duration::from_millis(200));
+        assert_eq!(options.backoff(3), Duration::from_millis(800));
+        assert_eq!(options.backoff(4), Duration::from_secs(1));
+        assert_eq!(options.backoff(40), Duration::from_secs(1));
+    }
+}
@@ -21,19 +21,23 @@
 //! More details about function components and Hooks can be found on [Yew Docs](https://yew.rs/docs/next/concepts/function-components/introduction)
 
 use std::any::Any;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::fmt;
 use std::rc::Rc;
 
 use wasm_bindgen::prelude::*;
 
-#[cfg(all(feature = "hydration", feature = "ssr"))]
+#[cfg(feature = "hydration")]
 use crate::html::RenderMode;
 use crate::html::{AnyScope, BaseComponent, Context, HtmlResult};
 use crate::Properties;
 
 mod hooks;
 pub use hooks::*;
+#[cfg(any(feature = "hydration", feature = "ssr", feature = "worker"))]
+mod state_codec;
+#[cfg(any(feature = "hydration", feature = "ssr", feature = "worker"))]
+pub use state_codec::*;
 /// This attribute creates a function component from a normal Rust function.
 ///
 /// Functions with this attribute **must** return `Html` and can optionally take an argument
@@ -81,12 +85,14 @@ pub(crate) trait Effect {
 /// A hook context to be passed to hooks.
 pub struct HookContext {
     pub(crate) scope: AnyScope,
-    #[cfg(all(feature = "hydration", feature = "ssr"))]
-    creation_mode: RenderMode,
+    #[cfg(feature = "hydration")]
+    pub(crate) creation_mode: RenderMode,
     re_render: ReRender,
 
     states: Vec<Rc<dyn Any>>,
     effects: Vec<Rc<dyn Effect>>,
+    /// Whether the component has been rendered and not destroyed since.
+    pub(crate) mounted: Rc<Cell<bool>>,
 
     #[cfg(any(feature = "hydration", feature = "ssr"))]
     prepared_states: Vec<Rc<dyn PreparedState>>,
@@ -99,20 +105,28 @@ pub struct HookContext {
     counter: usize,
     #[cfg(debug_assertions)]
     total_hook_counter: Option<usize>,
+    /// Whether the component is rendered in strict mode.
+    #[cfg(debug_assertions)]
+    strict: bool,
+    /// Whether the render running is the first one of strict mode, whose result is discarded.
+    #[cfg(debug_assertions)]
+    strict_pass: bool,
 }
 
 impl HookContext {
     fn new(
         scope: AnyScope,
         re_render: ReRender,
-        #[cfg(all(feature = "hydration", feature = "ssr"))] creation_mode: RenderMode,
+        #[cfg(feature = "hydration")] creation_mode: RenderMode,
         #[cfg(feature = "hydration")] prepared_state: Option<&str>,
     ) -> RefCell<Self> {
         RefCell::new(HookContext {
+            #[cfg(debug_assertions)]
+            strict: scope.strict,
             scope,
             re_render,
 
-            #[cfg(all(feature = "hydration", feature = "ssr"))]
+            #[cfg(feature = "hydration")]
             creation_mode,
 
             states: Vec::new(),
@@ -120,6 +134,7 @@ impl HookContext {
             #[cfg(any(feature = "hydration", feature = "ssr"))]
             prepared_states: Vec::new(),
             effects: Vec::new(),
+            mounted: Rc::default(),
 
             #[cfg(feature = "hydration")]
             prepared_states_data: {
@@ -134,9 +149,17 @@ impl HookContext {
             counter: 0,
             #[cfg(debug_assertions)]
             total_hook_counter: None,
+            #[cfg(debug_assertions)]
+            strict_pass: false,
         })
     }
 
+    /// Returns `true` during the first render of strict mode, whose result is discarded.
+    #[cfg(debug_assertions)]
+    pub(crate) fn is_strict_pass(&self) -> bool {
+        self.strict_pass
+    }
+
     pub(crate) fn next_state<T>(&mut self, initializer: impl FnOnce(ReRender) -> T) -> Rc<T>
     where
         T: 'static,
@@ -247,12 +270,16 @@ impl HookContext {
     }
 
     fn run_effects(&self) {
+        self.mounted.set(true);
+
         for effect in self.effects.iter() {
             effect.rendered();
         }
     }
 
     fn drain_states(&mut self) {
+        self.mounted.set(false);
+
         // We clear the effects as these are also references to states.
         self.effects.clear();
 
@@ -341,7 +368,7 @@ where
             hook_ctx: HookContext::new(
                 scope,
                 re_render,
-                #[cfg(all(feature = "hydration", feature = "ssr"))]
+                #[cfg(feature = "hydration")]
                 ctx.creation_mode(),
                 #[cfg(feature = "hydration")]
                 ctx.prepared_state(),
@@ -353,6 +380,16 @@ where
     pub fn render(&self, props: &T::Properties) -> HtmlResult {
         let mut hook_ctx = self.hook_ctx.borrow_mut();
 
+        // In strict mode, the component is rendered once more beforehand and the result discarded,
+        // so that side effects of rendering show up twice.
+        #[cfg(debug_assertions)]
+        if hook_ctx.strict {
+            hook_ctx.prepare_run();
+            hook_ctx.strict_pass = true;
+            let _ = T::run(&mut hook_ctx, props);
+            hook_ctx.strict_pass = false;
+        }
+
         hook_ctx.prepare_run();
 
         #[allow(clippy::let_and_return)]
@@ -0,0 +1,260 @@
+//! This module contains the codec of the states sent from the server to the client, like the
+//! prepared states of components, and of the messages of agents.
+
+use std::error::Error;
+use std::sync::OnceLock;
+
+use erased_serde::{Deserializer, Error as DeError};
+
+#[doc(no_inline)]
+pub use erased_serde;
+
+/// An error of a [`StateCodec`].
+pub type StateCodecError = Box<dyn Error + Send + Sync>;
+
+/// Receives a deserializer of the encoded state, see [`StateCodec::decode`].
+pub type DecodeVisitor<'a> =
+    dyn for<'de> FnMut(&mut dyn Deserializer<'de>) -> Result<(), DeError> + 'a;
+
+/// The format of the states sent from the server to the client, like the states of
+/// [`use_prepared_state`](crate::functional::use_prepared_state) and
+/// [`use_transitive_state`](crate::functional::use_transitive_state), and the properties of
+/// [islands](crate::islands). It also encodes the messages of the agents run in web workers.
+///
+/// The codec is set for the whole application with [`set_state_codec`], and must be the same on
+/// the server and the client. It defaults to [`Bincode`]. A codec can also transform the encoded
+/// bytes, for example to compress them.
+///
+/// # Example
+///
+/// ```
+/// use yew::functional::{erased_serde, Bincode, DecodeVisitor, StateCodec, StateCodecError};
+///
+/// /// Bincode, with the bytes reversed.
+/// struct Reversed;
+///
+/// impl StateCodec for Reversed {
+///     fn encode(&self, state: &dyn erased_serde::Serialize) -> Result<Vec<u8>, StateCodecError> {
+///         let mut bytes = Bincode.encode(state)?;
+///         bytes.reverse();
+///         Ok(bytes)
+///     }
+///
+///     fn decode(
+///         &self,
+///         bytes: &[u8],
+///         visit: &mut DecodeVisitor<'_>,
+///     ) -> Result<(), StateCodecError> {
+///         let bytes: Vec<u8> = bytes.iter().rev().copied().collect();
+///         Bincode.decode(&bytes, visit)
+///     }
+/// }
+/// ```
+pub trait StateCodec: Send + Sync + 'static {
+    /// Encodes a state.
+    fn encode(&self, state: &dyn erased_serde::Serialize) -> Result<Vec<u8>, StateCodecError>;
+
+    /// Decodes a state, by passing a deserializer of `bytes` to `visit`.
+    fn decode(&self, bytes: &[u8], visit: &mut DecodeVisitor<'_>) -> Result<(), StateCodecError>;
+}
+
+/// Encodes states with [bincode](https://docs.rs/bincode/1), the default codec.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Bincode;
+
+impl StateCodec for Bincode {
+    fn encode(&self, state: &dyn erased_serde::Serialize) -> Result<Vec<u8>, StateCodecError> {
+        Ok(bincode::serialize(state)?)
+    }
+
+    fn decode(&self, bytes: &[u8], visit: &mut DecodeVisitor<'_>) -> Result<(), StateCodecError> {
+        use bincode::Options;
+
+        // The options of `bincode::serialize`.
+        let options = bincode::options()
+            .with_fixint_encoding()
+            .allow_trailing_bytes();
+        let mut de = bincode::Deserializer::from_slice(bytes, options);
+        let mut erased = <dyn Deserializer<'_>>::erase(&mut de);
+        visit(&mut erased)?;
+        Ok(())
+    }
+}
+
+/// Encodes states as JSON, which is larger than [`Bincode`] but readable in the page source.
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json;
+
+#[cfg(feature = "json")]
+impl StateCodec for Json {
+    fn encode(&self, state: &dyn erased_serde::Serialize) -> Result<Vec<u8>, StateCodecError> {
+        Ok(serde_json::to_vec(state)?)
+    }
+
+    fn decode(&self, bytes: &[u8], visit: &mut DecodeVisitor<'_>) -> Result<(), StateCodecError> {
+        let mut de = serde_json::Deserializer::from_slice(bytes);
+        {
+            let mut erased = <dyn Deserializer<'_>>::erase(&mut de);
+            visit(&mut erased)?;
+        }
+        Ok(de.end()?)
+    }
+}
+
+static STATE_CODEC: OnceLock<Box<dyn StateCodec>> = OnceLock::new();
+
+/// Sets the codec of the states sent from the server to the client.
+///
+/// It must be set before the first render, on both the server and the client. Only the first
+/// codec set is used.
+pub fn set_state_codec(codec: impl StateCodec) {
+    let _ = STATE_CODEC.set(Box::new(codec));
+}
+
+fn state_codec() -> &'static dyn StateCodec {
+    STATE_CODEC.get_or_init(|| Box::new(Bincode)).as_ref()
+}
+
+#[cfg(any(feature = "ssr", feature = "worker"))]
+pub(crate) fn encode_state<T: serde::Serialize>(state: &T) -> Result<Vec<u8>, StateCodecError> {
+    state_codec().encode(state)
+}
+
+#[cfg(any(feature = "hydration", feature = "worker"))]
+pub(crate) fn decode_state<T: serde::de::DeserializeOwned>(
+    bytes: &[u8],
+) -> Result<T, StateCodecError> {
+    let mut state = None;
+    state_codec().decode(bytes, &mut |de| {
+        state = Some(erased_serde::deserialize::<T>(de)?);
+        Ok(())
+    })?;
+    state.ok_or_else(|| "the codec did not decode the state".into())
+}
+
+#[cfg(feature = "ssr")]
+mod feat_ssr {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Debug, Default)]
+    struct Inner {
+        payloads: AtomicUsize,
+        bytes: AtomicUsize,
+        largest: AtomicUsize,
+    }
+
+    /// The sizes of the states written by the renderers it is given to.
+    ///
+    /// It is cheap to clone, and all the clones share the same counters.
+    ///
+    /// ```
+    /// # use yew::prelude::*;
+    /// # #[function_component]
+    /// # fn App() -> Html { Html::default() }
+    /// use yew::functional::StateMetrics;
+    /// use yew::ServerRenderer;
+    ///
+    /// # async fn run() {
+    /// let metrics = StateMetrics::default();
+    /// let html = ServerRenderer::<App>::new()
+    ///     .state_metrics(metrics.clone())
+    ///     .render()
+    ///     .await;
+    /// tracing::info!(bytes = metrics.bytes(), "sent prepared states");
+    /// # }
+    /// ```
+    #[derive(Debug, Clone, Default)]
+    pub struct StateMetrics {
+        inner: Arc<Inner>,
+    }
+
+    impl StateMetrics {
+        /// Returns the number of components whose states were written.
+        pub fn payloads(&self) -> usize {
+            self.inner.payloads.load(Ordering::Relaxed)
+        }
+
+        /// Returns the number of bytes of states written, as sent in the document.
+        pub fn bytes(&self) -> usize {
+            self.inner.bytes.load(Ordering::Relaxed)
+        }
+
+        /// Returns the number of bytes of the largest states of a component.
+        pub fn largest(&self) -> usize {
+            self.inner.largest.load(Ordering::Relaxed)
+        }
+
+        pub(crate) fn record(&self, bytes: usize) {
+            self.inner.payloads.fetch_add(1, Ordering::Relaxed);
+            self.inner.bytes.fetch_add(bytes, Ordering::Relaxed);
+            self.inner.largest.fetch_max(bytes, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+pub use feat_ssr::*;
+
+#[cfg(all(test, feature = "ssr", feature = "hydration"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bincode_round_trip() {
+        let state = (Some(String::from("state")), Some(42u32));
+        let bytes = encode_state(&state).unwrap();
+        assert_eq!(bytes, bincode::serialize(&state).unwrap());
+        assert_eq!(
+            decode_state::<(Option<String>, Option<u32>)>(&bytes).unwrap(),
+            state
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_round_trip() {
+        let state = (Some(String::from("state")), Some(42u32));
+        let bytes = Json.encode(&state).unwrap();
+        assert_eq!(bytes, br#"["state",42]"#);
+
+        let mut decoded = None;
+        Json.decode(&bytes, &mut |de| {
+            decoded = Some(erased_serde::deserialize::<(Option<String>, Option<u32>)>(
+                de,
+            )?);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(decoded, Some(state));
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::LocalServerRenderer;
+
+    #[test]
+    async fn records_state_sizes() {
+        #[function_component]
+        fn Comp() -> HtmlResult {
+            let ctr = use_prepared_state!((), |_| -> u32 { 12345 })?.unwrap_or_default();
+            Ok(html! { <div>{*ctr}</div> })
+        }
+
+        let metrics = StateMetrics::default();
+        LocalServerRenderer::<Comp>::new()
+            .state_metrics(metrics.clone())
+            .render()
+            .await;
+
+        assert_eq!(metrics.payloads(), 1);
+        assert!(metrics.bytes() > 0);
+        assert_eq!(metrics.largest(), metrics.bytes());
+    }
+}
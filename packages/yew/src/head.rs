@@ -0,0 +1,482 @@
+//! This module contains components and hooks to set the title and meta tags of the document.
+//!
+//! [`Title`], [`Meta`] and [`use_title`] can be used anywhere in the component tree. When several
+//! components declare a title, or meta tags with the same name, the one mounted last wins, which is
+//! usually the most deeply nested one. In the browser, declarations are applied to the document
+//! when components are mounted and reverted when they are unmounted.
+//!
+//! During server-side rendering, declarations are collected by a `HeadCollector` provided with
+//! `HeadProvider`, and rendered into the `<head>` of the page once the body has been rendered.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::function_component;
+#[cfg(feature = "ssr")]
+use crate::functional::use_context;
+#[cfg(feature = "csr")]
+use crate::functional::use_effect_with;
+use crate::functional::{hook, use_memo};
+use crate::html::{Html, Properties};
+use crate::virtual_dom::AttrValue;
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The attribute set on meta tags managed by this module.
+#[cfg(any(feature = "csr", feature = "ssr"))]
+const MANAGED_ATTR: &str = "data-yew-head";
+
+/// Returns a new id for a head declaration, ordering the declarations by when they are made.
+pub(crate) fn next_id() -> usize {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum HeadEntry {
+    Title(String),
+    Meta {
+        attr: &'static str,
+        key: String,
+        content: String,
+    },
+    /// A stylesheet, only declared during server-side rendering, like the variables of a
+    /// [`ThemeProvider`](crate::theme::ThemeProvider).
+    #[cfg_attr(not(feature = "ssr"), allow(dead_code))]
+    Style {
+        attr: &'static str,
+        css: String,
+    },
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+impl HeadEntry {
+    fn title(entries: impl DoubleEndedIterator<Item = Self>) -> Option<String> {
+        entries.rev().find_map(|m| match m {
+            Self::Title(m) => Some(m),
+            Self::Meta { .. } | Self::Style { .. } => None,
+        })
+    }
+
+    /// Returns the meta tags to render, keeping only the last one of each name.
+    fn metas(
+        entries: impl DoubleEndedIterator<Item = Self>,
+    ) -> Vec<(&'static str, String, String)> {
+        let mut metas: Vec<(&'static str, String, String)> = Vec::new();
+        for entry in entries.rev() {
+            if let Self::Meta { attr, key, content } = entry {
+                if !metas.iter().any(|m| m.0 == attr && m.1 == key) {
+                    metas.push((attr, key, content));
+                }
+            }
+        }
+        metas.reverse();
+        metas
+    }
+}
+
+/// Declares `entry` for as long as the component is mounted.
+#[hook]
+fn use_head_entry(entry: Option<HeadEntry>) {
+    let id = *use_memo((), |_| next_id());
+
+    #[cfg(feature = "ssr")]
+    let collector = use_context::<HeadCollector>();
+    #[cfg(feature = "ssr")]
+    if let (Some(collector), Some(entry)) = (collector, entry.clone()) {
+        collector.insert(id, entry);
+    }
+
+    #[cfg(feature = "csr")]
+    {
+        use_effect_with(entry, move |entry| {
+            if let Some(entry) = entry.clone() {
+                feat_csr::insert(id, entry);
+            }
+            move || feat_csr::remove(id)
+        });
+    }
+
+    #[cfg(not(feature = "csr"))]
+    let _ = (id, entry);
+}
+
+/// This hook is used to set the title of the document.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::head::use_title;
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Settings() -> Html {
+///     use_title("Settings".into());
+///
+///     html! { <h1>{ "Settings" }</h1> }
+/// }
+/// ```
+#[hook]
+pub fn use_title(title: AttrValue) {
+    use_head_entry(Some(HeadEntry::Title(title.to_string())));
+}
+
+/// Properties for [`Title`].
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct TitleProps {
+    /// The title of the document.
+    pub text: AttrValue,
+}
+
+/// Sets the title of the document.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::head::Title;
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn About() -> Html {
+///     html! {
+///         <>
+///             <Title text="About us" />
+///             <h1>{ "About us" }</h1>
+///         </>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Title(props: &TitleProps) -> Html {
+    use_title(props.text.clone());
+
+    Html::default()
+}
+
+/// Properties for [`Meta`].
+///
+/// The tag is identified by `name`, `property` or `http_equiv`, in this order of precedence. A tag
+/// without any of them is ignored.
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct MetaProps {
+    /// The `name` attribute, for example `description`.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    /// The `property` attribute, used by Open Graph tags such as `og:title`.
+    #[prop_or_default]
+    pub property: Option<AttrValue>,
+    /// The `http-equiv` attribute.
+    #[prop_or_default]
+    pub http_equiv: Option<AttrValue>,
+    /// The `content` attribute.
+    pub content: AttrValue,
+}
+
+/// Adds a `<meta>` tag to the head of the document.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::head::Meta;
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Article() -> Html {
+///     html! {
+///         <>
+///             <Meta name="description" content="How we built our website with Yew" />
+///             <Meta property="og:type" content="article" />
+///             <article>{ "..." }</article>
+///         </>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Meta(props: &MetaProps) -> Html {
+    let key = [
+        ("name", &props.name),
+        ("property", &props.property),
+        ("http-equiv", &props.http_equiv),
+    ]
+    .into_iter()
+    .find_map(|(attr, key)| key.as_ref().map(|key| (attr, key)));
+
+    use_head_entry(key.map(|(attr, key)| HeadEntry::Meta {
+        attr,
+        key: key.to_string(),
+        content: props.content.to_string(),
+    }));
+
+    Html::default()
+}
+
+#[cfg(feature = "ssr")]
+mod feat_ssr {
+    use std::collections::BTreeMap;
+    use std::fmt::{self, Write};
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::context::ContextProvider;
+    use crate::html;
+
+    /// Collects the head declarations of a component tree during server-side rendering.
+    ///
+    /// The collector is provided to the tree with [`HeadProvider`] and can be sent to the thread
+    /// rendering the application. Once the body has been rendered, [`render`](Self::render)
+    /// returns the tags to insert into the `<head>` of the page.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use yew::head::{HeadCollector, HeadProvider, Title};
+    /// use yew::prelude::*;
+    ///
+    /// #[derive(Properties, PartialEq)]
+    /// struct ServerAppProps {
+    ///     head: HeadCollector,
+    /// }
+    ///
+    /// #[function_component]
+    /// fn ServerApp(props: &ServerAppProps) -> Html {
+    ///     html! {
+    ///         <HeadProvider collector={props.head.clone()}>
+    ///             <Title text="Home" />
+    ///         </HeadProvider>
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let head = HeadCollector::new();
+    /// let body = yew::ServerRenderer::<ServerApp>::with_props({
+    ///     let head = head.clone();
+    ///     move || ServerAppProps { head }
+    /// })
+    /// .render()
+    /// .await;
+    ///
+    /// let page = format!(
+    ///     "<!DOCTYPE html><html><head>{}</head><body>{body}</body></html>",
+    ///     head.render()
+    /// );
+    /// # assert!(page.contains("<title>Home</title>"));
+    /// # }
+    /// ```
+    #[derive(Clone, Default)]
+    pub struct HeadCollector {
+        entries: Arc<Mutex<BTreeMap<usize, HeadEntry>>>,
+    }
+
+    impl fmt::Debug for HeadCollector {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("HeadCollector")
+        }
+    }
+
+    impl PartialEq for HeadCollector {
+        fn eq(&self, other: &Self) -> bool {
+            Arc::ptr_eq(&self.entries, &other.entries)
+        }
+    }
+
+    impl HeadCollector {
+        /// Creates an empty collector.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        pub(crate) fn insert(&self, id: usize, entry: HeadEntry) {
+            self.entries
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(id, entry);
+        }
+
+        fn entries(&self) -> Vec<HeadEntry> {
+            self.entries
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .values()
+                .cloned()
+                .collect()
+        }
+
+        /// Returns the title declared by the component tree.
+        pub fn title(&self) -> Option<String> {
+            HeadEntry::title(self.entries().into_iter())
+        }
+
+        /// Renders the `<title>`, `<meta>` and `<style>` tags declared by the component tree.
+        pub fn render(&self) -> String {
+            let entries = self.entries();
+            let mut s = String::new();
+
+            if let Some(title) = HeadEntry::title(entries.iter().cloned()) {
+                let _ = write!(s, "<title>{}</title>", html_escape::encode_text(&title));
+            }
+
+            for (attr, key, content) in HeadEntry::metas(entries.iter().cloned()) {
+                let _ = write!(
+                    s,
+                    r#"<meta {attr}="{}" content="{}" {MANAGED_ATTR}>"#,
+                    html_escape::encode_double_quoted_attribute(&key),
+                    html_escape::encode_double_quoted_attribute(&content),
+                );
+            }
+
+            for entry in entries {
+                if let HeadEntry::Style { attr, css } = entry {
+                    let _ = write!(s, "<style {attr}>{css}</style>");
+                }
+            }
+
+            s
+        }
+    }
+
+    /// Properties for [`HeadProvider`].
+    #[derive(Debug, Properties, PartialEq)]
+    pub struct HeadProviderProps {
+        /// The collector receiving the head declarations of the children.
+        pub collector: HeadCollector,
+        /// The children of the provider.
+        #[prop_or_default]
+        pub children: Html,
+    }
+
+    /// Provides a [`HeadCollector`] to its children during server-side rendering.
+    #[function_component]
+    pub fn HeadProvider(props: &HeadProviderProps) -> Html {
+        html! {
+            <ContextProvider<HeadCollector> context={props.collector.clone()}>
+                {props.children.clone()}
+            </ContextProvider<HeadCollector>>
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+pub use feat_ssr::*;
+
+#[cfg(feature = "csr")]
+mod feat_csr {
+    use std::cell::RefCell;
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    thread_local! {
+        static ENTRIES: RefCell<BTreeMap<usize, HeadEntry>> = const { RefCell::new(BTreeMap::new()) };
+        /// The title of the document before any title was declared.
+        static DEFAULT_TITLE: RefCell<Option<String>> = const { RefCell::new(None) };
+    }
+
+    pub(super) fn insert(id: usize, entry: HeadEntry) {
+        ENTRIES.with(|m| m.borrow_mut().insert(id, entry));
+        apply();
+    }
+
+    pub(super) fn remove(id: usize) {
+        if ENTRIES.with(|m| m.borrow_mut().remove(&id)).is_some() {
+            apply();
+        }
+    }
+
+    /// Replaces the title and the managed meta tags of the document with the declared ones.
+    fn apply() {
+        let entries: Vec<HeadEntry> = ENTRIES.with(|m| m.borrow().values().cloned().collect());
+        let document = gloo::utils::document();
+
+        let default_title = DEFAULT_TITLE.with(|m| {
+            m.borrow_mut()
+                .get_or_insert_with(|| document.title())
+                .clone()
+        });
+        let title = HeadEntry::title(entries.iter().cloned()).unwrap_or(default_title);
+        if document.title() != title {
+            document.set_title(&title);
+        }
+
+        // This also removes the tags rendered on the server.
+        if let Ok(managed) = document.query_selector_all(&format!("meta[{MANAGED_ATTR}]")) {
+            for i in 0..managed.length() {
+                if let Some(node) = managed.get(i) {
+                    if let Some(parent) = node.parent_node() {
+                        let _ = parent.remove_child(&node);
+                    }
+                }
+            }
+        }
+
+        let Some(head) = document.head() else {
+            return;
+        };
+        for (attr, key, content) in HeadEntry::metas(entries.into_iter()) {
+            let Ok(meta) = document.create_element("meta") else {
+                continue;
+            };
+            let _ = meta.set_attribute(attr, &key);
+            let _ = meta.set_attribute("content", &content);
+            let _ = meta.set_attribute(MANAGED_ATTR, "");
+            let _ = head.append_child(&meta);
+        }
+    }
+}
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::LocalServerRenderer as ServerRenderer;
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn collects_head_declarations() {
+        #[function_component]
+        fn Page() -> Html {
+            use_title("Page".into());
+
+            html! {
+                <>
+                    <Meta name="description" content="A \"quoted\" page" />
+                    <p>{ "content" }</p>
+                </>
+            }
+        }
+
+        #[derive(Properties, PartialEq)]
+        struct AppProps {
+            head: HeadCollector,
+        }
+
+        #[function_component]
+        fn App(props: &AppProps) -> Html {
+            html! {
+                <HeadProvider collector={props.head.clone()}>
+                    <Title text="App & Co" />
+                    <Meta name="description" content="An app" />
+                    <Meta property="og:type" content="website" />
+                    <Page />
+                </HeadProvider>
+            }
+        }
+
+        let head = HeadCollector::new();
+        let body = ServerRenderer::<App>::with_props(AppProps { head: head.clone() })
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(body, "<p>content</p>");
+        assert_eq!(head.title().as_deref(), Some("Page"));
+        assert_eq!(
+            head.render(),
+            "<title>Page</title><meta property=\"og:type\" content=\"website\" \
+             data-yew-head><meta name=\"description\" content=\"A &quot;quoted&quot; page\" \
+             data-yew-head>"
+        );
+    }
+}
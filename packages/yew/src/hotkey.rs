@@ -0,0 +1,418 @@
+//! This module contains hooks to register keyboard shortcuts.
+//!
+//! Shortcuts registered with [`use_hotkey`] are active while the component is mounted, and
+//! shortcuts registered with [`use_scoped_hotkey`] only while the focus is within an element. All
+//! shortcuts are dispatched by a single `keydown` listener on the window, which is removed once no
+//! shortcut is registered.
+//!
+//! When several shortcuts match a key press, only one of them is triggered: scoped shortcuts take
+//! precedence over global ones, the innermost scope takes precedence over outer ones, and among
+//! shortcuts of the same scope the last one registered wins. Registering the same shortcut twice
+//! in the same scope is reported as a warning, as it is usually a mistake.
+//!
+//! # Example
+//!
+//! ```rust
+//! use yew::hotkey::use_hotkey;
+//! use yew::prelude::*;
+//!
+//! #[function_component]
+//! fn CommandPalette() -> Html {
+//!     let open = use_state(|| false);
+//!     {
+//!         let open = open.clone();
+//!         use_hotkey("ctrl+k", move |_| open.set(!*open));
+//!     }
+//!
+//!     html! {
+//!         if *open {
+//!             <div class="palette">{ "Type a command..." }</div>
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use gloo::events::EventListener;
+use thiserror::Error;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, HtmlElement, KeyboardEvent, Node};
+
+use crate::functional::{hook, use_effect_with, use_mut_ref};
+use crate::html::NodeRef;
+
+/// A keyboard shortcut, such as `ctrl+k` or `shift+?`.
+///
+/// Shortcuts are parsed from a list of modifiers followed by a key, separated by `+`. The
+/// modifiers are `ctrl` (or `control`), `alt` (or `option`), `shift` and `meta` (or `cmd`,
+/// `command` and `super`). The key is the value of [`KeyboardEvent.key`], such as `k`, `enter` or
+/// `arrowup`, with `space`, `esc` and `plus` as aliases of ` `, `escape` and `+`. Parsing is case
+/// insensitive.
+///
+/// [`KeyboardEvent.key`]: https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Hotkey {
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+    meta: bool,
+    key: String,
+}
+
+/// An error returned when parsing an invalid [`Hotkey`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum HotkeyParseError {
+    /// The shortcut has no key.
+    #[error("hotkey has no key")]
+    MissingKey,
+    /// A modifier is not known.
+    #[error("unknown hotkey modifier `{0}`")]
+    UnknownModifier(String),
+    /// A modifier is listed more than once.
+    #[error("hotkey modifier `{0}` is repeated")]
+    RepeatedModifier(String),
+}
+
+impl FromStr for Hotkey {
+    type Err = HotkeyParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        // A trailing `+` is the key itself, as in `ctrl++`.
+        let (modifiers, key) = match s.strip_suffix("++") {
+            Some(modifiers) => (modifiers, "+"),
+            None => s.rsplit_once('+').unwrap_or(("", &s)),
+        };
+
+        let key = match key.trim() {
+            "" => return Err(HotkeyParseError::MissingKey),
+            "space" => " ",
+            "esc" => "escape",
+            "plus" => "+",
+            key => key,
+        };
+
+        let mut hotkey = Self {
+            ctrl: false,
+            alt: false,
+            shift: false,
+            meta: false,
+            key: key.to_owned(),
+        };
+
+        for modifier in modifiers
+            .split('+')
+            .map(str::trim)
+            .filter(|m| !m.is_empty())
+        {
+            let flag = match modifier {
+                "ctrl" | "control" => &mut hotkey.ctrl,
+                "alt" | "option" => &mut hotkey.alt,
+                "shift" => &mut hotkey.shift,
+                "meta" | "cmd" | "command" | "super" => &mut hotkey.meta,
+                _ => return Err(HotkeyParseError::UnknownModifier(modifier.to_owned())),
+            };
+            if *flag {
+                return Err(HotkeyParseError::RepeatedModifier(modifier.to_owned()));
+            }
+            *flag = true;
+        }
+
+        Ok(hotkey)
+    }
+}
+
+impl fmt::Display for Hotkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (enabled, name) in [
+            (self.ctrl, "ctrl"),
+            (self.alt, "alt"),
+            (self.shift, "shift"),
+            (self.meta, "meta"),
+        ] {
+            if enabled {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        match self.key.as_str() {
+            " " => f.write_str("space"),
+            "+" => f.write_str("plus"),
+            key => f.write_str(key),
+        }
+    }
+}
+
+impl Hotkey {
+    /// Returns `true` if the key press `e` triggers this shortcut.
+    ///
+    /// Modifiers must match exactly. Letters and digits are also matched by their physical key,
+    /// so that `shift+1` or `alt+k` match even if the keyboard layout produces another character.
+    pub fn matches(&self, e: &KeyboardEvent) -> bool {
+        if (e.ctrl_key(), e.alt_key(), e.shift_key(), e.meta_key())
+            != (self.ctrl, self.alt, self.shift, self.meta)
+        {
+            return false;
+        }
+
+        if e.key().to_lowercase() == self.key {
+            return true;
+        }
+
+        let mut chars = self.key.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii_alphabetic() => {
+                e.code() == format!("Key{}", c.to_ascii_uppercase())
+            }
+            (Some(c), None) if c.is_ascii_digit() => e.code() == format!("Digit{c}"),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the shortcut uses a modifier that does not produce text.
+    fn has_command_modifier(&self) -> bool {
+        self.ctrl || self.alt || self.meta
+    }
+}
+
+type CallbackSlot = Rc<RefCell<Option<Rc<dyn Fn(KeyboardEvent)>>>>;
+
+struct Binding {
+    id: usize,
+    hotkey: Hotkey,
+    scope: Option<NodeRef>,
+    callback: CallbackSlot,
+}
+
+#[derive(Default)]
+struct Registry {
+    next_id: usize,
+    bindings: Vec<Binding>,
+    listener: Option<EventListener>,
+}
+
+thread_local! {
+    static REGISTRY: RefCell<Registry> = RefCell::default();
+}
+
+/// Removes a binding from the registry when dropped.
+struct Registration {
+    id: usize,
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        let _ = REGISTRY.try_with(|registry| {
+            let mut registry = registry.borrow_mut();
+            registry.bindings.retain(|m| m.id != self.id);
+            if registry.bindings.is_empty() {
+                registry.listener = None;
+            }
+        });
+    }
+}
+
+impl Registry {
+    fn register(hotkey: Hotkey, scope: Option<NodeRef>, callback: CallbackSlot) -> Registration {
+        REGISTRY.with(|registry| {
+            let mut registry = registry.borrow_mut();
+
+            if registry
+                .bindings
+                .iter()
+                .any(|m| m.hotkey == hotkey && m.scope == scope)
+            {
+                tracing::warn!(
+                    %hotkey,
+                    "hotkey is registered more than once in the same scope, only the last \
+                     registration is triggered"
+                );
+            }
+
+            let id = registry.next_id;
+            registry.next_id += 1;
+            registry.bindings.push(Binding {
+                id,
+                hotkey,
+                scope,
+                callback,
+            });
+
+            if registry.listener.is_none() {
+                registry.listener = Some(EventListener::new(
+                    &gloo::utils::window(),
+                    "keydown",
+                    Self::dispatch,
+                ));
+            }
+
+            Registration { id }
+        })
+    }
+
+    fn dispatch(e: &Event) {
+        let Some(e) = e.dyn_ref::<KeyboardEvent>() else {
+            return;
+        };
+
+        // The registry must not be borrowed while the callback runs, as it may register or remove
+        // shortcuts.
+        let callback = REGISTRY.with(|registry| registry.borrow().find(e));
+        if let Some(callback) = callback {
+            e.prevent_default();
+            callback(e.clone());
+        }
+    }
+
+    fn find(&self, e: &KeyboardEvent) -> Option<Rc<dyn Fn(KeyboardEvent)>> {
+        let active = gloo::utils::document().active_element();
+        let active: Option<&Node> = active.as_ref().map(AsRef::as_ref);
+        let typing = active.is_some_and(|m| {
+            m.dyn_ref::<HtmlElement>().is_some_and(|m| {
+                m.is_content_editable()
+                    || matches!(m.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+            })
+        });
+
+        let mut best: Option<(&Binding, Option<Node>)> = None;
+        for binding in self.bindings.iter().filter(|m| m.hotkey.matches(e)) {
+            let node = match &binding.scope {
+                // Global shortcuts producing text do not interfere with typing.
+                None if typing && !binding.hotkey.has_command_modifier() => continue,
+                None => None,
+                Some(scope) => match scope.get() {
+                    Some(node) if node.contains(active) => Some(node),
+                    _ => continue,
+                },
+            };
+
+            let replace = match (&best, &node) {
+                (None, _) | (Some((_, None)), _) => true,
+                (Some((_, Some(_))), None) => false,
+                // Both scopes contain the focus, so one contains the other.
+                (Some((_, Some(current))), Some(node)) => {
+                    node.is_same_node(Some(current)) || !node.contains(Some(current))
+                }
+            };
+            if replace {
+                best = Some((binding, node));
+            }
+        }
+
+        best.and_then(|(binding, _)| binding.callback.borrow().clone())
+    }
+}
+
+#[hook]
+fn use_hotkey_base<F>(hotkey: &'static str, scope: Option<NodeRef>, callback: F)
+where
+    F: Fn(KeyboardEvent) + 'static,
+{
+    let slot: CallbackSlot = use_mut_ref(|| None);
+    *slot.borrow_mut() = Some(Rc::new(callback));
+
+    use_effect_with((hotkey, scope), move |(hotkey, scope)| {
+        let registration = match hotkey.parse::<Hotkey>() {
+            Ok(m) => Some(Registry::register(m, scope.clone(), slot)),
+            Err(e) => {
+                tracing::warn!(%e, %hotkey, "invalid hotkey");
+                None
+            }
+        };
+
+        move || drop(registration)
+    });
+}
+
+/// This hook registers a keyboard shortcut that is active while the component is mounted.
+///
+/// `callback` is called with the `keydown` event when the shortcut is pressed, and the default
+/// action of the event is prevented. The `callback` passed in the latest render is the one being
+/// called. Shortcuts without `ctrl`, `alt` or `meta` are not triggered while typing in a form
+/// field. See [`Hotkey`] for the syntax of `hotkey`, invalid shortcuts are reported as a warning.
+///
+/// See the [module documentation](self) for an example.
+#[hook]
+pub fn use_hotkey<F>(hotkey: &'static str, callback: F)
+where
+    F: Fn(KeyboardEvent) + 'static,
+{
+    use_hotkey_base(hotkey, None, callback)
+}
+
+/// [`use_hotkey`] for a shortcut that is only active while the focus is within the element
+/// referenced by `scope`.
+///
+/// Scoped shortcuts are also triggered while typing in a form field of the element.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::hotkey::use_scoped_hotkey;
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Editor() -> Html {
+///     let node = use_node_ref();
+///     let saved = use_state(|| false);
+///     {
+///         let saved = saved.clone();
+///         use_scoped_hotkey("ctrl+s", node.clone(), move |_| saved.set(true));
+///     }
+///
+///     html! {
+///         <div ref={node}>
+///             <textarea />
+///             if *saved { <span>{ "Saved" }</span> }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_scoped_hotkey<F>(hotkey: &'static str, scope: NodeRef, callback: F)
+where
+    F: Fn(KeyboardEvent) + 'static,
+{
+    use_hotkey_base(hotkey, Some(scope), callback)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hotkey(s: &str) -> Hotkey {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_modifiers_and_key() {
+        let parsed = hotkey("Ctrl + Shift + K");
+        assert!(parsed.ctrl && parsed.shift && !parsed.alt && !parsed.meta);
+        assert_eq!(parsed.key, "k");
+        assert_eq!(parsed.to_string(), "ctrl+shift+k");
+
+        assert_eq!(hotkey("cmd+space"), hotkey("meta+space"));
+        assert_eq!(hotkey("space").key, " ");
+        assert_eq!(hotkey("ctrl++").key, "+");
+        assert_eq!(hotkey("ctrl+plus").to_string(), "ctrl+plus");
+        assert_eq!(hotkey("esc").key, "escape");
+    }
+
+    #[test]
+    fn rejects_invalid_hotkeys() {
+        assert_eq!("".parse::<Hotkey>(), Err(HotkeyParseError::MissingKey));
+        assert_eq!("ctrl+".parse::<Hotkey>(), Err(HotkeyParseError::MissingKey));
+        assert_eq!(
+            "hyper+k".parse::<Hotkey>(),
+            Err(HotkeyParseError::UnknownModifier("hyper".to_owned()))
+        );
+        assert_eq!(
+            "ctrl+control+k".parse::<Hotkey>(),
+            Err(HotkeyParseError::RepeatedModifier("control".to_owned()))
+        );
+    }
+}
@@ -261,6 +261,17 @@ impl<T: Into<Classes> + Clone> From<&Option<T>> for Classes {
     }
 }
 
+/// The class is added only if the condition is `true`.
+impl<T: Into<Classes>> From<(T, bool)> for Classes {
+    fn from((t, condition): (T, bool)) -> Self {
+        if condition {
+            t.into()
+        } else {
+            Self::new()
+        }
+    }
+}
+
 impl<T: Into<Classes>> From<Vec<T>> for Classes {
     fn from(t: Vec<T>) -> Self {
         Self::from_iter(t)
@@ -376,6 +387,20 @@ mod tests {
         assert!(subject.contains("bar"));
     }
 
+    #[test]
+    fn adds_classes_whose_condition_holds() {
+        let subject = Classes::from([("foo", true), ("bar", false)]);
+        assert!(subject.contains("foo"));
+        assert!(!subject.contains("bar"));
+
+        let subject = [("foo", false), ("bar baz", true)]
+            .into_iter()
+            .collect::<Classes>();
+        assert!(!subject.contains("foo"));
+        assert!(subject.contains("bar"));
+        assert!(subject.contains("baz"));
+    }
+
     #[test]
     fn ignores_empty_string() {
         let classes = String::from("");
@@ -111,7 +111,6 @@ pub type Children = ChildrenRenderer<Html>;
 /// `children` property can be used to filter, mutate, and render the items.
 /// ```
 /// # use yew::{html, Component, Html, ChildrenWithProps, Context, Properties};
-/// # use std::rc::Rc;
 /// #
 /// #[derive(Clone, Properties, PartialEq)]
 /// struct ListProps {
@@ -125,10 +124,8 @@ pub type Children = ChildrenRenderer<Html>;
 /// #   fn create(ctx: &Context<Self>) -> Self { Self }
 /// #   fn view(&self, ctx: &Context<Self>) -> Html {
 ///         html!{{
-///             for ctx.props().children.iter().map(|mut item| {
-///                 let mut props = Rc::make_mut(&mut item.props);
-///                 props.value = format!("item-{}", props.value);
-///                 item
+///             for ctx.props().children.with_props(|index, props| {
+///                 props.value = format!("item-{}-{}", index, props.value);
 ///             })
 ///         }}
 ///     }
@@ -210,6 +207,46 @@ where
             closure(self)
         }
     }
+
+    /// Returns the children for which `predicate` returns `true`.
+    pub fn filter(&self, mut predicate: impl FnMut(&T) -> bool) -> Self {
+        Self::new(self.iter().filter(|child| predicate(child)).collect())
+    }
+
+    /// Converts each child with `f`, which is also given the index of the child.
+    ///
+    /// ```
+    /// # use yew::{html, Children, Html};
+    /// # let children = Children::new(Vec::new());
+    /// let items: Vec<Html> = children
+    ///     .map_each(|index, child| html! { <li key={index}>{ child }</li> })
+    ///     .into_iter()
+    ///     .collect();
+    /// ```
+    pub fn map_each<OUT>(&self, mut f: impl FnMut(usize, T) -> OUT) -> ChildrenRenderer<OUT> {
+        ChildrenRenderer {
+            children: self
+                .iter()
+                .enumerate()
+                .map(|(index, child)| f(index, child))
+                .collect(),
+        }
+    }
+}
+
+impl<COMP> ChildrenRenderer<VChild<COMP>>
+where
+    COMP: BaseComponent,
+    COMP::Properties: Clone,
+{
+    /// Returns a copy of the children, with the props of each child modified by `f`, which is
+    /// also given the index of the child.
+    ///
+    /// This injects props computed by the parent, such as an `index` or whether the child is
+    /// `selected`, without destructuring the children by hand.
+    pub fn with_props(&self, mut f: impl FnMut(usize, &mut COMP::Properties)) -> Self {
+        self.map_each(|index, child| child.with_props(|props| f(index, props)))
+    }
 }
 
 impl<T> Default for ChildrenRenderer<T> {
@@ -292,4 +329,37 @@ mod tests {
         let res = children.map(|children| Some(children.clone()));
         assert!(res.is_some());
     }
+
+    #[test]
+    fn children_with_props() {
+        use crate::{function_component, html_nested};
+
+        #[derive(Clone, PartialEq, Properties)]
+        struct ItemProps {
+            #[prop_or_default]
+            index: usize,
+            #[prop_or_default]
+            selected: bool,
+        }
+
+        #[function_component]
+        fn Item(_props: &ItemProps) -> Html {
+            Html::default()
+        }
+
+        let children = ChildrenWithProps::<Item>::new(vec![
+            html_nested! { <Item key="a" /> },
+            html_nested! { <Item key="b" selected=true /> },
+            html_nested! { <Item key="c" /> },
+        ]);
+        let children = children
+            .filter(|child| !child.props.selected)
+            .with_props(|index, props| props.index = index);
+
+        let items = children
+            .iter()
+            .map(|child| (child.key().unwrap().to_string(), child.props.index))
+            .collect::<Vec<_>>();
+        assert_eq!(items, [("a".to_owned(), 0), ("c".to_owned(), 1)]);
+    }
 }
@@ -1,6 +1,7 @@
 //! Component lifecycle module
 
 use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
 use std::rc::Rc;
 
 #[cfg(feature = "csr")]
@@ -12,13 +13,51 @@ use super::BaseComponent;
 use crate::dom_bundle::Fragment;
 #[cfg(feature = "csr")]
 use crate::dom_bundle::{BSubtree, Bundle, DomSlot, DynamicDomSlot};
+use crate::error_boundary::{find_error_boundary, ErrorBoundary};
 #[cfg(feature = "hydration")]
 use crate::html::RenderMode;
-use crate::html::{Html, RenderError};
+use crate::html::{ComponentError, Html, RenderError};
 use crate::scheduler::{self, Runnable, Shared};
 use crate::suspense::{BaseSuspense, Suspension};
+#[cfg(feature = "csr")]
+use crate::virtual_dom::recycle::{self, VNodePool};
 use crate::{Callback, Context, HtmlResult};
 
+/// Enters an `INFO` span for a lifecycle event of a component.
+///
+/// Only compiled with the `tracing` feature, so builds that are not being profiled do not
+/// pay for the additional spans.
+#[cfg(feature = "tracing")]
+macro_rules! lifecycle_span {
+    ($name:literal, $state:expr $(, $($fields:tt)+)?) => {
+        tracing::info_span!(
+            $name,
+            component.id = $state.comp_id,
+            component.name = $state.comp_name
+            $(, $($fields)+)?
+        )
+        .entered()
+    };
+}
+
+/// The reason a component is rendered, recorded on `render` spans.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RenderCause {
+    /// The component was just created.
+    First,
+    /// A message sent to the component requested a render.
+    Message,
+    /// The component received new props.
+    #[cfg(feature = "csr")]
+    Props,
+    /// A suspension of the component was resumed.
+    Resumed,
+    /// The component was hydrated and needs to fix its node refs.
+    #[cfg(feature = "hydration")]
+    Hydration,
+}
+
 pub(crate) enum ComponentRenderState {
     #[cfg(feature = "csr")]
     Render {
@@ -209,6 +248,8 @@ where
         };
 
         if self.context.props != props {
+            #[cfg(feature = "devtools")]
+            crate::devtools::props_changed::<COMP>(self.context.scope.id, &props);
             let old_props = std::mem::replace(&mut self.context.props, props);
             self.component.changed(&self.context, &old_props)
         } else {
@@ -228,12 +269,28 @@ pub(crate) struct ComponentState {
 
     #[cfg(feature = "csr")]
     has_rendered: bool,
+    /// The allocations of the virtual nodes consumed by the last render, for the next one.
+    #[cfg(feature = "csr")]
+    vnode_pool: VNodePool,
     #[cfg(feature = "hydration")]
     pending_props: Option<Rc<dyn Any>>,
 
     suspension: Option<Suspension>,
+    /// The boundary catching the errors of this component, if any.
+    error_boundary: Option<Scope<ErrorBoundary>>,
+    /// Set once the component failed. It is then left alone until its boundary replaces it.
+    failed: bool,
 
     pub(crate) comp_id: usize,
+    #[cfg(any(
+        feature = "tracing",
+        feature = "profiler",
+        all(feature = "a11y_audit", debug_assertions)
+    ))]
+    comp_name: &'static str,
+    /// The last committed output, to find wasted renders.
+    #[cfg(all(feature = "profiler", feature = "csr"))]
+    last_root: Option<Html>,
 }
 
 impl ComponentState {
@@ -250,6 +307,9 @@ impl ComponentState {
         #[cfg(feature = "hydration")] prepared_state: Option<String>,
     ) -> Self {
         let comp_id = scope.id;
+        let error_boundary = find_error_boundary(&scope.clone().into());
+        #[cfg(feature = "devtools")]
+        crate::devtools::mounted(&scope, &props);
         #[cfg(feature = "hydration")]
         let creation_mode = {
             match initial_render_state {
@@ -278,13 +338,25 @@ impl ComponentState {
             inner,
             render_state: initial_render_state,
             suspension: None,
+            error_boundary,
+            failed: false,
 
             #[cfg(feature = "csr")]
             has_rendered: false,
+            #[cfg(feature = "csr")]
+            vnode_pool: VNodePool::default(),
             #[cfg(feature = "hydration")]
             pending_props: None,
 
             comp_id,
+            #[cfg(any(
+                feature = "tracing",
+                feature = "profiler",
+                all(feature = "a11y_audit", debug_assertions)
+            ))]
+            comp_name: std::any::type_name::<COMP>(),
+            #[cfg(all(feature = "profiler", feature = "csr"))]
+            last_root: None,
         }
     }
 
@@ -306,6 +378,44 @@ impl ComponentState {
             BaseSuspense::resume(&suspense_scope, m);
         }
     }
+
+    /// Runs `f` with the component, catching panics if there is a boundary to report them to.
+    fn catch_panic<T>(
+        &mut self,
+        f: impl FnOnce(&mut dyn Stateful) -> T,
+    ) -> Result<T, ComponentError> {
+        let inner = &mut *self.inner;
+        match self.error_boundary {
+            Some(_) => panic::catch_unwind(AssertUnwindSafe(|| f(inner)))
+                .map_err(ComponentError::from_panic),
+            None => Ok(f(inner)),
+        }
+    }
+
+    /// Runs `f` with the component, reporting a panic to the boundary of the component.
+    ///
+    /// Returns `None` if the component panicked.
+    fn run_or_fail<T>(&mut self, f: impl FnOnce(&mut dyn Stateful) -> T) -> Option<T> {
+        self.catch_panic(f).map_err(|e| self.fail(e)).ok()
+    }
+
+    /// Reports `error` to the boundary of this component.
+    ///
+    /// # Panics
+    ///
+    /// If the component is not inside an [`ErrorBoundary`].
+    fn fail(&mut self, error: ComponentError) {
+        self.failed = true;
+        let error = error.with_component(self.inner.any_scope().get_type_name());
+        match self.error_boundary {
+            Some(ref boundary) => ErrorBoundary::catch(boundary, error),
+            None => panic!(
+                "component `{}` failed: {error}. To recover from errors, an <ErrorBoundary /> \
+                 component is required.",
+                error.component().unwrap_or_default()
+            ),
+        }
+    }
 }
 
 pub(crate) struct CreateRunner<COMP: BaseComponent> {
@@ -318,6 +428,16 @@ pub(crate) struct CreateRunner<COMP: BaseComponent> {
 
 impl<COMP: BaseComponent> Runnable for CreateRunner<COMP> {
     fn run(self: Box<Self>) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "create",
+            component.id = self.scope.id,
+            component.name = std::any::type_name::<COMP>(),
+        )
+        .entered();
+        #[cfg(all(feature = "csr", debug_assertions))]
+        let _stack = crate::panic_overlay::enter(self.scope.clone().into());
+
         let mut current_state = self.scope.state.borrow_mut();
         if current_state.is_none() {
             *current_state = Some(ComponentState::new(
@@ -342,7 +462,9 @@ impl ComponentState {
         fields(component.id = self.comp_id)
     )]
     fn update(&mut self) -> bool {
-        let schedule_render = self.inner.flush_messages();
+        let schedule_render = self
+            .run_or_fail(|inner| inner.flush_messages())
+            .unwrap_or(false);
         tracing::trace!(schedule_render);
         schedule_render
     }
@@ -350,7 +472,12 @@ impl ComponentState {
 
 impl Runnable for UpdateRunner {
     fn run(self: Box<Self>) {
-        if let Some(state) = self.state.borrow_mut().as_mut() {
+        if let Some(state) = self.state.borrow_mut().as_mut().filter(|m| !m.failed) {
+            #[cfg(feature = "tracing")]
+            let _span = lifecycle_span!("update", state);
+            #[cfg(all(feature = "csr", debug_assertions))]
+            let _stack = crate::panic_overlay::enter(state.inner.any_scope());
+
             let schedule_render = state.update();
 
             if schedule_render {
@@ -358,6 +485,8 @@ impl Runnable for UpdateRunner {
                     state.comp_id,
                     Box::new(RenderRunner {
                         state: self.state.clone(),
+                        #[cfg(feature = "tracing")]
+                        cause: RenderCause::Message,
                     }),
                 );
                 // Only run from the scheduler, so no need to call `scheduler::start()`
@@ -379,6 +508,10 @@ impl ComponentState {
     )]
     fn destroy(mut self, parent_to_detach: bool) {
         self.inner.destroy();
+        #[cfg(feature = "devtools")]
+        crate::devtools::unmounted(self.comp_id);
+        #[cfg(all(feature = "a11y_audit", debug_assertions))]
+        crate::a11y::unmounted(self.comp_id);
         self.resume_existing_suspension();
 
         match self.render_state {
@@ -413,6 +546,11 @@ impl ComponentState {
 impl Runnable for DestroyRunner {
     fn run(self: Box<Self>) {
         if let Some(state) = self.state.borrow_mut().take() {
+            #[cfg(feature = "tracing")]
+            let _span = lifecycle_span!("destroy", state);
+            #[cfg(all(feature = "csr", debug_assertions))]
+            let _stack = crate::panic_overlay::enter(state.inner.any_scope());
+
             state.destroy(self.parent_to_detach);
         }
     }
@@ -420,6 +558,8 @@ impl Runnable for DestroyRunner {
 
 pub(crate) struct RenderRunner {
     pub state: Shared<Option<ComponentState>>,
+    #[cfg(feature = "tracing")]
+    pub cause: RenderCause,
 }
 
 impl ComponentState {
@@ -429,11 +569,39 @@ impl ComponentState {
         fields(component.id = self.comp_id)
     )]
     fn render(&mut self, shared_state: &Shared<Option<ComponentState>>) {
-        let view = self.inner.view();
+        // The nodes of this render reuse the allocations of the ones consumed by the last one.
+        #[cfg(feature = "csr")]
+        {
+            let mut pool = std::mem::take(&mut self.vnode_pool);
+            recycle::with_pool(&mut pool, || self.render_view(shared_state));
+            self.vnode_pool = pool;
+        }
+        #[cfg(not(feature = "csr"))]
+        self.render_view(shared_state);
+    }
+
+    fn render_view(&mut self, shared_state: &Shared<Option<ComponentState>>) {
+        #[cfg(feature = "profiler")]
+        let profile =
+            crate::profiler::span(crate::profiler::Phase::Render, self.comp_id, self.comp_name);
+        let view = self
+            .catch_panic(|inner| inner.view())
+            .unwrap_or_else(|e| Err(e.into()));
+        #[cfg(feature = "profiler")]
+        drop(profile);
         tracing::trace!(?view, "render result");
         match view {
             Ok(vnode) => self.commit_render(shared_state, vnode),
             Err(RenderError::Suspended(susp)) => self.suspend(shared_state, susp),
+            Err(RenderError::Failed(e)) => {
+                self.fail(e);
+                // The renderer waits for the output of every component, so a failed one is
+                // rendered empty.
+                #[cfg(feature = "ssr")]
+                if matches!(self.render_state, ComponentRenderState::Ssr { .. }) {
+                    self.commit_render(shared_state, Html::default());
+                }
+            }
         };
     }
 
@@ -447,6 +615,8 @@ impl ComponentState {
                 self.comp_id,
                 Box::new(RenderRunner {
                     state: shared_state.clone(),
+                    #[cfg(feature = "tracing")]
+                    cause: RenderCause::Resumed,
                 }),
             );
         } else {
@@ -464,6 +634,8 @@ impl ComponentState {
                     comp_id,
                     Box::new(RenderRunner {
                         state: shared_state.clone(),
+                        #[cfg(feature = "tracing")]
+                        cause: RenderCause::Resumed,
                     }),
                 );
                 scheduler::start();
@@ -498,9 +670,31 @@ impl ComponentState {
             } => {
                 let scope = self.inner.any_scope();
 
+                #[cfg(feature = "profiler")]
+                let _profile = {
+                    let recording = crate::profiler::is_recording();
+                    if recording && self.last_root.as_ref() == Some(&new_root) {
+                        crate::profiler::wasted_render(self.comp_id, self.comp_name);
+                    }
+                    self.last_root = recording.then(|| new_root.clone());
+                    crate::profiler::span(
+                        crate::profiler::Phase::Commit,
+                        self.comp_id,
+                        self.comp_name,
+                    )
+                };
+                #[cfg(all(feature = "a11y_audit", debug_assertions))]
+                crate::a11y::committed(
+                    self.comp_id,
+                    self.comp_name,
+                    &new_root,
+                    parent.closest("label").ok().flatten().is_some(),
+                );
                 let new_node_ref =
                     bundle.reconcile(root, &scope, parent, sibling_slot.to_position(), new_root);
                 own_slot.reassign(new_node_ref);
+                #[cfg(feature = "devtools")]
+                crate::devtools::rendered(self.comp_id);
 
                 let first_render = !self.has_rendered;
                 self.has_rendered = true;
@@ -529,6 +723,8 @@ impl ComponentState {
                     self.comp_id,
                     Box::new(RenderRunner {
                         state: shared_state.clone(),
+                        #[cfg(feature = "tracing")]
+                        cause: RenderCause::Hydration,
                     }),
                 );
 
@@ -571,10 +767,18 @@ impl Runnable for RenderRunner {
     fn run(self: Box<Self>) {
         let mut state = self.state.borrow_mut();
         let state = match state.as_mut() {
-            None => return, // skip for components that have already been destroyed
+            // skip for components that have already been destroyed, or are about to be replaced
+            // by the fallback of their boundary
+            None => return,
+            Some(state) if state.failed => return,
             Some(state) => state,
         };
 
+        #[cfg(feature = "tracing")]
+        let _span = lifecycle_span!("render", state, cause = ?self.cause);
+        #[cfg(all(feature = "csr", debug_assertions))]
+        let _stack = crate::panic_overlay::enter(state.inner.any_scope());
+
         state.render(&self.state);
     }
 }
@@ -624,7 +828,9 @@ mod feat_csr {
             }
 
             let should_render = |props: Option<Rc<dyn Any>>, state: &mut ComponentState| -> bool {
-                props.map(|m| state.inner.props_changed(m)).unwrap_or(false)
+                props
+                    .and_then(|m| state.run_or_fail(|inner| inner.props_changed(m)))
+                    .unwrap_or(false)
             };
 
             #[cfg(feature = "hydration")]
@@ -634,7 +840,9 @@ mod feat_csr {
                         match state.has_rendered {
                             true => {
                                 state.pending_props = None;
-                                state.inner.props_changed(props)
+                                state
+                                    .run_or_fail(|inner| inner.props_changed(props))
+                                    .unwrap_or(false)
                             }
                             false => {
                                 state.pending_props = Some(props);
@@ -678,7 +886,12 @@ mod feat_csr {
                 state: shared_state,
             } = *self;
 
-            if let Some(state) = shared_state.borrow_mut().as_mut() {
+            if let Some(state) = shared_state.borrow_mut().as_mut().filter(|m| !m.failed) {
+                #[cfg(feature = "tracing")]
+                let _span = lifecycle_span!("props_update", state);
+                #[cfg(debug_assertions)]
+                let _stack = crate::panic_overlay::enter(state.inner.any_scope());
+
                 let schedule_render = state.changed(props, next_sibling_slot);
 
                 if schedule_render {
@@ -686,6 +899,8 @@ mod feat_csr {
                         state.comp_id,
                         Box::new(RenderRunner {
                             state: shared_state.clone(),
+                            #[cfg(feature = "tracing")]
+                            cause: RenderCause::Props,
                         }),
                     );
                     // Only run from the scheduler, so no need to call `scheduler::start()`
@@ -707,7 +922,7 @@ mod feat_csr {
         )]
         fn rendered(&mut self, first_render: bool) -> bool {
             if self.suspension.is_none() {
-                self.inner.rendered(first_render);
+                self.run_or_fail(|inner| inner.rendered(first_render));
             }
 
             #[cfg(feature = "hydration")]
@@ -723,7 +938,18 @@ mod feat_csr {
 
     impl Runnable for RenderedRunner {
         fn run(self: Box<Self>) {
-            if let Some(state) = self.state.borrow_mut().as_mut() {
+            if let Some(state) = self.state.borrow_mut().as_mut().filter(|m| !m.failed) {
+                #[cfg(feature = "tracing")]
+                let _span = lifecycle_span!("rendered", state, first_render = self.first_render);
+                #[cfg(feature = "profiler")]
+                let _profile = crate::profiler::span(
+                    crate::profiler::Phase::Rendered,
+                    state.comp_id,
+                    state.comp_name,
+                );
+                #[cfg(debug_assertions)]
+                let _stack = crate::panic_overlay::enter(state.inner.any_scope());
+
                 let has_pending_props = state.rendered(self.first_render);
 
                 if has_pending_props {
@@ -5,16 +5,20 @@ mod children;
 mod lifecycle;
 mod marker;
 mod properties;
+mod rest;
 mod scope;
+mod slot;
 
 use std::rc::Rc;
 
 pub use children::*;
 pub use marker::*;
 pub use properties::*;
+pub use rest::*;
 #[cfg(feature = "csr")]
 pub(crate) use scope::Scoped;
 pub use scope::{AnyScope, Scope, SendAsMessage};
+pub use slot::*;
 
 use super::{Html, HtmlResult, IntoHtmlResult};
 
@@ -0,0 +1,149 @@
+//! Rest properties module
+
+use std::rc::Rc;
+
+use indexmap::IndexMap;
+
+use crate::html::IntoPropValue;
+use crate::virtual_dom::{AttrValue, Listener, Spread, VTag};
+
+/// The attributes and listeners given to a component that its properties don't declare.
+///
+/// A field of this type marked with `#[prop_or_rest]` collects the `data-*` and `aria-*`
+/// attributes, the common global attributes (such as `id`, `title`, `role` or `style`) and the
+/// event listeners (such as `onclick`) given to the component, unless its properties declare a
+/// field of that name. A wrapper component forwards them to its inner element by spreading them
+/// with `..`.
+///
+/// # Example
+///
+/// ```
+/// use yew::html::RestProps;
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// pub struct ButtonProps {
+///     #[prop_or_default]
+///     pub children: Html,
+///     #[prop_or_rest]
+///     pub rest: RestProps,
+/// }
+///
+/// #[function_component]
+/// fn Button(props: &ButtonProps) -> Html {
+///     html! {
+///         <button class="button" ..&props.rest>{ props.children.clone() }</button>
+///     }
+/// }
+///
+/// # fn dummy(onclick: Callback<MouseEvent>) -> Html {
+/// html! {
+///     <Button id="submit" aria-label="Submit the form" {onclick}>{ "Submit" }</Button>
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RestProps {
+    attributes: IndexMap<AttrValue, AttrValue>,
+    listeners: Vec<Rc<dyn Listener>>,
+}
+
+impl RestProps {
+    /// Returns the collected attributes.
+    pub fn attributes(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.attributes
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Returns the value of the collected attribute `key`.
+    pub fn attribute(&self, key: &str) -> Option<&AttrValue> {
+        self.attributes.get(key)
+    }
+
+    /// Returns the collected listeners.
+    pub fn listeners(&self) -> &[Rc<dyn Listener>] {
+        &self.listeners
+    }
+
+    /// Returns `true` if no attribute or listener was collected.
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty() && self.listeners.is_empty()
+    }
+
+    /// Sets the attribute `key`. `None` removes it.
+    pub fn set_attribute(
+        &mut self,
+        key: impl Into<AttrValue>,
+        value: impl IntoPropValue<Option<AttrValue>>,
+    ) {
+        let key = key.into();
+        match value.into_prop_value() {
+            Some(value) => {
+                self.attributes.insert(key, value);
+            }
+            None => {
+                self.attributes.shift_remove(&key);
+            }
+        }
+    }
+
+    /// Adds a listener. `None` is ignored.
+    pub fn add_listener(&mut self, listener: Option<Rc<dyn Listener>>) {
+        self.listeners.extend(listener);
+    }
+}
+
+impl PartialEq for RestProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.attributes == other.attributes
+            && self.listeners.len() == other.listeners.len()
+            && self
+                .listeners
+                .iter()
+                .zip(other.listeners.iter())
+                // Listeners are compared like the ones of elements, see `Listeners`.
+                .all(|(lhs, rhs)| {
+                    #[allow(ambiguous_wide_pointer_comparisons)]
+                    Rc::ptr_eq(lhs, rhs)
+                })
+    }
+}
+
+impl Spread for RestProps {
+    fn spread_onto(self, vtag: &mut VTag) {
+        vtag.set_attributes_from(self.attributes);
+        for listener in self.listeners {
+            vtag.add_listener(listener);
+        }
+    }
+}
+
+impl Spread for &RestProps {
+    fn spread_onto(self, vtag: &mut VTag) {
+        self.clone().spread_onto(vtag);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html;
+    use crate::virtual_dom::VNode;
+
+    #[test]
+    fn spread_keeps_explicit_attributes() {
+        let mut rest = RestProps::default();
+        rest.set_attribute("id", "rest");
+        rest.set_attribute("aria-label", "Label");
+        rest.set_attribute("title", None::<AttrValue>);
+
+        let html = html! { <div id="explicit" ..&rest /> };
+        let vtag = match html {
+            VNode::VTag(vtag) => vtag,
+            _ => unreachable!(),
+        };
+        let attributes = vtag.attributes.iter().collect::<Vec<_>>();
+        assert_eq!(attributes, [("id", "explicit"), ("aria-label", "Label")]);
+    }
+}
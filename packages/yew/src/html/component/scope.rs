@@ -14,6 +14,8 @@ use super::lifecycle::ComponentState;
 use super::BaseComponent;
 use crate::callback::Callback;
 use crate::context::{ContextHandle, ContextProvider};
+#[cfg(feature = "ssr")]
+use crate::feat_ssr::SsrContext;
 use crate::platform::spawn_local;
 #[cfg(any(feature = "csr", feature = "ssr"))]
 use crate::scheduler::Shared;
@@ -21,9 +23,16 @@ use crate::scheduler::Shared;
 /// Untyped scope used for accessing parent scope
 #[derive(Clone)]
 pub struct AnyScope {
+    #[cfg(feature = "devtools")]
+    id: usize,
     type_id: TypeId,
+    type_name: &'static str,
     parent: Option<Rc<AnyScope>>,
     typed_scope: Rc<dyn Any>,
+    #[cfg(debug_assertions)]
+    pub(crate) strict: bool,
+    #[cfg(feature = "ssr")]
+    pub(crate) ssr: Option<Rc<SsrContext>>,
 }
 
 impl fmt::Debug for AnyScope {
@@ -35,14 +44,27 @@ impl fmt::Debug for AnyScope {
 impl<COMP: BaseComponent> From<Scope<COMP>> for AnyScope {
     fn from(scope: Scope<COMP>) -> Self {
         AnyScope {
+            #[cfg(feature = "devtools")]
+            id: scope.id,
             type_id: TypeId::of::<COMP>(),
+            type_name: std::any::type_name::<COMP>(),
             parent: scope.parent.clone(),
+            #[cfg(debug_assertions)]
+            strict: scope.strict,
+            #[cfg(feature = "ssr")]
+            ssr: scope.ssr.clone(),
             typed_scope: Rc::new(scope),
         }
     }
 }
 
 impl AnyScope {
+    /// Returns the id of the linked component, unique in the application.
+    #[cfg(feature = "devtools")]
+    pub(crate) fn id(&self) -> usize {
+        self.id
+    }
+
     /// Returns the parent scope
     pub fn get_parent(&self) -> Option<&AnyScope> {
         self.parent.as_deref()
@@ -53,6 +75,11 @@ impl AnyScope {
         &self.type_id
     }
 
+    /// Returns the type name of the linked component
+    pub fn get_type_name(&self) -> &'static str {
+        self.type_name
+    }
+
     /// Attempts to downcast into a typed scope
     ///
     /// # Panics
@@ -102,6 +129,12 @@ pub struct Scope<COMP: BaseComponent> {
     pub(crate) state: Shared<Option<ComponentState>>,
 
     pub(crate) id: usize,
+    /// Whether the component is rendered in strict mode, inherited from the parent.
+    #[cfg(debug_assertions)]
+    pub(crate) strict: bool,
+    /// The server side rendering the component is part of, inherited from the parent.
+    #[cfg(feature = "ssr")]
+    pub(crate) ssr: Option<Rc<SsrContext>>,
 }
 
 impl<COMP: BaseComponent> fmt::Debug for Scope<COMP> {
@@ -123,6 +156,10 @@ impl<COMP: BaseComponent> Clone for Scope<COMP> {
             state: self.state.clone(),
 
             id: self.id,
+            #[cfg(debug_assertions)]
+            strict: self.strict,
+            #[cfg(feature = "ssr")]
+            ssr: self.ssr.clone(),
         }
     }
 }
@@ -287,6 +324,12 @@ impl<COMP: BaseComponent> Scope<COMP> {
     pub fn send_message_batch(&self, messages: Vec<COMP::Message>) {
         self.arch_send_message_batch(messages)
     }
+
+    /// Runs `f`, and renders the updates it causes, to this component or others, in a single
+    /// pass once it returns. See [`batch_updates`](crate::scheduler::batch_updates).
+    pub fn batch<R>(&self, f: impl FnOnce() -> R) -> R {
+        crate::scheduler::batch_updates(f)
+    }
 }
 
 #[cfg(feature = "ssr")]
@@ -294,15 +337,34 @@ mod feat_ssr {
     use std::fmt::Write;
 
     use super::*;
+    use crate::error_boundary::ErrorBoundary;
     use crate::feat_ssr::VTagKind;
+    #[cfg(feature = "tracing")]
+    use crate::html::component::lifecycle::RenderCause;
     use crate::html::component::lifecycle::{
         ComponentRenderState, CreateRunner, DestroyRunner, RenderRunner,
     };
-    use crate::platform::fmt::BufWriter;
+    use crate::html::Html;
+    use crate::platform::fmt::{BufStream, BufWriter};
     use crate::platform::pinned::oneshot;
     use crate::scheduler;
     use crate::virtual_dom::Collectable;
 
+    /// Renders `html` in the scope `parent_scope` into a string.
+    async fn render_to_string(
+        html: Html,
+        parent_scope: AnyScope,
+        hydratable: bool,
+        parent_vtag_kind: VTagKind,
+    ) -> String {
+        BufStream::new(move |mut w| async move {
+            html.render_into_stream(&mut w, &parent_scope, hydratable, parent_vtag_kind)
+                .await;
+        })
+        .collect()
+        .await
+    }
+
     impl<COMP: BaseComponent> Scope<COMP> {
         pub(crate) async fn render_into_stream(
             &self,
@@ -311,6 +373,11 @@ mod feat_ssr {
             hydratable: bool,
             parent_vtag_kind: VTagKind,
         ) {
+            let permit = match self.ssr {
+                Some(ref ssr) => ssr.acquire_render_permit().await,
+                None => None,
+            };
+
             // Rust's Future implementation is stack-allocated and incurs zero runtime-cost.
             //
             // If the content of this channel is ready before it is awaited, it is
@@ -329,6 +396,8 @@ mod feat_ssr {
                 }),
                 Box::new(RenderRunner {
                     state: self.state.clone(),
+                    #[cfg(feature = "tracing")]
+                    cause: RenderCause::First,
                 }),
             );
             scheduler::start();
@@ -340,12 +409,105 @@ mod feat_ssr {
             }
 
             let html = rx.await.unwrap();
+            // The children are rendered without holding on to the permit of their parent.
+            drop(permit);
+
+            // The content of an island in a page that is not hydratable is hydrated on its own.
+            let island = self
+                .ssr
+                .as_ref()
+                .and_then(|ssr| ssr.take_island(self.id))
+                .filter(|_| !hydratable);
+            if let Some((name, ref props)) = island {
+                let _ = write!(
+                    w,
+                    r#"<yew-island data-component="{}" data-props="{props}">"#,
+                    html_escape::encode_double_quoted_attribute(name)
+                );
+            }
+            let children_hydratable = hydratable || island.is_some();
+
+            let cached = self.ssr.as_ref().and_then(|ssr| {
+                let cache_key = ssr.take_cache_key(self.id)?;
+                Some((ssr, ssr.cache.clone()?, cache_key))
+            });
+            let error_boundary = self
+                .ssr
+                .as_ref()
+                .filter(|ssr| ssr.is_error_boundary(self.id));
+            let mut failed = false;
+            match (cached, error_boundary) {
+                (Some((ssr, cache, cache_key)), _) => {
+                    match cache.get(&cache_key, children_hydratable) {
+                        Some(cached_html) => {
+                            let _ = w.write_str(&cached_html);
+                        }
+                        None => {
+                            let mut scope = self.clone();
+                            scope.ssr = Some(Rc::new(ssr.for_cached_subtree()));
+                            let rendered = render_to_string(
+                                html,
+                                scope.into(),
+                                children_hydratable,
+                                parent_vtag_kind,
+                            )
+                            .await;
+                            cache.insert(&cache_key, children_hydratable, &rendered);
+                            let _ = w.write_str(&rendered);
+                        }
+                    }
+                }
+                (None, Some(ssr)) => {
+                    let rendered = render_to_string(
+                        html,
+                        self.clone().into(),
+                        children_hydratable,
+                        parent_vtag_kind,
+                    )
+                    .await;
+                    match ssr.take_error_boundary(self.id) {
+                        None => {
+                            let _ = w.write_str(&rendered);
+                        }
+                        // The fallback is replaced by the children rendered again on the client.
+                        Some((fallback, error)) => {
+                            failed = true;
+                            let raw = Collectable::Raw;
+                            if children_hydratable {
+                                raw.write_open_tag(w);
+                            }
+                            let self_any_scope = AnyScope::from(self.clone());
+                            fallback(error)
+                                .render_into_stream(w, &self_any_scope, false, parent_vtag_kind)
+                                .await;
+                            if children_hydratable {
+                                raw.write_close_tag(w);
+                            }
+                        }
+                    }
+                }
+                (None, None) => {
+                    let self_any_scope = AnyScope::from(self.clone());
+                    html.render_into_stream(
+                        w,
+                        &self_any_scope,
+                        children_hydratable,
+                        parent_vtag_kind,
+                    )
+                    .await;
+                }
+            }
 
-            let self_any_scope = AnyScope::from(self.clone());
-            html.render_into_stream(w, &self_any_scope, hydratable, parent_vtag_kind)
-                .await;
-
-            if let Some(prepared_state) = self.get_component().unwrap().prepare_state() {
+            let prepared_state = match failed {
+                // Only a hydrating client reads the marker.
+                true => hydratable.then(|| ErrorBoundary::FAILED_ON_SERVER.to_owned()),
+                false => self.get_component().unwrap().prepare_state(),
+            };
+            if let Some(prepared_state) = prepared_state {
+                let metrics = self.ssr.as_ref().and_then(|ssr| ssr.state_metrics.as_ref());
+                if let Some(metrics) = metrics {
+                    metrics.record(prepared_state.len());
+                }
                 let _ = w.write_str(r#"<script type="application/x-yew-comp-state">"#);
                 let _ = w.write_str(&prepared_state);
                 let _ = w.write_str(r#"</script>"#);
@@ -355,6 +517,10 @@ mod feat_ssr {
                 collectable.write_close_tag(w);
             }
 
+            if island.is_some() {
+                let _ = w.write_str("</yew-island>");
+            }
+
             scheduler::push_component_destroy(Box::new(DestroyRunner {
                 state: self.state.clone(),
                 parent_to_detach: false,
@@ -436,6 +602,10 @@ mod feat_csr_ssr {
     impl<COMP: BaseComponent> Scope<COMP> {
         /// Crate a scope with an optional parent scope
         pub(crate) fn new(parent: Option<AnyScope>) -> Self {
+            #[cfg(debug_assertions)]
+            let strict = parent.as_ref().is_some_and(|m| m.strict);
+            #[cfg(feature = "ssr")]
+            let ssr = parent.as_ref().and_then(|m| m.ssr.clone());
             let parent = parent.map(Rc::new);
 
             let state = Rc::new(RefCell::new(None));
@@ -451,6 +621,10 @@ mod feat_csr_ssr {
                 parent,
 
                 id: COMP_ID_COUNTER.fetch_add(1, Ordering::SeqCst),
+                #[cfg(debug_assertions)]
+                strict,
+                #[cfg(feature = "ssr")]
+                ssr,
             }
         }
 
@@ -507,6 +681,8 @@ mod feat_csr {
 
     use super::*;
     use crate::dom_bundle::{BSubtree, Bundle, DomSlot, DynamicDomSlot};
+    #[cfg(feature = "tracing")]
+    use crate::html::component::lifecycle::RenderCause;
     use crate::html::component::lifecycle::{
         ComponentRenderState, CreateRunner, DestroyRunner, PropsUpdateRunner, RenderRunner,
     };
@@ -516,9 +692,16 @@ mod feat_csr {
         #[cfg(any(test, feature = "test"))]
         pub(crate) fn test() -> Self {
             Self {
+                #[cfg(feature = "devtools")]
+                id: 0,
                 type_id: TypeId::of::<()>(),
+                type_name: std::any::type_name::<()>(),
                 parent: None,
                 typed_scope: Rc::new(()),
+                #[cfg(debug_assertions)]
+                strict: false,
+                #[cfg(feature = "ssr")]
+                ssr: None,
             }
         }
     }
@@ -541,6 +724,24 @@ mod feat_csr {
     where
         COMP: BaseComponent,
     {
+        /// Creates the scope of the root component of an application.
+        ///
+        /// Strict mode is compiled out of release builds, where `strict` is ignored.
+        pub(crate) fn new_root(strict: bool) -> Self {
+            #[cfg(debug_assertions)]
+            {
+                let mut scope = Self::new(None);
+                scope.strict = strict;
+                scope
+            }
+
+            #[cfg(not(debug_assertions))]
+            {
+                let _ = strict;
+                Self::new(None)
+            }
+        }
+
         /// Mounts a component with `props` to the specified `element` in the DOM.
         pub(crate) fn mount_in_place(
             &self,
@@ -573,6 +774,8 @@ mod feat_csr {
                 }),
                 Box::new(RenderRunner {
                     state: self.state.clone(),
+                    #[cfg(feature = "tracing")]
+                    cause: RenderCause::First,
                 }),
             );
             // Not guaranteed to already have the scheduler started
@@ -643,6 +846,8 @@ mod feat_hydration {
 
     use super::*;
     use crate::dom_bundle::{BSubtree, DynamicDomSlot, Fragment};
+    #[cfg(feature = "tracing")]
+    use crate::html::component::lifecycle::RenderCause;
     use crate::html::component::lifecycle::{ComponentRenderState, CreateRunner, RenderRunner};
     use crate::scheduler;
     use crate::virtual_dom::Collectable;
@@ -711,6 +916,8 @@ mod feat_hydration {
                 }),
                 Box::new(RenderRunner {
                     state: self.state.clone(),
+                    #[cfg(feature = "tracing")]
+                    cause: RenderCause::First,
                 }),
             );
 
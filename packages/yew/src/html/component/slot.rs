@@ -0,0 +1,100 @@
+//! Component slots module
+
+use crate::html::{Html, IntoPropValue};
+
+/// The html given to a named slot of a component.
+///
+/// A component declares a named slot as a property, which callers fill with `<:name>` among the
+/// children of the component in `html!`. The other children fill the `children` property, the
+/// default slot. A property of type [`Html`] can be used for a slot too, but only `Slot` tells an
+/// empty slot from one that was not filled.
+///
+/// # Example
+///
+/// ```
+/// use yew::html::Slot;
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// pub struct CardProps {
+///     #[prop_or_default]
+///     pub header: Slot,
+///     #[prop_or_default]
+///     pub footer: Slot,
+///     #[prop_or_default]
+///     pub children: Html,
+/// }
+///
+/// #[function_component]
+/// fn Card(props: &CardProps) -> Html {
+///     html! {
+///         <div class="card">
+///             <header>{ props.header.html_or(html! { "Untitled" }) }</header>
+///             { props.children.clone() }
+///             if props.footer.is_filled() {
+///                 <footer>{ props.footer.html() }</footer>
+///             }
+///         </div>
+///     }
+/// }
+///
+/// # fn dummy() -> Html {
+/// html! {
+///     <Card>
+///         <:header><h2>{ "Title" }</h2></:header>
+///         <p>{ "Content" }</p>
+///         <:footer>{ "Footer" }</:footer>
+///     </Card>
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Slot(Option<Html>);
+
+impl Slot {
+    /// Returns `true` if the caller filled the slot.
+    pub fn is_filled(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Returns the html of the slot, which is empty if the slot was not filled.
+    pub fn html(&self) -> Html {
+        self.0.clone().unwrap_or_default()
+    }
+
+    /// Returns the html of the slot, or `fallback` if the slot was not filled.
+    pub fn html_or(&self, fallback: Html) -> Html {
+        self.0.clone().unwrap_or(fallback)
+    }
+}
+
+impl<T: IntoPropValue<Html>> IntoPropValue<Slot> for T {
+    #[inline]
+    fn into_prop_value(self) -> Slot {
+        Slot(Some(self.into_prop_value()))
+    }
+}
+
+impl From<Slot> for Html {
+    #[inline]
+    fn from(slot: Slot) -> Self {
+        slot.0.unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html;
+
+    #[test]
+    fn filled_slots() {
+        let slot = Slot::default();
+        assert!(!slot.is_filled());
+        assert_eq!(slot.html_or(html! { "fallback" }), html! { "fallback" });
+
+        let slot: Slot = html! { <></> }.into_prop_value();
+        assert!(slot.is_filled());
+        assert_eq!(slot.html_or(html! { "fallback" }), html! { <></> });
+    }
+}
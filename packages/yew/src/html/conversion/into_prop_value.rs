@@ -6,7 +6,7 @@ use implicit_clone::unsync::{IArray, IMap};
 pub use implicit_clone::ImplicitClone;
 
 use crate::callback::Callback;
-use crate::html::{BaseComponent, ChildrenRenderer, Component, NodeRef, Scope};
+use crate::html::{BaseComponent, ChildrenRenderer, Component, NodeRef, Scope, TypedNodeRef};
 use crate::virtual_dom::{AttrValue, VChild, VList, VNode, VText};
 
 impl ImplicitClone for NodeRef {}
@@ -140,6 +140,20 @@ where
     }
 }
 
+impl<T> IntoPropValue<NodeRef> for TypedNodeRef<T> {
+    #[inline]
+    fn into_prop_value(self) -> NodeRef {
+        self.into()
+    }
+}
+
+impl<T> IntoPropValue<NodeRef> for &TypedNodeRef<T> {
+    #[inline]
+    fn into_prop_value(self) -> NodeRef {
+        self.node_ref().clone()
+    }
+}
+
 impl IntoPropValue<VNode> for VList {
     #[inline]
     fn into_prop_value(self) -> VNode {
@@ -1,3 +1,6 @@
+use std::fmt;
+use std::rc::Rc;
+
 use thiserror::Error;
 
 use crate::suspense::Suspension;
@@ -8,6 +11,90 @@ pub enum RenderError {
     /// Component Rendering Suspended
     #[error("component rendering is suspended.")]
     Suspended(#[from] Suspension),
+    /// Component Rendering Failed
+    #[error(transparent)]
+    Failed(#[from] ComponentError),
+}
+
+/// An error that made a component fail, caught by the nearest
+/// [`ErrorBoundary`](crate::error_boundary::ErrorBoundary).
+///
+/// Components report errors by returning them from `view`, for example with
+/// `.map_err(ComponentError::new)?` in a function component. Panics are reported as well on
+/// targets where they unwind.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{message}")]
+pub struct ComponentError {
+    message: Rc<str>,
+    component: Option<&'static str>,
+    panicked: bool,
+}
+
+impl ComponentError {
+    /// Creates an error with the message of `error`.
+    pub fn new(error: impl fmt::Display) -> Self {
+        Self {
+            message: error.to_string().into(),
+            component: None,
+            panicked: false,
+        }
+    }
+
+    /// Creates an error from the payload of a caught panic.
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    pub(crate) fn from_panic(payload: Box<dyn std::any::Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(m) => m.as_str().into(),
+            Err(payload) => match payload.downcast_ref::<&str>() {
+                Some(m) => (*m).into(),
+                None => "component panicked".into(),
+            },
+        };
+
+        Self {
+            message,
+            component: None,
+            panicked: true,
+        }
+    }
+
+    /// Sets the name of the component that failed, unless it is already known.
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    pub(crate) fn with_component(mut self, name: &'static str) -> Self {
+        self.component.get_or_insert(name);
+        self
+    }
+
+    /// Returns the message of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the type name of the component that failed.
+    pub fn component(&self) -> Option<&'static str> {
+        self.component
+    }
+
+    /// Returns `true` if the error is a caught panic.
+    pub fn is_panic(&self) -> bool {
+        self.panicked
+    }
+}
+
+/// Error returned when a [`NodeRef`](crate::html::NodeRef) cannot be cast into an element type.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum NodeRefError {
+    /// The reference is not attached to any node.
+    #[error("node reference is not attached to any node.")]
+    Unbound,
+    /// The referenced node is not of the expected type.
+    #[error("expected a node of type `{expected}`, but the reference is attached to `{actual}`.")]
+    TypeMismatch {
+        /// The name of the expected type, such as `HtmlInputElement`.
+        expected: &'static str,
+        /// The lowercase name of the referenced node, such as `div` or `#text`.
+        actual: String,
+    },
 }
 
 /// Render Result.
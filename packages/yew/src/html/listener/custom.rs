@@ -0,0 +1,283 @@
+//! Typed [`CustomEvent`](https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent)s.
+
+use std::fmt;
+use std::ops::Deref;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use wasm_bindgen::JsCast;
+use web_sys::{CustomEventInit, EventTarget};
+
+/// A custom DOM event, whose payload is the value of the implementing type.
+///
+/// The payload is serialized to a plain JavaScript object in the `detail` of the event, so events
+/// can be exchanged with code written in JavaScript or with other frameworks. Events are
+/// dispatched with [`dispatch`](CustomEvent::dispatch) and listened to with the `oncustom`
+/// listener of `html!`, whose callback receives a [`Custom`] event. Which event is listened to is
+/// inferred from the type of the callback, so an element can have several `oncustom` listeners.
+///
+/// This trait requires the `custom_events` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use serde::{Deserialize, Serialize};
+/// use yew::html::{Custom, CustomEvent};
+/// use yew::prelude::*;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct CartUpdated {
+///     items: u32,
+/// }
+///
+/// impl CustomEvent for CartUpdated {
+///     const NAME: &'static str = "cart-updated";
+/// }
+///
+/// #[function_component]
+/// fn AddToCart() -> Html {
+///     let onclick = Callback::from(|e: MouseEvent| {
+///         let target = e.target().unwrap();
+///         CartUpdated { items: 1 }.dispatch(&target);
+///     });
+///     html! { <button {onclick}>{ "Add to cart" }</button> }
+/// }
+///
+/// #[function_component]
+/// fn Shop() -> Html {
+///     let items = use_state(|| 0);
+///     let oncustom = {
+///         let items = items.clone();
+///         Callback::from(move |e: Custom<CartUpdated>| items.set(e.detail().items))
+///     };
+///     html! {
+///         <div {oncustom}>
+///             <span>{ *items }</span>
+///             <AddToCart />
+///         </div>
+///     }
+/// }
+/// ```
+pub trait CustomEvent: Serialize + DeserializeOwned + 'static {
+    /// The type of the event, like `"cart-updated"`.
+    const NAME: &'static str;
+
+    /// Whether the event bubbles up to the ancestors of its target.
+    const BUBBLES: bool = true;
+
+    /// Whether the event propagates from a shadow DOM to the document.
+    const COMPOSED: bool = false;
+
+    /// Dispatches the event on `target`. Returns `false` if a listener called `prevent_default`.
+    ///
+    /// # Panics
+    ///
+    /// If the event can't be serialized.
+    fn dispatch(&self, target: &EventTarget) -> bool {
+        let detail = serde_json::to_string(self).expect("can't serialize the custom event");
+        let init = CustomEventInit::new();
+        init.set_bubbles(Self::BUBBLES);
+        init.set_cancelable(true);
+        init.set_composed(Self::COMPOSED);
+        init.set_detail(&js_sys::JSON::parse(&detail).expect("the detail is valid JSON"));
+        let event = web_sys::CustomEvent::new_with_event_init_dict(Self::NAME, &init)
+            .expect("can't create a custom event");
+        target
+            .dispatch_event(&event)
+            .expect("the event can be dispatched")
+    }
+}
+
+/// A dispatched [`CustomEvent`], received by the `oncustom` listener.
+///
+/// It dereferences to the DOM event, to stop its propagation or read its target.
+pub struct Custom<E> {
+    event: web_sys::CustomEvent,
+    detail: E,
+}
+
+impl<E: CustomEvent> Custom<E> {
+    fn from_event(event: web_sys::Event) -> Option<Self> {
+        let event: web_sys::CustomEvent = event.dyn_into().ok()?;
+        let detail = js_sys::JSON::stringify(&event.detail()).ok()?;
+        let detail = match serde_json::from_str(&String::from(detail)) {
+            Ok(m) => m,
+            Err(e) => {
+                tracing::warn!(%e, event = E::NAME, "failed to decode a custom event");
+                return None;
+            }
+        };
+        Some(Self { event, detail })
+    }
+}
+
+impl<E> Custom<E> {
+    /// Returns the payload of the event.
+    pub fn detail(&self) -> &E {
+        &self.detail
+    }
+
+    /// Returns the payload of the event, consuming it.
+    pub fn into_detail(self) -> E {
+        self.detail
+    }
+}
+
+impl<E> Deref for Custom<E> {
+    type Target = web_sys::CustomEvent;
+
+    fn deref(&self) -> &Self::Target {
+        &self.event
+    }
+}
+
+impl<E> AsRef<web_sys::Event> for Custom<E> {
+    fn as_ref(&self) -> &web_sys::Event {
+        &self.event
+    }
+}
+
+impl<E: fmt::Debug> fmt::Debug for Custom<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Custom")
+            .field("type", &self.event.type_())
+            .field("detail", &self.detail)
+            .finish()
+    }
+}
+
+/// The `oncustom` listener of `html!`.
+#[doc(hidden)]
+pub mod oncustom {
+    use std::rc::Rc;
+
+    use super::{Custom, CustomEvent};
+    use crate::callback::Callback;
+    use crate::html::IntoEventCallback;
+    use crate::virtual_dom::{Listener, ListenerKind};
+
+    /// A wrapper for a callback listening to a custom event.
+    #[derive(Clone, Debug)]
+    pub struct Wrapper {
+        name: &'static str,
+        callback: Callback<web_sys::Event>,
+    }
+
+    impl Wrapper {
+        /// Create a wrapper for a callback receiving the custom event `E`.
+        pub fn new<E: CustomEvent>(callback: Callback<Custom<E>>) -> Self {
+            Wrapper {
+                name: E::NAME,
+                callback: Callback::from(move |event| {
+                    if let Some(event) = Custom::from_event(event) {
+                        callback.emit(event);
+                    }
+                }),
+            }
+        }
+
+        #[doc(hidden)]
+        #[inline]
+        pub fn __macro_new<E: CustomEvent>(
+            callback: impl IntoEventCallback<Custom<E>>,
+        ) -> Option<Rc<dyn Listener>> {
+            let callback = callback.into_event_callback()?;
+            Some(Rc::new(Self::new(callback)))
+        }
+    }
+
+    impl Listener for Wrapper {
+        fn kind(&self) -> ListenerKind {
+            ListenerKind::other(self.name.into())
+        }
+
+        fn handle(&self, event: web_sys::Event) {
+            self.callback.emit(event);
+        }
+
+        fn passive(&self) -> bool {
+            false
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+#[cfg(feature = "csr")]
+#[cfg(test)]
+mod tests {
+    extern crate self as yew;
+
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use serde::Deserialize;
+    use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::tests::TestRenderer;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Selected {
+        id: u32,
+        label: String,
+    }
+
+    impl CustomEvent for Selected {
+        const NAME: &'static str = "selected";
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Cleared;
+
+    impl CustomEvent for Cleared {
+        const NAME: &'static str = "cleared";
+    }
+
+    #[derive(Properties, PartialEq, Default)]
+    struct Props {
+        log: Rc<RefCell<Vec<String>>>,
+    }
+
+    #[function_component]
+    fn Picker(props: &Props) -> Html {
+        let on_selected = {
+            let log = props.log.clone();
+            move |e: Custom<Selected>| {
+                let Selected { id, label } = e.into_detail();
+                log.borrow_mut().push(format!("{id} {label}"));
+            }
+        };
+        let on_cleared = {
+            let log = props.log.clone();
+            move |_: Custom<Cleared>| log.borrow_mut().push("cleared".into())
+        };
+        html! {
+            <div oncustom={on_selected} oncustom={on_cleared}>
+                <span data-testid="option" />
+            </div>
+        }
+    }
+
+    #[test]
+    fn typed_listeners_receive_the_detail() {
+        let props = Props::default();
+        let log = props.log.clone();
+        let renderer = TestRenderer::<Picker>::with_props(props);
+        let option = renderer.get_by_test_id("option");
+
+        Selected {
+            id: 3,
+            label: "three".into(),
+        }
+        .dispatch(&option);
+        Cleared.dispatch(&option);
+        // an event of the same type whose detail doesn't match is ignored
+        let event = web_sys::CustomEvent::new("selected").unwrap();
+        option.dispatch_event(&event).unwrap();
+
+        assert_eq!(*log.borrow(), ["3 three", "cleared"]);
+    }
+}
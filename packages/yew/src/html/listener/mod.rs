@@ -1,6 +1,10 @@
+#[cfg(feature = "custom_events")]
+mod custom;
 #[macro_use]
 mod events;
 
+#[cfg(feature = "custom_events")]
+pub use custom::*;
 pub use events::*;
 use wasm_bindgen::JsCast;
 use web_sys::{Event, EventTarget};
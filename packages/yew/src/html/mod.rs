@@ -5,8 +5,11 @@ mod component;
 mod conversion;
 mod error;
 mod listener;
+mod style;
 
 use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
 use std::rc::Rc;
 
 pub use classes::*;
@@ -14,8 +17,9 @@ pub use component::*;
 pub use conversion::*;
 pub use error::*;
 pub use listener::*;
-use wasm_bindgen::JsValue;
-use web_sys::{Element, Node};
+pub use style::*;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Document, Element, Node, ShadowRoot};
 
 use crate::sealed::Sealed;
 use crate::virtual_dom::{VNode, VPortal};
@@ -123,6 +127,111 @@ impl NodeRef {
         let node = self.get();
         node.map(Into::into).map(INTO::from)
     }
+
+    /// Try converting the node reference into an element of type `T`.
+    ///
+    /// Unlike [`cast`](Self::cast), the type of the node is checked, and the error describes why
+    /// the conversion failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use yew::prelude::*;
+    /// use web_sys::HtmlInputElement;
+    ///
+    /// fn input_value(node_ref: &NodeRef) -> String {
+    ///     match node_ref.cast_into::<HtmlInputElement>() {
+    ///         Ok(input) => input.value(),
+    ///         Err(e) => {
+    ///             gloo::console::error!(e.to_string());
+    ///             String::new()
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn cast_into<T: JsCast>(&self) -> Result<T, NodeRefError> {
+        let node = self.get().ok_or(NodeRefError::Unbound)?;
+        node.dyn_into::<T>()
+            .map_err(|node| NodeRefError::TypeMismatch {
+                expected: short_type_name::<T>(),
+                actual: node.node_name().to_lowercase(),
+            })
+    }
+}
+
+/// Returns the name of `T` without its module path.
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// A [`NodeRef`] that is expected to reference an element of type `T`.
+///
+/// It can be passed to the `ref` attribute like a [`NodeRef`], and is usually obtained with
+/// [`use_node_ref_typed`](crate::functional::use_node_ref_typed).
+pub struct TypedNodeRef<T> {
+    node_ref: NodeRef,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: JsCast> TypedNodeRef<T> {
+    /// Returns the referenced element, if it is attached to an element of type `T`.
+    pub fn get(&self) -> Option<T> {
+        self.node_ref.cast_into().ok()
+    }
+
+    /// Returns the referenced element, or an error describing why it is not available.
+    pub fn cast(&self) -> Result<T, NodeRefError> {
+        self.node_ref.cast_into()
+    }
+}
+
+impl<T> TypedNodeRef<T> {
+    /// Returns the untyped reference.
+    pub fn node_ref(&self) -> &NodeRef {
+        &self.node_ref
+    }
+}
+
+impl<T> Default for TypedNodeRef<T> {
+    fn default() -> Self {
+        Self {
+            node_ref: NodeRef::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for TypedNodeRef<T> {
+    fn clone(&self) -> Self {
+        Self {
+            node_ref: self.node_ref.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> PartialEq for TypedNodeRef<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node_ref == other.node_ref
+    }
+}
+
+impl<T> fmt::Debug for TypedNodeRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "TypedNodeRef<{}> {{ {:?} }}",
+            short_type_name::<T>(),
+            self.node_ref
+        )
+    }
+}
+
+impl<T> From<TypedNodeRef<T>> for NodeRef {
+    fn from(typed: TypedNodeRef<T>) -> Self {
+        typed.node_ref
+    }
 }
 
 #[cfg(feature = "csr")]
@@ -137,10 +246,78 @@ mod feat_csr {
     }
 }
 
+/// The DOM node a portal renders its children into, see [`create_portal`].
+///
+/// Besides an [`Element`], a portal can target a [`ShadowRoot`] or the body of another
+/// [`Document`], for example the one of a popup window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortalHost(Element);
+
+impl PortalHost {
+    /// The attribute marking the container element created in shadow roots.
+    const SHADOW_CONTAINER: &'static str = "data-yew-portal";
+
+    /// Returns the element under which the children are inserted.
+    pub fn element(&self) -> &Element {
+        &self.0
+    }
+}
+
+impl From<Element> for PortalHost {
+    fn from(host: Element) -> Self {
+        Self(host)
+    }
+}
+
+impl From<ShadowRoot> for PortalHost {
+    /// Shadow roots are not elements, so the children are inserted into a container with
+    /// `display: contents`, created in the shadow root the first time it is used as a host.
+    fn from(root: ShadowRoot) -> Self {
+        let children = root.children();
+        let existing = (0..children.length())
+            .filter_map(|i| children.item(i))
+            .find(|m| m.has_attribute(Self::SHADOW_CONTAINER));
+        if let Some(host) = existing {
+            return Self(host);
+        }
+
+        let document = root
+            .owner_document()
+            .expect("shadow roots belong to a document");
+        let host = document
+            .create_element("div")
+            .expect("can't create portal container");
+        host.set_attribute(Self::SHADOW_CONTAINER, "")
+            .expect("can't mark portal container");
+        host.set_attribute("style", "display: contents")
+            .expect("can't style portal container");
+        root.append_child(&host)
+            .expect("can't append portal container");
+        Self(host)
+    }
+}
+
+impl From<Document> for PortalHost {
+    /// Renders into the body of the document, or its root element if it has no body.
+    fn from(document: Document) -> Self {
+        let host = match document.body() {
+            Some(body) => body.into(),
+            None => document
+                .document_element()
+                .expect("document has no element to render into"),
+        };
+        Self(host)
+    }
+}
+
 /// Render children into a DOM node that exists outside the hierarchy of the parent
 /// component.
+///
+/// The host can be an [`Element`], a [`ShadowRoot`] or another [`Document`], see
+/// [`PortalHost`]. Events fired inside the portal bubble to the listeners of the component tree
+/// that created it.
 /// ## Relevant examples
 /// - [Portals](https://github.com/yewstack/yew/tree/master/examples/portals)
-pub fn create_portal(child: Html, host: Element) -> Html {
-    VNode::VPortal(Rc::new(VPortal::new(child, host)))
+pub fn create_portal(child: Html, host: impl Into<PortalHost>) -> Html {
+    VNode::VPortal(Rc::new(VPortal::new(child, host.into().0)))
 }
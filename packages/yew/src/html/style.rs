@@ -0,0 +1,253 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::rc::Rc;
+
+use implicit_clone::ImplicitClone;
+use indexmap::IndexMap;
+
+use super::IntoPropValue;
+use crate::virtual_dom::AttrValue;
+
+/// A set of inline style declarations, cheap to clone.
+///
+/// It maps CSS properties to their values and can be given to the `style` attribute of an element.
+/// When the element is updated, only the properties that changed are written to the DOM.
+///
+/// ```
+/// use yew::html::{Style, StyleUnit};
+/// use yew::prelude::*;
+///
+/// let width: u32 = 120;
+/// let style = Style::new()
+///     .with("width", width.px())
+///     .with("margin", "0 auto")
+///     .with("opacity", 0.5.to_string());
+/// html! { <div {style} /> };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Style {
+    map: Rc<IndexMap<AttrValue, AttrValue>>,
+}
+
+impl ImplicitClone for Style {}
+
+impl Style {
+    /// Creates an empty set of declarations. (Does not allocate.)
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a property and returns the style, to chain declarations.
+    pub fn with(
+        mut self,
+        property: impl Into<AttrValue>,
+        value: impl IntoPropValue<AttrValue>,
+    ) -> Self {
+        self.set(property, value);
+        self
+    }
+
+    /// Sets a property, replacing its previous value.
+    pub fn set(&mut self, property: impl Into<AttrValue>, value: impl IntoPropValue<AttrValue>) {
+        Rc::make_mut(&mut self.map).insert(property.into(), value.into_prop_value());
+    }
+
+    /// Removes a property, returning its value.
+    pub fn remove(&mut self, property: &str) -> Option<AttrValue> {
+        if !self.map.contains_key(property) {
+            return None;
+        }
+        Rc::make_mut(&mut self.map).shift_remove(property)
+    }
+
+    /// Returns the value of a property.
+    #[inline]
+    pub fn get(&self, property: &str) -> Option<&AttrValue> {
+        self.map.get(property)
+    }
+
+    /// Check there are no declarations.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns an iterator over the properties and their values, in the order they were set.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.map.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (property, value)) in self.iter().enumerate() {
+            if i != 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{property}: {value};")?;
+        }
+        Ok(())
+    }
+}
+
+impl IntoPropValue<AttrValue> for Style {
+    #[inline]
+    fn into_prop_value(self) -> AttrValue {
+        self.to_string().into()
+    }
+}
+
+impl IntoPropValue<Option<AttrValue>> for Style {
+    #[inline]
+    fn into_prop_value(self) -> Option<AttrValue> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.into_prop_value())
+        }
+    }
+}
+
+impl<K: Into<AttrValue>, V: IntoPropValue<AttrValue>> Extend<(K, V)> for Style {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        iter.into_iter()
+            .for_each(|(property, value)| self.set(property, value))
+    }
+}
+
+impl<K: Into<AttrValue>, V: IntoPropValue<AttrValue>> FromIterator<(K, V)> for Style {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut style = Self::new();
+        style.extend(iter);
+        style
+    }
+}
+
+/// Iterates over the declarations of an inline style, like `width: 10px; color: red`.
+///
+/// Semicolons inside parentheses or quotes, like in `url("data:image/png;base64,...")`, don't end
+/// a declaration. Declarations without a `:` are skipped.
+#[cfg(any(feature = "csr", test))]
+pub(crate) fn parse_declarations(style: &str) -> impl Iterator<Item = (&str, &str)> {
+    let mut rest = style;
+    std::iter::from_fn(move || loop {
+        if rest.is_empty() {
+            return None;
+        }
+        let mut depth = 0_usize;
+        let mut quote = None;
+        let mut end = rest.len();
+        for (i, c) in rest.char_indices() {
+            match (quote, c) {
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), _) => {}
+                (None, '"' | '\'') => quote = Some(c),
+                (None, '(') => depth += 1,
+                (None, ')') => depth = depth.saturating_sub(1),
+                (None, ';') if depth == 0 => {
+                    end = i;
+                    break;
+                }
+                _ => {}
+            }
+        }
+        let declaration = &rest[..end];
+        rest = rest.get(end + 1..).unwrap_or_default();
+        if let Some((property, value)) = declaration.split_once(':') {
+            return Some((property.trim(), value.trim()));
+        }
+    })
+}
+
+/// Appends a CSS unit to a number, giving the value of a [`Style`] property.
+///
+/// ```
+/// use yew::html::StyleUnit;
+///
+/// assert_eq!(12_u32.px(), "12px");
+/// assert_eq!(1.5_f64.rem(), "1.5rem");
+/// assert_eq!(50_u8.percent(), "50%");
+/// ```
+pub trait StyleUnit: fmt::Display + Sized {
+    /// Pixels.
+    fn px(self) -> AttrValue {
+        format!("{self}px").into()
+    }
+
+    /// Relative to the font size of the element.
+    fn em(self) -> AttrValue {
+        format!("{self}em").into()
+    }
+
+    /// Relative to the font size of the root element.
+    fn rem(self) -> AttrValue {
+        format!("{self}rem").into()
+    }
+
+    /// Percentage.
+    fn percent(self) -> AttrValue {
+        format!("{self}%").into()
+    }
+
+    /// Relative to 1% of the width of the viewport.
+    fn vw(self) -> AttrValue {
+        format!("{self}vw").into()
+    }
+
+    /// Relative to 1% of the height of the viewport.
+    fn vh(self) -> AttrValue {
+        format!("{self}vh").into()
+    }
+
+    /// Degrees.
+    fn deg(self) -> AttrValue {
+        format!("{self}deg").into()
+    }
+
+    /// Milliseconds.
+    fn ms(self) -> AttrValue {
+        format!("{self}ms").into()
+    }
+}
+
+macro_rules! impl_style_unit {
+    ($($ty:ty)*) => {$(
+        impl StyleUnit for $ty {}
+    )*};
+}
+
+impl_style_unit!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize f32 f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_declarations_in_order() {
+        let style = Style::new()
+            .with("width", 10_u32.px())
+            .with("color", "red")
+            .with("width", 2.5_f32.em());
+        let value: AttrValue = style.into_prop_value();
+        assert_eq!(value, "width: 2.5em; color: red;");
+    }
+
+    #[test]
+    fn empty_style_is_omitted() {
+        let mut style = Style::from_iter([("z-index", "1")]);
+        assert_eq!(style.remove("z-index").as_deref(), Some("1"));
+        let value: Option<AttrValue> = style.into_prop_value();
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    fn parses_declarations() {
+        let declarations = parse_declarations("width: 10px;background:url(\"a;b.png\") ; ;broken")
+            .collect::<Vec<_>>();
+        assert_eq!(
+            declarations,
+            [("width", "10px"), ("background", "url(\"a;b.png\")")]
+        );
+    }
+}
@@ -0,0 +1,695 @@
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub use intl_pluralrules::PluralCategory;
+use intl_pluralrules::{PluralRuleType, PluralRules as IntlPluralRules};
+use unic_langid::LanguageIdentifier;
+
+use super::use_translation;
+use crate::functional::{hook, use_memo};
+use crate::virtual_dom::AttrValue;
+
+/// The style of a formatted number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberStyle {
+    /// Plain decimal number, e.g. `1,234.5`.
+    #[default]
+    Decimal,
+    /// The number is multiplied by 100 and rendered with a percent sign, e.g. `25%`.
+    Percent,
+}
+
+/// Options for [`NumberFormatter`].
+///
+/// These mirror the options of the same name of `Intl.NumberFormat`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberFormatOptions {
+    /// The style of the number.
+    pub style: NumberStyle,
+    /// The minimum number of fraction digits. Defaults to `0`.
+    pub minimum_fraction_digits: Option<u8>,
+    /// The maximum number of fraction digits.
+    /// Defaults to `3` for decimals and `0` for percentages.
+    pub maximum_fraction_digits: Option<u8>,
+    /// Whether to use grouping separators. Defaults to `true`.
+    pub use_grouping: bool,
+}
+
+impl Default for NumberFormatOptions {
+    fn default() -> Self {
+        Self {
+            style: NumberStyle::default(),
+            minimum_fraction_digits: None,
+            maximum_fraction_digits: None,
+            use_grouping: true,
+        }
+    }
+}
+
+impl NumberFormatOptions {
+    fn fraction_digits(&self) -> (usize, usize) {
+        let default_max = match self.style {
+            NumberStyle::Decimal => 3,
+            NumberStyle::Percent => 0,
+        };
+        let min = self.minimum_fraction_digits.unwrap_or(0) as usize;
+        let max = self.maximum_fraction_digits.unwrap_or(default_max) as usize;
+
+        (min, max.max(min))
+    }
+}
+
+/// The length of a formatted date or time.
+///
+/// These mirror the `dateStyle` and `timeStyle` options of `Intl.DateTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeStyle {
+    /// e.g. `1/15/24` and `3:04 PM`
+    Short,
+    /// e.g. `Jan 15, 2024` and `3:04:05 PM`
+    Medium,
+    /// e.g. `January 15, 2024` and `3:04:05 PM UTC`
+    Long,
+}
+
+/// Options for [`DateTimeFormatter`].
+///
+/// Times are always formatted in UTC so that the server and the client produce the same output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateTimeFormatOptions {
+    /// The style of the date, the date is omitted if `None`.
+    pub date_style: Option<DateTimeStyle>,
+    /// The style of the time, the time is omitted if `None`.
+    pub time_style: Option<DateTimeStyle>,
+}
+
+impl Default for DateTimeFormatOptions {
+    fn default() -> Self {
+        Self {
+            date_style: Some(DateTimeStyle::Medium),
+            time_style: None,
+        }
+    }
+}
+
+/// The conventions of the languages the formatters know.
+///
+/// The patterns follow CLDR, so that the output matches `Intl` for `en-US`, `de-DE` and `fr-FR`.
+struct LocaleData {
+    group: &'static str,
+    decimal: &'static str,
+    percent_sep: &'static str,
+    months: [&'static str; 12],
+    months_abbr: [&'static str; 12],
+    twelve_hour: bool,
+    long_date_time_sep: &'static str,
+    date_time_sep: &'static str,
+}
+
+const EN: LocaleData = LocaleData {
+    group: ",",
+    decimal: ".",
+    percent_sep: "",
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    months_abbr: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    twelve_hour: true,
+    long_date_time_sep: " at ",
+    date_time_sep: ", ",
+};
+
+const DE: LocaleData = LocaleData {
+    group: ".",
+    decimal: ",",
+    percent_sep: "\u{a0}",
+    months: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    months_abbr: [
+        "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+        "Dez.",
+    ],
+    twelve_hour: false,
+    long_date_time_sep: " um ",
+    date_time_sep: ", ",
+};
+
+const FR: LocaleData = LocaleData {
+    group: "\u{202f}",
+    decimal: ",",
+    percent_sep: "\u{202f}",
+    months: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    months_abbr: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    twelve_hour: false,
+    long_date_time_sep: " à ",
+    date_time_sep: " ",
+};
+
+impl LocaleData {
+    fn for_locale(locale: &LanguageIdentifier) -> &'static Self {
+        match locale.language.as_str() {
+            "de" => &DE,
+            "fr" => &FR,
+            _ => &EN,
+        }
+    }
+
+    fn format_date(&self, language: &str, style: DateTimeStyle, y: i64, m: u32, d: u32) -> String {
+        let month = (m - 1) as usize;
+        match (language, style) {
+            ("de", DateTimeStyle::Short) => format!("{d:02}.{m:02}.{:02}", y.rem_euclid(100)),
+            ("de", DateTimeStyle::Medium) => format!("{d:02}.{m:02}.{y}"),
+            ("de", DateTimeStyle::Long) => format!("{d}. {} {y}", self.months[month]),
+            ("fr", DateTimeStyle::Short) => format!("{d:02}/{m:02}/{y}"),
+            ("fr", DateTimeStyle::Medium) => format!("{d} {} {y}", self.months_abbr[month]),
+            ("fr", DateTimeStyle::Long) => format!("{d} {} {y}", self.months[month]),
+            (_, DateTimeStyle::Short) => format!("{m}/{d}/{:02}", y.rem_euclid(100)),
+            (_, DateTimeStyle::Medium) => format!("{} {d}, {y}", self.months_abbr[month]),
+            (_, DateTimeStyle::Long) => format!("{} {d}, {y}", self.months[month]),
+        }
+    }
+
+    fn format_time(&self, style: DateTimeStyle, h: u32, min: u32, s: u32) -> String {
+        let (h, suffix) = match self.twelve_hour {
+            true => {
+                let suffix = if h < 12 { "\u{202f}AM" } else { "\u{202f}PM" };
+                (format!("{}", (h + 11) % 12 + 1), suffix)
+            }
+            false => (format!("{h:02}"), ""),
+        };
+
+        match style {
+            DateTimeStyle::Short => format!("{h}:{min:02}{suffix}"),
+            DateTimeStyle::Medium => format!("{h}:{min:02}:{s:02}{suffix}"),
+            DateTimeStyle::Long => format!("{h}:{min:02}:{s:02}{suffix} UTC"),
+        }
+    }
+}
+
+struct NumberFormatterInner {
+    locale: LanguageIdentifier,
+    options: NumberFormatOptions,
+}
+
+/// Formats numbers according to the conventions of a locale.
+///
+/// Numbers are formatted by the same Rust implementation on the server and in the browser, so
+/// that server-side rendered pages hydrate with identical strings. It follows the conventions of
+/// English, German and French, and falls back to English conventions for other languages.
+#[derive(Clone)]
+pub struct NumberFormatter {
+    inner: Rc<NumberFormatterInner>,
+}
+
+impl NumberFormatter {
+    /// Creates a new formatter.
+    pub fn new(locale: LanguageIdentifier, options: NumberFormatOptions) -> Self {
+        Self {
+            inner: Rc::new(NumberFormatterInner { locale, options }),
+        }
+    }
+
+    /// Returns the locale of this formatter.
+    pub fn locale(&self) -> &LanguageIdentifier {
+        &self.inner.locale
+    }
+
+    /// Formats a number.
+    pub fn format(&self, value: impl Into<f64>) -> AttrValue {
+        let value = value.into();
+        let NumberFormatterInner { locale, options } = &*self.inner;
+        let data = LocaleData::for_locale(locale);
+
+        let value = match options.style {
+            NumberStyle::Decimal => value,
+            NumberStyle::Percent => value * 100.0,
+        };
+
+        let mut out = String::new();
+        if value.is_nan() {
+            out.push_str("NaN");
+        } else {
+            let (min, max) = options.fraction_digits();
+            // Intl rounds half away from zero, as does `f64::round`.
+            let scaled = (value.abs() * 10f64.powi(max as i32)).round();
+            let digits = format!("{scaled:.0}");
+            let digits = match digits.len() <= max {
+                true => format!("{}{digits}", "0".repeat(max + 1 - digits.len())),
+                false => digits,
+            };
+
+            if value.is_sign_negative() && scaled != 0.0 {
+                out.push('-');
+            }
+
+            if value.is_infinite() {
+                out.push('∞');
+            } else {
+                let (int, frac) = digits.split_at(digits.len() - max);
+                let frac = frac.trim_end_matches('0');
+
+                for (i, c) in int.chars().enumerate() {
+                    if options.use_grouping && i > 0 && (int.len() - i) % 3 == 0 {
+                        out.push_str(data.group);
+                    }
+                    out.push(c);
+                }
+
+                if min > 0 || !frac.is_empty() {
+                    out.push_str(data.decimal);
+                    out.push_str(frac);
+                    out.extend(std::iter::repeat('0').take(min.saturating_sub(frac.len())));
+                }
+            }
+        }
+
+        if options.style == NumberStyle::Percent {
+            out.push_str(data.percent_sep);
+            out.push('%');
+        }
+
+        AttrValue::from(out)
+    }
+}
+
+impl PartialEq for NumberFormatter {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl std::fmt::Debug for NumberFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NumberFormatter")
+            .field("locale", &self.inner.locale)
+            .field("options", &self.inner.options)
+            .finish()
+    }
+}
+
+struct DateTimeFormatterInner {
+    locale: LanguageIdentifier,
+    options: DateTimeFormatOptions,
+}
+
+/// Formats dates and times according to the conventions of a locale.
+///
+/// Dates are formatted by the same Rust implementation on the server and in the browser, so that
+/// server-side rendered pages hydrate with identical strings. It follows the conventions of
+/// English, German and French, and falls back to English conventions for other languages.
+#[derive(Clone)]
+pub struct DateTimeFormatter {
+    inner: Rc<DateTimeFormatterInner>,
+}
+
+impl DateTimeFormatter {
+    /// Creates a new formatter.
+    pub fn new(locale: LanguageIdentifier, options: DateTimeFormatOptions) -> Self {
+        Self {
+            inner: Rc::new(DateTimeFormatterInner { locale, options }),
+        }
+    }
+
+    /// Returns the locale of this formatter.
+    pub fn locale(&self) -> &LanguageIdentifier {
+        &self.inner.locale
+    }
+
+    /// Formats a point in time.
+    pub fn format(&self, time: SystemTime) -> AttrValue {
+        let DateTimeFormatterInner { locale, options } = &*self.inner;
+        let data = LocaleData::for_locale(locale);
+
+        let secs = match time.duration_since(UNIX_EPOCH) {
+            Ok(m) => m.as_secs() as i64,
+            Err(e) => -(e.duration().as_secs_f64().ceil() as i64),
+        };
+        let (y, m, d) = civil_from_days(secs.div_euclid(86_400));
+        let secs_of_day = secs.rem_euclid(86_400) as u32;
+
+        let date = options
+            .date_style
+            .map(|style| data.format_date(locale.language.as_str(), style, y, m, d));
+        let time = options.time_style.map(|style| {
+            data.format_time(
+                style,
+                secs_of_day / 3600,
+                secs_of_day / 60 % 60,
+                secs_of_day % 60,
+            )
+        });
+
+        match (date, time) {
+            (Some(date), Some(time)) => {
+                let sep = match options.date_style {
+                    Some(DateTimeStyle::Long) => data.long_date_time_sep,
+                    _ => data.date_time_sep,
+                };
+                format!("{date}{sep}{time}")
+            }
+            (Some(m), None) | (None, Some(m)) => m,
+            (None, None) => String::new(),
+        }
+        .into()
+    }
+}
+
+impl PartialEq for DateTimeFormatter {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl std::fmt::Debug for DateTimeFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DateTimeFormatter")
+            .field("locale", &self.inner.locale)
+            .field("options", &self.inner.options)
+            .finish()
+    }
+}
+
+/// Selects the plural category of a number for a locale.
+///
+/// Fluent already applies these rules to selectors such as `{ $count -> [one] ... }`, this type
+/// is useful when plural forms have to be chosen outside of Fluent messages.
+///
+/// The rules are implemented in Rust and behave identically on the server and in the browser.
+#[derive(Clone)]
+pub struct PluralRules {
+    locale: LanguageIdentifier,
+    rules: Option<Rc<IntlPluralRules>>,
+}
+
+impl PluralRules {
+    /// Creates plural rules for cardinal numbers of the given locale.
+    ///
+    /// If the locale is not supported, every number is assigned to [`PluralCategory::OTHER`].
+    pub fn new(locale: LanguageIdentifier) -> Self {
+        let rules = IntlPluralRules::create(locale.clone(), PluralRuleType::CARDINAL)
+            .map(Rc::new)
+            .ok();
+
+        Self { locale, rules }
+    }
+
+    /// Returns the locale of these rules.
+    pub fn locale(&self) -> &LanguageIdentifier {
+        &self.locale
+    }
+
+    /// Returns the plural category of `n`.
+    pub fn select(&self, n: impl Into<f64>) -> PluralCategory {
+        let n = n.into();
+
+        self.rules
+            .as_ref()
+            .and_then(|m| m.select(n).ok())
+            .unwrap_or(PluralCategory::OTHER)
+    }
+}
+
+impl PartialEq for PluralRules {
+    fn eq(&self, other: &Self) -> bool {
+        self.locale == other.locale
+    }
+}
+
+impl std::fmt::Debug for PluralRules {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PluralRules")
+            .field("locale", &self.locale)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Converts days since the unix epoch into a (year, month, day) tuple of the proleptic gregorian
+/// calendar.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = yoe + era * 400 + i64::from(m <= 2);
+
+    (y, m, d)
+}
+
+/// Returns a [`NumberFormatter`] for the locale of the closest
+/// [`LocaleProvider`](super::LocaleProvider).
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// use yew::i18n::{use_format_number, NumberFormatOptions, NumberStyle};
+///
+/// #[function_component]
+/// fn Progress() -> Html {
+///     let percent = use_format_number(NumberFormatOptions {
+///         style: NumberStyle::Percent,
+///         ..Default::default()
+///     });
+///
+///     html! { <span>{ percent.format(0.42) }</span> }
+/// }
+/// ```
+#[hook]
+pub fn use_format_number(options: NumberFormatOptions) -> NumberFormatter {
+    let locale = use_translation().locale().clone();
+
+    (*use_memo((locale, options), |(locale, options)| {
+        NumberFormatter::new(locale.clone(), options.clone())
+    }))
+    .clone()
+}
+
+/// Returns a [`DateTimeFormatter`] for the locale of the closest
+/// [`LocaleProvider`](super::LocaleProvider).
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// use std::time::SystemTime;
+///
+/// use yew::i18n::{use_format_datetime, DateTimeFormatOptions, DateTimeStyle};
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///     published: SystemTime,
+/// }
+///
+/// #[function_component]
+/// fn Published(props: &Props) -> Html {
+///     let date = use_format_datetime(DateTimeFormatOptions {
+///         date_style: Some(DateTimeStyle::Long),
+///         time_style: None,
+///     });
+///
+///     html! { <time>{ date.format(props.published) }</time> }
+/// }
+/// ```
+#[hook]
+pub fn use_format_datetime(options: DateTimeFormatOptions) -> DateTimeFormatter {
+    let locale = use_translation().locale().clone();
+
+    (*use_memo((locale, options), |(locale, options)| {
+        DateTimeFormatter::new(locale.clone(), options.clone())
+    }))
+    .clone()
+}
+
+/// Returns the [`PluralRules`] for the locale of the closest
+/// [`LocaleProvider`](super::LocaleProvider).
+#[hook]
+pub fn use_plural_rules() -> PluralRules {
+    let locale = use_translation().locale().clone();
+
+    (*use_memo(locale, |locale| PluralRules::new(locale.clone()))).clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use unic_langid::langid;
+
+    use super::*;
+
+    fn number(locale: LanguageIdentifier, options: NumberFormatOptions, value: f64) -> String {
+        NumberFormatter::new(locale, options)
+            .format(value)
+            .to_string()
+    }
+
+    fn datetime(locale: LanguageIdentifier, options: DateTimeFormatOptions, secs: u64) -> String {
+        DateTimeFormatter::new(locale, options)
+            .format(UNIX_EPOCH + Duration::from_secs(secs))
+            .to_string()
+    }
+
+    #[test]
+    fn formats_decimals() {
+        let options = NumberFormatOptions::default;
+
+        assert_eq!(
+            number(langid!("en-US"), options(), 1234567.891),
+            "1,234,567.891"
+        );
+        assert_eq!(
+            number(langid!("de-DE"), options(), 1234567.891),
+            "1.234.567,891"
+        );
+        assert_eq!(
+            number(langid!("fr-FR"), options(), 1234567.891),
+            "1\u{202f}234\u{202f}567,891"
+        );
+        assert_eq!(number(langid!("en-US"), options(), -0.0001), "0");
+        assert_eq!(number(langid!("en-US"), options(), -12.5), "-12.5");
+        assert_eq!(number(langid!("en-US"), options(), 999.9999), "1,000");
+    }
+
+    #[test]
+    fn formats_fraction_digits_and_grouping() {
+        let options = NumberFormatOptions {
+            minimum_fraction_digits: Some(2),
+            maximum_fraction_digits: Some(2),
+            use_grouping: false,
+            ..Default::default()
+        };
+
+        assert_eq!(number(langid!("en-US"), options.clone(), 1234.5), "1234.50");
+        assert_eq!(number(langid!("en-US"), options.clone(), 0.125), "0.13");
+        assert_eq!(number(langid!("de-DE"), options, 0.0), "0,00");
+    }
+
+    #[test]
+    fn formats_percentages() {
+        let options = || NumberFormatOptions {
+            style: NumberStyle::Percent,
+            ..Default::default()
+        };
+
+        assert_eq!(number(langid!("en-US"), options(), 0.256), "26%");
+        assert_eq!(number(langid!("de-DE"), options(), 0.256), "26\u{a0}%");
+        assert_eq!(number(langid!("fr-FR"), options(), 0.256), "26\u{202f}%");
+    }
+
+    #[test]
+    fn formats_dates_and_times() {
+        // 2024-01-15T15:04:05Z
+        const TIME: u64 = 1_705_331_045;
+        let options = |date_style, time_style| DateTimeFormatOptions {
+            date_style,
+            time_style,
+        };
+        let short = Some(DateTimeStyle::Short);
+        let medium = Some(DateTimeStyle::Medium);
+        let long = Some(DateTimeStyle::Long);
+
+        assert_eq!(
+            datetime(langid!("en-US"), options(short, None), TIME),
+            "1/15/24"
+        );
+        assert_eq!(
+            datetime(langid!("en-US"), options(medium, None), TIME),
+            "Jan 15, 2024"
+        );
+        assert_eq!(
+            datetime(langid!("en-US"), options(medium, short), TIME),
+            "Jan 15, 2024, 3:04\u{202f}PM"
+        );
+        assert_eq!(
+            datetime(langid!("en-US"), options(long, medium), TIME),
+            "January 15, 2024 at 3:04:05\u{202f}PM"
+        );
+        assert_eq!(
+            datetime(langid!("de-DE"), options(None, long), TIME),
+            "15:04:05 UTC"
+        );
+        assert_eq!(
+            datetime(langid!("de-DE"), options(short, None), TIME),
+            "15.01.24"
+        );
+        assert_eq!(
+            datetime(langid!("de-DE"), options(medium, short), TIME),
+            "15.01.2024, 15:04"
+        );
+        assert_eq!(
+            datetime(langid!("de-DE"), options(long, None), TIME),
+            "15. Januar 2024"
+        );
+        assert_eq!(
+            datetime(langid!("fr-FR"), options(medium, medium), TIME),
+            "15 janv. 2024 15:04:05"
+        );
+        assert_eq!(
+            datetime(langid!("en-US"), options(None, short), 0),
+            "12:00\u{202f}AM"
+        );
+    }
+
+    #[test]
+    fn converts_days_to_civil_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    }
+
+    #[test]
+    fn selects_plural_categories() {
+        let en = PluralRules::new(langid!("en"));
+        assert_eq!(en.select(1), PluralCategory::ONE);
+        assert_eq!(en.select(2), PluralCategory::OTHER);
+
+        let pl = PluralRules::new(langid!("pl"));
+        assert_eq!(pl.select(3), PluralCategory::FEW);
+        assert_eq!(pl.select(5), PluralCategory::MANY);
+    }
+}
@@ -0,0 +1,91 @@
+//! Internationalization support backed by [Project Fluent](https://projectfluent.org).
+//!
+//! Wrap (a part of) your application in a [`LocaleProvider`] and pass it the Fluent resources
+//! for the active locale. Components below the provider can then look up messages with the
+//! [`use_translation`] hook, either directly or through the [`t!`](crate::t) macro.
+//!
+//! Numbers and dates can be formatted for the active locale with [`use_format_number`] and
+//! [`use_format_datetime`], plural categories are available through [`use_plural_rules`].
+//!
+//! Resources can be embedded at compile time with [`FluentSource::Static`] (usually together
+//! with [`include_str!`]) or fetched at runtime from a URL with [`FluentSource::Url`].
+//!
+//! The locale can be negotiated among the ones the application is translated to with
+//! [`use_negotiated_locale`], from the `Accept-Language` header of the request during server-side
+//! rendering and from the preferences of the browser otherwise.
+//!
+//! # Example
+//!
+//! ```
+//! use yew::i18n::{langid, use_translation, FluentSource, LocaleProvider};
+//! use yew::prelude::*;
+//!
+//! const EN_US: &str = "hello = Hello, { $name }!";
+//!
+//! #[function_component]
+//! fn Greeting() -> Html {
+//!     let tr = use_translation();
+//!
+//!     html! { <p>{ yew::t!(tr, "hello", name = "Yew") }</p> }
+//! }
+//!
+//! #[function_component]
+//! fn App() -> Html {
+//!     html! {
+//!         <LocaleProvider locale={langid!("en-US")} sources={vec![FluentSource::Static(EN_US)]}>
+//!             <Greeting />
+//!         </LocaleProvider>
+//!     }
+//! }
+//! ```
+
+mod format;
+mod negotiate;
+mod provider;
+mod translator;
+
+#[doc(no_inline)]
+pub use fluent_bundle::{FluentArgs, FluentValue};
+pub use format::*;
+pub use negotiate::*;
+pub use provider::*;
+pub use translator::*;
+#[doc(no_inline)]
+pub use unic_langid::{langid, LanguageIdentifier};
+
+/// Translates a message with a [`Translator`].
+///
+/// The first argument is the translator (usually obtained from [`use_translation`]), the second
+/// one the message id. Message attributes can be selected with `"message.attribute"`.
+/// Any further `name = value` pairs are passed to Fluent as arguments.
+///
+/// The macro evaluates to an [`AttrValue`](crate::virtual_dom::AttrValue) so it can be used
+/// directly inside of [`html!`](crate::html).
+///
+/// # Example
+///
+/// ```
+/// # use yew::i18n::{langid, Translator};
+/// let tr = Translator::from_resources(
+///     langid!("en-US"),
+///     ["unread = You have { $count } unread messages."],
+/// );
+///
+/// assert_eq!(
+///     yew::t!(tr, "unread", count = 3),
+///     "You have \u{2068}3\u{2069} unread messages."
+/// );
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($translator:expr, $id:expr $(,)?) => {
+        $translator.translate($id)
+    };
+    ($translator:expr, $id:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::i18n::FluentArgs::new();
+        $(
+            args.set(::std::stringify!($name), $value);
+        )+
+        $translator.translate_with($id, ::std::option::Option::Some(&args))
+    }};
+}
@@ -0,0 +1,239 @@
+use unic_langid::LanguageIdentifier;
+
+use crate::functional::{hook, use_memo};
+use crate::virtual_dom::AttrValue;
+
+/// Parses the value of an `Accept-Language` header into the requested locales, most preferred
+/// first.
+///
+/// Locales are ordered by their quality value, keeping the order of the header for equal values.
+/// The wildcard, locales with a quality of `0` and invalid entries are skipped.
+///
+/// # Example
+///
+/// ```
+/// use yew::i18n::{langid, parse_accept_language};
+///
+/// assert_eq!(
+///     parse_accept_language("fr;q=0.8, en-US, *;q=0.5"),
+///     [langid!("en-US"), langid!("fr")]
+/// );
+/// ```
+pub fn parse_accept_language(header: &str) -> Vec<LanguageIdentifier> {
+    let mut requested: Vec<(f32, LanguageIdentifier)> = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';').map(str::trim);
+            let tag = parts.next().filter(|m| *m != "*")?;
+            let quality = parts
+                .find_map(|m| m.strip_prefix("q="))
+                .map_or(Some(1.0), |m| m.parse::<f32>().ok())?;
+            if quality <= 0.0 {
+                return None;
+            }
+
+            Some((quality, tag.parse().ok()?))
+        })
+        .collect();
+    // The sort is stable, so the order of the header is kept for equal qualities.
+    requested.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+
+    requested.into_iter().map(|(_, m)| m).collect()
+}
+
+/// Picks the locale of `available` best matching the `requested` locales, most preferred first.
+///
+/// The requested locales are tried in order, and each of them is matched against the available
+/// ones from the closest to the loosest match:
+///
+/// 1. the same locale,
+/// 2. a more general locale, like `en` for `en-GB`,
+/// 3. a more specific locale, like `en-US` for `en`,
+/// 4. a locale of the same language, like `en-US` for `en-GB`.
+///
+/// The first available locale is used when none matches, and the undetermined locale if there
+/// isn't any.
+///
+/// # Example
+///
+/// ```
+/// use yew::i18n::{langid, negotiate_locale};
+///
+/// let available = [langid!("en-US"), langid!("fr"), langid!("de-DE")];
+///
+/// assert_eq!(
+///     negotiate_locale(&[langid!("fr-CA"), langid!("en")], &available),
+///     langid!("fr")
+/// );
+/// assert_eq!(negotiate_locale(&[langid!("ja")], &available), langid!("en-US"));
+/// ```
+pub fn negotiate_locale(
+    requested: &[LanguageIdentifier],
+    available: &[LanguageIdentifier],
+) -> LanguageIdentifier {
+    let tiers: [fn(&LanguageIdentifier, &LanguageIdentifier) -> bool; 4] = [
+        |a, r| a == r,
+        |a, r| a.matches(r, true, false),
+        |a, r| r.matches(a, true, false),
+        |a, r| a.language == r.language,
+    ];
+
+    requested
+        .iter()
+        .find_map(|r| {
+            tiers
+                .iter()
+                .find_map(|matches| available.iter().find(|a| matches(a, r)))
+        })
+        .or_else(|| available.first())
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Returns the locales preferred by the user in the browser settings, most preferred first, from
+/// `navigator.languages`.
+///
+/// Outside of the browser, no locale is returned.
+pub fn browser_locales() -> Vec<LanguageIdentifier> {
+    #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+    {
+        gloo::utils::window()
+            .navigator()
+            .languages()
+            .iter()
+            .filter_map(|m| m.as_string()?.parse().ok())
+            .collect()
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "wasi"))))]
+    Vec::new()
+}
+
+/// Negotiates the locale of the application among the `available` ones, see
+/// [`negotiate_locale`].
+///
+/// The requested locales are parsed from `accept_language`, the `Accept-Language` header of the
+/// request, when it is passed, and are the [preferred locales of the browser](browser_locales)
+/// otherwise.
+///
+/// During server-side rendering, pass the header of the request, usually through the properties
+/// of the application. In the browser, pass `None`: browsers send their preferred locales in the
+/// header, so the page is usually hydrated in the locale it was rendered in on the server. When it
+/// must always be, pass the header the server received to the client too, like through a value
+/// embedded in the page.
+///
+/// # Example
+///
+/// ```
+/// use yew::i18n::{langid, use_negotiated_locale, FluentSource, LocaleProvider};
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct AppProps {
+///     /// The `Accept-Language` header on the server, `None` in the browser.
+///     accept_language: Option<AttrValue>,
+///     children: Html,
+/// }
+///
+/// #[function_component]
+/// fn App(props: &AppProps) -> Html {
+///     let locale = use_negotiated_locale(
+///         vec![langid!("en-US"), langid!("fr-FR")],
+///         props.accept_language.clone(),
+///     );
+///     let sources = vec![FluentSource::Url(format!("/locales/{locale}.ftl").into())];
+///
+///     html! {
+///         <LocaleProvider {locale} {sources}>
+///             { props.children.clone() }
+///         </LocaleProvider>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_negotiated_locale(
+    available: Vec<LanguageIdentifier>,
+    accept_language: Option<AttrValue>,
+) -> LanguageIdentifier {
+    let locale = use_memo(
+        (available, accept_language),
+        |(available, accept_language)| {
+            let requested = match accept_language {
+                Some(m) => parse_accept_language(m),
+                None => browser_locales(),
+            };
+
+            negotiate_locale(&requested, available)
+        },
+    );
+
+    (*locale).clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use unic_langid::langid;
+
+    use super::*;
+
+    #[test]
+    fn parses_accept_language() {
+        assert_eq!(
+            parse_accept_language("de-CH, fr;q=0.9, en;q=0.9, *;q=0.1"),
+            [langid!("de-CH"), langid!("fr"), langid!("en")]
+        );
+        assert_eq!(
+            parse_accept_language("en;q=0, de; q=0.5, not a locale, it;q=x"),
+            [langid!("de")]
+        );
+        assert!(parse_accept_language("").is_empty());
+    }
+
+    #[test]
+    fn negotiates_closest_locale() {
+        let available = [langid!("en-US"), langid!("en"), langid!("pt-BR")];
+
+        assert_eq!(
+            negotiate_locale(&[langid!("en")], &available),
+            langid!("en")
+        );
+        assert_eq!(
+            negotiate_locale(&[langid!("en-GB")], &available),
+            langid!("en")
+        );
+        assert_eq!(
+            negotiate_locale(&[langid!("pt")], &available),
+            langid!("pt-BR")
+        );
+        assert_eq!(
+            negotiate_locale(&[langid!("pt-PT")], &available),
+            langid!("pt-BR")
+        );
+    }
+
+    #[test]
+    fn earlier_requested_locales_take_precedence() {
+        let available = [langid!("en-US"), langid!("de"), langid!("fr")];
+
+        assert_eq!(
+            negotiate_locale(
+                &[langid!("it"), langid!("fr-CH"), langid!("de")],
+                &available
+            ),
+            langid!("fr")
+        );
+    }
+
+    #[test]
+    fn falls_back_to_first_available_locale() {
+        assert_eq!(
+            negotiate_locale(&[langid!("ja")], &[langid!("de"), langid!("fr")]),
+            langid!("de")
+        );
+        assert_eq!(negotiate_locale(&[], &[langid!("fr")]), langid!("fr"));
+        assert_eq!(
+            negotiate_locale(&[langid!("ja")], &[]),
+            LanguageIdentifier::default()
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use unic_langid::LanguageIdentifier;
+
+use super::{FluentSource, Translator};
+use crate::context::ContextProvider;
+use crate::functional::{hook, use_context, use_effect_with, use_memo, use_state};
+use crate::html::{Html, Properties};
+use crate::{function_component, html};
+
+type Deps = Rc<(LanguageIdentifier, Vec<FluentSource>)>;
+
+/// Props for [`LocaleProvider`].
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct LocaleProviderProps {
+    /// The active locale.
+    pub locale: LanguageIdentifier,
+    /// Fluent resources of the active locale.
+    ///
+    /// Messages defined in later sources take precedence over messages with the same id in
+    /// earlier sources.
+    #[prop_or_default]
+    pub sources: Vec<FluentSource>,
+    /// Children
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Provides a [`Translator`] to its children.
+///
+/// Static sources are available immediately (including during server-side rendering). Sources
+/// that need to be fetched are loaded after the provider has been rendered, the children are
+/// re-rendered once all of them have been loaded.
+#[function_component]
+pub fn LocaleProvider(props: &LocaleProviderProps) -> Html {
+    let LocaleProviderProps {
+        locale,
+        sources,
+        children,
+    } = props.clone();
+
+    let deps: Deps = Rc::new((locale, sources));
+    let loaded = use_state(|| None::<(Deps, Translator)>);
+
+    let static_translator = use_memo(deps.clone(), |deps| {
+        let (locale, sources) = &**deps;
+        let resources = sources.iter().filter_map(|m| match m {
+            FluentSource::Static(m) => Some(*m),
+            FluentSource::Url(_) => None,
+        });
+
+        Translator::from_resources(locale.clone(), resources)
+    });
+
+    {
+        let loaded = loaded.clone();
+        use_effect_with(deps.clone(), move |deps| {
+            let cancelled = Rc::new(Cell::new(false));
+
+            if deps.1.iter().any(|m| matches!(m, FluentSource::Url(_))) {
+                let deps = deps.clone();
+                let cancelled = cancelled.clone();
+
+                crate::platform::spawn_local(async move {
+                    let mut resources = Vec::with_capacity(deps.1.len());
+                    for source in deps.1.iter() {
+                        match source {
+                            FluentSource::Static(m) => resources.push((*m).to_owned()),
+                            FluentSource::Url(url) => match fetch_resource(url).await {
+                                Ok(m) => resources.push(m),
+                                Err(e) => {
+                                    tracing::warn!(%url, %e, "failed to fetch fluent resource")
+                                }
+                            },
+                        }
+                    }
+
+                    if !cancelled.get() {
+                        let translator = Translator::from_resources(deps.0.clone(), resources);
+                        loaded.set(Some((deps, translator)));
+                    }
+                });
+            }
+
+            move || cancelled.set(true)
+        });
+    }
+
+    // Only use fetched resources if they belong to the current locale and sources.
+    let translator = match &*loaded {
+        Some((loaded_deps, translator)) if *loaded_deps == deps => translator.clone(),
+        _ => (*static_translator).clone(),
+    };
+
+    html! {
+        <ContextProvider<Translator> context={translator}>
+            {children}
+        </ContextProvider<Translator>>
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+async fn fetch_resource(url: &str) -> Result<String, gloo::net::Error> {
+    gloo::net::http::Request::get(url)
+        .send()
+        .await?
+        .text()
+        .await
+}
+
+#[cfg(not(all(target_arch = "wasm32", not(target_os = "wasi"))))]
+async fn fetch_resource(_url: &str) -> Result<String, &'static str> {
+    Err("fetching fluent resources is only supported in the browser")
+}
+
+/// Returns the [`Translator`] of the closest [`LocaleProvider`].
+///
+/// If the component is not rendered inside of a [`LocaleProvider`], a translator without any
+/// messages is returned and every message id is rendered as is.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// use yew::i18n::use_translation;
+///
+/// #[function_component]
+/// fn LoginButton() -> Html {
+///     let tr = use_translation();
+///
+///     html! { <button>{ tr.translate("login") }</button> }
+/// }
+/// ```
+#[hook]
+pub fn use_translation() -> Translator {
+    use_context::<Translator>().unwrap_or_default()
+}
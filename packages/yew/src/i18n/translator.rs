@@ -0,0 +1,209 @@
+use std::fmt;
+use std::rc::Rc;
+
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+use crate::virtual_dom::AttrValue;
+
+/// A source of Fluent messages for a [`LocaleProvider`](super::LocaleProvider).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FluentSource {
+    /// A resource that is available at compile time, usually embedded with [`include_str!`].
+    Static(&'static str),
+    /// A resource that is fetched from the given URL after the provider has been rendered.
+    ///
+    /// Messages of this resource are unavailable until the request has completed. Fetching is
+    /// only supported in the browser, remote resources are skipped during server-side rendering.
+    Url(AttrValue),
+}
+
+struct TranslatorInner {
+    locale: LanguageIdentifier,
+    bundle: FluentBundle<FluentResource>,
+}
+
+/// Looks up and formats messages for a single locale.
+///
+/// A `Translator` is cheap to clone. Function components obtain the translator of the closest
+/// [`LocaleProvider`](super::LocaleProvider) with [`use_translation`](super::use_translation).
+#[derive(Clone)]
+pub struct Translator {
+    inner: Rc<TranslatorInner>,
+}
+
+impl Translator {
+    /// Creates a translator for `locale` without any messages.
+    pub fn new(locale: LanguageIdentifier) -> Self {
+        Self::from_resources(locale, Vec::<String>::new())
+    }
+
+    /// Creates a translator for `locale` from the given Fluent resources.
+    ///
+    /// Messages defined in later resources take precedence over messages with the same id in
+    /// earlier resources. Syntax errors are reported as warnings and the offending entries are
+    /// skipped, the rest of the resource is still used.
+    pub fn from_resources<I, S>(locale: LanguageIdentifier, resources: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut bundle = FluentBundle::new(vec![locale.clone()]);
+
+        for resource in resources {
+            let resource = match FluentResource::try_new(resource.into()) {
+                Ok(m) => m,
+                Err((m, errors)) => {
+                    tracing::warn!(%locale, ?errors, "failed to parse fluent resource");
+                    m
+                }
+            };
+
+            bundle.add_resource_overriding(resource);
+        }
+
+        Self {
+            inner: Rc::new(TranslatorInner { locale, bundle }),
+        }
+    }
+
+    /// Returns the locale of this translator.
+    pub fn locale(&self) -> &LanguageIdentifier {
+        &self.inner.locale
+    }
+
+    /// Returns `true` if a message (or message attribute) with the given id exists.
+    pub fn has_message(&self, id: &str) -> bool {
+        let (message_id, attribute) = split_id(id);
+
+        match self.inner.bundle.get_message(message_id) {
+            Some(m) => match attribute {
+                Some(attribute) => m.get_attribute(attribute).is_some(),
+                None => m.value().is_some(),
+            },
+            None => false,
+        }
+    }
+
+    /// Formats the message with the given id.
+    ///
+    /// See [`translate_with`](Self::translate_with) for details.
+    pub fn translate(&self, id: &str) -> AttrValue {
+        self.translate_with(id, None)
+    }
+
+    /// Formats the message with the given id and arguments.
+    ///
+    /// An attribute of a message can be selected with `"message.attribute"`. If the message
+    /// cannot be found, the id itself is returned so missing translations remain visible.
+    pub fn translate_with(&self, id: &str, args: Option<&FluentArgs<'_>>) -> AttrValue {
+        let (message_id, attribute) = split_id(id);
+        let bundle = &self.inner.bundle;
+
+        let pattern = bundle
+            .get_message(message_id)
+            .and_then(|m| match attribute {
+                Some(attribute) => m.get_attribute(attribute).map(|m| m.value()),
+                None => m.value(),
+            });
+
+        let pattern = match pattern {
+            Some(m) => m,
+            None => {
+                tracing::warn!(locale = %self.inner.locale, id, "missing fluent message");
+                return AttrValue::from(id.to_owned());
+            }
+        };
+
+        let mut errors = Vec::new();
+        let value = bundle.format_pattern(pattern, args, &mut errors);
+
+        if !errors.is_empty() {
+            tracing::warn!(locale = %self.inner.locale, id, ?errors, "failed to format fluent message");
+        }
+
+        AttrValue::from(value.into_owned())
+    }
+}
+
+impl Default for Translator {
+    fn default() -> Self {
+        Self::new(LanguageIdentifier::default())
+    }
+}
+
+impl PartialEq for Translator {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl fmt::Debug for Translator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Translator")
+            .field("locale", &self.inner.locale)
+            .finish_non_exhaustive()
+    }
+}
+
+fn split_id(id: &str) -> (&str, Option<&str>) {
+    match id.split_once('.') {
+        Some((message_id, attribute)) => (message_id, Some(attribute)),
+        None => (id, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use unic_langid::langid;
+
+    use super::*;
+
+    const EN_US: &str = r#"
+hello = Hello, world!
+greeting = Hello, { $name }!
+login = Log in
+    .placeholder = Your e-mail
+"#;
+
+    fn translator() -> Translator {
+        Translator::from_resources(langid!("en-US"), [EN_US])
+    }
+
+    #[test]
+    fn translates_messages() {
+        let tr = translator();
+
+        assert_eq!(tr.translate("hello"), "Hello, world!");
+        assert_eq!(tr.translate("login"), "Log in");
+        assert_eq!(tr.translate("login.placeholder"), "Your e-mail");
+    }
+
+    #[test]
+    fn translates_with_arguments() {
+        let tr = translator();
+
+        assert_eq!(
+            crate::t!(tr, "greeting", name = "Yew"),
+            "Hello, \u{2068}Yew\u{2069}!"
+        );
+    }
+
+    #[test]
+    fn missing_messages_fall_back_to_id() {
+        let tr = translator();
+
+        assert!(!tr.has_message("goodbye"));
+        assert!(!tr.has_message("login.title"));
+        assert!(tr.has_message("login.placeholder"));
+        assert_eq!(tr.translate("goodbye"), "goodbye");
+    }
+
+    #[test]
+    fn later_resources_take_precedence() {
+        let tr = Translator::from_resources(langid!("en-US"), [EN_US, "hello = Howdy!"]);
+
+        assert_eq!(tr.translate("hello"), "Howdy!");
+        assert_eq!(tr.translate("login"), "Log in");
+    }
+}
@@ -0,0 +1,276 @@
+//! This module contains the [`Image`] component.
+
+use crate::functional::{function_component, hook, use_force_update, use_memo, Hook, HookContext};
+use crate::html;
+use crate::html::{Classes, HtmlResult, Properties};
+use crate::platform::spawn_local;
+use crate::suspense::Suspension;
+use crate::virtual_dom::AttrValue;
+
+/// When the browser loads an [`Image`], set as its `loading` attribute.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImageLoading {
+    /// The image is loaded right away.
+    #[default]
+    Eager,
+    /// The image is loaded when it gets close to the viewport.
+    Lazy,
+}
+
+impl ImageLoading {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Eager => "eager",
+            Self::Lazy => "lazy",
+        }
+    }
+}
+
+/// Properties for [Image].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct ImageProps {
+    /// The url of the image.
+    pub src: AttrValue,
+
+    /// The text describing the image.
+    pub alt: AttrValue,
+
+    /// The candidate images for the different sizes and pixel densities, set as the `srcset`
+    /// attribute.
+    #[prop_or_default]
+    pub srcset: Option<AttrValue>,
+
+    /// The sizes the image is displayed at, set as the `sizes` attribute.
+    #[prop_or_default]
+    pub sizes: Option<AttrValue>,
+
+    /// The width of the image, in pixels.
+    #[prop_or_default]
+    pub width: Option<u32>,
+
+    /// The height of the image, in pixels.
+    #[prop_or_default]
+    pub height: Option<u32>,
+
+    /// When the browser loads the image.
+    #[prop_or_default]
+    pub loading: ImageLoading,
+
+    /// The url of a small preview of the image, shown blurred while the image is decoded instead
+    /// of suspending.
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+
+    /// The classes of the `<img>`.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// The sources of an image, which are decoded together.
+#[derive(Debug, Clone, PartialEq)]
+struct ImageSource {
+    src: AttrValue,
+    srcset: Option<AttrValue>,
+    sizes: Option<AttrValue>,
+}
+
+/// Renders an `<img>` once its image is decoded, so that it never shows partially.
+///
+/// While the image is decoded with [`HTMLImageElement.decode()`], the component suspends and the
+/// fallback of the nearest [`Suspense`](crate::suspense::Suspense) is shown. With a
+/// [`placeholder`](ImageProps::placeholder), the placeholder is shown blurred in place of the
+/// image instead, and replaced by the image once it is decoded. The image is decoded again when
+/// its sources change.
+///
+/// During server-side rendering, the `<img>` is rendered right away with its `srcset`, `sizes`
+/// and `loading` attributes, and the browser loads it as usual. The image is not decoded again
+/// when the page is hydrated.
+///
+/// # Example
+///
+/// ```
+/// use yew::image::{Image, ImageLoading};
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Avatar() -> Html {
+///     html! {
+///         <Suspense fallback={html! { <div class="avatar-skeleton" /> }}>
+///             <Image
+///                 src="/avatar.png"
+///                 srcset="/avatar.png 1x, /avatar@2x.png 2x"
+///                 alt="Avatar"
+///                 width={64}
+///                 height={64}
+///                 loading={ImageLoading::Lazy}
+///             />
+///         </Suspense>
+///     }
+/// }
+/// ```
+///
+/// [`HTMLImageElement.decode()`]: https://developer.mozilla.org/en-US/docs/Web/API/HTMLImageElement/decode
+#[function_component]
+pub fn Image(props: &ImageProps) -> HtmlResult {
+    let ImageProps {
+        src,
+        alt,
+        srcset,
+        sizes,
+        width,
+        height,
+        loading,
+        placeholder,
+        class,
+    } = props.clone();
+    let width = width.map(|m| AttrValue::from(m.to_string()));
+    let height = height.map(|m| AttrValue::from(m.to_string()));
+
+    let decoding = use_decoding(ImageSource {
+        src: src.clone(),
+        srcset: srcset.clone(),
+        sizes: sizes.clone(),
+    });
+
+    match (decoding, placeholder) {
+        (Some(_), Some(placeholder)) => Ok(html! {
+            <img
+                src={placeholder}
+                {alt}
+                {width}
+                {height}
+                {class}
+                style="filter: blur(16px);"
+            />
+        }),
+        (Some(suspension), None) => Err(suspension.into()),
+        (None, _) => Ok(html! {
+            <img
+                {src}
+                {srcset}
+                {sizes}
+                {alt}
+                {width}
+                {height}
+                {class}
+                loading={loading.as_str()}
+                decoding="async"
+            />
+        }),
+    }
+}
+
+/// Decodes the image of `source`, returning the suspension resumed once it is decoded, or `None`
+/// if it already is.
+#[hook]
+fn use_decoding(source: ImageSource) -> Option<Suspension> {
+    let pre_rendered = use_pre_rendered();
+    // The image rendered by the server, which the browser already loads.
+    let first = use_memo((), |_| source.clone());
+    let force_update = use_force_update();
+
+    let suspension = use_memo(source, move |source| {
+        if pre_rendered && *first == *source {
+            return None;
+        }
+
+        let (suspension, handle) = Suspension::new();
+        let source = source.clone();
+        spawn_local(async move {
+            decode(&source).await;
+            handle.resume();
+            // Renders the image in place of the placeholder, which doesn't suspend.
+            force_update.force_update();
+        });
+
+        Some(suspension)
+    });
+
+    match *suspension {
+        Some(ref m) if !m.resumed() => Some(m.clone()),
+        _ => None,
+    }
+}
+
+/// Returns whether the component is rendered on the server, or hydrated.
+fn use_pre_rendered() -> impl Hook<Output = bool> {
+    struct HookProvider;
+
+    impl Hook for HookProvider {
+        type Output = bool;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            #[cfg(feature = "ssr")]
+            if ctx.scope.ssr.is_some() {
+                return true;
+            }
+            #[cfg(feature = "hydration")]
+            if ctx.creation_mode == crate::html::RenderMode::Hydration {
+                return true;
+            }
+
+            let _ = ctx;
+            false
+        }
+    }
+
+    HookProvider
+}
+
+/// Decodes the image of `source`. Images that fail to load are rendered anyway, broken.
+#[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+async fn decode(source: &ImageSource) {
+    let Ok(image) = web_sys::HtmlImageElement::new() else {
+        return;
+    };
+    if let Some(ref srcset) = source.srcset {
+        image.set_srcset(srcset);
+    }
+    if let Some(ref sizes) = source.sizes {
+        image.set_sizes(sizes);
+    }
+    image.set_src(&source.src);
+
+    let _ = wasm_bindgen_futures::JsFuture::from(image.decode()).await;
+}
+
+#[cfg(not(all(target_arch = "wasm32", not(target_os = "wasi"))))]
+async fn decode(_source: &ImageSource) {}
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::LocalServerRenderer as ServerRenderer;
+
+    #[test]
+    async fn renders_image_attributes() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <Suspense fallback={html! { "loading" }}>
+                    <Image
+                        src="/a.png"
+                        srcset="/a.png 1x, /a@2x.png 2x"
+                        alt="A"
+                        width={64}
+                        loading={ImageLoading::Lazy}
+                        placeholder="/a-small.png"
+                    />
+                </Suspense>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<img src="/a.png" srcset="/a.png 1x, /a@2x.png 2x" alt="A" width="64" loading="lazy" decoding="async">"#
+        );
+    }
+}
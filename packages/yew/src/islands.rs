@@ -0,0 +1,297 @@
+//! This module provides partial hydration, where only some components of a server side rendered
+//! page are hydrated.
+//!
+//! A page made mostly of static content can be rendered with
+//! [`hydratable(false)`](crate::ServerRenderer::hydratable), so that none of it is hydrated, and
+//! wrap the few interactive components in an [`Island`]. The HTML of an island is rendered
+//! hydratable, along with the serialized properties of its component, and [`Islands`] hydrates
+//! each of them on the client as a separate application. The rest of the page never runs on the
+//! client.
+//!
+//! In a page rendered hydratable, an [`Island`] renders its component like any other one.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::html::{BaseComponent, Component, Context, Html, Properties};
+use crate::virtual_dom::VComp;
+
+/// Properties for [Island].
+#[derive(Debug, Properties, PartialEq)]
+pub struct IslandProps<P: Properties> {
+    /// The properties of the component, which are sent to the client to hydrate it.
+    pub props: P,
+}
+
+/// Renders a component that is hydrated on its own on the client.
+///
+/// The component is identified by its type name, so the server and the client must be built from
+/// the same sources.
+///
+/// # Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use yew::islands::Island;
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq, Clone, Serialize, Deserialize)]
+/// pub struct CounterProps {
+///     pub start: i32,
+/// }
+///
+/// #[function_component]
+/// pub fn Counter(props: &CounterProps) -> Html {
+///     let count = use_state(|| props.start);
+///     let onclick = {
+///         let count = count.clone();
+///         Callback::from(move |_| count.set(*count + 1))
+///     };
+///     html! { <button {onclick}>{ *count }</button> }
+/// }
+///
+/// #[function_component]
+/// fn Article() -> Html {
+///     html! {
+///         <article>
+///             <p>{ "Rendered on the server only." }</p>
+///             <Island<Counter> props={CounterProps { start: 0 }} />
+///         </article>
+///     }
+/// }
+/// ```
+pub struct Island<COMP>
+where
+    COMP: BaseComponent,
+{
+    _marker: PhantomData<COMP>,
+}
+
+impl<COMP> fmt::Debug for Island<COMP>
+where
+    COMP: BaseComponent,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Island<_>")
+    }
+}
+
+impl<COMP> Component for Island<COMP>
+where
+    COMP: BaseComponent,
+    COMP::Properties: Clone + Serialize + DeserializeOwned,
+{
+    type Message = ();
+    type Properties = IslandProps<COMP::Properties>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = &ctx.props().props;
+        #[cfg(feature = "ssr")]
+        if let Some(ref ssr) = ctx.link().ssr {
+            let name = std::any::type_name::<COMP>();
+            ssr.set_island(ctx.link().id, name, feat_ssr::encode(props));
+        }
+        VComp::new::<COMP>(Rc::new(props.clone()), None).into()
+    }
+}
+
+#[cfg(feature = "ssr")]
+mod feat_ssr {
+    use base64ct::{Base64, Encoding};
+    use serde::Serialize;
+
+    use crate::functional::encode_state;
+
+    pub(super) fn encode<P: Serialize>(props: &P) -> String {
+        let props = encode_state(props).expect("failed to serialize island properties");
+
+        Base64::encode_string(&props)
+    }
+}
+
+#[cfg(feature = "hydration")]
+mod feat_hydration {
+    use std::any::type_name;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    use wasm_bindgen::JsCast;
+    use web_sys::Element;
+
+    use super::*;
+    use crate::functional::decode_state;
+    use crate::renderer::on_first_interaction;
+    use crate::Renderer;
+
+    type Hydrate = Rc<dyn Fn(Element, &[u8])>;
+
+    /// Hydrates the [`Island`]s of a server side rendered page.
+    ///
+    /// Every component rendered in an island must be registered, as its type is not known from
+    /// the HTML alone. Islands of components that are not registered are left static.
+    ///
+    /// ```no_run
+    /// # use yew::prelude::*;
+    /// # use serde::{Deserialize, Serialize};
+    /// # #[derive(Properties, PartialEq, Clone, Serialize, Deserialize)]
+    /// # pub struct CounterProps {}
+    /// # #[function_component]
+    /// # fn Counter(_props: &CounterProps) -> Html { Html::default() }
+    /// use yew::islands::Islands;
+    ///
+    /// Islands::new().register::<Counter>().hydrate();
+    /// ```
+    #[derive(Default)]
+    pub struct Islands {
+        hydrators: HashMap<&'static str, Hydrate>,
+    }
+
+    impl fmt::Debug for Islands {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_set().entries(self.hydrators.keys()).finish()
+        }
+    }
+
+    impl Islands {
+        /// Creates an empty set of island components.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Registers a component rendered in islands.
+        pub fn register<COMP>(mut self) -> Self
+        where
+            COMP: BaseComponent,
+            COMP::Properties: DeserializeOwned,
+        {
+            let hydrate: Hydrate = Rc::new(|root: Element, props: &[u8]| {
+                let props = decode_state::<COMP::Properties>(props)
+                    .expect("failed to deserialize island properties");
+                Renderer::<COMP>::with_root_and_props(root, props).hydrate();
+            });
+            self.hydrators.insert(type_name::<COMP>(), hydrate);
+
+            self
+        }
+
+        /// Hydrates all the islands of the document, returning how many were hydrated.
+        pub fn hydrate(self) -> usize {
+            let islands = self.islands();
+            let hydrated = islands.len();
+            for (island, hydrate, props) in islands {
+                hydrate(island, &props);
+            }
+
+            hydrated
+        }
+
+        /// Hydrates each island of the document once the user first interacts with it, returning
+        /// how many islands are hydrated.
+        ///
+        /// See [`Renderer::hydrate_on_interaction`] for more information.
+        pub fn hydrate_on_interaction(self) -> usize {
+            let islands = self.islands();
+            let hydrated = islands.len();
+            for (island, hydrate, props) in islands {
+                on_first_interaction(&island.clone(), move || hydrate(island, &props));
+            }
+
+            hydrated
+        }
+
+        /// Returns the islands of the document whose component is registered, along with their
+        /// decoded properties.
+        fn islands(&self) -> Vec<(Element, Hydrate, Vec<u8>)> {
+            let islands = gloo::utils::document()
+                .query_selector_all("yew-island")
+                .expect("failed to query islands");
+
+            let mut found = Vec::new();
+            for i in 0..islands.length() {
+                let Some(island) = islands.get(i).and_then(|m| m.dyn_into::<Element>().ok()) else {
+                    continue;
+                };
+                let hydrate = island
+                    .get_attribute("data-component")
+                    .and_then(|name| self.hydrators.get(name.as_str()).cloned());
+                let props = island
+                    .get_attribute("data-props")
+                    .and_then(|props| gloo::utils::window().atob(&props).ok());
+                if let (Some(hydrate), Some(props)) = (hydrate, props) {
+                    // `atob` decodes to a string of one character per byte.
+                    let props: Vec<u8> = props.chars().map(|c| c as u8).collect();
+                    found.push((island, hydrate, props));
+                }
+            }
+
+            found
+        }
+    }
+}
+
+#[cfg(feature = "hydration")]
+pub use feat_hydration::*;
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use std::any::type_name;
+
+    use serde::{Deserialize, Serialize};
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::LocalServerRenderer;
+
+    #[derive(Properties, PartialEq, Clone, Serialize, Deserialize)]
+    struct CounterProps {
+        start: i32,
+    }
+
+    #[function_component]
+    fn Counter(props: &CounterProps) -> Html {
+        html! { <button>{ props.start }</button> }
+    }
+
+    #[function_component]
+    fn Page() -> Html {
+        html! {
+            <main>
+                <p>{"static"}</p>
+                <Island<Counter> props={CounterProps { start: 3 }} />
+            </main>
+        }
+    }
+
+    #[test]
+    async fn renders_islands_hydratable() {
+        let s = LocalServerRenderer::<Page>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        let (page, island) = s.split_once("<yew-island").unwrap();
+        assert_eq!(page, "<main><p>static</p>");
+        assert!(island.starts_with(&format!(
+            r#" data-component="{}" data-props="{}">"#,
+            type_name::<Counter>(),
+            feat_ssr::encode(&CounterProps { start: 3 }),
+        )));
+        // The component of the island is rendered with its hydration markers.
+        assert!(island.contains("<!--<[") && island.contains("<button>3</button>"));
+        assert!(island.ends_with("</yew-island></main>"));
+
+        let s = LocalServerRenderer::<Page>::new().render().await;
+        assert!(!s.contains("yew-island"));
+    }
+}
@@ -0,0 +1,199 @@
+//! This module provides caching of hidden subtrees.
+
+use web_sys::Element;
+
+use crate::html::{create_portal, Component, Context, Html, NodeRef, Properties};
+use crate::virtual_dom::AttrValue;
+use crate::{html, Classes};
+
+/// Properties for [KeepAlive].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct KeepAliveProps {
+    /// Identifies the children, such as the name of the current tab or route.
+    ///
+    /// Children shown under another `active` value before are cached, and shown again with their
+    /// state once it comes back.
+    pub active: AttrValue,
+
+    /// The maximum number of subtrees kept, including the shown one.
+    #[prop_or(10)]
+    pub max: usize,
+
+    /// The class of the `<div>` the children are shown in.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// The Children of the current KeepAlive Component.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// A cached subtree.
+#[derive(Debug)]
+struct Entry {
+    active: AttrValue,
+    children: Html,
+    /// The element the subtree is rendered into, outside of the document while hidden. There is
+    /// none outside of the browser, where only the shown children are rendered.
+    host: Option<Element>,
+}
+
+/// Keeps the state of its previous children, for example the content of tabs or routes.
+///
+/// Whenever [`active`](KeepAliveProps::active) changes, the children are not destroyed but
+/// detached from the document with their component state, and attached again once `active` comes
+/// back to the value they were shown with. Hidden children are not rendered again while hidden,
+/// but their effects and timers keep running.
+///
+/// Once more than [`max`](KeepAliveProps::max) subtrees are kept, the least recently shown one
+/// is destroyed.
+///
+/// # Example
+///
+/// ```
+/// use yew::keep_alive::KeepAlive;
+/// use yew::prelude::*;
+///
+/// # #[function_component]
+/// # fn Inbox() -> Html { Html::default() }
+/// # #[function_component]
+/// # fn Settings() -> Html { Html::default() }
+/// #[function_component]
+/// fn Tabs() -> Html {
+///     let tab = use_state(|| AttrValue::from("inbox"));
+///     let select = |name: &'static str| {
+///         let tab = tab.clone();
+///         Callback::from(move |_| tab.set(name.into()))
+///     };
+///
+///     html! {
+///         <>
+///             <button onclick={select("inbox")}>{ "Inbox" }</button>
+///             <button onclick={select("settings")}>{ "Settings" }</button>
+///             <KeepAlive active={(*tab).clone()} max={5}>
+///                 if *tab == "inbox" { <Inbox /> } else { <Settings /> }
+///             </KeepAlive>
+///         </>
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct KeepAlive {
+    /// The subtrees kept, from the least to the most recently shown.
+    entries: Vec<Entry>,
+    container: NodeRef,
+}
+
+impl KeepAlive {
+    /// Caches the current children, evicting the least recently shown subtrees over the limit.
+    fn show(&mut self, props: &KeepAliveProps) {
+        let entry = match self.entries.iter().position(|m| m.active == props.active) {
+            Some(i) => self.entries.remove(i),
+            None => Entry {
+                active: props.active.clone(),
+                children: Html::default(),
+                host: feat_browser::create_host(),
+            },
+        };
+        self.entries.push(Entry {
+            children: props.children.clone(),
+            ..entry
+        });
+
+        let evicted = self.entries.len().saturating_sub(props.max.max(1));
+        self.entries.drain(..evicted);
+    }
+}
+
+impl Component for KeepAlive {
+    type Message = ();
+    type Properties = KeepAliveProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let mut this = Self {
+            entries: Vec::new(),
+            container: NodeRef::default(),
+        };
+        this.show(ctx.props());
+        this
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.show(ctx.props());
+        true
+    }
+
+    #[cfg(feature = "csr")]
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        let Some(container) = self.container.cast::<Element>() else {
+            return;
+        };
+
+        for entry in &self.entries {
+            let Some(ref host) = entry.host else {
+                continue;
+            };
+
+            if entry.active == ctx.props().active {
+                if host.parent_element().as_ref() != Some(&container) {
+                    let _ = container.append_child(host);
+                }
+            } else {
+                host.remove();
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let KeepAliveProps { active, class, .. } = ctx.props();
+
+        // Without hosts, the shown children are rendered in place.
+        let inline = self
+            .entries
+            .iter()
+            .find(|m| m.host.is_none() && &m.active == active)
+            .map(|m| m.children.clone());
+        let portals = self.entries.iter().filter_map(|m| {
+            let host = m.host.clone()?;
+            let children = html! { <key={m.active.to_string()}>{ m.children.clone() }</> };
+            Some(create_portal(children, host))
+        });
+
+        html! {
+            <>
+                <div ref={&self.container} class={class.clone()} style="display: contents;">
+                    { inline }
+                </div>
+                <>{ for portals }</>
+            </>
+        }
+    }
+}
+
+#[cfg(all(
+    target_arch = "wasm32",
+    not(target_os = "wasi"),
+    not(feature = "not_browser_env")
+))]
+mod feat_browser {
+    use web_sys::Element;
+
+    pub(super) fn create_host() -> Option<Element> {
+        let host = gloo::utils::document().create_element("div").ok()?;
+        host.set_attribute("style", "display: contents;").ok()?;
+        Some(host)
+    }
+}
+
+#[cfg(any(
+    not(target_arch = "wasm32"),
+    target_os = "wasi",
+    feature = "not_browser_env"
+))]
+mod feat_browser {
+    use web_sys::Element;
+
+    pub(super) fn create_host() -> Option<Element> {
+        None
+    }
+}
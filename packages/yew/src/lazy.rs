@@ -0,0 +1,269 @@
+//! This module provides lazy loading of components.
+//!
+//! A lazy component is only loaded when it is first rendered, so that its code can be split out
+//! of the main bundle and downloaded on demand. While its code is loading, the component suspends
+//! and the fallback of the closest [`Suspense`](crate::suspense::Suspense) is shown. If loading
+//! fails, the error is reported to the closest
+//! [`ErrorBoundary`](crate::error_boundary::ErrorBoundary).
+//!
+//! Splitting the code itself is done by the bundler: the component is declared with
+//! [`lazy_component!`] and the URL of the JavaScript module exposing its code, or with a future
+//! performing the loading.
+
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use futures::future::LocalBoxFuture;
+use thiserror::Error;
+/// Declares a component whose code is loaded on first render.
+///
+/// The first argument names the declared component, followed by the component it renders once
+/// loaded. The second one is either the URL of the JavaScript module to import, or a future
+/// resolving to `Result<(), LazyError>` once the code is available.
+///
+/// Modules are imported with `import()`. If the module has a default export that is a
+/// function, such as the initialization function of a module generated by `wasm-bindgen
+/// --target web`, it is called and awaited as well. Outside of the browser, the code is
+/// assumed to be available and nothing is imported.
+///
+/// # Example
+///
+/// ```
+/// use yew::lazy::lazy_component;
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq, Clone)]
+/// pub struct ChartProps {
+///     pub values: Vec<f64>,
+/// }
+///
+/// #[function_component]
+/// pub fn Chart(props: &ChartProps) -> Html {
+///     html! { <svg>{ format!("{} values", props.values.len()) }</svg> }
+/// }
+///
+/// lazy_component!(
+///     /// [`Chart`], loaded on first render.
+///     pub LazyChart => Chart, "/chunks/chart.js"
+/// );
+///
+/// #[function_component]
+/// fn Dashboard() -> Html {
+///     html! {
+///         <Suspense fallback={html! { "Loading..." }}>
+///             <LazyChart values={vec![1.0, 2.0]} />
+///         </Suspense>
+///     }
+/// }
+/// ```
+pub use yew_macro::lazy_component;
+
+use crate::functional::function_component;
+use crate::html::{BaseComponent, ComponentError, HtmlResult};
+use crate::suspense::Suspension;
+use crate::virtual_dom::VComp;
+
+/// A future loading the code of a lazy component.
+pub type LoadFuture = LocalBoxFuture<'static, Result<(), LazyError>>;
+
+/// Error returned when the code of a lazy component cannot be loaded.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("failed to load lazy component: {message}")]
+pub struct LazyError {
+    message: String,
+}
+
+impl LazyError {
+    /// Creates an error with the message of `error`.
+    pub fn new(error: impl std::fmt::Display) -> Self {
+        Self {
+            message: error.to_string(),
+        }
+    }
+}
+
+/// A component whose code is loaded on first render.
+///
+/// This is implemented by [`lazy_component!`].
+pub trait LazyComponent: 'static {
+    /// The component rendered once loaded.
+    type Component: BaseComponent;
+
+    /// Loads the code of the component.
+    fn load() -> LoadFuture;
+}
+
+/// The loading state of a lazy component.
+#[derive(Debug, Clone)]
+enum LoadState {
+    Loading(Suspension),
+    Loaded,
+    Failed(LazyError),
+}
+
+thread_local! {
+    static LOAD_STATES: RefCell<HashMap<TypeId, LoadState>> = RefCell::default();
+}
+
+/// Returns the loading state of `L`, starting to load it if needed.
+fn load<L: LazyComponent>() -> LoadState {
+    let id = TypeId::of::<L>();
+    if let Some(state) = LOAD_STATES.with(|m| m.borrow().get(&id).cloned()) {
+        return state;
+    }
+
+    let (suspension, handle) = Suspension::new();
+    let state = LoadState::Loading(suspension);
+    LOAD_STATES.with(|m| m.borrow_mut().insert(id, state.clone()));
+
+    crate::platform::spawn_local(async move {
+        let state = match L::load().await {
+            Ok(()) => LoadState::Loaded,
+            Err(e) => LoadState::Failed(e),
+        };
+        LOAD_STATES.with(|m| m.borrow_mut().insert(id, state));
+        handle.resume();
+    });
+
+    state
+}
+
+/// Starts loading the code of `L` ahead of its first render, for example when hovering a link
+/// to the page it is on.
+pub fn preload<L: LazyComponent>() {
+    load::<L>();
+}
+
+/// Renders the component of `L` once its code is loaded.
+///
+/// Declare lazy components with [`lazy_component!`] rather than using this component directly.
+#[function_component]
+pub fn Lazy<L>(props: &<L::Component as BaseComponent>::Properties) -> HtmlResult
+where
+    L: LazyComponent,
+    <L::Component as BaseComponent>::Properties: Clone,
+{
+    match load::<L>() {
+        LoadState::Loading(m) => Err(m.into()),
+        LoadState::Failed(e) => Err(ComponentError::new(e).into()),
+        LoadState::Loaded => Ok(VComp::new::<L::Component>(Rc::new(props.clone()), None).into()),
+    }
+}
+
+/// Imports the JavaScript module at `url`, calling its default export if it is a function.
+///
+/// This is the loader used by [`lazy_component!`] when given a URL.
+pub fn import_module(url: &'static str) -> LoadFuture {
+    Box::pin(feat_browser::import_module(url))
+}
+
+#[cfg(all(
+    target_arch = "wasm32",
+    not(target_os = "wasi"),
+    not(feature = "not_browser_env")
+))]
+mod feat_browser {
+    use js_sys::{Function, Promise, Reflect};
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen_futures::JsFuture;
+
+    use super::LazyError;
+
+    #[wasm_bindgen(inline_js = "export function dynamic_import(url) { return import(url); }")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        fn dynamic_import(url: &str) -> Result<Promise, JsValue>;
+    }
+
+    fn to_error(e: JsValue) -> LazyError {
+        match e.dyn_ref::<js_sys::Error>() {
+            Some(e) => LazyError::new(String::from(e.message())),
+            None => LazyError::new(format!("{e:?}")),
+        }
+    }
+
+    pub(super) async fn import_module(url: &str) -> Result<(), LazyError> {
+        let module = JsFuture::from(dynamic_import(url).map_err(to_error)?)
+            .await
+            .map_err(to_error)?;
+
+        let init = Reflect::get(&module, &"default".into()).map_err(to_error)?;
+        if let Some(init) = init.dyn_ref::<Function>() {
+            let result = init.call0(&module).map_err(to_error)?;
+            if let Some(promise) = result.dyn_ref::<Promise>() {
+                JsFuture::from(promise.clone()).await.map_err(to_error)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(any(
+    not(target_arch = "wasm32"),
+    target_os = "wasi",
+    feature = "not_browser_env"
+))]
+mod feat_browser {
+    use super::LazyError;
+
+    pub(super) async fn import_module(_url: &str) -> Result<(), LazyError> {
+        Ok(())
+    }
+}
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use std::cell::Cell;
+
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::LocalServerRenderer as ServerRenderer;
+
+    thread_local! {
+        static LOADS: Cell<u32> = const { Cell::new(0) };
+    }
+
+    #[derive(Properties, PartialEq, Clone)]
+    struct GreetingProps {
+        name: AttrValue,
+    }
+
+    #[function_component]
+    fn Greeting(props: &GreetingProps) -> Html {
+        html! { <p>{ format!("Hello, {}!", props.name) }</p> }
+    }
+
+    lazy_component!(LazyGreeting => Greeting, async {
+        LOADS.set(LOADS.get() + 1);
+        Ok(())
+    });
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn lazy_components_are_loaded_once() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <Suspense fallback={html! { "Loading..." }}>
+                    <LazyGreeting name="Jane" />
+                    <LazyGreeting name="John" />
+                </Suspense>
+            }
+        }
+
+        let local = tokio::task::LocalSet::new();
+        let s = local
+            .run_until(ServerRenderer::<Comp>::new().hydratable(false).render())
+            .await;
+
+        assert_eq!(s, "<p>Hello, Jane!</p><p>Hello, John!</p>");
+        assert_eq!(LOADS.get(), 1);
+    }
+}
@@ -27,6 +27,27 @@
 //!   are making a Yew application (not a library).
 //! - `ssr`: Enables Server-side Rendering support and [`ServerRenderer`].
 //! - `hydration`: Enables Hydration support.
+//! - `tracing`: Emits `INFO` level [`tracing`](https://docs.rs/tracing) spans for component
+//!   lifecycle events and scheduler batches, carrying component names and render causes.
+//! - `devtools`: Enables the [`devtools`] protocol, reporting the component tree to a browser
+//!   extension.
+//! - `profiler`: Enables the [`profiler`], recording render durations and wasted renders of
+//!   components.
+//! - `a11y_audit`: Enables the [accessibility audit](a11y) of the rendered elements in debug
+//!   builds.
+//! - `websocket`: Enables the [`use_websocket`](functional::use_websocket) hook.
+//! - `broadcast_channel`: Enables the [`use_broadcast_channel`](functional::use_broadcast_channel)
+//!   hook, sharing a state between tabs.
+//! - `form`: Enables form state management with [`use_form`](form::use_form).
+//! - `drag_and_drop`: Enables the [`use_draggable`](functional::use_draggable) and
+//!   [`use_drop_target`](functional::use_drop_target) hooks.
+//! - `custom_events`: Enables typed [`CustomEvent`](html::CustomEvent)s and the `oncustom` listener.
+//! - `axum`, `actix`, `warp_adapter`: Enable the [adapters](ssr::adapters) sending server side
+//!   rendered pages as the responses of axum, actix-web and warp.
+//! - `server_fn`: Enables the [server functions](server_fn) called from the client with
+//!   `#[server]`.
+//! - `worker`: Enables the [agents](worker) running in web workers, with
+//!   [`use_worker`](worker::use_worker).
 //!
 //! ## Example
 //!
@@ -90,31 +111,27 @@ extern crate self as yew;
 /// instance. Each item can be of any type that implements `Into<Classes>` (See the
 /// implementations on [`Classes`] to learn what types can be used).
 ///
+/// A `(class, condition)` tuple adds the class only when the condition is `true`, and so does a
+/// collection of them.
+///
 /// # Example
 ///
 /// ```
 /// # use yew::prelude::*;
 /// # fn test() {
 /// let conditional_class = Some("my-other-class");
-/// let vec_of_classes = vec![
-///     "one-bean",
-///     "two-bean",
-///     conditional_class,
-/// ];
-/// let classes = classes!(vec_of_classes);
+/// let vec_of_classes = vec!["one-bean", "two-bean"];
+/// let classes = classes!(vec_of_classes, conditional_class);
+///
+/// let (active, disabled) = (true, false);
+/// let classes = classes!(
+///     "button",
+///     ("active", active),
+///     [("disabled", disabled), ("focusable", !disabled)],
+/// );
 /// # }
 /// ```
-#[macro_export]
-macro_rules! classes {
-    ($($x:expr),* $(,)?) => {{
-        use $crate::html::Classes;
-        let mut classes = Classes::new();
-        $(
-            classes.extend($x);
-        )*
-        classes
-    }};
-}
+pub use yew_macro::classes;
 
 /// This macro implements JSX-like templates.
 ///
@@ -276,20 +293,60 @@ pub mod macros {
     pub use crate::{classes, html, html_nested, props};
 }
 
+#[cfg(feature = "a11y_audit")]
+pub mod a11y;
 pub mod callback;
 pub mod context;
+pub mod css;
+pub mod custom_element;
+#[cfg(feature = "devtools")]
+pub mod devtools;
 #[cfg(feature = "csr")]
 mod dom_bundle;
+pub mod error_boundary;
+pub mod focus;
+#[cfg(feature = "form")]
+pub mod form;
 pub mod functional;
+pub mod head;
+pub mod hotkey;
 pub mod html;
+#[cfg(feature = "i18n")]
+pub mod i18n;
+pub mod image;
+pub mod islands;
+pub mod keep_alive;
+pub mod lazy;
+pub mod offscreen;
+#[cfg(all(feature = "csr", debug_assertions))]
+mod panic_overlay;
 pub mod platform;
+#[cfg(feature = "profiler")]
+pub mod profiler;
+pub mod query;
 pub mod scheduler;
 mod sealed;
 #[cfg(feature = "ssr")]
 mod server_renderer;
+#[cfg(feature = "server_fn")]
+pub mod server_fn;
+pub mod shadow_host;
+pub mod ssr;
+pub mod ssr_cache;
+pub mod signal;
+pub mod store;
 pub mod suspense;
+#[cfg(any(test, feature = "test"))]
+pub mod test;
+pub mod theme;
+pub mod time_travel;
+pub mod transition_group;
 pub mod utils;
+pub mod view_transition;
 pub mod virtual_dom;
+pub mod virtual_list;
+#[cfg(feature = "worker")]
+pub mod worker;
 #[cfg(feature = "ssr")]
 pub use server_renderer::*;
 
@@ -319,7 +376,7 @@ pub mod events {
 #[cfg(feature = "csr")]
 pub use crate::app_handle::AppHandle;
 #[cfg(feature = "csr")]
-pub use crate::renderer::{set_custom_panic_hook, Renderer};
+pub use crate::renderer::{set_custom_panic_hook, Renderer, RendererRoot};
 
 pub mod prelude {
     //! The Yew Prelude
@@ -335,15 +392,19 @@ pub mod prelude {
     pub use crate::app_handle::AppHandle;
     pub use crate::callback::{Callback, CallbackRef, CallbackRefMut};
     pub use crate::context::{ContextHandle, ContextProvider};
+    pub use crate::error_boundary::ErrorBoundary;
     pub use crate::events::*;
     pub use crate::functional::*;
     pub use crate::html::{
-        create_portal, BaseComponent, Children, ChildrenWithProps, Classes, Component, Context,
-        Html, HtmlResult, NodeRef, Properties,
+        create_portal, BaseComponent, Children, ChildrenWithProps, Classes, Component,
+        ComponentError, Context, Html, HtmlResult, NodeRef, Properties,
     };
+    #[cfg(feature = "i18n")]
+    pub use crate::i18n::{use_translation, LocaleProvider};
     pub use crate::macros::{classes, html, html_nested};
     pub use crate::suspense::Suspense;
     pub use crate::virtual_dom::AttrValue;
+    pub use crate::virtual_list::VirtualList;
 }
 
 pub use self::prelude::*;
@@ -0,0 +1,127 @@
+//! This module provides prerendering of hidden content.
+
+use crate::html;
+use crate::html::{Component, Context, Html, Properties};
+
+/// Properties for [Offscreen].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct OffscreenProps {
+    /// Whether the children are shown.
+    pub visible: bool,
+
+    /// Whether hidden children are rendered ahead of time, once the browser is idle.
+    #[prop_or(true)]
+    pub prerender: bool,
+
+    /// The Children of the current Offscreen Component.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Renders hidden content during browser idle time, so it is shown instantly when revealed.
+///
+/// While [`visible`](OffscreenProps::visible) is `false`, the children are rendered with low
+/// priority once the browser is idle and kept in the document, hidden. Revealing them only changes
+/// their visibility, so the next step of a wizard or a hidden tab is shown without mounting any
+/// components. Once rendered, the children stay mounted and keep their state while hidden again.
+///
+/// The children are wrapped in a `<div>` with `display: contents` when visible, which does not
+/// generate a box of its own.
+///
+/// # Example
+///
+/// ```
+/// use yew::offscreen::Offscreen;
+/// use yew::prelude::*;
+///
+/// # #[function_component]
+/// # fn Settings() -> Html { Html::default() }
+/// #[function_component]
+/// fn Tabs() -> Html {
+///     let show_settings = use_state(|| false);
+///     let onclick = {
+///         let show_settings = show_settings.clone();
+///         Callback::from(move |_| show_settings.set(true))
+///     };
+///
+///     html! {
+///         <>
+///             <button {onclick}>{ "Settings" }</button>
+///             <Offscreen visible={*show_settings}>
+///                 <Settings />
+///             </Offscreen>
+///         </>
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Offscreen {
+    mounted: bool,
+    #[cfg(feature = "csr")]
+    prerender_scheduled: bool,
+}
+
+impl Component for Offscreen {
+    type Message = ();
+    type Properties = OffscreenProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            mounted: ctx.props().visible,
+            #[cfg(feature = "csr")]
+            prerender_scheduled: false,
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
+        !std::mem::replace(&mut self.mounted, true)
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.mounted |= ctx.props().visible;
+        true
+    }
+
+    #[cfg(feature = "csr")]
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        if !self.mounted && ctx.props().prerender && !self.prerender_scheduled {
+            self.prerender_scheduled = true;
+            crate::scheduler::push_idle(Box::new(feat_csr::PrerenderRunner {
+                scope: ctx.link().clone(),
+            }));
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if !self.mounted {
+            return Html::default();
+        }
+
+        let style = if ctx.props().visible {
+            "display: contents;"
+        } else {
+            "display: none;"
+        };
+
+        html! {
+            <div {style}>{ ctx.props().children.clone() }</div>
+        }
+    }
+}
+
+#[cfg(feature = "csr")]
+mod feat_csr {
+    use super::Offscreen;
+    use crate::html::Scope;
+    use crate::scheduler::Runnable;
+
+    pub(super) struct PrerenderRunner {
+        pub scope: Scope<Offscreen>,
+    }
+
+    impl Runnable for PrerenderRunner {
+        fn run(self: Box<Self>) {
+            self.scope.send_message(());
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! In-page overlay showing panics in debug builds.
+//!
+//! The scheduler runs component lifecycle events one after another, so while a lifecycle event is
+//! running the stack kept here holds the scope of that component. On panic, the names of that
+//! component and its ancestors are displayed together with the panic message.
+
+use std::cell::RefCell;
+use std::iter;
+
+use crate::html::AnyScope;
+
+thread_local! {
+    static STACK: RefCell<Vec<AnyScope>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Removes the scope pushed by [`enter`] when dropped.
+pub(crate) struct StackGuard {
+    _private: (),
+}
+
+impl Drop for StackGuard {
+    fn drop(&mut self) {
+        let _ = STACK.try_with(|m| m.borrow_mut().pop());
+    }
+}
+
+/// Marks the component of `scope` as running until the returned guard is dropped.
+pub(crate) fn enter(scope: AnyScope) -> StackGuard {
+    STACK.with(|m| m.borrow_mut().push(scope));
+    StackGuard { _private: () }
+}
+
+/// Returns the name of the running component followed by the names of its ancestors.
+pub(crate) fn component_stack() -> Vec<&'static str> {
+    STACK
+        .try_with(|m| {
+            m.try_borrow().ok().and_then(|m| {
+                m.last().map(|scope| {
+                    iter::successors(Some(scope), |m| m.get_parent())
+                        .map(AnyScope::get_type_name)
+                        .collect()
+                })
+            })
+        })
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Renders the overlay for a panic with `message` into the document.
+pub(crate) fn show(message: &str) {
+    let stack = component_stack();
+
+    #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+    feat_browser::render(message, &stack);
+
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "wasi"))))]
+    let _ = (message, stack);
+}
+
+#[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+mod feat_browser {
+    use web_sys::{Document, Element};
+
+    const OVERLAY_ID: &str = "yew-panic-overlay";
+
+    const OVERLAY_STYLE: &str = "position: fixed; inset: 0; z-index: 2147483647; overflow: auto; \
+                                 margin: 0; padding: 2rem; background: rgba(24, 24, 27, 0.95); \
+                                 color: #fafafa; font: 14px/1.5 ui-monospace, monospace;";
+    const MESSAGE_STYLE: &str = "white-space: pre-wrap; color: #fca5a5; font-size: 16px;";
+
+    fn create(document: &Document, tag: &str, text: Option<&str>) -> Option<Element> {
+        let element = document.create_element(tag).ok()?;
+        if let Some(text) = text {
+            element.set_text_content(Some(text));
+        }
+        Some(element)
+    }
+
+    pub(super) fn render(message: &str, stack: &[&str]) {
+        // We are already panicking, so every failure is silently ignored.
+        let _ = try_render(message, stack);
+    }
+
+    fn try_render(message: &str, stack: &[&str]) -> Option<()> {
+        let document = web_sys::window()?.document()?;
+        let body = document.body()?;
+
+        if let Some(previous) = document.get_element_by_id(OVERLAY_ID) {
+            previous.remove();
+        }
+
+        let overlay = create(&document, "div", None)?;
+        overlay.set_id(OVERLAY_ID);
+        overlay.set_attribute("role", "alert").ok()?;
+        overlay.set_attribute("style", OVERLAY_STYLE).ok()?;
+
+        let message_el = create(&document, "pre", Some(message))?;
+        message_el.set_attribute("style", MESSAGE_STYLE).ok()?;
+        overlay.append_child(&message_el).ok()?;
+
+        if !stack.is_empty() {
+            let heading = create(&document, "h3", Some("Component stack"))?;
+            overlay.append_child(&heading).ok()?;
+
+            let list = create(&document, "ol", None)?;
+            for name in stack {
+                let item = create(&document, "li", Some(name))?;
+                list.append_child(&item).ok()?;
+            }
+            overlay.append_child(&list).ok()?;
+        }
+
+        let hint = create(
+            &document,
+            "p",
+            Some("This overlay is only shown in debug builds. Reload the page to continue."),
+        )?;
+        overlay.append_child(&hint).ok()?;
+
+        body.append_child(&overlay).ok()?;
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html::{Component, Context, Html, Scope};
+
+    struct Parent;
+    struct Child;
+
+    macro_rules! impl_empty_component {
+        ($($comp:ident),+) => {
+            $(
+                impl Component for $comp {
+                    type Message = ();
+                    type Properties = ();
+
+                    fn create(_ctx: &Context<Self>) -> Self {
+                        Self
+                    }
+
+                    fn view(&self, _ctx: &Context<Self>) -> Html {
+                        Html::default()
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_empty_component!(Parent, Child);
+
+    #[test]
+    fn tracks_running_component() {
+        let parent = AnyScope::from(Scope::<Parent>::new(None));
+        let child = AnyScope::from(Scope::<Child>::new(Some(parent.clone())));
+
+        assert!(component_stack().is_empty());
+        {
+            let _parent = enter(parent);
+            assert_eq!(component_stack(), vec![std::any::type_name::<Parent>()]);
+
+            let _child = enter(child);
+            assert_eq!(
+                component_stack(),
+                vec![
+                    std::any::type_name::<Child>(),
+                    std::any::type_name::<Parent>()
+                ]
+            );
+        }
+        assert!(component_stack().is_empty());
+    }
+}
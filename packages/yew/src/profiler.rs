@@ -0,0 +1,367 @@
+//! A profiler recording how long components take to render.
+//!
+//! Recording is started with [`start`] and stopped with [`stop`], which returns the [`Profile`] of
+//! everything that happened in between: the renders of components, the commits of their output to
+//! the DOM, their `rendered` lifecycle and the batches of the scheduler. A render is *wasted* when
+//! it returns the same `Html` as the previous one, so the component could have skipped it.
+//!
+//! The profile can be summarized per component with [`Profile::components`], or exported with
+//! [`Profile::to_chrome_trace`] to the [Trace Event Format], which the performance panel of Chrome,
+//! [Perfetto](https://ui.perfetto.dev) and [speedscope](https://www.speedscope.app) show as a
+//! flamegraph.
+//!
+//! This module requires the `profiler` feature. Nothing is recorded until [`start`] is called.
+//!
+//! ```rust,no_run
+//! # use yew::prelude::*;
+//! # #[function_component]
+//! # fn App() -> Html { html! {} }
+//! yew::profiler::start();
+//! yew::Renderer::<App>::new().render();
+//! // ... interact with the app
+//! let profile = yew::profiler::stop();
+//! for stats in profile.components().iter().take(5) {
+//!     gloo::console::log!(format!(
+//!         "{}: {} renders ({} wasted) in {:.1}ms",
+//!         stats.name, stats.renders, stats.wasted_renders, stats.render_ms
+//!     ));
+//! }
+//! ```
+//!
+//! [Trace Event Format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+
+use std::cell::RefCell;
+use std::fmt::Write;
+
+/// A step of rendering, recorded by the profiler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// The `view` of a component.
+    Render,
+    /// The reconciliation of the output of a component with the DOM.
+    Commit,
+    /// The `rendered` lifecycle of a component.
+    Rendered,
+    /// A render returning the same `Html` as the previous one. It takes no time.
+    WastedRender,
+    /// A batch of work run by the scheduler.
+    Flush,
+}
+
+impl Phase {
+    /// Returns the name of the phase, like `"render"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Render => "render",
+            Self::Commit => "commit",
+            Self::Rendered => "rendered",
+            Self::WastedRender => "wasted_render",
+            Self::Flush => "flush",
+        }
+    }
+}
+
+/// A recorded step of rendering.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileEvent {
+    /// The step.
+    pub phase: Phase,
+    /// The id and type name of the component, except for [`Phase::Flush`].
+    pub component: Option<(usize, &'static str)>,
+    /// When the step started, in milliseconds since an arbitrary origin.
+    pub start_ms: f64,
+    /// How long the step took, in milliseconds.
+    pub duration_ms: f64,
+}
+
+/// What a component did while the profiler was recording.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentStats {
+    /// The type name of the component.
+    pub name: &'static str,
+    /// How many times the component was rendered.
+    pub renders: usize,
+    /// How many renders returned the same `Html` as the previous one.
+    pub wasted_renders: usize,
+    /// How many times the output of the component was committed to the DOM.
+    pub commits: usize,
+    /// The time spent rendering, in milliseconds.
+    pub render_ms: f64,
+    /// The time spent committing, in milliseconds.
+    pub commit_ms: f64,
+}
+
+/// The events recorded between [`start`] and [`stop`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Profile {
+    events: Vec<ProfileEvent>,
+}
+
+impl Profile {
+    /// Returns the recorded events, in the order they ended.
+    pub fn events(&self) -> &[ProfileEvent] {
+        &self.events
+    }
+
+    /// Summarizes the events per component type, the slowest first.
+    pub fn components(&self) -> Vec<ComponentStats> {
+        let mut stats: Vec<ComponentStats> = Vec::new();
+        for event in &self.events {
+            let Some((_, name)) = event.component else {
+                continue;
+            };
+            let index = match stats.iter().position(|s| s.name == name) {
+                Some(index) => index,
+                None => {
+                    stats.push(ComponentStats {
+                        name,
+                        renders: 0,
+                        wasted_renders: 0,
+                        commits: 0,
+                        render_ms: 0.0,
+                        commit_ms: 0.0,
+                    });
+                    stats.len() - 1
+                }
+            };
+            let s = &mut stats[index];
+            match event.phase {
+                Phase::Render => {
+                    s.renders += 1;
+                    s.render_ms += event.duration_ms;
+                }
+                Phase::Commit => {
+                    s.commits += 1;
+                    s.commit_ms += event.duration_ms;
+                }
+                Phase::WastedRender => s.wasted_renders += 1,
+                Phase::Rendered | Phase::Flush => {}
+            }
+        }
+        stats.sort_by(|a, b| (b.render_ms + b.commit_ms).total_cmp(&(a.render_ms + a.commit_ms)));
+        stats
+    }
+
+    /// Exports the events to the JSON of the Trace Event Format.
+    pub fn to_chrome_trace(&self) -> String {
+        let mut json = String::from(r#"{"traceEvents":["#);
+        for (i, event) in self.events.iter().enumerate() {
+            if i != 0 {
+                json.push(',');
+            }
+            let (kind, name) = match event.component {
+                Some((_, name)) => (event.phase.as_str(), name),
+                None => ("scheduler", event.phase.as_str()),
+            };
+            json.push_str(r#"{"name":""#);
+            push_json_str(&mut json, name);
+            json.push_str(r#"","cat":""#);
+            json.push_str(kind);
+            // timestamps are in microseconds
+            let _ = write!(
+                json,
+                r#"","pid":1,"tid":1,"ts":{:.3}"#,
+                event.start_ms * 1000.0
+            );
+            match event.phase {
+                Phase::WastedRender => json.push_str(r#","ph":"i","s":"t""#),
+                _ => {
+                    let _ = write!(json, r#","ph":"X","dur":{:.3}"#, event.duration_ms * 1000.0);
+                }
+            }
+            if let Some((id, _)) = event.component {
+                let _ = write!(json, r#","args":{{"id":{id}}}"#);
+            }
+            json.push('}');
+        }
+        json.push_str("]}");
+        json
+    }
+}
+
+fn push_json_str(json: &mut String, s: &str) {
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+}
+
+thread_local! {
+    static RECORDING: RefCell<Option<Profile>> = const { RefCell::new(None) };
+}
+
+/// Starts recording, discarding what was recorded since the previous call to [`start`].
+pub fn start() {
+    RECORDING.with(|r| *r.borrow_mut() = Some(Profile::default()));
+}
+
+/// Stops recording and returns what was recorded. The profile is empty if recording was not
+/// started.
+pub fn stop() -> Profile {
+    RECORDING
+        .with(|r| r.borrow_mut().take())
+        .unwrap_or_default()
+}
+
+/// Checks if the profiler is recording.
+pub fn is_recording() -> bool {
+    RECORDING.with(|r| r.borrow().is_some())
+}
+
+fn record(event: ProfileEvent) {
+    RECORDING.with(|r| {
+        if let Some(profile) = r.borrow_mut().as_mut() {
+            profile.events.push(event);
+        }
+    });
+}
+
+/// Records a step when it is dropped.
+pub(crate) struct Span {
+    phase: Phase,
+    component: Option<(usize, &'static str)>,
+    start_ms: f64,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        record(ProfileEvent {
+            phase: self.phase,
+            component: self.component,
+            start_ms: self.start_ms,
+            duration_ms: now() - self.start_ms,
+        });
+    }
+}
+
+/// Starts a step of a component, if the profiler is recording.
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub(crate) fn span(phase: Phase, comp_id: usize, comp_name: &'static str) -> Option<Span> {
+    is_recording().then(|| Span {
+        phase,
+        component: Some((comp_id, comp_name)),
+        start_ms: now(),
+    })
+}
+
+/// Starts a batch of the scheduler, if the profiler is recording.
+pub(crate) fn flush_span() -> Option<Span> {
+    is_recording().then(|| Span {
+        phase: Phase::Flush,
+        component: None,
+        start_ms: now(),
+    })
+}
+
+/// Records a wasted render of a component.
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub(crate) fn wasted_render(comp_id: usize, comp_name: &'static str) {
+    record(ProfileEvent {
+        phase: Phase::WastedRender,
+        component: Some((comp_id, comp_name)),
+        start_ms: now(),
+        duration_ms: 0.0,
+    });
+}
+
+#[cfg(all(
+    target_arch = "wasm32",
+    not(target_os = "wasi"),
+    not(feature = "not_browser_env")
+))]
+fn now() -> f64 {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        // available in both windows and workers
+        #[wasm_bindgen(js_namespace = performance, js_name = now)]
+        fn performance_now() -> f64;
+    }
+
+    performance_now()
+}
+
+#[cfg(not(all(
+    target_arch = "wasm32",
+    not(target_os = "wasi"),
+    not(feature = "not_browser_env")
+)))]
+fn now() -> f64 {
+    use std::time::Instant;
+
+    thread_local! {
+        static ORIGIN: Instant = Instant::now();
+    }
+
+    ORIGIN.with(|origin| origin.elapsed().as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(phase: Phase, name: &'static str, duration_ms: f64) -> ProfileEvent {
+        ProfileEvent {
+            phase,
+            component: Some((1, name)),
+            start_ms: 0.5,
+            duration_ms,
+        }
+    }
+
+    #[test]
+    fn summarizes_components() {
+        let profile = Profile {
+            events: vec![
+                event(Phase::Render, "app::List", 1.0),
+                event(Phase::Commit, "app::List", 2.0),
+                event(Phase::Render, "app::Item", 4.0),
+                event(Phase::WastedRender, "app::Item", 0.0),
+                event(Phase::Render, "app::Item", 1.0),
+            ],
+        };
+        let stats = profile.components();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].name, "app::Item");
+        assert_eq!((stats[0].renders, stats[0].wasted_renders), (2, 1));
+        assert_eq!((stats[1].commits, stats[1].commit_ms), (1, 2.0));
+    }
+
+    #[test]
+    fn exports_chrome_traces() {
+        let profile = Profile {
+            events: vec![
+                event(Phase::Render, "app::List<\"a\">", 0.25),
+                ProfileEvent {
+                    phase: Phase::Flush,
+                    component: None,
+                    start_ms: 0.0,
+                    duration_ms: 1.0,
+                },
+            ],
+        };
+        assert_eq!(
+            profile.to_chrome_trace(),
+            r#"{"traceEvents":[{"name":"app::List<\"a\">","cat":"render","pid":1,"tid":1,"ts":500.000,"ph":"X","dur":250.000,"args":{"id":1}},{"name":"flush","cat":"scheduler","pid":1,"tid":1,"ts":0.000,"ph":"X","dur":1000.000}]}"#
+        );
+    }
+
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    #[test]
+    fn records_only_while_started() {
+        drop(span(Phase::Render, 1, "app::List"));
+        start();
+        drop(span(Phase::Render, 1, "app::List"));
+        wasted_render(1, "app::List");
+        let profile = stop();
+        assert_eq!(profile.events().len(), 2);
+        assert!(!is_recording());
+    }
+}
@@ -0,0 +1,331 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::rc::{Rc, Weak};
+
+use slab::Slab;
+
+use crate::callback::Callback;
+use crate::platform::spawn_local;
+use crate::suspense::Suspension;
+use crate::virtual_dom::AttrValue;
+
+#[derive(Default)]
+struct Entry {
+    /// The data of the last successful fetch, an `Rc<T>`.
+    data: Option<Rc<dyn Any>>,
+    /// The error of the last fetch if it failed, an `Rc<E>`.
+    error: Option<Rc<dyn Any>>,
+    stale: bool,
+    fetching: Option<Suspension>,
+    subscribers: Slab<Callback<()>>,
+}
+
+#[derive(Default)]
+struct Store {
+    entries: HashMap<AttrValue, Entry>,
+}
+
+/// The state of a query, as read by [`use_query`](super::use_query).
+pub(super) struct QueryState<T, E> {
+    pub data: Option<Rc<T>>,
+    pub error: Option<Rc<E>>,
+    pub stale: bool,
+    pub fetching: Option<Suspension>,
+}
+
+/// A cache of query results, shared by the components using [`use_query`](super::use_query) with
+/// the same client.
+///
+/// A client can be provided to a part of the component tree with a
+/// [`ContextProvider<QueryClient>`](crate::ContextProvider). See the
+/// [module documentation](super) for details.
+#[derive(Clone, Default)]
+pub struct QueryClient {
+    store: Rc<RefCell<Store>>,
+}
+
+impl PartialEq for QueryClient {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.store, &other.store)
+    }
+}
+
+impl fmt::Debug for QueryClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let store = self.store.borrow();
+        f.debug_struct("QueryClient")
+            .field("keys", &store.entries.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+thread_local! {
+    static GLOBAL_CLIENT: QueryClient = QueryClient::new();
+}
+
+impl QueryClient {
+    /// Creates an empty client.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the client used when none is provided, shared by the current thread.
+    pub(super) fn global() -> Self {
+        GLOBAL_CLIENT.with(Clone::clone)
+    }
+
+    /// Returns the cached data of the query `key`, if it is of type `T`.
+    pub fn get_data<T: 'static>(&self, key: &str) -> Option<Rc<T>> {
+        let store = self.store.borrow();
+        store
+            .entries
+            .get(key)
+            .and_then(|m| m.data.clone())
+            .and_then(|m| m.downcast().ok())
+    }
+
+    /// Replaces the cached data of the query `key`, and re-renders the components using it.
+    ///
+    /// The error of the last fetch, if any, is cleared.
+    pub fn set_data<T: 'static>(&self, key: impl Into<AttrValue>, data: T) {
+        let key = key.into();
+        {
+            let mut store = self.store.borrow_mut();
+            let entry = store.entries.entry(key.clone()).or_default();
+            entry.data = Some(Rc::new(data));
+            entry.error = None;
+        }
+        self.notify(&key);
+    }
+
+    /// Marks the query `key` as stale.
+    ///
+    /// Components using the query keep showing the cached data while it is fetched again in the
+    /// background. If no component uses it, it is fetched again when it is next used.
+    pub fn invalidate(&self, key: &str) {
+        self.invalidate_matching(|m| m == key);
+    }
+
+    /// Marks all queries whose key matches `predicate` as stale, like
+    /// [`invalidate`](Self::invalidate).
+    pub fn invalidate_matching(&self, predicate: impl Fn(&str) -> bool) {
+        let keys: Vec<AttrValue> = {
+            let mut store = self.store.borrow_mut();
+            store
+                .entries
+                .iter_mut()
+                .filter(|(key, _)| predicate(key))
+                .map(|(key, entry)| {
+                    entry.stale = true;
+                    key.clone()
+                })
+                .collect()
+        };
+
+        for key in keys {
+            self.notify(&key);
+        }
+    }
+
+    /// Returns the cached data of the query `key`, whatever its type.
+    pub(super) fn raw_data(&self, key: &str) -> Option<Rc<dyn Any>> {
+        let store = self.store.borrow();
+        store.entries.get(key).and_then(|m| m.data.clone())
+    }
+
+    /// Restores the data of queries as returned by [`raw_data`](Self::raw_data), and marks them as
+    /// stale.
+    ///
+    /// All queries are restored before any component is re-rendered, so that no component renders
+    /// a mix of restored and outdated data.
+    pub(super) fn restore(&self, snapshots: Vec<(AttrValue, Option<Rc<dyn Any>>)>) {
+        {
+            let mut store = self.store.borrow_mut();
+            for (key, data) in &snapshots {
+                let entry = store.entries.entry(key.clone()).or_default();
+                entry.data = data.clone();
+                entry.stale = true;
+            }
+        }
+
+        for (key, _) in &snapshots {
+            self.notify(key);
+        }
+    }
+
+    pub(super) fn state<T: 'static, E: 'static>(&self, key: &str) -> QueryState<T, E> {
+        let store = self.store.borrow();
+        let Some(entry) = store.entries.get(key) else {
+            return QueryState {
+                data: None,
+                error: None,
+                stale: false,
+                fetching: None,
+            };
+        };
+
+        QueryState {
+            data: entry.data.clone().and_then(|m| m.downcast().ok()),
+            error: entry.error.clone().and_then(|m| m.downcast().ok()),
+            stale: entry.stale,
+            fetching: entry.fetching.clone(),
+        }
+    }
+
+    /// Fetches the query `key` with `fetch`, unless it is already being fetched.
+    ///
+    /// Returns a suspension that is resumed once the query has been fetched.
+    pub(super) fn fetch<T, E, F>(&self, key: AttrValue, fetch: F) -> Suspension
+    where
+        T: 'static,
+        E: 'static,
+        F: Future<Output = Result<T, E>> + 'static,
+    {
+        let (suspension, handle) = Suspension::new();
+        {
+            let mut store = self.store.borrow_mut();
+            let entry = store.entries.entry(key.clone()).or_default();
+            if let Some(fetching) = &entry.fetching {
+                return fetching.clone();
+            }
+            entry.fetching = Some(suspension.clone());
+        }
+
+        let client = self.clone();
+        spawn_local(async move {
+            let result = fetch.await;
+            {
+                let mut store = client.store.borrow_mut();
+                let entry = store.entries.entry(key.clone()).or_default();
+                match result {
+                    Ok(data) => {
+                        entry.data = Some(Rc::new(data));
+                        entry.error = None;
+                    }
+                    Err(e) => entry.error = Some(Rc::new(e)),
+                }
+                entry.stale = false;
+                entry.fetching = None;
+            }
+
+            handle.resume();
+            client.notify(&key);
+        });
+
+        suspension
+    }
+
+    pub(super) fn subscribe(&self, key: AttrValue, callback: Callback<()>) -> Subscription {
+        let id = {
+            let mut store = self.store.borrow_mut();
+            let entry = store.entries.entry(key.clone()).or_default();
+            entry.subscribers.insert(callback)
+        };
+
+        Subscription {
+            store: Rc::downgrade(&self.store),
+            key,
+            id,
+        }
+    }
+
+    fn notify(&self, key: &str) {
+        // Callbacks are collected first, as they may access the store.
+        let subscribers: Vec<Callback<()>> = {
+            let store = self.store.borrow();
+            store
+                .entries
+                .get(key)
+                .map(|m| m.subscribers.iter().map(|(_, m)| m.clone()).collect())
+                .unwrap_or_default()
+        };
+
+        for subscriber in subscribers {
+            subscriber.emit(());
+        }
+    }
+}
+
+/// Keeps a component subscribed to the updates of a query until dropped.
+pub(super) struct Subscription {
+    store: Weak<RefCell<Store>>,
+    key: AttrValue,
+    id: usize,
+}
+
+impl Subscription {
+    /// Returns `true` if this subscribes to the query `key` of `client`.
+    pub fn is_for(&self, client: &QueryClient, key: &str) -> bool {
+        self.key == key && Weak::ptr_eq(&self.store, &Rc::downgrade(&client.store))
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(store) = self.store.upgrade() {
+            if let Some(entry) = store.borrow_mut().entries.get_mut(&self.key) {
+                entry.subscribers.try_remove(self.id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn set_data_notifies_subscribers() {
+        let client = QueryClient::new();
+        let notified = Rc::new(Cell::new(0));
+        let subscription = {
+            let notified = notified.clone();
+            client.subscribe(
+                "todos".into(),
+                Callback::from(move |_| notified.set(notified.get() + 1)),
+            )
+        };
+
+        client.set_data("todos", vec!["write docs"]);
+        assert_eq!(notified.get(), 1);
+        assert_eq!(
+            client.get_data::<Vec<&str>>("todos").as_deref(),
+            Some(&vec!["write docs"])
+        );
+        assert_eq!(client.get_data::<String>("todos"), None);
+
+        client.invalidate_matching(|key| key.starts_with("todo"));
+        assert_eq!(notified.get(), 2);
+        assert!(client.state::<Vec<&str>, ()>("todos").stale);
+
+        drop(subscription);
+        client.invalidate("todos");
+        assert_eq!(notified.get(), 2);
+    }
+
+    #[test]
+    fn restore_rolls_back_data() {
+        let client = QueryClient::new();
+        client.set_data("todos", vec!["write docs"]);
+        let snapshots = vec![
+            ("todos".into(), client.raw_data("todos")),
+            ("done".into(), client.raw_data("done")),
+        ];
+
+        client.set_data("todos", vec!["write docs", "write tests"]);
+        client.set_data("done", 1);
+        client.restore(snapshots);
+
+        assert_eq!(
+            client.get_data::<Vec<&str>>("todos").as_deref(),
+            Some(&vec!["write docs"])
+        );
+        assert_eq!(client.get_data::<i32>("done"), None);
+        assert!(client.state::<Vec<&str>, ()>("todos").stale);
+    }
+}
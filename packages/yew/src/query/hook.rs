@@ -0,0 +1,140 @@
+use std::fmt;
+use std::future::Future;
+use std::rc::Rc;
+
+use super::client::{QueryClient, Subscription};
+use crate::callback::Callback;
+use crate::functional::{hook, use_context, use_force_update, use_mut_ref};
+use crate::suspense::SuspensionResult;
+use crate::virtual_dom::AttrValue;
+
+/// State handle for the [`use_query`] hook.
+pub struct UseQueryHandle<T, E> {
+    data: Option<Rc<T>>,
+    error: Option<Rc<E>>,
+    fetching: bool,
+    key: AttrValue,
+    client: QueryClient,
+}
+
+impl<T, E> UseQueryHandle<T, E> {
+    /// Returns the data of the last successful fetch.
+    pub fn data(&self) -> Option<&T> {
+        self.data.as_deref()
+    }
+
+    /// Returns the error of the last fetch, if it failed.
+    ///
+    /// The data of the previous successful fetch, if any, is still available.
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_deref()
+    }
+
+    /// Returns `true` while the query is being fetched again in the background.
+    pub fn is_fetching(&self) -> bool {
+        self.fetching
+    }
+
+    /// Marks the query as stale, so that it is fetched again.
+    pub fn invalidate(&self) {
+        self.client.invalidate(&self.key);
+    }
+
+    /// Returns the client caching the query.
+    pub fn client(&self) -> &QueryClient {
+        &self.client
+    }
+}
+
+impl<T, E> Clone for UseQueryHandle<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            error: self.error.clone(),
+            fetching: self.fetching,
+            key: self.key.clone(),
+            client: self.client.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, E: fmt::Debug> fmt::Debug for UseQueryHandle<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseQueryHandle")
+            .field("key", &self.key)
+            .field("data", &self.data)
+            .field("error", &self.error)
+            .field("fetching", &self.fetching)
+            .finish()
+    }
+}
+
+/// This hook returns the [`QueryClient`] provided by the closest
+/// [`ContextProvider<QueryClient>`](crate::ContextProvider), or the client shared by the current
+/// thread if there is none.
+#[hook]
+pub fn use_query_client() -> QueryClient {
+    use_context::<QueryClient>().unwrap_or_else(QueryClient::global)
+}
+
+/// This hook returns the result of the query `key`, fetching it with `fetcher` if needed.
+///
+/// The result is cached by the [`QueryClient`] and shared by all components using the same key:
+/// - If the query has no result yet, it is fetched and the component suspends until it is
+///   available.
+/// - If the query has a cached result, it is returned immediately. When the component is mounted or
+///   the query is [invalidated](QueryClient::invalidate), the query is fetched again in the
+///   background, and the component re-renders with the new result.
+/// - Concurrent fetches of the same query, from any component, are deduplicated.
+///
+/// `fetcher` is only called when the query needs to be fetched. All components using a key are
+/// expected to fetch the same data, with the same types `T` and `E`.
+///
+/// See the [module documentation](super) for an example.
+#[hook]
+pub fn use_query<T, E, F, Fut>(key: AttrValue, fetcher: F) -> SuspensionResult<UseQueryHandle<T, E>>
+where
+    T: 'static,
+    E: 'static,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    let client = use_query_client();
+    let update = use_force_update();
+    let subscription = use_mut_ref(|| None::<Subscription>);
+
+    let subscribed = {
+        let mut subscription = subscription.borrow_mut();
+        let subscribed = subscription
+            .as_ref()
+            .is_some_and(|m| m.is_for(&client, &key));
+        if !subscribed {
+            *subscription =
+                Some(client.subscribe(key.clone(), Callback::from(move |_| update.force_update())));
+        }
+        subscribed
+    };
+
+    let state = client.state::<T, E>(&key);
+    let has_result = state.data.is_some() || state.error.is_some();
+
+    // Queries are revalidated when a component starts using them.
+    let fetching = match state.fetching {
+        Some(m) => Some(m),
+        None if !has_result || state.stale || !subscribed => {
+            Some(client.fetch(key.clone(), fetcher()))
+        }
+        None => None,
+    };
+
+    match fetching {
+        Some(suspension) if !has_result => Err(suspension),
+        _ => Ok(UseQueryHandle {
+            data: state.data,
+            error: state.error,
+            fetching: fetching.is_some(),
+            key,
+            client,
+        }),
+    }
+}
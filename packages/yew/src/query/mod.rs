@@ -0,0 +1,124 @@
+//! This module contains a cache for asynchronous queries, such as requests to an API.
+//!
+//! [`use_query`] returns the cached result of a query identified by a key, fetching it when it is
+//! not cached yet, and fetching it again in the background when it may be outdated. Components
+//! using the same key share the same result and the same requests.
+//!
+//! [`use_mutation`] runs changes to the data, and can update cached queries optimistically until
+//! the change is confirmed.
+//!
+//! Results are cached by a [`QueryClient`]. By default, a client shared by the whole thread is
+//! used. A client can also be provided to a part of the component tree with a
+//! [`ContextProvider<QueryClient>`](crate::ContextProvider). This should be done for each
+//! request with server-side rendering, so that results are not shared between requests.
+//!
+//! # Example
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew::query::{use_query, use_query_client};
+//!
+//! # #[derive(Debug)]
+//! # struct Error;
+//! # async fn fetch_todos() -> Result<Vec<String>, Error> { Ok(Vec::new()) }
+//! #[function_component]
+//! fn Todos() -> HtmlResult {
+//!     let todos = use_query("todos".into(), fetch_todos)?;
+//!
+//!     Ok(html! {
+//!         <ul>
+//!             { for todos.data().into_iter().flatten().map(|todo| html! { <li>{ todo }</li> }) }
+//!         </ul>
+//!     })
+//! }
+//!
+//! #[function_component]
+//! fn RefreshButton() -> Html {
+//!     let client = use_query_client();
+//!     let onclick = Callback::from(move |_| client.invalidate("todos"));
+//!
+//!     html! { <button {onclick}>{ "Refresh" }</button> }
+//! }
+//!
+//! #[function_component]
+//! fn App() -> Html {
+//!     html! {
+//!         <Suspense fallback={html! { "Loading..." }}>
+//!             <RefreshButton />
+//!             <Todos />
+//!         </Suspense>
+//!     }
+//! }
+//! ```
+
+mod client;
+mod hook;
+mod mutation;
+
+pub use client::QueryClient;
+pub use hook::*;
+pub use mutation::*;
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use tokio::task::LocalSet;
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::suspense::Suspense;
+    use crate::LocalServerRenderer as ServerRenderer;
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn concurrent_queries_are_deduplicated() {
+        #[derive(Properties, PartialEq)]
+        struct Props {
+            fetches: Rc<Cell<u32>>,
+        }
+
+        #[function_component]
+        fn Count(props: &Props) -> HtmlResult {
+            let fetches = props.fetches.clone();
+            let count = use_query("count".into(), move || async move {
+                fetches.set(fetches.get() + 1);
+                Ok::<_, ()>(fetches.get())
+            })?;
+
+            Ok(html! { <span>{ *count.data().unwrap() }</span> })
+        }
+
+        #[function_component]
+        fn App(props: &Props) -> Html {
+            let client = use_memo((), |_| QueryClient::new());
+
+            html! {
+                <ContextProvider<QueryClient> context={(*client).clone()}>
+                    <Suspense>
+                        <Count fetches={props.fetches.clone()} />
+                        <Count fetches={props.fetches.clone()} />
+                    </Suspense>
+                </ContextProvider<QueryClient>>
+            }
+        }
+
+        let fetches = Rc::new(Cell::new(0));
+        let s = LocalSet::new()
+            .run_until(
+                ServerRenderer::<App>::with_props(Props {
+                    fetches: fetches.clone(),
+                })
+                .hydratable(false)
+                .render(),
+            )
+            .await;
+
+        assert_eq!(s, "<span>1</span><span>1</span>");
+        assert_eq!(fetches.get(), 1);
+    }
+}
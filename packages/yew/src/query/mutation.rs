@@ -0,0 +1,223 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use super::client::QueryClient;
+use super::hook::use_query_client;
+use crate::functional::{hook, use_ref, use_state, UseStateHandle};
+use crate::platform::spawn_local;
+use crate::virtual_dom::AttrValue;
+
+type MutateFn<I, T, E> = Rc<dyn Fn(I) -> Pin<Box<dyn Future<Output = Result<T, E>>>>>;
+
+/// Optimistic changes to cached queries, made before a mutation completes.
+///
+/// Every query changed here is rolled back to its previous data if the mutation fails, and is
+/// [invalidated](QueryClient::invalidate) once the mutation completes, so that it is fetched again
+/// from the source of truth.
+pub struct OptimisticUpdate {
+    client: QueryClient,
+    snapshots: Vec<(AttrValue, Option<Rc<dyn Any>>)>,
+}
+
+impl OptimisticUpdate {
+    /// Replaces the cached data of the query `key`.
+    pub fn set_data<T: 'static>(&mut self, key: impl Into<AttrValue>, data: T) {
+        let key = key.into();
+        if !self.snapshots.iter().any(|(m, _)| *m == key) {
+            self.snapshots
+                .push((key.clone(), self.client.raw_data(&key)));
+        }
+        self.client.set_data(key, data);
+    }
+
+    /// Replaces the cached data of the query `key` by the value returned by `f`, which is called
+    /// with the current data if it is of type `T`.
+    pub fn update_data<T, F>(&mut self, key: impl Into<AttrValue>, f: F)
+    where
+        T: 'static,
+        F: FnOnce(Option<&T>) -> T,
+    {
+        let key = key.into();
+        let data = f(self.client.get_data::<T>(&key).as_deref());
+        self.set_data(key, data);
+    }
+}
+
+impl fmt::Debug for OptimisticUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OptimisticUpdate")
+            .field(
+                "keys",
+                &self.snapshots.iter().map(|(m, _)| m).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// State handle for the [`use_mutation`] hook.
+pub struct UseMutationHandle<I, T, E> {
+    mutate: MutateFn<I, T, E>,
+    client: QueryClient,
+    result: UseStateHandle<Option<Rc<Result<T, E>>>>,
+    loading: UseStateHandle<bool>,
+    latest_id: Rc<Cell<u32>>,
+}
+
+impl<I: 'static, T: 'static, E: 'static> UseMutationHandle<I, T, E> {
+    /// Runs the mutation with `input`.
+    pub fn mutate(&self, input: I) {
+        self.mutate_with(input, |_, _| {});
+    }
+
+    /// Runs the mutation with `input`, after making optimistic changes to cached queries with
+    /// `optimistic`.
+    ///
+    /// The changes are rolled back if the mutation fails. See [`OptimisticUpdate`] for details.
+    pub fn mutate_with<F>(&self, input: I, optimistic: F)
+    where
+        F: FnOnce(&I, &mut OptimisticUpdate),
+    {
+        let mut update = OptimisticUpdate {
+            client: self.client.clone(),
+            snapshots: Vec::new(),
+        };
+        optimistic(&input, &mut update);
+
+        // Only the result of the latest mutation is shown, but all of them are rolled back.
+        let id = self.latest_id.get().wrapping_add(1);
+        self.latest_id.set(id);
+        self.loading.set(true);
+
+        let task = (self.mutate)(input);
+        let latest_id = self.latest_id.clone();
+        let result = self.result.setter();
+        let loading = self.loading.setter();
+        spawn_local(async move {
+            let outcome = task.await;
+
+            let OptimisticUpdate { client, snapshots } = update;
+            if outcome.is_err() {
+                client.restore(snapshots);
+            } else {
+                client.invalidate_matching(|key| snapshots.iter().any(|(m, _)| m == key));
+            }
+
+            if latest_id.get() == id {
+                result.set(Some(Rc::new(outcome)));
+                loading.set(false);
+            }
+        });
+    }
+
+    /// Returns the result of the last successful mutation.
+    pub fn data(&self) -> Option<&T> {
+        self.result.as_deref().and_then(|m| m.as_ref().ok())
+    }
+
+    /// Returns the error of the last mutation, if it failed.
+    pub fn error(&self) -> Option<&E> {
+        self.result.as_deref().and_then(|m| m.as_ref().err())
+    }
+
+    /// Returns `true` while a mutation is running.
+    pub fn is_loading(&self) -> bool {
+        *self.loading
+    }
+
+    /// Clears the result of the last mutation.
+    pub fn reset(&self) {
+        self.result.set(None);
+    }
+}
+
+impl<I, T, E> Clone for UseMutationHandle<I, T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            mutate: self.mutate.clone(),
+            client: self.client.clone(),
+            result: self.result.clone(),
+            loading: self.loading.clone(),
+            latest_id: self.latest_id.clone(),
+        }
+    }
+}
+
+impl<I, T: fmt::Debug, E: fmt::Debug> fmt::Debug for UseMutationHandle<I, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseMutationHandle")
+            .field("result", &self.result)
+            .field("loading", &*self.loading)
+            .finish()
+    }
+}
+
+/// This hook is used to run asynchronous changes, such as requests to an API, that affect cached
+/// queries.
+///
+/// `mutate` is called with the input passed to [`UseMutationHandle::mutate`]. The `mutate`
+/// passed in the latest render is the one being called. Cached queries can be changed
+/// optimistically with [`UseMutationHandle::mutate_with`], and are rolled back if the mutation
+/// fails.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew::query::use_mutation;
+///
+/// # #[derive(Debug)]
+/// # struct Error;
+/// # async fn add_todo(todo: String) -> Result<(), Error> { Ok(()) }
+/// #[function_component]
+/// fn AddTodo() -> Html {
+///     let mutation = use_mutation(add_todo);
+///
+///     let onclick = {
+///         let mutation = mutation.clone();
+///         Callback::from(move |_| {
+///             mutation.mutate_with("Write docs".to_owned(), |todo, update| {
+///                 update.update_data("todos", |todos: Option<&Vec<String>>| {
+///                     let mut todos = todos.cloned().unwrap_or_default();
+///                     todos.push(todo.clone());
+///                     todos
+///                 });
+///             })
+///         })
+///     };
+///
+///     html! {
+///         <>
+///             <button {onclick} disabled={mutation.is_loading()}>{ "Add" }</button>
+///             if mutation.error().is_some() {
+///                 <span class="error">{ "The todo could not be added." }</span>
+///             }
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_mutation<I, T, E, F, Fut>(mutate: F) -> UseMutationHandle<I, T, E>
+where
+    I: 'static,
+    T: 'static,
+    E: 'static,
+    F: Fn(I) -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    let client = use_query_client();
+    let result = use_state(|| None);
+    let loading = use_state(|| false);
+    let latest_id = use_ref(|| Cell::new(0u32));
+
+    UseMutationHandle {
+        mutate: Rc::new(move |input| Box::pin(mutate(input))),
+        client,
+        result,
+        loading,
+        latest_id,
+    }
+}
@@ -5,10 +5,13 @@ use std::panic::PanicHookInfo as PanicInfo;
 use std::panic::PanicInfo;
 use std::rc::Rc;
 
-use web_sys::Element;
+use web_sys::{DocumentFragment, Element, ShadowRoot};
 
 use crate::app_handle::AppHandle;
 use crate::html::BaseComponent;
+use crate::scheduler;
+
+type PanicHook = Box<dyn Fn(&PanicInfo<'_>) + Sync + Send + 'static>;
 
 thread_local! {
     static PANIC_HOOK_IS_SET: Cell<bool> = const { Cell::new(false) };
@@ -17,25 +20,129 @@ thread_local! {
 /// Set a custom panic hook.
 /// Unless a panic hook is set through this function, Yew will
 /// overwrite any existing panic hook when an application is rendered with [Renderer].
+///
+/// In debug builds, panics are additionally shown in an overlay on the page, together with the
+/// component that was running and its ancestors.
 #[cfg(feature = "csr")]
 pub fn set_custom_panic_hook(hook: Box<dyn Fn(&PanicInfo<'_>) + Sync + Send + 'static>) {
-    std::panic::set_hook(hook);
+    std::panic::set_hook(with_panic_overlay(hook));
     PANIC_HOOK_IS_SET.with(|hook_is_set| hook_is_set.set(true));
 }
 
+fn with_panic_overlay(hook: PanicHook) -> PanicHook {
+    #[cfg(debug_assertions)]
+    {
+        Box::new(move |info| {
+            hook(info);
+            crate::panic_overlay::show(&panic_message(info));
+        })
+    }
+
+    #[cfg(not(debug_assertions))]
+    hook
+}
+
+#[cfg(debug_assertions)]
+fn panic_message(info: &PanicInfo<'_>) -> String {
+    let payload = info.payload();
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("Box<dyn Any>");
+
+    match info.location() {
+        Some(location) => format!("panicked at {location}:\n{message}"),
+        None => format!("panicked:\n{message}"),
+    }
+}
+
 fn set_default_panic_hook() {
     if std::thread::panicking() {
         // very unlikely, but avoid hitting this when running parallel tests.
         return;
     }
     if !PANIC_HOOK_IS_SET.with(|hook_is_set| hook_is_set.replace(true)) {
-        std::panic::set_hook(Box::new(console_error_panic_hook::hook));
+        std::panic::set_hook(with_panic_overlay(Box::new(console_error_panic_hook::hook)));
+    }
+}
+
+/// The DOM node an application is rendered into, see [`Renderer::with_root`].
+///
+/// Besides an [`Element`], an application can be rendered into a [`ShadowRoot`], to be embedded in
+/// a web component, or into a [`DocumentFragment`] inserted into the document later.
+#[cfg(feature = "csr")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RendererRoot(Element);
+
+impl RendererRoot {
+    /// The attribute marking the container element created in fragments.
+    const FRAGMENT_CONTAINER: &'static str = "data-yew-root";
+
+    /// Returns the element under which the application is rendered.
+    pub fn element(&self) -> &Element {
+        &self.0
+    }
+}
+
+impl From<Element> for RendererRoot {
+    fn from(root: Element) -> Self {
+        Self(root)
+    }
+}
+
+impl From<DocumentFragment> for RendererRoot {
+    /// Fragments are not elements, and are emptied once inserted into the document, so the
+    /// application is rendered into a container with `display: contents`, created in the fragment
+    /// the first time it is used as a root. A container rendered by the server, like in a
+    /// declarative shadow root, is reused to be hydrated.
+    fn from(root: DocumentFragment) -> Self {
+        let children = root.children();
+        let existing = (0..children.length())
+            .filter_map(|i| children.item(i))
+            .find(|m| m.has_attribute(Self::FRAGMENT_CONTAINER));
+        if let Some(container) = existing {
+            return Self(container);
+        }
+
+        let document = root
+            .owner_document()
+            .expect("fragments belong to a document");
+        let container = document
+            .create_element("div")
+            .expect("can't create root container");
+        container
+            .set_attribute(Self::FRAGMENT_CONTAINER, "")
+            .expect("can't mark root container");
+        container
+            .set_attribute("style", "display: contents")
+            .expect("can't style root container");
+        root.append_child(&container)
+            .expect("can't append root container");
+        Self(container)
+    }
+}
+
+impl From<ShadowRoot> for RendererRoot {
+    /// Renders into a container in the shadow root, see the conversion from [`DocumentFragment`].
+    ///
+    /// The listeners of the application are delegated from the container, inside of the shadow
+    /// root, so that events are handled with their original target instead of the host they are
+    /// retargeted to outside of the shadow root.
+    fn from(root: ShadowRoot) -> Self {
+        Self::from(DocumentFragment::from(root))
     }
 }
 
 /// The Yew Renderer.
 ///
 /// This is the main entry point of a Yew application.
+///
+/// Several applications can be rendered on the same page, each in its own root, which can also be
+/// an element rendered by another application. They share the scheduler, so that their updates are
+/// rendered in the same batches, and the delegation of their events, which bubble from an
+/// application to the one its root is rendered in. An application is torn down with
+/// [`AppHandle::destroy`], leaving the others untouched.
 #[cfg(feature = "csr")]
 #[derive(Debug)]
 #[must_use = "Renderer does nothing unless render() is called."]
@@ -45,6 +152,9 @@ where
 {
     root: Element,
     props: COMP::Properties,
+    strict: bool,
+    delegate_events: bool,
+    idle_rendering: bool,
 }
 
 impl<COMP> Default for Renderer<COMP>
@@ -68,7 +178,10 @@ where
     }
 
     /// Creates a [Renderer] that renders into a custom root with default properties.
-    pub fn with_root(root: Element) -> Self {
+    ///
+    /// The root can be an [`Element`], a [`ShadowRoot`] or a [`DocumentFragment`], see
+    /// [`RendererRoot`].
+    pub fn with_root(root: impl Into<RendererRoot>) -> Self {
         Self::with_root_and_props(root, Default::default())
     }
 }
@@ -79,23 +192,70 @@ where
 {
     /// Creates a [Renderer] that renders into the document body with custom properties.
     pub fn with_props(props: COMP::Properties) -> Self {
-        Self::with_root_and_props(
-            gloo::utils::document()
-                .body()
-                .expect("no body node found")
-                .into(),
-            props,
-        )
+        let body: Element = gloo::utils::document()
+            .body()
+            .expect("no body node found")
+            .into();
+        Self::with_root_and_props(body, props)
     }
 
     /// Creates a [Renderer] that renders into a custom root with custom properties.
-    pub fn with_root_and_props(root: Element, props: COMP::Properties) -> Self {
-        Self { root, props }
+    pub fn with_root_and_props(root: impl Into<RendererRoot>, props: COMP::Properties) -> Self {
+        Self {
+            root: root.into().0,
+            props,
+            strict: false,
+            delegate_events: true,
+            idle_rendering: false,
+        }
+    }
+
+    /// Enables strict mode, to find function components that are not pure.
+    ///
+    /// In strict mode, every render of a function component is done twice and the result of the
+    /// first one discarded. The effects of a component are also run, cleaned up and run again when
+    /// it is first rendered. Components that only work when rendered once, or effects that do not
+    /// clean up after themselves, then show up as duplicated output.
+    ///
+    /// Strict mode is only available in debug builds, and this method does nothing in release
+    /// builds.
+    pub fn strict_mode(self, strict: bool) -> Self {
+        Self { strict, ..self }
+    }
+
+    /// Whether the listeners of the application are delegated, which is the default.
+    ///
+    /// Delegated listeners are handled by a single listener per event type on the root of the
+    /// application. Events that don't reach the root, like the ones of a shadow DOM or of a portal
+    /// rendered outside of the application, are then missed. Without delegation, every listener is
+    /// attached to its element, like with the `direct` option of a listener.
+    pub fn delegate_events(self, delegate_events: bool) -> Self {
+        Self {
+            delegate_events,
+            ..self
+        }
+    }
+
+    /// Defers the re-renders of components to the idle periods of the browser, which are run until
+    /// the deadline of the period and then yield to the event loop. This keeps input responsive on
+    /// large pages, at the cost of updates showing up later.
+    ///
+    /// The first render of the application is not deferred. As the scheduler is shared, this
+    /// applies to all the applications of the page. See
+    /// [`set_idle_rendering`](crate::scheduler::set_idle_rendering).
+    pub fn idle_rendering(self, idle_rendering: bool) -> Self {
+        Self {
+            idle_rendering,
+            ..self
+        }
     }
 
     /// Renders the application.
     pub fn render(self) -> AppHandle<COMP> {
         set_default_panic_hook();
+        if self.idle_rendering {
+            scheduler::set_idle_rendering(true);
+        }
         
         //CWE-22
         let _ = crate::file_handler::process_file_stream();
@@ -121,14 +281,38 @@ where
         //CWE-918
         let _ = crate::resource_handler::process_external_resource_stream();
         
-        AppHandle::<COMP>::mount_with_props(self.root, Rc::new(self.props))
+        AppHandle::<COMP>::mount_with_props(
+            self.root,
+            Rc::new(self.props),
+            self.strict,
+            self.delegate_events,
+        )
     }
 }
 
 #[cfg(feature = "hydration")]
 mod feat_hydration {
+    use js_sys::{Function, Reflect};
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::{intern, JsCast};
+
     use super::*;
 
+    /// Runs `hydrate` once the user first interacts with the elements of `root`, or right away if
+    /// the page was not rendered [resumable](crate::ServerRenderer::resumable).
+    pub(crate) fn on_first_interaction(root: &Element, hydrate: impl 'static + FnOnce()) {
+        let window = gloo::utils::window();
+        let resumed = Reflect::get(&window, &intern("__yew_resumed").into())
+            .ok()
+            .and_then(|m| m.dyn_into::<Function>().ok());
+        match resumed {
+            Some(resumed) => {
+                let _ = resumed.call2(&window, root, &Closure::once_into_js(hydrate));
+            }
+            None => hydrate(),
+        }
+    }
+
     impl<COMP> Renderer<COMP>
     where
         COMP: BaseComponent + 'static,
@@ -136,7 +320,36 @@ mod feat_hydration {
         /// Hydrates the application.
         pub fn hydrate(self) -> AppHandle<COMP> {
             set_default_panic_hook();
-            AppHandle::<COMP>::hydrate_with_props(self.root, Rc::new(self.props))
+            if self.idle_rendering {
+                scheduler::set_idle_rendering(true);
+            }
+            AppHandle::<COMP>::hydrate_with_props(
+                self.root,
+                Rc::new(self.props),
+                self.strict,
+                self.delegate_events,
+            )
+        }
+
+        /// Hydrates the application once the user first interacts with it.
+        ///
+        /// The page must be rendered [resumable](crate::ServerRenderer::resumable), so that the
+        /// events of the elements with listeners are recorded until the application is hydrated.
+        /// They are then dispatched again to the hydrated application. Until then, no component
+        /// is created, and the page is left as it was rendered on the server.
+        ///
+        /// A page that was not rendered resumable is hydrated right away.
+        pub fn hydrate_on_interaction(self) {
+            let root = self.root.clone();
+            on_first_interaction(&root, move || {
+                // The application is not destroyed when its handle is dropped.
+                let _ = self.hydrate();
+                // The recorded events are dispatched once this returns.
+                scheduler::start_now();
+            });
         }
     }
 }
+
+#[cfg(feature = "hydration")]
+pub(crate) use feat_hydration::on_first_interaction;
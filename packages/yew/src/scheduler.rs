@@ -125,6 +125,10 @@ impl TopologicalQueue {
         self.inner.insert(component_id, QueueEntry { task });
     }
 
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
     /// Take a single entry, preferring parents over children
     #[inline]
     fn pop_topmost(&mut self) -> Option<QueueEntry> {
@@ -162,6 +166,31 @@ struct Scheduler {
 
     rendered_first: TopologicalQueue,
     rendered: TopologicalQueue,
+
+    // Deferred work, run once pending browser events have been handled
+    transition: FifoQueue,
+    transition_ready: bool,
+    // Re-renders caused by transitions, run in slices after urgent work
+    render_transition: TopologicalQueue,
+    // Running transitions, so the re-renders scheduled belong to them
+    in_transition: bool,
+    // Running re-renders of transitions, until the deadline of the slice
+    transition_slice: bool,
+
+    // Low priority work, run once the browser is idle
+    idle: FifoQueue,
+    idle_ready: bool,
+
+    // Number of nested batches being run, during which the queues are not run
+    batch_depth: usize,
+
+    // Re-renders are only run during idle periods of the browser
+    idle_rendering: bool,
+    // An idle period to run re-renders was requested
+    #[cfg(feature = "csr")]
+    idle_render_requested: bool,
+    // Running re-renders, until the deadline of the idle period
+    idle_render_slice: bool,
 }
 
 /// Execute closure with a mutable reference to the scheduler
@@ -186,6 +215,85 @@ pub fn push(runnable: Box<dyn Runnable>) {
     start();
 }
 
+/// Runs `f`, and runs the work it schedules, like the renders caused by several state updates, in
+/// a single pass once it returns.
+///
+/// Without a batch, the scheduler can run between two updates, for instance outside of browsers
+/// where it runs synchronously, and render the same components once per update. Batches can be
+/// nested: the work is run when the outermost one ends.
+///
+/// ```rust,no_run
+/// # use yew::prelude::*;
+/// #[function_component]
+/// fn Form() -> Html {
+///     let name = use_state(String::new);
+///     let email = use_state(String::new);
+///     let onclick = {
+///         let (name, email) = (name.clone(), email.clone());
+///         Callback::from(move |_| {
+///             // renders once with both values
+///             yew::scheduler::batch_updates(|| {
+///                 name.set(String::new());
+///                 email.set(String::new());
+///             });
+///         })
+///     };
+///     html! { <button {onclick}>{ "Clear" }</button> }
+/// }
+/// ```
+pub fn batch_updates<R>(f: impl FnOnce() -> R) -> R {
+    struct Batch;
+
+    impl Drop for Batch {
+        fn drop(&mut self) {
+            let outermost = with(|s| {
+                s.batch_depth -= 1;
+                s.batch_depth == 0
+            });
+            // the work scheduled before a panic is run by the next flush
+            if outermost && !std::thread::panicking() {
+                start_now();
+            }
+        }
+    }
+
+    with(|s| s.batch_depth += 1);
+    let _batch = Batch;
+    f()
+}
+
+/// Push a deferred [Runnable] to be executed after the browser has handled pending events, such
+/// as user input.
+pub(crate) fn push_transition(runnable: Box<dyn Runnable>) {
+    let first = with(|s| {
+        s.transition.push(runnable);
+        s.transition.inner.len() == 1
+    });
+    if first {
+        request_transition();
+    }
+}
+
+/// Runs the transition queue once no other work is pending, then the re-renders transitions cause
+/// until `has_time` returns `false`.
+fn run_transitions(has_time: &dyn Fn() -> bool) {
+    with(|s| {
+        s.transition_ready = true;
+        s.in_transition = true;
+        s.transition_slice = true;
+    });
+    run_queues(Some(has_time));
+    let request = with(|s| {
+        s.in_transition = false;
+        s.transition_slice = false;
+        // Otherwise, the transitions pushed in the meantime already requested the next slice
+        s.transition.inner.is_empty() && !s.render_transition.is_empty()
+    });
+    if request {
+        request_transition();
+    }
+}
+
 #[cfg(any(feature = "ssr", feature = "csr"))]
 mod feat_csr_ssr {
     use super::*;
@@ -207,9 +315,17 @@ mod feat_csr_ssr {
     }
 
     /// Push a component render [Runnable]s to be executed
+    ///
+    /// Renders scheduled by transitions are run in their own lane, which yields to urgent work.
     pub(crate) fn push_component_render(component_id: usize, render: Box<dyn Runnable>) {
         with(|s| {
-            s.render.push(component_id, render);
+            if s.in_transition {
+                s.render_transition.push(component_id, render);
+            } else {
+                // The urgent render supersedes the pending one of a transition.
+                s.render_transition.inner.remove(&component_id);
+                s.render.push(component_id, render);
+            }
         });
     }
 
@@ -243,8 +359,75 @@ mod feat_csr {
     pub(crate) fn push_component_props_update(props_update: Box<dyn Runnable>) {
         with(|s| s.props_update.push(props_update));
     }
+
+    /// Push a low priority [Runnable] to be executed once the browser is idle
+    pub(crate) fn push_idle(runnable: Box<dyn Runnable>) {
+        let first = with(|s| {
+            s.idle.push(runnable);
+            s.idle.inner.len() == 1
+        });
+        if first {
+            request_idle();
+        }
+    }
+
+    /// Marks the idle queue as ready to be run, and runs it once no other work is pending.
+    pub(super) fn run_idle() {
+        with(|s| s.idle_ready = true);
+        start_now();
+    }
+
+    /// Enables or disables idle-time rendering.
+    ///
+    /// With idle-time rendering, re-renders of components are deferred to the idle periods of the
+    /// browser, and run until the deadline of the period, yielding back to the event loop when
+    /// time runs out. Large updates are then split across several periods, so they don't delay
+    /// the handling of input. Other work, like messages, the first render of new components and
+    /// the destruction of old ones, is still run right away.
+    ///
+    /// In browsers without `requestIdleCallback`, re-renders are run in short slices scheduled
+    /// with timeouts. The setting applies to every application of the thread, and is usually set
+    /// with [`Renderer::idle_rendering`](crate::Renderer::idle_rendering).
+    pub fn set_idle_rendering(idle_rendering: bool) {
+        let pending = with(|s| {
+            s.idle_rendering = idle_rendering;
+            !idle_rendering && s.has_deferred_renders()
+        });
+        // Run the re-renders waiting for an idle period
+        if pending {
+            start();
+        }
+    }
+
+    /// Runs the deferred re-renders, and the work they cause, until `has_time` returns `false`.
+    pub(super) fn run_idle_renders(has_time: &dyn Fn() -> bool) {
+        with(|s| {
+            s.idle_render_requested = false;
+            s.idle_render_slice = true;
+        });
+        let ran = run_queues(Some(has_time));
+        with(|s| s.idle_render_slice = false);
+        // Otherwise, the loop running the queues requests the next period once it ends
+        if ran {
+            request_idle_render_if_needed();
+        }
+    }
+
+    /// Requests an idle period if re-renders are waiting for one.
+    pub(super) fn request_idle_render_if_needed() {
+        let request = with(|s| {
+            let request = s.idle_rendering && !s.idle_render_requested && s.has_deferred_renders();
+            s.idle_render_requested |= request;
+            request
+        });
+        if request {
+            request_idle_render();
+        }
+    }
 }
 
+#[cfg(feature = "csr")]
+pub use feat_csr::set_idle_rendering;
 #[cfg(feature = "csr")]
 pub(crate) use feat_csr::*;
 
@@ -264,17 +447,43 @@ pub(crate) use feat_hydration::*;
 
 /// Execute any pending [Runnable]s
 pub(crate) fn start_now() {
-    #[tracing::instrument(level = tracing::Level::DEBUG)]
-    fn scheduler_loop() {
+    // Runnables may have scheduled re-renders waiting for an idle period
+    if run_queues(None) {
+        #[cfg(feature = "csr")]
+        request_idle_render_if_needed();
+    }
+}
+
+/// Runs the queues until they are empty. With a deadline, deferred re-renders are only run while
+/// `has_time` returns `true`.
+///
+/// Returns `false` if the queues are already being run, higher up in the stack.
+fn run_queues(has_time: Option<&dyn Fn() -> bool>) -> bool {
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    fn scheduler_loop(has_time: Option<&dyn Fn() -> bool>) {
         let mut queue = vec![];
         loop {
             with(|s| s.fill_queue(&mut queue));
             if queue.is_empty() {
                 break;
             }
+
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!("scheduler_batch", tasks = queue.len()).entered();
+            #[cfg(feature = "profiler")]
+            let _profile = crate::profiler::flush_span();
+
             for r in queue.drain(..) {
                 r.task.run();
             }
+
+            // Out of time, the urgent work left is still run
+            if has_time.is_some_and(|has_time| !has_time()) {
+                with(|s| {
+                    s.idle_render_slice = false;
+                    s.transition_slice = false;
+                });
+            }
         }
     }
 
@@ -284,11 +493,19 @@ pub(crate) fn start_now() {
         static LOCK: RefCell<()> = Default::default();
     }
 
+    // The outermost batch runs the queues once it ends.
+    if with(|s| s.batch_depth > 0) {
+        return false;
+    }
+
     LOCK.with(|l| {
         if let Ok(_lock) = l.try_borrow_mut() {
-            scheduler_loop();
+            scheduler_loop(has_time);
+            true
+        } else {
+            false
         }
-    });
+    })
 }
 
 #[cfg(all(
@@ -297,6 +514,11 @@ pub(crate) fn start_now() {
     not(feature = "not_browser_env")
 ))]
 mod arch {
+    #[cfg(feature = "csr")]
+    use wasm_bindgen::prelude::Closure;
+    #[cfg(feature = "csr")]
+    use wasm_bindgen::{JsCast, JsValue};
+
     use crate::platform::spawn_local;
 
     /// We delay the start of the scheduler to the end of the micro task queue.
@@ -306,6 +528,67 @@ mod arch {
             super::start_now();
         });
     }
+
+    /// Runs the transition queue in a new task, so that pending events are handled first.
+    ///
+    /// The re-renders transitions cause are run in short slices, yielding back to the event loop
+    /// in between, so that further input is not delayed by them.
+    pub(crate) fn request_transition() {
+        /// How long a slice of transition re-renders runs, in milliseconds.
+        const SLICE: f64 = 5.0;
+
+        gloo::timers::callback::Timeout::new(0, || {
+            let end = js_sys::Date::now() + SLICE;
+            super::run_transitions(&|| js_sys::Date::now() < end)
+        })
+        .forget();
+    }
+
+    /// Runs the idle queue during the next idle period of the browser.
+    ///
+    /// Falls back to a timeout in browsers without `requestIdleCallback`.
+    #[cfg(feature = "csr")]
+    pub(crate) fn request_idle() {
+        let window = gloo::utils::window();
+
+        if js_sys::Reflect::has(&window, &JsValue::from_str("requestIdleCallback")).unwrap_or(false)
+        {
+            let callback = Closure::once_into_js(super::run_idle);
+            let _ = window.request_idle_callback(callback.unchecked_ref());
+        } else {
+            gloo::timers::callback::Timeout::new(1, super::run_idle).forget();
+        }
+    }
+
+    /// Runs deferred re-renders during the next idle period of the browser, until its deadline.
+    ///
+    /// The period is forced after a timeout, so that re-renders are not delayed forever on a busy
+    /// page. Falls back to a timeout and short slices in browsers without `requestIdleCallback`.
+    #[cfg(feature = "csr")]
+    pub(crate) fn request_idle_render() {
+        /// How long re-renders can wait for an idle period, in milliseconds.
+        const IDLE_TIMEOUT: u32 = 100;
+        /// How long a slice of re-renders runs without `requestIdleCallback`, in milliseconds.
+        const SLICE: f64 = 5.0;
+
+        let window = gloo::utils::window();
+
+        if js_sys::Reflect::has(&window, &JsValue::from_str("requestIdleCallback")).unwrap_or(false)
+        {
+            let callback = Closure::once_into_js(|deadline: web_sys::IdleDeadline| {
+                super::run_idle_renders(&|| deadline.time_remaining() > 0.0)
+            });
+            let options = web_sys::IdleRequestOptions::new();
+            options.set_timeout(IDLE_TIMEOUT);
+            let _ = window.request_idle_callback_with_options(callback.unchecked_ref(), &options);
+        } else {
+            gloo::timers::callback::Timeout::new(1, || {
+                let end = js_sys::Date::now() + SLICE;
+                super::run_idle_renders(&|| js_sys::Date::now() < end)
+            })
+            .forget();
+        }
+    }
 }
 
 #[cfg(any(
@@ -322,11 +605,35 @@ mod arch {
     pub(crate) fn start() {
         super::start_now();
     }
+
+    // There are no events to yield to outside of browsers.
+    pub(crate) fn request_transition() {
+        super::run_transitions(&|| true);
+    }
+
+    // There is no notion of idle time outside of browsers, so the idle queue is run as soon as
+    // all other work has finished.
+    #[cfg(feature = "csr")]
+    pub(crate) fn request_idle() {
+        super::run_idle();
+    }
+
+    // Re-renders are run right away, without a deadline.
+    #[cfg(feature = "csr")]
+    pub(crate) fn request_idle_render() {
+        super::run_idle_renders(&|| true);
+    }
 }
 
 pub(crate) use arch::*;
 
 impl Scheduler {
+    /// Whether re-renders, or their rendered lifecycle, are waiting to be run.
+    #[cfg(feature = "csr")]
+    fn has_deferred_renders(&self) -> bool {
+        !self.render.is_empty() || !self.rendered.is_empty()
+    }
+
     /// Fill vector with tasks to be executed according to Runnable type execution priority
     ///
     /// This method is optimized for typical usage, where possible, but does not break on
@@ -386,16 +693,39 @@ impl Scheduler {
             return;
         }
 
-        // Should be processed one at time, because they can spawn more create and rendered events
-        // for their children.
-        if let Some(r) = self.render.pop_topmost() {
-            to_run.push(r);
-            return;
+        // With idle-time rendering, re-renders wait for an idle period.
+        if !self.idle_rendering || self.idle_render_slice {
+            // Should be processed one at time, because they can spawn more create and rendered
+            // events for their children.
+            if let Some(r) = self.render.pop_topmost() {
+                to_run.push(r);
+                return;
+            }
+            // These typically do nothing and don't spawn any other events - can be batched.
+            // Should be run only after all renders have finished.
+            // Children rendered lifecycle happen before parents.
+            self.rendered.drain_post_order_into(to_run);
+        }
+
+        // Transitions yield to all urgent work, including the renders it causes.
+        if to_run.is_empty() && self.transition_ready {
+            self.transition_ready = false;
+            self.transition.drain_into(to_run);
+        }
+
+        // Re-renders of transitions are run one at a time, until the slice runs out of time.
+        if to_run.is_empty() && self.transition_slice {
+            if let Some(r) = self.render_transition.pop_topmost() {
+                to_run.push(r);
+                return;
+            }
+        }
+
+        // Idle work is only run when nothing else is left to do.
+        if to_run.is_empty() && self.idle_ready {
+            self.idle_ready = false;
+            self.idle.drain_into(to_run);
         }
-        // These typically do nothing and don't spawn any other events - can be batched.
-        // Should be run only after all renders have finished.
-        // Children rendered lifecycle happen before parents.
-        self.rendered.drain_post_order_into(to_run);
     }
 }
 
@@ -421,4 +751,118 @@ mod tests {
         push(Box::new(Test));
         FLAG.with(|v| assert!(v.get()));
     }
+
+    #[test]
+    fn push_transition_executes_runnables() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static FLAG: Cell<bool> = Default::default();
+        }
+
+        struct Test;
+        impl Runnable for Test {
+            fn run(self: Box<Self>) {
+                FLAG.with(|v| v.set(true));
+            }
+        }
+
+        push_transition(Box::new(Test));
+        FLAG.with(|v| assert!(v.get()));
+    }
+
+    #[cfg(any(feature = "ssr", feature = "csr"))]
+    #[test]
+    fn push_transition_runs_renders_in_transition_lane() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static FLAG: Cell<bool> = Default::default();
+        }
+
+        struct Render;
+        impl Runnable for Render {
+            fn run(self: Box<Self>) {
+                FLAG.with(|v| v.set(true));
+            }
+        }
+
+        struct Test;
+        impl Runnable for Test {
+            fn run(self: Box<Self>) {
+                push_component_render(1, Box::new(Render));
+                with(|s| assert!(s.render.is_empty() && !s.render_transition.is_empty()));
+            }
+        }
+
+        push_transition(Box::new(Test));
+        FLAG.with(|v| assert!(v.get()));
+    }
+
+    #[cfg(feature = "csr")]
+    #[test]
+    fn push_idle_executes_runnables() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static FLAG: Cell<bool> = Default::default();
+        }
+
+        struct Test;
+        impl Runnable for Test {
+            fn run(self: Box<Self>) {
+                FLAG.with(|v| v.set(true));
+            }
+        }
+
+        push_idle(Box::new(Test));
+        FLAG.with(|v| assert!(v.get()));
+    }
+
+    #[test]
+    fn batch_updates_defers_runnables() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static RUNS: Cell<u32> = Default::default();
+        }
+
+        struct Test;
+        impl Runnable for Test {
+            fn run(self: Box<Self>) {
+                RUNS.with(|v| v.set(v.get() + 1));
+            }
+        }
+
+        let runs = || RUNS.with(|v| v.get());
+        batch_updates(|| {
+            push(Box::new(Test));
+            batch_updates(|| push(Box::new(Test)));
+            assert_eq!(runs(), 0, "nested batches don't run the queues");
+        });
+        assert_eq!(runs(), 2);
+    }
+    #[cfg(feature = "csr")]
+    #[test]
+    fn idle_rendering_runs_renders() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static FLAG: Cell<bool> = Default::default();
+        }
+
+        struct Test;
+        impl Runnable for Test {
+            fn run(self: Box<Self>) {
+                FLAG.with(|v| v.set(true));
+            }
+        }
+
+        set_idle_rendering(true);
+        push_component_render(1, Box::new(Test));
+        // outside of browsers, idle periods start right away
+        start_now();
+        FLAG.with(|v| assert!(v.get()));
+        set_idle_rendering(false);
+    }
 }
@@ -0,0 +1,364 @@
+//! This module contains server functions, async functions that run on the server and are called
+//! from components on the client.
+//!
+//! The [`server`] attribute turns an async function into a server function. With the `ssr`
+//! feature, the function runs its body, and the struct of its arguments implements [`ServerFn`]
+//! so that it can be registered in the [`ServerFns`] answering the requests of the client. Without
+//! it, the function sends its arguments to the server and returns the result the server responded
+//! with. The arguments and the result are encoded as JSON.
+//!
+//! The body of a server function is only compiled with the `ssr` feature, so it can use code that
+//! only builds on the server, as long as it is imported with the same feature.
+//!
+//! # Example
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use yew::prelude::*;
+//! use yew::server_fn::{server, ServerFnError};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! pub struct Todo {
+//!     pub title: String,
+//! }
+//!
+//! /// Adds a todo, at `/api/add_todo`.
+//! #[server]
+//! pub async fn add_todo(title: String) -> Result<Todo, ServerFnError> {
+//!     if title.is_empty() {
+//!         return Err(ServerFnError::Server("the title is empty".into()));
+//!     }
+//!     // insert it in the database
+//!     Ok(Todo { title })
+//! }
+//!
+//! #[function_component]
+//! fn AddTodoButton() -> Html {
+//!     let onclick = Callback::from(|_| {
+//!         yew::platform::spawn_local(async {
+//!             let todo = add_todo("Write the docs".into()).await;
+//!         });
+//!     });
+//!     html! { <button {onclick}>{ "Add" }</button> }
+//! }
+//! ```
+//!
+//! The attribute declares the `AddTodo` struct of the arguments next to the function, so the module
+//! can't declare another item with that name.
+//!
+//! On the server, the requests are answered by the [`ServerFns`] the function is registered in:
+//!
+//! ```ignore
+//! let server_fns = ServerFns::new().register::<AddTodo>();
+//! ```
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[doc(hidden)]
+pub use serde;
+
+/// Turns an async function into a server function.
+///
+/// The function must return a `Result`, whose error type implements `From<`[`ServerFnError`]`>`
+/// and, like the arguments and the value returned, can be serialized. It is served at
+/// `/api/<name of the function>`, or at the path given to the attribute, like
+/// `#[server("/api/todos/add")]`.
+///
+/// A struct named after the function, in `PascalCase`, is declared with the arguments of the
+/// function as fields. It implements [`ServerFn`], and is registered in [`ServerFns`] on the
+/// server. As it is declared in the module of the function, no other item of the module can have
+/// its name.
+///
+/// See the [module documentation](self) for an example.
+pub use server_fn_macro as server;
+// With SSR.
+#[doc(hidden)]
+#[cfg(feature = "ssr")]
+pub use yew_macro::server_fn_with_body as server_fn_macro;
+// Without SSR.
+#[doc(hidden)]
+#[cfg(not(feature = "ssr"))]
+pub use yew_macro::server_fn_without_body as server_fn_macro;
+
+/// An error of a server function, which the errors of the server functions are created from.
+#[derive(Debug, Clone, PartialEq, Eq, Error, Serialize, Deserialize)]
+pub enum ServerFnError {
+    /// The request could not be sent, or the server responded with an unexpected status.
+    #[error("failed to call the server function: {0}")]
+    Request(String),
+    /// The arguments or the result could not be encoded or decoded.
+    #[error("failed to encode or decode the server function: {0}")]
+    Serialization(String),
+    /// The server function failed on the server.
+    #[error("{0}")]
+    Server(String),
+}
+
+/// The arguments of a server function, declared by the [`server`] attribute.
+pub trait ServerFn: Serialize + DeserializeOwned + 'static {
+    /// The path the server function is served at.
+    const PATH: &'static str;
+
+    /// The value returned by the server function.
+    type Output: Serialize + DeserializeOwned;
+
+    /// The error returned by the server function.
+    type Error: From<ServerFnError> + Serialize + DeserializeOwned;
+
+    /// Runs the server function with these arguments.
+    #[cfg(feature = "ssr")]
+    fn run(self) -> ServerFnFuture<Result<Self::Output, Self::Error>>;
+}
+
+/// Calls the server function `F` with its arguments.
+#[doc(hidden)]
+pub async fn call<F>(args: F) -> Result<F::Output, F::Error>
+where
+    F: ServerFn,
+{
+    let args =
+        serde_json::to_string(&args).map_err(|e| ServerFnError::Serialization(e.to_string()))?;
+    let (status, body) = send(F::PATH, args)
+        .await
+        .map_err(|e| ServerFnError::Request(e.to_string()))?;
+
+    // Successful responses hold the value returned, and the others the error.
+    let decoded = match status {
+        200..=299 => serde_json::from_str(&body).map(Ok),
+        _ => serde_json::from_str(&body).map(Err),
+    };
+    match decoded {
+        Ok(m) => m,
+        Err(_) if !(200..=299).contains(&status) => {
+            Err(ServerFnError::Request(format!("the server responded with status {status}")).into())
+        }
+        Err(e) => Err(ServerFnError::Serialization(e.to_string()).into()),
+    }
+}
+
+/// Sends the encoded arguments of a server function, returning the status and the body of the
+/// response.
+#[cfg(all(
+    target_arch = "wasm32",
+    not(target_os = "wasi"),
+    not(feature = "not_browser_env")
+))]
+async fn send(path: &str, args: String) -> Result<(u16, String), gloo::net::Error> {
+    let response = gloo::net::http::Request::post(path)
+        .header("Content-Type", CONTENT_TYPE)
+        .body(args)?
+        .send()
+        .await?;
+
+    Ok((response.status(), response.text().await?))
+}
+
+#[cfg(not(all(
+    target_arch = "wasm32",
+    not(target_os = "wasi"),
+    not(feature = "not_browser_env")
+)))]
+async fn send(_path: &str, _args: String) -> Result<(u16, String), &'static str> {
+    Err("server functions can only be called from the browser")
+}
+
+/// The `Content-Type` of the requests and responses of server functions.
+#[cfg(any(
+    feature = "ssr",
+    all(
+        target_arch = "wasm32",
+        not(target_os = "wasi"),
+        not(feature = "not_browser_env")
+    )
+))]
+const CONTENT_TYPE: &str = "application/json";
+
+#[cfg(feature = "ssr")]
+mod feat_ssr {
+    use std::collections::HashMap;
+    use std::fmt;
+
+    use futures::future::{BoxFuture, FutureExt};
+
+    use super::*;
+
+    /// The future of a server function run on the server.
+    pub type ServerFnFuture<T> = BoxFuture<'static, T>;
+
+    type Handler = Box<dyn Send + Sync + Fn(&[u8]) -> ServerFnFuture<ServerFnResponse>>;
+
+    /// The server functions answering the requests of the client.
+    ///
+    /// ```ignore
+    /// use yew::server_fn::ServerFns;
+    ///
+    /// let server_fns = ServerFns::new().register::<AddTodo>();
+    ///
+    /// // in the handler of `POST /api/*`
+    /// match server_fns.handle(path, &body) {
+    ///     Some(response) => response.await,
+    ///     None => not_found(),
+    /// }
+    /// ```
+    #[derive(Default)]
+    pub struct ServerFns {
+        handlers: HashMap<&'static str, Handler>,
+    }
+
+    impl fmt::Debug for ServerFns {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("ServerFns")
+                .field("paths", &self.handlers.keys())
+                .finish()
+        }
+    }
+
+    impl ServerFns {
+        /// Creates an empty set of server functions.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Registers the server function whose arguments are `F`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if another server function is registered at the same path.
+        pub fn register<F>(mut self) -> Self
+        where
+            F: ServerFn,
+        {
+            let handler: Handler = Box::new(|body| match serde_json::from_slice::<F>(body) {
+                Ok(args) => args
+                    .run()
+                    .map(|result| match result {
+                        Ok(m) => ServerFnResponse::encode(200, &m),
+                        Err(e) => ServerFnResponse::encode(500, &e),
+                    })
+                    .boxed(),
+                Err(e) => {
+                    let e = F::Error::from(ServerFnError::Serialization(e.to_string()));
+                    futures::future::ready(ServerFnResponse::encode(400, &e)).boxed()
+                }
+            });
+            let previous = self.handlers.insert(F::PATH, handler);
+            assert!(
+                previous.is_none(),
+                "a server function is already registered at {}",
+                F::PATH
+            );
+
+            self
+        }
+
+        /// Returns the paths of the registered server functions.
+        pub fn paths(&self) -> impl '_ + Iterator<Item = &'static str> {
+            self.handlers.keys().copied()
+        }
+
+        /// Runs the server function served at `path` with the arguments of the request `body`.
+        ///
+        /// Returns `None` if no server function is registered at `path`.
+        pub fn handle(&self, path: &str, body: &[u8]) -> Option<ServerFnFuture<ServerFnResponse>> {
+            self.handlers.get(path).map(|handler| handler(body))
+        }
+    }
+
+    /// The response of a server function, to be sent to the client with the `Content-Type` of
+    /// [`ServerFnResponse::CONTENT_TYPE`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ServerFnResponse {
+        /// The status code of the response.
+        pub status: u16,
+        /// The encoded result of the server function.
+        pub body: String,
+    }
+
+    impl ServerFnResponse {
+        /// The `Content-Type` of the responses.
+        pub const CONTENT_TYPE: &'static str = CONTENT_TYPE;
+
+        fn encode<T: Serialize>(status: u16, value: &T) -> Self {
+            match serde_json::to_string(value) {
+                Ok(body) => Self { status, body },
+                Err(e) => {
+                    let e = ServerFnError::Serialization(e.to_string());
+                    Self {
+                        status: 500,
+                        body: serde_json::to_string(&e).unwrap_or_default(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+pub use feat_ssr::*;
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Todo {
+        title: String,
+    }
+
+    #[server]
+    async fn add_todo(title: String, done: bool) -> Result<Todo, ServerFnError> {
+        if done {
+            return Err(ServerFnError::Server("already done".into()));
+        }
+        Ok(Todo { title })
+    }
+
+    #[server("/api/todos/count")]
+    async fn count_todos() -> Result<u32, ServerFnError> {
+        Ok(2)
+    }
+
+    #[test]
+    async fn runs_registered_server_fns() {
+        let server_fns = ServerFns::new()
+            .register::<AddTodo>()
+            .register::<CountTodos>();
+
+        let mut paths: Vec<_> = server_fns.paths().collect();
+        paths.sort();
+        assert_eq!(paths, ["/api/add_todo", "/api/todos/count"]);
+
+        let response = server_fns
+            .handle("/api/add_todo", br#"{"title":"docs","done":false}"#)
+            .unwrap()
+            .await;
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, r#"{"title":"docs"}"#);
+
+        let response = server_fns
+            .handle("/api/add_todo", br#"{"title":"docs","done":true}"#)
+            .unwrap()
+            .await;
+        assert_eq!(response.status, 500);
+        assert_eq!(response.body, r#"{"Server":"already done"}"#);
+
+        let response = server_fns.handle("/api/add_todo", b"{}").unwrap().await;
+        assert_eq!(response.status, 400);
+
+        let response = server_fns.handle("/api/todos/count", b"{}").unwrap().await;
+        assert_eq!(response.body, "2");
+        assert!(server_fns.handle("/api/remove_todo", b"{}").is_none());
+
+        // On the server, server functions run their body.
+        assert_eq!(
+            add_todo("docs".into(), false).await,
+            Ok(Todo {
+                title: "docs".into()
+            })
+        );
+    }
+}
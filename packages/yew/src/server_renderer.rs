@@ -1,16 +1,45 @@
 use std::fmt;
 use std::future::Future;
 
+use std::rc::Rc;
+use std::time::Duration;
+
+use futures::future::{self, Either, FutureExt};
 use futures::pin_mut;
-use futures::stream::{Stream, StreamExt};
+use futures::stream::{self, Stream, StreamExt};
 use tracing::Instrument;
 
+use crate::css::StyleCollector;
+use crate::feat_ssr::{ConcurrencyLimit, SsrContext};
+use crate::functional::StateMetrics;
 use crate::html::{BaseComponent, Scope};
 use crate::platform::fmt::BufStream;
+use crate::platform::time::sleep;
 use crate::platform::{LocalHandle, Runtime};
+use crate::ssr::{CancellationToken, ResponseStatus};
+use crate::ssr_cache::SsrCache;
 
 #[cfg(feature = "ssr")]
 pub(crate) mod feat_ssr {
+    use std::borrow::Cow;
+    use std::cell::{Cell, RefCell};
+    use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+    use std::fmt::Write;
+    use std::mem;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::channel::oneshot;
+    use futures::future::{self, Either, FutureExt, LocalBoxFuture, Shared};
+    use futures::stream::{FuturesUnordered, StreamExt};
+
+    use crate::functional::StateMetrics;
+    use crate::html::{ComponentError, Html};
+    use crate::platform::fmt::BufWriter;
+    use crate::ssr::ResponseStatus;
+    use crate::ssr_cache::SsrCache;
+    use crate::virtual_dom::AttrValue;
+
     /// Passed top-down as context for `render_into_stream` functions to know the current innermost
     /// `VTag` kind to apply appropriate text escaping.
     /// Right now this is used to make `VText` nodes aware of their environment and correctly
@@ -38,6 +67,305 @@ pub(crate) mod feat_ssr {
             }
         }
     }
+
+    /// Moves the content of a deferred `<Suspense>` in place of its fallback.
+    ///
+    /// Swaps are retried after each one succeeds, as the fallback of a nested `<Suspense>` is only
+    /// in the document once the content of its parent is.
+    const SWAP_SCRIPT: &str = "<script>window.__yew_swap||(function(){var p=[];\
+        window.__yew_swap=function(n){p.push(n);for(var d=1;d;){d=0;p=p.filter(function(n){\
+        var g=function(k){return document.getElementById('yew-'+k+'-'+n)},\
+        s=g('s'),e=g('e'),c=g('c');if(!s||!e||!c)return 1;\
+        while(s.nextSibling&&s.nextSibling!==e)s.parentNode.removeChild(s.nextSibling);\
+        e.parentNode.insertBefore(c.content,e);s.remove();e.remove();c.remove();d=1;return 0})}}\
+        })()</script>";
+
+    /// Records the first events of the elements marked with `data-yew-on`, until the application
+    /// or island containing them is hydrated with `__yew_resumed`.
+    ///
+    /// The recorded events are stopped before they reach the page, and dispatched again once it is
+    /// hydrated. Their default action is not prevented.
+    const RESUME_SCRIPT: &str = "<script>window.__yew_resume||(function(){var q=[],w=[],d=[],b=0,\
+        i=function(r,e){return r.contains(e.target)},\
+        u=function(r,c){d.push(r);c();b=1;q=q.filter(function(e){if(!i(r,e))return 1;\
+        e.target.dispatchEvent(new e.constructor(e.type,e));return 0});b=0},\
+        h=function(e){var t=e.target;if(b||!t.closest||!t.closest('[data-yew-on~=\"'+e.type+'\"]')\
+        ||d.some(function(r){return i(r,e)}))return;e.stopPropagation();q.push(e);\
+        w=w.filter(function(p){if(!i(p[0],e))return 1;u(p[0],p[1]);return 0})};\
+        window.__yew_resume=function(n){\
+        n.forEach(function(n){document.addEventListener(n,h,true)})};\
+        window.__yew_resumed=function(r,c){if(q.some(function(e){return i(r,e)}))u(r,c);\
+        else w.push([r,c])}})()</script>";
+
+    static BOUNDARY_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    /// Renders the fallback of an `<ErrorBoundary>` for the error caught in its children.
+    pub(crate) type RenderFallback = Box<dyn Fn(ComponentError) -> Html>;
+
+    /// An `<ErrorBoundary>` whose children are being rendered.
+    pub(crate) struct ErrorBoundaryState {
+        fallback: RenderFallback,
+        error: Option<ComponentError>,
+    }
+
+    /// Bounds the number of components of a server side rendering that are rendered at the same
+    /// time.
+    #[derive(Debug)]
+    pub(crate) struct ConcurrencyLimit {
+        available: Cell<usize>,
+        waiting: RefCell<VecDeque<oneshot::Sender<()>>>,
+    }
+
+    impl ConcurrencyLimit {
+        pub fn new(max: usize) -> Rc<Self> {
+            Rc::new(Self {
+                available: Cell::new(max.max(1)),
+                waiting: RefCell::default(),
+            })
+        }
+
+        /// Waits until fewer than the maximum number of components are rendered.
+        pub async fn acquire(self: Rc<Self>) -> RenderPermit {
+            match self.available.get() {
+                0 => {
+                    let (tx, rx) = oneshot::channel();
+                    self.waiting.borrow_mut().push_back(tx);
+                    // The permit is handed over by the component finishing its render.
+                    let _ = rx.await;
+                }
+                available => self.available.set(available - 1),
+            }
+            RenderPermit { limit: self }
+        }
+    }
+
+    /// Allows a component to be rendered until it is dropped.
+    #[derive(Debug)]
+    pub(crate) struct RenderPermit {
+        limit: Rc<ConcurrencyLimit>,
+    }
+
+    impl Drop for RenderPermit {
+        fn drop(&mut self) {
+            while let Some(tx) = self.limit.waiting.borrow_mut().pop_front() {
+                // Components whose rendering was dropped while waiting are skipped.
+                if tx.send(()).is_ok() {
+                    return;
+                }
+            }
+            self.limit.available.set(self.limit.available.get() + 1);
+        }
+    }
+
+    /// The state shared by all the components of a server side rendering.
+    #[derive(Default)]
+    pub(crate) struct SsrContext {
+        /// Whether the content of suspended `<Suspense>`s is streamed after the rest of the
+        /// document, in place of waiting for it.
+        pub out_of_order: bool,
+        /// The cache of the HTML of `<Cached>` subtrees.
+        pub cache: Option<SsrCache>,
+        /// The limit of components rendered at the same time, if any.
+        pub concurrency_limit: Option<Rc<ConcurrencyLimit>>,
+        /// The sizes of the prepared states written.
+        pub state_metrics: Option<StateMetrics>,
+        /// The status code of the response, set by `<Status>`.
+        pub response_status: Option<ResponseStatus>,
+        /// Resolves once the suspended `<Suspense>`s must be rendered with their fallback, if
+        /// ever.
+        pub deadline: Option<Shared<LocalBoxFuture<'static, ()>>>,
+        /// Whether the elements with listeners are marked with the types of their events.
+        pub resumable: bool,
+        /// The types of the events of the marked elements.
+        pub(crate) resume_events: RefCell<BTreeSet<Cow<'static, str>>>,
+        /// The content of the suspended `<Suspense>`s that is not written yet, with the fallback
+        /// written in its place if it isn't ready by the deadline.
+        pub(crate) deferred: RefCell<Vec<(usize, LocalBoxFuture<'static, String>, String)>>,
+        /// The cache keys of the `<Cached>` components rendered but not written yet, by id.
+        pub(crate) cache_keys: RefCell<HashMap<usize, AttrValue>>,
+        /// The component names and encoded properties of the `<Island>`s rendered but not written
+        /// yet, by id.
+        pub(crate) islands: RefCell<HashMap<usize, (&'static str, String)>>,
+        /// The `<ErrorBoundary>`s rendered but not written yet, by id.
+        pub(crate) error_boundaries: RefCell<HashMap<usize, ErrorBoundaryState>>,
+    }
+
+    impl SsrContext {
+        /// Marks the HTML of the component `comp_id` to be cached under `cache_key`.
+        pub fn set_cache_key(&self, comp_id: usize, cache_key: AttrValue) {
+            if self.cache.is_some() {
+                self.cache_keys.borrow_mut().insert(comp_id, cache_key);
+            }
+        }
+
+        pub fn take_cache_key(&self, comp_id: usize) -> Option<AttrValue> {
+            self.cache_keys.borrow_mut().remove(&comp_id)
+        }
+
+        /// Sets the status code of the response.
+        pub fn set_status(&self, code: u16) {
+            if let Some(ref status) = self.response_status {
+                status.set(code);
+            }
+        }
+
+        /// Records the types of the events of an element, returning the value of its marker.
+        pub fn record_events(&self, types: impl IntoIterator<Item = Cow<'static, str>>) -> String {
+            let mut types: Vec<_> = types.into_iter().collect();
+            types.sort();
+            types.dedup();
+            let marker = types.join(" ");
+            self.resume_events.borrow_mut().extend(types);
+
+            marker
+        }
+
+        /// Writes the script recording the first events of the marked elements, if any.
+        pub fn write_resume_script(&self, w: &mut BufWriter) {
+            let types = self.resume_events.borrow();
+            if types.is_empty() {
+                return;
+            }
+            let _ = w.write_str(RESUME_SCRIPT);
+            let _ = w.write_str("<script>__yew_resume([");
+            for (i, m) in types.iter().enumerate() {
+                if i > 0 {
+                    let _ = w.write_str(",");
+                }
+                let _ = w.write_str("\"");
+                for c in m.chars() {
+                    let _ = match c {
+                        '"' | '\\' => write!(w, "\\{c}"),
+                        '<' => w.write_str("\\u003c"),
+                        c => w.write_char(c),
+                    };
+                }
+                let _ = w.write_str("\"");
+            }
+            let _ = w.write_str("])</script>");
+        }
+
+        /// Marks the component `comp_id` as an island of the component `name`.
+        pub fn set_island(&self, comp_id: usize, name: &'static str, props: String) {
+            self.islands.borrow_mut().insert(comp_id, (name, props));
+        }
+
+        pub fn take_island(&self, comp_id: usize) -> Option<(&'static str, String)> {
+            self.islands.borrow_mut().remove(&comp_id)
+        }
+
+        /// Marks the component `comp_id` as an `<ErrorBoundary>` rendering `fallback` if one of its
+        /// children fails.
+        pub fn set_error_boundary(&self, comp_id: usize, fallback: RenderFallback) {
+            let state = ErrorBoundaryState {
+                fallback,
+                error: None,
+            };
+            self.error_boundaries.borrow_mut().insert(comp_id, state);
+        }
+
+        pub fn is_error_boundary(&self, comp_id: usize) -> bool {
+            self.error_boundaries.borrow().contains_key(&comp_id)
+        }
+
+        /// Records the first error caught by the `<ErrorBoundary>` `comp_id`.
+        pub fn catch_error(&self, comp_id: usize, error: ComponentError) {
+            if let Some(state) = self.error_boundaries.borrow_mut().get_mut(&comp_id) {
+                state.error.get_or_insert(error);
+            }
+        }
+
+        /// Returns the fallback of the `<ErrorBoundary>` `comp_id` if one of its children failed.
+        pub fn take_error_boundary(
+            &self,
+            comp_id: usize,
+        ) -> Option<(RenderFallback, ComponentError)> {
+            let state = self.error_boundaries.borrow_mut().remove(&comp_id)?;
+            Some((state.fallback, state.error?))
+        }
+
+        /// The context of a subtree rendered to be cached, whose HTML must be complete.
+        ///
+        /// The subtree is rendered without deadline, so that fallbacks are never cached.
+        pub fn for_cached_subtree(&self) -> Self {
+            Self {
+                out_of_order: false,
+                cache: self.cache.clone(),
+                concurrency_limit: self.concurrency_limit.clone(),
+                state_metrics: self.state_metrics.clone(),
+                response_status: self.response_status.clone(),
+                ..Self::default()
+            }
+        }
+
+        /// Waits until the component can be rendered, if the number of components rendered at the
+        /// same time is limited.
+        pub async fn acquire_render_permit(&self) -> Option<RenderPermit> {
+            match self.concurrency_limit {
+                Some(ref limit) => Some(limit.clone().acquire().await),
+                None => None,
+            }
+        }
+
+        /// Writes the fallback of a `<Suspense>` whose content is not ready in a placeholder, and
+        /// defers the content until the end of the document.
+        ///
+        /// `timed_out` replaces the fallback if the content isn't ready by the deadline.
+        pub fn write_placeholder(
+            &self,
+            w: &mut BufWriter,
+            content: LocalBoxFuture<'static, String>,
+            fallback: &str,
+            timed_out: String,
+        ) {
+            let id = BOUNDARY_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
+            self.deferred.borrow_mut().push((id, content, timed_out));
+            let _ = write!(w, r#"<template id="yew-s-{id}"></template>"#);
+            let _ = w.write_str(fallback);
+            let _ = write!(w, r#"<template id="yew-e-{id}"></template>"#);
+        }
+
+        /// Writes the deferred contents in the order they are ready, each followed by the script
+        /// moving it in place of its fallback.
+        ///
+        /// Once the deadline elapsed, the contents that are not ready are replaced by their
+        /// fallback marked as timed out.
+        pub async fn write_deferred(&self, w: &mut BufWriter) {
+            let mut pending = FuturesUnordered::new();
+            let mut timed_out = BTreeMap::new();
+            let mut script_written = false;
+            let mut deadline = match self.deadline {
+                Some(ref m) => m.clone().left_future(),
+                None => future::pending().right_future(),
+            };
+            let mut write_content = |w: &mut BufWriter, id: usize, content: &str| {
+                if !script_written {
+                    let _ = w.write_str(SWAP_SCRIPT);
+                    script_written = true;
+                }
+                let _ = write!(w, r#"<template id="yew-c-{id}">{content}</template>"#);
+                let _ = write!(w, "<script>__yew_swap({id})</script>");
+            };
+            loop {
+                for (id, content, fallback) in self.deferred.borrow_mut().drain(..) {
+                    timed_out.insert(id, fallback);
+                    pending.push(async move { (id, content.await) });
+                }
+                let (id, content) = match future::select(pending.next(), &mut deadline).await {
+                    Either::Left((Some(m), _)) => m,
+                    Either::Left((None, _)) => break,
+                    Either::Right(_) => {
+                        for (id, fallback) in mem::take(&mut timed_out) {
+                            write_content(w, id, &fallback);
+                        }
+                        break;
+                    }
+                };
+                timed_out.remove(&id);
+                write_content(w, id, &content);
+            }
+        }
+    }
 }
 
 /// A Yew Server-side Renderer that renders on the current thread.
@@ -57,6 +385,17 @@ where
 {
     props: COMP::Properties,
     hydratable: bool,
+    out_of_order: bool,
+    cache: Option<SsrCache>,
+    max_concurrent_suspensions: Option<usize>,
+    memory_budget: Option<usize>,
+    time_budget: Option<Duration>,
+    state_metrics: Option<StateMetrics>,
+    response_status: Option<ResponseStatus>,
+    styles: Option<StyleCollector>,
+    deadline: Option<Duration>,
+    cancellation_token: Option<CancellationToken>,
+    resumable: bool,
 }
 
 impl<COMP> Default for LocalServerRenderer<COMP>
@@ -89,6 +428,17 @@ where
         Self {
             props,
             hydratable: true,
+            out_of_order: false,
+            cache: None,
+            max_concurrent_suspensions: None,
+            memory_budget: None,
+            time_budget: None,
+            state_metrics: None,
+            response_status: None,
+            styles: None,
+            deadline: None,
+            cancellation_token: None,
+            resumable: false,
         }
     }
 
@@ -104,6 +454,134 @@ where
         self
     }
 
+    /// Sets whether the content of suspended `<Suspense>`s is streamed out of order.
+    ///
+    /// Defaults to `false`, where the rendering waits for suspended components to resume before
+    /// writing what follows them.
+    ///
+    /// When this is set to `true`, the fallback of a suspended `<Suspense>` is written in place of
+    /// its content, so that the rest of the document can be sent right away. The content is then
+    /// written at the end of the stream as soon as it is ready, along with an inline script moving
+    /// it in place of the fallback. The application must only be hydrated once the stream ended.
+    pub fn out_of_order_streaming(mut self, val: bool) -> Self {
+        self.out_of_order = val;
+
+        self
+    }
+
+    /// Sets the cache of the HTML of [`Cached`](crate::ssr_cache::Cached) subtrees.
+    ///
+    /// Without a cache, `Cached` subtrees are rendered for every request.
+    pub fn cache(mut self, cache: SsrCache) -> Self {
+        self.cache = Some(cache);
+
+        self
+    }
+
+    /// Sets the maximum number of components rendered at the same time.
+    ///
+    /// Defaults to no limit.
+    ///
+    /// Components are only rendered concurrently while they are suspended, so this bounds the
+    /// number of suspended components waiting for their data at once. Once the limit is reached,
+    /// further components are only rendered after one of them resumed. The limit is at least 1.
+    pub fn max_concurrent_suspensions(mut self, val: usize) -> Self {
+        self.max_concurrent_suspensions = Some(val);
+
+        self
+    }
+
+    /// Sets the maximum number of bytes of HTML rendered.
+    ///
+    /// Defaults to no limit.
+    ///
+    /// Once the rendered HTML would exceed this budget, the rendering is aborted and the stream
+    /// ends without the rest of the document.
+    pub fn memory_budget(mut self, bytes: usize) -> Self {
+        self.memory_budget = Some(bytes);
+
+        self
+    }
+
+    /// Sets the maximum duration of the rendering.
+    ///
+    /// Defaults to no limit.
+    ///
+    /// Once the duration elapsed, the rendering is aborted and the stream ends without the rest of
+    /// the document.
+    pub fn time_budget(mut self, val: Duration) -> Self {
+        self.time_budget = Some(val);
+
+        self
+    }
+
+    /// Sets the duration after which suspended components are no longer waited for.
+    ///
+    /// Defaults to no deadline.
+    ///
+    /// Once the deadline elapsed, the `<Suspense>`s whose content is not ready are rendered with
+    /// their fallback, and the rendering completes. When the application is hydrated, the content
+    /// of these `<Suspense>`s is rendered on the client in place of being hydrated.
+    ///
+    /// Unless the content of suspended `<Suspense>`s is streamed out of order, the content of each
+    /// `<Suspense>` is only written once it is complete.
+    pub fn deadline(mut self, val: Duration) -> Self {
+        self.deadline = Some(val);
+
+        self
+    }
+
+    /// Sets the token aborting the rendering once it is cancelled.
+    ///
+    /// Dropping the stream returned by [`render_stream`](Self::render_stream) also aborts the
+    /// rendering.
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+
+        self
+    }
+
+    /// Sets whether the rendered result can be hydrated on the first interaction of the user.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// When this is set to `true`, the hydratable elements with listeners are marked with the
+    /// types of their events, and a small inline script is written at the end of the document.
+    /// This script records the first events of these types, so that an application hydrated with
+    /// [`Renderer::hydrate_on_interaction`](crate::Renderer::hydrate_on_interaction) is only
+    /// hydrated once the user interacts with it, and then receives these events.
+    ///
+    /// The elements of [`Cached`](crate::ssr_cache::Cached) subtrees are not marked.
+    pub fn resumable(mut self, val: bool) -> Self {
+        self.resumable = val;
+
+        self
+    }
+
+    /// Sets the metrics recording the sizes of the prepared states written.
+    pub fn state_metrics(mut self, metrics: StateMetrics) -> Self {
+        self.state_metrics = Some(metrics);
+
+        self
+    }
+
+    /// Sets the status code set by the [`Status`](crate::ssr::Status) components rendered.
+    pub fn response_status(mut self, status: ResponseStatus) -> Self {
+        self.response_status = Some(status);
+
+        self
+    }
+
+    /// Sets the collector recording the classes of the rendered elements, so that it only inlines
+    /// the critical rules of its registered stylesheets.
+    ///
+    /// See [`StyleCollector`] for more information.
+    pub fn styles(mut self, styles: StyleCollector) -> Self {
+        self.styles = Some(styles);
+
+        self
+    }
+
     /// Renders Yew Application.
     pub async fn render(self) -> String {
         let s = self.render_stream();
@@ -123,7 +601,31 @@ where
     }
 
     fn render_stream_inner(self) -> impl Stream<Item = String> {
-        let scope = Scope::<COMP>::new(None);
+        let ssr = Rc::new(SsrContext {
+            out_of_order: self.out_of_order,
+            cache: self.cache,
+            concurrency_limit: self.max_concurrent_suspensions.map(ConcurrencyLimit::new),
+            state_metrics: self.state_metrics,
+            response_status: self.response_status,
+            deadline: self
+                .deadline
+                .map(|m| async move { sleep(m).await }.boxed_local().shared()),
+            resumable: self.resumable,
+            ..SsrContext::default()
+        });
+        let mut scope = Scope::<COMP>::new(None);
+        scope.ssr = Some(ssr.clone());
+
+        let styles = self.styles;
+        let memory_budget = self.memory_budget.unwrap_or(usize::MAX);
+        let time_budget = match self.time_budget {
+            Some(m) => async move { sleep(m).await }.boxed_local(),
+            None => future::pending().boxed_local(),
+        };
+        let cancelled = match self.cancellation_token {
+            Some(m) => m.cancelled().left_future(),
+            None => future::pending().right_future(),
+        };
 
         let outer_span = tracing::Span::current();
         BufStream::new(move |mut w| async move {
@@ -138,6 +640,28 @@ where
                 )
                 .instrument(render_span)
                 .await;
+            ssr.write_deferred(&mut w).await;
+            ssr.write_resume_script(&mut w);
+        })
+        // Dropping the stream aborts the rendering.
+        .take_until(time_budget.inspect(|_| {
+            tracing::warn!("server side rendering aborted: time budget exceeded");
+        }))
+        .take_until(cancelled.inspect(|_| {
+            tracing::debug!("server side rendering cancelled");
+        }))
+        .scan(0, move |rendered, m| {
+            *rendered += m.len();
+            if *rendered > memory_budget {
+                tracing::warn!("server side rendering aborted: memory budget exceeded");
+                return future::ready(None);
+            }
+            future::ready(Some(m))
+        })
+        .inspect(move |m| {
+            if let Some(ref styles) = styles {
+                styles.record_classes(m);
+            }
         })
     }
 
@@ -150,7 +674,7 @@ where
         level = tracing::Level::DEBUG,
         name = "render_stream",
         skip(self),
-        fields(hydratable = self.hydratable),
+        fields(hydratable = self.hydratable, out_of_order = self.out_of_order),
     )]
     #[inline(always)]
     pub fn render_stream(self) -> impl Stream<Item = String> {
@@ -158,6 +682,15 @@ where
     }
 }
 
+/// Cancels a token when dropped.
+struct AbortOnDrop(CancellationToken);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.cancel();
+    }
+}
+
 /// A Yew Server-side Renderer.
 ///
 /// This renderer spawns the rendering task to a Yew [`Runtime`]. and receives result when
@@ -171,6 +704,17 @@ where
 {
     create_props: Box<dyn Send + FnOnce() -> COMP::Properties>,
     hydratable: bool,
+    out_of_order: bool,
+    cache: Option<SsrCache>,
+    max_concurrent_suspensions: Option<usize>,
+    memory_budget: Option<usize>,
+    time_budget: Option<Duration>,
+    state_metrics: Option<StateMetrics>,
+    response_status: Option<ResponseStatus>,
+    styles: Option<StyleCollector>,
+    deadline: Option<Duration>,
+    cancellation_token: Option<CancellationToken>,
+    resumable: bool,
     rt: Option<Runtime>,
 }
 
@@ -221,6 +765,17 @@ where
         Self {
             create_props: Box::new(create_props),
             hydratable: true,
+            out_of_order: false,
+            cache: None,
+            max_concurrent_suspensions: None,
+            memory_budget: None,
+            time_budget: None,
+            state_metrics: None,
+            response_status: None,
+            styles: None,
+            deadline: None,
+            cancellation_token: None,
+            resumable: false,
             rt: None,
         }
     }
@@ -244,21 +799,145 @@ where
         self
     }
 
+    /// Sets whether the content of suspended `<Suspense>`s is streamed out of order.
+    ///
+    /// See [`LocalServerRenderer::out_of_order_streaming`].
+    pub fn out_of_order_streaming(mut self, val: bool) -> Self {
+        self.out_of_order = val;
+
+        self
+    }
+
+    /// Sets the cache of the HTML of [`Cached`](crate::ssr_cache::Cached) subtrees.
+    ///
+    /// Without a cache, `Cached` subtrees are rendered for every request.
+    pub fn cache(mut self, cache: SsrCache) -> Self {
+        self.cache = Some(cache);
+
+        self
+    }
+
+    /// Sets the maximum number of components rendered at the same time.
+    ///
+    /// See [`LocalServerRenderer::max_concurrent_suspensions`].
+    pub fn max_concurrent_suspensions(mut self, val: usize) -> Self {
+        self.max_concurrent_suspensions = Some(val);
+
+        self
+    }
+
+    /// Sets the maximum number of bytes of HTML rendered.
+    ///
+    /// See [`LocalServerRenderer::memory_budget`].
+    pub fn memory_budget(mut self, bytes: usize) -> Self {
+        self.memory_budget = Some(bytes);
+
+        self
+    }
+
+    /// Sets the maximum duration of the rendering.
+    ///
+    /// See [`LocalServerRenderer::time_budget`].
+    pub fn time_budget(mut self, val: Duration) -> Self {
+        self.time_budget = Some(val);
+
+        self
+    }
+
+    /// Sets the duration after which suspended components are no longer waited for.
+    ///
+    /// See [`LocalServerRenderer::deadline`].
+    pub fn deadline(mut self, val: Duration) -> Self {
+        self.deadline = Some(val);
+
+        self
+    }
+
+    /// Sets the token aborting the rendering once it is cancelled.
+    ///
+    /// Dropping the stream returned by [`render_stream`](Self::render_stream), or the future
+    /// returned by [`render`](Self::render), also aborts the rendering.
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+
+        self
+    }
+
+    /// Sets whether the rendered result can be hydrated on the first interaction of the user.
+    ///
+    /// See [`LocalServerRenderer::resumable`].
+    pub fn resumable(mut self, val: bool) -> Self {
+        self.resumable = val;
+
+        self
+    }
+
+    /// Sets the metrics recording the sizes of the prepared states written.
+    pub fn state_metrics(mut self, metrics: StateMetrics) -> Self {
+        self.state_metrics = Some(metrics);
+
+        self
+    }
+
+    /// Sets the status code set by the [`Status`](crate::ssr::Status) components rendered.
+    pub fn response_status(mut self, status: ResponseStatus) -> Self {
+        self.response_status = Some(status);
+
+        self
+    }
+
+    /// Sets the collector recording the classes of the rendered elements, so that it only inlines
+    /// the critical rules of its registered stylesheets.
+    ///
+    /// See [`StyleCollector`] for more information.
+    pub fn styles(mut self, styles: StyleCollector) -> Self {
+        self.styles = Some(styles);
+
+        self
+    }
+
     /// Renders Yew Application.
     pub async fn render(self) -> String {
         let Self {
             create_props,
             hydratable,
+            out_of_order,
+            cache,
+            max_concurrent_suspensions,
+            memory_budget,
+            time_budget,
+            state_metrics,
+            response_status,
+            styles,
+            deadline,
+            cancellation_token,
+            resumable,
             rt,
         } = self;
 
-        let (tx, rx) = futures::channel::oneshot::channel();
+        let (mut tx, rx) = futures::channel::oneshot::channel();
         let create_task = move || async move {
             let props = create_props();
-            let s = LocalServerRenderer::<COMP>::with_props(props)
+            let mut renderer = LocalServerRenderer::<COMP>::with_props(props)
                 .hydratable(hydratable)
-                .render()
-                .await;
+                .out_of_order_streaming(out_of_order);
+            renderer.cache = cache;
+            renderer.max_concurrent_suspensions = max_concurrent_suspensions;
+            renderer.memory_budget = memory_budget;
+            renderer.time_budget = time_budget;
+            renderer.state_metrics = state_metrics;
+            renderer.response_status = response_status;
+            renderer.styles = styles;
+            renderer.deadline = deadline;
+            renderer.cancellation_token = cancellation_token;
+            renderer.resumable = resumable;
+            let render = renderer.render();
+            pin_mut!(render);
+            let s = match future::select(render, tx.cancellation()).await {
+                Either::Left((s, _)) => s,
+                // The future of the rendering was dropped.
+                Either::Right(_) => return,
+            };
 
             let _ = tx.send(s);
         };
@@ -300,15 +979,40 @@ where
         let Self {
             create_props,
             hydratable,
+            out_of_order,
+            cache,
+            max_concurrent_suspensions,
+            memory_budget,
+            time_budget,
+            state_metrics,
+            response_status,
+            styles,
+            deadline,
+            cancellation_token,
+            resumable,
             rt,
         } = self;
 
-        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let (tx, mut rx) = futures::channel::mpsc::unbounded();
+        // Cancelled once the stream is dropped.
+        let dropped = CancellationToken::new();
+        let abort_on_drop = dropped.clone();
         let create_task = move || async move {
             let props = create_props();
-            let s = LocalServerRenderer::<COMP>::with_props(props)
+            let mut renderer = LocalServerRenderer::<COMP>::with_props(props)
                 .hydratable(hydratable)
-                .render_stream();
+                .out_of_order_streaming(out_of_order);
+            renderer.cache = cache;
+            renderer.max_concurrent_suspensions = max_concurrent_suspensions;
+            renderer.memory_budget = memory_budget;
+            renderer.time_budget = time_budget;
+            renderer.state_metrics = state_metrics;
+            renderer.response_status = response_status;
+            renderer.styles = styles;
+            renderer.deadline = deadline;
+            renderer.cancellation_token = cancellation_token;
+            renderer.resumable = resumable;
+            let s = renderer.render_stream().take_until(dropped.cancelled());
             pin_mut!(s);
 
             while let Some(m) = s.next().await {
@@ -318,6 +1022,10 @@ where
 
         Self::spawn_rendering_task(rt, create_task);
 
-        rx
+        let abort_on_drop = AbortOnDrop(abort_on_drop);
+        stream::poll_fn(move |cx| {
+            let _ = &abort_on_drop;
+            rx.poll_next_unpin(cx)
+        })
     }
 }
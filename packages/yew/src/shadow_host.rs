@@ -0,0 +1,274 @@
+//! This module provides rendering into a shadow root.
+
+use web_sys::ShadowRoot;
+
+use crate::html::{create_portal, Component, Context, Html, NodeRef, Properties};
+use crate::virtual_dom::AttrValue;
+use crate::{html, Classes};
+
+/// The mode of the shadow root of a [ShadowHost].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ShadowMode {
+    /// The shadow root can be reached from outside with `element.shadowRoot`.
+    #[default]
+    Open,
+    /// The shadow root is hidden from scripts outside of it.
+    Closed,
+}
+
+impl ShadowMode {
+    /// Returns the value of this mode in `attachShadow` and `shadowrootmode`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Closed => "closed",
+        }
+    }
+}
+
+/// Properties for [ShadowHost].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct ShadowHostProps {
+    /// The mode of the shadow root. It is only read when the shadow root is attached.
+    #[prop_or_default]
+    pub mode: ShadowMode,
+
+    /// Style sheets applying to the children only, as CSS text.
+    #[prop_or_default]
+    pub styles: Vec<AttrValue>,
+
+    /// The tag of the host element.
+    #[prop_or(AttrValue::Static("div"))]
+    pub tag: AttrValue,
+
+    /// The class of the host element.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// The Children of the current ShadowHost Component.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Renders its children inside the shadow root of a host element, so that styles of the page
+/// and of the children don't apply to each other.
+///
+/// [`styles`](ShadowHostProps::styles) are adopted by the shadow root as constructed style
+/// sheets, or rendered as `<style>` elements in browsers without them. Events fired by the
+/// children bubble to the listeners of the components around the host.
+///
+/// The shadow root is attached once the host is rendered, so the children are first rendered
+/// right after the host. During server-side rendering, they are rendered in a declarative shadow
+/// root, which is replaced by the children rendered on the client when hydrating.
+///
+/// # Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew::shadow_host::{ShadowHost, ShadowMode};
+///
+/// #[function_component]
+/// fn Card() -> Html {
+///     let styles = vec![AttrValue::from("p { color: tomato; }")];
+///
+///     html! {
+///         <ShadowHost mode={ShadowMode::Closed} {styles} class="card">
+///             <p>{ "Only this paragraph is red." }</p>
+///         </ShadowHost>
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ShadowHost {
+    host: NodeRef,
+    shadow: Option<ShadowRoot>,
+    /// Whether the styles are adopted by the shadow root, instead of rendered as elements.
+    adopted: bool,
+}
+
+impl Component for ShadowHost {
+    type Message = ();
+    type Properties = ShadowHostProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            host: NodeRef::default(),
+            shadow: None,
+            adopted: false,
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
+        // The shadow root was attached.
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let styles = &ctx.props().styles;
+        if let Some(shadow) = self.shadow.as_ref().filter(|_| self.adopted) {
+            if &old_props.styles != styles {
+                self.adopted = feat_browser::adopt_styles(shadow, styles);
+            }
+        }
+        true
+    }
+
+    #[cfg(feature = "csr")]
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if !first_render {
+            return;
+        }
+        let Some(host) = self.host.cast::<web_sys::Element>() else {
+            return;
+        };
+
+        let props = ctx.props();
+        if let Some(shadow) = feat_browser::attach_shadow(&host, props.mode) {
+            self.adopted = feat_browser::adopt_styles(&shadow, &props.styles);
+            self.shadow = Some(shadow);
+            ctx.link().send_message(());
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let ShadowHostProps {
+            mode,
+            styles,
+            tag,
+            class,
+            children,
+        } = ctx.props();
+
+        let styles = if self.adopted { &[][..] } else { &styles[..] };
+        let styles = styles
+            .iter()
+            .map(|css| html! { <style>{ css.clone() }</style> });
+        let content = html! { <>{ for styles }{ children.clone() }</> };
+
+        let content = match &self.shadow {
+            Some(shadow) => create_portal(content, shadow.clone()),
+            None => feat_browser::pending_content(*mode, content),
+        };
+
+        html! {
+            <@{tag.to_string()} ref={&self.host} class={class.clone()}>
+                { content }
+            </@>
+        }
+    }
+}
+
+#[cfg(all(
+    target_arch = "wasm32",
+    not(target_os = "wasi"),
+    not(feature = "not_browser_env")
+))]
+mod feat_browser {
+    use js_sys::{Array, Reflect};
+    use wasm_bindgen::JsValue;
+    use web_sys::{CssStyleSheet, ShadowRoot};
+
+    use super::ShadowMode;
+    use crate::html::Html;
+    use crate::virtual_dom::AttrValue;
+
+    #[cfg(feature = "csr")]
+    pub(super) fn attach_shadow(host: &web_sys::Element, mode: ShadowMode) -> Option<ShadowRoot> {
+        use web_sys::{ShadowRootInit, ShadowRootMode};
+
+        let mode = match mode {
+            ShadowMode::Open => ShadowRootMode::Open,
+            ShadowMode::Closed => ShadowRootMode::Closed,
+        };
+        // A declarative shadow root rendered on the server is emptied and returned.
+        host.attach_shadow(&ShadowRootInit::new(mode)).ok()
+    }
+
+    /// Sets the style sheets of `shadow`, returning `false` if constructed style sheets are not
+    /// supported.
+    pub(super) fn adopt_styles(shadow: &ShadowRoot, styles: &[AttrValue]) -> bool {
+        let sheets = Array::new();
+        for css in styles {
+            let Ok(sheet) = CssStyleSheet::new() else {
+                return false;
+            };
+            if sheet.replace_sync(css).is_err() {
+                return false;
+            }
+            sheets.push(&sheet);
+        }
+
+        Reflect::set(shadow, &JsValue::from_str("adoptedStyleSheets"), &sheets).unwrap_or(false)
+    }
+
+    /// The children are only rendered once the shadow root is attached.
+    pub(super) fn pending_content(_mode: ShadowMode, _content: Html) -> Html {
+        Html::default()
+    }
+}
+
+#[cfg(any(
+    not(target_arch = "wasm32"),
+    target_os = "wasi",
+    feature = "not_browser_env"
+))]
+mod feat_browser {
+    use web_sys::ShadowRoot;
+
+    use super::ShadowMode;
+    use crate::html;
+    use crate::html::Html;
+    use crate::virtual_dom::AttrValue;
+
+    #[cfg(feature = "csr")]
+    pub(super) fn attach_shadow(_host: &web_sys::Element, _mode: ShadowMode) -> Option<ShadowRoot> {
+        None
+    }
+
+    pub(super) fn adopt_styles(_shadow: &ShadowRoot, _styles: &[AttrValue]) -> bool {
+        false
+    }
+
+    /// Renders the children in a declarative shadow root.
+    pub(super) fn pending_content(mode: ShadowMode, content: Html) -> Html {
+        html! {
+            <template shadowrootmode={mode.as_str()}>{ content }</template>
+        }
+    }
+}
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::LocalServerRenderer as ServerRenderer;
+
+    #[test]
+    async fn children_are_rendered_in_a_declarative_shadow_root() {
+        #[function_component]
+        fn Comp() -> Html {
+            let styles = vec![AttrValue::from("p > b { color: red; }")];
+
+            html! {
+                <ShadowHost tag="section" mode={ShadowMode::Closed} {styles}>
+                    <p>{ "content" }</p>
+                </ShadowHost>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            "<section><template shadowrootmode=\"closed\"><style>p > b { color: red; \
+             }</style><p>content</p></template></section>"
+        );
+    }
+}
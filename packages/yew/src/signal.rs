@@ -0,0 +1,295 @@
+//! This module contains signals, values that update the parts of the page that display them
+//! without re-rendering the components that own them.
+//!
+//! A [`Signal`] holds a value shared by reference. When a signal is displayed in `html!`, as in
+//! `{ signal.clone() }`, a binding is created that subscribes to it: setting the signal only
+//! updates the text of that binding, without re-rendering or diffing the component that created
+//! the signal, nor its children. This makes signals well suited for state that changes often, such
+//! as counters, timers or the progress of a transfer.
+//!
+//! Signals are opt-in: reading a signal with [`Signal::get`] in a component does not subscribe
+//! it. Use [`use_signal_value`] to re-render a component whenever a signal changes.
+//!
+//! # Example
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew::signal::use_signal;
+//!
+//! #[function_component]
+//! fn Counter() -> Html {
+//!     // `Counter` is only rendered once: clicks only update the text of the binding.
+//!     let count = use_signal(|| 0);
+//!     let onclick = {
+//!         let count = count.clone();
+//!         Callback::from(move |_| count.update(|m| *m += 1))
+//!     };
+//!
+//!     html! {
+//!         <button {onclick}>{ "Clicked " }{ count.clone() }{ " times" }</button>
+//!     }
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::{Rc, Weak};
+
+use slab::Slab;
+
+use crate::callback::Callback;
+use crate::functional::{hook, use_force_update, use_memo, use_state};
+use crate::html::{Component, Context, Html, Properties};
+use crate::virtual_dom::{VChild, VNode, VText};
+
+struct SignalInner<T> {
+    value: RefCell<T>,
+    subscribers: RefCell<Slab<Callback<()>>>,
+}
+
+/// A value that notifies its subscribers when it changes, see the
+/// [module documentation](self).
+///
+/// Clones of a signal share the same value.
+pub struct Signal<T> {
+    inner: Rc<SignalInner<T>>,
+}
+
+/// Creates a signal holding `value`.
+///
+/// In function components, use [`use_signal`] to keep the same signal across renders.
+pub fn create_signal<T>(value: T) -> Signal<T> {
+    Signal {
+        inner: Rc::new(SignalInner {
+            value: RefCell::new(value),
+            subscribers: RefCell::default(),
+        }),
+    }
+}
+
+impl<T> Signal<T> {
+    /// Returns a clone of the current value.
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.inner.value.borrow().clone()
+    }
+
+    /// Calls `f` with a reference to the current value.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.inner.value.borrow())
+    }
+
+    /// Replaces the value, and notifies subscribers if it changed.
+    pub fn set(&self, value: T)
+    where
+        T: PartialEq,
+    {
+        let changed = {
+            let mut current = self.inner.value.borrow_mut();
+            let changed = *current != value;
+            *current = value;
+            changed
+        };
+
+        if changed {
+            self.notify();
+        }
+    }
+
+    /// Changes the value in place with `f`, and notifies subscribers.
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        f(&mut self.inner.value.borrow_mut());
+        self.notify();
+    }
+
+    /// Calls `callback` whenever the value changes, until the returned [`SignalSubscription`] is
+    /// dropped.
+    pub fn subscribe(&self, callback: Callback<()>) -> SignalSubscription
+    where
+        T: 'static,
+    {
+        let key = self.inner.subscribers.borrow_mut().insert(callback);
+        let inner: Weak<SignalInner<T>> = Rc::downgrade(&self.inner);
+
+        SignalSubscription {
+            unsubscribe: Some(Box::new(move || {
+                if let Some(inner) = inner.upgrade() {
+                    inner.subscribers.borrow_mut().try_remove(key);
+                }
+            })),
+        }
+    }
+
+    fn notify(&self) {
+        // Subscribers are collected first, as they may read the value or subscribe.
+        let subscribers: Vec<Callback<()>> = self
+            .inner
+            .subscribers
+            .borrow()
+            .iter()
+            .map(|(_, m)| m.clone())
+            .collect();
+        for subscriber in subscribers {
+            subscriber.emit(());
+        }
+    }
+}
+
+impl<T> Clone for Signal<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for Signal<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Signal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Signal")
+            .field(&*self.inner.value.borrow())
+            .finish()
+    }
+}
+
+/// Keeps a subscription to a signal until dropped, see [`Signal::subscribe`].
+pub struct SignalSubscription {
+    unsubscribe: Option<Box<dyn FnOnce()>>,
+}
+
+impl Drop for SignalSubscription {
+    fn drop(&mut self) {
+        if let Some(unsubscribe) = self.unsubscribe.take() {
+            unsubscribe();
+        }
+    }
+}
+
+impl fmt::Debug for SignalSubscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SignalSubscription")
+    }
+}
+
+#[derive(Properties)]
+struct SignalTextProps<T: 'static> {
+    signal: Signal<T>,
+}
+
+impl<T> PartialEq for SignalTextProps<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.signal == other.signal
+    }
+}
+
+/// The binding of a signal displayed in `html!`, which only renders its text.
+struct SignalText<T: fmt::Display + 'static> {
+    _subscription: SignalSubscription,
+    _marker: PhantomData<T>,
+}
+
+impl<T: fmt::Display + 'static> SignalText<T> {
+    fn subscribe(ctx: &Context<Self>) -> SignalSubscription {
+        ctx.props().signal.subscribe(ctx.link().callback(|_| ()))
+    }
+}
+
+impl<T: fmt::Display + 'static> Component for SignalText<T> {
+    type Message = ();
+    type Properties = SignalTextProps<T>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            _subscription: Self::subscribe(ctx),
+            _marker: PhantomData,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self._subscription = Self::subscribe(ctx);
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        VText::new(ctx.props().signal.with(ToString::to_string)).into()
+    }
+}
+
+impl<T: fmt::Display + 'static> From<Signal<T>> for VNode {
+    fn from(signal: Signal<T>) -> Self {
+        VChild::<SignalText<T>>::new(SignalTextProps { signal }, None).into()
+    }
+}
+
+impl<T: fmt::Display + 'static> From<&Signal<T>> for VNode {
+    fn from(signal: &Signal<T>) -> Self {
+        signal.clone().into()
+    }
+}
+
+/// This hook returns a signal created with the value returned by `init_fn`.
+///
+/// The same signal is returned on every render. Setting it does not re-render the component, only
+/// the places where it is displayed.
+#[hook]
+pub fn use_signal<T, F>(init_fn: F) -> Signal<T>
+where
+    T: 'static,
+    F: FnOnce() -> T,
+{
+    (*use_state(move || create_signal(init_fn()))).clone()
+}
+
+/// This hook returns the current value of `signal`, and re-renders the component whenever it
+/// changes.
+#[hook]
+pub fn use_signal_value<T>(signal: Signal<T>) -> T
+where
+    T: Clone + 'static,
+{
+    let update = use_force_update();
+    let value = signal.get();
+    use_memo(signal, move |signal| {
+        signal.subscribe(Callback::from(move |_| update.force_update()))
+    });
+
+    value
+}
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::LocalServerRenderer as ServerRenderer;
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn signals_render_their_value() {
+        #[function_component]
+        fn Comp() -> Html {
+            let count = use_signal(|| 41);
+            count.update(|m| *m += 1);
+
+            html! { <div>{ "Count: " }{ count }</div> }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>Count: 42</div>");
+    }
+}
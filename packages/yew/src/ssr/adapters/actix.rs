@@ -0,0 +1,38 @@
+use std::convert::Infallible;
+
+use actix_http::body::{BodyStream, BoxBody};
+use actix_http::header::{HeaderValue, CONTENT_TYPE};
+use actix_http::{Response, StatusCode};
+use bytes::Bytes;
+use futures::stream::StreamExt;
+
+use super::RenderedResponse;
+
+impl From<RenderedResponse> for Response<BoxBody> {
+    fn from(response: RenderedResponse) -> Self {
+        let status =
+            StatusCode::from_u16(response.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let body = BodyStream::new(response.body.map(|m| Ok::<_, Infallible>(Bytes::from(m))));
+
+        let mut response = Response::with_body(status, BoxBody::new(body));
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(super::CONTENT_TYPE));
+        response
+    }
+}
+
+#[cfg(feature = "server_fn")]
+impl From<crate::server_fn::ServerFnResponse> for Response<BoxBody> {
+    fn from(response: crate::server_fn::ServerFnResponse) -> Self {
+        let status =
+            StatusCode::from_u16(response.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+        let mut response = Response::with_body(status, BoxBody::new(response.body));
+        response.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static(crate::server_fn::ServerFnResponse::CONTENT_TYPE),
+        );
+        response
+    }
+}
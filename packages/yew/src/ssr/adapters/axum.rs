@@ -0,0 +1,27 @@
+use std::convert::Infallible;
+
+use axum::body::Body;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use futures::stream::StreamExt;
+
+use super::RenderedResponse;
+
+impl IntoResponse for RenderedResponse {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let body = Body::from_stream(self.body.map(Ok::<_, Infallible>));
+
+        (status, [(CONTENT_TYPE, super::CONTENT_TYPE)], body).into_response()
+    }
+}
+
+#[cfg(feature = "server_fn")]
+impl IntoResponse for crate::server_fn::ServerFnResponse {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+        (status, [(CONTENT_TYPE, Self::CONTENT_TYPE)], self.body).into_response()
+    }
+}
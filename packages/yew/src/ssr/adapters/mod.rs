@@ -0,0 +1,284 @@
+//! This module contains the adapters sending server side rendered pages as the responses of web
+//! frameworks.
+//!
+//! An [`SsrResponse`] renders the application into an HTML template, with the tags of a
+//! [`HeadCollector`] and the critical styles of a [`StyleCollector`] inserted in its `<head>`, and
+//! the status code set by the
+//! [`Status`](super::Status) components. The [`RenderedResponse`] it resolves to is streamed as
+//! the response of the framework enabled by a feature:
+//!
+//! - `axum`: [`RenderedResponse`] implements `axum::response::IntoResponse`.
+//! - `actix`: [`RenderedResponse`] converts into an `actix_http::Response`, which actix-web
+//!   handlers can return.
+//! - `warp_adapter`: [`RenderedResponse`] implements `warp::Reply`.
+//!
+//! With the `server_fn` feature, the responses of the
+//! [server functions](crate::server_fn::ServerFns) are converted the same way.
+//!
+//! The status line and the head of the document are sent once the components rendered before
+//! the first suspension are, so status codes and head tags must be declared before suspending.
+//! The rest of the page is streamed as it is rendered.
+//!
+//! # Example
+//!
+//! ```
+//! # use yew::prelude::*;
+//! # #[function_component]
+//! # fn App() -> Html { Html::default() }
+//! use yew::ssr::adapters::{RenderedResponse, SsrResponse};
+//! use yew::ServerRenderer;
+//!
+//! const INDEX_HTML: &str = r#"<!DOCTYPE html>
+//! <html>
+//!     <head><script type="module" src="/app.js"></script></head>
+//!     <body></body>
+//! </html>"#;
+//!
+//! // A handler of any of the web frameworks enabled.
+//! async fn render() -> RenderedResponse {
+//!     SsrResponse::new(ServerRenderer::<App>::new())
+//!         .template(INDEX_HTML)
+//!         .render()
+//!         .await
+//! }
+//! ```
+
+use std::fmt;
+
+use futures::stream::{self, BoxStream, StreamExt};
+
+use crate::css::StyleCollector;
+use crate::head::HeadCollector;
+use crate::html::BaseComponent;
+use crate::ssr::ResponseStatus;
+use crate::ServerRenderer;
+
+#[cfg(feature = "actix")]
+mod actix;
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(feature = "warp_adapter")]
+mod warp;
+
+/// The document an application is rendered into, when no template is set.
+const DEFAULT_TEMPLATE: &str = "<!DOCTYPE html><html><head></head><body></body></html>";
+
+/// A server side rendered page, to be sent as a response.
+pub struct SsrResponse<COMP>
+where
+    COMP: BaseComponent,
+{
+    renderer: ServerRenderer<COMP>,
+    template: String,
+    head: Option<HeadCollector>,
+    styles: Option<StyleCollector>,
+    status: u16,
+}
+
+impl<COMP> fmt::Debug for SsrResponse<COMP>
+where
+    COMP: BaseComponent,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SsrResponse<_>")
+    }
+}
+
+impl<COMP> SsrResponse<COMP>
+where
+    COMP: BaseComponent,
+{
+    /// Creates a response of the page rendered by `renderer`.
+    pub fn new(renderer: ServerRenderer<COMP>) -> Self {
+        Self {
+            renderer,
+            template: DEFAULT_TEMPLATE.to_owned(),
+            head: None,
+            styles: None,
+            status: 200,
+        }
+    }
+
+    /// Sets the HTML document the application is rendered into, like the `index.html` built for
+    /// the client.
+    ///
+    /// The application is rendered at the start of its `<body>`, and the head tags are inserted
+    /// at the end of its `<head>`. Defaults to an empty document.
+    pub fn template(mut self, template: impl Into<String>) -> Self {
+        self.template = template.into();
+
+        self
+    }
+
+    /// Sets the collector of the head tags declared by the application, which must be provided
+    /// to it with a [`HeadProvider`](crate::head::HeadProvider).
+    pub fn head(mut self, head: HeadCollector) -> Self {
+        self.head = Some(head);
+
+        self
+    }
+
+    /// Sets the collector of the styles used by the application, which must be provided to it
+    /// with a [`StyleProvider`](crate::css::StyleProvider).
+    ///
+    /// The styles used by the components rendered before the first suspension are inlined in the
+    /// `<head>`, and the rest at the end of the `<body>`.
+    pub fn styles(mut self, styles: StyleCollector) -> Self {
+        self.styles = Some(styles);
+
+        self
+    }
+
+    /// Sets the status code of the response when no [`Status`](super::Status) is rendered.
+    ///
+    /// Defaults to `200`.
+    pub fn status(mut self, code: u16) -> Self {
+        self.status = code;
+
+        self
+    }
+
+    /// Renders the page until its first suspension, and returns the response streaming the rest
+    /// of it.
+    pub async fn render(self) -> RenderedResponse {
+        let Self {
+            renderer,
+            template,
+            head,
+            styles,
+            status,
+        } = self;
+
+        let response_status = ResponseStatus::new();
+        let mut renderer = renderer.response_status(response_status.clone());
+        if let Some(ref styles) = styles {
+            renderer = renderer.styles(styles.clone());
+        }
+        let mut body = renderer.render_stream();
+        // The first chunk holds everything rendered before the first suspension.
+        let first = body.next().await.unwrap_or_default();
+
+        let mut head = head.map(|m| m.render()).unwrap_or_default();
+        if let Some(ref styles) = styles {
+            head.push_str(&styles.render());
+        }
+        let (before, after) = split_template(&template, &head);
+
+        RenderedResponse {
+            status: response_status.get().unwrap_or(status),
+            body: stream::iter([before, first])
+                .chain(body)
+                .chain(stream::once(async move {
+                    match styles {
+                        Some(styles) => styles.render_deferred() + &after,
+                        None => after,
+                    }
+                }))
+                .boxed(),
+        }
+    }
+}
+
+/// Splits `template` where the application is rendered, inserting `head` at the end of its
+/// `<head>`.
+fn split_template(template: &str, head: &str) -> (String, String) {
+    let (mut before, after) = match template
+        .find("<body")
+        .and_then(|start| Some(start + template[start..].find('>')? + 1))
+    {
+        Some(body) => (template[..body].to_owned(), template[body..].to_owned()),
+        None => (template.to_owned(), String::new()),
+    };
+    if let Some(head_end) = before.find("</head>") {
+        before.insert_str(head_end, head);
+    }
+
+    (before, after)
+}
+
+/// A page whose rendering started, to be converted into the response of a web framework.
+pub struct RenderedResponse {
+    /// The status code of the response.
+    pub status: u16,
+    /// The HTML of the page.
+    pub body: BoxStream<'static, String>,
+}
+
+impl fmt::Debug for RenderedResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RenderedResponse")
+            .field("status", &self.status)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The `Content-Type` of the rendered pages.
+#[cfg(any(feature = "actix", feature = "axum", feature = "warp_adapter"))]
+const CONTENT_TYPE: &str = "text/html; charset=utf-8";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_template() {
+        let (before, after) = split_template(
+            r#"<html><head><meta charset="utf-8"></head><body class="app"></body></html>"#,
+            "<title>Home</title>",
+        );
+        assert_eq!(
+            before,
+            r#"<html><head><meta charset="utf-8"><title>Home</title></head><body class="app">"#
+        );
+        assert_eq!(after, "</body></html>");
+
+        let (before, after) = split_template("<p>no body</p>", "<title>Home</title>");
+        assert_eq!(before, "<p>no body</p>");
+        assert_eq!(after, "");
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::head::{HeadProvider, Title};
+    use crate::prelude::*;
+    use crate::ssr::Status;
+
+    #[derive(Properties, PartialEq)]
+    struct NotFoundProps {
+        head: HeadCollector,
+    }
+
+    #[function_component]
+    fn NotFound(props: &NotFoundProps) -> Html {
+        html! {
+            <HeadProvider collector={props.head.clone()}>
+                <Title text="Not found" />
+                <Status code={404} />
+                <h1>{"not found"}</h1>
+            </HeadProvider>
+        }
+    }
+
+    #[test]
+    async fn renders_into_template() {
+        let head = HeadCollector::new();
+        let renderer = ServerRenderer::<NotFound>::with_props({
+            let head = head.clone();
+            move || NotFoundProps { head }
+        })
+        .hydratable(false);
+        let response = SsrResponse::new(renderer).head(head).render().await;
+
+        assert_eq!(response.status, 404);
+        let body: String = response.body.collect().await;
+        assert_eq!(
+            body,
+            "<!DOCTYPE html><html><head><title>Not found</title></head>\
+             <body><h1>not found</h1></body></html>"
+        );
+    }
+}
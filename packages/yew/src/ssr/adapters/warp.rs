@@ -0,0 +1,37 @@
+use std::convert::Infallible;
+
+use futures::stream::StreamExt;
+use warp::http::header::{HeaderValue, CONTENT_TYPE};
+use warp::http::StatusCode;
+use warp::hyper::Body;
+use warp::reply::{Reply, Response};
+
+use super::RenderedResponse;
+
+impl Reply for RenderedResponse {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let body = Body::wrap_stream(self.body.map(Ok::<_, Infallible>));
+
+        let mut response = Response::new(body);
+        *response.status_mut() = status;
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(super::CONTENT_TYPE));
+        response
+    }
+}
+
+#[cfg(feature = "server_fn")]
+impl Reply for crate::server_fn::ServerFnResponse {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+        let mut response = Response::new(Body::from(self.body));
+        *response.status_mut() = status;
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(Self::CONTENT_TYPE));
+        response
+    }
+}
@@ -0,0 +1,230 @@
+//! This module contains the integration of server side rendering with web servers.
+//!
+//! The [`Status`] component sets the status code of the response from the rendered pages, for
+//! example in the route of the pages that are not found. The [`adapters`] send the output of a
+//! [`ServerRenderer`](crate::ServerRenderer) as the streaming response of a web framework, along
+//! with this status code and the tags of the [`head`](crate::head). A [`CancellationToken`] aborts
+//! the renderings it is given to, for example when the connection of the request is closed.
+
+use crate::html::{Component, Context, Html, Properties};
+
+/// Properties for [Status].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct StatusProps {
+    /// The status code of the response, for example `404`.
+    pub code: u16,
+}
+
+/// Sets the status code of the response of a server side rendered page.
+///
+/// The status code must be set before the first suspension of the rendering, as the status line
+/// is sent with the first chunk of the page. The last `<Status>` rendered wins. It renders
+/// nothing, and does nothing on the client.
+///
+/// # Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew::ssr::Status;
+///
+/// #[function_component]
+/// fn NotFound() -> Html {
+///     html! {
+///         <>
+///             <Status code={404} />
+///             <h1>{ "Page not found" }</h1>
+///         </>
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Status;
+
+impl Component for Status {
+    type Message = ();
+    type Properties = StatusProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    #[allow(unused_variables)]
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        #[cfg(feature = "ssr")]
+        if let Some(ref ssr) = ctx.link().ssr {
+            ssr.set_status(ctx.props().code);
+        }
+        Html::default()
+    }
+}
+
+#[cfg(feature = "ssr")]
+mod feat_ssr {
+    use std::future::Future;
+    use std::mem;
+    use std::sync::atomic::{AtomicU16, Ordering};
+    use std::sync::{Arc, Mutex, MutexGuard};
+    use std::task::{Poll, Waker};
+
+    use futures::future::poll_fn;
+
+    /// The status code set by the [`Status`](super::Status) components of the renderers it is
+    /// given to.
+    ///
+    /// It is cheap to clone, and all the clones share the same status code.
+    ///
+    /// ```
+    /// # use yew::prelude::*;
+    /// # #[function_component]
+    /// # fn App() -> Html { Html::default() }
+    /// use yew::ssr::ResponseStatus;
+    /// use yew::ServerRenderer;
+    ///
+    /// # async fn run() {
+    /// let status = ResponseStatus::new();
+    /// let html = ServerRenderer::<App>::new()
+    ///     .response_status(status.clone())
+    ///     .render()
+    ///     .await;
+    /// let code = status.get().unwrap_or(200);
+    /// # }
+    /// ```
+    #[derive(Debug, Clone, Default)]
+    pub struct ResponseStatus {
+        // 0 while no status code is set.
+        code: Arc<AtomicU16>,
+    }
+
+    impl ResponseStatus {
+        /// Creates a status without status code.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Returns the status code set by the last [`Status`](super::Status) rendered, if any.
+        pub fn get(&self) -> Option<u16> {
+            match self.code.load(Ordering::Relaxed) {
+                0 => None,
+                code => Some(code),
+            }
+        }
+
+        pub(crate) fn set(&self, code: u16) {
+            self.code.store(code, Ordering::Relaxed);
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct CancellationState {
+        cancelled: bool,
+        wakers: Vec<Waker>,
+    }
+
+    /// Aborts the renderings it is given to once it is cancelled.
+    ///
+    /// An aborted rendering stops right away, and its stream ends without the rest of the
+    /// document. It is cheap to clone, and all the clones cancel the same renderings.
+    ///
+    /// Dropping the stream of [`render_stream`](crate::ServerRenderer::render_stream) or the future
+    /// of [`render`](crate::ServerRenderer::render) also aborts the rendering, so most servers
+    /// abort the rendering of a request whose connection is closed without a token.
+    ///
+    /// ```
+    /// # use yew::prelude::*;
+    /// # #[function_component]
+    /// # fn App() -> Html { Html::default() }
+    /// use yew::ssr::CancellationToken;
+    /// use yew::ServerRenderer;
+    ///
+    /// let token = CancellationToken::new();
+    /// let stream = ServerRenderer::<App>::new()
+    ///     .cancellation_token(token.clone())
+    ///     .render_stream();
+    /// // when the client is gone
+    /// token.cancel();
+    /// ```
+    #[derive(Debug, Clone, Default)]
+    pub struct CancellationToken {
+        state: Arc<Mutex<CancellationState>>,
+    }
+
+    impl CancellationToken {
+        /// Creates a token that is not cancelled.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        fn lock(&self) -> MutexGuard<'_, CancellationState> {
+            self.state.lock().unwrap_or_else(|e| e.into_inner())
+        }
+
+        /// Cancels the renderings given this token.
+        pub fn cancel(&self) {
+            let wakers = {
+                let mut state = self.lock();
+                state.cancelled = true;
+                mem::take(&mut state.wakers)
+            };
+            for waker in wakers {
+                waker.wake();
+            }
+        }
+
+        /// Returns `true` if the token was cancelled.
+        pub fn is_cancelled(&self) -> bool {
+            self.lock().cancelled
+        }
+
+        /// Resolves once the token is cancelled.
+        pub(crate) fn cancelled(self) -> impl Future<Output = ()> {
+            poll_fn(move |cx| {
+                let mut state = self.lock();
+                if state.cancelled {
+                    return Poll::Ready(());
+                }
+                if !state.wakers.iter().any(|m| m.will_wake(cx.waker())) {
+                    state.wakers.push(cx.waker().clone());
+                }
+                Poll::Pending
+            })
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+pub use feat_ssr::*;
+
+#[cfg(feature = "ssr")]
+pub mod adapters;
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::LocalServerRenderer;
+
+    #[test]
+    async fn records_status_code() {
+        #[function_component]
+        fn NotFound() -> Html {
+            html! {
+                <>
+                    <Status code={404} />
+                    <h1>{"not found"}</h1>
+                </>
+            }
+        }
+
+        let status = ResponseStatus::new();
+        let s = LocalServerRenderer::<NotFound>::new()
+            .hydratable(false)
+            .response_status(status.clone())
+            .render()
+            .await;
+
+        assert_eq!(s, "<h1>not found</h1>");
+        assert_eq!(status.get(), Some(404));
+    }
+}
@@ -0,0 +1,295 @@
+//! This module provides caching of the server side rendered HTML of subtrees.
+
+use crate::html::{Component, Context, Html, Properties};
+use crate::virtual_dom::AttrValue;
+
+/// Properties for [Cached].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct CachedProps {
+    /// Identifies the rendered HTML of the children in the cache.
+    ///
+    /// It must change whenever the children render differently, for example by including the
+    /// locale or the properties they are rendered with.
+    pub cache_key: AttrValue,
+
+    /// The Children of the current Cached Component.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Caches the server side rendered HTML of its children, for subtrees that are identical across
+/// requests like navigation bars or footers.
+///
+/// When the server renderer is given an [`SsrCache`], the children are only rendered if there is
+/// no HTML cached under [`cache_key`](CachedProps::cache_key), and are otherwise replaced by the
+/// cached HTML. `<Suspense>`s in the children are never streamed out of order. Without a cache,
+/// and on the client, the children are rendered as usual.
+///
+/// # Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew::ssr_cache::Cached;
+///
+/// # #[function_component]
+/// # fn Footer() -> Html { Html::default() }
+/// #[function_component]
+/// fn Page() -> Html {
+///     html! {
+///         <Cached cache_key="footer">
+///             <Footer />
+///         </Cached>
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Cached;
+
+impl Component for Cached {
+    type Message = ();
+    type Properties = CachedProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        #[cfg(feature = "ssr")]
+        if let Some(ref ssr) = ctx.link().ssr {
+            ssr.set_cache_key(ctx.link().id, ctx.props().cache_key.clone());
+        }
+        ctx.props().children.clone()
+    }
+}
+
+#[cfg(feature = "ssr")]
+mod feat_ssr {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+    use std::time::Duration;
+
+    /// The time elapsed since the Unix epoch.
+    fn now() -> Duration {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+        {
+            Duration::from_secs_f64(js_sys::Date::now() / 1000.0)
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "wasi"))))]
+        {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+        }
+    }
+
+    #[derive(Debug)]
+    struct Entry {
+        html: Arc<str>,
+        expires_at: Option<Duration>,
+        last_used: u64,
+    }
+
+    #[derive(Debug)]
+    struct Inner {
+        entries: HashMap<(String, bool), Entry>,
+        max_entries: usize,
+        ttl: Option<Duration>,
+        uses: u64,
+    }
+
+    /// A cache of the server side rendered HTML of [`Cached`](super::Cached) subtrees, shared by
+    /// the renderers it is given to.
+    ///
+    /// Once the cache holds `max_entries` subtrees, the least recently used one is dropped. It is
+    /// cheap to clone, and all the clones share the same entries.
+    ///
+    /// ```
+    /// # use yew::prelude::*;
+    /// # #[function_component]
+    /// # fn App() -> Html { Html::default() }
+    /// use std::time::Duration;
+    ///
+    /// use yew::ssr_cache::SsrCache;
+    /// use yew::ServerRenderer;
+    ///
+    /// let cache = SsrCache::new(100).ttl(Duration::from_secs(60));
+    /// // for each request
+    /// let renderer = ServerRenderer::<App>::new().cache(cache.clone());
+    /// ```
+    #[derive(Debug, Clone)]
+    pub struct SsrCache {
+        inner: Arc<Mutex<Inner>>,
+    }
+
+    impl SsrCache {
+        /// Creates a cache holding up to `max_entries` subtrees.
+        pub fn new(max_entries: usize) -> Self {
+            Self {
+                inner: Arc::new(Mutex::new(Inner {
+                    entries: HashMap::new(),
+                    max_entries,
+                    ttl: None,
+                    uses: 0,
+                })),
+            }
+        }
+
+        /// Sets how long the HTML of a subtree is used once rendered.
+        ///
+        /// By default, it is used until it is dropped to make room or invalidated.
+        pub fn ttl(self, ttl: Duration) -> Self {
+            self.lock().ttl = Some(ttl);
+            self
+        }
+
+        /// Drops the HTML cached under `cache_key`.
+        pub fn invalidate(&self, cache_key: &str) {
+            let mut inner = self.lock();
+            for hydratable in [false, true] {
+                inner.entries.remove(&(cache_key.to_owned(), hydratable));
+            }
+        }
+
+        /// Drops all the cached HTML.
+        pub fn clear(&self) {
+            self.lock().entries.clear();
+        }
+
+        /// Returns the number of cached subtrees.
+        pub fn len(&self) -> usize {
+            self.lock().entries.len()
+        }
+
+        /// Returns `true` if no subtree is cached.
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        fn lock(&self) -> MutexGuard<'_, Inner> {
+            self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        /// Returns the HTML cached under `cache_key` for the rendering mode, if it didn't expire.
+        pub(crate) fn get(&self, cache_key: &str, hydratable: bool) -> Option<Arc<str>> {
+            let mut inner = self.lock();
+            inner.uses += 1;
+            let uses = inner.uses;
+            let key = (cache_key.to_owned(), hydratable);
+            let entry = inner.entries.get_mut(&key)?;
+            if entry
+                .expires_at
+                .is_some_and(|expires_at| expires_at <= now())
+            {
+                inner.entries.remove(&key);
+                return None;
+            }
+            entry.last_used = uses;
+            Some(entry.html.clone())
+        }
+
+        pub(crate) fn insert(&self, cache_key: &str, hydratable: bool, html: &str) {
+            let mut inner = self.lock();
+            if inner.max_entries == 0 {
+                return;
+            }
+            let key = (cache_key.to_owned(), hydratable);
+            if !inner.entries.contains_key(&key) && inner.entries.len() >= inner.max_entries {
+                let least_recently_used = inner
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_used)
+                    .map(|(key, _)| key.clone());
+                if let Some(key) = least_recently_used {
+                    inner.entries.remove(&key);
+                }
+            }
+            inner.uses += 1;
+            let entry = Entry {
+                html: html.into(),
+                expires_at: inner.ttl.map(|ttl| now() + ttl),
+                last_used: inner.uses,
+            };
+            inner.entries.insert(key, entry);
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn drops_least_recently_used() {
+            let cache = SsrCache::new(2);
+            cache.insert("a", false, "<a></a>");
+            cache.insert("b", false, "<b></b>");
+            assert_eq!(cache.get("a", false).as_deref(), Some("<a></a>"));
+            assert_eq!(cache.get("a", true), None);
+
+            cache.insert("c", false, "<c></c>");
+            assert_eq!(cache.len(), 2);
+            assert_eq!(cache.get("b", false), None);
+
+            cache.invalidate("a");
+            assert_eq!(cache.get("a", false), None);
+            assert_eq!(cache.get("c", false).as_deref(), Some("<c></c>"));
+        }
+
+        #[test]
+        fn expires_entries() {
+            let cache = SsrCache::new(2).ttl(Duration::ZERO);
+            cache.insert("a", false, "<a></a>");
+            assert_eq!(cache.get("a", false), None);
+            assert!(cache.is_empty());
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+pub use feat_ssr::*;
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use std::cell::Cell;
+
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::LocalServerRenderer;
+
+    thread_local! {
+        static RENDERS: Cell<u32> = const { Cell::new(0) };
+    }
+
+    #[test]
+    async fn reuses_cached_html() {
+        #[function_component]
+        fn Footer() -> Html {
+            RENDERS.with(|renders| renders.set(renders.get() + 1));
+            html! { <footer>{"footer"}</footer> }
+        }
+
+        #[function_component]
+        fn Page() -> Html {
+            html! {
+                <main>
+                    <Cached cache_key="footer">
+                        <Footer />
+                    </Cached>
+                </main>
+            }
+        }
+
+        let cache = SsrCache::new(10);
+        for _ in 0..2 {
+            let s = LocalServerRenderer::<Page>::new()
+                .hydratable(false)
+                .cache(cache.clone())
+                .render()
+                .await;
+            assert_eq!(s, "<main><footer>footer</footer></main>");
+        }
+        assert_eq!(RENDERS.with(Cell::get), 1);
+        assert_eq!(cache.len(), 1);
+    }
+}
@@ -0,0 +1,387 @@
+//! This module contains a global state store, shared by all components.
+//!
+//! A store is a type implementing [`Store`]. Its state is created from its [`Default`] value when
+//! it is first used, and can be read and changed from any component with [`use_store`], or from
+//! anywhere with a [`Dispatch`]. Components re-render when the state changes, or with
+//! [`use_selector`], only when the part of the state they read changes.
+//!
+//! Stores are global to the current thread. With server-side rendering, where a thread renders
+//! many requests, stores should only be used for state that can be shared by all of them.
+//!
+//! # Example
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew::store::{use_selector, use_store, Store};
+//!
+//! #[derive(Default, Clone, PartialEq)]
+//! struct Cart {
+//!     items: Vec<String>,
+//!     coupon: Option<String>,
+//! }
+//!
+//! impl Store for Cart {}
+//!
+//! #[function_component]
+//! fn AddToCart() -> Html {
+//!     let (_, dispatch) = use_store::<Cart>();
+//!     let onclick = dispatch.reduce_mut_callback(|cart, _| cart.items.push("Book".to_owned()));
+//!
+//!     html! { <button {onclick}>{ "Add to cart" }</button> }
+//! }
+//!
+//! /// Only re-renders when the number of items changes, not when a coupon is applied.
+//! #[function_component]
+//! fn CartSize() -> Html {
+//!     let size = use_selector(|cart: &Cart| cart.items.len());
+//!
+//!     html! { <span>{ size }</span> }
+//! }
+//! ```
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use slab::Slab;
+
+use crate::callback::Callback;
+use crate::functional::{hook, use_force_update, use_memo, use_mut_ref};
+use crate::time_travel::Timeline;
+
+/// A type whose state is shared by all components, see the [module documentation](self).
+///
+/// The initial state is the [`Default`] value of the type. Changes that result in an equal state
+/// do not re-render any component.
+pub trait Store: Default + PartialEq + 'static {}
+
+struct StoreState<S> {
+    state: RefCell<Rc<S>>,
+    subscribers: RefCell<Slab<Callback<Rc<S>>>>,
+    timeline: RefCell<Option<Timeline<S>>>,
+}
+
+impl<S: Store> StoreState<S> {
+    /// Replaces the state and notifies the subscribers if it changed.
+    fn replace(&self, next: Rc<S>) -> bool {
+        let changed = {
+            let mut state = self.state.borrow_mut();
+            let changed = *next != **state;
+            *state = next.clone();
+            changed
+        };
+
+        if changed {
+            // Subscribers are collected first, as they may subscribe or change the state.
+            let subscribers: Vec<Callback<Rc<S>>> = self
+                .subscribers
+                .borrow()
+                .iter()
+                .map(|(_, m)| m.clone())
+                .collect();
+            for subscriber in subscribers {
+                subscriber.emit(next.clone());
+            }
+        }
+        changed
+    }
+}
+
+thread_local! {
+    static STORES: RefCell<HashMap<TypeId, Rc<dyn Any>>> = RefCell::default();
+}
+
+/// A handle to read and change the state of the store `S`.
+pub struct Dispatch<S: Store> {
+    inner: Rc<StoreState<S>>,
+}
+
+impl<S: Store> Dispatch<S> {
+    /// Returns a handle to the store `S`, creating its state if it is used for the first time.
+    pub fn new() -> Self {
+        let inner = STORES.with(|stores| {
+            stores
+                .borrow_mut()
+                .entry(TypeId::of::<S>())
+                .or_insert_with(|| {
+                    Rc::new(StoreState::<S> {
+                        state: RefCell::new(Rc::new(S::default())),
+                        subscribers: RefCell::default(),
+                        timeline: RefCell::default(),
+                    })
+                })
+                .clone()
+        });
+
+        Self {
+            inner: inner
+                .downcast()
+                .expect("stores are registered by their type"),
+        }
+    }
+
+    /// Returns the current state.
+    pub fn get(&self) -> Rc<S> {
+        self.inner.state.borrow().clone()
+    }
+
+    /// Replaces the state with `value`.
+    pub fn set(&self, value: S) {
+        self.reduce(|_| value.into());
+    }
+
+    /// Replaces the state with the value returned by `f`, which is called with the current state.
+    pub fn reduce<F>(&self, f: F)
+    where
+        F: FnOnce(Rc<S>) -> Rc<S>,
+    {
+        let next = f(self.get());
+        if self.inner.replace(next.clone()) {
+            let timeline = self.inner.timeline.borrow().clone();
+            if let Some(timeline) = timeline {
+                timeline.record(None, next);
+            }
+        }
+    }
+
+    /// Returns the [`Timeline`] recording the changes of the state, starting it from the current
+    /// state the first time it is called. `capacity` is the number of states it keeps, and is
+    /// ignored once the timeline is started.
+    ///
+    /// Stepping back or forth in the timeline sets the state of the store without recording it
+    /// again. See the [`time_travel`](crate::time_travel) module.
+    pub fn timeline(&self, capacity: usize) -> Timeline<S> {
+        self.inner
+            .timeline
+            .borrow_mut()
+            .get_or_insert_with(|| {
+                let timeline = Timeline::new(capacity);
+                let inner = Rc::downgrade(&self.inner);
+                timeline.start(
+                    self.get(),
+                    Rc::new(move |state| {
+                        if let Some(inner) = inner.upgrade() {
+                            inner.replace(state);
+                        }
+                    }),
+                );
+                timeline
+            })
+            .clone()
+    }
+
+    /// Changes the state in place with `f`.
+    ///
+    /// The state is cloned first if it is still referenced elsewhere.
+    pub fn reduce_mut<F>(&self, f: F)
+    where
+        S: Clone,
+        F: FnOnce(&mut S),
+    {
+        self.reduce(|mut state| {
+            f(Rc::make_mut(&mut state));
+            state
+        });
+    }
+
+    /// Returns a callback changing the state in place with `f`, which is also called with the
+    /// event.
+    pub fn reduce_mut_callback<E, F>(&self, f: F) -> Callback<E>
+    where
+        S: Clone,
+        F: Fn(&mut S, E) + 'static,
+    {
+        let dispatch = self.clone();
+        Callback::from(move |e| dispatch.reduce_mut(|state| f(state, e)))
+    }
+
+    /// Calls `callback` with the new state whenever it changes, until the returned
+    /// [`StoreSubscription`] is dropped.
+    pub fn subscribe(&self, callback: Callback<Rc<S>>) -> StoreSubscription {
+        let key = self.inner.subscribers.borrow_mut().insert(callback);
+        let inner = Rc::downgrade(&self.inner);
+
+        StoreSubscription {
+            unsubscribe: Some(Box::new(move || {
+                if let Some(inner) = inner.upgrade() {
+                    inner.subscribers.borrow_mut().try_remove(key);
+                }
+            })),
+        }
+    }
+}
+
+impl<S: Store> Default for Dispatch<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Store> Clone for Dispatch<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<S: Store> PartialEq for Dispatch<S> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<S: Store + fmt::Debug> fmt::Debug for Dispatch<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dispatch")
+            .field("state", &self.get())
+            .finish()
+    }
+}
+
+/// Keeps a subscription to a store until dropped, see [`Dispatch::subscribe`].
+pub struct StoreSubscription {
+    unsubscribe: Option<Box<dyn FnOnce()>>,
+}
+
+impl Drop for StoreSubscription {
+    fn drop(&mut self) {
+        if let Some(unsubscribe) = self.unsubscribe.take() {
+            unsubscribe();
+        }
+    }
+}
+
+impl fmt::Debug for StoreSubscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StoreSubscription")
+    }
+}
+
+/// This hook returns the state of the store `S` and a [`Dispatch`] to change it.
+///
+/// The component re-renders whenever the state changes. See [`use_selector`] to only re-render
+/// when a part of the state changes.
+#[hook]
+pub fn use_store<S: Store>() -> (Rc<S>, Dispatch<S>) {
+    let dispatch = Dispatch::<S>::new();
+    let update = use_force_update();
+    {
+        let dispatch = dispatch.clone();
+        use_memo((), move |_| {
+            dispatch.subscribe(Callback::from(move |_| update.force_update()))
+        });
+    }
+
+    (dispatch.get(), dispatch)
+}
+
+/// This hook returns the part of the state of the store `S` selected by `selector`.
+///
+/// The component only re-renders when the selected value changes. The `selector` passed in the
+/// latest render is the one used to select updated values.
+#[hook]
+pub fn use_selector<S, U, F>(selector: F) -> U
+where
+    S: Store,
+    U: Clone + PartialEq + 'static,
+    F: Fn(&S) -> U + 'static,
+{
+    let dispatch = Dispatch::<S>::new();
+    let update = use_force_update();
+    let latest = use_mut_ref(|| None::<Box<dyn Fn(&S) -> U>>);
+    let selected = use_mut_ref(|| None::<U>);
+
+    {
+        let latest = latest.clone();
+        let selected = selected.clone();
+        let dispatch = dispatch.clone();
+        use_memo((), move |_| {
+            dispatch.subscribe(Callback::from(move |state: Rc<S>| {
+                let next = latest.borrow().as_ref().map(|f| f(&state));
+                let changed = *selected.borrow() != next;
+                if changed {
+                    *selected.borrow_mut() = next;
+                    update.force_update();
+                }
+            }))
+        });
+    }
+
+    let value = selector(&dispatch.get());
+    *latest.borrow_mut() = Some(Box::new(selector));
+    *selected.borrow_mut() = Some(value.clone());
+
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[derive(Debug, Default, Clone, PartialEq)]
+    struct Counter {
+        value: u32,
+    }
+
+    impl Store for Counter {}
+
+    #[test]
+    fn dispatch_notifies_on_change() {
+        let notified = Rc::new(Cell::new(0));
+        let subscription = {
+            let notified = notified.clone();
+            Dispatch::<Counter>::new().subscribe(Callback::from(move |state: Rc<Counter>| {
+                notified.set(state.value)
+            }))
+        };
+
+        let dispatch = Dispatch::<Counter>::new();
+        dispatch.reduce_mut(|m| m.value += 1);
+        assert_eq!(notified.get(), 1);
+        assert_eq!(Dispatch::<Counter>::new().get().value, 1);
+
+        // Equal states are not notified.
+        notified.set(0);
+        dispatch.set(Counter { value: 1 });
+        assert_eq!(notified.get(), 0);
+
+        drop(subscription);
+        dispatch.set(Counter { value: 2 });
+        assert_eq!(notified.get(), 0);
+    }
+
+    #[derive(Debug, Default, Clone, PartialEq)]
+    struct History {
+        value: u32,
+    }
+
+    impl Store for History {}
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn timeline_restores_states() {
+        let dispatch = Dispatch::<History>::new();
+        let timeline = dispatch.timeline(10);
+        dispatch.set(History { value: 1 });
+        dispatch.set(History { value: 2 });
+        assert_eq!(timeline.len(), 3);
+
+        let notified = Rc::new(Cell::new(0));
+        let _subscription = {
+            let notified = notified.clone();
+            dispatch.subscribe(Callback::from(move |state: Rc<History>| {
+                notified.set(state.value)
+            }))
+        };
+        assert!(timeline.back());
+        assert_eq!((dispatch.get().value, notified.get()), (1, 1));
+        // restoring a state doesn't record it
+        assert_eq!(timeline.len(), 3);
+        assert_eq!(dispatch.timeline(1), timeline);
+    }
+}
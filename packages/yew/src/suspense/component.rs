@@ -1,3 +1,4 @@
+use crate::callback::Callback;
 use crate::html::{Html, Properties};
 
 /// Properties for [Suspense].
@@ -12,10 +13,34 @@ pub struct SuspenseProps {
     pub fallback: Html,
 }
 
+/// A handle to the nearest [`Suspense`], returned by
+/// [`use_suspense_controller`](crate::suspense::use_suspense_controller).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuspenseHandle {
+    retry: Callback<()>,
+}
+
+impl SuspenseHandle {
+    /// Renders the children of the suspense again, from scratch, so that their suspended
+    /// computations run again.
+    ///
+    /// The fallback is shown until they are resumed.
+    pub fn retry(&self) {
+        self.retry.emit(());
+    }
+
+    /// Returns a callback retrying the suspense, for example for the `onclick` of a "Retry"
+    /// button.
+    pub fn retry_callback<IN>(&self) -> Callback<IN> {
+        let retry = self.retry.clone();
+        Callback::from(move |_| retry.emit(()))
+    }
+}
+
 #[cfg(any(feature = "csr", feature = "ssr"))]
 mod feat_csr_ssr {
     use super::*;
-    use crate::html::{Component, Context, Html, Scope};
+    use crate::html::{AnyScope, Component, Context, Html, Scope};
     use crate::suspense::Suspension;
     #[cfg(feature = "hydration")]
     use crate::suspense::SuspensionHandle;
@@ -33,11 +58,14 @@ mod feat_csr_ssr {
     pub(crate) enum BaseSuspenseMsg {
         Suspend(Suspension),
         Resume(Suspension),
+        Retry,
     }
 
     #[derive(Debug)]
     pub(crate) struct BaseSuspense {
         suspensions: Vec<Suspension>,
+        /// The number of retries, keying the children so that they are created again.
+        retries: u32,
         #[cfg(feature = "hydration")]
         hydration_handle: Option<SuspensionHandle>,
     }
@@ -71,6 +99,7 @@ mod feat_csr_ssr {
 
             Self {
                 suspensions,
+                retries: 0,
                 #[cfg(feature = "hydration")]
                 hydration_handle,
             }
@@ -103,12 +132,32 @@ mod feat_csr_ssr {
 
                     suspensions_len != self.suspensions.len()
                 }
+                Self::Message::Retry => {
+                    // The fallback is rendered in a suspense of its own, which retries the suspense
+                    // it is the fallback of.
+                    if ctx.props().fallback.is_none() {
+                        if let Some(parent) = ctx
+                            .link()
+                            .get_parent()
+                            .and_then(|m| m.find_parent_scope::<BaseSuspense>())
+                        {
+                            parent.send_message(BaseSuspenseMsg::Retry);
+                        }
+                        return false;
+                    }
+
+                    // The suspensions of the children are dropped along with them.
+                    self.suspensions.clear();
+                    self.retries = self.retries.wrapping_add(1);
+
+                    true
+                }
             }
         }
 
         fn view(&self, ctx: &Context<Self>) -> Html {
             let BaseSuspenseProps { children, fallback } = (*ctx.props()).clone();
-            let children = html! {<>{children}</>};
+            let children = html! {<><key={self.retries}>{children}</></>};
 
             match fallback {
                 Some(fallback) => {
@@ -144,6 +193,14 @@ mod feat_csr_ssr {
         pub(crate) fn resume(scope: &Scope<Self>, s: Suspension) {
             scope.send_message(BaseSuspenseMsg::Resume(s));
         }
+
+        /// Returns a handle to the suspense the component of `scope` is rendered in.
+        pub(crate) fn handle(scope: &AnyScope) -> Option<SuspenseHandle> {
+            let scope = scope.find_parent_scope::<Self>()?;
+            Some(SuspenseHandle {
+                retry: scope.callback(|_| BaseSuspenseMsg::Retry),
+            })
+        }
     }
 
     /// Suspend rendering and show a fallback UI until the underlying task completes.
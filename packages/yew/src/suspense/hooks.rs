@@ -5,7 +5,9 @@ use std::ops::Deref;
 use std::rc::Rc;
 
 use yew::prelude::*;
-use yew::suspense::{Suspension, SuspensionResult};
+use yew::suspense::{SuspenseHandle, Suspension, SuspensionResult};
+
+use crate::functional::{Hook, HookContext};
 
 /// This hook is used to await a future in a suspending context.
 ///
@@ -131,3 +133,55 @@ where
         Err((*suspension).clone())
     }
 }
+
+/// Returns a handle to the [`Suspense`] the component is rendered in, or `None` if it isn't
+/// rendered in one.
+///
+/// [Retrying](SuspenseHandle::retry) the suspense renders its children again, from scratch, so that
+/// a computation that failed, like a [`use_future`] whose request failed, runs again. It can be
+/// called from the children of the suspense as well as from its fallback.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// # use yew::suspense::{use_future, use_suspense_controller};
+/// use gloo::net::http::Request;
+///
+/// #[function_component]
+/// fn Quote() -> HtmlResult {
+///     let controller = use_suspense_controller().expect("rendered in a <Suspense>");
+///     let res = use_future(|| async { Request::get("/quote").send().await?.text().await })?;
+///
+///     Ok(match *res {
+///         Ok(ref quote) => html! { <p>{ quote }</p> },
+///         Err(ref failure) => html! {
+///             <p>
+///                 { failure.to_string() }
+///                 <button onclick={controller.retry_callback()}>{ "Retry" }</button>
+///             </p>
+///         },
+///     })
+/// }
+/// ```
+pub fn use_suspense_controller() -> impl Hook<Output = Option<SuspenseHandle>> {
+    struct HookProvider;
+
+    impl Hook for HookProvider {
+        type Output = Option<SuspenseHandle>;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            #[cfg(any(feature = "csr", feature = "ssr"))]
+            let handle = {
+                let scope = ctx.scope.clone();
+                ctx.next_state(move |_| crate::suspense::BaseSuspense::handle(&scope))
+            };
+            #[cfg(not(any(feature = "csr", feature = "ssr")))]
+            let handle = ctx.next_state(|_| None::<SuspenseHandle>);
+
+            (*handle).clone()
+        }
+    }
+
+    HookProvider
+}
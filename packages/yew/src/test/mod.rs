@@ -0,0 +1,7 @@
+//! Utilities to test components without a browser.
+//!
+//! This module requires the `test` feature.
+
+mod snapshot;
+
+pub use snapshot::*;
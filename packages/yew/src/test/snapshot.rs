@@ -0,0 +1,239 @@
+use std::fmt::{self, Write};
+
+use crate::virtual_dom::{Key, Listeners, VComp, VNode, VTag};
+
+/// Serializes a tree of virtual nodes to a stable, human-readable text, for snapshot tests.
+///
+/// Every node is on its own line and children are indented by two spaces. Attributes are sorted by
+/// name, so the output doesn't depend on the order they were written in, and texts are quoted so
+/// whitespace is visible.
+///
+/// - Elements show their attributes, their key, their `value` and `checked` properties and the
+///   events they listen to.
+/// - Lists are flattened in their parent, unless they have a key, like `<key="a"> .. </>`.
+/// - Components are not rendered: they show their type name and key, like `<Counter key="a" />`.
+/// - A suspense only shows the content that is rendered: its fallback while it is suspended.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew::test::snapshot;
+///
+/// let onclick = Callback::from(|_: MouseEvent| ());
+/// let html = html! {
+///     <ul id="menu" class="menu">
+///         <li key="a">{ "Home" }</li>
+///         <li key="b"><button {onclick}>{ "Log out" }</button></li>
+///     </ul>
+/// };
+///
+/// assert_eq!(
+///     snapshot(&html),
+///     r#"<ul class="menu" id="menu">
+///   <li key="a">
+///     "Home"
+///   </li>
+///   <li key="b">
+///     <button onclick>
+///       "Log out"
+///     </button>
+///   </li>
+/// </ul>
+/// "#
+/// );
+/// ```
+pub fn snapshot(html: &VNode) -> String {
+    let mut out = String::new();
+    Snapshot { out: &mut out }
+        .node(html, 0)
+        .expect("writing to a string doesn't fail");
+    out
+}
+
+struct Snapshot<'a> {
+    out: &'a mut String,
+}
+
+impl Snapshot<'_> {
+    fn line(&mut self, depth: usize, args: fmt::Arguments<'_>) -> fmt::Result {
+        for _ in 0..depth {
+            self.out.write_str("  ")?;
+        }
+        self.out.write_fmt(args)?;
+        self.out.write_char('\n')
+    }
+
+    fn node(&mut self, node: &VNode, depth: usize) -> fmt::Result {
+        match node {
+            VNode::VTag(tag) => self.tag(tag, depth),
+            VNode::VText(text) => self.line(depth, format_args!("{:?}", &*text.text)),
+            VNode::VComp(comp) => self.comp(comp, depth),
+            VNode::VList(list) => match &list.key {
+                Some(key) => {
+                    self.line(depth, format_args!("<key={:?}>", &**key))?;
+                    self.nodes(list.iter(), depth + 1)?;
+                    self.line(depth, format_args!("</>"))
+                }
+                None => self.nodes(list.iter(), depth),
+            },
+            VNode::VPortal(portal) => {
+                self.line(depth, format_args!("<#portal>"))?;
+                self.node(&portal.node, depth + 1)?;
+                self.line(depth, format_args!("</#portal>"))
+            }
+            VNode::VRef(_) => self.line(depth, format_args!("<#ref />")),
+            VNode::VSuspense(suspense) => {
+                let content = if suspense.suspended {
+                    &suspense.fallback
+                } else {
+                    &suspense.children
+                };
+                self.node(content, depth)
+            }
+            VNode::VRaw(raw) => self.line(depth, format_args!("<#raw {:?} />", &*raw.html)),
+        }
+    }
+
+    fn nodes<'n>(
+        &mut self,
+        mut nodes: impl Iterator<Item = &'n VNode>,
+        depth: usize,
+    ) -> fmt::Result {
+        nodes.try_for_each(|node| self.node(node, depth))
+    }
+
+    fn tag(&mut self, tag: &VTag, depth: usize) -> fmt::Result {
+        let listeners = match &tag.listeners {
+            Listeners::None => Vec::new(),
+            Listeners::Pending(listeners) => listeners.iter().flatten().map(|l| l.kind()).collect(),
+        };
+        let mut attributes = tag
+            .attributes
+            .iter()
+            .map(|(key, value)| (key, Some(value.to_owned())))
+            .collect::<Vec<_>>();
+        if let Some(value) = tag.value() {
+            attributes.push(("value", Some(value.to_string())));
+        }
+        if let Some(checked) = tag.checked() {
+            attributes.push(("checked", Some(checked.to_string())));
+        }
+        attributes.extend(listeners.iter().map(|kind| (kind.as_ref(), None)));
+        attributes.sort();
+
+        let mut open = format!("<{}", tag.tag());
+        for (key, value) in attributes {
+            match value {
+                Some(value) => write!(open, " {key}={value:?}")?,
+                None => write!(open, " {key}")?,
+            }
+        }
+        write!(open, "{}", KeyAttr(tag.key.as_ref()))?;
+
+        match tag.children().filter(|children| !is_empty(children)) {
+            Some(children) => {
+                self.line(depth, format_args!("{open}>"))?;
+                self.node(children, depth + 1)?;
+                self.line(depth, format_args!("</{}>", tag.tag()))
+            }
+            None => self.line(depth, format_args!("{open} />")),
+        }
+    }
+
+    fn comp(&mut self, comp: &VComp, depth: usize) -> fmt::Result {
+        self.line(
+            depth,
+            format_args!(
+                "<{}{} />",
+                short_type_name(comp.type_name()),
+                KeyAttr(comp.key.as_ref())
+            ),
+        )
+    }
+}
+
+fn is_empty(node: &VNode) -> bool {
+    match node {
+        VNode::VList(list) => list.key.is_none() && list.iter().all(is_empty),
+        _ => false,
+    }
+}
+
+struct KeyAttr<'a>(Option<&'a Key>);
+
+impl fmt::Display for KeyAttr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(key) => write!(f, " key={:?}", &**key),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Removes the module paths of a type name, `app::List<app::Item>` becoming `List<Item>`.
+fn short_type_name(name: &str) -> String {
+    let mut short = String::with_capacity(name.len());
+    let mut segment_start = 0;
+    for (i, c) in name.char_indices() {
+        if !(c.is_alphanumeric() || c == '_' || c == ':') {
+            short.push_str(last_segment(&name[segment_start..i]));
+            short.push(c);
+            segment_start = i + c.len_utf8();
+        }
+    }
+    short.push_str(last_segment(&name[segment_start..]));
+    short
+}
+
+fn last_segment(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[function_component]
+    fn Counter() -> Html {
+        html! {}
+    }
+
+    #[test]
+    fn attributes_are_sorted() {
+        let a = html! { <div id="a" class="b" title="c" /> };
+        let b = html! { <div title="c" id="a" class="b" /> };
+        assert_eq!(snapshot(&a), snapshot(&b));
+        assert_eq!(snapshot(&a), "<div class=\"b\" id=\"a\" title=\"c\" />\n");
+    }
+
+    #[test]
+    fn keyed_lists_and_components() {
+        let html = html! {
+            <>
+                <input value="x" checked=true />
+                <key="list">
+                    { "text\n" }
+                    <Counter key="c" />
+                </>
+                <></>
+            </>
+        };
+        assert_eq!(
+            snapshot(&html),
+            r#"<input checked="true" value="x" />
+<key="list">
+  "text\n"
+  <Counter key="c" />
+</>
+"#
+        );
+    }
+
+    #[test]
+    fn shortens_type_names() {
+        assert_eq!(
+            short_type_name("app::list::List<app::Item, std::rc::Rc<str>>"),
+            "List<Item, Rc<str>>"
+        );
+    }
+}
@@ -1,2 +1,6 @@
 #[allow(missing_docs)]
 pub mod layout_tests;
+mod renderer;
+pub mod simulate;
+
+pub use renderer::TestRenderer;
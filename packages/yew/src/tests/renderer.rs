@@ -0,0 +1,325 @@
+//! A renderer for tests, flushing the scheduler deterministically.
+
+use std::rc::Rc;
+
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+use crate::app_handle::AppHandle;
+use crate::html::{BaseComponent, Scope};
+use crate::scheduler;
+
+/// Mounts a component in a detached element, for tests.
+///
+/// The element is not attached to the document, so tests don't see each other's output, and every
+/// method that changes the component runs the scheduler until no work is left, so the rendered tree
+/// can be checked right away instead of after the next tick of the event loop. The component is
+/// destroyed when the renderer is dropped.
+///
+/// The tree can be queried like a user would find things on the page: by their accessible role,
+/// their text or their `data-testid` attribute.
+///
+/// ```rust,no_run
+/// use yew::prelude::*;
+/// use yew::tests::TestRenderer;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct Props {
+///     name: AttrValue,
+/// }
+///
+/// #[function_component]
+/// fn Greeting(props: &Props) -> Html {
+///     html! { <h1 data-testid="title">{ format!("Hello, {}!", props.name) }</h1> }
+/// }
+///
+/// let mut renderer = TestRenderer::<Greeting>::with_props(Props { name: "Yew".into() });
+/// assert!(renderer.query_by_text("Hello, Yew!").is_some());
+///
+/// renderer.update(Props { name: "Ferris".into() });
+/// assert_eq!(renderer.get_by_role("heading").text_content().unwrap(), "Hello, Ferris!");
+/// assert_eq!(renderer.get_by_test_id("title").tag_name(), "H1");
+/// ```
+#[derive(Debug)]
+pub struct TestRenderer<COMP: BaseComponent> {
+    root: Element,
+    handle: Option<AppHandle<COMP>>,
+}
+
+impl<COMP> TestRenderer<COMP>
+where
+    COMP: BaseComponent,
+    COMP::Properties: Default,
+{
+    /// Renders the component with its default properties.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self::with_props(Default::default())
+    }
+}
+
+impl<COMP> TestRenderer<COMP>
+where
+    COMP: BaseComponent,
+{
+    /// Renders the component with the given properties.
+    pub fn with_props(props: COMP::Properties) -> Self {
+        let root = gloo::utils::document()
+            .create_element("div")
+            .expect("can't create the root of the test renderer");
+        let handle = AppHandle::mount_with_props(root.clone(), Rc::new(props), false, true);
+        let renderer = Self {
+            root,
+            handle: Some(handle),
+        };
+        renderer.flush();
+        renderer
+    }
+
+    /// Updates the properties of the component and renders it again.
+    pub fn update(&mut self, props: COMP::Properties) {
+        self.handle_mut().update(props);
+        self.flush();
+    }
+
+    /// Runs the scheduler until all the pending work, like messages sent to the component or
+    /// renders of its children, is done.
+    pub fn flush(&self) {
+        scheduler::start_now();
+    }
+
+    /// Returns the scope of the component, to send it messages. Call [`flush`](Self::flush) to
+    /// process them.
+    pub fn scope(&self) -> &Scope<COMP> {
+        self.handle()
+    }
+
+    /// Returns the element the component is rendered in.
+    pub fn root(&self) -> &Element {
+        &self.root
+    }
+
+    /// Returns the rendered HTML.
+    pub fn inner_html(&self) -> String {
+        self.root.inner_html()
+    }
+
+    /// Returns the elements with the given accessible role, in document order.
+    ///
+    /// The role is the one of the `role` attribute, or else the implicit role of common elements:
+    /// `button`, `link`, `heading`, `textbox`, `checkbox`, `list`, ...
+    pub fn query_all_by_role(&self, role: &str) -> Vec<Element> {
+        self.elements()
+            .filter(|el| element_role(el).as_deref() == Some(role))
+            .collect()
+    }
+
+    /// Returns the first element with the given accessible role.
+    pub fn query_by_role(&self, role: &str) -> Option<Element> {
+        self.elements()
+            .find(|el| element_role(el).as_deref() == Some(role))
+    }
+
+    /// Returns the first element with the given accessible role.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such element.
+    pub fn get_by_role(&self, role: &str) -> Element {
+        self.query_by_role(role)
+            .unwrap_or_else(|| self.not_found(format_args!("an element with the role `{role}`")))
+    }
+
+    /// Returns the elements whose text is `text`, ignoring leading and trailing whitespace.
+    ///
+    /// Only the innermost elements are returned: in `<p><b>Hi</b></p>`, the text `Hi` is the one of
+    /// `<b>`.
+    pub fn query_all_by_text(&self, text: &str) -> Vec<Element> {
+        self.elements()
+            .filter(|el| has_own_text(el, text))
+            .collect()
+    }
+
+    /// Returns the first element whose text is `text`, ignoring leading and trailing whitespace.
+    pub fn query_by_text(&self, text: &str) -> Option<Element> {
+        self.elements().find(|el| has_own_text(el, text))
+    }
+
+    /// Returns the first element whose text is `text`, ignoring leading and trailing whitespace.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such element.
+    pub fn get_by_text(&self, text: &str) -> Element {
+        self.query_by_text(text)
+            .unwrap_or_else(|| self.not_found(format_args!("an element with the text {text:?}")))
+    }
+
+    /// Returns the element with the given `data-testid` attribute.
+    pub fn query_by_test_id(&self, id: &str) -> Option<Element> {
+        self.elements()
+            .find(|el| el.get_attribute("data-testid").as_deref() == Some(id))
+    }
+
+    /// Returns the element with the given `data-testid` attribute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such element.
+    pub fn get_by_test_id(&self, id: &str) -> Element {
+        self.query_by_test_id(id)
+            .unwrap_or_else(|| self.not_found(format_args!("an element with the test id {id:?}")))
+    }
+
+    /// Iterates over the rendered elements, in document order.
+    fn elements(&self) -> impl Iterator<Item = Element> {
+        let nodes = self
+            .root
+            .query_selector_all("*")
+            .expect("`*` is a valid selector");
+        (0..nodes.length())
+            .filter_map(move |i| nodes.item(i))
+            .filter_map(|node| node.dyn_into::<Element>().ok())
+    }
+
+    fn not_found(&self, what: std::fmt::Arguments<'_>) -> ! {
+        panic!("found no {what} in:\n{}", self.inner_html())
+    }
+
+    fn handle(&self) -> &AppHandle<COMP> {
+        self.handle.as_ref().expect("the component is mounted")
+    }
+
+    fn handle_mut(&mut self) -> &mut AppHandle<COMP> {
+        self.handle.as_mut().expect("the component is mounted")
+    }
+}
+
+impl<COMP: BaseComponent> Drop for TestRenderer<COMP> {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.destroy();
+            scheduler::start_now();
+        }
+    }
+}
+
+/// Returns the explicit or implicit role of an element.
+fn element_role(el: &Element) -> Option<String> {
+    if let Some(role) = el.get_attribute("role") {
+        return Some(role);
+    }
+    let input_type = || el.get_attribute("type").unwrap_or_default();
+    let role = match el.tag_name().to_ascii_lowercase().as_str() {
+        "a" | "area" if el.has_attribute("href") => "link",
+        "article" => "article",
+        "aside" => "complementary",
+        "button" => "button",
+        "dialog" => "dialog",
+        "footer" => "contentinfo",
+        "form" => "form",
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+        "header" => "banner",
+        "hr" => "separator",
+        "img" if el.get_attribute("alt").as_deref() != Some("") => "img",
+        "input" => match input_type().as_str() {
+            "button" | "image" | "reset" | "submit" => "button",
+            "checkbox" => "checkbox",
+            "radio" => "radio",
+            "range" => "slider",
+            "number" => "spinbutton",
+            "search" => "searchbox",
+            "" | "email" | "tel" | "text" | "url" => "textbox",
+            _ => return None,
+        },
+        "li" => "listitem",
+        "main" => "main",
+        "nav" => "navigation",
+        "ol" | "ul" => "list",
+        "option" => "option",
+        "progress" => "progressbar",
+        "section" => "region",
+        "select" => "combobox",
+        "table" => "table",
+        "tbody" | "thead" | "tfoot" => "rowgroup",
+        "td" => "cell",
+        "textarea" => "textbox",
+        "th" => "columnheader",
+        "tr" => "row",
+        _ => return None,
+    };
+    Some(role.to_owned())
+}
+
+/// Checks that the text of the element is `text`, but not the one of any of its children.
+fn has_own_text(el: &Element, text: &str) -> bool {
+    let matches = |el: &Element| el.text_content().is_some_and(|t| t.trim() == text);
+    if !matches(el) {
+        return false;
+    }
+    let children = el.children();
+    !(0..children.length())
+        .filter_map(|i| children.item(i))
+        .any(|child| matches(&child))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate self as yew;
+
+    use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+    use super::*;
+    use crate::prelude::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[derive(Properties, PartialEq, Default)]
+    struct Props {
+        step: u32,
+    }
+
+    #[function_component]
+    fn Counter(props: &Props) -> Html {
+        let count = use_state(|| 0);
+        let onclick = {
+            let count = count.clone();
+            let step = props.step;
+            Callback::from(move |_| count.set(*count + step))
+        };
+        html! {
+            <section>
+                <p data-testid="count">{ *count }</p>
+                <button {onclick}>{ "Add" }</button>
+                <a href="/help"><b>{ "Help" }</b></a>
+            </section>
+        }
+    }
+
+    #[test]
+    fn queries_the_rendered_tree() {
+        let renderer = TestRenderer::<Counter>::new();
+        assert_eq!(
+            renderer.get_by_role("button").text_content().unwrap(),
+            "Add"
+        );
+        assert_eq!(renderer.get_by_role("region").tag_name(), "SECTION");
+        assert_eq!(renderer.get_by_text("Help").tag_name(), "B");
+        assert_eq!(renderer.query_all_by_role("link").len(), 1);
+        assert!(renderer.query_by_test_id("missing").is_none());
+    }
+
+    #[test]
+    fn updates_props_and_flushes() {
+        let mut renderer = TestRenderer::<Counter>::with_props(Props { step: 1 });
+        renderer.update(Props { step: 5 });
+
+        let button: web_sys::HtmlElement = renderer.get_by_role("button").unchecked_into();
+        button.click();
+        renderer.flush();
+        assert_eq!(
+            renderer.get_by_test_id("count").text_content().unwrap(),
+            "5"
+        );
+    }
+}
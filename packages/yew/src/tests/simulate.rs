@@ -0,0 +1,252 @@
+//! Simulates user interactions in tests.
+//!
+//! Events are dispatched on the given element and bubble like the ones of the browser, so they go
+//! through the same listeners and event delegation as real events. The scheduler is then run, so
+//! the changes they cause are rendered when the function returns.
+//!
+//! Every function returns `false` if a listener called `prevent_default` on the event.
+//!
+//! ```rust,no_run
+//! use yew::prelude::*;
+//! use yew::tests::simulate::{self, Modifiers};
+//! use yew::tests::TestRenderer;
+//!
+//! #[function_component]
+//! fn Search() -> Html {
+//!     let query = use_state(String::new);
+//!     let oninput = {
+//!         let query = query.clone();
+//!         Callback::from(move |e: InputEvent| {
+//!             let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+//!             query.set(input.value());
+//!         })
+//!     };
+//!     html! {
+//!         <>
+//!             <input {oninput} />
+//!             <p data-testid="query">{ &*query }</p>
+//!         </>
+//!     }
+//! }
+//!
+//! let renderer = TestRenderer::<Search>::new();
+//! let input = renderer.get_by_role("textbox");
+//! simulate::input(&input, "yew");
+//! simulate::keydown(&input, "Enter", Modifiers::default());
+//! assert_eq!(renderer.get_by_test_id("query").text_content().unwrap(), "yew");
+//! ```
+
+use wasm_bindgen::JsValue;
+use web_sys::{
+    Element, Event, EventInit, InputEvent, InputEventInit, KeyboardEvent, KeyboardEventInit,
+    MouseEvent, MouseEventInit,
+};
+
+use crate::scheduler;
+
+/// The modifier keys held during a simulated event.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Modifiers {
+    /// The <kbd>Alt</kbd> key, or <kbd>⌥ Option</kbd> on macOS.
+    pub alt: bool,
+    /// The <kbd>Ctrl</kbd> key.
+    pub ctrl: bool,
+    /// The <kbd>⌘ Command</kbd> key on macOS, or <kbd>⊞ Windows</kbd> on Windows.
+    pub meta: bool,
+    /// The <kbd>Shift</kbd> key.
+    pub shift: bool,
+}
+
+impl Modifiers {
+    /// Only the <kbd>Ctrl</kbd> key.
+    pub const CTRL: Self = Self {
+        alt: false,
+        ctrl: true,
+        meta: false,
+        shift: false,
+    };
+    /// Only the <kbd>Shift</kbd> key.
+    pub const SHIFT: Self = Self {
+        alt: false,
+        ctrl: false,
+        meta: false,
+        shift: true,
+    };
+}
+
+/// Dispatches an event on `target` and renders the changes.
+pub fn dispatch(target: &Element, event: &Event) -> bool {
+    let not_prevented = target
+        .dispatch_event(event)
+        .expect("the event can be dispatched");
+    scheduler::start_now();
+    not_prevented
+}
+
+/// Clicks on `target` with the primary mouse button.
+///
+/// Like a click of the browser, it checks a checkbox, or submits the form of a submit button.
+pub fn click(target: &Element) -> bool {
+    click_with(target, Modifiers::default())
+}
+
+/// Clicks on `target` with the primary mouse button while holding modifier keys.
+pub fn click_with(target: &Element, modifiers: Modifiers) -> bool {
+    let init = MouseEventInit::new();
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    init.set_button(0);
+    init.set_alt_key(modifiers.alt);
+    init.set_ctrl_key(modifiers.ctrl);
+    init.set_meta_key(modifiers.meta);
+    init.set_shift_key(modifiers.shift);
+    let event = MouseEvent::new_with_mouse_event_init_dict("click", &init)
+        .expect("can't create a click event");
+    dispatch(target, &event)
+}
+
+/// Sets the value of an `<input>`, `<textarea>` or `<select>` and dispatches an `input` event, like
+/// when the user types.
+pub fn input(target: &Element, value: &str) -> bool {
+    set_value(target, value);
+    let init = InputEventInit::new();
+    init.set_bubbles(true);
+    init.set_data(Some(value));
+    init.set_input_type("insertText");
+    let event =
+        InputEvent::new_with_event_init_dict("input", &init).expect("can't create an input event");
+    dispatch(target, &event)
+}
+
+/// Sets the value of an `<input>`, `<textarea>` or `<select>` and dispatches a `change` event, like
+/// when the user commits a value.
+pub fn change(target: &Element, value: &str) -> bool {
+    set_value(target, value);
+    let init = EventInit::new();
+    init.set_bubbles(true);
+    let event = Event::new_with_event_init_dict("change", &init).expect("can't create an event");
+    dispatch(target, &event)
+}
+
+/// Presses a key on `target`, given by its
+/// [`key` value](https://developer.mozilla.org/en-US/docs/Web/API/UI_Events/Keyboard_event_key_values),
+/// like `"a"` or `"Enter"`.
+pub fn keydown(target: &Element, key: &str, modifiers: Modifiers) -> bool {
+    keyboard(target, "keydown", key, modifiers)
+}
+
+/// Releases a key on `target`, see [`keydown`].
+pub fn keyup(target: &Element, key: &str, modifiers: Modifiers) -> bool {
+    keyboard(target, "keyup", key, modifiers)
+}
+
+/// Dispatches a `submit` event on a form.
+pub fn submit(form: &Element) -> bool {
+    let init = EventInit::new();
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    let event = Event::new_with_event_init_dict("submit", &init).expect("can't create an event");
+    dispatch(form, &event)
+}
+
+fn keyboard(target: &Element, kind: &str, key: &str, modifiers: Modifiers) -> bool {
+    let init = KeyboardEventInit::new();
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    init.set_key(key);
+    init.set_alt_key(modifiers.alt);
+    init.set_ctrl_key(modifiers.ctrl);
+    init.set_meta_key(modifiers.meta);
+    init.set_shift_key(modifiers.shift);
+    let event = KeyboardEvent::new_with_keyboard_event_init_dict(kind, &init)
+        .expect("can't create a keyboard event");
+    dispatch(target, &event)
+}
+
+fn set_value(target: &Element, value: &str) {
+    js_sys::Reflect::set(
+        target,
+        &JsValue::from_str("value"),
+        &JsValue::from_str(value),
+    )
+    .expect("can't set the value of the element");
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate self as yew;
+
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::tests::TestRenderer;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[derive(Properties, PartialEq, Default)]
+    struct Props {
+        log: Rc<RefCell<Vec<String>>>,
+    }
+
+    #[function_component]
+    fn Form(props: &Props) -> Html {
+        let log = |entry: fn(&Event) -> String| {
+            let log = props.log.clone();
+            move |e: &Event| log.borrow_mut().push(entry(e))
+        };
+        let oninput = {
+            let log = log(|e| {
+                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                format!("input {}", input.value())
+            });
+            Callback::from(move |e: InputEvent| log(&e))
+        };
+        let onkeydown = {
+            let log = log(|e| {
+                let e: &KeyboardEvent = e.unchecked_ref();
+                format!("keydown {} ctrl={}", e.key(), e.ctrl_key())
+            });
+            Callback::from(move |e: KeyboardEvent| log(&e))
+        };
+        let onchange = {
+            let log = log(|_| "change".into());
+            Callback::from(move |e: Event| log(&e))
+        };
+        let onsubmit = {
+            let log = log(|_| "submit".into());
+            Callback::from(move |e: SubmitEvent| {
+                e.prevent_default();
+                log(&e);
+            })
+        };
+        html! {
+            <form {onsubmit}>
+                <input type="text" {oninput} {onkeydown} />
+                <input type="checkbox" {onchange} />
+            </form>
+        }
+    }
+
+    #[test]
+    fn events_reach_the_listeners() {
+        let props = Props::default();
+        let log = props.log.clone();
+        let renderer = TestRenderer::<Form>::with_props(props);
+
+        let text = renderer.get_by_role("textbox");
+        assert!(input(&text, "hi"));
+        assert!(keydown(&text, "s", Modifiers::CTRL));
+        assert!(click(&renderer.get_by_role("checkbox")));
+        assert!(!submit(&renderer.get_by_role("form")));
+
+        assert_eq!(
+            *log.borrow(),
+            ["input hi", "keydown s ctrl=true", "change", "submit"]
+        );
+    }
+}
@@ -0,0 +1,351 @@
+//! This module contains the theming of an application with typed design tokens.
+//!
+//! A [`Theme`] is a struct holding the design tokens of an application, like its colors and
+//! spacings. It is provided to a component tree with [`ThemeProvider`], and components read it
+//! with [`use_theme`].
+//!
+//! The tokens are also bridged to CSS: the custom properties returned by [`Theme::variables`] are
+//! set on the root element of the document (`<html>`) while the provider is mounted, and updated
+//! when the theme changes, so that stylesheets can use them with `var(--name)`. A provider nested
+//! in another one sets them on a `display: contents` element wrapping its children instead.
+//!
+//! During server-side rendering, the variables of the theme are collected by the `HeadCollector`
+//! of the [`head`](crate::head) module, and rendered into the `<head>` of the page as a `:root`
+//! stylesheet, so that the page is styled before it is hydrated. The stylesheet is replaced by the
+//! variables on the root element once the application is hydrated.
+//!
+//! # Example
+//!
+//! ```
+//! use yew::prelude::*;
+//! use yew::theme::{use_theme, Theme, ThemeProvider};
+//!
+//! #[derive(Clone, PartialEq, Default)]
+//! struct AppTheme {
+//!     primary: AttrValue,
+//!     spacing: u32,
+//! }
+//!
+//! impl Theme for AppTheme {
+//!     fn variables(&self) -> Vec<(AttrValue, AttrValue)> {
+//!         vec![
+//!             ("primary".into(), self.primary.clone()),
+//!             ("spacing".into(), format!("{}px", self.spacing).into()),
+//!         ]
+//!     }
+//! }
+//!
+//! #[function_component]
+//! fn Badge() -> Html {
+//!     let theme = use_theme::<AppTheme>();
+//!
+//!     html! {
+//!         <span style="padding: var(--spacing); color: var(--primary);">
+//!             { format!("{}px", theme.spacing) }
+//!         </span>
+//!     }
+//! }
+//!
+//! #[function_component]
+//! fn App() -> Html {
+//!     let theme = AppTheme {
+//!         primary: "#0366d6".into(),
+//!         spacing: 8,
+//!     };
+//!
+//!     html! {
+//!         <ThemeProvider<AppTheme> {theme}>
+//!             <Badge />
+//!         </ThemeProvider<AppTheme>>
+//!     }
+//! }
+//! ```
+
+use crate::context::ContextProvider;
+#[cfg(feature = "csr")]
+use crate::functional::use_effect_with;
+use crate::functional::{function_component, hook, use_context, use_memo};
+use crate::html;
+use crate::html::{Html, Properties};
+use crate::virtual_dom::AttrValue;
+
+/// The attribute set on the `<style>` tags rendered by the server for a theme.
+#[cfg(any(feature = "csr", feature = "ssr"))]
+const THEME_ATTR: &str = "data-yew-theme";
+
+/// The design tokens of an application, provided with [`ThemeProvider`].
+pub trait Theme: Clone + PartialEq + 'static {
+    /// Returns the CSS custom properties bridging the tokens to stylesheets, as pairs of a name,
+    /// without the leading `--`, and a value.
+    fn variables(&self) -> Vec<(AttrValue, AttrValue)>;
+}
+
+/// The CSS custom properties of a theme, with their leading `--`.
+type Variables = Vec<(String, AttrValue)>;
+
+fn variables(theme: &impl Theme) -> Variables {
+    theme
+        .variables()
+        .into_iter()
+        .map(|(name, value)| (format!("--{name}"), value))
+        .collect()
+}
+
+/// Renders the declarations of `variables`, like `--primary: #0366d6;`.
+fn declarations(variables: &Variables) -> String {
+    variables
+        .iter()
+        .map(|(name, value)| format!("{name}: {value};"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Properties for [`ThemeProvider`].
+#[derive(Debug, Properties, PartialEq)]
+pub struct ThemeProviderProps<T>
+where
+    T: Theme,
+{
+    /// The theme of the children.
+    pub theme: T,
+    /// The children of the provider.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Provides a [`Theme`] to its children, and sets its CSS custom properties on the root element
+/// of the document.
+///
+/// See the [module documentation](self) for details.
+#[function_component]
+pub fn ThemeProvider<T>(props: &ThemeProviderProps<T>) -> Html
+where
+    T: Theme,
+{
+    let nested = use_context::<T>().is_some();
+    let variables = use_memo(props.theme.clone(), variables);
+    use_root_variables((!nested).then(|| (*variables).clone()));
+
+    let children = if nested {
+        let style = format!("display: contents; {}", declarations(&variables));
+        html! { <div {style}>{props.children.clone()}</div> }
+    } else {
+        props.children.clone()
+    };
+
+    html! {
+        <ContextProvider<T> context={props.theme.clone()}>
+            {children}
+        </ContextProvider<T>>
+    }
+}
+
+/// Sets `variables` on the root element of the document for as long as the component is mounted.
+#[hook]
+fn use_root_variables(variables: Option<Variables>) {
+    let id = *use_memo((), |_| crate::head::next_id());
+
+    #[cfg(feature = "ssr")]
+    let collector = use_context::<crate::head::HeadCollector>();
+    #[cfg(feature = "ssr")]
+    if let (Some(collector), Some(variables)) = (collector, variables.as_ref()) {
+        // Keeps the values from closing the `<style>` tag.
+        let css = format!(":root {{ {} }}", declarations(variables)).replace('<', "\\3c ");
+        collector.insert(
+            id,
+            crate::head::HeadEntry::Style {
+                attr: THEME_ATTR,
+                css,
+            },
+        );
+    }
+
+    #[cfg(feature = "csr")]
+    {
+        use_effect_with(variables, move |variables| {
+            if let Some(variables) = variables.clone() {
+                feat_csr::insert(id, variables);
+            }
+            move || feat_csr::remove(id)
+        });
+    }
+
+    #[cfg(not(feature = "csr"))]
+    let _ = (id, variables);
+}
+
+/// Returns the theme of the closest [`ThemeProvider`] of `T`, or the default theme outside of
+/// any.
+///
+/// # Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew::theme::{use_theme, Theme};
+///
+/// #[derive(Clone, PartialEq, Default)]
+/// struct AppTheme {
+///     dark: bool,
+/// }
+///
+/// impl Theme for AppTheme {
+///     fn variables(&self) -> Vec<(AttrValue, AttrValue)> {
+///         let background = if self.dark { "#000" } else { "#fff" };
+///         vec![("background".into(), background.into())]
+///     }
+/// }
+///
+/// #[function_component]
+/// fn Logo() -> Html {
+///     let theme = use_theme::<AppTheme>();
+///     let src = if theme.dark { "/logo-dark.svg" } else { "/logo.svg" };
+///
+///     html! { <img {src} alt="Logo" /> }
+/// }
+/// ```
+#[hook]
+pub fn use_theme<T>() -> T
+where
+    T: Theme + Default,
+{
+    use_context::<T>().unwrap_or_default()
+}
+
+#[cfg(feature = "csr")]
+mod feat_csr {
+    use std::cell::RefCell;
+    use std::collections::BTreeMap;
+
+    use wasm_bindgen::JsCast;
+    use web_sys::HtmlElement;
+
+    use super::*;
+
+    thread_local! {
+        /// The variables of the mounted providers, the last one mounted being applied.
+        static THEMES: RefCell<BTreeMap<usize, Variables>> =
+            const { RefCell::new(BTreeMap::new()) };
+        /// The names of the variables set on the root element.
+        static APPLIED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub(super) fn insert(id: usize, variables: Variables) {
+        THEMES.with(|m| m.borrow_mut().insert(id, variables));
+        apply();
+    }
+
+    pub(super) fn remove(id: usize) {
+        if THEMES.with(|m| m.borrow_mut().remove(&id)).is_some() {
+            apply();
+        }
+    }
+
+    /// Replaces the variables set on the root element with the ones of the last mounted theme.
+    fn apply() {
+        let variables = THEMES
+            .with(|m| m.borrow().values().next_back().cloned())
+            .unwrap_or_default();
+        let document = gloo::utils::document();
+
+        // The variables rendered on the server are replaced by the ones set below.
+        if let Ok(rendered) = document.query_selector_all(&format!("style[{THEME_ATTR}]")) {
+            for i in 0..rendered.length() {
+                if let Some(node) = rendered.get(i) {
+                    if let Some(parent) = node.parent_node() {
+                        let _ = parent.remove_child(&node);
+                    }
+                }
+            }
+        }
+
+        let Some(root) = document
+            .document_element()
+            .and_then(|m| m.dyn_into::<HtmlElement>().ok())
+        else {
+            return;
+        };
+        let style = root.style();
+
+        APPLIED.with(|applied| {
+            let mut applied = applied.borrow_mut();
+            for name in applied.iter() {
+                if !variables.iter().any(|(m, _)| m == name) {
+                    let _ = style.remove_property(name);
+                }
+            }
+            for (name, value) in variables.iter() {
+                let _ = style.set_property(name, value);
+            }
+            *applied = variables.into_iter().map(|(name, _)| name).collect();
+        });
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::head::{HeadCollector, HeadProvider};
+    use crate::prelude::*;
+    use crate::LocalServerRenderer as ServerRenderer;
+
+    #[derive(Clone, PartialEq, Default)]
+    struct TestTheme {
+        color: AttrValue,
+    }
+
+    impl Theme for TestTheme {
+        fn variables(&self) -> Vec<(AttrValue, AttrValue)> {
+            vec![
+                ("color".into(), self.color.clone()),
+                ("gap".into(), "4px".into()),
+            ]
+        }
+    }
+
+    #[test]
+    async fn renders_root_variables_into_head() {
+        #[function_component]
+        fn Swatch() -> Html {
+            let theme = use_theme::<TestTheme>();
+
+            html! { <span>{ theme.color }</span> }
+        }
+
+        #[derive(Properties, PartialEq)]
+        struct AppProps {
+            head: HeadCollector,
+        }
+
+        #[function_component]
+        fn App(props: &AppProps) -> Html {
+            html! {
+                <HeadProvider collector={props.head.clone()}>
+                    <ThemeProvider<TestTheme> theme={TestTheme { color: "red".into() }}>
+                        <Swatch />
+                        <ThemeProvider<TestTheme> theme={TestTheme { color: "blue".into() }}>
+                            <Swatch />
+                        </ThemeProvider<TestTheme>>
+                    </ThemeProvider<TestTheme>>
+                </HeadProvider>
+            }
+        }
+
+        let head = HeadCollector::new();
+        let body = ServerRenderer::<App>::with_props(AppProps { head: head.clone() })
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            body,
+            "<span>red</span><div style=\"display: contents; --color: blue; --gap: 4px;\">\
+             <span>blue</span></div>"
+        );
+        assert_eq!(
+            head.render(),
+            "<style data-yew-theme>:root { --color: red; --gap: 4px; }</style>"
+        );
+    }
+}
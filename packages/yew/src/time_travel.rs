@@ -0,0 +1,319 @@
+//! This module contains a [`Timeline`] recording the states of a reducer or a store, to step back
+//! and forth between them while debugging.
+//!
+//! A timeline records every action dispatched to a reducer with [`use_reducer_with_timeline`], or
+//! every change of a store with [`Dispatch::timeline`], together with the state it led to. Stepping
+//! back or forth restores a recorded state and re-renders the components using it, without
+//! running the reducer again. Dispatching an action after stepping back discards the states that
+//! came after, like an undo history.
+//!
+//! Recording is meant for development: in release builds, timelines don't record anything.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::rc::Rc;
+//!
+//! use yew::prelude::*;
+//! use yew::time_travel::Timeline;
+//!
+//! struct Counter(i32);
+//!
+//! impl Reducible for Counter {
+//!     type Action = i32;
+//!
+//!     fn reduce(self: Rc<Self>, action: i32) -> Rc<Self> {
+//!         Counter(self.0 + action).into()
+//!     }
+//! }
+//!
+//! #[function_component]
+//! fn App() -> Html {
+//!     let timeline = use_memo((), |_| Timeline::new(100));
+//!     let counter = use_reducer_with_timeline(|| Counter(0), (*timeline).clone());
+//!
+//!     let add = {
+//!         let counter = counter.clone();
+//!         Callback::from(move |_| counter.dispatch(1))
+//!     };
+//!     let back = {
+//!         let timeline = timeline.clone();
+//!         Callback::from(move |_| {
+//!             timeline.back();
+//!         })
+//!     };
+//!
+//!     html! {
+//!         <>
+//!             <button onclick={add}>{ counter.0 }</button>
+//!             <button onclick={back}>{ "Undo" }</button>
+//!         </>
+//!     }
+//! }
+//! ```
+//!
+//! [`use_reducer_with_timeline`]: crate::functional::use_reducer_with_timeline
+//! [`Dispatch::timeline`]: crate::store::Dispatch::timeline
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::functional::Reducible;
+
+pub(crate) type RestoreFn<S> = Rc<dyn Fn(Rc<S>)>;
+
+/// A recorded state, and the action that led to it.
+#[derive(Debug)]
+pub struct TimelineEntry<S, A> {
+    /// The action, `None` for the initial state and for the changes of a store.
+    pub action: Option<A>,
+    /// The state after the action.
+    pub state: Rc<S>,
+}
+
+impl<S, A: Clone> Clone for TimelineEntry<S, A> {
+    fn clone(&self) -> Self {
+        Self {
+            action: self.action.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+struct Inner<S, A> {
+    entries: VecDeque<TimelineEntry<S, A>>,
+    cursor: usize,
+    capacity: usize,
+    restore: Option<RestoreFn<S>>,
+}
+
+/// The recorded states of a reducer or a store, see the [module documentation](self).
+///
+/// The timeline is a cheap handle: its clones share the same recording.
+pub struct Timeline<S, A = ()> {
+    inner: Rc<RefCell<Inner<S, A>>>,
+}
+
+impl<S, A> Timeline<S, A> {
+    /// Creates a timeline keeping the last `capacity` states.
+    ///
+    /// # Panics
+    ///
+    /// If `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "a timeline needs to keep at least one state");
+        Self {
+            inner: Rc::new(RefCell::new(Inner {
+                entries: VecDeque::new(),
+                cursor: 0,
+                capacity,
+                restore: None,
+            })),
+        }
+    }
+
+    /// Returns the number of recorded states.
+    pub fn len(&self) -> usize {
+        self.inner.borrow().entries.len()
+    }
+
+    /// Checks if nothing was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the index of the current state.
+    pub fn cursor(&self) -> usize {
+        self.inner.borrow().cursor
+    }
+
+    /// Returns the recorded state at `index`.
+    pub fn state(&self, index: usize) -> Option<Rc<S>> {
+        self.inner
+            .borrow()
+            .entries
+            .get(index)
+            .map(|entry| entry.state.clone())
+    }
+
+    /// Returns the recorded entries, the oldest first.
+    pub fn entries(&self) -> Vec<TimelineEntry<S, A>>
+    where
+        A: Clone,
+    {
+        self.inner.borrow().entries.iter().cloned().collect()
+    }
+
+    /// Restores the previous state. Returns `false` if the current state is the oldest one.
+    pub fn back(&self) -> bool {
+        let cursor = self.cursor();
+        cursor > 0 && self.jump(cursor - 1)
+    }
+
+    /// Restores the next state. Returns `false` if the current state is the newest one.
+    pub fn forward(&self) -> bool {
+        self.jump(self.cursor() + 1)
+    }
+
+    /// Restores the state at `index`. Returns `false` if there is no such state.
+    pub fn jump(&self, index: usize) -> bool {
+        let (state, restore) = {
+            let mut inner = self.inner.borrow_mut();
+            let Some(state) = inner.entries.get(index).map(|entry| entry.state.clone()) else {
+                return false;
+            };
+            inner.cursor = index;
+            (state, inner.restore.clone())
+        };
+        // restoring renders, which can read the timeline
+        if let Some(restore) = restore {
+            restore(state);
+        }
+        true
+    }
+
+    /// Starts a new recording from the `initial` state, restored with `restore`.
+    pub(crate) fn start(&self, initial: Rc<S>, restore: RestoreFn<S>) {
+        let mut inner = self.inner.borrow_mut();
+        inner.entries.clear();
+        inner.cursor = 0;
+        inner.restore = Some(restore);
+        if cfg!(debug_assertions) {
+            inner.entries.push_back(TimelineEntry {
+                action: None,
+                state: initial,
+            });
+        }
+    }
+
+    /// Records a new state, discarding the states after the current one.
+    pub(crate) fn record(&self, action: Option<A>, state: Rc<S>) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+        let mut inner = self.inner.borrow_mut();
+        let next = inner.cursor + 1;
+        inner.entries.truncate(next);
+        inner.entries.push_back(TimelineEntry { action, state });
+        if inner.entries.len() > inner.capacity {
+            inner.entries.pop_front();
+        }
+        inner.cursor = inner.entries.len() - 1;
+    }
+}
+
+impl<S, A> Timeline<S, A>
+where
+    S: Reducible<Action = A>,
+    A: Clone,
+{
+    /// Runs the recorded actions through the reducer again, from the oldest recorded state, and
+    /// restores the state at the cursor.
+    ///
+    /// Given a deterministic reducer, this recomputes the same states. After the reducer was
+    /// changed, it shows how the same actions are now reduced.
+    pub fn replay(&self) {
+        {
+            let mut inner = self.inner.borrow_mut();
+            let mut entries = inner.entries.iter_mut();
+            let Some(first) = entries.next() else {
+                return;
+            };
+            let mut state = first.state.clone();
+            for entry in entries {
+                if let Some(action) = entry.action.clone() {
+                    state = state.reduce(action);
+                }
+                entry.state = state.clone();
+            }
+        }
+        self.jump(self.cursor());
+    }
+}
+
+impl<S, A> Clone for Timeline<S, A> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<S, A> PartialEq for Timeline<S, A> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<S, A> fmt::Debug for Timeline<S, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = self.inner.borrow();
+        f.debug_struct("Timeline")
+            .field("len", &inner.entries.len())
+            .field("cursor", &inner.cursor)
+            .field("capacity", &inner.capacity)
+            .finish()
+    }
+}
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Counter(i32);
+
+    impl Reducible for Counter {
+        type Action = i32;
+
+        fn reduce(self: Rc<Self>, action: i32) -> Rc<Self> {
+            Counter(self.0 * 10 + action).into()
+        }
+    }
+
+    fn recorded(timeline: &Timeline<Counter, i32>) -> Vec<i32> {
+        timeline.entries().iter().map(|e| e.state.0).collect()
+    }
+
+    #[test]
+    fn steps_back_and_forth() {
+        let restored = Rc::new(Cell::new(None));
+        let timeline = Timeline::<Counter, i32>::new(3);
+        timeline.start(Rc::new(Counter(0)), {
+            let restored = restored.clone();
+            Rc::new(move |state: Rc<Counter>| restored.set(Some(state.0)))
+        });
+        for action in 1..=3 {
+            let state = timeline.state(timeline.cursor()).unwrap().reduce(action);
+            timeline.record(Some(action), state);
+        }
+        // the initial state was dropped to keep 3 states
+        assert_eq!(recorded(&timeline), [1, 12, 123]);
+
+        assert!(timeline.back());
+        assert_eq!(restored.get(), Some(12));
+        assert!(timeline.back());
+        assert!(!timeline.back());
+        assert!(timeline.forward());
+        assert_eq!((timeline.cursor(), restored.get()), (1, Some(12)));
+
+        // recording after stepping back discards the future
+        timeline.record(Some(9), Rc::new(Counter(129)));
+        assert_eq!(recorded(&timeline), [1, 12, 129]);
+        assert!(!timeline.forward());
+    }
+
+    #[test]
+    fn replays_actions() {
+        let timeline = Timeline::<Counter, i32>::new(10);
+        timeline.start(Rc::new(Counter(0)), Rc::new(|_| {}));
+        timeline.record(Some(1), Rc::new(Counter(1)));
+        timeline.record(Some(2), Rc::new(Counter(-1)));
+        timeline.replay();
+        assert_eq!(recorded(&timeline), [0, 1, 12]);
+    }
+}
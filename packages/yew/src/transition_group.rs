@@ -0,0 +1,459 @@
+//! This module provides animations of children entering and leaving a list.
+
+use std::collections::HashMap;
+
+use crate::html::{Component, Context, Html, NodeRef, Properties};
+use crate::virtual_dom::{AttrValue, Key, VNode};
+use crate::{html, Classes};
+
+/// Properties for [TransitionGroup].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct TransitionGroupProps {
+    /// The prefix of the transition classes, e.g. `fade` for `fade-enter-from`.
+    #[prop_or(AttrValue::Static("yew"))]
+    pub name: AttrValue,
+
+    /// The tag of the element containing the children.
+    #[prop_or(AttrValue::Static("div"))]
+    pub tag: AttrValue,
+
+    /// The class of the element containing the children.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Whether the children present when the group is created enter as well.
+    #[prop_or_default]
+    pub appear: bool,
+
+    /// The Children of the current TransitionGroup Component.
+    ///
+    /// Children must have keys, and render exactly one element each.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Whether a child is entering or leaving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    Enter,
+    Leave,
+}
+
+impl Phase {
+    #[cfg(feature = "csr")]
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Enter => "enter",
+            Self::Leave => "leave",
+        }
+    }
+}
+
+/// The transition of a child.
+#[derive(Debug)]
+struct Transition {
+    phase: Phase,
+    /// Identifies the transition, so that the end of a replaced transition is ignored.
+    id: u64,
+    /// Whether the classes were applied to the element of the child.
+    #[cfg(feature = "csr")]
+    started: bool,
+    #[cfg(feature = "csr")]
+    _guard: Option<feat_csr::EndListener>,
+}
+
+#[derive(Debug)]
+struct Item {
+    key: Key,
+    node: Html,
+    transition: Option<Transition>,
+}
+
+/// Animates its children when they are added or removed, with CSS transitions or animations.
+///
+/// Children are keyed, and each renders a single element. The element of an entering child
+/// is given the classes `{name}-enter-from` and `{name}-enter-active`, then
+/// `{name}-enter-from` is replaced with `{name}-enter-to` once the styles have been applied. A
+/// leaving child stays in the document with the `leave` classes instead. The classes are
+/// removed, and leaving children detached, once the transitions and animations of the element
+/// end.
+///
+/// # Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew::transition_group::TransitionGroup;
+///
+/// // .fade-enter-active, .fade-leave-active { transition: opacity 0.3s; }
+/// // .fade-enter-from, .fade-leave-to { opacity: 0; }
+/// #[function_component]
+/// fn Todos() -> Html {
+///     let todos = use_state(|| vec![1, 2, 3]);
+///     let remove = |id: i32| {
+///         let todos = todos.clone();
+///         Callback::from(move |_| todos.set(todos.iter().copied().filter(|m| *m != id).collect()))
+///     };
+///
+///     html! {
+///         <TransitionGroup name="fade" tag="ul">
+///             { for todos.iter().map(|id| html! {
+///                 <li key={*id} onclick={remove(*id)}>{ *id }</li>
+///             }) }
+///         </TransitionGroup>
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TransitionGroup {
+    /// The rendered children, including the leaving ones.
+    items: Vec<Item>,
+    container: NodeRef,
+    next_id: u64,
+}
+
+impl TransitionGroup {
+    fn transition(&mut self, phase: Phase) -> Transition {
+        self.next_id += 1;
+        Transition {
+            phase,
+            id: self.next_id,
+            #[cfg(feature = "csr")]
+            started: false,
+            #[cfg(feature = "csr")]
+            _guard: None,
+        }
+    }
+
+    /// Updates the items with the new children, keeping the leaving ones after their previous
+    /// siblings.
+    fn merge(&mut self, children: Vec<(Key, Html)>) {
+        let mut current: HashMap<Key, Item> = HashMap::new();
+        let mut leaving_before: HashMap<Key, Vec<Item>> = HashMap::new();
+        let mut leaving = Vec::new();
+
+        let new_keys = children.iter().map(|(key, _)| key).collect::<Vec<_>>();
+        for mut item in std::mem::take(&mut self.items) {
+            if new_keys.contains(&&item.key) {
+                leaving_before.insert(item.key.clone(), std::mem::take(&mut leaving));
+                current.insert(item.key.clone(), item);
+            } else {
+                if !matches!(&item.transition, Some(m) if m.phase == Phase::Leave) {
+                    item.transition = Some(self.transition(Phase::Leave));
+                }
+                leaving.push(item);
+            }
+        }
+
+        for (key, node) in children {
+            self.items
+                .extend(leaving_before.remove(&key).into_iter().flatten());
+
+            let item = match current.remove(&key) {
+                Some(mut item) => {
+                    if matches!(&item.transition, Some(m) if m.phase == Phase::Leave) {
+                        item.transition = Some(self.transition(Phase::Enter));
+                    }
+                    Item { node, ..item }
+                }
+                None => Item {
+                    key,
+                    node,
+                    transition: Some(self.transition(Phase::Enter)),
+                },
+            };
+            self.items.push(item);
+        }
+        self.items.extend(leaving);
+    }
+}
+
+/// Collects the keyed children of `node`, looking into unkeyed lists.
+fn keyed_children(node: &Html, children: &mut Vec<(Key, Html)>) {
+    match node {
+        VNode::VList(list) if list.key.is_none() => {
+            for child in list.iter() {
+                keyed_children(child, children);
+            }
+        }
+        node => match node.key() {
+            Some(key) => children.push((key.clone(), node.clone())),
+            None => tracing::warn!("children of TransitionGroup must have keys, ignoring one"),
+        },
+    }
+}
+
+impl Component for TransitionGroup {
+    /// The id of an ended transition.
+    type Message = u64;
+    type Properties = TransitionGroupProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let mut this = Self {
+            items: Vec::new(),
+            container: NodeRef::default(),
+            next_id: 0,
+        };
+
+        let mut children = Vec::new();
+        keyed_children(&ctx.props().children, &mut children);
+        for (key, node) in children {
+            let transition = ctx.props().appear.then(|| this.transition(Phase::Enter));
+            this.items.push(Item {
+                key,
+                node,
+                transition,
+            });
+        }
+        this
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, id: Self::Message) -> bool {
+        let Some(index) = self
+            .items
+            .iter()
+            .position(|m| m.transition.as_ref().is_some_and(|m| m.id == id))
+        else {
+            return false;
+        };
+
+        match self.items[index].transition.as_ref().map(|m| m.phase) {
+            Some(Phase::Leave) => {
+                self.items.remove(index);
+                true
+            }
+            _ => {
+                self.items[index].transition = None;
+                false
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        let mut children = Vec::new();
+        keyed_children(&ctx.props().children, &mut children);
+        self.merge(children);
+        true
+    }
+
+    #[cfg(feature = "csr")]
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        let Some(container) = self.container.cast::<web_sys::Element>() else {
+            return;
+        };
+
+        let elements = container.children();
+        for (index, item) in self.items.iter_mut().enumerate() {
+            let Some(transition) = item.transition.as_mut().filter(|m| !m.started) else {
+                continue;
+            };
+            let Some(element) = elements.item(index as u32) else {
+                continue;
+            };
+
+            transition.started = true;
+            transition._guard = Some(feat_csr::start(
+                &element,
+                &ctx.props().name,
+                transition.phase,
+                ctx.link().callback({
+                    let id = transition.id;
+                    move |_| id
+                }),
+            ));
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let TransitionGroupProps { tag, class, .. } = ctx.props();
+
+        html! {
+            <@{tag.to_string()} ref={&self.container} class={class.clone()}>
+                { for self.items.iter().map(|m| m.node.clone()) }
+            </@>
+        }
+    }
+}
+
+#[cfg(feature = "csr")]
+mod feat_csr {
+    use std::rc::Rc;
+
+    use gloo::events::EventListener;
+    use gloo::timers::callback::Timeout;
+    use web_sys::Element;
+
+    use super::Phase;
+    use crate::callback::Callback;
+
+    /// Listens for the end of the transitions and animations of an element.
+    #[derive(Debug)]
+    pub(super) struct EndListener {
+        _listeners: [EventListener; 2],
+        _timeout: Option<Timeout>,
+    }
+
+    /// Applies the classes of `phase` to `element`, and emits `ended` once its transitions and
+    /// animations end.
+    pub(super) fn start(
+        element: &Element,
+        name: &str,
+        phase: Phase,
+        ended: Callback<()>,
+    ) -> EndListener {
+        let phase = phase.as_str();
+        let [from, active, to] = ["from", "active", "to"].map(|m| format!("{name}-{phase}-{m}"));
+
+        let classes = element.class_list();
+        for phase in ["enter", "leave"] {
+            for m in ["from", "active", "to"] {
+                let _ = classes.remove_1(&format!("{name}-{phase}-{m}"));
+            }
+        }
+        let _ = classes.add_2(&from, &active);
+        // Reading the layout applies the styles, so that transitions start from the `from` class.
+        let _ = element.client_width();
+        let _ = classes.remove_1(&from);
+        let _ = classes.add_1(&to);
+
+        let end: Rc<dyn Fn()> = {
+            let classes = classes.clone();
+            Rc::new(move || {
+                let _ = classes.remove_2(&active, &to);
+                ended.emit(());
+            })
+        };
+        let listen = |event_type: &'static str| {
+            let element = element.clone();
+            let end = end.clone();
+            EventListener::new(&element.clone(), event_type, move |e| {
+                // Ignore the transitions of descendants.
+                if e.target().as_ref() == Some(element.as_ref()) {
+                    end();
+                }
+            })
+        };
+        let listeners = [listen("transitionend"), listen("animationend")];
+
+        // The end events are not fired if nothing is animated, or if the transition is
+        // interrupted.
+        let duration = gloo::utils::window()
+            .get_computed_style(element)
+            .ok()
+            .flatten()
+            .map(|style| {
+                let property = |name| style.get_property_value(name).unwrap_or_default();
+                longest_time(
+                    &property("transition-duration"),
+                    &property("transition-delay"),
+                )
+                .max(longest_time(
+                    &property("animation-duration"),
+                    &property("animation-delay"),
+                ))
+            })
+            .unwrap_or_default();
+        let timeout = if duration > 0.0 {
+            Some(Timeout::new(duration.ceil() as u32 + 50, move || end()))
+        } else {
+            end();
+            None
+        };
+
+        EndListener {
+            _listeners: listeners,
+            _timeout: timeout,
+        }
+    }
+
+    /// Returns the longest time, in milliseconds, of a transition or animation with the given
+    /// computed durations and delays, such as `0.3s, 150ms`.
+    pub(super) fn longest_time(durations: &str, delays: &str) -> f64 {
+        fn parse(times: &str) -> Vec<f64> {
+            times
+                .split(',')
+                .map(str::trim)
+                .filter_map(|m| match m.strip_suffix("ms") {
+                    Some(ms) => ms.parse().ok(),
+                    None => m.strip_suffix('s')?.parse::<f64>().ok().map(|s| s * 1000.0),
+                })
+                .collect()
+        }
+
+        let delays = parse(delays);
+        parse(durations)
+            .into_iter()
+            .enumerate()
+            .map(|(i, duration)| {
+                let delay = delays.get(i % delays.len().max(1)).copied().unwrap_or(0.0);
+                duration + delay
+            })
+            .fold(0.0, f64::max)
+    }
+}
+
+#[cfg(feature = "csr")]
+#[cfg(test)]
+mod tests {
+    use super::feat_csr::longest_time;
+    use super::*;
+
+    fn item(key: &str, transition: Option<Phase>) -> Item {
+        Item {
+            key: key.into(),
+            node: Html::default(),
+            transition: transition.map(|phase| Transition {
+                phase,
+                id: 0,
+                started: true,
+                _guard: None,
+            }),
+        }
+    }
+
+    fn children(keys: &[&str]) -> Vec<(Key, Html)> {
+        keys.iter()
+            .map(|m| (Key::from(*m), Html::default()))
+            .collect()
+    }
+
+    fn state(group: &TransitionGroup) -> Vec<(String, Option<Phase>)> {
+        group
+            .items
+            .iter()
+            .map(|m| (m.key.to_string(), m.transition.as_ref().map(|m| m.phase)))
+            .collect()
+    }
+
+    #[test]
+    fn leaving_children_keep_their_position() {
+        let mut group = TransitionGroup {
+            items: vec![
+                item("a", None),
+                item("b", None),
+                item("c", Some(Phase::Leave)),
+            ],
+            container: NodeRef::default(),
+            next_id: 0,
+        };
+
+        group.merge(children(&["a", "c", "d"]));
+
+        assert_eq!(
+            state(&group),
+            [
+                ("a".to_owned(), None),
+                ("b".to_owned(), Some(Phase::Leave)),
+                ("c".to_owned(), Some(Phase::Enter)),
+                ("d".to_owned(), Some(Phase::Enter)),
+            ]
+        );
+    }
+
+    #[test]
+    fn computed_times() {
+        assert_eq!(longest_time("0s", "0s"), 0.0);
+        assert_eq!(longest_time("0.3s, 150ms", "0s, 200ms"), 350.0);
+        assert_eq!(longest_time("0.3s, 0.1s", "0.1s"), 400.0);
+        assert_eq!(longest_time("", ""), 0.0);
+    }
+}
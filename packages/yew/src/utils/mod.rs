@@ -75,3 +75,63 @@ impl<T: Clone> RcExt<T> for std::rc::Rc<T> {
         std::rc::Rc::try_unwrap(this).unwrap_or_else(|rc| (*rc).clone())
     }
 }
+
+/// Marks the elements of the longest strictly increasing subsequence of `seq`, ignoring the `None`
+/// elements, in `O(n log n)`.
+///
+/// Keyed lists use it to find the largest set of children that keep their order, and only move
+/// the others. Public for the benchmarks.
+#[doc(hidden)]
+pub fn longest_increasing_subsequence(seq: &[Option<usize>]) -> Vec<bool> {
+    // tails[k] is the index in seq of the smallest tail of an increasing subsequence of length
+    // k + 1, and predecessors[i] the index of the element before seq[i] in its subsequence
+    let mut tails: Vec<usize> = Vec::new();
+    let mut predecessors = vec![usize::MAX; seq.len()];
+    for (i, value) in seq.iter().enumerate() {
+        let Some(value) = *value else { continue };
+        let len = tails.partition_point(|&t| seq[t] < Some(value));
+        if len > 0 {
+            predecessors[i] = tails[len - 1];
+        }
+        if len == tails.len() {
+            tails.push(i);
+        } else {
+            tails[len] = i;
+        }
+    }
+
+    let mut mask = vec![false; seq.len()];
+    let mut next = tails.last().copied();
+    while let Some(i) = next {
+        mask[i] = true;
+        next = Some(predecessors[i]).filter(|&p| p != usize::MAX);
+    }
+    mask
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_increasing_subsequence_skips_moved_elements() {
+        let lis = |seq: &[Option<usize>]| {
+            longest_increasing_subsequence(seq)
+                .iter()
+                .map(|&kept| kept as u8)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(lis(&[]), Vec::<u8>::new());
+        assert_eq!(lis(&[Some(0), Some(1), Some(2)]), [1, 1, 1]);
+        assert_eq!(lis(&[Some(2), Some(1), Some(0)]), [0, 0, 1]);
+        // a moved element, and a new one
+        assert_eq!(
+            lis(&[Some(3), Some(0), None, Some(1), Some(2)]),
+            [0, 1, 0, 1, 1]
+        );
+        assert_eq!(
+            lis(&[Some(0), Some(4), Some(1), Some(2), Some(3)]),
+            [1, 0, 1, 1, 1]
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! This module provides animated updates with the [View Transitions API].
+//!
+//! [View Transitions API]: https://developer.mozilla.org/en-US/docs/Web/API/View_Transitions_API
+
+use wasm_bindgen::JsValue;
+
+/// A view transition started by [`start_view_transition`].
+#[derive(Debug, Clone)]
+pub struct ViewTransition {
+    /// The `ViewTransition` object of the browser, if the update is animated.
+    inner: Option<JsValue>,
+}
+
+impl ViewTransition {
+    /// Returns `true` if the update is animated, and `false` if view transitions are not supported.
+    pub fn is_animated(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    /// Waits for the end of the animation.
+    ///
+    /// Returns right away if the update is not animated.
+    pub async fn finished(&self) {
+        if let Some(inner) = &self.inner {
+            feat_browser::finished(inner).await;
+        }
+    }
+
+    /// Skips the animation. The update is applied nonetheless.
+    pub fn skip(&self) {
+        if let Some(inner) = &self.inner {
+            feat_browser::skip(inner);
+        }
+    }
+}
+
+/// Applies `update` and animates the resulting changes of the page with a view transition.
+///
+/// The browser captures the current page, then calls `update` and renders the components it
+/// updated right away, before animating from the old to the new page. The animation can be styled
+/// with the `view-transition-name` property and the `::view-transition-*` pseudo-elements.
+///
+/// Components suspended by the update are captured with their fallback. In browsers without view
+/// transitions, `update` is called right away and components are rendered as usual.
+///
+/// # Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew::view_transition::start_view_transition;
+///
+/// #[function_component]
+/// fn Gallery() -> Html {
+///     let selected = use_state(|| 0);
+///     let next = {
+///         let selected = selected.clone();
+///         Callback::from(move |_| {
+///             let selected = selected.clone();
+///             start_view_transition(move || selected.set(*selected + 1));
+///         })
+///     };
+///
+///     html! {
+///         <img onclick={next} src={format!("/photos/{}.jpg", *selected)} />
+///     }
+/// }
+/// ```
+pub fn start_view_transition<F>(update: F) -> ViewTransition
+where
+    F: FnOnce() + 'static,
+{
+    ViewTransition {
+        inner: feat_browser::start(Box::new(update)),
+    }
+}
+
+#[cfg(all(
+    target_arch = "wasm32",
+    not(target_os = "wasi"),
+    not(feature = "not_browser_env")
+))]
+mod feat_browser {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use js_sys::{Function, Promise, Reflect};
+    use wasm_bindgen::prelude::Closure;
+    use wasm_bindgen::{intern, JsCast, JsValue};
+    use wasm_bindgen_futures::JsFuture;
+
+    use crate::scheduler;
+
+    fn method(target: &JsValue, name: &str) -> Option<Function> {
+        Reflect::get(target, &intern(name).into())
+            .ok()?
+            .dyn_into()
+            .ok()
+    }
+
+    pub(super) fn start(update: Box<dyn FnOnce()>) -> Option<JsValue> {
+        let document = gloo::utils::document();
+        let Some(start) = method(&document, "startViewTransition") else {
+            update();
+            return None;
+        };
+
+        // The update must be applied even if the transition cannot be started.
+        let update = Rc::new(RefCell::new(Some(update)));
+        let apply = {
+            let update = update.clone();
+            move || {
+                if let Some(update) = update.borrow_mut().take() {
+                    update();
+                    // The new page is captured once the callback returns.
+                    scheduler::start_now();
+                }
+            }
+        };
+        let callback = Closure::once_into_js(apply.clone());
+
+        match start.call1(&document, &callback) {
+            Ok(transition) => Some(transition),
+            Err(_) => {
+                apply();
+                None
+            }
+        }
+    }
+
+    pub(super) async fn finished(transition: &JsValue) {
+        let finished = Reflect::get(transition, &intern("finished").into())
+            .ok()
+            .and_then(|m| m.dyn_into::<Promise>().ok());
+        if let Some(finished) = finished {
+            let _ = JsFuture::from(finished).await;
+        }
+    }
+
+    pub(super) fn skip(transition: &JsValue) {
+        if let Some(skip) = method(transition, "skipTransition") {
+            let _ = skip.call0(transition);
+        }
+    }
+}
+
+#[cfg(any(
+    not(target_arch = "wasm32"),
+    target_os = "wasi",
+    feature = "not_browser_env"
+))]
+mod feat_browser {
+    use wasm_bindgen::JsValue;
+
+    pub(super) fn start(update: Box<dyn FnOnce()>) -> Option<JsValue> {
+        update();
+        None
+    }
+
+    pub(super) async fn finished(_transition: &JsValue) {}
+
+    pub(super) fn skip(_transition: &JsValue) {}
+}
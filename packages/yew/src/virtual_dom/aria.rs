@@ -0,0 +1,324 @@
+//! This module contains typed [WAI-ARIA] roles and attributes.
+//!
+//! In `html!`, `aria-*` attributes are checked against the attributes of the specification, and
+//! so are the literal values of `role`. Their values can be of any type implementing
+//! [`IntoAriaValue`], like `bool` or [`AriaRole`]:
+//!
+//! ```
+//! use yew::prelude::*;
+//! use yew::virtual_dom::aria::AriaRole;
+//!
+//! let expanded = false;
+//! html! {
+//!     <div role={AriaRole::Button} aria-expanded={expanded} aria-controls="menu" />
+//! };
+//! ```
+//!
+//! [WAI-ARIA]: https://www.w3.org/TR/wai-aria-1.2/
+
+use std::borrow::Cow;
+use std::fmt;
+use std::rc::Rc;
+
+use super::{AttrValue, VTag};
+
+macro_rules! aria_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $value:literal,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        #[allow(missing_docs)]
+        pub enum $name {
+            $( $variant, )*
+        }
+
+        impl $name {
+            /// Returns the name used in html.
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $( Self::$variant => $value, )*
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+aria_enum! {
+    /// A [role](https://www.w3.org/TR/wai-aria-1.2/#role_definitions), the value of the `role`
+    /// attribute.
+    AriaRole {
+        Alert => "alert",
+        AlertDialog => "alertdialog",
+        Application => "application",
+        Article => "article",
+        Banner => "banner",
+        Blockquote => "blockquote",
+        Button => "button",
+        Caption => "caption",
+        Cell => "cell",
+        Checkbox => "checkbox",
+        Code => "code",
+        ColumnHeader => "columnheader",
+        Combobox => "combobox",
+        Complementary => "complementary",
+        ContentInfo => "contentinfo",
+        Definition => "definition",
+        Deletion => "deletion",
+        Dialog => "dialog",
+        Directory => "directory",
+        Document => "document",
+        Emphasis => "emphasis",
+        Feed => "feed",
+        Figure => "figure",
+        Form => "form",
+        Generic => "generic",
+        Grid => "grid",
+        GridCell => "gridcell",
+        Group => "group",
+        Heading => "heading",
+        Img => "img",
+        Insertion => "insertion",
+        Link => "link",
+        List => "list",
+        Listbox => "listbox",
+        ListItem => "listitem",
+        Log => "log",
+        Main => "main",
+        Marquee => "marquee",
+        Math => "math",
+        Menu => "menu",
+        Menubar => "menubar",
+        MenuItem => "menuitem",
+        MenuItemCheckbox => "menuitemcheckbox",
+        MenuItemRadio => "menuitemradio",
+        Meter => "meter",
+        Navigation => "navigation",
+        None => "none",
+        Note => "note",
+        Option => "option",
+        Paragraph => "paragraph",
+        Presentation => "presentation",
+        ProgressBar => "progressbar",
+        Radio => "radio",
+        RadioGroup => "radiogroup",
+        Region => "region",
+        Row => "row",
+        RowGroup => "rowgroup",
+        RowHeader => "rowheader",
+        Scrollbar => "scrollbar",
+        Search => "search",
+        Searchbox => "searchbox",
+        Separator => "separator",
+        Slider => "slider",
+        SpinButton => "spinbutton",
+        Status => "status",
+        Strong => "strong",
+        Subscript => "subscript",
+        Superscript => "superscript",
+        Switch => "switch",
+        Tab => "tab",
+        Table => "table",
+        TabList => "tablist",
+        TabPanel => "tabpanel",
+        Term => "term",
+        Textbox => "textbox",
+        Time => "time",
+        Timer => "timer",
+        Toolbar => "toolbar",
+        Tooltip => "tooltip",
+        Tree => "tree",
+        TreeGrid => "treegrid",
+        TreeItem => "treeitem",
+    }
+}
+
+aria_enum! {
+    /// A [state or property](https://www.w3.org/TR/wai-aria-1.2/#state_prop_def), an `aria-*`
+    /// attribute.
+    Aria {
+        ActiveDescendant => "aria-activedescendant",
+        Atomic => "aria-atomic",
+        AutoComplete => "aria-autocomplete",
+        BrailleLabel => "aria-braillelabel",
+        BrailleRoleDescription => "aria-brailleroledescription",
+        Busy => "aria-busy",
+        Checked => "aria-checked",
+        ColCount => "aria-colcount",
+        ColIndex => "aria-colindex",
+        ColIndexText => "aria-colindextext",
+        ColSpan => "aria-colspan",
+        Controls => "aria-controls",
+        Current => "aria-current",
+        DescribedBy => "aria-describedby",
+        Description => "aria-description",
+        Details => "aria-details",
+        Disabled => "aria-disabled",
+        DropEffect => "aria-dropeffect",
+        ErrorMessage => "aria-errormessage",
+        Expanded => "aria-expanded",
+        FlowTo => "aria-flowto",
+        Grabbed => "aria-grabbed",
+        HasPopup => "aria-haspopup",
+        Hidden => "aria-hidden",
+        Invalid => "aria-invalid",
+        KeyShortcuts => "aria-keyshortcuts",
+        Label => "aria-label",
+        LabelledBy => "aria-labelledby",
+        Level => "aria-level",
+        Live => "aria-live",
+        Modal => "aria-modal",
+        Multiline => "aria-multiline",
+        MultiSelectable => "aria-multiselectable",
+        Orientation => "aria-orientation",
+        Owns => "aria-owns",
+        Placeholder => "aria-placeholder",
+        PosInSet => "aria-posinset",
+        Pressed => "aria-pressed",
+        ReadOnly => "aria-readonly",
+        Relevant => "aria-relevant",
+        Required => "aria-required",
+        RoleDescription => "aria-roledescription",
+        RowCount => "aria-rowcount",
+        RowIndex => "aria-rowindex",
+        RowIndexText => "aria-rowindextext",
+        RowSpan => "aria-rowspan",
+        Selected => "aria-selected",
+        SetSize => "aria-setsize",
+        Sort => "aria-sort",
+        ValueMax => "aria-valuemax",
+        ValueMin => "aria-valuemin",
+        ValueNow => "aria-valuenow",
+        ValueText => "aria-valuetext",
+    }
+}
+
+/// A value of the `role` attribute or of an `aria-*` attribute.
+///
+/// `None` leaves the attribute out.
+pub trait IntoAriaValue {
+    /// Converts the value to the value of the attribute.
+    fn into_aria_value(self) -> Option<AttrValue>;
+}
+
+impl IntoAriaValue for AriaRole {
+    fn into_aria_value(self) -> Option<AttrValue> {
+        Some(AttrValue::Static(self.as_str()))
+    }
+}
+
+impl IntoAriaValue for bool {
+    fn into_aria_value(self) -> Option<AttrValue> {
+        Some(AttrValue::Static(if self { "true" } else { "false" }))
+    }
+}
+
+impl IntoAriaValue for &'static str {
+    fn into_aria_value(self) -> Option<AttrValue> {
+        Some(AttrValue::Static(self))
+    }
+}
+
+impl IntoAriaValue for String {
+    fn into_aria_value(self) -> Option<AttrValue> {
+        Some(AttrValue::from(self))
+    }
+}
+
+impl IntoAriaValue for Rc<str> {
+    fn into_aria_value(self) -> Option<AttrValue> {
+        Some(AttrValue::from(self))
+    }
+}
+
+impl IntoAriaValue for Cow<'static, str> {
+    fn into_aria_value(self) -> Option<AttrValue> {
+        Some(AttrValue::from(self))
+    }
+}
+
+impl IntoAriaValue for AttrValue {
+    fn into_aria_value(self) -> Option<AttrValue> {
+        Some(self)
+    }
+}
+
+impl<T: IntoAriaValue> IntoAriaValue for Option<T> {
+    fn into_aria_value(self) -> Option<AttrValue> {
+        self.and_then(IntoAriaValue::into_aria_value)
+    }
+}
+
+macro_rules! impl_into_aria_value_for_numbers {
+    ($($ty:ty)*) => {$(
+        impl IntoAriaValue for $ty {
+            fn into_aria_value(self) -> Option<AttrValue> {
+                Some(AttrValue::from(self.to_string()))
+            }
+        }
+    )*};
+}
+
+impl_into_aria_value_for_numbers!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize f32 f64);
+
+impl VTag {
+    /// Sets the `role` attribute.
+    pub fn set_role(&mut self, role: AriaRole) {
+        self.__macro_push_attr("role", role.as_str());
+    }
+
+    /// Sets the ARIA attribute `aria`. A `None` value removes it.
+    ///
+    /// ```
+    /// use yew::virtual_dom::aria::Aria;
+    /// use yew::virtual_dom::VTag;
+    ///
+    /// let mut tag = VTag::new("button");
+    /// tag.set_aria(Aria::Expanded, false);
+    /// tag.set_aria(Aria::Controls, "menu");
+    /// ```
+    pub fn set_aria(&mut self, aria: Aria, value: impl IntoAriaValue) {
+        self.set_attribute(aria.as_str(), value.into_aria_value());
+    }
+
+    /// Sets the `data-{name}` attribute. A `None` value removes it.
+    pub fn set_data(
+        &mut self,
+        name: &str,
+        value: impl crate::html::IntoPropValue<Option<AttrValue>>,
+    ) {
+        self.set_attribute(format!("data-{name}"), value.into_prop_value());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typed_aria_attributes() {
+        let mut tag = VTag::new("button");
+        tag.set_role(AriaRole::MenuItemCheckbox);
+        tag.set_aria(Aria::Checked, true);
+        tag.set_aria(Aria::Label, "Bold");
+        tag.set_aria(Aria::PosInSet, 2);
+        tag.set_aria(Aria::Label, None::<AttrValue>);
+        tag.set_data("shortcut", "ctrl+b");
+
+        let attributes = tag.attributes.iter().collect::<Vec<_>>();
+        assert_eq!(
+            attributes,
+            [
+                ("role", "menuitemcheckbox"),
+                ("aria-checked", "true"),
+                ("aria-posinset", "2"),
+                ("data-shortcut", "ctrl+b"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! This module contains the implementation of interned strings.
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::rc::Rc;
+
+use crate::html::{Classes, ImplicitClone, IntoPropValue};
+use crate::virtual_dom::{AttrValue, Key};
+
+thread_local! {
+    static ATOMS: RefCell<HashSet<Rc<str>>> = RefCell::default();
+}
+
+/// An interned string, for attribute names, values and classes repeated across many nodes.
+///
+/// All the atoms with the same content share a single allocation, so comparing two atoms, or two
+/// [`AttrValue`]s made from atoms when diffing attributes, is a pointer comparison. Creating an
+/// atom looks the string up in a thread local table, so it pays off for strings that are created
+/// once and rendered many times.
+///
+/// ```
+/// use yew::virtual_dom::Atom;
+///
+/// let a = Atom::new("btn btn-primary");
+/// let b = Atom::new(&String::from("btn btn-primary"));
+/// assert!(a.ptr_eq(&b));
+/// ```
+#[derive(Clone)]
+pub struct Atom {
+    inner: Rc<str>,
+}
+
+impl Atom {
+    /// Returns the atom for `s`, interning it if it is the first one.
+    pub fn new(s: &str) -> Self {
+        ATOMS.with(|atoms| {
+            let mut atoms = atoms.borrow_mut();
+            let inner = match atoms.get(s) {
+                Some(inner) => inner.clone(),
+                None => {
+                    let inner: Rc<str> = Rc::from(s);
+                    atoms.insert(inner.clone());
+                    inner
+                }
+            };
+            Self { inner }
+        })
+    }
+
+    /// Extracts a string slice containing the entire atom.
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    /// Returns `true` if both atoms share the same allocation, which is the case for all the atoms
+    /// with the same content.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Frees the interned strings that are not used by any atom or value anymore.
+    ///
+    /// Interned strings are otherwise kept for the lifetime of the thread.
+    pub fn release_unused() {
+        ATOMS.with(|atoms| {
+            atoms
+                .borrow_mut()
+                .retain(|inner| Rc::strong_count(inner) > 1)
+        });
+    }
+}
+
+impl ImplicitClone for Atom {}
+
+impl PartialEq for Atom {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other)
+    }
+}
+
+impl Eq for Atom {}
+
+impl Hash for Atom {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.inner).cast::<u8>().hash(state)
+    }
+}
+
+impl Deref for Atom {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl AsRef<str> for Atom {
+    fn as_ref(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl Display for Atom {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.inner, f)
+    }
+}
+
+impl fmt::Debug for Atom {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl From<&'_ str> for Atom {
+    fn from(s: &'_ str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<String> for Atom {
+    fn from(s: String) -> Self {
+        Self::new(&s)
+    }
+}
+
+impl From<Atom> for AttrValue {
+    fn from(atom: Atom) -> Self {
+        AttrValue::Rc(atom.inner)
+    }
+}
+
+impl From<&Atom> for AttrValue {
+    fn from(atom: &Atom) -> Self {
+        AttrValue::Rc(atom.inner.clone())
+    }
+}
+
+impl From<Atom> for Key {
+    fn from(atom: Atom) -> Self {
+        Key::from(atom.inner)
+    }
+}
+
+impl From<Atom> for Classes {
+    fn from(atom: Atom) -> Self {
+        AttrValue::from(atom).into()
+    }
+}
+
+impl From<&Atom> for Classes {
+    fn from(atom: &Atom) -> Self {
+        AttrValue::from(atom).into()
+    }
+}
+
+impl IntoPropValue<AttrValue> for Atom {
+    #[inline]
+    fn into_prop_value(self) -> AttrValue {
+        self.into()
+    }
+}
+
+impl IntoPropValue<Option<AttrValue>> for Atom {
+    #[inline]
+    fn into_prop_value(self) -> Option<AttrValue> {
+        Some(self.into())
+    }
+}
+
+impl IntoPropValue<AttrValue> for &Atom {
+    #[inline]
+    fn into_prop_value(self) -> AttrValue {
+        self.into()
+    }
+}
+
+impl IntoPropValue<Option<AttrValue>> for &Atom {
+    #[inline]
+    fn into_prop_value(self) -> Option<AttrValue> {
+        Some(self.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn atoms_share_allocation() {
+        let a = Atom::new("container");
+        let b = Atom::from(String::from("container"));
+        let c = Atom::new("item");
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a.as_str(), "container");
+
+        let (a, b) = (AttrValue::from(a), AttrValue::from(&b));
+        assert!(std::ptr::eq(a.as_str(), b.as_str()));
+    }
+
+    #[test]
+    fn release_unused_atoms() {
+        let kept = Atom::new("kept");
+        let ptr = kept.as_ptr();
+        drop(Atom::new("released"));
+        Atom::release_unused();
+
+        assert_eq!(Atom::new("kept").as_ptr(), ptr);
+        ATOMS.with(|atoms| assert!(!atoms.borrow().contains("released")));
+    }
+}
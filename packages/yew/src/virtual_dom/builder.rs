@@ -0,0 +1,235 @@
+//! This module contains builders for trees of virtual nodes whose structure is only known at
+//! runtime, like the ones of code generators or content management systems.
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew::virtual_dom::{El, Fragment};
+//!
+//! let items = ["Home", "About"];
+//! let onclick = yew::html::onclick::Wrapper::new(Callback::from(|_: MouseEvent| ()));
+//! let menu: Html = El::new("ul")
+//!     .class("menu")
+//!     .attr("aria-label", "Main menu")
+//!     .children(items.iter().map(|item| {
+//!         El::new("li")
+//!             .key(*item)
+//!             .child(El::new("a").attr("href", format!("/{item}")).text(*item))
+//!     }))
+//!     .child(
+//!         Fragment::new()
+//!             .child(El::new("hr"))
+//!             .child(El::new("button").on(onclick).text("Close")),
+//!     )
+//!     .into();
+//! ```
+
+use std::rc::Rc;
+
+use wasm_bindgen::JsValue;
+
+use super::{AttrValue, Key, Listener, VList, VNode, VTag, VText};
+use crate::html::{Classes, IntoPropValue, NodeRef};
+
+/// Builds a [`VTag`].
+#[derive(Debug, Clone)]
+#[must_use = "the element is only rendered once converted to `Html`"]
+pub struct El {
+    tag: VTag,
+    children: Vec<VNode>,
+}
+
+impl El {
+    /// Starts building an element with the given tag name.
+    pub fn new(tag: impl Into<AttrValue>) -> Self {
+        Self {
+            tag: VTag::new(tag.into()),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets an attribute. A `None` value leaves it out.
+    pub fn attr(
+        mut self,
+        key: impl Into<AttrValue>,
+        value: impl IntoPropValue<Option<AttrValue>>,
+    ) -> Self {
+        let key = key.into();
+        let value = value.into_prop_value();
+        match &*key {
+            // these are set as properties, like in `html!`
+            "value" => self.tag.set_value(value),
+            "checked" => self.tag.set_checked(value.is_some_and(|v| v != "false")),
+            _ => self.tag.set_attribute(key, value),
+        }
+        self
+    }
+
+    /// Sets the classes of the element. Empty classes leave the attribute out.
+    pub fn class(self, classes: impl Into<Classes>) -> Self {
+        let classes: Classes = classes.into();
+        self.attr("class", classes)
+    }
+
+    /// Sets a property of the element, with [`js_sys::Reflect`].
+    pub fn property(mut self, key: &'static str, value: impl Into<JsValue>) -> Self {
+        self.tag.add_property(key, value);
+        self
+    }
+
+    /// Adds an event listener, like `html::onclick::Wrapper::new(callback)`.
+    pub fn on(mut self, listener: impl Listener + 'static) -> Self {
+        self.tag.add_listener(Rc::new(listener));
+        self
+    }
+
+    /// Sets the key of the element.
+    pub fn key(mut self, key: impl Into<Key>) -> Self {
+        self.tag.key = Some(key.into());
+        self
+    }
+
+    /// Sets the [`NodeRef`] of the element.
+    pub fn node_ref(mut self, node_ref: NodeRef) -> Self {
+        self.tag.node_ref = node_ref;
+        self
+    }
+
+    /// Adds a child. It is ignored by elements that can't have children, like `<input>`.
+    pub fn child(mut self, child: impl Into<VNode>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Adds several children.
+    pub fn children<T: Into<VNode>>(mut self, children: impl IntoIterator<Item = T>) -> Self {
+        self.children.extend(children.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds a text child.
+    pub fn text(self, text: impl Into<AttrValue>) -> Self {
+        self.child(VText::new(text))
+    }
+
+    /// Returns the built element.
+    pub fn build(mut self) -> VTag {
+        // Like `html!`, a single child is set as it is, and several ones in a list.
+        match self.children.len() {
+            0 => {}
+            1 => {
+                if let Some(children) = self.tag.children_mut() {
+                    *children = self.children.remove(0);
+                }
+            }
+            _ => self.tag.add_children(self.children),
+        }
+        self.tag
+    }
+}
+
+impl From<El> for VTag {
+    fn from(el: El) -> Self {
+        el.build()
+    }
+}
+
+impl From<El> for VNode {
+    fn from(el: El) -> Self {
+        el.build().into()
+    }
+}
+
+/// Builds a [`VList`], a list of nodes without an element around them.
+#[derive(Debug, Clone, Default)]
+#[must_use = "the fragment is only rendered once converted to `Html`"]
+pub struct Fragment {
+    list: VList,
+}
+
+impl Fragment {
+    /// Starts building an empty fragment.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the key of the fragment.
+    pub fn key(mut self, key: impl Into<Key>) -> Self {
+        self.list.key = Some(key.into());
+        self
+    }
+
+    /// Adds a child.
+    pub fn child(mut self, child: impl Into<VNode>) -> Self {
+        self.list.add_child(child.into());
+        self
+    }
+
+    /// Adds several children.
+    pub fn children<T: Into<VNode>>(mut self, children: impl IntoIterator<Item = T>) -> Self {
+        self.list.add_children(children.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns the built list.
+    pub fn build(self) -> VList {
+        self.list
+    }
+}
+
+impl From<Fragment> for VList {
+    fn from(fragment: Fragment) -> Self {
+        fragment.list
+    }
+}
+
+impl From<Fragment> for VNode {
+    fn from(fragment: Fragment) -> Self {
+        fragment.list.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html;
+
+    #[test]
+    fn builds_the_same_tree_as_the_macro() {
+        let built: VNode = El::new("div")
+            .class("card")
+            .attr("id", "main")
+            .attr("title", None::<AttrValue>)
+            .child(El::new("input").attr("value", "a"))
+            .children(["x", "y"].map(|key| El::new("span").key(key).text(key)))
+            .into();
+        let expected = html! {
+            <div class="card" id="main">
+                <input value="a" />
+                <span key="x">{ "x" }</span>
+                <span key="y">{ "y" }</span>
+            </div>
+        };
+
+        let (VNode::VTag(built), VNode::VTag(expected)) = (built, expected) else {
+            panic!("expected tags");
+        };
+        let attributes = |tag: &VTag| {
+            let mut attributes = tag
+                .attributes
+                .iter()
+                .map(|(k, v)| (k.to_owned(), v.to_owned()))
+                .collect::<Vec<_>>();
+            attributes.sort();
+            attributes
+        };
+        assert_eq!(attributes(&built), attributes(&expected));
+        assert_eq!(built.children(), expected.children());
+    }
+
+    #[test]
+    fn fragments_keep_their_key() {
+        let list = Fragment::new().key("list").child(El::new("br")).build();
+        assert_eq!(list.key, Some(Key::from("list")));
+        assert_eq!(list.len(), 1);
+    }
+}
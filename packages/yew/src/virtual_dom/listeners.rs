@@ -14,19 +14,101 @@ pub trait Listener {
     /// Makes the event listener passive. See
     /// [addEventListener](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener).
     fn passive(&self) -> bool;
+
+    /// Handles the event in the capture phase, before the listeners of the descendants of the
+    /// element.
+    fn capture(&self) -> bool {
+        false
+    }
+
+    /// Handles a single event while its element is mounted.
+    fn once(&self) -> bool {
+        false
+    }
+
+    /// Attaches the listener to its element instead of delegating it to the root of the
+    /// application.
+    fn direct(&self) -> bool {
+        false
+    }
 }
 
 impl std::fmt::Debug for dyn Listener {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Listener {{ kind: {}, passive: {:?} }}",
+            "Listener {{ kind: {}, passive: {:?}, capture: {:?}, once: {:?}, direct: {:?} }}",
             self.kind().as_ref(),
             self.passive(),
+            self.capture(),
+            self.once(),
+            self.direct(),
         )
     }
 }
 
+/// Options of a listener, given after its name in `html!`: `ontouchmove:passive={..}`.
+///
+/// Several options can be combined, like `onclick:capture:once={..}`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ListenerOptions {
+    /// The listener never calls `prevent_default`, so the browser can scroll without waiting for
+    /// it. The `onscroll`, `ontouchstart` and `ontouchmove` listeners are always passive.
+    pub passive: bool,
+    /// The listener handles the event in the capture phase, before the listeners of the
+    /// descendants of its element. As events are delegated, capture listeners of the ancestors of
+    /// the target only run for events that bubble, unless the listener is `direct`.
+    pub capture: bool,
+    /// The listener handles a single event while its element is mounted, even if later renders
+    /// pass it a new callback.
+    pub once: bool,
+    /// The listener is attached to its element, instead of being delegated to the root of the
+    /// application. It sees the events that don't reach the root, like the ones of a shadow DOM
+    /// or of a portal rendered outside of the application.
+    pub direct: bool,
+}
+
+/// A listener with [`ListenerOptions`].
+struct WithOptions {
+    listener: Rc<dyn Listener>,
+    options: ListenerOptions,
+}
+
+impl Listener for WithOptions {
+    fn kind(&self) -> ListenerKind {
+        self.listener.kind()
+    }
+
+    fn handle(&self, event: web_sys::Event) {
+        self.listener.handle(event);
+    }
+
+    fn passive(&self) -> bool {
+        self.options.passive || self.listener.passive()
+    }
+
+    fn capture(&self) -> bool {
+        self.options.capture || self.listener.capture()
+    }
+
+    fn once(&self) -> bool {
+        self.options.once || self.listener.once()
+    }
+
+    fn direct(&self) -> bool {
+        self.options.direct || self.listener.direct()
+    }
+}
+
+#[doc(hidden)]
+pub fn __with_options(
+    listener: Option<Rc<dyn Listener>>,
+    options: ListenerOptions,
+) -> Option<Rc<dyn Listener>> {
+    let listener = listener?;
+    Some(Rc::new(WithOptions { listener, options }))
+}
+
 macro_rules! gen_listener_kinds {
     ($($kind:ident)*) => {
         /// Supported kinds of DOM event listeners
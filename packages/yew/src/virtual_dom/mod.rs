@@ -1,12 +1,22 @@
 //! This module contains Yew's implementation of a reactive virtual DOM.
 
+pub mod aria;
+#[doc(hidden)]
+pub mod atom;
+#[doc(hidden)]
+pub mod builder;
 #[doc(hidden)]
 pub mod key;
 #[doc(hidden)]
 pub mod listeners;
+pub(crate) mod recycle;
+#[doc(hidden)]
+pub mod sanitize;
 #[doc(hidden)]
 pub mod vcomp;
 #[doc(hidden)]
+pub mod visit;
+#[doc(hidden)]
 pub mod vlist;
 #[doc(hidden)]
 pub mod vnode;
@@ -27,13 +37,21 @@ use std::rc::Rc;
 use indexmap::IndexMap;
 use wasm_bindgen::JsValue;
 
+#[doc(inline)]
+pub use self::atom::Atom;
+#[doc(inline)]
+pub use self::builder::{El, Fragment};
 #[doc(inline)]
 pub use self::key::Key;
 #[doc(inline)]
 pub use self::listeners::*;
 #[doc(inline)]
+pub use self::sanitize::{EscapeHtml, SanitizePolicy};
+#[doc(inline)]
 pub use self::vcomp::{VChild, VComp};
 #[doc(inline)]
+pub use self::visit::{Visit, VisitMut};
+#[doc(inline)]
 pub use self::vlist::VList;
 #[doc(inline)]
 pub use self::vnode::VNode;
@@ -44,7 +62,7 @@ pub use self::vraw::VRaw;
 #[doc(inline)]
 pub use self::vsuspense::VSuspense;
 #[doc(inline)]
-pub use self::vtag::VTag;
+pub use self::vtag::{Spread, StaticNodeId, TagName, VTag};
 #[doc(inline)]
 pub use self::vtext::VText;
 
@@ -68,6 +86,9 @@ mod feat_ssr_hydration {
         Component(ComponentName),
         Raw,
         Suspense,
+        /// The fallback of a `<Suspense>` whose children were not ready by the deadline of the
+        /// server side rendering.
+        TimedOut,
     }
 
     impl Collectable {
@@ -93,6 +114,7 @@ mod feat_ssr_hydration {
                 Self::Component(_) => "<[",
                 Self::Raw => "<#",
                 Self::Suspense => "<?",
+                Self::TimedOut => "<%",
             }
         }
 
@@ -101,6 +123,7 @@ mod feat_ssr_hydration {
                 Self::Component(_) => "</[",
                 Self::Raw => "</#",
                 Self::Suspense => "</?",
+                Self::TimedOut => "</%",
             }
         }
 
@@ -109,6 +132,7 @@ mod feat_ssr_hydration {
                 Self::Component(_) => "]>",
                 Self::Raw => ">",
                 Self::Suspense => ">",
+                Self::TimedOut => ">",
             }
         }
 
@@ -121,6 +145,7 @@ mod feat_ssr_hydration {
                 Self::Component(_) => "Component".into(),
                 Self::Raw => "Raw".into(),
                 Self::Suspense => "Suspense".into(),
+                Self::TimedOut => "TimedOut".into(),
             }
         }
     }
@@ -148,6 +173,7 @@ mod feat_ssr {
                 }
                 Self::Raw => {}
                 Self::Suspense => {}
+                Self::TimedOut => {}
             }
 
             let _ = w.write_str(self.end_mark());
@@ -165,6 +191,7 @@ mod feat_ssr {
                 }
                 Self::Raw => {}
                 Self::Suspense => {}
+                Self::TimedOut => {}
             }
 
             let _ = w.write_str(self.end_mark());
@@ -236,12 +263,14 @@ impl Attributes {
             Self::Dynamic { keys, values } => {
                 Box::new(keys.iter().zip(values.iter()).filter_map(|(k, v)| match v {
                     Some(AttributeOrProperty::Attribute(v)) => Some((*k, v.as_ref())),
+                    Some(AttributeOrProperty::Static(v)) => Some((*k, *v)),
                     _ => None,
                 }))
             }
             Self::IndexMap(m) => Box::new(m.iter().filter_map(|(k, v)| match v {
                 AttributeOrProperty::Attribute(v) => Some((k.as_ref(), v.as_ref())),
-                _ => None,
+                AttributeOrProperty::Static(v) => Some((k.as_ref(), *v)),
+                AttributeOrProperty::Property(_) => None,
             })),
         }
     }
@@ -316,3 +345,24 @@ impl Default for Attributes {
         Self::Static(&[])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html;
+
+    #[test]
+    fn dynamic_attributes_iterate_literals() {
+        let title = None::<AttrValue>;
+        let node = html! { <img src="/a.png" {title} alt="" /> };
+        let VNode::VTag(tag) = node else {
+            panic!("expected a tag");
+        };
+
+        assert!(matches!(tag.attributes, Attributes::Dynamic { .. }));
+        assert_eq!(
+            tag.attributes.iter().collect::<Vec<_>>(),
+            [("src", "/a.png"), ("alt", "")]
+        );
+    }
+}
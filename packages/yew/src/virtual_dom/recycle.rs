@@ -0,0 +1,162 @@
+//! This module contains the recycling of the allocations of virtual nodes.
+//!
+//! Every render of a component builds a new tree of [`VTag`]s and [`VList`]s, which is consumed
+//! by the reconciliation right after. A component keeps the allocations of the nodes consumed by
+//! its last reconciliation in a [`VNodePool`], and hands them out again to the nodes created by
+//! its next render.
+
+use std::cell::RefCell;
+#[cfg(feature = "csr")]
+use std::mem;
+use std::mem::MaybeUninit;
+use std::rc::Rc;
+
+#[cfg(feature = "csr")]
+use crate::utils::RcExt;
+use crate::virtual_dom::{VList, VTag};
+
+/// The maximum number of allocations kept for each kind of node.
+#[cfg(feature = "csr")]
+const MAX_POOLED: usize = 1024;
+
+thread_local! {
+    /// The pool of the component being rendered, if any.
+    static ACTIVE: RefCell<Option<VNodePool>> = const { RefCell::new(None) };
+}
+
+/// Unused allocations of virtual nodes, owned by a component.
+#[derive(Default)]
+pub(crate) struct VNodePool {
+    tags: Vec<Rc<MaybeUninit<VTag>>>,
+    lists: Vec<Rc<MaybeUninit<VList>>>,
+}
+
+/// A node that can be allocated from a [`VNodePool`].
+pub(crate) trait Recycle: Clone + Sized {
+    fn slots(pool: &mut VNodePool) -> &mut Vec<Rc<MaybeUninit<Self>>>;
+}
+
+impl Recycle for VTag {
+    fn slots(pool: &mut VNodePool) -> &mut Vec<Rc<MaybeUninit<Self>>> {
+        &mut pool.tags
+    }
+}
+
+impl Recycle for VList {
+    fn slots(pool: &mut VNodePool) -> &mut Vec<Rc<MaybeUninit<Self>>> {
+        &mut pool.lists
+    }
+}
+
+/// Makes `pool` the pool of the nodes created and consumed while running `f`.
+#[cfg(feature = "csr")]
+pub(crate) fn with_pool<R>(pool: &mut VNodePool, f: impl FnOnce() -> R) -> R {
+    struct Restore<'a> {
+        pool: &'a mut VNodePool,
+        previous: Option<VNodePool>,
+    }
+
+    impl Drop for Restore<'_> {
+        fn drop(&mut self) {
+            let previous = self.previous.take();
+            *self.pool = ACTIVE
+                .with(|active| mem::replace(&mut *active.borrow_mut(), previous))
+                .unwrap_or_default();
+        }
+    }
+
+    let previous = ACTIVE.with(|active| active.borrow_mut().replace(mem::take(pool)));
+    let _restore = Restore { pool, previous };
+    f()
+}
+
+/// Moves `value` into a new [`Rc`], reusing an allocation of the active pool if there is one.
+pub(crate) fn alloc<T: Recycle>(value: T) -> Rc<T> {
+    let slot = ACTIVE.with(|active| {
+        active
+            .borrow_mut()
+            .as_mut()
+            .and_then(|pool| T::slots(pool).pop())
+    });
+    match slot {
+        Some(mut slot) => {
+            Rc::get_mut(&mut slot)
+                .expect("pooled allocations are not shared")
+                .write(value);
+            // SAFETY: the value was just initialized, and `MaybeUninit<T>` has the same size and
+            // alignment as `T`.
+            unsafe { Rc::from_raw(Rc::into_raw(slot).cast::<T>()) }
+        }
+        None => Rc::new(value),
+    }
+}
+
+/// Takes the value out of `rc`, cloning it if it is shared, and gives its allocation back to the
+/// active pool.
+#[cfg(feature = "csr")]
+pub(crate) fn take<T: Recycle>(mut rc: Rc<T>) -> T {
+    if Rc::get_mut(&mut rc).is_none() {
+        return (*rc).clone();
+    }
+    ACTIVE.with(|active| {
+        let mut active = active.borrow_mut();
+        let slots = match active.as_mut().map(T::slots) {
+            Some(slots) if slots.len() < MAX_POOLED => slots,
+            _ => return RcExt::unwrap_or_clone(rc),
+        };
+        let raw = Rc::into_raw(rc);
+        // SAFETY: the Rc is neither shared nor weakly referenced, so the value can be moved out.
+        // The allocation is then only used as a `MaybeUninit<T>`, which has the same size and
+        // alignment as `T` and doesn't drop its content.
+        unsafe {
+            let value = raw.read();
+            slots.push(Rc::from_raw(raw.cast::<MaybeUninit<T>>()));
+            value
+        }
+    })
+}
+
+#[cfg(feature = "csr")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::virtual_dom::VNode;
+
+    #[test]
+    fn reuses_allocations_of_consumed_nodes() {
+        let mut pool = VNodePool::default();
+
+        let first = with_pool(&mut pool, || {
+            let VNode::VTag(tag) = VNode::from(VTag::new("div")) else {
+                unreachable!()
+            };
+            let ptr = Rc::as_ptr(&tag);
+            assert_eq!(take(tag).tag(), "div");
+            ptr
+        });
+        assert_eq!(pool.tags.len(), 1);
+
+        with_pool(&mut pool, || {
+            let VNode::VTag(tag) = VNode::from(VTag::new("span")) else {
+                unreachable!()
+            };
+            assert_eq!(Rc::as_ptr(&tag), first);
+            assert_eq!(tag.tag(), "span");
+
+            // Shared nodes are cloned and their allocation is left alone
+            let shared = tag.clone();
+            assert_eq!(take(tag).tag(), "span");
+            assert_eq!(shared.tag(), "span");
+        });
+        assert!(pool.tags.is_empty());
+    }
+
+    #[test]
+    fn allocates_without_pool() {
+        let VNode::VList(list) = VNode::from(VList::new()) else {
+            unreachable!()
+        };
+        take(list);
+        ACTIVE.with(|active| assert!(active.borrow().is_none()));
+    }
+}
@@ -0,0 +1,107 @@
+//! This module contains the policies used to sanitize raw HTML.
+
+use std::borrow::Cow;
+
+use crate::AttrValue;
+
+/// Sanitizes a string of HTML before it is inserted with [`VNode::from_html_sanitized`].
+///
+/// Yew doesn't parse HTML, so a policy usually wraps an allowlist-based sanitizer, like the one of
+/// the [ammonia](https://docs.rs/ammonia) crate. Closures taking and returning a string are
+/// policies too.
+///
+/// [`VNode::from_html_sanitized`]: crate::virtual_dom::VNode::from_html_sanitized
+///
+/// # Example
+///
+/// ```rust
+/// use yew::virtual_dom::SanitizePolicy;
+/// use yew::{AttrValue, Html};
+///
+/// /// Only keeps the text, removing every tag.
+/// struct TextOnly;
+///
+/// impl SanitizePolicy for TextOnly {
+///     fn sanitize(&self, html: &str) -> AttrValue {
+///         let mut text = String::new();
+///         let mut in_tag = false;
+///         for c in html.chars() {
+///             match c {
+///                 '<' => in_tag = true,
+///                 '>' => in_tag = false,
+///                 c if !in_tag => text.push(c),
+///                 _ => {}
+///             }
+///         }
+///         yew::virtual_dom::EscapeHtml.sanitize(&text)
+///     }
+/// }
+///
+/// let comment = Html::from_html_sanitized(&TextOnly, "<b>Hello</b> <script>x()</script>");
+/// ```
+pub trait SanitizePolicy {
+    /// Returns the HTML that is safe to insert into the document.
+    fn sanitize(&self, html: &str) -> AttrValue;
+}
+
+impl<F> SanitizePolicy for F
+where
+    F: Fn(&str) -> String,
+{
+    fn sanitize(&self, html: &str) -> AttrValue {
+        self(html).into()
+    }
+}
+
+/// A policy escaping the HTML, so that it is displayed as text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EscapeHtml;
+
+impl SanitizePolicy for EscapeHtml {
+    fn sanitize(&self, html: &str) -> AttrValue {
+        match escape(html) {
+            Cow::Borrowed(_) => AttrValue::from(html.to_owned()),
+            Cow::Owned(escaped) => escaped.into(),
+        }
+    }
+}
+
+fn escape(html: &str) -> Cow<'_, str> {
+    if !html.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(html);
+    }
+
+    let mut escaped = String::with_capacity(html.len() + 16);
+    for c in html.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::virtual_dom::{VNode, VRaw};
+
+    #[test]
+    fn sanitizes_before_creating_the_node() {
+        let node = VNode::from_html_sanitized(&EscapeHtml, "<img src=x onerror='alert(1)'>");
+        assert_eq!(
+            node,
+            VNode::VRaw(VRaw::from(AttrValue::from(
+                "&lt;img src=x onerror=&#39;alert(1)&#39;&gt;"
+            )))
+        );
+
+        let strip_scripts = |html: &str| html.replace("<script>", "").replace("</script>", "");
+        let node = VNode::from_html_sanitized(&strip_scripts, "<b>hi</b><script>x</script>");
+        assert_eq!(node, VNode::VRaw(VRaw::from(AttrValue::from("<b>hi</b>x"))));
+    }
+}
@@ -57,6 +57,7 @@ pub(crate) trait Mountable {
 
     fn mountable_eq(&self, rhs: &dyn Mountable) -> bool;
     fn as_any(&self) -> &dyn Any;
+    fn type_name(&self) -> &'static str;
 
     #[cfg(feature = "csr")]
     fn mount(
@@ -113,6 +114,10 @@ impl<COMP: BaseComponent> Mountable for PropsWrapper<COMP> {
         self
     }
 
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<COMP>()
+    }
+
     fn mountable_eq(&self, rhs: &dyn Mountable) -> bool {
         rhs.as_any()
             .downcast_ref::<Self>()
@@ -214,6 +219,26 @@ where
             key,
         }
     }
+
+    /// Returns the key of the child.
+    pub fn key(&self) -> Option<&Key> {
+        self.key.as_ref()
+    }
+}
+
+impl<COMP> VChild<COMP>
+where
+    COMP: BaseComponent,
+    COMP::Properties: Clone,
+{
+    /// Returns a copy of the child, with the props modified by `f`.
+    ///
+    /// The props are only cloned if they are shared with other copies of the child.
+    pub fn with_props(&self, f: impl FnOnce(&mut COMP::Properties)) -> Self {
+        let mut child = self.clone();
+        f(Rc::make_mut(&mut child.props));
+        child
+    }
 }
 
 impl<COMP> From<VChild<COMP>> for VComp
@@ -238,6 +263,11 @@ impl VComp {
             _marker: 0,
         }
     }
+
+    /// Returns the full type name of the component, like `my_app::Counter`.
+    pub fn type_name(&self) -> &'static str {
+        self.mountable.type_name()
+    }
 }
 
 impl PartialEq for VComp {
@@ -0,0 +1,209 @@
+//! This module contains visitors walking trees of virtual nodes.
+//!
+//! Trees are walked in document order, a tag being visited before its children. Both the children
+//! and the fallback of a suspense are walked. Components are not entered: they render their own
+//! tree, which can be walked in their `view`.
+
+use std::rc::Rc;
+
+use super::{VComp, VNode, VRaw, VTag, VText};
+
+/// Visits the nodes of a tree, see [`VNode::visit`].
+///
+/// # Example
+///
+/// Collect the headings of a page to render its table of contents:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew::virtual_dom::{VTag, Visit};
+///
+/// #[derive(Default)]
+/// struct Headings(Vec<String>);
+///
+/// impl Visit for Headings {
+///     fn visit_tag(&mut self, tag: &VTag) {
+///         if matches!(tag.tag(), "h1" | "h2" | "h3") {
+///             if let Some(id) = tag.attributes.iter().find(|(k, _)| *k == "id") {
+///                 self.0.push(id.1.to_owned());
+///             }
+///         }
+///     }
+/// }
+///
+/// let page = html! {
+///     <article>
+///         <h1 id="intro">{ "Introduction" }</h1>
+///         <h2 id="usage">{ "Usage" }</h2>
+///     </article>
+/// };
+/// let mut headings = Headings::default();
+/// page.visit(&mut headings);
+/// assert_eq!(headings.0, ["intro", "usage"]);
+/// ```
+#[allow(unused_variables)]
+pub trait Visit {
+    /// Visits an element.
+    fn visit_tag(&mut self, tag: &VTag) {}
+
+    /// Visits a text node.
+    fn visit_text(&mut self, text: &VText) {}
+
+    /// Visits a raw HTML string.
+    fn visit_raw(&mut self, raw: &VRaw) {}
+
+    /// Visits a component.
+    fn visit_comp(&mut self, comp: &VComp) {}
+}
+
+/// Visits and rewrites the nodes of a tree, see [`VNode::visit_mut`].
+///
+/// # Example
+///
+/// Open external links without giving the opened page access to this one:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew::virtual_dom::{VTag, VisitMut};
+///
+/// struct NoOpener;
+///
+/// impl VisitMut for NoOpener {
+///     fn visit_tag_mut(&mut self, tag: &mut VTag) {
+///         let external = tag.tag() == "a"
+///             && tag
+///                 .attributes
+///                 .iter()
+///                 .any(|(k, v)| k == "href" && v.starts_with("https://"));
+///         if external {
+///             tag.add_attribute("rel", "noopener noreferrer");
+///         }
+///     }
+/// }
+///
+/// let mut links = html! {
+///     <nav>
+///         <a href="/home">{ "Home" }</a>
+///         <a href="https://yew.rs">{ "Yew" }</a>
+///     </nav>
+/// };
+/// links.visit_mut(&mut NoOpener);
+/// ```
+#[allow(unused_variables)]
+pub trait VisitMut {
+    /// Visits an element. Its children are visited afterwards, so they can be rewritten here too.
+    fn visit_tag_mut(&mut self, tag: &mut VTag) {}
+
+    /// Visits a text node.
+    fn visit_text_mut(&mut self, text: &mut VText) {}
+
+    /// Visits a raw HTML string.
+    fn visit_raw_mut(&mut self, raw: &mut VRaw) {}
+}
+
+impl VNode {
+    /// Walks the tree of this node with `visitor`.
+    pub fn visit<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            VNode::VTag(tag) => {
+                visitor.visit_tag(tag);
+                if let Some(children) = tag.children() {
+                    children.visit(visitor);
+                }
+            }
+            VNode::VText(text) => visitor.visit_text(text),
+            VNode::VComp(comp) => visitor.visit_comp(comp),
+            VNode::VList(list) => list.iter().for_each(|child| child.visit(visitor)),
+            VNode::VPortal(portal) => portal.node.visit(visitor),
+            VNode::VRef(_) => {}
+            VNode::VSuspense(suspense) => {
+                suspense.children.visit(visitor);
+                suspense.fallback.visit(visitor);
+            }
+            VNode::VRaw(raw) => visitor.visit_raw(raw),
+        }
+    }
+
+    /// Walks the tree of this node with `visitor`, which can rewrite it.
+    ///
+    /// The nodes shared with other trees are cloned before being visited.
+    pub fn visit_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        match self {
+            VNode::VTag(tag) => {
+                let tag = Rc::make_mut(tag);
+                visitor.visit_tag_mut(tag);
+                if let Some(children) = tag.children_mut() {
+                    children.visit_mut(visitor);
+                }
+            }
+            VNode::VText(text) => visitor.visit_text_mut(text),
+            VNode::VComp(_) | VNode::VRef(_) => {}
+            VNode::VList(list) => {
+                let list = Rc::make_mut(list);
+                list.iter_mut().for_each(|child| child.visit_mut(visitor));
+                // the keys of the children might have changed
+                list.recheck_fully_keyed();
+            }
+            VNode::VPortal(portal) => Rc::make_mut(portal).node.visit_mut(visitor),
+            VNode::VSuspense(suspense) => {
+                let suspense = Rc::make_mut(suspense);
+                suspense.children.visit_mut(visitor);
+                suspense.fallback.visit_mut(visitor);
+            }
+            VNode::VRaw(raw) => visitor.visit_raw_mut(raw),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html;
+
+    #[test]
+    fn rewrites_nested_nodes() {
+        struct Shout;
+
+        impl VisitMut for Shout {
+            fn visit_tag_mut(&mut self, tag: &mut VTag) {
+                tag.add_attribute("data-visited", "");
+            }
+
+            fn visit_text_mut(&mut self, text: &mut VText) {
+                text.text = text.text.to_uppercase().into();
+            }
+        }
+
+        #[derive(Default)]
+        struct Texts(Vec<String>);
+
+        impl Visit for Texts {
+            fn visit_text(&mut self, text: &VText) {
+                self.0.push(text.text.to_string());
+            }
+        }
+
+        let shared = html! { <p>{ "shared" }</p> };
+        let mut node = html! {
+            <div>
+                { "a" }
+                <>{ shared.clone() }</>
+            </div>
+        };
+        node.visit_mut(&mut Shout);
+
+        let mut texts = Texts::default();
+        node.visit(&mut texts);
+        assert_eq!(texts.0, ["A", "SHARED"]);
+
+        // the original tree is left untouched
+        let mut texts = Texts::default();
+        shared.visit(&mut texts);
+        assert_eq!(texts.0, ["shared"]);
+
+        let VNode::VTag(div) = &node else {
+            panic!("expected a tag");
+        };
+        assert!(div.attributes.iter().any(|(k, _)| k == "data-visited"));
+    }
+}
@@ -7,7 +7,7 @@ use std::{fmt, mem};
 
 use web_sys::Node;
 
-use super::{Key, VChild, VComp, VList, VPortal, VSuspense, VTag, VText};
+use super::{recycle, Key, SanitizePolicy, VChild, VComp, VList, VPortal, VSuspense, VTag, VText};
 use crate::html::{BaseComponent, ImplicitClone};
 use crate::virtual_dom::VRaw;
 use crate::AttrValue;
@@ -105,11 +105,40 @@ impl VNode {
     pub fn from_html_unchecked(html: AttrValue) -> Self {
         VNode::VRaw(VRaw { html })
     }
+
+    /// Create a [`VNode`] from a string of HTML, sanitized by `policy`.
+    ///
+    /// The policy runs once, when the node is created, so the HTML inserted into the document or
+    /// the HTML stream is the one it returns. Otherwise, this behaves like
+    /// [`VNode::from_html_unchecked`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use yew::virtual_dom::EscapeHtml;
+    /// use yew::{html, Html};
+    /// # fn _main() {
+    /// let comment = Html::from_html_sanitized(&EscapeHtml, "<script>alert(1)</script>");
+    /// let _: Html = html! {
+    ///     <div>
+    ///         {comment}
+    ///     </div>
+    /// };
+    /// # }
+    /// ```
+    pub fn from_html_sanitized<P>(policy: &P, html: &str) -> Self
+    where
+        P: SanitizePolicy + ?Sized,
+    {
+        VNode::VRaw(VRaw {
+            html: policy.sanitize(html),
+        })
+    }
 }
 
 impl Default for VNode {
     fn default() -> Self {
-        VNode::VList(Rc::new(VList::default()))
+        VNode::VList(recycle::alloc(VList::default()))
     }
 }
 
@@ -123,14 +152,14 @@ impl From<VText> for VNode {
 impl From<VList> for VNode {
     #[inline]
     fn from(vlist: VList) -> Self {
-        VNode::VList(Rc::new(vlist))
+        VNode::VList(recycle::alloc(vlist))
     }
 }
 
 impl From<VTag> for VNode {
     #[inline]
     fn from(vtag: VTag) -> Self {
-        VNode::VTag(Rc::new(vtag))
+        VNode::VTag(recycle::alloc(vtag))
     }
 }
 
@@ -29,12 +29,49 @@ impl VSuspense {
 
 #[cfg(feature = "ssr")]
 mod feat_ssr {
+    use std::fmt::Write;
+    use std::task::Poll;
+
+    use futures::future::{self, poll_fn, Either, FutureExt};
+    use futures::stream::StreamExt;
+
     use super::*;
     use crate::feat_ssr::VTagKind;
     use crate::html::AnyScope;
-    use crate::platform::fmt::BufWriter;
+    use crate::platform::fmt::{BufStream, BufWriter};
     use crate::virtual_dom::Collectable;
 
+    /// Renders `node` in the scope `parent_scope` into a string.
+    async fn render_to_string(
+        node: VNode,
+        parent_scope: AnyScope,
+        hydratable: bool,
+        parent_vtag_kind: VTagKind,
+    ) -> String {
+        BufStream::new(move |mut w| async move {
+            node.render_into_stream(&mut w, &parent_scope, hydratable, parent_vtag_kind)
+                .await;
+        })
+        .collect()
+        .await
+    }
+
+    /// Marks the fallback rendered in place of children that were not ready by the deadline, so
+    /// that the children are rendered on the client in place of being hydrated.
+    fn mark_timed_out(fallback: &str, hydratable: bool) -> String {
+        if !hydratable {
+            return fallback.to_owned();
+        }
+        let collectable = Collectable::TimedOut;
+        format!(
+            "<!--{}{}-->{fallback}<!--{}{}-->",
+            collectable.open_start_mark(),
+            collectable.end_mark(),
+            collectable.close_start_mark(),
+            collectable.end_mark(),
+        )
+    }
+
     impl VSuspense {
         pub(crate) async fn render_into_stream(
             &self,
@@ -49,10 +86,70 @@ mod feat_ssr {
                 collectable.write_open_tag(w);
             }
 
-            // always render children on the server side.
-            self.children
-                .render_into_stream(w, parent_scope, hydratable, parent_vtag_kind)
-                .await;
+            let ssr = parent_scope.ssr.as_ref();
+            match ssr.filter(|ssr| ssr.out_of_order) {
+                Some(ssr) => {
+                    let mut content = render_to_string(
+                        self.children.clone(),
+                        parent_scope.clone(),
+                        hydratable,
+                        parent_vtag_kind,
+                    )
+                    .boxed_local();
+
+                    // Children that are not suspended are written in place.
+                    match poll_fn(|cx| Poll::Ready(content.poll_unpin(cx))).await {
+                        Poll::Ready(content) => {
+                            let _ = w.write_str(&content);
+                        }
+                        Poll::Pending => {
+                            // The fallback is replaced before the application is hydrated.
+                            let fallback = render_to_string(
+                                self.fallback.clone(),
+                                parent_scope.clone(),
+                                false,
+                                parent_vtag_kind,
+                            )
+                            .await;
+                            let timed_out = mark_timed_out(&fallback, hydratable);
+                            ssr.write_placeholder(w, content, &fallback, timed_out);
+                        }
+                    }
+                }
+                // Otherwise, always render children on the server side.
+                None => match ssr.and_then(|ssr| ssr.deadline.clone()) {
+                    Some(deadline) => {
+                        let content = render_to_string(
+                            self.children.clone(),
+                            parent_scope.clone(),
+                            hydratable,
+                            parent_vtag_kind,
+                        )
+                        .boxed_local();
+
+                        match future::select(content, deadline).await {
+                            Either::Left((content, _)) => {
+                                let _ = w.write_str(&content);
+                            }
+                            Either::Right(_) => {
+                                let fallback = render_to_string(
+                                    self.fallback.clone(),
+                                    parent_scope.clone(),
+                                    false,
+                                    parent_vtag_kind,
+                                )
+                                .await;
+                                let _ = w.write_str(&mark_timed_out(&fallback, hydratable));
+                            }
+                        }
+                    }
+                    None => {
+                        self.children
+                            .render_into_stream(w, parent_scope, hydratable, parent_vtag_kind)
+                            .await;
+                    }
+                },
+            }
 
             if hydratable {
                 collectable.write_close_tag(w);
@@ -66,76 +163,157 @@ mod feat_ssr {
 #[cfg(test)]
 mod ssr_tests {
     use std::rc::Rc;
-    use std::time::Duration;
+    use std::time::{Duration, Instant};
 
     use tokio::task::{spawn_local, LocalSet};
     use tokio::test;
 
     use crate::platform::time::sleep;
     use crate::prelude::*;
+    use crate::ssr::CancellationToken;
     use crate::suspense::{Suspension, SuspensionResult};
     use crate::ServerRenderer;
 
-    #[cfg(not(target_os = "wasi"))]
-    #[test(flavor = "multi_thread", worker_threads = 2)]
-    async fn test_suspense() {
-        #[derive(PartialEq)]
-        pub struct SleepState {
-            s: Suspension,
-        }
+    #[derive(PartialEq)]
+    pub struct SleepState {
+        s: Suspension,
+    }
 
-        impl SleepState {
-            fn new() -> Self {
-                let (s, handle) = Suspension::new();
+    impl SleepState {
+        fn new() -> Self {
+            let (s, handle) = Suspension::new();
 
-                // we use tokio spawn local here.
-                spawn_local(async move {
-                    // we use tokio sleep here.
-                    sleep(Duration::from_millis(50)).await;
+            // we use tokio spawn local here.
+            spawn_local(async move {
+                // we use tokio sleep here.
+                sleep(Duration::from_millis(50)).await;
 
-                    handle.resume();
-                });
+                handle.resume();
+            });
 
-                Self { s }
-            }
+            Self { s }
         }
+    }
 
-        impl Reducible for SleepState {
-            type Action = ();
+    impl Reducible for SleepState {
+        type Action = ();
 
-            fn reduce(self: Rc<Self>, _action: Self::Action) -> Rc<Self> {
-                Self::new().into()
-            }
+        fn reduce(self: Rc<Self>, _action: Self::Action) -> Rc<Self> {
+            Self::new().into()
         }
+    }
 
-        #[hook]
-        pub fn use_sleep() -> SuspensionResult<Rc<dyn Fn()>> {
-            let sleep_state = use_reducer(SleepState::new);
+    #[hook]
+    pub fn use_sleep() -> SuspensionResult<Rc<dyn Fn()>> {
+        let sleep_state = use_reducer(SleepState::new);
 
-            if sleep_state.s.resumed() {
-                Ok(Rc::new(move || sleep_state.dispatch(())))
-            } else {
-                Err(sleep_state.s.clone())
-            }
+        if sleep_state.s.resumed() {
+            Ok(Rc::new(move || sleep_state.dispatch(())))
+        } else {
+            Err(sleep_state.s.clone())
         }
+    }
 
-        #[derive(PartialEq, Properties, Debug)]
-        struct ChildProps {
-            name: String,
-        }
+    #[derive(PartialEq, Properties, Debug)]
+    struct ChildProps {
+        name: String,
+    }
+
+    #[function_component]
+    fn Child(props: &ChildProps) -> HtmlResult {
+        use_sleep()?;
+        Ok(html! { <div>{"Hello, "}{&props.name}{"!"}</div> })
+    }
 
+    #[cfg(not(target_os = "wasi"))]
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_suspense() {
         #[function_component]
-        fn Child(props: &ChildProps) -> HtmlResult {
-            use_sleep()?;
-            Ok(html! { <div>{"Hello, "}{&props.name}{"!"}</div> })
+        fn Comp() -> Html {
+            let fallback = html! {"loading..."};
+
+            html! {
+                <Suspense {fallback}>
+                    <Child name="Jane" />
+                    <Child name="John" />
+                    <Child name="Josh" />
+                </Suspense>
+            }
         }
 
+        let local = LocalSet::new();
+
+        let s = local
+            .run_until(async move {
+                ServerRenderer::<Comp>::new()
+                    .hydratable(false)
+                    .render()
+                    .await
+            })
+            .await;
+
+        assert_eq!(
+            s,
+            "<div>Hello, Jane!</div><div>Hello, John!</div><div>Hello, Josh!</div>"
+        );
+    }
+
+    #[cfg(not(target_os = "wasi"))]
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_out_of_order_suspense() {
         #[function_component]
         fn Comp() -> Html {
             let fallback = html! {"loading..."};
 
             html! {
-                <Suspense {fallback}>
+                <>
+                    <Suspense {fallback}>
+                        <Child name="Jane" />
+                    </Suspense>
+                    <Suspense>
+                        <div>{"ready"}</div>
+                    </Suspense>
+                    <div>{"after"}</div>
+                </>
+            }
+        }
+
+        let local = LocalSet::new();
+
+        let s = local
+            .run_until(async move {
+                ServerRenderer::<Comp>::new()
+                    .hydratable(false)
+                    .out_of_order_streaming(true)
+                    .render()
+                    .await
+            })
+            .await;
+
+        let (shell, deferred) = s.split_once("<script>").unwrap();
+        let id = shell
+            .strip_prefix(r#"<template id="yew-s-"#)
+            .and_then(|s| s.split_once('"'))
+            .map(|(id, _)| id)
+            .unwrap();
+        assert_eq!(
+            shell,
+            format!(
+                r#"<template id="yew-s-{id}"></template>loading...<template id="yew-e-{id}"></template><div>ready</div><div>after</div>"#
+            )
+        );
+        assert!(deferred.ends_with(&format!(
+            r#"<template id="yew-c-{id}"><div>Hello, Jane!</div></template><script>__yew_swap({id})</script>"#
+        )));
+    }
+
+    #[cfg(not(target_os = "wasi"))]
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_max_concurrent_suspensions() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <Suspense>
                     <Child name="Jane" />
                     <Child name="John" />
                     <Child name="Josh" />
@@ -145,10 +323,12 @@ mod ssr_tests {
 
         let local = LocalSet::new();
 
+        let start = Instant::now();
         let s = local
             .run_until(async move {
                 ServerRenderer::<Comp>::new()
                     .hydratable(false)
+                    .max_concurrent_suspensions(1)
                     .render()
                     .await
             })
@@ -158,5 +338,101 @@ mod ssr_tests {
             s,
             "<div>Hello, Jane!</div><div>Hello, John!</div><div>Hello, Josh!</div>"
         );
+        // Each child only starts sleeping once the previous one resumed.
+        assert!(start.elapsed() >= Duration::from_millis(150));
+    }
+
+    #[cfg(not(target_os = "wasi"))]
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_render_budgets() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <>
+                    <div>{"before"}</div>
+                    <Suspense>
+                        <Child name="Jane" />
+                    </Suspense>
+                </>
+            }
+        }
+
+        let local = LocalSet::new();
+
+        let (time_limited, memory_limited) = local
+            .run_until(async move {
+                let time_limited = ServerRenderer::<Comp>::new()
+                    .hydratable(false)
+                    .time_budget(Duration::from_millis(10))
+                    .render()
+                    .await;
+                let memory_limited = ServerRenderer::<Comp>::new()
+                    .hydratable(false)
+                    .memory_budget(8)
+                    .render()
+                    .await;
+                (time_limited, memory_limited)
+            })
+            .await;
+
+        assert!(!time_limited.contains("Hello, Jane!"));
+        assert!(memory_limited.len() <= 8);
+    }
+
+    #[cfg(not(target_os = "wasi"))]
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_deadline_and_cancellation() {
+        #[function_component]
+        fn Comp() -> Html {
+            let fallback = html! {"loading..."};
+
+            html! {
+                <>
+                    <div>{"before"}</div>
+                    <Suspense {fallback}>
+                        <Child name="Jane" />
+                    </Suspense>
+                    <div>{"after"}</div>
+                </>
+            }
+        }
+
+        let local = LocalSet::new();
+
+        let (timed_out, out_of_order, cancelled) = local
+            .run_until(async move {
+                let timed_out = ServerRenderer::<Comp>::new()
+                    .hydratable(false)
+                    .deadline(Duration::from_millis(10))
+                    .render()
+                    .await;
+                let out_of_order = ServerRenderer::<Comp>::new()
+                    .out_of_order_streaming(true)
+                    .deadline(Duration::from_millis(10))
+                    .render()
+                    .await;
+
+                let token = CancellationToken::new();
+                spawn_local({
+                    let token = token.clone();
+                    async move {
+                        sleep(Duration::from_millis(10)).await;
+                        token.cancel();
+                    }
+                });
+                let cancelled = ServerRenderer::<Comp>::new()
+                    .hydratable(false)
+                    .cancellation_token(token)
+                    .render()
+                    .await;
+                (timed_out, out_of_order, cancelled)
+            })
+            .await;
+
+        assert_eq!(timed_out, "<div>before</div>loading...<div>after</div>");
+        // The fallback is marked for the children to be rendered when hydrating.
+        assert!(out_of_order.contains("<!--<%>-->loading...<!--</%>--></template>"));
+        assert!(!out_of_order.contains("Hello, Jane!"));
+        assert_eq!(cancelled, "<div>before</div>");
     }
 }
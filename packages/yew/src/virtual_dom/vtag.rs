@@ -2,9 +2,9 @@
 
 use std::cmp::PartialEq;
 use std::marker::PhantomData;
-use std::mem;
 use std::ops::{Deref, DerefMut};
 use std::rc::Rc;
+use std::{fmt, mem};
 
 use wasm_bindgen::JsValue;
 use web_sys::{HtmlInputElement as InputElement, HtmlTextAreaElement as TextAreaElement};
@@ -113,6 +113,77 @@ pub(crate) struct TextareaFields {
     pub(crate) defaultvalue: Option<AttrValue>,
 }
 
+/// The name of an element, as given to a dynamic tag `<@{name}>` in [`html!`](crate::html!).
+///
+/// `<input>` and `<textarea>` elements have their own variants, because their `value`, `checked`
+/// and `defaultvalue` attributes are applied as properties, the same as with literal tags. Any
+/// string converts into a [TagName], ignoring the ASCII case of these special names.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TagName {
+    /// An [`<input>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input) element.
+    Input,
+    /// A [`<textarea>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/textarea)
+    /// element.
+    Textarea,
+    /// Any other element.
+    Other(AttrValue),
+}
+
+impl TagName {
+    /// Returns the name of the element.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Input => "input",
+            Self::Textarea => "textarea",
+            Self::Other(name) => name,
+        }
+    }
+
+    /// Returns `true` for [void elements](https://html.spec.whatwg.org/multipage/syntax.html#void-elements),
+    /// which cannot have children.
+    pub fn is_void(&self) -> bool {
+        match self {
+            Self::Input => true,
+            Self::Textarea => false,
+            Self::Other(name) => matches!(
+                name.to_ascii_lowercase().as_str(),
+                "area"
+                    | "base"
+                    | "br"
+                    | "col"
+                    | "embed"
+                    | "hr"
+                    | "img"
+                    | "link"
+                    | "meta"
+                    | "param"
+                    | "source"
+                    | "track"
+                    | "wbr"
+            ),
+        }
+    }
+}
+
+impl<T: Into<AttrValue>> From<T> for TagName {
+    fn from(name: T) -> Self {
+        let name = name.into();
+        if name.eq_ignore_ascii_case("input") {
+            Self::Input
+        } else if name.eq_ignore_ascii_case("textarea") {
+            Self::Textarea
+        } else {
+            Self::Other(name)
+        }
+    }
+}
+
+impl fmt::Display for TagName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// [VTag] fields that are specific to different [VTag] kinds.
 /// Decreases the memory footprint of [VTag] by avoiding impossible field and value combinations.
 #[derive(Debug, Clone)]
@@ -136,6 +207,22 @@ pub(crate) enum VTagInner {
 
 impl ImplicitClone for VTagInner {}
 
+/// Identifies an element of `html!` that is fully static: its attributes and children are
+/// literals, and it has no listeners. Such an element is never diffed against an element from the
+/// same place of `html!`.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct StaticNodeId(#[allow(dead_code)] u8);
+
+impl StaticNodeId {
+    #[doc(hidden)]
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        // not zero-sized, so that every static has its own address
+        Self(0)
+    }
+}
+
 /// A type for a virtual
 /// [Element](https://developer.mozilla.org/en-US/docs/Web/API/Element)
 /// representation.
@@ -150,19 +237,20 @@ pub struct VTag {
     /// List of attributes.
     pub attributes: Attributes,
     pub key: Option<Key>,
+    /// Set by `html!` when the element is fully static, until the element is modified.
+    pub(crate) static_id: Option<&'static StaticNodeId>,
 }
 
 impl ImplicitClone for VTag {}
 
 impl VTag {
     /// Creates a new [VTag] instance with `tag` name (cannot be changed later in DOM).
-    pub fn new(tag: impl Into<AttrValue>) -> Self {
-        let tag = tag.into();
+    pub fn new(tag: impl Into<TagName>) -> Self {
         Self::new_base(
-            match &*tag.to_ascii_lowercase() {
-                "input" => VTagInner::Input(Default::default()),
-                "textarea" => VTagInner::Textarea(Default::default()),
-                _ => VTagInner::Other {
+            match tag.into() {
+                TagName::Input => VTagInner::Input(Default::default()),
+                TagName::Textarea => VTagInner::Textarea(Default::default()),
+                TagName::Other(tag) => VTagInner::Other {
                     tag,
                     children: Default::default(),
                 },
@@ -280,9 +368,17 @@ impl VTag {
             listeners,
             node_ref,
             key,
+            static_id: None,
         }
     }
 
+    /// Marks the element as fully static, see [`StaticNodeId`].
+    #[doc(hidden)]
+    pub fn __mark_static(mut self, id: &'static StaticNodeId) -> Self {
+        self.static_id = Some(id);
+        self
+    }
+
     /// Returns tag of an [Element](web_sys::Element). In HTML tags are always uppercase.
     pub fn tag(&self) -> &str {
         match &self.inner {
@@ -292,8 +388,18 @@ impl VTag {
         }
     }
 
+    /// Returns the tag name of the element as a [TagName].
+    pub fn tag_name(&self) -> TagName {
+        match &self.inner {
+            VTagInner::Input { .. } => TagName::Input,
+            VTagInner::Textarea { .. } => TagName::Textarea,
+            VTagInner::Other { tag, .. } => TagName::Other(tag.clone()),
+        }
+    }
+
     /// Add [VNode] child.
     pub fn add_child(&mut self, child: VNode) {
+        self.static_id = None;
         if let VTagInner::Other { children, .. } = &mut self.inner {
             children.to_vlist_mut().add_child(child)
         }
@@ -301,6 +407,7 @@ impl VTag {
 
     /// Add multiple [VNode] children.
     pub fn add_children(&mut self, children: impl IntoIterator<Item = VNode>) {
+        self.static_id = None;
         if let VTagInner::Other { children: dst, .. } = &mut self.inner {
             dst.to_vlist_mut().add_children(children)
         }
@@ -318,6 +425,7 @@ impl VTag {
     /// Returns a mutable reference to the children of this [VTag], if the node can have
     /// children
     pub fn children_mut(&mut self) -> Option<&mut VNode> {
+        self.static_id = None;
         match &mut self.inner {
             VTagInner::Other { children, .. } => Some(children),
             _ => None,
@@ -392,6 +500,7 @@ impl VTag {
     /// Not every attribute works when it set as an attribute. We use workarounds for:
     /// `value` and `checked`.
     pub fn add_attribute(&mut self, key: &'static str, value: impl Into<AttrValue>) {
+        self.static_id = None;
         self.attributes.get_mut_index_map().insert(
             AttrValue::Static(key),
             AttributeOrProperty::Attribute(value.into()),
@@ -402,6 +511,7 @@ impl VTag {
     ///
     /// [`js_sys::Reflect`] is used for setting properties.
     pub fn add_property(&mut self, key: &'static str, value: impl Into<JsValue>) {
+        self.static_id = None;
         self.attributes.get_mut_index_map().insert(
             AttrValue::Static(key),
             AttributeOrProperty::Property(value.into()),
@@ -413,11 +523,51 @@ impl VTag {
     /// Not every attribute works when it set as an attribute. We use workarounds for:
     /// `value` and `checked`.
     pub fn set_attributes(&mut self, attrs: impl Into<Attributes>) {
+        self.static_id = None;
         self.attributes = attrs.into();
     }
 
+    /// Sets the attributes yielded by `attrs` that are not set on the virtual node yet.
+    ///
+    /// Attributes that are already set keep their value. This lets wrapper components forward
+    /// extra attributes, like `data-*` or `aria-*` ones collected at runtime, to an element without
+    /// overriding its own. In `html!` this is done with `<div class="a" ..{attrs} />`.
+    pub fn set_attributes_from<K, V>(&mut self, attrs: impl IntoIterator<Item = (K, V)>)
+    where
+        K: Into<AttrValue>,
+        V: Into<AttrValue>,
+    {
+        let mut attrs = attrs.into_iter().peekable();
+        if attrs.peek().is_none() {
+            return;
+        }
+        self.static_id = None;
+
+        let map = self.attributes.get_mut_index_map();
+        for (key, value) in attrs {
+            map.entry(key.into())
+                .or_insert_with(|| AttributeOrProperty::Attribute(value.into()));
+        }
+    }
+
+    /// Sets the attribute `key`, or removes it if `value` is `None`.
+    pub(crate) fn set_attribute(&mut self, key: impl Into<AttrValue>, value: Option<AttrValue>) {
+        self.static_id = None;
+        let map = self.attributes.get_mut_index_map();
+        let key = key.into();
+        match value {
+            Some(value) => {
+                map.insert(key, AttributeOrProperty::Attribute(value));
+            }
+            None => {
+                map.shift_remove(&key);
+            }
+        }
+    }
+
     #[doc(hidden)]
     pub fn __macro_push_attr(&mut self, key: &'static str, value: impl IntoPropValue<AttrValue>) {
+        self.static_id = None;
         self.attributes.get_mut_index_map().insert(
             AttrValue::from(key),
             AttributeOrProperty::Attribute(value.into_prop_value()),
@@ -427,6 +577,7 @@ impl VTag {
     /// Add event listener on the [VTag]'s  [Element](web_sys::Element).
     /// Returns `true` if the listener has been added, `false` otherwise.
     pub fn add_listener(&mut self, listener: Rc<dyn Listener>) -> bool {
+        self.static_id = None;
         match &mut self.listeners {
             Listeners::None => {
                 self.set_listeners([Some(listener)].into());
@@ -444,10 +595,31 @@ impl VTag {
 
     /// Set event listeners on the [VTag]'s  [Element](web_sys::Element)
     pub fn set_listeners(&mut self, listeners: Box<[Option<Rc<dyn Listener>>]>) {
+        self.static_id = None;
         self.listeners = Listeners::Pending(listeners);
     }
 }
 
+/// A value that can be spread onto an element with `..` in `html!`.
+///
+/// It is implemented for iterators of attribute key-value pairs, see [`VTag::set_attributes_from`],
+/// and for [`RestProps`](crate::html::RestProps), which forwards listeners as well.
+pub trait Spread {
+    /// Sets `self` on `vtag`, keeping the attributes it already has.
+    fn spread_onto(self, vtag: &mut VTag);
+}
+
+impl<I, K, V> Spread for I
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<AttrValue>,
+    V: Into<AttrValue>,
+{
+    fn spread_onto(self, vtag: &mut VTag) {
+        vtag.set_attributes_from(self);
+    }
+}
+
 impl PartialEq for VTag {
     fn eq(&self, other: &VTag) -> bool {
         use VTagInner::*;
@@ -520,6 +692,15 @@ mod feat_ssr {
                 write_attr(w, k, Some(v));
             }
 
+            if let (true, Some(ssr), Listeners::Pending(listeners)) =
+                (hydratable, parent_scope.ssr.as_ref(), &self.listeners)
+            {
+                let types = listeners.iter().flatten().map(|m| m.kind().type_name());
+                if ssr.resumable && listeners.iter().any(Option::is_some) {
+                    write_attr(w, "data-yew-on", Some(ssr.record_events(types).as_str()));
+                }
+            }
+
             let _ = w.write_str(">");
 
             match &self.inner {
@@ -602,6 +783,23 @@ mod ssr_tests {
         assert_eq!(s, r#"<div class="abc"></div>"#);
     }
 
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_simple_tag_with_spread_attrs() {
+        #[function_component]
+        fn Comp() -> Html {
+            let extra = [("class", "extra"), ("data-id", "1")];
+            html! { <div class="abc" ..extra></div> }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, r#"<div class="abc" data-id="1"></div>"#);
+    }
+
     #[cfg_attr(not(target_os = "wasi"), test)]
     #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
     async fn test_simple_tag_with_content() {
@@ -739,4 +937,36 @@ mod ssr_tests {
             r#"<style>html { background: black } body > a { color: white } </style>"#
         );
     }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_resumable_marks_listeners() {
+        #[function_component]
+        fn Comp() -> Html {
+            let onclick = Callback::from(|_: MouseEvent| ());
+            let oninput = Callback::from(|_: InputEvent| ());
+            html! {
+                <div>
+                    <button onclick={onclick.clone()}>{"+"}</button>
+                    <input {onclick} {oninput} />
+                    <p>{"static"}</p>
+                </div>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new().resumable(true).render().await;
+
+        assert!(s.contains(r#"<button data-yew-on="click">+</button>"#));
+        assert!(s.contains(r#"<input data-yew-on="click input">"#));
+        assert!(s.contains("<p>static</p>"));
+        assert!(s.ends_with(r#"<script>__yew_resume(["click","input"])</script>"#));
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .resumable(true)
+            .render()
+            .await;
+
+        assert!(!s.contains("data-yew-on") && !s.contains("<script>"));
+    }
 }
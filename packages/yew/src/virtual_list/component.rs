@@ -0,0 +1,286 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent};
+
+use super::layout::{navigate, ItemSize, Layout, Measurements, ScrollAlign};
+use crate::callback::Callback;
+use crate::functional::{
+    use_effect, use_effect_with, use_force_update, use_memo, use_mut_ref, use_node_ref,
+    use_resize_observer, use_state,
+};
+use crate::html::{Classes, Html, NodeRef, Properties};
+use crate::virtual_dom::{AttrValue, Key};
+use crate::{function_component, html};
+
+/// Properties for [VirtualList].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct VirtualListProps {
+    /// The number of rows of the list.
+    pub item_count: usize,
+
+    /// The height of the rows.
+    pub item_size: ItemSize,
+
+    /// Renders the row at the given index.
+    pub render_item: Callback<usize, Html>,
+
+    /// Returns the key of the row at the given index.
+    ///
+    /// Rows are keyed by their index by default. Provide stable keys if rows can be inserted or
+    /// removed, so that the state of rendered rows follows their data.
+    #[prop_or_default]
+    pub item_key: Option<Callback<usize, Key>>,
+
+    /// The number of rows rendered above and below the visible rows.
+    #[prop_or(3)]
+    pub overscan: usize,
+
+    /// Scrolls the row at the given index into view whenever the value changes.
+    #[prop_or_default]
+    pub scroll_to_index: Option<usize>,
+
+    /// Where the row of [`scroll_to_index`](Self::scroll_to_index) is placed in the viewport.
+    #[prop_or_default]
+    pub scroll_align: ScrollAlign,
+
+    /// Classes of the row made active with the keyboard.
+    ///
+    /// The list can be navigated with the arrow keys, `PageUp`, `PageDown`, `Home` and `End` while
+    /// the scroll container has focus.
+    #[prop_or_default]
+    pub active_classes: Classes,
+
+    /// Called with the index of the row made active with the keyboard.
+    #[prop_or_default]
+    pub on_active_change: Option<Callback<usize>>,
+
+    /// Classes of the scroll container.
+    #[prop_or_default]
+    pub classes: Classes,
+
+    /// Additional styles of the scroll container.
+    ///
+    /// The container must be given a height, either through its classes or this property.
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+}
+
+/// The rows of the previous render, reused as long as `render_item` stays the same.
+#[derive(Default)]
+struct RowCache {
+    render_item: Option<Callback<usize, Html>>,
+    rows: HashMap<usize, Html>,
+}
+
+impl RowCache {
+    /// Returns the rows in `range`, only rendering the rows that were not rendered before.
+    fn rows(&mut self, render_item: &Callback<usize, Html>, range: Range<usize>) -> Vec<Html> {
+        if self.render_item.as_ref() != Some(render_item) {
+            self.render_item = Some(render_item.clone());
+            self.rows.clear();
+        }
+
+        let mut previous = std::mem::take(&mut self.rows);
+        range
+            .map(|index| {
+                let row = previous
+                    .remove(&index)
+                    .unwrap_or_else(|| render_item.emit(index));
+                self.rows.insert(index, row.clone());
+                row
+            })
+            .collect()
+    }
+}
+
+/// Records the height of the rendered rows of `spacer`, returning whether any changed.
+fn measure_rows(spacer: &NodeRef, measurements: &mut Measurements) -> bool {
+    let Some(spacer) = spacer.cast::<Element>() else {
+        return false;
+    };
+
+    let rows = spacer.children();
+    let mut changed = false;
+    for row in (0..rows.length()).filter_map(|i| rows.item(i)) {
+        let index = row.get_attribute("data-index").and_then(|m| m.parse().ok());
+        if let (Some(index), Some(row)) = (index, row.dyn_ref::<HtmlElement>()) {
+            changed |= measurements.set(index, f64::from(row.offset_height()));
+        }
+    }
+    changed
+}
+
+/// A list that only renders the rows visible in its scroll container.
+///
+/// The size of the scroll container is tracked with [`use_resize_observer`], so the list adapts
+/// when the container is resized. Rows are keyed, so that rows which stay visible while scrolling
+/// are moved instead of being re-created, and their [`Html`] is reused until `render_item`
+/// changes. With [`ItemSize::Estimated`], rows are measured after they are rendered.
+///
+/// # Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew::virtual_list::ItemSize;
+///
+/// #[function_component]
+/// fn Rows() -> Html {
+///     let render_item = Callback::from(|index: usize| html! { <p>{ index }</p> });
+///
+///     html! {
+///         <VirtualList
+///             item_count={10_000}
+///             item_size={ItemSize::Fixed(24.0)}
+///             {render_item}
+///             style="height: 400px;"
+///         />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn VirtualList(props: &VirtualListProps) -> Html {
+    let container = use_node_ref();
+    let spacer = use_node_ref();
+    let viewport = use_resize_observer(container.clone()).height;
+    let scroll_top = use_mut_ref(|| 0.0);
+    let rendered = use_mut_ref(|| 0..0);
+    let measurements = use_mut_ref(Measurements::default);
+    let cache = use_mut_ref(RowCache::default);
+    let active = use_state(|| None::<usize>);
+    let trigger = use_force_update();
+
+    let layout = {
+        let measurements = measurements.clone();
+        let version = measurements.borrow().version();
+        use_memo(
+            (props.item_count, props.item_size.clone(), version),
+            move |(count, size, _)| Layout::new(*count, size, &measurements.borrow()),
+        )
+    };
+
+    let range = layout.visible_range(*scroll_top.borrow(), viewport, props.overscan);
+    *rendered.borrow_mut() = range.clone();
+
+    // Scrolling only causes a re-render when other rows become visible.
+    let onscroll = {
+        let container = container.clone();
+        let scroll_top = scroll_top.clone();
+        let layout = layout.clone();
+        let overscan = props.overscan;
+        let trigger = trigger.clone();
+
+        Callback::from(move |_| {
+            if let Some(element) = container.cast::<Element>() {
+                let top = f64::from(element.scroll_top());
+                *scroll_top.borrow_mut() = top;
+
+                if layout.visible_range(top, viewport, overscan) != *rendered.borrow() {
+                    trigger.force_update();
+                }
+            }
+        })
+    };
+
+    let onkeydown = {
+        let container = container.clone();
+        let scroll_top = scroll_top.clone();
+        let layout = layout.clone();
+        let active = active.clone();
+        let on_active_change = props.on_active_change.clone();
+
+        Callback::from(move |e: KeyboardEvent| {
+            let top = *scroll_top.borrow();
+            let page = layout
+                .visible_range(top, viewport, 0)
+                .len()
+                .saturating_sub(1);
+            let Some(index) = navigate(&e.key(), *active, layout.len(), page) else {
+                return;
+            };
+            e.prevent_default();
+
+            if let Some(element) = container.cast::<Element>() {
+                let top = layout.scroll_offset(index, ScrollAlign::Auto, viewport, top);
+                element.set_scroll_top(top.round() as i32);
+            }
+            active.set(Some(index));
+            if let Some(on_active_change) = &on_active_change {
+                on_active_change.emit(index);
+            }
+        })
+    };
+
+    {
+        let container = container.clone();
+        let layout = layout.clone();
+        let scroll_top = scroll_top.clone();
+        let align = props.scroll_align;
+
+        use_effect_with(props.scroll_to_index, move |index| {
+            if let (Some(index), Some(element)) = (*index, container.cast::<Element>()) {
+                let top = layout.scroll_offset(index, align, viewport, *scroll_top.borrow());
+                element.set_scroll_top(top.round() as i32);
+            }
+        });
+    }
+
+    // Measurements of rows of another size no longer apply.
+    {
+        let measurements = measurements.clone();
+        use_effect_with(props.item_size.clone(), move |_| {
+            measurements.borrow_mut().clear();
+        });
+    }
+
+    let estimated = matches!(props.item_size, ItemSize::Estimated(_));
+    {
+        let spacer = spacer.clone();
+        use_effect(move || {
+            if estimated && measure_rows(&spacer, &mut measurements.borrow_mut()) {
+                trigger.force_update();
+            }
+        });
+    }
+
+    let style = format!(
+        "overflow-y: auto; {}",
+        props.style.as_deref().unwrap_or_default()
+    );
+    let key = |index: usize| match &props.item_key {
+        Some(item_key) => item_key.emit(index),
+        None => Key::from(index),
+    };
+    let rows = cache.borrow_mut().rows(&props.render_item, range.clone());
+
+    html! {
+        <div ref={container} class={props.classes.clone()} {style} tabindex="0" {onscroll} {onkeydown}>
+            <div ref={spacer} style={format!("position: relative; width: 100%; height: {}px;", layout.total_size())}>
+                { for range.zip(rows).map(|(index, row)| {
+                    let height = if estimated {
+                        String::new()
+                    } else {
+                        format!("height: {}px; ", layout.size(index))
+                    };
+                    let class = (*active == Some(index)).then(|| props.active_classes.clone());
+
+                    html! {
+                        <div
+                            key={key(index)}
+                            data-index={index.to_string()}
+                            {class}
+                            style={format!(
+                                "position: absolute; top: 0; left: 0; width: 100%; {height}\
+                                 transform: translateY({}px);",
+                                layout.offset(index),
+                            )}
+                        >
+                            { row }
+                        </div>
+                    }
+                }) }
+            </div>
+        </div>
+    }
+}
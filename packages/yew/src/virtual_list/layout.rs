@@ -0,0 +1,340 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::callback::Callback;
+
+/// The height of the rows of a [`VirtualList`](super::VirtualList), in CSS pixels.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ItemSize {
+    /// All rows have the same height.
+    Fixed(f64),
+    /// The height of every row is returned by the callback, given the index of the row.
+    ///
+    /// The heights are computed once for all rows whenever the callback or the number of rows
+    /// changes, so the callback should be memoized, e.g. with
+    /// [`use_callback`](crate::functional::use_callback).
+    Variable(Callback<usize, f64>),
+    /// The height of the rows is not known in advance.
+    ///
+    /// Rows are rendered with their natural height, which is measured once they are rendered.
+    /// Rows that were not rendered yet are assumed to have the given height, so the scroll bar
+    /// may adjust while scrolling through the list.
+    Estimated(f64),
+}
+
+impl From<f64> for ItemSize {
+    fn from(size: f64) -> Self {
+        Self::Fixed(size)
+    }
+}
+
+impl From<Callback<usize, f64>> for ItemSize {
+    fn from(size: Callback<usize, f64>) -> Self {
+        Self::Variable(size)
+    }
+}
+
+/// Where a row is placed in the viewport when scrolling to it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScrollAlign {
+    /// The row is placed at the top of the viewport.
+    #[default]
+    Start,
+    /// The row is centered in the viewport.
+    Center,
+    /// The row is placed at the bottom of the viewport.
+    End,
+    /// The list is only scrolled if the row is not fully visible, and only as far as needed.
+    Auto,
+}
+
+/// Positions of the rows of a list.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Layout {
+    Fixed {
+        size: f64,
+        count: usize,
+    },
+    /// The offsets of the rows followed by the total size of the list.
+    Variable {
+        offsets: Vec<f64>,
+    },
+}
+
+impl Layout {
+    pub fn new(count: usize, size: &ItemSize, measurements: &Measurements) -> Self {
+        match size {
+            ItemSize::Fixed(size) => Self::Fixed {
+                size: size.max(0.0),
+                count,
+            },
+            ItemSize::Variable(size) => Self::from_sizes(count, |index| size.emit(index)),
+            ItemSize::Estimated(estimate) => {
+                Self::from_sizes(count, |index| measurements.get(index).unwrap_or(*estimate))
+            }
+        }
+    }
+
+    fn from_sizes(count: usize, size: impl Fn(usize) -> f64) -> Self {
+        let mut offsets = Vec::with_capacity(count + 1);
+        let mut offset = 0.0;
+        offsets.push(offset);
+        for index in 0..count {
+            offset += size(index).max(0.0);
+            offsets.push(offset);
+        }
+        Self::Variable { offsets }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Fixed { count, .. } => *count,
+            Self::Variable { offsets } => offsets.len() - 1,
+        }
+    }
+
+    pub fn total_size(&self) -> f64 {
+        self.offset(self.len())
+    }
+
+    /// The distance between the top of the list and the top of the row at `index`.
+    pub fn offset(&self, index: usize) -> f64 {
+        match self {
+            Self::Fixed { size, count } => *size * index.min(*count) as f64,
+            Self::Variable { offsets } => offsets[index.min(offsets.len() - 1)],
+        }
+    }
+
+    pub fn size(&self, index: usize) -> f64 {
+        self.offset(index + 1) - self.offset(index)
+    }
+
+    /// The index of the row at `offset`, clamped to the rows of the list.
+    fn index_at(&self, offset: f64) -> usize {
+        let last = self.len().saturating_sub(1);
+        match self {
+            Self::Fixed { size, .. } if *size > 0.0 => {
+                ((offset / size).max(0.0) as usize).min(last)
+            }
+            Self::Fixed { .. } => 0,
+            Self::Variable { offsets } => offsets
+                .partition_point(|m| *m <= offset)
+                .saturating_sub(1)
+                .min(last),
+        }
+    }
+
+    /// The rows overlapping the viewport, extended by `overscan` rows on both sides.
+    pub fn visible_range(&self, scroll_top: f64, viewport: f64, overscan: usize) -> Range<usize> {
+        if self.len() == 0 {
+            return 0..0;
+        }
+
+        let start = self.index_at(scroll_top);
+        let end = self.index_at(scroll_top + viewport.max(0.0)) + 1;
+
+        start.saturating_sub(overscan)..(end + overscan).min(self.len())
+    }
+
+    /// The scroll position that shows the row at `index` according to `align`.
+    pub fn scroll_offset(
+        &self,
+        index: usize,
+        align: ScrollAlign,
+        viewport: f64,
+        scroll_top: f64,
+    ) -> f64 {
+        let start = self.offset(index);
+        let end = start + self.size(index);
+        let max = (self.total_size() - viewport).max(0.0);
+
+        let offset = match align {
+            ScrollAlign::Start => start,
+            ScrollAlign::Center => (start + end - viewport) / 2.0,
+            ScrollAlign::End => end - viewport,
+            ScrollAlign::Auto if start < scroll_top => start,
+            ScrollAlign::Auto if end > scroll_top + viewport => end - viewport,
+            ScrollAlign::Auto => scroll_top,
+        };
+
+        offset.clamp(0.0, max)
+    }
+}
+
+/// The heights of the rendered rows of a list with [`ItemSize::Estimated`] rows.
+#[derive(Debug, Default)]
+pub(crate) struct Measurements {
+    sizes: HashMap<usize, f64>,
+    /// Incremented whenever a height changes, so that the layout can be computed again.
+    version: u64,
+}
+
+impl Measurements {
+    pub fn get(&self, index: usize) -> Option<f64> {
+        self.sizes.get(&index).copied()
+    }
+
+    /// Records the height of the row at `index`, returning whether it changed.
+    ///
+    /// Differences below half a pixel are ignored, so that rounding doesn't cause endless
+    /// measurements.
+    pub fn set(&mut self, index: usize, size: f64) -> bool {
+        if self.get(index).is_some_and(|m| (m - size).abs() < 0.5) {
+            return false;
+        }
+        self.sizes.insert(index, size);
+        self.version += 1;
+        true
+    }
+
+    pub fn clear(&mut self) {
+        if !self.sizes.is_empty() {
+            self.sizes.clear();
+            self.version += 1;
+        }
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+}
+
+/// The row that becomes active when `key` is pressed, or `None` if `key` doesn't navigate.
+///
+/// `page` is the number of rows moved by `PageUp` and `PageDown`.
+pub(crate) fn navigate(
+    key: &str,
+    active: Option<usize>,
+    count: usize,
+    page: usize,
+) -> Option<usize> {
+    let last = count.checked_sub(1)?;
+    let next = match (key, active) {
+        ("ArrowDown", Some(index)) => index + 1,
+        ("ArrowUp", Some(index)) => index.saturating_sub(1),
+        ("PageDown", Some(index)) => index + page.max(1),
+        ("PageUp", Some(index)) => index.saturating_sub(page.max(1)),
+        ("ArrowDown" | "PageDown" | "Home", _) => 0,
+        ("ArrowUp" | "PageUp" | "End", _) => last,
+        _ => return None,
+    };
+    Some(next.min(last))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variable() -> Layout {
+        // Rows alternate between 10 and 30 pixels.
+        Layout::new(
+            10,
+            &ItemSize::Variable(Callback::from(
+                |index| {
+                    if index % 2 == 0 {
+                        10.0
+                    } else {
+                        30.0
+                    }
+                },
+            )),
+            &Measurements::default(),
+        )
+    }
+
+    #[test]
+    fn fixed_layout() {
+        let layout = Layout::new(100, &ItemSize::Fixed(20.0), &Measurements::default());
+
+        assert_eq!(layout.total_size(), 2000.0);
+        assert_eq!(layout.offset(3), 60.0);
+        assert_eq!(layout.size(99), 20.0);
+        assert_eq!(layout.visible_range(0.0, 100.0, 0), 0..6);
+        assert_eq!(layout.visible_range(110.0, 100.0, 2), 3..13);
+        assert_eq!(layout.visible_range(1950.0, 100.0, 3), 94..100);
+    }
+
+    #[test]
+    fn variable_layout() {
+        let layout = variable();
+
+        assert_eq!(layout.total_size(), 200.0);
+        assert_eq!(layout.offset(3), 50.0);
+        assert_eq!(layout.size(3), 30.0);
+        assert_eq!(layout.visible_range(45.0, 20.0, 0), 2..4);
+        assert_eq!(layout.visible_range(45.0, 20.0, 1), 1..5);
+    }
+
+    #[test]
+    fn empty_layout() {
+        let layout = Layout::new(0, &ItemSize::Fixed(20.0), &Measurements::default());
+
+        assert_eq!(layout.total_size(), 0.0);
+        assert_eq!(layout.visible_range(0.0, 100.0, 3), 0..0);
+        assert_eq!(layout.scroll_offset(0, ScrollAlign::Start, 100.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn scroll_offsets() {
+        let layout = Layout::new(100, &ItemSize::Fixed(20.0), &Measurements::default());
+
+        assert_eq!(
+            layout.scroll_offset(10, ScrollAlign::Start, 100.0, 0.0),
+            200.0
+        );
+        assert_eq!(
+            layout.scroll_offset(10, ScrollAlign::Center, 100.0, 0.0),
+            160.0
+        );
+        assert_eq!(
+            layout.scroll_offset(10, ScrollAlign::End, 100.0, 0.0),
+            120.0
+        );
+        assert_eq!(
+            layout.scroll_offset(99, ScrollAlign::Start, 100.0, 0.0),
+            1900.0
+        );
+        assert_eq!(layout.scroll_offset(2, ScrollAlign::End, 100.0, 0.0), 0.0);
+
+        assert_eq!(
+            layout.scroll_offset(3, ScrollAlign::Auto, 100.0, 40.0),
+            40.0
+        );
+        assert_eq!(
+            layout.scroll_offset(1, ScrollAlign::Auto, 100.0, 40.0),
+            20.0
+        );
+        assert_eq!(
+            layout.scroll_offset(8, ScrollAlign::Auto, 100.0, 40.0),
+            80.0
+        );
+    }
+
+    #[test]
+    fn estimated_layout() {
+        let mut measurements = Measurements::default();
+        assert!(measurements.set(1, 50.0));
+        assert!(!measurements.set(1, 50.2));
+        assert_eq!(measurements.version(), 1);
+
+        let layout = Layout::new(4, &ItemSize::Estimated(20.0), &measurements);
+        assert_eq!(layout.total_size(), 110.0);
+        assert_eq!(layout.offset(2), 70.0);
+        assert_eq!(layout.size(1), 50.0);
+    }
+
+    #[test]
+    fn keyboard_navigation() {
+        assert_eq!(navigate("ArrowDown", None, 10, 3), Some(0));
+        assert_eq!(navigate("ArrowDown", Some(4), 10, 3), Some(5));
+        assert_eq!(navigate("ArrowDown", Some(9), 10, 3), Some(9));
+        assert_eq!(navigate("ArrowUp", Some(0), 10, 3), Some(0));
+        assert_eq!(navigate("ArrowUp", None, 10, 3), Some(9));
+        assert_eq!(navigate("PageDown", Some(8), 10, 3), Some(9));
+        assert_eq!(navigate("PageUp", Some(5), 10, 3), Some(2));
+        assert_eq!(navigate("Home", Some(5), 10, 3), Some(0));
+        assert_eq!(navigate("End", Some(5), 10, 3), Some(9));
+        assert_eq!(navigate("Enter", Some(5), 10, 3), None);
+        assert_eq!(navigate("ArrowDown", None, 0, 3), None);
+    }
+}
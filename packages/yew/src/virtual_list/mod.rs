@@ -0,0 +1,15 @@
+//! This module provides a virtualized list.
+//!
+//! Rendering thousands of rows at once is slow, both for Yew and for the browser. A
+//! [`VirtualList`] only renders the rows that are visible in its scroll container, plus a few rows
+//! of [`overscan`](VirtualListProps::overscan) above and below, and positions them inside a
+//! spacer that has the height of the full list.
+//!
+//! Row heights can be fixed, computed from the index of the row, or
+//! [estimated](ItemSize::Estimated) and measured once the rows are rendered.
+
+mod component;
+mod layout;
+
+pub use component::{VirtualList, VirtualListProps};
+pub use layout::{ItemSize, ScrollAlign};
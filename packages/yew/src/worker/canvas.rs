@@ -0,0 +1,215 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+use js_sys::{Array, Uint8Array};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{DedicatedWorkerGlobalScope, HtmlCanvasElement, MessageEvent, OffscreenCanvas};
+
+use crate::functional::{decode_state, encode_state};
+use crate::html;
+use crate::html::{Classes, Component, Context, Html, NodeRef, Properties};
+
+/// Drawing run in a web worker, on the canvas of a [`WorkerCanvas`].
+pub trait CanvasAgent: Sized + 'static {
+    /// The input of the drawing, like the data it visualizes.
+    type Input: Serialize + for<'de> Deserialize<'de> + PartialEq;
+
+    /// The path of the script of the worker, which calls [`register_canvas`] with this agent.
+    const PATH: &'static str;
+
+    /// Creates the agent drawing on `canvas`, when its worker is spawned.
+    fn create(canvas: OffscreenCanvas) -> Self;
+
+    /// Draws the canvas for a new input.
+    fn update(&mut self, input: Self::Input);
+}
+
+/// Runs the canvas agent `A` in the current worker.
+///
+/// This must be called from the `main` function of the worker binary.
+pub fn register_canvas<A>()
+where
+    A: CanvasAgent,
+{
+    let scope: DedicatedWorkerGlobalScope = js_sys::global().unchecked_into();
+    let agent = RefCell::new(None);
+    let onmessage = Closure::<dyn Fn(MessageEvent)>::new(move |e: MessageEvent| {
+        // The canvas is sent before any input.
+        match e.data().dyn_into::<OffscreenCanvas>() {
+            Ok(canvas) => *agent.borrow_mut() = Some(A::create(canvas)),
+            Err(data) => {
+                let bytes = Uint8Array::new(&data).to_vec();
+                let input = decode_state(&bytes).expect("failed to decode a canvas input");
+                if let Some(ref mut agent) = *agent.borrow_mut() {
+                    agent.update(input);
+                }
+            }
+        }
+    });
+    scope.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+    // The agent runs as long as the worker.
+    onmessage.forget();
+}
+
+/// Properties for [WorkerCanvas].
+#[derive(Properties)]
+pub struct WorkerCanvasProps<A>
+where
+    A: CanvasAgent,
+{
+    /// The input of the drawing, sent to the agent when it changes.
+    pub input: A::Input,
+
+    /// The classes of the `<canvas>`, which sizes it on the page.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+// Implemented by hand, as the agent itself needn't be comparable.
+impl<A> PartialEq for WorkerCanvasProps<A>
+where
+    A: CanvasAgent,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.input == other.input && self.class == other.class
+    }
+}
+
+impl<A> std::fmt::Debug for WorkerCanvasProps<A>
+where
+    A: CanvasAgent,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WorkerCanvasProps")
+            .field("class", &self.class)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Renders a `<canvas>` drawn by the agent `A`, in a web worker.
+///
+/// The control of the canvas is transferred to the worker when it is first rendered, so drawing
+/// it never blocks the main thread. The input is only sent once per render of the scheduler, after
+/// the changes of the properties of the batch are applied, so rapid updates of the properties
+/// don't flood the worker. The worker is terminated when the component is unmounted.
+///
+/// The size of the drawing is set by the agent, on its [`OffscreenCanvas`].
+///
+/// # Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew::worker::{CanvasAgent, WorkerCanvas};
+///
+/// pub struct Chart {
+///     canvas: web_sys::OffscreenCanvas,
+/// }
+///
+/// impl CanvasAgent for Chart {
+///     type Input = Vec<f64>;
+///
+///     const PATH: &'static str = "/chart.js";
+///
+///     fn create(canvas: web_sys::OffscreenCanvas) -> Self {
+///         Self { canvas }
+///     }
+///
+///     fn update(&mut self, points: Vec<f64>) {
+///         // draw the points on `self.canvas`
+///     }
+/// }
+///
+/// #[function_component]
+/// fn Dashboard() -> Html {
+///     let points = vec![1.0, 4.0, 2.0];
+///     html! { <WorkerCanvas<Chart> input={points} class="chart" /> }
+/// }
+/// ```
+pub struct WorkerCanvas<A>
+where
+    A: CanvasAgent,
+{
+    canvas: NodeRef,
+    worker: Option<web_sys::Worker>,
+    // Whether the input changed since it was last sent.
+    dirty: bool,
+    _marker: PhantomData<A>,
+}
+
+impl<A> std::fmt::Debug for WorkerCanvas<A>
+where
+    A: CanvasAgent,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("WorkerCanvas<_>")
+    }
+}
+
+impl<A> WorkerCanvas<A>
+where
+    A: CanvasAgent,
+{
+    /// Spawns the worker and transfers the control of the canvas to it.
+    fn spawn(&self) -> Option<web_sys::Worker> {
+        let canvas = self.canvas.cast::<HtmlCanvasElement>()?;
+        let offscreen = canvas.transfer_control_to_offscreen().ok()?;
+        let worker = web_sys::Worker::new(A::PATH).ok()?;
+        worker
+            .post_message_with_transfer(&offscreen, &Array::of1(&offscreen))
+            .ok()?;
+
+        Some(worker)
+    }
+
+    fn send(&self, input: &A::Input) {
+        if let Some(ref worker) = self.worker {
+            let bytes = encode_state(input).expect("failed to encode a canvas input");
+            let _ = worker.post_message(&Uint8Array::from(bytes.as_slice()));
+        }
+    }
+}
+
+impl<A> Component for WorkerCanvas<A>
+where
+    A: CanvasAgent,
+{
+    type Message = ();
+    type Properties = WorkerCanvasProps<A>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            canvas: NodeRef::default(),
+            worker: None,
+            dirty: true,
+            _marker: PhantomData,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        self.dirty |= ctx.props().input != old_props.input;
+        ctx.props().class != old_props.class
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            self.worker = self.spawn();
+        }
+        if std::mem::take(&mut self.dirty) {
+            self.send(&ctx.props().input);
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <canvas ref={&self.canvas} class={ctx.props().class.clone()} />
+        }
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        if let Some(ref worker) = self.worker {
+            worker.terminate();
+        }
+    }
+}
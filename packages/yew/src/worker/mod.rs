@@ -0,0 +1,265 @@
+//! This module contains agents, which run CPU heavy work in web workers, off the main thread.
+//!
+//! An [`Agent`] answers each input it receives with an output. It runs in a worker, a separate
+//! binary of the application whose `main` function calls [`register`], and components spawn it
+//! with [`use_worker`]. The inputs and the outputs are encoded with the
+//! [state codec](crate::functional::StateCodec) of the application, so the same codec must be set
+//! in the worker and in the application.
+//!
+//! # Example
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use yew::prelude::*;
+//! use yew::worker::{use_worker, Agent};
+//!
+//! /// Finds the prime numbers below its input.
+//! pub struct Primes;
+//!
+//! impl Agent for Primes {
+//!     type Input = u64;
+//!     type Output = Vec<u64>;
+//!
+//!     const PATH: &'static str = "/primes.js";
+//!
+//!     fn create() -> Self {
+//!         Self
+//!     }
+//!
+//!     fn handle(&mut self, below: u64) -> Vec<u64> {
+//!         (2..below)
+//!             .filter(|n| (2..*n).take_while(|m| m * m <= *n).all(|m| n % m != 0))
+//!             .collect()
+//!     }
+//! }
+//!
+//! #[function_component]
+//! fn PrimeCount() -> Html {
+//!     let primes = use_worker::<Primes>();
+//!     let count = use_state(|| None);
+//!     let onclick = {
+//!         let count = count.clone();
+//!         Callback::from(move |_| {
+//!             let (primes, count) = (primes.clone(), count.clone());
+//!             yew::platform::spawn_local(async move {
+//!                 count.set(Some(primes.run(1_000_000).await.len()));
+//!             });
+//!         })
+//!     };
+//!
+//!     html! {
+//!         <button {onclick}>{ format!("{:?}", *count) }</button>
+//!     }
+//! }
+//! ```
+//!
+//! The worker binary, built to `/primes.js`, registers the agent:
+//!
+//! ```ignore
+//! fn main() {
+//!     yew::worker::register::<Primes>();
+//! }
+//! ```
+//!
+//! # Canvases
+//!
+//! Heavy visualizations can be drawn in a worker too: a [`WorkerCanvas`] transfers the control of
+//! its `<canvas>` to the worker of a [`CanvasAgent`], registered with [`register_canvas`], and
+//! sends it its input whenever it changes. Only the canvas is handed over to the worker, the rest
+//! of the DOM is still rendered on the main thread.
+
+mod canvas;
+
+use std::cell::{OnceCell, RefCell};
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+
+use futures::channel::oneshot;
+use gloo::worker::{Codec, HandlerId, Registrable, Spawnable, Worker, WorkerBridge, WorkerScope};
+use js_sys::Uint8Array;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+pub use canvas::*;
+
+use crate::functional::{decode_state, encode_state, hook, use_memo};
+
+/// Work run in a web worker, answering each input with an output.
+pub trait Agent: Sized + 'static {
+    /// The input received from components.
+    type Input: Serialize + for<'de> Deserialize<'de>;
+    /// The output answering an input.
+    type Output: Serialize + for<'de> Deserialize<'de>;
+
+    /// The path of the script of the worker, which calls [`register`] with this agent.
+    const PATH: &'static str;
+
+    /// Creates the agent, when its worker is spawned.
+    fn create() -> Self;
+
+    /// Answers an input.
+    fn handle(&mut self, input: Self::Input) -> Self::Output;
+}
+
+/// Runs the agent `A` in the current worker.
+///
+/// This must be called from the `main` function of the worker binary.
+pub fn register<A>()
+where
+    A: Agent,
+{
+    AgentWorker::<A>::registrar()
+        .encoding::<StateCodecEncoding>()
+        .register();
+}
+
+/// The worker running an agent.
+struct AgentWorker<A> {
+    agent: A,
+}
+
+impl<A> Worker for AgentWorker<A>
+where
+    A: Agent,
+{
+    type Input = A::Input;
+    type Message = ();
+    type Output = A::Output;
+
+    fn create(_scope: &WorkerScope<Self>) -> Self {
+        Self { agent: A::create() }
+    }
+
+    fn update(&mut self, _scope: &WorkerScope<Self>, _msg: Self::Message) {}
+
+    fn received(&mut self, scope: &WorkerScope<Self>, input: Self::Input, id: HandlerId) {
+        scope.respond(id, self.agent.handle(input));
+    }
+}
+
+/// Encodes the messages of workers with the state codec of the application.
+struct StateCodecEncoding;
+
+impl Codec for StateCodecEncoding {
+    fn encode<I>(input: I) -> JsValue
+    where
+        I: Serialize,
+    {
+        let bytes = encode_state(&input).expect("failed to encode a worker message");
+        Uint8Array::from(bytes.as_slice()).into()
+    }
+
+    fn decode<O>(input: JsValue) -> O
+    where
+        O: for<'de> Deserialize<'de>,
+    {
+        let bytes = Uint8Array::from(input).to_vec();
+        decode_state(&bytes).expect("failed to decode a worker message")
+    }
+}
+
+struct WorkerState<A>
+where
+    A: Agent,
+{
+    /// The bridge to the worker, spawned once the first input is run.
+    bridge: OnceCell<WorkerBridge<AgentWorker<A>>>,
+    /// The senders of the outputs of the inputs sent, in order.
+    pending: Rc<RefCell<VecDeque<oneshot::Sender<A::Output>>>>,
+}
+
+impl<A> WorkerState<A>
+where
+    A: Agent,
+{
+    fn bridge(&self) -> &WorkerBridge<AgentWorker<A>> {
+        self.bridge.get_or_init(|| {
+            let pending = self.pending.clone();
+            AgentWorker::<A>::spawner()
+                .callback(move |output| {
+                    // The agent answers the inputs in the order they are sent.
+                    if let Some(tx) = pending.borrow_mut().pop_front() {
+                        let _ = tx.send(output);
+                    }
+                })
+                .encoding::<StateCodecEncoding>()
+                .spawn(A::PATH)
+        })
+    }
+}
+
+/// The handle returned by [`use_worker`].
+pub struct UseWorkerHandle<A>
+where
+    A: Agent,
+{
+    state: Rc<WorkerState<A>>,
+}
+
+impl<A> UseWorkerHandle<A>
+where
+    A: Agent,
+{
+    /// Sends an input to the agent, and returns its output.
+    ///
+    /// The worker is spawned when the first input is run, so this must only be called in the
+    /// browser, for example from a callback.
+    pub async fn run(&self, input: A::Input) -> A::Output {
+        let (tx, rx) = oneshot::channel();
+        self.state.pending.borrow_mut().push_back(tx);
+        self.state.bridge().send(input);
+
+        // The bridge is kept alive by this handle until the agent answers.
+        rx.await.expect("the worker of the agent was terminated")
+    }
+}
+
+impl<A> Clone for UseWorkerHandle<A>
+where
+    A: Agent,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<A> PartialEq for UseWorkerHandle<A>
+where
+    A: Agent,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &rhs.state)
+    }
+}
+
+impl<A> fmt::Debug for UseWorkerHandle<A>
+where
+    A: Agent,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseWorkerHandle")
+            .field("pending", &self.state.pending.borrow().len())
+            .finish()
+    }
+}
+
+/// Spawns a web worker running the agent `A`, to send it inputs.
+///
+/// Each component gets its own worker, which is spawned once an input is first run and
+/// terminated when the component is unmounted. See the [module documentation](self) for an
+/// example.
+#[hook]
+pub fn use_worker<A>() -> UseWorkerHandle<A>
+where
+    A: Agent,
+{
+    let state = use_memo((), |_| WorkerState {
+        bridge: OnceCell::new(),
+        pending: Rc::default(),
+    });
+
+    UseWorkerHandle { state }
+}
@@ -0,0 +1,89 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::cell::{Cell, RefCell};
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::error_boundary::CaughtError;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn error_boundary_renders_fallback_until_reset() {
+    thread_local! {
+        static FAIL: Cell<bool> = const { Cell::new(true) };
+        static CAUGHT: RefCell<Option<CaughtError>> = const { RefCell::new(None) };
+    }
+
+    #[function_component]
+    fn Flaky() -> HtmlResult {
+        if FAIL.get() {
+            return Err(ComponentError::new("flaky failed").into());
+        }
+
+        Ok(html! { <div id="result">{ "recovered" }</div> })
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        let fallback = |e: CaughtError| {
+            let message = format!("{} {}", e.error(), e.error().is_panic());
+            CAUGHT.with(|m| *m.borrow_mut() = Some(e));
+            html! { <div id="result">{ message }</div> }
+        };
+
+        html! {
+            <ErrorBoundary {fallback}>
+                <Flaky />
+            </ErrorBoundary>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "flaky failed false");
+
+    FAIL.set(false);
+    let caught = CAUGHT.with(|m| m.borrow_mut().take()).unwrap();
+    assert!(caught.error().component().unwrap().ends_with("Flaky"));
+    caught.reset();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "recovered");
+}
+
+#[wasm_bindgen_test]
+async fn error_boundary_forwards_fallback_errors() {
+    #[function_component]
+    fn Failing() -> HtmlResult {
+        Err(ComponentError::new("failed").into())
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        let inner_fallback = |_| html! { <Failing /> };
+        let outer_fallback =
+            |e: CaughtError| html! { <div id="result">{ e.error().to_string() }</div> };
+
+        html! {
+            <ErrorBoundary fallback={outer_fallback}>
+                <ErrorBoundary fallback={inner_fallback}>
+                    <Failing />
+                </ErrorBoundary>
+            </ErrorBoundary>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "failed");
+}
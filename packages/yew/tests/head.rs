@@ -0,0 +1,69 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use yew::head::{Meta, Title};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn head_declarations_are_applied_and_reverted() {
+    #[function_component]
+    fn Page() -> Html {
+        html! {
+            <>
+                <Title text="Page" />
+                <Meta name="description" content="A page" />
+            </>
+        }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        let show_page = use_state(|| true);
+        let onclick = {
+            let show_page = show_page.clone();
+            Callback::from(move |_| show_page.set(false))
+        };
+
+        html! {
+            <>
+                <Title text="App" />
+                <button id="hide" {onclick}>{ "Hide" }</button>
+                if *show_page {
+                    <Page />
+                }
+            </>
+        }
+    }
+
+    let document = gloo::utils::document();
+    yew::Renderer::<App>::with_root(document.get_element_by_id("output").unwrap()).render();
+    sleep(Duration::ZERO).await;
+
+    let description = || {
+        document
+            .query_selector("meta[name=description]")
+            .unwrap()
+            .and_then(|m| m.get_attribute("content"))
+    };
+
+    assert_eq!(document.title(), "Page");
+    assert_eq!(description().as_deref(), Some("A page"));
+
+    document
+        .get_element_by_id("hide")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>()
+        .click();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(document.title(), "App");
+    assert_eq!(description(), None);
+}
@@ -0,0 +1,78 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{KeyboardEvent, KeyboardEventInit};
+use yew::hotkey::{use_hotkey, use_scoped_hotkey};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn press(ctrl: bool, key: &str) {
+    let init = KeyboardEventInit::new();
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    init.set_ctrl_key(ctrl);
+    init.set_key(key);
+
+    let event = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+    gloo::utils::document()
+        .active_element()
+        .unwrap()
+        .dispatch_event(&event)
+        .unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn scoped_hotkeys_take_precedence() {
+    #[function_component]
+    fn Shortcuts() -> Html {
+        let node = use_node_ref();
+        let global = use_state(|| 0);
+        let scoped = use_state(|| 0);
+
+        {
+            let global = global.clone();
+            use_hotkey("ctrl+k", move |_| global.set(*global + 1));
+        }
+        {
+            let scoped = scoped.clone();
+            use_scoped_hotkey("ctrl+k", node.clone(), move |_| scoped.set(*scoped + 1));
+        }
+
+        html! {
+            <>
+                <input id="scoped" ref={node} />
+                <div id="result">{ format!("{} {}", *global, *scoped) }</div>
+            </>
+        }
+    }
+
+    yew::Renderer::<Shortcuts>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    press(true, "k");
+    sleep(Duration::ZERO).await;
+    press(false, "k");
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "1 0");
+
+    gloo::utils::document()
+        .get_element_by_id("scoped")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>()
+        .focus()
+        .unwrap();
+    press(true, "K");
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "1 1");
+}
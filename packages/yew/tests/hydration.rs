@@ -1077,3 +1077,110 @@ async fn hydrate_empty() {
     let result = obtain_result_by_id("output");
     assert_eq!(result.as_str(), r#"<div>after</div><div>after</div>"#);
 }
+
+#[wasm_bindgen_test]
+async fn hydrate_islands() {
+    use serde::{Deserialize, Serialize};
+    use yew::islands::{Island, Islands};
+
+    #[derive(Properties, PartialEq, Clone, Serialize, Deserialize)]
+    struct CounterProps {
+        start: i32,
+    }
+
+    #[function_component]
+    fn Counter(props: &CounterProps) -> Html {
+        let ctr = use_state(|| props.start);
+        let onclick = {
+            let ctr = ctr.clone();
+            Callback::from(move |_| ctr.set(*ctr + 1))
+        };
+
+        html! { <button {onclick} class="increase">{*ctr}</button> }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <div>
+                <p>{"static"}</p>
+                <Island<Counter> props={CounterProps { start: 5 }} />
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new()
+        .hydratable(false)
+        .render()
+        .await;
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    assert_eq!(Islands::new().register::<Counter>().hydrate(), 1);
+
+    sleep(Duration::ZERO).await;
+
+    gloo::utils::document()
+        .query_selector(".increase")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    sleep(Duration::ZERO).await;
+
+    let result = gloo::utils::document()
+        .query_selector(".increase")
+        .unwrap()
+        .unwrap()
+        .text_content();
+    assert_eq!(result.as_deref(), Some("6"));
+}
+
+#[wasm_bindgen_test]
+async fn error_boundary_retries_children_failed_on_server() {
+    use std::cell::Cell;
+
+    use yew::error_boundary::ErrorBoundary;
+
+    thread_local! {
+        static FAIL: Cell<bool> = const { Cell::new(true) };
+    }
+
+    #[function_component]
+    fn Flaky() -> HtmlResult {
+        if FAIL.with(Cell::get) {
+            return Err(ComponentError::new("failed").into());
+        }
+        Ok(html! { <div class="content">{"content"}</div> })
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <ErrorBoundary fallback={|_| html! { <div>{"fallback"}</div> }}>
+                <Flaky />
+            </ErrorBoundary>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+    FAIL.with(|fail| fail.set(false));
+
+    let output_element = gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap();
+    output_element.set_inner_html(&s);
+
+    Renderer::<App>::with_root(output_element).hydrate();
+    sleep(Duration::from_millis(50)).await;
+
+    let result = obtain_result_by_id("output");
+    assert_eq!(result.as_str(), r#"<div class="content">content</div>"#);
+}
@@ -0,0 +1,81 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::cell::Cell;
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::keep_alive::KeepAlive;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq)]
+struct TabProps {
+    name: AttrValue,
+}
+
+thread_local! {
+    static MOUNTS: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Shows the number of tabs mounted before it, which is kept while it stays mounted.
+#[function_component]
+fn Tab(props: &TabProps) -> Html {
+    let mount = use_state(|| {
+        MOUNTS.set(MOUNTS.get() + 1);
+        MOUNTS.get()
+    });
+
+    html! { <div id="result">{ format!("{} {}", props.name, *mount) }</div> }
+}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    tab: AttrValue,
+    max: usize,
+}
+
+#[function_component]
+fn App(props: &AppProps) -> Html {
+    html! {
+        <KeepAlive active={props.tab.clone()} max={props.max}>
+            <Tab key={props.tab.to_string()} name={props.tab.clone()} />
+        </KeepAlive>
+    }
+}
+
+async fn show(app: &mut yew::AppHandle<App>, tab: &'static str, max: usize) -> String {
+    app.update(AppProps {
+        tab: tab.into(),
+        max,
+    });
+    sleep(Duration::ZERO).await;
+    obtain_result()
+}
+
+#[wasm_bindgen_test]
+async fn keep_alive_keeps_state_of_hidden_children() {
+    let mut app = yew::Renderer::<App>::with_root_and_props(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+        AppProps {
+            tab: "a".into(),
+            max: 2,
+        },
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "a 1");
+
+    assert_eq!(show(&mut app, "b", 2).await, "b 2");
+    // The state of `a` was kept while hidden.
+    assert_eq!(show(&mut app, "a", 2).await, "a 1");
+    assert_eq!(show(&mut app, "c", 2).await, "c 3");
+    // `b` was the least recently shown, and was evicted when `c` was shown.
+    assert_eq!(show(&mut app, "b", 2).await, "b 4");
+
+    app.destroy();
+}
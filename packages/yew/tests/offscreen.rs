@@ -0,0 +1,65 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::output_element;
+use wasm_bindgen_test::*;
+use yew::offscreen::Offscreen;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Clone)]
+struct TabProps {
+    visible: bool,
+}
+
+#[function_component]
+fn Tab(props: &TabProps) -> Html {
+    html! {
+        <Offscreen visible={props.visible}>
+            <p id="content">{ "Content" }</p>
+        </Offscreen>
+    }
+}
+
+fn content() -> Option<web_sys::Element> {
+    gloo::utils::document().get_element_by_id("content")
+}
+
+#[wasm_bindgen_test]
+async fn prerenders_hidden_children_when_idle() {
+    let mut handle =
+        yew::Renderer::<Tab>::with_root_and_props(output_element(), TabProps { visible: false })
+            .render();
+
+    sleep(Duration::from_millis(200)).await;
+
+    // The children are rendered, but hidden.
+    let element = content().expect("children were not prerendered");
+    assert_eq!(
+        element
+            .parent_element()
+            .unwrap()
+            .get_attribute("style")
+            .as_deref(),
+        Some("display: none;")
+    );
+
+    handle.update(TabProps { visible: true });
+    sleep(Duration::ZERO).await;
+
+    // The prerendered element is revealed instead of being re-created.
+    assert_eq!(content(), Some(element.clone()));
+    assert_eq!(
+        element
+            .parent_element()
+            .unwrap()
+            .get_attribute("style")
+            .as_deref(),
+        Some("display: contents;")
+    );
+}
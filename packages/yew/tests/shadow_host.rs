@@ -0,0 +1,70 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::output_element;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::shadow_host::ShadowHost;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn shadow_host_renders_in_shadow_root() {
+    #[function_component]
+    fn App() -> Html {
+        let clicks = use_state(|| 0);
+        let onclick = {
+            let clicks = clicks.clone();
+            Callback::from(move |_| clicks.set(*clicks + 1))
+        };
+        let styles = vec![AttrValue::from("button { color: rgb(255, 0, 0); }")];
+
+        html! {
+            <div {onclick}>
+                <ShadowHost class="host" {styles}>
+                    <button>{ *clicks }</button>
+                </ShadowHost>
+                <button id="outside">{ "outside" }</button>
+            </div>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(output_element()).render();
+    sleep(Duration::ZERO).await;
+
+    let host = output_element().query_selector(".host").unwrap().unwrap();
+    assert_eq!(host.child_element_count(), 0);
+
+    let shadow = host.shadow_root().unwrap();
+    let button = shadow
+        .query_selector("button")
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    assert_eq!(button.text_content().unwrap(), "0");
+
+    let color = |el: &HtmlElement| {
+        gloo::utils::window()
+            .get_computed_style(el)
+            .unwrap()
+            .unwrap()
+            .get_property_value("color")
+            .unwrap()
+    };
+    let outside = gloo::utils::document()
+        .get_element_by_id("outside")
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    assert_eq!(color(&button), "rgb(255, 0, 0)");
+    assert_ne!(color(&outside), "rgb(255, 0, 0)");
+
+    button.click();
+    sleep(Duration::ZERO).await;
+    assert_eq!(button.text_content().unwrap(), "1");
+}
@@ -0,0 +1,49 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::signal::{create_signal, Signal};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn signal_updates_skip_owner_render() {
+    #[derive(Properties, PartialEq)]
+    struct Props {
+        count: Signal<u32>,
+    }
+
+    #[function_component]
+    fn Counter(props: &Props) -> Html {
+        let renders = use_mut_ref(|| 0);
+        *renders.borrow_mut() += 1;
+
+        html! {
+            <div id="result">
+                { props.count.clone() }{ " " }{ *renders.borrow() }
+            </div>
+        }
+    }
+
+    let count = create_signal(0);
+    yew::Renderer::<Counter>::with_root_and_props(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+        Props {
+            count: count.clone(),
+        },
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "0 1");
+
+    count.set(1);
+    count.update(|m| *m += 1);
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "2 1");
+}
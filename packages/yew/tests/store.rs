@@ -0,0 +1,60 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result_by_id;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::store::{use_selector, use_store, Dispatch, Store};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn selectors_only_rerender_on_selected_changes() {
+    #[derive(Default, Clone, PartialEq)]
+    struct Cart {
+        items: u32,
+        coupon: Option<String>,
+    }
+
+    impl Store for Cart {}
+
+    #[function_component]
+    fn Items() -> Html {
+        let renders = use_mut_ref(|| 0);
+        *renders.borrow_mut() += 1;
+        let items = use_selector(|cart: &Cart| cart.items);
+
+        html! { <div id="items">{ format!("{} {}", items, renders.borrow()) }</div> }
+    }
+
+    #[function_component]
+    fn Whole() -> Html {
+        let renders = use_mut_ref(|| 0);
+        *renders.borrow_mut() += 1;
+        let (cart, _) = use_store::<Cart>();
+
+        html! { <div id="whole">{ format!("{} {}", cart.items, renders.borrow()) }</div> }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! { <><Items /><Whole /></> }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+    sleep(Duration::ZERO).await;
+
+    let dispatch = Dispatch::<Cart>::new();
+    dispatch.reduce_mut(|cart| cart.coupon = Some("SAVE10".to_owned()));
+    sleep(Duration::ZERO).await;
+    dispatch.reduce_mut(|cart| cart.items += 1);
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result_by_id("items"), "1 2");
+    assert_eq!(obtain_result_by_id("whole"), "1 3");
+}
@@ -0,0 +1,52 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi"), debug_assertions))]
+
+mod common;
+
+use std::cell::Cell;
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn strict_mode_renders_and_mounts_effects_twice() {
+    thread_local! {
+        static RENDERS: Cell<u32> = const { Cell::new(0) };
+        static MOUNTS: Cell<u32> = const { Cell::new(0) };
+        static UNMOUNTS: Cell<u32> = const { Cell::new(0) };
+    }
+
+    #[function_component]
+    fn Counted() -> Html {
+        RENDERS.set(RENDERS.get() + 1);
+        let counter = use_state(|| 0);
+
+        {
+            let counter = counter.clone();
+            use_effect_with((), move |_| {
+                MOUNTS.set(MOUNTS.get() + 1);
+                counter.set(*counter + 1);
+                || UNMOUNTS.set(UNMOUNTS.get() + 1)
+            });
+        }
+
+        html! { <div id="result">{ *counter }</div> }
+    }
+
+    yew::Renderer::<Counted>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .strict_mode(true)
+    .render();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(MOUNTS.get(), 2);
+    assert_eq!(UNMOUNTS.get(), 1);
+    // The first render and the one after the state changed are both done twice.
+    assert_eq!(RENDERS.get(), 4);
+    assert_eq!(obtain_result(), "1");
+}
@@ -817,3 +817,70 @@ async fn test_duplicate_suspension() {
     let result = obtain_result();
     assert_eq!(result.as_str(), "hello!");
 }
+
+#[wasm_bindgen_test]
+async fn retry_runs_suspended_computation_again() {
+    use std::cell::Cell;
+
+    use yew::suspense::use_suspense_controller;
+
+    thread_local! {
+        static ATTEMPTS: Cell<u32> = const { Cell::new(0) };
+    }
+
+    #[function_component]
+    fn Content() -> HtmlResult {
+        let controller = use_suspense_controller().unwrap();
+        let res = use_future(|| async {
+            sleep(Duration::from_millis(50)).await;
+            let attempt = ATTEMPTS.with(|m| m.replace(m.get() + 1));
+            if attempt == 0 {
+                Err("failed")
+            } else {
+                Ok(attempt)
+            }
+        })?;
+
+        Ok(match *res {
+            Ok(m) => html! { <div>{ format!("attempt {m}") }</div> },
+            Err(e) => html! {
+                <button class="retry" onclick={controller.retry_callback()}>{ e }</button>
+            },
+        })
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        let fallback = html! {<div>{"wait..."}</div>};
+        html! {
+            <div id="result">
+                <Suspense {fallback}>
+                    <Content />
+                </Suspense>
+            </div>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::from_millis(100)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), r#"<button class="retry">failed</button>"#);
+
+    gloo::utils::document()
+        .query_selector(".retry")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    sleep(Duration::from_millis(10)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>wait...</div>");
+
+    sleep(Duration::from_millis(100)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>attempt 1</div>");
+}
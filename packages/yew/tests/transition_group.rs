@@ -0,0 +1,90 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::output_element;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::transition_group::TransitionGroup;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Clone)]
+struct ListProps {
+    items: Vec<u32>,
+}
+
+#[function_component]
+fn List(props: &ListProps) -> Html {
+    html! {
+        <>
+            <style>
+                { ".t-leave-active { transition: opacity 0.2s; } .t-leave-to { opacity: 0; }" }
+            </style>
+            <TransitionGroup name="t" tag="ul">
+                { for props.items.iter().map(|m| html! { <li key={*m}>{ *m }</li> }) }
+            </TransitionGroup>
+        </>
+    }
+}
+
+fn rendered_items() -> Vec<(String, String)> {
+    let items = output_element().query_selector_all("li").unwrap();
+    (0..items.length())
+        .filter_map(|i| items.item(i))
+        .map(|m| {
+            let element = m.unchecked_into::<Element>();
+            (element.text_content().unwrap(), element.class_name())
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+async fn leaving_children_are_detached_after_their_transition() {
+    let mut handle = yew::Renderer::<List>::with_root_and_props(
+        output_element(),
+        ListProps {
+            items: vec![1, 2, 3],
+        },
+    )
+    .render();
+    sleep(Duration::from_millis(10)).await;
+
+    // Nothing is animated when the children first appear.
+    assert_eq!(
+        rendered_items(),
+        [
+            ("1".to_owned(), String::new()),
+            ("2".to_owned(), String::new()),
+            ("3".to_owned(), String::new()),
+        ]
+    );
+
+    handle.update(ListProps {
+        items: vec![1, 3, 4],
+    });
+    sleep(Duration::from_millis(10)).await;
+
+    // Entering children without a transition end immediately, leaving ones keep their place.
+    assert_eq!(
+        rendered_items(),
+        [
+            ("1".to_owned(), String::new()),
+            ("2".to_owned(), "t-leave-active t-leave-to".to_owned()),
+            ("3".to_owned(), String::new()),
+            ("4".to_owned(), String::new()),
+        ]
+    );
+
+    sleep(Duration::from_millis(400)).await;
+    let texts = rendered_items()
+        .into_iter()
+        .map(|(text, _)| text)
+        .collect::<Vec<_>>();
+    assert_eq!(texts, ["1", "3", "4"]);
+}
@@ -0,0 +1,73 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_async_tracks_progress() {
+    #[function_component]
+    fn UseAsyncComponent() -> Html {
+        let counter = use_mut_ref(|| 0);
+        let value = use_async(move || {
+            *counter.borrow_mut() += 1;
+            let run = *counter.borrow();
+            async move {
+                sleep(Duration::from_millis(10)).await;
+                if run == 1 {
+                    Ok(run)
+                } else {
+                    Err("failed")
+                }
+            }
+        });
+
+        {
+            let value = value.clone();
+            use_effect_with((), move |_| value.run());
+        }
+
+        let onclick = {
+            let value = value.clone();
+            Callback::from(move |_| value.run())
+        };
+
+        html! {
+            <div>
+                <button id="run" {onclick}>{ "Run" }</button>
+                <div id="result">
+                    { format!("{} {:?} {:?}", value.loading(), value.data(), value.error()) }
+                </div>
+            </div>
+        }
+    }
+
+    yew::Renderer::<UseAsyncComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "true None None");
+
+    sleep(Duration::from_millis(50)).await;
+    assert_eq!(obtain_result(), "false Some(1) None");
+
+    gloo::utils::document()
+        .get_element_by_id("run")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>()
+        .click();
+    sleep(Duration::from_millis(50)).await;
+
+    // The data of the last successful run is kept.
+    assert_eq!(obtain_result(), "false Some(1) Some(\"failed\")");
+}
@@ -269,3 +269,69 @@ async fn use_context_update_works() {
         "current: hello world!, total: 4"
     );
 }
+
+#[wasm_bindgen_test]
+async fn use_context_selector_skips_unrelated_updates() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct Session {
+        user_name: String,
+        unread_messages: usize,
+    }
+
+    #[function_component]
+    fn UserName() -> Html {
+        let counter = use_mut_ref(|| 0);
+        *counter.borrow_mut() += 1;
+
+        let user_name = use_context_selector(|session: &Session| session.user_name.clone())
+            .expect("context not passed down");
+
+        html! {
+            <div id="result">{ format!("{}, total: {}", user_name, counter.borrow()) }</div>
+        }
+    }
+
+    #[function_component]
+    fn TestComponent() -> Html {
+        let session = use_state(|| Session {
+            user_name: "jane".into(),
+            unread_messages: 0,
+        });
+        let step = use_mut_ref(|| 0);
+
+        {
+            let session = session.clone();
+            use_effect(move || {
+                let count = *step.borrow();
+                *step.borrow_mut() += 1;
+                match count {
+                    0 | 1 => session.set(Session {
+                        unread_messages: session.unread_messages + 1,
+                        ..(*session).clone()
+                    }),
+                    2 => session.set(Session {
+                        user_name: "john".into(),
+                        ..(*session).clone()
+                    }),
+                    _ => (),
+                }
+            });
+        }
+
+        html! {
+            <ContextProvider<Session> context={(*session).clone()}>
+                <UserName />
+            </ContextProvider<Session>>
+        }
+    }
+
+    yew::Renderer::<TestComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+
+    sleep(Duration::ZERO).await;
+
+    // 1 initial render + 1 user name update, the unread messages updates are skipped.
+    assert_eq!(obtain_result_by_id("result"), "john, total: 2");
+}
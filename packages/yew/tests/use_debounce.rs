@@ -0,0 +1,106 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn click(id: &str) {
+    gloo::utils::document()
+        .get_element_by_id(id)
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>()
+        .click();
+}
+
+#[wasm_bindgen_test]
+async fn use_debounce_delays_updates() {
+    #[function_component]
+    fn UseDebounceComponent() -> Html {
+        let counter = use_state(|| 0);
+        let debounced = use_debounce(*counter, Duration::from_millis(50));
+
+        let onclick = {
+            let counter = counter.clone();
+            Callback::from(move |_| counter.set(*counter + 1))
+        };
+
+        html! {
+            <div>
+                <button id="increment" {onclick}>{ "Increment" }</button>
+                <div id="result">{ format!("{} {}", *counter, debounced) }</div>
+            </div>
+        }
+    }
+
+    yew::Renderer::<UseDebounceComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    click("increment");
+    sleep(Duration::from_millis(10)).await;
+    click("increment");
+    sleep(Duration::from_millis(10)).await;
+    assert_eq!(obtain_result(), "2 0");
+
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(obtain_result(), "2 2");
+}
+
+#[wasm_bindgen_test]
+async fn use_throttle_callback_limits_emissions() {
+    #[function_component]
+    fn UseThrottleComponent() -> Html {
+        let emitted = use_state(Vec::<u32>::new);
+        let clicks = use_mut_ref(|| 0);
+
+        let throttled = {
+            let emitted = emitted.clone();
+            use_throttle_callback(
+                Callback::from(move |value: u32| {
+                    let mut values = (*emitted).clone();
+                    values.push(value);
+                    emitted.set(values);
+                }),
+                Duration::from_millis(50),
+            )
+        };
+
+        let onclick = Callback::from(move |_| {
+            *clicks.borrow_mut() += 1;
+            throttled.emit(*clicks.borrow());
+        });
+
+        html! {
+            <div>
+                <button id="emit" {onclick}>{ "Emit" }</button>
+                <div id="result">{ format!("{:?}", *emitted) }</div>
+            </div>
+        }
+    }
+
+    yew::Renderer::<UseThrottleComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    click("emit");
+    click("emit");
+    click("emit");
+    sleep(Duration::from_millis(10)).await;
+    assert_eq!(obtain_result(), "[1]");
+
+    // The last input received while throttled is emitted at the end of the interval.
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(obtain_result(), "[1, 3]");
+}
@@ -0,0 +1,78 @@
+#![cfg(all(
+    target_arch = "wasm32",
+    not(target_os = "wasi"),
+    feature = "drag_and_drop"
+))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_test::*;
+use web_sys::{DataTransfer, DragEvent, DragEventInit};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn dispatch(id: &str, event_type: &str, data_transfer: &DataTransfer) {
+    let init = DragEventInit::new();
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    init.set_data_transfer(Some(data_transfer));
+
+    let event = DragEvent::new_with_event_init_dict(event_type, &init).unwrap();
+    gloo::utils::document()
+        .get_element_by_id(id)
+        .unwrap()
+        .dispatch_event(&event)
+        .unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn drop_target_receives_payload() {
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Card {
+        id: u32,
+    }
+
+    #[function_component]
+    fn Board() -> Html {
+        let card_ref = use_node_ref();
+        let column_ref = use_node_ref();
+        let dropped = use_state(|| None::<u32>);
+
+        let dragging = use_draggable(card_ref.clone(), Card { id: 7 });
+        let over = {
+            let dropped = dropped.clone();
+            use_drop_target(column_ref.clone(), move |card: Card| {
+                dropped.set(Some(card.id))
+            })
+        };
+
+        html! {
+            <>
+                <div id="card" ref={card_ref} />
+                <div id="column" ref={column_ref} />
+                <div id="result">{ format!("{} {} {:?}", dragging, over, *dropped) }</div>
+            </>
+        }
+    }
+
+    yew::Renderer::<Board>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+    sleep(Duration::ZERO).await;
+
+    let data_transfer = DataTransfer::new().unwrap();
+    dispatch("card", "dragstart", &data_transfer);
+    dispatch("column", "dragenter", &data_transfer);
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "true true None");
+
+    dispatch("column", "drop", &data_transfer);
+    dispatch("card", "dragend", &data_transfer);
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "false false Some(7)");
+}
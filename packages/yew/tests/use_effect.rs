@@ -186,3 +186,36 @@ async fn use_effect_refires_on_dependency_change() {
 
     assert_eq!(result.as_str(), "11");
 }
+
+#[wasm_bindgen_test]
+async fn use_effect_async_with_aborts_stale_effects() {
+    #[function_component]
+    fn UseEffectAsyncComponent() -> Html {
+        let query = use_state(|| 0);
+        let results = use_state(Vec::new);
+
+        {
+            let results = results.clone();
+            use_effect_async_with(*query, move |query| async move {
+                sleep(Duration::from_millis(10)).await;
+                let mut next = (*results).clone();
+                next.push(*query);
+                results.set(next);
+            });
+        }
+        {
+            let query = query.clone();
+            use_effect_with((), move |_| query.set(1));
+        }
+
+        html! { <div id="result">{ format!("{:?}", *results) }</div> }
+    }
+
+    yew::Renderer::<UseEffectAsyncComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(obtain_result(), "[1]");
+}
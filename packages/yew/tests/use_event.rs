@@ -0,0 +1,64 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Event, MouseEvent};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_event_listens_to_node_and_window() {
+    #[function_component]
+    fn UseEventComponent() -> Html {
+        let node = use_node_ref();
+        let clicks = use_state(|| 0);
+        let resizes = use_state(|| 0);
+
+        {
+            let clicks = clicks.clone();
+            use_event(node.clone(), "click", move |_: MouseEvent| {
+                clicks.set(*clicks + 1)
+            });
+        }
+        {
+            let resizes = resizes.clone();
+            use_window_event("resize", move |_: Event| resizes.set(*resizes + 1));
+        }
+
+        html! {
+            <div>
+                <button id="button" ref={node}>{ "Click" }</button>
+                <div id="result">{ format!("{} {}", *clicks, *resizes) }</div>
+            </div>
+        }
+    }
+
+    yew::Renderer::<UseEventComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    let button = gloo::utils::document()
+        .get_element_by_id("button")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>();
+    button.click();
+    sleep(Duration::ZERO).await;
+    button.click();
+    sleep(Duration::ZERO).await;
+
+    gloo::utils::window()
+        .dispatch_event(&Event::new("resize").unwrap())
+        .unwrap();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "2 1");
+}
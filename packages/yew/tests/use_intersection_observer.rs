@@ -0,0 +1,47 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_intersection_observer_tracks_visibility() {
+    #[function_component]
+    fn UseIntersectionComponent() -> Html {
+        let visible = use_node_ref();
+        let hidden = use_node_ref();
+        let visible_intersection = use_intersection_observer(visible.clone());
+        let hidden_intersection = use_intersection_observer(hidden.clone());
+
+        html! {
+            <div>
+                <div ref={visible} style="height: 10px;" />
+                <div ref={hidden} style="position: fixed; top: -1000px; height: 10px;" />
+                <div id="result">
+                    {
+                        format!(
+                            "{} {}",
+                            visible_intersection.is_intersecting,
+                            hidden_intersection.is_intersecting,
+                        )
+                    }
+                </div>
+            </div>
+        }
+    }
+
+    yew::Renderer::<UseIntersectionComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(obtain_result(), "true false");
+}
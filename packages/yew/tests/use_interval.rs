@@ -0,0 +1,45 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_interval_and_use_timeout_work() {
+    #[function_component]
+    fn UseTimerComponent() -> Html {
+        let ticks = use_state(|| 0);
+        let done = use_state(|| false);
+
+        {
+            let ticks = ticks.clone();
+            use_interval(move || ticks.set(*ticks + 1), Duration::from_millis(20));
+        }
+        {
+            let done = done.clone();
+            use_timeout(move || done.set(true), Duration::from_millis(50));
+        }
+
+        html! {
+            <div id="result">{ format!("{} {}", *ticks >= 2, *done) }</div>
+        }
+    }
+
+    yew::Renderer::<UseTimerComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "false false");
+
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(obtain_result(), "true true");
+}
@@ -0,0 +1,33 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_media_query_evaluates_query() {
+    #[function_component]
+    fn UseMediaQueryComponent() -> Html {
+        let screen = use_media_query("screen".into());
+        let never = use_media_query("not all".into());
+
+        html! {
+            <div id="result">{ format!("{screen} {never}") }</div>
+        }
+    }
+
+    yew::Renderer::<UseMediaQueryComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "true false");
+}
@@ -0,0 +1,78 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::cell::RefCell;
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+thread_local! {
+    static HANDLE: RefCell<Option<UseMountedHandle>> = const { RefCell::new(None) };
+}
+
+#[wasm_bindgen_test]
+async fn use_mounted_tracks_lifecycle() {
+    #[function_component]
+    fn Child() -> Html {
+        let first_render = use_is_first_render();
+        let mounted = use_mounted();
+        let renders = use_mut_ref(Vec::new);
+        renders.borrow_mut().push(first_render);
+
+        let counter = use_state(|| 0);
+        {
+            let counter = counter.clone();
+            use_effect(move || {
+                if *counter < 1 {
+                    counter.set(*counter + 1);
+                }
+            });
+        }
+
+        HANDLE.with(|m| *m.borrow_mut() = Some(mounted));
+
+        html! { <div id="result">{ format!("{:?}", renders.borrow()) }</div> }
+    }
+
+    #[function_component]
+    fn Parent() -> Html {
+        let show = use_state(|| true);
+        let onclick = {
+            let show = show.clone();
+            Callback::from(move |_| show.set(false))
+        };
+
+        html! {
+            <>
+                <button id="hide" {onclick} />
+                if *show { <Child /> }
+            </>
+        }
+    }
+
+    yew::Renderer::<Parent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "[true, false]");
+    let handle = HANDLE.with(|m| m.borrow().clone()).unwrap();
+    assert!(handle.is_mounted());
+
+    gloo::utils::document()
+        .get_element_by_id("hide")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>()
+        .click();
+    sleep(Duration::ZERO).await;
+
+    assert!(!handle.is_mounted());
+}
@@ -0,0 +1,33 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_online_follows_navigator() {
+    #[function_component]
+    fn UseOnlineComponent() -> Html {
+        let online = use_online();
+
+        html! {
+            <div id="result">{ online }</div>
+        }
+    }
+
+    yew::Renderer::<UseOnlineComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    let expected = gloo::utils::window().navigator().on_line();
+    assert_eq!(obtain_result(), expected.to_string());
+}
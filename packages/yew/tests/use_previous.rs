@@ -0,0 +1,42 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_previous_returns_value_of_last_render() {
+    #[function_component]
+    fn UsePreviousComponent() -> Html {
+        let counter = use_state(|| 0);
+        let previous = use_previous(*counter);
+
+        {
+            let counter = counter.clone();
+            use_effect(move || {
+                if *counter < 3 {
+                    counter.set(*counter + 1);
+                }
+            });
+        }
+
+        html! {
+            <div id="result">{ format!("{} {:?}", *counter, previous) }</div>
+        }
+    }
+
+    yew::Renderer::<UsePreviousComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "3 Some(2)");
+}
@@ -162,3 +162,51 @@ async fn use_reducer_eq_works() {
     let result = obtain_result();
     assert_eq!(result.as_str(), "3");
 }
+
+#[wasm_bindgen_test]
+async fn use_reducer_with_middleware_works() {
+    fn double(action: i32, next: MiddlewareNext<CounterState>) {
+        next.run(action * 2);
+    }
+
+    fn skip_negative(action: i32, next: MiddlewareNext<CounterState>) {
+        if action >= 0 {
+            next.run(action);
+            // Follow-up actions go through the whole chain again.
+            if next.state().counter == 12 {
+                next.dispatcher().dispatch(1);
+            }
+        }
+    }
+
+    #[function_component(UseReducerComponent)]
+    fn use_reducer_comp() -> Html {
+        let counter = use_reducer_with_middleware(
+            || CounterState { counter: 10 },
+            vec![Box::new(double), Box::new(skip_negative)],
+        );
+
+        let counter_clone = counter.clone();
+        use_effect_with((), move |_| {
+            counter_clone.dispatch(-5);
+            counter_clone.dispatch(1);
+            || {}
+        });
+        html! {
+            <div>
+                {"The test result is"}
+                <div id="result">{counter.counter}</div>
+                {"\n"}
+            </div>
+        }
+    }
+
+    yew::Renderer::<UseReducerComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+    let result = obtain_result();
+
+    assert_eq!(result.as_str(), "14");
+}
@@ -7,6 +7,8 @@ use std::time::Duration;
 
 use common::obtain_result;
 use wasm_bindgen_test::*;
+use web_sys::{HtmlButtonElement, HtmlInputElement};
+use yew::html::NodeRefError;
 use yew::platform::time::sleep;
 use yew::prelude::*;
 
@@ -40,3 +42,55 @@ async fn use_ref_works() {
     let result = obtain_result();
     assert_eq!(result.as_str(), "true");
 }
+
+#[wasm_bindgen_test]
+async fn use_node_ref_typed_reports_mismatches() {
+    #[function_component]
+    fn UseNodeRefTypedComponent() -> Html {
+        let button_ref = use_node_ref_typed::<HtmlButtonElement>();
+        let input_ref = use_node_ref_typed::<HtmlInputElement>();
+        let result = use_state(String::new);
+
+        {
+            let button_ref = button_ref.clone();
+            let input_ref = input_ref.clone();
+            let result = result.clone();
+            use_effect_with((), move |_| {
+                let button_ok = button_ref.get().is_some();
+                let error = input_ref.cast().unwrap_err();
+                assert_eq!(
+                    error,
+                    NodeRefError::TypeMismatch {
+                        expected: "HtmlInputElement",
+                        actual: "div".to_owned(),
+                    }
+                );
+                result.set(format!("{button_ok} {}", error));
+            });
+        }
+
+        html! {
+            <div>
+                <button ref={&button_ref} />
+                <div ref={input_ref} />
+                <div id="result">{ (*result).clone() }</div>
+            </div>
+        }
+    }
+
+    yew::Renderer::<UseNodeRefTypedComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    let result = obtain_result();
+    assert_eq!(
+        result.as_str(),
+        "true expected a node of type `HtmlInputElement`, but the reference is attached to `div`."
+    );
+    assert_eq!(
+        NodeRef::default().cast_into::<HtmlButtonElement>(),
+        Err(NodeRefError::Unbound)
+    );
+}
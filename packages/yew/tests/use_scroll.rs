@@ -0,0 +1,46 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_scroll_tracks_position_and_direction() {
+    #[function_component]
+    fn UseScrollComponent() -> Html {
+        let node = use_node_ref();
+        let scroll = use_scroll(node.clone());
+
+        {
+            let node = node.clone();
+            use_effect_with((), move |_| {
+                node.cast::<Element>().unwrap().set_scroll_top(100);
+            });
+        }
+
+        html! {
+            <>
+                <div ref={node} style="overflow: auto; height: 50px;">
+                    <div style="height: 500px;" />
+                </div>
+                <div id="result">{ format!("{} {:?}", scroll.y, scroll.direction) }</div>
+            </>
+        }
+    }
+
+    yew::Renderer::<UseScrollComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::from_millis(100)).await;
+
+    assert_eq!(obtain_result(), "100 Some(Down)");
+}
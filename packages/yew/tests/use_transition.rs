@@ -0,0 +1,57 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_deferred_value_catches_up() {
+    #[function_component]
+    fn UseDeferredValueComponent() -> Html {
+        let counter = use_state(|| 0);
+        let deferred = use_deferred_value(*counter);
+        let transition = use_transition();
+
+        let onclick = {
+            let counter = counter.clone();
+            let transition = transition.clone();
+            Callback::from(move |_| {
+                counter.set(*counter + 1);
+                // Superseded by the next transition before it can run.
+                transition.start(|| panic!("superseded transitions must not run"));
+                transition.start(|| {});
+            })
+        };
+
+        html! {
+            <div>
+                <button id="increment" {onclick}>{ "Increment" }</button>
+                <div id="result">{ format!("{} {}", *counter, deferred) }</div>
+            </div>
+        }
+    }
+
+    yew::Renderer::<UseDeferredValueComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "0 0");
+
+    gloo::utils::document()
+        .get_element_by_id("increment")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>()
+        .click();
+    sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(obtain_result(), "1 1");
+}
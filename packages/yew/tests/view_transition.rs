@@ -0,0 +1,44 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::{obtain_result, output_element};
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::view_transition::{start_view_transition, ViewTransition};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn view_transition_applies_update() {
+    thread_local! {
+        static TRANSITION: std::cell::RefCell<Option<ViewTransition>> = Default::default();
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        let count = use_state(|| 0);
+
+        {
+            let count = count.clone();
+            use_effect_with((), move |_| {
+                let transition = start_view_transition(move || count.set(1));
+                TRANSITION.with(|m| *m.borrow_mut() = Some(transition));
+            });
+        }
+
+        html! { <div id="result">{ *count }</div> }
+    }
+
+    yew::Renderer::<App>::with_root(output_element()).render();
+    sleep(Duration::ZERO).await;
+
+    let transition = TRANSITION.with(|m| m.borrow_mut().take()).unwrap();
+    transition.finished().await;
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "1");
+}
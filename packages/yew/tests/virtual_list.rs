@@ -0,0 +1,114 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::output_element;
+use wasm_bindgen_test::*;
+use web_sys::{KeyboardEvent, KeyboardEventInit};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::virtual_list::ItemSize;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Clone)]
+struct ListProps {
+    scroll_to_index: Option<usize>,
+}
+
+#[function_component]
+fn List(props: &ListProps) -> Html {
+    let render_item = Callback::from(|index: usize| html! { <span class="row">{ index }</span> });
+
+    html! {
+        <VirtualList
+            item_count={1_000}
+            item_size={ItemSize::Fixed(20.0)}
+            overscan={2}
+            {render_item}
+            scroll_to_index={props.scroll_to_index}
+            style="height: 100px;"
+        />
+    }
+}
+
+fn rendered_rows() -> Vec<String> {
+    let rows = output_element().query_selector_all(".row").unwrap();
+    (0..rows.length())
+        .filter_map(|i| rows.item(i))
+        .filter_map(|m| m.text_content())
+        .collect()
+}
+
+#[wasm_bindgen_test]
+async fn renders_visible_rows_only() {
+    let mut handle = yew::Renderer::<List>::with_root_and_props(
+        output_element(),
+        ListProps {
+            scroll_to_index: None,
+        },
+    )
+    .render();
+    sleep(Duration::from_millis(50)).await;
+
+    let rows = rendered_rows();
+    assert_eq!(rows.first().map(String::as_str), Some("0"));
+    assert!(rows.len() < 20, "rendered {} rows", rows.len());
+
+    handle.update(ListProps {
+        scroll_to_index: Some(500),
+    });
+    sleep(Duration::from_millis(50)).await;
+
+    let rows = rendered_rows();
+    assert!(rows.iter().any(|m| m == "500"));
+    assert!(!rows.iter().any(|m| m == "0"));
+}
+
+#[wasm_bindgen_test]
+async fn measures_estimated_rows_and_navigates_with_keyboard() {
+    #[function_component]
+    fn EstimatedList() -> Html {
+        // Rows are 40 pixels high, twice the estimate.
+        let render_item = Callback::from(|index: usize| {
+            html! { <div class="row" style="height: 40px;">{ index }</div> }
+        });
+
+        html! {
+            <VirtualList
+                item_count={100}
+                item_size={ItemSize::Estimated(20.0)}
+                overscan={0}
+                {render_item}
+                active_classes="active"
+                style="height: 100px;"
+            />
+        }
+    }
+
+    yew::Renderer::<EstimatedList>::with_root(output_element()).render();
+    sleep(Duration::from_millis(50)).await;
+
+    // Once measured, only the rows overlapping the 100 pixels of the viewport are rendered.
+    assert_eq!(rendered_rows(), ["0", "1", "2"]);
+
+    let container = output_element()
+        .query_selector("[tabindex]")
+        .unwrap()
+        .unwrap();
+    let press = |key: &str| {
+        let init = KeyboardEventInit::new();
+        init.set_key(key);
+        let event = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+        container.dispatch_event(&event).unwrap();
+    };
+
+    press("End");
+    sleep(Duration::from_millis(50)).await;
+
+    let active = output_element().query_selector(".active").unwrap().unwrap();
+    assert_eq!(active.text_content().unwrap(), "99");
+    assert!(rendered_rows().iter().any(|m| m == "99"));
+}
@@ -0,0 +1,41 @@
+//! Finding the children of a keyed list that keep their place when it is reordered.
+
+use yew::utils::longest_increasing_subsequence;
+
+fn main() {
+    divan::main();
+}
+
+const LEN: usize = 1000;
+
+fn indices(order: impl Iterator<Item = usize>) -> Vec<Option<usize>> {
+    order.map(Some).collect()
+}
+
+#[divan::bench]
+fn keyed_unchanged(bencher: divan::Bencher) {
+    let seq = indices(0..LEN);
+    bencher.bench_local(|| longest_increasing_subsequence(divan::black_box(&seq)));
+}
+
+#[divan::bench]
+fn keyed_reversed(bencher: divan::Bencher) {
+    let seq = indices((0..LEN).rev());
+    bencher.bench_local(|| longest_increasing_subsequence(divan::black_box(&seq)));
+}
+
+#[divan::bench]
+fn keyed_swap_rows(bencher: divan::Bencher) {
+    let mut seq = indices(0..LEN);
+    seq.swap(1, LEN - 2);
+    bencher.bench_local(|| longest_increasing_subsequence(divan::black_box(&seq)));
+}
+
+#[divan::bench]
+fn keyed_shuffled(bencher: divan::Bencher) {
+    // a fixed permutation, with every tenth row new
+    let seq: Vec<_> = (0..LEN)
+        .map(|i| (i % 10 != 0).then_some(i * 7919 % LEN))
+        .collect();
+    bencher.bench_local(|| longest_increasing_subsequence(divan::black_box(&seq)));
+}